@@ -1,8 +1,9 @@
 use criterion::Bencher;
 use time::format_description::well_known::{Rfc2822, Rfc3339};
 use time::format_description::{modifier, Component};
+use time::macros::format_description as fd;
 use time::parsing::Parsed;
-use time::OffsetDateTime;
+use time::{OffsetDateTime, UtcDateTime};
 
 macro_rules! component {
     ($name:ident {$($field:ident : $value:expr),+ $(,)? }) => {{
@@ -216,9 +217,44 @@ setup_benchmark! {
         ben.iter(|| OffsetDateTime::parse("2021-01-02T03:04:05.123456789+01:02", &Rfc3339));
     }
 
+    fn parse_rfc3339_utc(ben: &mut Bencher<'_>) {
+        ben.iter(|| UtcDateTime::parse("2021-01-02T03:04:05Z", &Rfc3339));
+        ben.iter(|| UtcDateTime::parse("2021-01-02T03:04:05.123456789Z", &Rfc3339));
+        ben.iter(|| UtcDateTime::parse("2021-01-02T03:04:05.123456789-01:02", &Rfc3339));
+    }
+
+    // The equivalent format description, which goes through the generic component parsers and
+    // `Parsed`. This is the baseline that the dedicated RFC 3339 parser is compared against.
+    fn parse_rfc3339_generic(ben: &mut Bencher<'_>) {
+        let format = fd!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]][offset_hour \
+             sign:mandatory]:[offset_minute]"
+        );
+        ben.iter(|| OffsetDateTime::parse("2021-01-02T03:04:05+00:00", format));
+        ben.iter(|| OffsetDateTime::parse("2021-01-02T03:04:05.123456789+00:00", format));
+        ben.iter(|| OffsetDateTime::parse("2021-01-02T03:04:05.123456789-01:02", format));
+    }
+
     fn parse_rfc2822(ben: &mut Bencher<'_>) {
         ben.iter(|| OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 +0000", &Rfc2822));
         ben.iter(|| OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 +0607", &Rfc2822));
         ben.iter(|| OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 -0607", &Rfc2822));
     }
+
+    fn parse_rfc2822_utc(ben: &mut Bencher<'_>) {
+        ben.iter(|| UtcDateTime::parse("Sat, 02 Jan 2021 03:04:05 +0000", &Rfc2822));
+        ben.iter(|| UtcDateTime::parse("Sat, 02 Jan 2021 03:04:05 -0607", &Rfc2822));
+    }
+
+    // The equivalent format description, which goes through the generic component parsers and
+    // `Parsed`. This is the baseline that the dedicated RFC 2822 parser is compared against.
+    fn parse_rfc2822_generic(ben: &mut Bencher<'_>) {
+        let format = fd!(
+            "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] \
+             [offset_hour sign:mandatory][offset_minute]"
+        );
+        ben.iter(|| OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 +0000", format));
+        ben.iter(|| OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 +0607", format));
+        ben.iter(|| OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 -0607", format));
+    }
 }
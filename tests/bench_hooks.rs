@@ -0,0 +1,53 @@
+use time::bench_hooks::{self, Counters};
+use time::format_description::well_known::Rfc3339;
+use time::macros::{date, datetime, format_description, offset, time};
+use time::{Date, OffsetDateTime, Time};
+
+#[test]
+fn format() {
+    bench_hooks::reset();
+    assert_eq!(bench_hooks::counters(), Counters::default());
+
+    let mut buf = Vec::new();
+    assert!(date!(2021-01-02).format_into(&mut buf, format_description!("[year]")).is_ok());
+    assert!(time!(3:04).format(format_description!("[hour]:[minute]")).is_ok());
+    assert!(offset!(+1).format(format_description!("[offset_hour]")).is_ok());
+    assert!(date!(2021-01-02).format(&Rfc3339).is_err());
+
+    let counters = bench_hooks::counters();
+    assert_eq!(counters.formats, 4);
+    assert_eq!(counters.allocations_avoided, 1);
+    assert_eq!(counters.bytes_formatted, 4 + 5 + 2);
+    assert_eq!(counters.parses, 0);
+    assert_eq!(counters.bytes_parsed, 0);
+}
+
+#[test]
+fn parse() {
+    bench_hooks::reset();
+
+    assert!(Date::parse("2021-002", format_description!("[year]-[ordinal]")).is_ok());
+    assert!(Time::parse("invalid", format_description!("[hour]")).is_err());
+    assert!(OffsetDateTime::parse("2021-01-02T03:04:05Z", &Rfc3339).is_ok());
+    assert!(datetime!(2021-01-02 03:04:05 UTC).formatted_len(&Rfc3339).is_ok());
+
+    let counters = bench_hooks::counters();
+    assert_eq!(counters.parses, 3);
+    assert_eq!(counters.bytes_parsed, 8 + 7 + 20);
+    assert_eq!(counters.formats, 0);
+
+    bench_hooks::reset();
+    assert_eq!(bench_hooks::counters(), Counters::default());
+}
+
+#[test]
+fn per_thread() {
+    bench_hooks::reset();
+    assert!(date!(2021-01-02).format(&Rfc3339).is_err());
+    std::thread::spawn(|| {
+        assert_eq!(bench_hooks::counters(), Counters::default());
+    })
+    .join()
+    .expect("thread should not panic");
+    assert_eq!(bench_hooks::counters().formats, 1);
+}
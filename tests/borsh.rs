@@ -0,0 +1,126 @@
+use std::fmt::Debug;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use time::ext::NumericalDuration;
+use time::macros::{date, datetime, offset, time, utc_datetime};
+use time::{
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset, Weekday,
+};
+
+/// Assert that the value is encoded as the provided bytes and that the bytes decode to the value.
+#[track_caller]
+fn assert_encoding<T: BorshSerialize + BorshDeserialize + PartialEq + Debug>(
+    value: T,
+    bytes: &[u8],
+) {
+    assert_eq!(borsh::to_vec(&value).expect("serialization failed"), bytes);
+    assert_eq!(
+        borsh::from_slice::<T>(bytes).expect("deserialization failed"),
+        value
+    );
+}
+
+#[test]
+fn date() {
+    assert_encoding(date!(2024-060), &[0xE8, 0x07, 0, 0, 60, 0]);
+    assert_encoding(date!(-1-365), &[0xFF, 0xFF, 0xFF, 0xFF, 0x6D, 0x01]);
+    assert_encoding(
+        Date::MIN,
+        &borsh::to_vec(&(-999_999_i32, 1_u16)).expect("valid"),
+    );
+    assert!(borsh::from_slice::<Date>(&[0xE8, 0x07, 0, 0, 0x6F, 0x01]).is_err());
+    assert!(borsh::from_slice::<Date>(&[0xE8, 0x07, 0, 0]).is_err());
+}
+
+#[test]
+fn time() {
+    assert_encoding(time!(1:02:03.000_000_004), &[1, 2, 3, 4, 0, 0, 0]);
+    assert_encoding(Time::MAX, &[23, 59, 59, 0xFF, 0xC9, 0x9A, 0x3B]);
+    assert!(borsh::from_slice::<Time>(&[24, 0, 0, 0, 0, 0, 0]).is_err());
+    assert!(borsh::from_slice::<Time>(&[0, 0, 0, 0x00, 0xCA, 0x9A, 0x3B]).is_err());
+}
+
+#[test]
+fn utc_offset() {
+    assert_encoding(offset!(UTC), &[0, 0, 0]);
+    assert_encoding(offset!(-1:02:03), &[0xFF, 0xFE, 0xFD]);
+    assert!(borsh::from_slice::<UtcOffset>(&[26, 0, 0]).is_err());
+}
+
+#[test]
+fn primitive_date_time() {
+    assert_encoding(
+        datetime!(2024-060 1:02:03.000_000_004),
+        &[0xE8, 0x07, 0, 0, 60, 0, 1, 2, 3, 4, 0, 0, 0],
+    );
+    assert_encoding(
+        PrimitiveDateTime::MAX,
+        &borsh::to_vec(&(Date::MAX, Time::MAX)).expect("valid"),
+    );
+}
+
+#[test]
+fn utc_date_time() {
+    assert_encoding(
+        utc_datetime!(2024-060 1:02:03.000_000_004),
+        &[0xE8, 0x07, 0, 0, 60, 0, 1, 2, 3, 4, 0, 0, 0],
+    );
+    assert_encoding(
+        UtcDateTime::MIN,
+        &borsh::to_vec(&(Date::MIN, Time::MIDNIGHT)).expect("valid"),
+    );
+}
+
+#[test]
+fn offset_date_time() {
+    let value = datetime!(2024-060 1:02:03.000_000_004 -1:02:03);
+    assert_encoding(
+        value,
+        &[
+            0xE8, 0x07, 0, 0, 60, 0, 1, 2, 3, 4, 0, 0, 0, 0xFF, 0xFE, 0xFD,
+        ],
+    );
+    let deserialized =
+        borsh::from_slice::<OffsetDateTime>(&borsh::to_vec(&value).expect("valid")).expect("valid");
+    assert_eq!(deserialized.offset(), value.offset());
+}
+
+#[test]
+fn duration() {
+    assert_encoding(
+        1.5.seconds(),
+        &[1, 0, 0, 0, 0, 0, 0, 0, 0x00, 0x65, 0xCD, 0x1D],
+    );
+    assert_encoding(
+        Duration::MIN,
+        &borsh::to_vec(&(i64::MIN, -999_999_999_i32)).expect("valid"),
+    );
+    assert_encoding(
+        Duration::MAX,
+        &borsh::to_vec(&(i64::MAX, 999_999_999_i32)).expect("valid"),
+    );
+
+    let mixed_signs = borsh::to_vec(&(1_i64, -500_000_000_i32)).expect("valid");
+    assert_eq!(
+        borsh::from_slice::<Duration>(&mixed_signs).expect("valid"),
+        0.5.seconds()
+    );
+    let overflow = borsh::to_vec(&(i64::MAX, 1_000_000_000_i32)).expect("valid");
+    assert!(borsh::from_slice::<Duration>(&overflow).is_err());
+}
+
+#[test]
+fn month() {
+    assert_encoding(Month::January, &[1]);
+    assert_encoding(Month::December, &[12]);
+    assert!(borsh::from_slice::<Month>(&[0]).is_err());
+    assert!(borsh::from_slice::<Month>(&[13]).is_err());
+}
+
+#[test]
+fn weekday() {
+    assert_encoding(Weekday::Monday, &[1]);
+    assert_encoding(Weekday::Sunday, &[7]);
+    assert!(borsh::from_slice::<Weekday>(&[0]).is_err());
+    assert!(borsh::from_slice::<Weekday>(&[8]).is_err());
+}
@@ -0,0 +1,200 @@
+use ::chrono::{
+    DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Utc,
+};
+use time::ext::NumericalDuration;
+use time::macros::{date, datetime, offset, time, utc_datetime};
+use time::{
+    error, Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset,
+    Weekday,
+};
+
+fn naive_date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+}
+
+fn naive_time(hour: u32, minute: u32, second: u32, nanosecond: u32) -> NaiveTime {
+    NaiveTime::from_hms_nano_opt(hour, minute, second, nanosecond).expect("valid time")
+}
+
+#[test]
+fn date() {
+    assert_eq!(
+        NaiveDate::try_from(date!(2024-02-29)),
+        Ok(naive_date(2024, 2, 29))
+    );
+    assert_eq!(
+        Date::try_from(naive_date(2024, 2, 29)),
+        Ok(date!(2024-02-29))
+    );
+    assert_eq!(Date::try_from(naive_date(-1, 12, 31)), Ok(date!(-0001-12-31)));
+    assert_eq!(NaiveDate::try_from(Date::MAX), Err(error::ConversionRange));
+    for date in [NaiveDate::MIN, NaiveDate::MAX] {
+        assert_eq!(Date::try_from(date).and_then(NaiveDate::try_from), Ok(date));
+    }
+}
+
+#[test]
+fn time() {
+    assert_eq!(
+        NaiveTime::from(time!(12:34:56.789_012_345)),
+        naive_time(12, 34, 56, 789_012_345)
+    );
+    assert_eq!(NaiveTime::from(Time::MAX), naive_time(23, 59, 59, 999_999_999));
+    assert_eq!(
+        Time::try_from(naive_time(12, 34, 56, 789_012_345)),
+        Ok(time!(12:34:56.789_012_345))
+    );
+    assert_eq!(
+        Time::try_from(naive_time(23, 59, 59, 1_500_000_000)),
+        Err(error::ConversionRange)
+    );
+}
+
+#[test]
+fn primitive_date_time() {
+    let naive = NaiveDateTime::new(naive_date(2024, 1, 2), naive_time(3, 4, 5, 6));
+    assert_eq!(
+        NaiveDateTime::try_from(datetime!(2024-01-02 03:04:05.000_000_006)),
+        Ok(naive)
+    );
+    assert_eq!(
+        PrimitiveDateTime::try_from(naive),
+        Ok(datetime!(2024-01-02 03:04:05.000_000_006))
+    );
+    assert_eq!(
+        NaiveDateTime::try_from(PrimitiveDateTime::MIN),
+        Err(error::ConversionRange)
+    );
+    for datetime in [NaiveDateTime::MIN, NaiveDateTime::MAX] {
+        assert_eq!(
+            PrimitiveDateTime::try_from(datetime).and_then(NaiveDateTime::try_from),
+            Ok(datetime)
+        );
+    }
+}
+
+#[test]
+fn offset() {
+    let fixed = FixedOffset::west_opt(5 * 3_600 + 30 * 60).expect("valid offset");
+    assert_eq!(FixedOffset::try_from(offset!(-5:30)), Ok(fixed));
+    assert_eq!(UtcOffset::from(fixed), offset!(-5:30));
+    assert_eq!(
+        FixedOffset::try_from(offset!(+24:00)),
+        Err(error::ConversionRange)
+    );
+}
+
+#[test]
+fn utc_date_time() {
+    let chrono = Utc
+        .with_ymd_and_hms(2024, 1, 2, 3, 4, 5)
+        .single()
+        .expect("valid datetime");
+    assert_eq!(
+        DateTime::<Utc>::try_from(utc_datetime!(2024-01-02 03:04:05)),
+        Ok(chrono)
+    );
+    assert_eq!(
+        UtcDateTime::try_from(chrono),
+        Ok(utc_datetime!(2024-01-02 03:04:05))
+    );
+    assert_eq!(
+        DateTime::<Utc>::try_from(UtcDateTime::MAX),
+        Err(error::ConversionRange)
+    );
+}
+
+#[test]
+fn offset_date_time() {
+    let fixed = FixedOffset::east_opt(3_600).expect("valid offset");
+    let chrono = fixed
+        .with_ymd_and_hms(2024, 1, 2, 3, 4, 5)
+        .single()
+        .expect("valid datetime");
+    let value = datetime!(2024-01-02 03:04:05 +1);
+
+    let converted = DateTime::<FixedOffset>::try_from(value).expect("in range");
+    assert_eq!(converted, chrono);
+    assert_eq!(converted.offset(), &fixed);
+    let converted = OffsetDateTime::try_from(chrono).expect("in range");
+    assert_eq!(converted, value);
+    assert_eq!(converted.offset(), offset!(+1));
+
+    assert_eq!(
+        DateTime::<Utc>::try_from(value),
+        Ok(Utc
+            .with_ymd_and_hms(2024, 1, 2, 2, 4, 5)
+            .single()
+            .expect("valid datetime"))
+    );
+    assert_eq!(
+        OffsetDateTime::try_from(chrono.with_timezone(&Utc)),
+        Ok(datetime!(2024-01-02 02:04:05 UTC))
+    );
+    assert_eq!(
+        DateTime::<FixedOffset>::try_from(datetime!(2024-01-02 03:04:05 +25)),
+        Err(error::ConversionRange)
+    );
+    assert_eq!(
+        DateTime::<Utc>::try_from(PrimitiveDateTime::MAX.assume_utc()),
+        Err(error::ConversionRange)
+    );
+}
+
+#[test]
+fn duration() {
+    assert_eq!(
+        TimeDelta::try_from(1.5.seconds()),
+        Ok(TimeDelta::milliseconds(1_500))
+    );
+    assert_eq!(
+        TimeDelta::try_from((-1.5).seconds()),
+        Ok(TimeDelta::milliseconds(-1_500))
+    );
+    assert_eq!(TimeDelta::try_from(Duration::ZERO), Ok(TimeDelta::zero()));
+    assert_eq!(Duration::from(TimeDelta::milliseconds(1_500)), 1.5.seconds());
+    assert_eq!(
+        Duration::from(TimeDelta::milliseconds(-1_500)),
+        (-1.5).seconds()
+    );
+    assert_eq!(
+        Duration::from(TimeDelta::nanoseconds(-1)),
+        (-1).nanoseconds()
+    );
+    assert_eq!(
+        TimeDelta::try_from(Duration::MAX),
+        Err(error::ConversionRange)
+    );
+    assert_eq!(
+        TimeDelta::try_from(Duration::MIN),
+        Err(error::ConversionRange)
+    );
+}
+
+#[test]
+fn weekday() {
+    for weekday in [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ] {
+        let chrono = ::chrono::Weekday::from(weekday);
+        assert_eq!(chrono.number_from_monday(), u32::from(weekday.number_from_monday()));
+        assert_eq!(Weekday::from(chrono), weekday);
+    }
+}
+
+#[test]
+fn month() {
+    let mut month = Month::January;
+    for _ in 0..12 {
+        let chrono = ::chrono::Month::from(month);
+        assert_eq!(chrono.number_from_month(), u32::from(u8::from(month)));
+        assert_eq!(Month::from(chrono), month);
+        month = month.next();
+    }
+}
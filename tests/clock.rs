@@ -0,0 +1,61 @@
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
+
+use time::clock::{self, Clock, FixedClock, SystemClock};
+use time::macros::{datetime, utc_datetime};
+use time::{OffsetDateTime, UtcDateTime};
+
+/// A clock that is frozen only for the thread that installed it, so that other tests running in
+/// parallel continue to observe the system clock.
+struct ThreadLocalClock {
+    thread: Mutex<Option<ThreadId>>,
+    frozen: FixedClock,
+}
+
+impl Clock for ThreadLocalClock {
+    fn now_utc(&self) -> UtcDateTime {
+        let thread = *self.thread.lock().expect("lock should not be poisoned");
+        if thread == Some(thread::current().id()) {
+            self.frozen.now_utc()
+        } else {
+            SystemClock.now_utc()
+        }
+    }
+}
+
+#[test]
+fn fixed_clock() {
+    let clock = FixedClock::new(utc_datetime!(2024-01-02 03:04:05));
+    assert_eq!(clock.now_utc(), utc_datetime!(2024-01-02 03:04:05));
+    assert_eq!(clock.now_utc(), clock.now_utc());
+}
+
+#[test]
+fn system_clock() {
+    assert!(SystemClock.now_utc().year() >= 2019);
+}
+
+#[test]
+fn global_clock() {
+    static CLOCK: ThreadLocalClock = ThreadLocalClock {
+        thread: Mutex::new(None),
+        frozen: FixedClock::new(utc_datetime!(2024-01-02 03:04:05)),
+    };
+    *CLOCK.thread.lock().expect("lock should not be poisoned") = Some(thread::current().id());
+
+    clock::set_global_clock(&CLOCK);
+    assert_eq!(UtcDateTime::now(), utc_datetime!(2024-01-02 03:04:05));
+    assert_eq!(
+        OffsetDateTime::now_utc(),
+        datetime!(2024-01-02 03:04:05 UTC)
+    );
+    assert!(
+        thread::spawn(UtcDateTime::now)
+            .join()
+            .expect("thread should not panic")
+            > utc_datetime!(2024-01-02 03:04:05)
+    );
+
+    clock::reset_global_clock();
+    assert!(UtcDateTime::now() > utc_datetime!(2024-01-02 03:04:05));
+}
@@ -0,0 +1,51 @@
+use time::compat_0_2::{self, Compat02};
+use time::macros::{date, datetime, offset, time};
+use time::{Date, Error, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+#[test]
+fn format() -> time::Result<()> {
+    assert_eq!(compat_0_2::format(&date!(2019-01-02), "%F")?, "2019-01-02");
+    assert_eq!(compat_0_2::format(&time!(13:04:05), "%-I:%M %p")?, "1:04 PM");
+    assert_eq!(
+        compat_0_2::format(&datetime!(2019-01-02 3:04:05), "%c")?,
+        "Wed Jan  2 03:04:05 2019"
+    );
+    assert_eq!(
+        compat_0_2::format(&datetime!(2019-01-02 3:04:05 -1:30), "%FT%T%z")?,
+        "2019-01-02T03:04:05-0130"
+    );
+    assert_eq!(offset!(+1).format_0_2("%z")?, "+0100");
+    Ok(())
+}
+
+#[test]
+fn parse() -> time::Result<()> {
+    assert_eq!(compat_0_2::parse::<Date>("2019-01-02", "%F")?, date!(2019-01-02));
+    assert_eq!(compat_0_2::parse::<Time>("13:04:05", "%T")?, time!(13:04:05));
+    assert_eq!(
+        compat_0_2::parse::<PrimitiveDateTime>("2019-01-02 03:04:05", "%F %T")?,
+        datetime!(2019-01-02 3:04:05)
+    );
+    assert_eq!(
+        compat_0_2::parse::<OffsetDateTime>("2019-01-02T03:04:05-0130", "%FT%T%z")?,
+        datetime!(2019-01-02 3:04:05 -1:30)
+    );
+    assert_eq!(UtcOffset::parse_0_2("+0100", "%z")?, offset!(+1));
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    assert!(matches!(
+        compat_0_2::format(&date!(2019-01-02), "%Q"),
+        Err(Error::InvalidFormatDescription(_))
+    ));
+    assert!(matches!(
+        compat_0_2::format(&date!(2019-01-02), "%T"),
+        Err(Error::Format(_))
+    ));
+    assert!(matches!(
+        compat_0_2::parse::<Date>("2019-01-02", "%T"),
+        Err(Error::ParseFromDescription(_))
+    ));
+}
@@ -19,4 +19,7 @@ fn main() {
     let _ = date!(2021-W 01-0a);
     let _ = date!(2021-0a);
     let _ = date!(2021-01-0a);
+    let _ = date!(2021-W 00-1);
+    let _ = date!(2021-W 53-1);
+    let _ = date!(2021-02-29);
 }
@@ -5,4 +5,5 @@ fn main() {
     let _ = datetime!(2021-001 24:00);
     let _ = datetime!(2021-001 0:00 0);
     let _ = datetime!(2021-001 0:00 UTC x);
+    let _ = datetime!(2021-001 0:00, nanosecond = 1 UTC);
 }
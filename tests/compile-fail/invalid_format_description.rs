@@ -43,4 +43,6 @@ fn main() {
 
     let _ = format_description!("[ignore]");
     let _ = format_description!("[ignore count:0]");
+
+    let _ = format_description!(concat!("[year]", b"-[month]"));
 }
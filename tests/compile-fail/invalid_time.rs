@@ -14,4 +14,7 @@ fn main() {
     let _ = time!(0);
     let _ = time!(0 pm);
     let _ = time!(1 am :);
+    let _ = time!(13 am);
+    let _ = time!(0:00:00.123_456_789_1);
+    let _ = time!(0:00:00.5e1);
 }
@@ -3,7 +3,7 @@ use std::collections::HashSet;
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, time};
-use time::{util, Date, Duration, Month, Weekday};
+use time::{util, CalendarDate, Date, Duration, IsoWeekDate, Month, Weekday};
 
 #[test]
 fn debug() {
@@ -488,6 +488,39 @@ fn from_iso_week_date() {
     assert_eq!(date!(-0001-W 52-6), date!(0000-01-01));
 }
 
+#[test]
+fn from_iso_week_date_range_edges() {
+    let (year, week, weekday) = Date::MAX.to_iso_week_date();
+    assert_eq!(Date::from_iso_week_date(year, week, weekday), Ok(Date::MAX));
+    assert!(Date::from_iso_week_date(year, week, weekday.next()).is_err());
+    assert!(Date::last_day_of_iso_week(year, week).is_err());
+
+    let (year, week, weekday) = Date::MIN.to_iso_week_date();
+    assert_eq!(Date::from_iso_week_date(year, week, weekday), Ok(Date::MIN));
+    if weekday != Weekday::Monday {
+        assert!(Date::from_iso_week_date(year, week, weekday.previous()).is_err());
+        assert!(Date::first_day_of_iso_week(year, week).is_err());
+    }
+}
+
+#[test]
+fn first_day_of_iso_week() {
+    assert_eq!(Date::first_day_of_iso_week(2019, 1), Ok(date!(2018-12-31)));
+    assert_eq!(Date::first_day_of_iso_week(2020, 53), Ok(date!(2020-12-28)));
+    assert_eq!(Date::first_day_of_iso_week(2021, 1), Ok(date!(2021-01-04)));
+    assert!(Date::first_day_of_iso_week(2019, 0).is_err());
+    assert!(Date::first_day_of_iso_week(2019, 53).is_err());
+}
+
+#[test]
+fn last_day_of_iso_week() {
+    assert_eq!(Date::last_day_of_iso_week(2019, 1), Ok(date!(2019-01-06)));
+    assert_eq!(Date::last_day_of_iso_week(2020, 53), Ok(date!(2021-01-03)));
+    assert_eq!(Date::last_day_of_iso_week(2021, 52), Ok(date!(2022-01-02)));
+    assert!(Date::last_day_of_iso_week(2019, 0).is_err());
+    assert!(Date::last_day_of_iso_week(2019, 53).is_err());
+}
+
 #[test]
 fn year() {
     assert_eq!(date!(2019-002).year(), 2019);
@@ -502,6 +535,14 @@ fn month() {
     assert_eq!(date!(2020-060).month(), Month::February);
 }
 
+#[test]
+fn month0() {
+    assert_eq!(date!(2019-01-01).month0(), 0);
+    assert_eq!(date!(2019-060).month0(), 2);
+    assert_eq!(date!(2020-060).month0(), 1);
+    assert_eq!(date!(2019-12-31).month0(), 11);
+}
+
 #[test]
 fn day() {
     assert_eq!(date!(2019-002).day(), 2);
@@ -510,6 +551,38 @@ fn day() {
     assert_eq!(date!(2020-060).day(), 29);
 }
 
+#[test]
+fn ordinal0() {
+    assert_eq!(date!(2019-001).ordinal0(), 0);
+    assert_eq!(date!(2019-365).ordinal0(), 364);
+    assert_eq!(date!(2020-366).ordinal0(), 365);
+}
+
+#[test]
+fn is_leap_year() {
+    assert!(date!(2020-001).is_leap_year());
+    assert!(date!(2000-001).is_leap_year());
+    assert!(date!(0000-001).is_leap_year());
+    assert!(!date!(2019-001).is_leap_year());
+    assert!(!date!(1900-001).is_leap_year());
+    assert!(!date!(-0001-001).is_leap_year());
+}
+
+#[test]
+fn from_calendar_date0() {
+    assert_eq!(Date::from_calendar_date0(2019, 0, 1), Ok(date!(2019-01-01)));
+    assert_eq!(Date::from_calendar_date0(2020, 1, 29), Ok(date!(2020-02-29)));
+    assert_eq!(Date::from_calendar_date0(2019, 11, 31), Ok(date!(2019-12-31)));
+    assert!(matches!(
+        Date::from_calendar_date0(2019, 12, 1),
+        Err(err) if err.name() == "month0"
+    ));
+    assert!(matches!(
+        Date::from_calendar_date0(2019, 1, 29),
+        Err(err) if err.name() == "day"
+    ));
+}
+
 #[test]
 fn iso_week() {
     assert_eq!(date!(2019-01-01).iso_week(), 1);
@@ -519,6 +592,58 @@ fn iso_week() {
     assert_eq!(date!(2021-01-01).iso_week(), 53);
 }
 
+#[test]
+fn weeks_in_year() {
+    assert_eq!(date!(2019-06-01).weeks_in_year(), 52);
+    assert_eq!(date!(2020-06-01).weeks_in_year(), 53);
+    assert_eq!(date!(2020-12-31).weeks_in_year(), 53);
+    assert_eq!(date!(2021-01-03).weeks_in_year(), 53);
+    assert_eq!(date!(2021-01-04).weeks_in_year(), 52);
+    assert_eq!(date!(2024-12-30).weeks_in_year(), 52);
+    assert_eq!(date!(2026-12-31).weeks_in_year(), 53);
+
+    const WEEKS: u8 = date!(2015-01-01).weeks_in_year();
+    assert_eq!(WEEKS, util::weeks_in_year(2015));
+}
+
+#[test]
+fn days_in_month() {
+    for (date, days) in [
+        (date!(2019-01-01), 31),
+        (date!(2019-02-28), 28),
+        (date!(2020-02-01), 29),
+        (date!(2100-02-01), 28),
+        (date!(2000-02-29), 29),
+        (date!(2019-04-30), 30),
+        (date!(2019-12-31), 31),
+    ] {
+        assert_eq!(date.days_in_month(), days);
+        assert_eq!(date.days_in_month(), util::days_in_month(date.month(), date.year()));
+    }
+
+    const DAYS: u8 = date!(2021-09-15).days_in_month();
+    assert_eq!(DAYS, 30);
+}
+
+#[test]
+fn as_ymd() {
+    assert_eq!(date!(2019-01-02).as_ymd(), (2019, 1, 2));
+    assert_eq!(date!(2019-12-31).as_ymd(), (2019, 12, 31));
+    assert_eq!(date!(-9999-01-01).as_ymd(), (-9999, 1, 1));
+}
+
+#[test]
+fn try_from_ymd() {
+    assert_eq!(Date::try_from((2019, 1, 2)), Ok(date!(2019-01-02)));
+    assert_eq!(Date::try_from((2020, 2, 29)), Ok(date!(2020-02-29)));
+    assert!(Date::try_from((2019, 0, 1)).is_err());
+    assert!(Date::try_from((2019, 13, 1)).is_err());
+    assert!(Date::try_from((2019, 2, 29)).is_err());
+    assert!(Date::try_from((1_000_000, 1, 1)).is_err());
+    let date = date!(2019-10-04);
+    assert_eq!(Date::try_from(date.as_ymd()), Ok(date));
+}
+
 #[test]
 fn to_calendar_date() {
     assert_eq!(
@@ -562,6 +687,48 @@ fn to_calendar_date() {
     );
 }
 
+#[test]
+fn calendar_date() {
+    let calendar_date = date!(2019-01-02).calendar_date();
+    assert_eq!(
+        calendar_date,
+        CalendarDate {
+            year: 2019,
+            month: Month::January,
+            day: 2,
+        }
+    );
+    assert_eq!(CalendarDate::from(date!(2019-01-02)), calendar_date);
+    assert_eq!(Date::try_from(calendar_date), Ok(date!(2019-01-02)));
+    assert!(Date::try_from(CalendarDate {
+        day: 32,
+        ..calendar_date
+    })
+    .is_err());
+
+    assert_eq!(calendar_date.to_string(), "2019-01-02");
+    assert_eq!(date!(-1-12-31).calendar_date().to_string(), "-0001-12-31");
+    assert_eq!(date!(+10_000-01-01).calendar_date().to_string(), "+10000-01-01");
+    assert_eq!(format!("{calendar_date:>12}"), "  2019-01-02");
+    assert_eq!(
+        format!("{:x^14}", date!(+10_000-01-01).calendar_date()),
+        "x+10000-01-01x"
+    );
+
+    assert_eq!(
+        date!(2019-02-01)
+            .calendar_date()
+            .cmp(&date!(2019-01-31).calendar_date()),
+        Ordering::Greater
+    );
+    assert_eq!(
+        date!(2018-12-31)
+            .calendar_date()
+            .cmp(&date!(2019-01-01).calendar_date()),
+        Ordering::Less
+    );
+}
+
 #[test]
 fn to_ordinal_date() {
     assert_eq!(date!(2019-01-01).to_ordinal_date(), (2019, 1));
@@ -578,6 +745,45 @@ fn to_iso_week_date() {
     assert_eq!(date!(0000-01-01).to_iso_week_date(), (-1, 52, Saturday));
 }
 
+#[test]
+fn iso_week_date() {
+    let iso_week_date = date!(2021-01-01).iso_week_date();
+    assert_eq!(
+        iso_week_date,
+        IsoWeekDate {
+            year: 2020,
+            week: 53,
+            weekday: Weekday::Friday,
+        }
+    );
+    assert_eq!(IsoWeekDate::from(date!(2021-01-01)), iso_week_date);
+    assert_eq!(Date::try_from(iso_week_date), Ok(date!(2021-01-01)));
+    assert!(Date::try_from(IsoWeekDate {
+        year: 2021,
+        ..iso_week_date
+    })
+    .is_err());
+
+    assert_eq!(iso_week_date.to_string(), "2020-W53-5");
+    assert_eq!(date!(2019-01-06).iso_week_date().to_string(), "2019-W01-7");
+    assert_eq!(format!("{iso_week_date:>12}"), "  2020-W53-5");
+    assert_eq!(format!("{iso_week_date:x<12}"), "2020-W53-5xx");
+
+    // Monday is the first day of the week.
+    assert_eq!(
+        date!(2019-01-07)
+            .iso_week_date()
+            .cmp(&date!(2019-01-06).iso_week_date()),
+        Ordering::Greater
+    );
+    assert_eq!(
+        date!(2019-01-06)
+            .iso_week_date()
+            .cmp(&date!(2019-01-05).iso_week_date()),
+        Ordering::Greater
+    );
+}
+
 #[test]
 fn weekday() {
     use Weekday::*;
@@ -640,6 +846,23 @@ fn from_julian_day() {
     assert!(Date::from_julian_day(i32::MAX).is_err());
 }
 
+#[test]
+fn all_in_year() -> time::Result<()> {
+    let dates = Date::all_in_year(2024)?.collect::<Vec<_>>();
+    assert_eq!(dates.len(), 366);
+    assert_eq!(dates[0], date!(2024-01-01));
+    assert_eq!(dates[59], date!(2024-02-29));
+    assert_eq!(dates[365], date!(2024-12-31));
+    assert!(dates.windows(2).all(|pair| pair[0].next_day() == Some(pair[1])));
+
+    assert_eq!(Date::all_in_year(2023)?.len(), 365);
+    assert_eq!(Date::all_in_year(2023)?.next_back(), Some(date!(2023-12-31)));
+    assert_eq!(Date::all_in_year(Date::MIN.year())?.next(), Some(Date::MIN));
+    assert_eq!(Date::all_in_year(Date::MAX.year())?.next_back(), Some(Date::MAX));
+    assert!(Date::all_in_year(Date::MAX.year() + 1).is_err());
+    Ok(())
+}
+
 #[test]
 fn midnight() {
     assert_eq!(date!(1970-01-01).midnight(), datetime!(1970-01-01 0:00));
@@ -949,6 +1172,33 @@ fn replace_year() {
     assert_eq!(date!(2024-12-01).replace_year(2028), Ok(date!(2028-12-01)));
 }
 
+#[test]
+fn with_year_clamped() {
+    assert_eq!(date!(2024-02-29).with_year_clamped(2023), Ok(date!(2023-02-28)));
+    assert_eq!(date!(2024-02-29).with_year_clamped(2000), Ok(date!(2000-02-29)));
+    assert_eq!(date!(2024-02-28).with_year_clamped(2023), Ok(date!(2023-02-28)));
+    assert_eq!(date!(2024-03-01).with_year_clamped(2023), Ok(date!(2023-03-01)));
+    assert_eq!(date!(2023-02-28).with_year_clamped(2024), Ok(date!(2024-02-28)));
+    assert_eq!(date!(2023-03-01).with_year_clamped(2024), Ok(date!(2024-03-01)));
+    assert!(date!(2024-02-29).with_year_clamped(-1_000_000_000).is_err());
+    assert!(date!(2024-02-29).with_year_clamped(1_000_000_000).is_err());
+}
+
+#[test]
+fn next_february_29() {
+    assert_eq!(date!(2023-01-01).next_february_29(), Some(date!(2024-02-29)));
+    assert_eq!(date!(2024-02-28).next_february_29(), Some(date!(2024-02-29)));
+    assert_eq!(date!(2024-02-29).next_february_29(), Some(date!(2028-02-29)));
+    assert_eq!(date!(2024-12-31).next_february_29(), Some(date!(2028-02-29)));
+    assert_eq!(date!(1896-03-01).next_february_29(), Some(date!(1904-02-29)));
+    assert_eq!(date!(1999-03-01).next_february_29(), Some(date!(2000-02-29)));
+    assert_eq!(Date::MAX.next_february_29(), None);
+    assert_eq!(
+        Date::MIN.next_february_29().map(Date::year),
+        util::next_leap_year(Date::MIN.year())
+    );
+}
+
 #[test]
 fn replace_month() {
     assert_eq!(
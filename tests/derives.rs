@@ -6,7 +6,7 @@ use time::error::{self, ConversionRange, IndeterminateOffset, TryFromParsed};
 use time::ext::NumericalDuration;
 use time::format_description::{self, modifier, well_known, Component, BorrowedFormatItem, OwnedFormatItem};
 use time::macros::{date, offset, time, utc_datetime, datetime};
-use time::parsing::Parsed;
+use time::parsing::{Parsed, TryInOrder};
 use time::{Duration, Error, Month, Time, Weekday};
 #[allow(deprecated)]
 use time::Instant;
@@ -25,6 +25,12 @@ fn invalid_format_description() -> error::InvalidFormatDescription {
     format_description::parse("[").expect_err("format description is invalid")
 }
 
+fn invalid_component() -> error::ParseFromDescription {
+    Parsed::new()
+        .parse_component(b"a", Component::Hour(modifier::Hour::default()))
+        .expect_err("a is not a valid hour")
+}
+
 #[allow(clippy::cognitive_complexity)] // all test the same thing
 #[test]
 fn clone() {
@@ -47,12 +53,20 @@ fn clone() {
     #[allow(clippy::clone_on_copy)] // needed for coverage
     let _ = Parsed::new().clone();
     assert_cloned_eq!(error::Parse::ParseFromDescription(
-        error::ParseFromDescription::InvalidComponent("foo")
+        invalid_component()
     ));
     assert_cloned_eq!(error::DifferentVariant);
     assert_cloned_eq!(error::InvalidVariant);
-    assert_cloned_eq!(error::ParseFromDescription::InvalidComponent("foo"));
+    assert_cloned_eq!(error::InvalidDuration::InvalidSyntax);
+    assert_cloned_eq!(error::InvalidLocalTime::Nonexistent);
+    assert_cloned_eq!(invalid_component());
     assert_cloned_eq!(Component::OffsetSecond(modifier::OffsetSecond::default()));
+    assert_cloned_eq!(well_known::CommonFormat::Rfc3339);
+    assert_cloned_eq!(TryInOrder((
+        well_known::CommonFormat::Rfc3339,
+        well_known::CommonFormat::IsoDate
+    )));
+    assert_cloned_eq!(well_known::HttpDate);
     assert_cloned_eq!(well_known::Rfc2822);
     assert_cloned_eq!(well_known::Rfc3339);
     assert_cloned_eq!(well_known::Iso8601::DEFAULT);
@@ -103,6 +117,12 @@ fn hash() {
     Instant::now().hash(&mut hasher);
     Duration::ZERO.hash(&mut hasher);
     component_range_error().hash(&mut hasher);
+    well_known::CommonFormat::Rfc3339.hash(&mut hasher);
+    TryInOrder((
+        well_known::CommonFormat::Rfc3339,
+        well_known::CommonFormat::IsoDate,
+    ))
+    .hash(&mut hasher);
 }
 
 #[test]
@@ -155,8 +175,11 @@ fn debug() {
         Parsed::new();
         #[allow(deprecated)]
         Instant::now();
-        error::ParseFromDescription::InvalidComponent("foo");
+        invalid_component();
         error::Format::InvalidComponent("foo");
+        well_known::CommonFormat::Rfc3339;
+        TryInOrder((well_known::CommonFormat::Rfc3339, well_known::CommonFormat::IsoDate));
+        well_known::HttpDate;
         well_known::Rfc2822;
         well_known::Rfc3339;
         well_known::Iso8601::DEFAULT;
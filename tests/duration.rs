@@ -4,7 +4,7 @@ use std::time::Duration as StdDuration;
 
 use rstest::rstest;
 use time::ext::{NumericalDuration, NumericalStdDuration};
-use time::{error, Duration};
+use time::{error, Duration, HumanDuration, Iso8601Duration, Iso8601SignPlacement, Rounding};
 
 #[rstest]
 #[case(Duration::ZERO, 0.seconds())]
@@ -270,6 +270,28 @@ fn checked_seconds_f64_edge_cases(#[case] seconds: f64) {
     assert_eq!(Duration::checked_seconds_f64(seconds), None);
 }
 
+#[rstest]
+#[case(0.5, 0.5)]
+#[case(-0.5, -0.5)]
+#[case(123.250, 123.250)]
+#[case(0.000_000_000_012, 0.)]
+fn try_seconds_f64_success(#[case] seconds: f64, #[case] expected: f64) {
+    assert_eq!(Duration::try_seconds_f64(seconds), Ok(expected.seconds()));
+}
+
+#[rstest]
+#[case(f64::MAX)]
+#[case(f64::MIN)]
+#[case(f64::INFINITY)]
+#[case(f64::NEG_INFINITY)]
+#[case(f64::NAN)]
+fn try_seconds_f64_error(#[case] seconds: f64) {
+    assert_eq!(
+        Duration::try_seconds_f64(seconds),
+        Err(error::ConversionRange)
+    );
+}
+
 #[rstest]
 #[case(1.)]
 #[case(-1.)]
@@ -331,6 +353,28 @@ fn checked_seconds_f32_none(#[case] seconds: f32) {
     assert_eq!(Duration::checked_seconds_f32(seconds), None);
 }
 
+#[rstest]
+#[case(0.5, 0.5)]
+#[case(-0.5, -0.5)]
+#[case(123.250, 123.250)]
+#[case(0.000_000_000_012, 0.0)]
+fn try_seconds_f32_success(#[case] seconds: f32, #[case] expected: f64) {
+    assert_eq!(Duration::try_seconds_f32(seconds), Ok(expected.seconds()));
+}
+
+#[rstest]
+#[case(f32::MAX)]
+#[case(f32::MIN)]
+#[case(f32::INFINITY)]
+#[case(f32::NEG_INFINITY)]
+#[case(f32::NAN)]
+fn try_seconds_f32_error(#[case] seconds: f32) {
+    assert_eq!(
+        Duration::try_seconds_f32(seconds),
+        Err(error::ConversionRange)
+    );
+}
+
 #[rstest]
 #[case(1.0, 1.0)]
 #[case(-1.0, -1.0)]
@@ -610,6 +654,288 @@ fn display_precision(#[case] duration: Duration, #[case] precision: usize, #[cas
     assert_eq!(format!("{duration:.precision$}"), expected);
 }
 
+#[rstest]
+#[case(0.seconds(), "0 seconds")]
+#[case(1.days(), "1 day")]
+#[case((-48).hours(), "-2 days")]
+#[case(1.hours(), "1 hour")]
+#[case(1.minutes(), "1 minute")]
+#[case(10.seconds(), "10 seconds")]
+#[case(1.milliseconds(), "1 millisecond")]
+#[case(10.microseconds(), "10 microseconds")]
+#[case(1.nanoseconds(), "1 nanosecond")]
+#[case(
+    93_784_005_006_007.nanoseconds(),
+    "1 day 2 hours 3 minutes 4 seconds 5 milliseconds 6 microseconds 7 nanoseconds"
+)]
+#[case(3_661.seconds(), "1 hour 1 minute 1 second")]
+fn display_alternate(#[case] duration: Duration, #[case] expected: &str) {
+    assert_eq!(format!("{duration:#}"), expected);
+}
+
+#[rstest]
+#[case(0.seconds(), 3, "0.000 seconds")]
+#[case((-48).hours(), 3, "-2.000 days")]
+#[case(1.minutes(), 0, "1 minute")]
+#[case(1.minutes(), 1, "1.0 minute")]
+#[case(62.minutes(), 1, "1.0 hour")]
+#[case(66.minutes(), 1, "1.1 hours")]
+#[case(90.seconds(), 0, "2 minutes")]
+#[case(1_500.milliseconds(), 1, "1.5 seconds")]
+#[case(100.nanoseconds(), 0, "100 nanoseconds")]
+#[case(26.hours(), 3, "1.083 days")]
+fn display_alternate_precision(
+    #[case] duration: Duration,
+    #[case] precision: usize,
+    #[case] expected: &str,
+) {
+    assert_eq!(format!("{duration:#.precision$}"), expected);
+}
+
+#[rstest]
+#[case(format!("{:>8}", 90.minutes()), "   1h30m")]
+#[case(format!("{:<8}", (-90).minutes()), "-1h30m  ")]
+#[case(format!("{:x^9}", 90.minutes()), "xx1h30mxx")]
+#[case(format!("{:>8.2}", 90.minutes()), "   1.50h")]
+#[case(format!("{:>#12}", 1.5.seconds()), "1 second 500 milliseconds")]
+#[case(format!("{:>#12.1}", 1.5.seconds()), " 1.5 seconds")]
+#[case(format!("{:>4}", 0.seconds()), "  0s")]
+#[case(format!("{:>8}", 90.minutes().human()), "  1h 30m")]
+#[case(format!("{:>9}", 90.minutes().iso8601()), "  PT1H30M")]
+#[case(format!("{:>8.3}", 1.5.seconds().display_seconds()), "   1.500")]
+fn display_padding(#[case] formatted: String, #[case] expected: &str) {
+    assert_eq!(formatted, expected);
+}
+
+#[rstest]
+#[case(0.seconds(), None, "0")]
+#[case(0.seconds(), Some(3), "0.000")]
+#[case(90.seconds(), None, "90")]
+#[case(1.5.seconds(), None, "1.5")]
+#[case(1.5.seconds(), Some(0), "2")]
+#[case((-1.5).seconds(), Some(0), "-2")]
+#[case(1.days() + 1.nanoseconds(), None, "86400.000000001")]
+#[case(1_234_567.microseconds(), Some(3), "1.235")]
+#[case(1_234_499.microseconds(), Some(3), "1.234")]
+#[case((-1_234_567).microseconds(), Some(3), "-1.235")]
+#[case(999_999_999.nanoseconds(), Some(3), "1.000")]
+#[case(123.nanoseconds(), Some(9), "0.000000123")]
+#[case(123.nanoseconds(), Some(11), "0.00000012300")]
+#[case((-1).nanoseconds(), None, "-0.000000001")]
+#[case(Duration::MIN, None, "-9223372036854775808.999999999")]
+#[case(Duration::MAX, Some(0), "9223372036854775808")]
+fn display_seconds(
+    #[case] duration: Duration,
+    #[case] precision: Option<usize>,
+    #[case] expected: &str,
+) {
+    let formatted = match precision {
+        Some(precision) => format!("{:.precision$}", duration.display_seconds()),
+        None => duration.display_seconds().to_string(),
+    };
+    assert_eq!(formatted, expected);
+    assert_eq!(Duration::from(duration.display_seconds()), duration);
+}
+
+#[rstest]
+#[case(0.seconds(), "0s")]
+#[case(1.days(), "1d")]
+#[case((-48).hours(), "-2d")]
+#[case(90.minutes(), "1h 30m")]
+#[case(1.5.seconds(), "1.5s")]
+#[case(2.days() + 3.hours() + 45.minutes() + 12.5.seconds(), "2d 3h 45m 12.5s")]
+#[case(1.days() + 1.nanoseconds(), "1d 0.000000001s")]
+#[case(1.5.milliseconds(), "1.5ms")]
+#[case((-250).microseconds(), "-250µs")]
+#[case(1_001.nanoseconds(), "1.001µs")]
+#[case(7.nanoseconds(), "7ns")]
+#[case(Duration::MIN, "-106751991167300d 15h 30m 8.999999999s")]
+fn human_display(#[case] duration: Duration, #[case] expected: &str) {
+    assert_eq!(duration.human().to_string(), expected);
+    assert_eq!(
+        Duration::from(expected.parse::<HumanDuration>().expect("valid duration")),
+        duration
+    );
+}
+
+#[rstest]
+#[case("0s", 0.seconds())]
+#[case("-0s", 0.seconds())]
+#[case("+1m", 1.minutes())]
+#[case("1h30m", 90.minutes())]
+#[case("1d2h3m4s5ms6us7ns", 93_784_005_006_007.nanoseconds())]
+#[case("1d2h3m4s5ms6µs7ns", 93_784_005_006_007.nanoseconds())]
+#[case("  2d   3h ", 2.days() + 3.hours())]
+#[case("1.5d", 36.hours())]
+#[case("0.1234567899s", 123_456_789.nanoseconds())]
+#[case("30s 30s", 1.minutes())]
+#[case("-1.5h", (-90).minutes())]
+fn human_from_str(#[case] input: &str, #[case] expected: Duration) {
+    assert_eq!(
+        input.parse::<HumanDuration>().map(Duration::from),
+        Ok(expected)
+    );
+}
+
+#[rstest]
+#[case("", error::InvalidDuration::InvalidSyntax)]
+#[case("-", error::InvalidDuration::InvalidSyntax)]
+#[case("5", error::InvalidDuration::InvalidSyntax)]
+#[case("5x", error::InvalidDuration::InvalidSyntax)]
+#[case("s", error::InvalidDuration::InvalidSyntax)]
+#[case(".5s", error::InvalidDuration::InvalidSyntax)]
+#[case("5.s", error::InvalidDuration::InvalidSyntax)]
+#[case("5 s", error::InvalidDuration::InvalidSyntax)]
+#[case("--5s", error::InvalidDuration::InvalidSyntax)]
+#[case("1h -5s", error::InvalidDuration::InvalidSyntax)]
+#[case("106751991167301d", error::InvalidDuration::OutOfRange)]
+#[case(
+    "99999999999999999999999999999999999999999d",
+    error::InvalidDuration::OutOfRange
+)]
+fn human_from_str_err(#[case] input: &str, #[case] expected: error::InvalidDuration) {
+    assert_eq!(input.parse::<HumanDuration>(), Err(expected));
+}
+
+#[rstest]
+#[case(0.seconds(), "PT0S", "PT0S", "P0DT0H0M0S")]
+#[case(90.minutes(), "PT1H30M", "PT1H30M", "P0DT1H30M0S")]
+#[case(2.days(), "P2D", "P2D", "P2DT0H0M0S")]
+#[case(
+    2.days() + 3.hours() + 45.minutes() + 12.5.seconds(),
+    "P2DT3H45M12.5S",
+    "P2DT3H45M12.5S",
+    "P2DT3H45M12.5S"
+)]
+#[case((-5).minutes(), "-PT5M", "PT-5M", "-P0DT0H5M0S")]
+#[case((-0.5).seconds(), "-PT0.5S", "PT-0.5S", "-P0DT0H0M0.5S")]
+#[case(
+    -(1.days() + 1.nanoseconds()),
+    "-P1DT0.000000001S",
+    "P-1DT-0.000000001S",
+    "-P1DT0H0M0.000000001S"
+)]
+#[case(
+    Duration::MIN,
+    "-P106751991167300DT15H30M8.999999999S",
+    "P-106751991167300DT-15H-30M-8.999999999S",
+    "-P106751991167300DT15H30M8.999999999S"
+)]
+fn iso8601_display(
+    #[case] duration: Duration,
+    #[case] leading: &str,
+    #[case] components: &str,
+    #[case] zero_components: &str,
+) {
+    assert_eq!(duration.iso8601().to_string(), leading);
+    assert_eq!(
+        duration
+            .iso8601()
+            .set_sign_placement(Iso8601SignPlacement::Components)
+            .to_string(),
+        components
+    );
+    assert_eq!(
+        duration.iso8601().set_zero_components(true).to_string(),
+        zero_components
+    );
+    for expected in [leading, components, zero_components] {
+        assert_eq!(
+            Duration::from(expected.parse::<Iso8601Duration>().expect("valid duration")),
+            duration
+        );
+    }
+}
+
+#[rstest]
+#[case("PT0S", 0.seconds())]
+#[case("+PT1M", 1.minutes())]
+#[case("pt1h30m", 90.minutes())]
+#[case("P1DT2H3M4.005006007S", 93_784_005_006_007.nanoseconds())]
+#[case("PT0,25S", 250.milliseconds())]
+#[case("PT0.1234567899S", 123_456_789.nanoseconds())]
+#[case("-PT6H3M", (-6).hours() - 3.minutes())]
+#[case("PT-6H+3M", (-6).hours() + 3.minutes())]
+#[case("-PT-6H+3M", 6.hours() - 3.minutes())]
+#[case("P-1D", (-1).days())]
+fn iso8601_from_str(#[case] input: &str, #[case] expected: Duration) {
+    assert_eq!(
+        input.parse::<Iso8601Duration>().map(Duration::from),
+        Ok(expected)
+    );
+}
+
+#[rstest]
+#[case("", error::InvalidDuration::InvalidSyntax)]
+#[case("P", error::InvalidDuration::InvalidSyntax)]
+#[case("PT", error::InvalidDuration::InvalidSyntax)]
+#[case("P1DT", error::InvalidDuration::InvalidSyntax)]
+#[case("T5M", error::InvalidDuration::InvalidSyntax)]
+#[case("P5M", error::InvalidDuration::InvalidSyntax)]
+#[case("PT5D", error::InvalidDuration::InvalidSyntax)]
+#[case("PT5M1H", error::InvalidDuration::InvalidSyntax)]
+#[case("PT5M5M", error::InvalidDuration::InvalidSyntax)]
+#[case("PTT5M", error::InvalidDuration::InvalidSyntax)]
+#[case("PT1.5M", error::InvalidDuration::InvalidSyntax)]
+#[case("PT1.S", error::InvalidDuration::InvalidSyntax)]
+#[case("PT--5M", error::InvalidDuration::InvalidSyntax)]
+#[case("PT5", error::InvalidDuration::InvalidSyntax)]
+#[case("P1Y", error::InvalidDuration::InvalidSyntax)]
+#[case("P106751991167301D", error::InvalidDuration::OutOfRange)]
+#[case(
+    "P99999999999999999999999999999999999999999D",
+    error::InvalidDuration::OutOfRange
+)]
+fn iso8601_from_str_err(#[case] input: &str, #[case] expected: error::InvalidDuration) {
+    assert_eq!(input.parse::<Iso8601Duration>(), Err(expected));
+}
+
+#[rstest]
+fn backoff() {
+    let delays = |backoff: time::Backoff| backoff.take(6).collect::<Vec<_>>();
+
+    assert_eq!(
+        delays(1.seconds().backoff()),
+        [1, 2, 4, 8, 16, 32].map(|n| n.seconds())
+    );
+    assert_eq!(
+        delays(1.seconds().backoff().max(10.seconds())),
+        [1, 2, 4, 8, 10, 10].map(|n| n.seconds())
+    );
+    assert_eq!(
+        delays(10.milliseconds().backoff().factor(10.).max(30.seconds())),
+        [10, 100, 1_000, 10_000, 30_000, 30_000].map(|n| n.milliseconds())
+    );
+    assert_eq!(
+        delays(1.minutes().backoff().max(30.seconds())),
+        [30.seconds(); 6]
+    );
+    assert_eq!(
+        delays(1.seconds().backoff().factor(1.)),
+        [1.seconds(); 6]
+    );
+    assert_eq!(
+        delays(Duration::MAX.backoff()),
+        [Duration::MAX; 6]
+    );
+    assert_eq!(
+        Duration::ZERO.backoff().size_hint(),
+        (usize::MAX, None)
+    );
+}
+
+#[rstest]
+#[should_panic]
+fn backoff_factor_negative() {
+    let _ = 1.seconds().backoff().factor(-1.);
+}
+
+#[rstest]
+#[should_panic]
+fn backoff_factor_nan() {
+    let _ = 1.seconds().backoff().factor(f64::NAN);
+}
+
 #[rstest]
 #[case(0.std_seconds(), 0.seconds())]
 #[case(1.std_seconds(), 1.seconds())]
@@ -640,6 +966,27 @@ fn try_to_std_duration_error(#[case] duration: Duration) {
     assert_eq!(StdDuration::try_from(duration), Err(error::ConversionRange));
 }
 
+#[rstest]
+#[case(0.seconds(), Some(0.std_seconds()))]
+#[case(1.5.seconds(), Some(1_500.std_milliseconds()))]
+#[case(Duration::MAX, Some(StdDuration::new(i64::MAX as u64, 999_999_999)))]
+#[case((-1).nanoseconds(), None)]
+#[case((-1).seconds(), None)]
+#[case(Duration::MIN, None)]
+fn as_std_checked(#[case] duration: Duration, #[case] expected: Option<StdDuration>) {
+    assert_eq!(duration.as_std_checked(), expected);
+    assert_eq!(StdDuration::try_from(duration).ok(), expected);
+}
+
+#[rstest]
+#[case(Duration::ZERO)]
+#[case(1.5.seconds())]
+#[case(Duration::MAX)]
+fn std_duration_round_trip(#[case] duration: Duration) {
+    let std_duration = StdDuration::try_from(duration).expect("duration is non-negative");
+    assert_eq!(Duration::try_from(std_duration), Ok(duration));
+}
+
 #[rstest]
 #[case(1.seconds(), 1.seconds(), 2.seconds())]
 #[case(500.milliseconds(), 500.milliseconds(), 1.seconds())]
@@ -1048,3 +1395,154 @@ fn sum_iter() {
     let sum = i.into_iter().sum::<Duration>();
     assert_eq!(sum, 3.2.seconds());
 }
+
+#[rstest]
+#[case(5.seconds(), 1.5, 7_500.milliseconds())]
+#[case(5.seconds(), -0.5, (-2_500).milliseconds())]
+#[case(5.seconds(), 0., Duration::ZERO)]
+fn checked_mul_f64_some(#[case] duration: Duration, #[case] rhs: f64, #[case] expected: Duration) {
+    assert_eq!(duration.checked_mul_f64(rhs), Some(expected));
+}
+
+#[rstest]
+#[case(5.seconds(), f64::NAN)]
+#[case(5.seconds(), f64::INFINITY)]
+#[case(Duration::MAX, 2.)]
+#[case(Duration::MIN, 2.)]
+fn checked_mul_f64_none(#[case] duration: Duration, #[case] rhs: f64) {
+    assert_eq!(duration.checked_mul_f64(rhs), None);
+}
+
+#[rstest]
+#[case(Rounding::Floor, 2, 3, -3, -4)]
+#[case(Rounding::Ceiling, 3, 4, -2, -3)]
+#[case(Rounding::TowardZero, 2, 3, -2, -3)]
+#[case(Rounding::AwayFromZero, 3, 4, -3, -4)]
+#[case(Rounding::HalfAwayFromZero, 3, 4, -3, -4)]
+#[case(Rounding::HalfEven, 2, 4, -2, -4)]
+fn mul_f64_with(
+    #[case] rounding: Rounding,
+    #[case] five_halves: i64,
+    #[case] seven_halves: i64,
+    #[case] neg_five_halves: i64,
+    #[case] neg_seven_halves: i64,
+) {
+    assert_eq!(
+        5.nanoseconds().mul_f64_with(0.5, rounding),
+        five_halves.nanoseconds()
+    );
+    assert_eq!(
+        7.nanoseconds().mul_f64_with(0.5, rounding),
+        seven_halves.nanoseconds()
+    );
+    assert_eq!(
+        (-5).nanoseconds().mul_f64_with(0.5, rounding),
+        neg_five_halves.nanoseconds()
+    );
+    assert_eq!(
+        7.nanoseconds().mul_f64_with(-0.5, rounding),
+        neg_seven_halves.nanoseconds()
+    );
+    assert_eq!(
+        1.seconds().mul_f64_with(1.5, rounding),
+        1_500.milliseconds()
+    );
+}
+
+#[rstest]
+fn mul_f64_with_nearest() {
+    assert_eq!(
+        10.nanoseconds().mul_f64_with(0.26, Rounding::HalfEven),
+        3.nanoseconds()
+    );
+    assert_eq!(
+        10.nanoseconds().mul_f64_with(0.24, Rounding::HalfAwayFromZero),
+        2.nanoseconds()
+    );
+    assert_eq!(
+        Duration::MIN.mul_f64_with(0.0, Rounding::Floor),
+        Duration::ZERO
+    );
+}
+
+#[rstest]
+#[should_panic]
+fn mul_f64_with_nan() {
+    let _ = 1.seconds().mul_f64_with(f64::NAN, Rounding::HalfEven);
+}
+
+#[rstest]
+#[should_panic]
+fn mul_f64_with_overflow() {
+    let _ = Duration::MAX.mul_f64_with(2., Rounding::HalfEven);
+}
+
+#[rstest]
+#[case(Rounding::Floor, 3.nanoseconds())]
+#[case(Rounding::Ceiling, 4.nanoseconds())]
+#[case(Rounding::HalfEven, 3.nanoseconds())]
+fn div_f64_with(#[case] rounding: Rounding, #[case] expected: Duration) {
+    assert_eq!(10.nanoseconds().div_f64_with(3., rounding), expected);
+    assert_eq!((-10).nanoseconds().div_f64_with(-3., rounding), expected);
+}
+
+#[rstest]
+#[should_panic]
+fn div_f64_with_zero() {
+    let _ = 1.seconds().div_f64_with(0., Rounding::HalfEven);
+}
+
+#[rstest]
+#[case(3.seconds(), 2.seconds(), 1.5)]
+#[case(1.seconds(), (-4).seconds(), -0.25)]
+#[case(1.nanoseconds(), 1.seconds(), 1e-9)]
+fn div_duration_f64(#[case] a: Duration, #[case] b: Duration, #[case] expected: f64) {
+    assert_eq!(a.div_duration_f64(b), expected);
+}
+
+#[rstest]
+fn const_arithmetic() {
+    macro_rules! const_unwrap {
+        ($e:expr) => {
+            match $e {
+                Some(value) => value,
+                None => panic!("overflow"),
+            }
+        };
+    }
+
+    const TIMEOUTS: [Duration; 4] = [
+        Duration::seconds(5),
+        const_unwrap!(Duration::seconds(5).checked_mul(2)),
+        const_unwrap!(Duration::MINUTE.checked_add(Duration::seconds(30))),
+        const_unwrap!(Duration::MINUTE.checked_sub(Duration::seconds(90))).abs(),
+    ];
+    const OVERFLOW: Option<Duration> = Duration::MAX.checked_add(Duration::NANOSECOND);
+    const SATURATED: Duration = Duration::MIN.saturating_sub(Duration::SECOND);
+    const NEGATED: Option<Duration> = Duration::seconds(-3).checked_neg();
+    const HALF: Option<Duration> = Duration::SECOND.checked_div(2);
+    const SCALED: Duration = Duration::hours(1).saturating_mul(-24);
+    const STD: Duration = Duration::milliseconds(-1_500);
+    const UNSIGNED: StdDuration = STD.unsigned_abs();
+    const AS_STD: Option<StdDuration> = STD.as_std_checked();
+    const PARTS: (i64, i32, i128) = (
+        STD.whole_seconds(),
+        STD.subsec_nanoseconds(),
+        STD.whole_milliseconds(),
+    );
+    const SIGN: (bool, bool, bool) = (STD.is_negative(), STD.is_positive(), STD.is_zero());
+
+    assert_eq!(
+        TIMEOUTS,
+        [5.seconds(), 10.seconds(), 90.seconds(), 30.seconds()]
+    );
+    assert_eq!(OVERFLOW, None);
+    assert_eq!(SATURATED, Duration::MIN);
+    assert_eq!(NEGATED, Some(3.seconds()));
+    assert_eq!(HALF, Some(500.milliseconds()));
+    assert_eq!(SCALED, (-1).days());
+    assert_eq!(UNSIGNED, 1_500.std_milliseconds());
+    assert_eq!(AS_STD, None);
+    assert_eq!(PARTS, (-1, -500_000_000, -1_500));
+    assert_eq!(SIGN, (true, false, false));
+}
@@ -2,12 +2,13 @@ use std::error::Error as _;
 use std::io;
 
 use time::error::{
-    ComponentRange, ConversionRange, DifferentVariant, Error, Format, IndeterminateOffset,
-    InvalidFormatDescription, InvalidVariant, Parse, ParseFromDescription, TryFromParsed,
+    ComponentRange, ComponentRanges, ConversionRange, DifferentVariant, Error, Expected, Format,
+    IndeterminateOffset, InvalidDuration, InvalidFormatDescription, InvalidLocalTime,
+    InvalidOffset, InvalidSchedule, InvalidVariant, Parse, ParseFromDescription, TryFromParsed,
 };
-use time::macros::format_description;
-use time::parsing::Parsed;
-use time::{format_description, Date, Time};
+use time::macros::{date, format_description};
+use time::parsing::{Parsed, WeekdayMode};
+use time::{format_description, Date, Month, Time, Weekday};
 
 macro_rules! assert_display_eq {
     ($a:expr, $b:expr $(,)?) => {
@@ -34,6 +35,11 @@ fn component_range() -> ComponentRange {
     Date::from_ordinal_date(0, 367).expect_err("367 is not a valid day")
 }
 
+fn component_ranges() -> ComponentRanges {
+    time::PrimitiveDateTime::try_from((2021, 2, 30, 24, 0, 0, 0))
+        .expect_err("day and hour are invalid")
+}
+
 fn insufficient_type_information() -> Format {
     Time::MIDNIGHT
         .format(&format_description::well_known::Rfc3339)
@@ -52,13 +58,29 @@ fn io_error() -> io::Error {
     io::Error::last_os_error()
 }
 
+fn invalid_component() -> ParseFromDescription {
+    Parsed::new()
+        .parse_items(b"a", format_description!("[hour]"))
+        .expect_err("should fail to parse")
+}
+
 fn invalid_literal() -> ParseFromDescription {
     Parsed::parse_literal(b"a", b"b").expect_err("should fail to parse")
 }
 
+fn weekday_mismatch() -> TryFromParsed {
+    let err = Date::parse_with_weekday_mode(
+        "Sun 2024-001",
+        format_description!("[weekday repr:short] [year]-[ordinal]"),
+        WeekdayMode::Validate,
+    )
+    .expect_err("2024-001 is a Monday");
+    TryFromParsed::try_from(err).expect("error is from converting the parsed value")
+}
+
 #[test]
 fn debug() {
-    assert_dbg_reflexive!(Parse::from(ParseFromDescription::InvalidComponent("a")));
+    assert_dbg_reflexive!(Parse::from(invalid_component()));
     assert_dbg_reflexive!(invalid_format_description());
     assert_dbg_reflexive!(DifferentVariant);
     assert_dbg_reflexive!(InvalidVariant);
@@ -83,8 +105,8 @@ fn display() {
         Error::from(Format::InvalidComponent("a"))
     );
     assert_display_eq!(
-        ParseFromDescription::InvalidComponent("a"),
-        Error::from(Parse::from(ParseFromDescription::InvalidComponent("a")))
+        invalid_component(),
+        Error::from(Parse::from(invalid_component()))
     );
     assert_display_eq!(invalid_literal(), Parse::from(invalid_literal()));
     assert_display_eq!(
@@ -92,8 +114,8 @@ fn display() {
         Error::from(Parse::from(TryFromParsed::from(component_range())))
     );
     assert_display_eq!(
-        ParseFromDescription::InvalidComponent("a"),
-        Parse::from(ParseFromDescription::InvalidComponent("a"))
+        invalid_component(),
+        Parse::from(invalid_component())
     );
     assert_display_eq!(
         component_range(),
@@ -110,14 +132,49 @@ fn display() {
     assert_display_eq!(io_error(), Format::from(io_error()));
     assert_display_eq!(DifferentVariant, Error::from(DifferentVariant));
     assert_display_eq!(InvalidVariant, Error::from(InvalidVariant));
+    assert_display_eq!(
+        InvalidDuration::InvalidSyntax,
+        Error::from(InvalidDuration::InvalidSyntax)
+    );
+    assert_display_eq!(
+        InvalidDuration::OutOfRange,
+        Error::from(InvalidDuration::OutOfRange)
+    );
+    assert_display_eq!(
+        InvalidLocalTime::Nonexistent,
+        Error::from(InvalidLocalTime::Nonexistent)
+    );
+    assert_display_eq!(
+        InvalidLocalTime::Ambiguous,
+        Error::from(InvalidLocalTime::Ambiguous)
+    );
+    assert_display_eq!(
+        InvalidOffset::InvalidSyntax,
+        Error::from(InvalidOffset::InvalidSyntax)
+    );
+    assert_display_eq!(
+        component_range(),
+        InvalidOffset::from(component_range())
+    );
+    assert_display_eq!(
+        InvalidSchedule::InvalidFieldCount,
+        Error::from(InvalidSchedule::InvalidFieldCount)
+    );
+    assert_display_eq!(
+        InvalidSchedule::UnknownNickname,
+        Error::from(InvalidSchedule::UnknownNickname)
+    );
 }
 
 #[test]
 fn source() {
     assert_source!(Error::from(ConversionRange), ConversionRange);
     assert_source!(Error::from(component_range()), ComponentRange);
+    assert_source!(Error::from(component_ranges()), ComponentRanges);
+    assert_source!(component_ranges(), ComponentRange);
     assert_source!(TryFromParsed::from(component_range()), ComponentRange);
     assert_source!(TryFromParsed::InsufficientInformation, None);
+    assert_source!(weekday_mismatch(), None);
     assert_source!(insufficient_type_information(), None);
     assert_source!(Format::InvalidComponent("a"), None);
     assert_source!(Error::from(insufficient_type_information()), Format);
@@ -131,11 +188,11 @@ fn source() {
         TryFromParsed
     );
     assert_source!(
-        Parse::from(ParseFromDescription::InvalidComponent("a")),
+        Parse::from(invalid_component()),
         ParseFromDescription
     );
     assert_source!(
-        Error::from(ParseFromDescription::InvalidComponent("a")),
+        Error::from(invalid_component()),
         ParseFromDescription
     );
     assert_source!(unexpected_trailing_characters(), ParseFromDescription);
@@ -150,17 +207,100 @@ fn source() {
     assert_source!(Format::from(io_error()), io::Error);
     assert_source!(Error::from(DifferentVariant), DifferentVariant);
     assert_source!(Error::from(InvalidVariant), InvalidVariant);
+    assert_source!(Error::from(InvalidDuration::InvalidSyntax), InvalidDuration);
+    assert_source!(Error::from(InvalidLocalTime::Ambiguous), InvalidLocalTime);
+    assert_source!(Error::from(InvalidOffset::InvalidSyntax), InvalidOffset);
+    assert_source!(InvalidOffset::InvalidSyntax, None);
+    assert_source!(InvalidOffset::from(component_range()), ComponentRange);
+    assert_source!(Error::from(InvalidSchedule::InvalidFieldCount), InvalidSchedule);
+    assert_source!(InvalidSchedule::InvalidFieldCount, None);
 }
 
 #[test]
 fn component_name() {
     assert_eq!(component_range().name(), "ordinal");
+    assert_eq!(
+        Parse::from(weekday_mismatch()).component_name(),
+        Some("weekday")
+    );
+}
+
+#[test]
+fn expected() {
+    assert_eq!(Parse::from(weekday_mismatch()).expected(), None);
+    assert_display_eq!(Expected::UnspecifiedLiteral, "a literal");
+    assert_display_eq!(Expected::Component("month"), "the 'month' component");
+    assert_display_eq!(Expected::EndOfInput, "the end of input");
+}
+
+#[test]
+fn weekday_mismatch_display() {
+    assert_eq!(
+        weekday_mismatch().to_string(),
+        "the parsed weekday (Sunday) does not match the date, which is a Monday"
+    );
+    assert_display_eq!(weekday_mismatch(), Error::from(weekday_mismatch()));
+}
+
+#[test]
+fn component_range_conditional() {
+    let err = Date::from_calendar_date(2021, Month::April, 31).expect_err("April has 30 days");
+    assert!(err.is_conditional());
+    assert_eq!(err.conditional_year(), Some(2021));
+    assert_eq!(err.conditional_month(), Some(Month::April));
+    assert_eq!(err.to_string(), "day must be in the range 1..=30 for April 2021");
+
+    let err = date!(2024-02-29).replace_year(2023).expect_err("2023 is not a leap year");
+    assert_eq!(err.conditional_year(), Some(2023));
+    assert_eq!(err.conditional_month(), Some(Month::February));
+    assert_eq!(err.to_string(), "day must be in the range 1..=28 for February 2023");
+
+    assert_eq!(component_range().conditional_year(), Some(0));
+    assert_eq!(component_range().conditional_month(), None);
+    assert_eq!(
+        component_range().to_string(),
+        "ordinal must be in the range 1..=366 for 0"
+    );
+
+    let err = Date::from_iso_week_date(2021, 53, Weekday::Monday).expect_err("2021 has 52 weeks");
+    assert_eq!(err.to_string(), "week must be in the range 1..=52 for 2021");
+
+    let err = Time::from_hms(24, 0, 0).expect_err("24 is not a valid hour");
+    assert!(!err.is_conditional());
+    assert_eq!(err.conditional_year(), None);
+    assert_eq!(err.conditional_month(), None);
+    assert_eq!(err.to_string(), "hour must be in the range 0..=23");
+}
+
+#[test]
+fn component_ranges_contents() {
+    let err = component_ranges();
+    assert_eq!(err.len(), 2);
+    assert_eq!(err.first(), err.errors()[0]);
+    assert_eq!(
+        err.iter().map(|err| err.name()).collect::<Vec<_>>(),
+        ["day", "hour"]
+    );
+    assert_eq!(
+        err.clone().into_iter().collect::<Vec<_>>(),
+        err.errors()
+    );
+    assert_eq!(
+        err.to_string(),
+        "day must be in the range 1..=28 for February 2021; hour must be in the range 0..=23"
+    );
+    assert_display_eq!(err, Error::from(component_ranges()));
+
+    let err = ComponentRanges::from(component_range());
+    assert_eq!(err.errors(), [component_range()]);
+    assert_display_eq!(err, component_range());
 }
 
 #[allow(clippy::cognitive_complexity)] // all test the same thing
 #[test]
 fn conversion() {
     assert!(ComponentRange::try_from(Error::from(component_range())).is_ok());
+    assert!(ComponentRanges::try_from(Error::from(component_ranges())).is_ok());
     assert!(ConversionRange::try_from(Error::from(ConversionRange)).is_ok());
     assert!(Format::try_from(Error::from(insufficient_type_information())).is_ok());
     assert!(IndeterminateOffset::try_from(Error::from(IndeterminateOffset)).is_ok());
@@ -173,20 +313,29 @@ fn conversion() {
     assert!(Parse::try_from(Error::from(TryFromParsed::InsufficientInformation)).is_ok());
     assert!(DifferentVariant::try_from(Error::from(DifferentVariant)).is_ok());
     assert!(InvalidVariant::try_from(Error::from(InvalidVariant)).is_ok());
+    assert!(InvalidDuration::try_from(Error::from(InvalidDuration::OutOfRange)).is_ok());
+    assert!(InvalidLocalTime::try_from(Error::from(InvalidLocalTime::Nonexistent)).is_ok());
+    assert!(InvalidOffset::try_from(Error::from(InvalidOffset::InvalidSyntax)).is_ok());
+    assert!(InvalidSchedule::try_from(Error::from(InvalidSchedule::UnknownNickname)).is_ok());
     assert!(ComponentRange::try_from(TryFromParsed::ComponentRange(component_range())).is_ok());
     assert!(TryFromParsed::try_from(Error::from(TryFromParsed::InsufficientInformation)).is_ok());
     assert!(TryFromParsed::try_from(Parse::from(TryFromParsed::InsufficientInformation)).is_ok());
     assert!(io::Error::try_from(Format::from(io_error())).is_ok());
 
     assert!(ComponentRange::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(ComponentRanges::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(ConversionRange::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(Format::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(IndeterminateOffset::try_from(Error::from(ConversionRange)).is_err());
     assert!(InvalidFormatDescription::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(InvalidLocalTime::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(InvalidOffset::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(InvalidSchedule::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(ParseFromDescription::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(Parse::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(DifferentVariant::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(InvalidVariant::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(InvalidDuration::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(ComponentRange::try_from(TryFromParsed::InsufficientInformation).is_err());
     assert!(TryFromParsed::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(TryFromParsed::try_from(unexpected_trailing_characters()).is_err());
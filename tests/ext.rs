@@ -57,6 +57,116 @@ mod numerical_duration {
     }
 }
 
+mod checked_numerical_duration {
+    use time::ext::CheckedNumericalDuration;
+    use time::Duration;
+
+    #[test]
+    fn in_range() {
+        assert_eq!(5.checked_nanoseconds(), Some(Duration::nanoseconds(5)));
+        assert_eq!(5.checked_microseconds(), Some(Duration::microseconds(5)));
+        assert_eq!(5.checked_milliseconds(), Some(Duration::milliseconds(5)));
+        assert_eq!(5.checked_seconds(), Some(Duration::seconds(5)));
+        assert_eq!(5.checked_minutes(), Some(Duration::minutes(5)));
+        assert_eq!(5.checked_hours(), Some(Duration::hours(5)));
+        assert_eq!(5.checked_days(), Some(Duration::days(5)));
+        assert_eq!(5.checked_weeks(), Some(Duration::weeks(5)));
+        assert_eq!(
+            (i64::MAX as u64).checked_seconds(),
+            Some(Duration::seconds(i64::MAX))
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let value = i64::MAX as u64 + 1;
+        assert_eq!(value.checked_nanoseconds(), None);
+        assert_eq!(value.checked_microseconds(), None);
+        assert_eq!(value.checked_milliseconds(), None);
+        assert_eq!(value.checked_seconds(), None);
+        assert_eq!((i64::MAX as u64 / 60 + 1).checked_minutes(), None);
+        assert_eq!((i64::MAX as u64 / 3_600 + 1).checked_hours(), None);
+        assert_eq!((i64::MAX as u64 / 86_400 + 1).checked_days(), None);
+        assert_eq!((i64::MAX as u64 / 604_800 + 1).checked_weeks(), None);
+        assert_eq!(u64::MAX.checked_weeks(), None);
+    }
+
+    #[test]
+    fn float() {
+        // Ensure values truncate toward zero rather than round.
+        assert_eq!(1.9.checked_nanoseconds(), Some(Duration::nanoseconds(1)));
+        assert_eq!((-1.9).checked_nanoseconds(), Some(Duration::nanoseconds(-1)));
+
+        assert_eq!(1.5.checked_nanoseconds(), Some(Duration::nanoseconds(1)));
+        assert_eq!(1.5.checked_microseconds(), Some(Duration::nanoseconds(1_500)));
+        assert_eq!(1.5.checked_milliseconds(), Some(Duration::microseconds(1_500)));
+        assert_eq!(1.5.checked_seconds(), Some(Duration::milliseconds(1_500)));
+        assert_eq!(2.5.checked_minutes(), Some(Duration::seconds(150)));
+        assert_eq!(1.5.checked_hours(), Some(Duration::minutes(90)));
+        assert_eq!(1.5.checked_days(), Some(Duration::hours(36)));
+        assert_eq!(1.5.checked_weeks(), Some(Duration::hours(252)));
+        assert_eq!((-2.5).checked_minutes(), Some(Duration::seconds(-150)));
+
+        // Values beyond the range of the non-checked methods are still representable.
+        assert_eq!(1e12.checked_seconds(), Some(Duration::seconds(1_000_000_000_000)));
+    }
+
+    #[test]
+    fn float_out_of_range() {
+        assert_eq!(f64::NAN.checked_nanoseconds(), None);
+        assert_eq!(f64::INFINITY.checked_seconds(), None);
+        assert_eq!(f64::NEG_INFINITY.checked_seconds(), None);
+        assert_eq!(f64::MAX.checked_nanoseconds(), None);
+        assert_eq!(f64::MAX.checked_weeks(), None);
+        assert_eq!(f64::MIN.checked_weeks(), None);
+        assert_eq!(1e20.checked_seconds(), None);
+        assert_eq!((-1e20).checked_seconds(), None);
+    }
+}
+
+mod numerical_std_duration_shim {
+    use std::time::Duration as StdDuration;
+
+    use time::ext::NumericalStdDurationShim;
+    use time::Duration;
+
+    #[test]
+    fn std_nanoseconds_u128() {
+        assert_eq!(0.std_nanoseconds_u128(), StdDuration::ZERO);
+        assert_eq!(5_000_000_500.std_nanoseconds_u128(), StdDuration::new(5, 500));
+        assert_eq!(StdDuration::MAX.as_nanos().std_nanoseconds_u128(), StdDuration::MAX);
+        assert_eq!(
+            StdDuration::MAX.as_nanos().checked_std_nanoseconds_u128(),
+            Some(StdDuration::MAX)
+        );
+        assert_eq!((StdDuration::MAX.as_nanos() + 1).checked_std_nanoseconds_u128(), None);
+        assert_eq!(u128::MAX.checked_std_nanoseconds_u128(), None);
+    }
+
+    #[test]
+    fn nanoseconds_u128() {
+        assert_eq!(0.nanoseconds_u128(), Duration::ZERO);
+        assert_eq!(5_000_000_500.nanoseconds_u128(), Duration::new(5, 500));
+        let max = Duration::MAX.whole_nanoseconds() as u128;
+        assert_eq!(max.nanoseconds_u128(), Duration::MAX);
+        assert_eq!(max.checked_nanoseconds_u128(), Some(Duration::MAX));
+        assert_eq!((max + 1).checked_nanoseconds_u128(), None);
+        assert_eq!(u128::MAX.checked_nanoseconds_u128(), None);
+    }
+
+    #[test]
+    #[should_panic = "overflow constructing `core::time::Duration`"]
+    fn std_nanoseconds_u128_overflow() {
+        let _ = u128::MAX.std_nanoseconds_u128();
+    }
+
+    #[test]
+    #[should_panic = "overflow constructing `time::Duration`"]
+    fn nanoseconds_u128_overflow() {
+        let _ = u128::MAX.nanoseconds_u128();
+    }
+}
+
 mod numerical_std_duration {
     use std::time::Duration as StdDuration;
 
@@ -98,6 +208,12 @@ mod numerical_std_duration {
         assert_eq!(1.5.std_weeks(), StdDuration::from_secs(252 * 3_600));
     }
 
+    #[test]
+    #[should_panic]
+    fn float_nan() {
+        let _ = f64::NAN.std_seconds();
+    }
+
     #[test]
     fn arithmetic() {
         assert_eq!(
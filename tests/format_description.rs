@@ -1,4 +1,10 @@
-use time::format_description::{modifier, BorrowedFormatItem, Component, OwnedFormatItem};
+use time::format_description::well_known::iso8601::{self, DateKind, TimePrecision};
+use time::format_description::well_known::{Iso8601, Iso8601Runtime, Rfc2822, Rfc3339, Rfc9557};
+use time::format_description::{
+    self, modifier, BorrowedFormatItem, Component, Introspect, OwnedFormatItem,
+};
+use time::macros::{datetime, format_description as fd};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset};
 
 #[test]
 fn borrowed_format_item_component_conversions() {
@@ -66,3 +72,145 @@ fn owned_format_item_equality() {
         compound
     );
 }
+
+#[test]
+fn is_roundtrip_for_well_known() {
+    assert!(Rfc3339.is_roundtrip_for::<OffsetDateTime>());
+    assert!(Rfc3339.is_roundtrip_for::<UtcDateTime>());
+    assert!(!Rfc3339.is_roundtrip_for::<PrimitiveDateTime>());
+    assert!(!Rfc3339.is_roundtrip_for::<Date>());
+    assert!(Rfc9557::DEFAULT
+        .with_time_zone("Europe/Paris")
+        .is_roundtrip_for::<OffsetDateTime>());
+
+    assert!(!Rfc2822.is_roundtrip_for::<OffsetDateTime>());
+    assert!(!Rfc2822.is_roundtrip_for::<Time>());
+
+    assert!(Iso8601::DATE.is_roundtrip_for::<Date>());
+    assert!(Iso8601::OFFSET.is_roundtrip_for::<UtcOffset>());
+    assert!(!Iso8601::DEFAULT.is_roundtrip_for::<Date>());
+    assert!(!Iso8601::PARSING.is_roundtrip_for::<Date>());
+    // Fractional seconds are not written exactly.
+    assert!(!Iso8601::TIME.is_roundtrip_for::<Time>());
+    assert!(!Iso8601::DEFAULT.is_roundtrip_for::<OffsetDateTime>());
+
+    let week = iso8601::Config::DEFAULT
+        .set_formatted_components(iso8601::FormattedComponents::Date)
+        .set_date_kind(DateKind::Week);
+    assert!(Iso8601Runtime::new(week).is_roundtrip_for::<Date>());
+    let time = iso8601::Config::DEFAULT
+        .set_formatted_components(iso8601::FormattedComponents::Time)
+        .set_time_precision(TimePrecision::Minute {
+            decimal_digits: None,
+        });
+    assert!(!Iso8601Runtime::new(time).is_roundtrip_for::<Time>());
+}
+
+#[test]
+fn is_roundtrip_for_format_items() {
+    let date = fd!("[year]-[month]-[day]");
+    assert!(date.is_roundtrip_for::<Date>());
+    assert!(!date.is_roundtrip_for::<PrimitiveDateTime>());
+    assert!(fd!("[year range:standard][ordinal]").is_roundtrip_for::<Date>());
+    assert!(fd!("[year base:iso_week]-W[week_number]-[weekday]").is_roundtrip_for::<Date>());
+    assert!(fd!("[year]-W[week_number repr:sunday]-[weekday]").is_roundtrip_for::<Date>());
+    assert!(fd!("[year repr:century][year repr:last_two]-[month]-[day]").is_roundtrip_for::<Date>());
+    assert!(!fd!("[year repr:last_two]-[month]-[day]").is_roundtrip_for::<Date>());
+    assert!(!fd!("[year base:iso_week]-[month]-[day]").is_roundtrip_for::<Date>());
+
+    assert!(fd!("[hour]:[minute]:[second].[subsecond]").is_roundtrip_for::<Time>());
+    assert!(
+        fd!("[hour repr:12]:[minute]:[second].[subsecond] [period]").is_roundtrip_for::<Time>()
+    );
+    assert!(!fd!("[hour repr:12]:[minute]:[second].[subsecond]").is_roundtrip_for::<Time>());
+    assert!(!fd!("[hour]:[minute]:[second].[subsecond digits:3]").is_roundtrip_for::<Time>());
+    assert!(!fd!("[hour]:[minute]:[second]").is_roundtrip_for::<Time>());
+
+    assert!(fd!("[offset_hour][offset_minute][offset_second]").is_roundtrip_for::<UtcOffset>());
+    assert!(!fd!("[offset_hour]:[offset_minute]").is_roundtrip_for::<UtcOffset>());
+
+    let date_time = fd!("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond]");
+    assert!(date_time.is_roundtrip_for::<PrimitiveDateTime>());
+    assert!(date_time.is_roundtrip_for::<UtcDateTime>());
+    assert!(!date_time.is_roundtrip_for::<OffsetDateTime>());
+    assert!(!date_time.is_roundtrip_for::<Date>());
+}
+
+#[test]
+fn is_roundtrip_for_unix_timestamp() {
+    assert!(fd!("[unix_timestamp precision:nanosecond]").is_roundtrip_for::<UtcDateTime>());
+    assert!(!fd!("[unix_timestamp]").is_roundtrip_for::<UtcDateTime>());
+    assert!(fd!("[unix_timestamp].[subsecond]").is_roundtrip_for::<UtcDateTime>());
+    // The parsed value is always in UTC.
+    assert!(!fd!(
+        "[unix_timestamp precision:nanosecond] [offset_hour][offset_minute][offset_second]"
+    )
+    .is_roundtrip_for::<OffsetDateTime>());
+    assert!(!fd!("[unix_timestamp precision:nanosecond]").is_roundtrip_for::<PrimitiveDateTime>());
+}
+
+#[test]
+fn is_roundtrip_for_ambiguous() {
+    assert!(!fd!("[year][ignore count:1]-[month]-[day]").is_roundtrip_for::<Date>());
+    assert!(!fd!("[year]-[month padding:none][day]").is_roundtrip_for::<Date>());
+    assert!(fd!("[year]-[month padding:none]-[day]").is_roundtrip_for::<Date>());
+    assert!(fd!("[year range:standard][month padding:none]/[day]").is_roundtrip_for::<Date>());
+    assert!(!fd!("[year]-[ordinal padding:none]1").is_roundtrip_for::<Date>());
+    assert!(fd!("[year]-[month repr:short][day]").is_roundtrip_for::<Date>());
+    // The year may have more than four digits.
+    assert!(!fd!("[year][ordinal]").is_roundtrip_for::<Date>());
+    assert!(!fd!("[year sign:mandatory][ordinal]").is_roundtrip_for::<Date>());
+    assert!(fd!("[ordinal][year sign:mandatory]").is_roundtrip_for::<Date>());
+    // The subsecond may have any number of digits.
+    assert!(!fd!(
+        "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond][offset_hour]:[offset_minute]:\
+         [offset_second]"
+    )
+    .is_roundtrip_for::<OffsetDateTime>());
+    assert!(fd!(
+        "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond][offset_hour \
+         sign:mandatory]:[offset_minute]:[offset_second]"
+    )
+    .is_roundtrip_for::<OffsetDateTime>());
+}
+
+#[test]
+fn is_roundtrip_for_nested_and_owned() {
+    let optional = fd!(version = 2, "[year]-[month]-[day][optional [ [hour]]]");
+    assert!(!optional.is_roundtrip_for::<Date>());
+    let first = fd!(version = 2, "[year]-[first [[month]-[day]] [[ordinal]]]");
+    assert!(first.is_roundtrip_for::<Date>());
+    let first = fd!(version = 2, "[year]-[first [[ordinal]] [[month]-[day]]]");
+    assert!(first.is_roundtrip_for::<Date>());
+    let first = fd!(version = 2, "[year]-[first [[month]] [[month]-[day]]]");
+    assert!(!first.is_roundtrip_for::<Date>());
+
+    let owned = format_description::parse_owned::<2>(
+        "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond][offset_hour \
+         sign:mandatory]:[offset_minute]:[offset_second]",
+    )
+    .expect("format description is valid");
+    assert!(owned.is_roundtrip_for::<OffsetDateTime>());
+    assert!(!owned.is_roundtrip_for::<Time>());
+    assert!((&&owned).is_roundtrip_for::<UtcDateTime>());
+}
+
+#[test]
+fn is_roundtrip_for_values() -> time::Result<()> {
+    let description = fd!(
+        "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond][offset_hour \
+         sign:mandatory]:[offset_minute]:[offset_second]"
+    );
+    assert!(description.is_roundtrip_for::<OffsetDateTime>());
+
+    for value in [
+        datetime!(2021-01-02 03:04:05.123_456_789 +06:07:08),
+        datetime!(-0001-12-31 23:59:59.999_999_999 -00:00:01),
+        datetime!(2000-02-29 00:00 UTC),
+    ] {
+        let formatted = value.format(description)?;
+        assert_eq!(OffsetDateTime::parse(&formatted, description)?, value);
+    }
+
+    Ok(())
+}
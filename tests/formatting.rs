@@ -2,10 +2,15 @@ use std::io;
 use std::num::NonZeroU8;
 
 use time::format_description::well_known::iso8601::{DateKind, OffsetPrecision, TimePrecision};
-use time::format_description::well_known::{iso8601, Iso8601, Rfc2822, Rfc3339};
-use time::format_description::{self, BorrowedFormatItem, OwnedFormatItem};
+use time::format_description::well_known::{
+    iso8601, HttpDate, Iso8601, Rfc2822, Rfc3339, Rfc9557,
+};
+use time::format_description::{self, modifier, BorrowedFormatItem, Custom, OwnedFormatItem};
+use time::formatting::{
+    BoxedFormat, ComponentWriter, CustomFormattable, English, Locale, Localized,
+};
 use time::macros::{date, datetime, format_description as fd, offset, time, utc_datetime};
-use time::{OffsetDateTime, Time};
+use time::{util, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 #[test]
 fn rfc_2822() -> time::Result<()> {
@@ -42,6 +47,41 @@ fn rfc_2822() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn http_date() -> time::Result<()> {
+    assert_eq!(
+        datetime!(1994-11-06 08:49:37 UTC).format(&HttpDate)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        utc_datetime!(1994-11-06 08:49:37.5).format(&HttpDate)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        datetime!(1994-11-06 01:49:37 -07:00).format(&HttpDate)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        datetime!(0001-01-01 00:00:00 UTC).format(&HttpDate)?,
+        "Mon, 01 Jan 0001 00:00:00 GMT"
+    );
+
+    assert!(matches!(
+        datetime!(-0001-01-01 00:00:00 UTC).format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        Date::MIN.midnight().assume_offset(offset!(-1)).format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        date!(2021-01-02).format(&HttpDate),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn rfc_3339() -> time::Result<()> {
     assert_eq!(
@@ -105,6 +145,59 @@ fn rfc_3339() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn rfc_9557() -> time::Result<()> {
+    let value = datetime!(2021-01-02 03:04:05.1 -5);
+    assert_eq!(value.format(&Rfc9557::DEFAULT)?, value.format(&Rfc3339)?);
+    assert_eq!(
+        value.format(&Rfc9557::DEFAULT.with_time_zone("America/New_York"))?,
+        "2021-01-02T03:04:05.1-05:00[America/New_York]"
+    );
+    assert_eq!(
+        value.format(
+            &Rfc9557::DEFAULT
+                .critical(true)
+                .with_time_zone("America/New_York")
+        )?,
+        "2021-01-02T03:04:05.1-05:00[!America/New_York]"
+    );
+    assert_eq!(
+        value.format(&Rfc9557::DEFAULT.with_time_zone("-05:00"))?,
+        "2021-01-02T03:04:05.1-05:00[-05:00]"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 UTC).format(&Rfc9557::default().with_time_zone("Etc/GMT+0"))?,
+        "2021-01-02T03:04:05Z[Etc/GMT+0]"
+    );
+
+    for time_zone in ["", "America/", "1st/Zone", "..", "Zone]", "+5:00", "+24:00", "-0500"] {
+        assert!(
+            matches!(
+                value.format(&Rfc9557::DEFAULT.with_time_zone(time_zone)),
+                Err(time::error::Format::InvalidComponent("time_zone"))
+            ),
+            "{time_zone}"
+        );
+    }
+    let mut buf = Vec::new();
+    assert!(value
+        .format_into(&mut buf, &Rfc9557::DEFAULT.with_time_zone("a b"))
+        .is_err());
+    assert!(buf.is_empty());
+    assert!(matches!(
+        datetime!(2021-01-02 03:04:05 +0:00:01).format(&Rfc9557::DEFAULT),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+
+    let format = Rfc9557::new().with_time_zone("Europe/Paris").critical(true);
+    assert_eq!(format.time_zone(), Some("Europe/Paris"));
+    assert!(format.is_critical());
+    assert_eq!(Rfc9557::DEFAULT.time_zone(), None);
+    assert!(!Rfc9557::DEFAULT.is_critical());
+
+    Ok(())
+}
+
 #[test]
 fn iso_8601() -> time::Result<()> {
     macro_rules! assert_format_config {
@@ -184,6 +277,61 @@ fn iso_8601() -> time::Result<()> {
     assert_format_config!("2021-01-02T03:04:05Z", .set_time_precision(TimePrecision::Second { decimal_digits: None }));
     assert_format_config!("2021-002T03:04:05.000000000Z", .set_date_kind(DateKind::Ordinal));
     assert_format_config!("2020-W53-6T03:04:05.000000000Z", .set_date_kind(DateKind::Week));
+    assert_format_config!("2021-01-02T03:04:05.000000000Z", .set_midnight_as_24(true));
+
+    assert_eq!(
+        datetime!(2021-03-01 0:00 +1).format(
+            &Iso8601::<
+                {
+                    iso8601::Config::DEFAULT
+                        .set_midnight_as_24(true)
+                        .encode()
+                },
+            >
+        )?,
+        "2021-02-28T24:00:00.000000000+01:00"
+    );
+    assert_eq!(
+        datetime!(2021-01-04 0:00 UTC).format(
+            &Iso8601::<
+                {
+                    iso8601::Config::DEFAULT
+                        .set_midnight_as_24(true)
+                        .set_date_kind(DateKind::Week)
+                        .set_time_precision(TimePrecision::Minute { decimal_digits: None })
+                        .encode()
+                },
+            >
+        )?,
+        "2020-W53-7T24:00Z"
+    );
+    assert_eq!(
+        time!(0:00).format(
+            &Iso8601::<
+                {
+                    iso8601::Config::DEFAULT
+                        .set_midnight_as_24(true)
+                        .set_formatted_components(iso8601::FormattedComponents::Time)
+                        .set_time_precision(TimePrecision::Hour { decimal_digits: None })
+                        .encode()
+                },
+            >
+        )?,
+        "T24"
+    );
+    assert_eq!(
+        date!(2021-01-02).format(
+            &Iso8601::<
+                {
+                    iso8601::Config::DEFAULT
+                        .set_midnight_as_24(true)
+                        .set_formatted_components(iso8601::FormattedComponents::Date)
+                        .encode()
+                },
+            >
+        )?,
+        "2021-01-02"
+    );
 
     assert!(matches!(
         datetime!(+10_000-01-01 0:00 UTC).format(&Iso8601::DEFAULT),
@@ -213,6 +361,19 @@ fn iso_8601() -> time::Result<()> {
         ),
         Err(time::error::Format::InvalidComponent("year"))
     ));
+    assert!(matches!(
+        PrimitiveDateTime::MIN.assume_utc().format(
+            &Iso8601::<
+                {
+                    iso8601::Config::DEFAULT
+                        .set_midnight_as_24(true)
+                        .set_year_is_six_digits(true)
+                        .encode()
+                },
+            >
+        ),
+        Err(time::error::Format::InvalidComponent("day"))
+    ));
     assert!(matches!(
         datetime!(2021-01-02 03:04:05 +0:00:01).format(&Iso8601::DEFAULT),
         Err(time::error::Format::InvalidComponent("offset_second"))
@@ -233,6 +394,149 @@ fn iso_8601() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn iso_8601_runtime() -> time::Result<()> {
+    use time::format_description::well_known::iso8601::{Config, EncodedConfig};
+    use time::format_description::well_known::Iso8601Runtime;
+
+    const CONFIG: Config = Config::DEFAULT
+        .set_use_separators(false)
+        .set_date_kind(DateKind::Week)
+        .set_time_precision(TimePrecision::Minute {
+            decimal_digits: NonZeroU8::new(2),
+        })
+        .set_offset_precision(OffsetPrecision::Hour);
+    const ENCODED: EncodedConfig = CONFIG.encode();
+
+    let dt = datetime!(2021-01-02 03:04:05 +6);
+    assert_eq!(
+        dt.format(&Iso8601Runtime::new(CONFIG))?,
+        dt.format(&Iso8601::<ENCODED>)?
+    );
+    assert_eq!(dt.format(&Iso8601Runtime::new(CONFIG))?, "2020W536T0304.08+06");
+    assert_eq!(
+        dt.format(&Iso8601Runtime::default())?,
+        dt.format(&Iso8601::DEFAULT)?
+    );
+    assert_eq!(
+        dt.format(&Iso8601Runtime::from(Iso8601::DATE))?,
+        "2021-01-02"
+    );
+    assert_eq!(Iso8601Runtime::from(Iso8601::<ENCODED>).config(), &CONFIG);
+
+    assert!(matches!(
+        dt.date().format(&Iso8601Runtime::default()),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert_panic!(dt.format(&Iso8601Runtime::from(Iso8601::PARSING)));
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601_subsecond_rounding() -> time::Result<()> {
+    use time::format_description::well_known::iso8601::{Config, FormattedComponents};
+    use time::format_description::well_known::Iso8601Runtime;
+    use time::Rounding::*;
+
+    let config = |digits, rounding| {
+        Config::DEFAULT
+            .set_time_precision(TimePrecision::Second {
+                decimal_digits: NonZeroU8::new(digits),
+            })
+            .set_subsecond_rounding(rounding)
+    };
+    let time = |time: Time, digits, rounding| {
+        time.format(&Iso8601Runtime::new(
+            config(digits, rounding).set_formatted_components(FormattedComponents::Time),
+        ))
+    };
+
+    assert_eq!(time(time!(3:04:05.123_5), 3, TowardZero)?, "T03:04:05.123");
+    assert_eq!(time(time!(3:04:05.123_5), 3, Floor)?, "T03:04:05.123");
+    assert_eq!(time(time!(3:04:05.123_1), 3, Ceiling)?, "T03:04:05.124");
+    assert_eq!(time(time!(3:04:05.123_1), 3, AwayFromZero)?, "T03:04:05.124");
+    assert_eq!(time(time!(3:04:05.123_4), 3, HalfAwayFromZero)?, "T03:04:05.123");
+    assert_eq!(time(time!(3:04:05.123_5), 3, HalfAwayFromZero)?, "T03:04:05.124");
+    assert_eq!(time(time!(3:04:05.123_5), 3, HalfEven)?, "T03:04:05.124");
+    assert_eq!(time(time!(3:04:05.124_5), 3, HalfEven)?, "T03:04:05.124");
+    assert_eq!(time(time!(3:04:05.124_6), 3, HalfEven)?, "T03:04:05.125");
+    assert_eq!(time(time!(3:04:05.123), 3, Ceiling)?, "T03:04:05.123");
+    assert_eq!(time(time!(3:04:05.5), 0, HalfEven)?, "T03:04:06");
+    assert_eq!(time(time!(3:04:06.5), 0, HalfEven)?, "T03:04:06");
+    assert_eq!(time(time!(3:04:06.5), 0, HalfAwayFromZero)?, "T03:04:07");
+    assert_eq!(time(time!(3:04:05.5), 9, Ceiling)?, "T03:04:05.500000000");
+    assert_eq!(time(time!(23:59:59.999_9), 3, Ceiling)?, "T00:00:00.000");
+
+    assert_eq!(
+        datetime!(2021-12-31 23:59:59.999_5 UTC)
+            .format(&Iso8601Runtime::new(config(3, HalfAwayFromZero)))?,
+        "2022-01-01T00:00:00.000Z"
+    );
+    assert_eq!(
+        datetime!(2021-12-31 23:59:59.999_5 UTC).format(&Iso8601Runtime::new(
+            config(3, HalfAwayFromZero).set_midnight_as_24(true)
+        ))?,
+        "2021-12-31T24:00:00.000Z"
+    );
+    assert!(matches!(
+        PrimitiveDateTime::MAX.format(&Iso8601Runtime::new(
+            config(3, Ceiling).set_year_is_six_digits(true)
+        )),
+        Err(time::error::Format::InvalidComponent("day"))
+    ));
+
+    // The date is not rounded if the time is not formatted.
+    assert_eq!(
+        datetime!(2021-01-01 23:59:59.999_6 UTC).format(&Iso8601Runtime::new(
+            config(3, Ceiling).set_formatted_components(FormattedComponents::Date)
+        ))?,
+        "2021-01-01"
+    );
+
+    // Fractional hours and minutes are unaffected.
+    assert_eq!(
+        time!(3:04:59.9).format(&Iso8601Runtime::new(
+            Config::DEFAULT
+                .set_formatted_components(FormattedComponents::Time)
+                .set_time_precision(TimePrecision::Minute {
+                    decimal_digits: None
+                })
+                .set_subsecond_rounding(Ceiling)
+        ))?,
+        "T03:04"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn common_format() -> time::Result<()> {
+    use time::format_description::well_known::CommonFormat;
+
+    let dt = datetime!(2021-01-02 03:04:05 +6);
+    assert_eq!(dt.format(&CommonFormat::Rfc3339)?, dt.format(&Rfc3339)?);
+    assert_eq!(dt.format(&CommonFormat::Rfc2822)?, dt.format(&Rfc2822)?);
+    assert_eq!(dt.format(&CommonFormat::HttpDate)?, dt.format(&HttpDate)?);
+    assert_eq!(
+        dt.format(&CommonFormat::Iso8601)?,
+        dt.format(&Iso8601::DEFAULT)?
+    );
+    assert_eq!(dt.format(&CommonFormat::IsoDate)?, "2021-01-02");
+    assert_eq!(dt.format(&CommonFormat::IsoTime)?, "T03:04:05.000000000");
+    assert_eq!(
+        dt.format(&CommonFormat::IsoDateTime)?,
+        "2021-01-02T03:04:05.000000000"
+    );
+    assert_eq!(dt.format(&CommonFormat::IsoOffset)?, "+06:00");
+    assert!(matches!(
+        dt.time().format(&CommonFormat::Rfc3339),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn iso_8601_issue_678() -> time::Result<()> {
     macro_rules! assert_format_config {
@@ -430,6 +734,181 @@ fn format_date_err() {
     ));
 }
 
+#[test]
+fn format_year_width() -> time::Result<()> {
+    assert_eq!(date!(2019-12-31).format(fd!("[year width:4]"))?, "2019");
+    assert_eq!(date!(2019-12-31).format(fd!("[year width:5]"))?, "02019");
+    assert_eq!(date!(2019-12-31).format(fd!("[year width:6]"))?, "002019");
+    assert_eq!(
+        date!(2019-12-31).format(fd!("[year width:6 padding:space]"))?,
+        "  2019"
+    );
+    assert_eq!(
+        date!(2019-12-31).format(fd!("[year width:5 sign:mandatory]"))?,
+        "+02019"
+    );
+    assert_eq!(date!(-0001-01-01).format(fd!("[year width:5]"))?, "-00001");
+    assert_eq!(date!(+10_000-01-01).format(fd!("[year width:5]"))?, "10000");
+    assert_eq!(date!(+10_000-01-01).format(fd!("[year width:6]"))?, "010000");
+
+    assert!(matches!(
+        date!(+10_000-01-01).format(fd!("[year width:4]")),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        date!(+100_000-01-01).format(fd!("[year width:5]")),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        date!(+10_000-01-01).format(fd!("[year width:5 range:standard]")),
+        Err(time::error::Format::ComponentRange(cr)) if cr.name() == "year"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn format_year_notation() -> time::Result<()> {
+    let format = fd!("[year notation:bce]");
+    assert_eq!(date!(2024-01-01).format(format)?, "2024");
+    assert_eq!(date!(0001-01-01).format(format)?, "0001");
+    assert_eq!(date!(0000-01-01).format(format)?, "0001 BCE");
+    assert_eq!(date!(-0001-01-01).format(format)?, "0002 BCE");
+    assert_eq!(date!(-9999-01-01).format(format)?, "10000 BCE");
+    assert_eq!(
+        date!(-0001-01-01).format(fd!("[year notation:bce padding:none]"))?,
+        "2 BCE"
+    );
+    assert_eq!(
+        date!(-0001-01-01).format(fd!("[year notation:bce width:6 sign:mandatory]"))?,
+        "000002 BCE"
+    );
+    assert_eq!(
+        date!(-0001-01-01).format(fd!("[year notation:astronomical]"))?,
+        "-0001"
+    );
+    assert_eq!(
+        date!(-0001-01-01).format(fd!("[year notation:bce repr:last_two]"))?,
+        "01"
+    );
+    assert_eq!(
+        date!(-999_999-01-01).format(fd!("[year notation:bce]"))?,
+        "1000000 BCE"
+    );
+
+    assert!(matches!(
+        date!(-9999-01-01).format(fd!("[year notation:bce width:4]")),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn format_year_group_separator() -> time::Result<()> {
+    assert_eq!(
+        date!(2024-01-01).format(fd!("[year group_separator:underscore]"))?,
+        "2_024"
+    );
+    assert_eq!(
+        date!(2024-01-01).format(fd!("[year group_separator:comma]"))?,
+        "2,024"
+    );
+    assert_eq!(
+        date!(2024-01-01).format(fd!("[year group_separator:period]"))?,
+        "2.024"
+    );
+    assert_eq!(
+        date!(2024-01-01).format(fd!("[year group_separator:space]"))?,
+        "2 024"
+    );
+    assert_eq!(
+        date!(2024-01-01).format(fd!("[year group_separator:none]"))?,
+        "2024"
+    );
+    assert_eq!(
+        date!(0999-01-01).format(fd!("[year group_separator:underscore]"))?,
+        "0999"
+    );
+    assert_eq!(
+        date!(0024-01-01).format(fd!("[year width:6 group_separator:underscore]"))?,
+        "000024"
+    );
+    assert_eq!(
+        date!(0000-01-01).format(fd!("[year width:6 group_separator:underscore]"))?,
+        "000000"
+    );
+    assert_eq!(
+        date!(0999-01-01).format(fd!("[year padding:space group_separator:underscore]"))?,
+        " 999"
+    );
+    assert_eq!(
+        date!(0999-01-01).format(fd!("[year padding:none group_separator:underscore]"))?,
+        "999"
+    );
+    assert_eq!(
+        date!(-2024-01-01).format(fd!("[year group_separator:underscore]"))?,
+        "-2_024"
+    );
+    assert_eq!(
+        date!(2024-01-01).format(fd!("[year repr:last_two group_separator:underscore]"))?,
+        "24"
+    );
+    assert_eq!(
+        date!(+12_024-01-01).format(fd!("[year group_separator:underscore]"))?,
+        "+12_024"
+    );
+    assert_eq!(
+        date!(+12_024-01-01).format(fd!("[year width:6 group_separator:underscore]"))?,
+        "012_024"
+    );
+    assert_eq!(
+        date!(+123_456-01-01).format(fd!("[year group_separator:comma]"))?,
+        "+123,456"
+    );
+
+    assert_eq!(
+        date!(-9999-01-01).format(fd!("[year notation:bce group_separator:underscore]"))?,
+        "10_000 BCE"
+    );
+
+    let holocene_year = util::holocene_year(2024).expect("year is in range");
+    let date = date!(2024-01-01).replace_year(holocene_year)?;
+    assert_eq!(
+        date.format(fd!("[year group_separator:underscore] HE"))?,
+        "+12_024 HE"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn format_day_ordinal_suffix() -> time::Result<()> {
+    let format = fd!("[month repr:long] [day padding:none ordinal_suffix:true]");
+    for (date, expected) in [
+        (date!(2019-01-01), "January 1st"),
+        (date!(2019-01-02), "January 2nd"),
+        (date!(2019-01-03), "January 3rd"),
+        (date!(2019-01-04), "January 4th"),
+        (date!(2019-01-11), "January 11th"),
+        (date!(2019-01-12), "January 12th"),
+        (date!(2019-01-13), "January 13th"),
+        (date!(2019-01-21), "January 21st"),
+        (date!(2019-01-22), "January 22nd"),
+        (date!(2019-01-23), "January 23rd"),
+        (date!(2019-01-30), "January 30th"),
+        (date!(2019-01-31), "January 31st"),
+    ] {
+        assert_eq!(date.format(format)?, expected);
+    }
+    assert_eq!(
+        date!(2019-01-01).format(fd!("[day ordinal_suffix:true]"))?,
+        "01st"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn display_date() {
     assert_eq!(date!(2019-01-01).to_string(), "2019-01-01");
@@ -441,6 +920,9 @@ fn display_date() {
     assert_eq!(date!(+100_000-01-01).to_string(), "+100000-01-01");
     assert_eq!(date!(-10_000 - 01 - 01).to_string(), "-10000-01-01");
     assert_eq!(date!(-100_000 - 01 - 01).to_string(), "-100000-01-01");
+
+    assert_eq!(format!("{:>12}", date!(2019-01-01)), "  2019-01-01");
+    assert_eq!(format!("{:x^15}", date!(+10_000-01-01)), "x+10000-01-01xx");
 }
 
 #[test]
@@ -535,6 +1017,10 @@ fn display_pdt() {
         datetime!(1970-01-01 0:00:01).to_string(),
         String::from("1970-01-01 0:00:01.0")
     );
+    assert_eq!(
+        format!("{:>22}", datetime!(1970-01-01 0:00)),
+        "  1970-01-01 0:00:00.0"
+    );
 }
 
 #[test]
@@ -568,6 +1054,10 @@ fn display_odt() {
         datetime!(1970-01-01 0:00 UTC).to_string(),
         "1970-01-01 0:00:00.0 +00:00:00"
     );
+    assert_eq!(
+        format!("{:<32}|", datetime!(1970-01-01 0:00 UTC)),
+        "1970-01-01 0:00:00.0 +00:00:00  |"
+    );
 }
 
 #[test]
@@ -598,6 +1088,10 @@ fn display_udt() {
         utc_datetime!(1970-01-01 0:00).to_string(),
         "1970-01-01 0:00:00.0 +00"
     );
+    assert_eq!(
+        format!("{:x^26}", utc_datetime!(1970-01-01 0:00)),
+        "x1970-01-01 0:00:00.0 +00x"
+    );
 }
 
 #[test]
@@ -623,6 +1117,33 @@ fn insufficient_type_information() {
     assert_insufficient_type_information(datetime!(2021-001 0:00).format(&Iso8601::DEFAULT));
 }
 
+#[test]
+fn formatted_len() -> time::Result<()> {
+    let format = fd!("[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour]");
+    let odt = datetime!(2021-01-02 03:04:05 +06);
+    assert_eq!(odt.formatted_len(format)?, odt.format(format)?.len());
+    assert_eq!(odt.formatted_len(&Rfc2822)?, odt.format(&Rfc2822)?.len());
+    assert_eq!(odt.formatted_len(&HttpDate)?, odt.format(&HttpDate)?.len());
+    assert_eq!(odt.formatted_len(&Iso8601::DEFAULT)?, 35);
+    assert_eq!(
+        odt.formatted_len(&Localized::new(fd!("[month repr:long]"), English))?,
+        "January".len()
+    );
+    assert_eq!(date!(2021-01-02).formatted_len(fd!("[year]-[ordinal]"))?, 8);
+    assert_eq!(time!(3:04).formatted_len(fd!("[hour]:[minute]"))?, 5);
+    assert_eq!(datetime!(2021-01-02 03:04).formatted_len(fd!("[day] [hour]"))?, 5);
+    assert_eq!(offset!(+1).formatted_len(fd!("[offset_hour sign:mandatory]"))?, 3);
+    assert_eq!(
+        utc_datetime!(2021-01-02 03:04).formatted_len(&Rfc3339)?,
+        "2021-01-02T03:04:00Z".len()
+    );
+    assert!(matches!(
+        Time::MIDNIGHT.formatted_len(&Rfc3339),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    Ok(())
+}
+
 #[allow(clippy::cognitive_complexity)] // all test the same thing
 #[test]
 fn failed_write() -> time::Result<()> {
@@ -773,6 +1294,58 @@ fn failed_write() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn format_into_batches_writes() -> time::Result<()> {
+    /// A writer that records the length of each write it receives.
+    #[derive(Default)]
+    struct RecordWrites {
+        buf: Vec<u8>,
+        writes: Vec<usize>,
+    }
+
+    impl io::Write for RecordWrites {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.buf.extend_from_slice(buf);
+            self.writes.push(buf.len());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut output = RecordWrites::default();
+    let bytes =
+        datetime!(2021-01-02 03:04:05.123_456_789 +06:07).format_into(&mut output, &Rfc3339)?;
+    assert_eq!(output.buf, b"2021-01-02T03:04:05.123456789+06:07");
+    assert_eq!(output.writes, [bytes]);
+
+    let mut output = RecordWrites::default();
+    date!(2021-01-02).format_into(&mut output, fd!("[year]-[month]-[day]"))?;
+    time!(3:04:05).format_into(&mut output, fd!(" [hour]:[minute]:[second]"))?;
+    assert_eq!(output.buf, b"2021-01-02 03:04:05");
+    assert_eq!(output.writes, [10, 9]);
+
+    // Output that exceeds the buffer is still written in full and in order.
+    let mut output = RecordWrites::default();
+    let bytes = Time::MIDNIGHT.format_into(
+        &mut output,
+        &[
+            BorrowedFormatItem::Literal(b"x"),
+            BorrowedFormatItem::Literal(&[b'a'; 300]),
+            BorrowedFormatItem::Literal(b"y"),
+        ][..],
+    )?;
+    assert_eq!(bytes, 302);
+    assert_eq!(output.buf.len(), 302);
+    assert_eq!(output.buf.first(), Some(&b'x'));
+    assert_eq!(output.buf.last(), Some(&b'y'));
+    assert_eq!(output.writes, [1, 300, 1]);
+
+    Ok(())
+}
+
 #[test]
 fn first() -> time::Result<()> {
     assert_eq!(Time::MIDNIGHT.format(&BorrowedFormatItem::First(&[]))?, "");
@@ -838,3 +1411,229 @@ fn unix_timestamp() -> time::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn localized() -> time::Result<()> {
+    struct French;
+
+    impl Locale for French {
+        fn month_name(&self, month: Month) -> &str {
+            match month {
+                Month::January => "janvier",
+                Month::February => "février",
+                _ => "mois",
+            }
+        }
+
+        fn month_abbreviation(&self, month: Month) -> &str {
+            match month {
+                Month::January => "janv.",
+                Month::February => "févr.",
+                _ => "m.",
+            }
+        }
+
+        fn weekday_name(&self, weekday: Weekday) -> &str {
+            match weekday {
+                Weekday::Saturday => "samedi",
+                _ => "jour",
+            }
+        }
+
+        fn weekday_abbreviation(&self, weekday: Weekday) -> &str {
+            match weekday {
+                Weekday::Saturday => "sam.",
+                _ => "j.",
+            }
+        }
+    }
+
+    let format = fd!("[weekday] [day] [month repr:long] [year]");
+    assert_eq!(
+        date!(2021-01-02).format(&Localized::new(format, French))?,
+        "samedi 02 janvier 2021"
+    );
+    assert_eq!(
+        date!(2021-02-06).format(&Localized::new(
+            fd!("[weekday repr:short] [day] [month repr:short]"),
+            &French
+        ))?,
+        "sam. 06 févr."
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 UTC).format(&Localized::new(
+            OwnedFormatItem::from(fd!("[weekday], [month repr:long] [hour]:[minute]")),
+            French
+        ))?,
+        "samedi, janvier 03:04"
+    );
+    assert_eq!(
+        date!(2021-01-02).format(&Localized::new(format, English))?,
+        date!(2021-01-02).format(format)?
+    );
+    // Well-known formats are unaffected by the locale.
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 UTC).format(&Localized::new(Rfc2822, French))?,
+        "Sat, 02 Jan 2021 03:04:05 +0000"
+    );
+
+    let mut buf = Vec::new();
+    date!(2021-01-02).format_into(&mut buf, &Localized::new(&format, French))?;
+    assert_eq!(buf, b"samedi 02 janvier 2021");
+
+    Ok(())
+}
+
+#[test]
+fn component_writer() -> time::Result<()> {
+    let dt = datetime!(2021-01-02 03:04:05.678 -1:30);
+    let mut writer = ComponentWriter::new(Vec::new());
+
+    let mut bytes = writer.write_weekday(dt.date(), modifier::Weekday::default())?;
+    bytes += writer.write_literal(b", ")?;
+    bytes += writer.write_year(dt.date(), modifier::Year::default())?;
+    bytes += writer.write_literal(b"-")?;
+    bytes += writer.write_month(dt.date(), modifier::Month::default())?;
+    bytes += writer.write_literal(b"-")?;
+    bytes += writer.write_day(dt.date(), modifier::Day::default())?;
+    bytes += writer.write_literal(b"T")?;
+    bytes += writer.write_hour(dt.time(), modifier::Hour::default())?;
+    bytes += writer.write_literal(b":")?;
+    bytes += writer.write_minute(dt.time(), modifier::Minute::default())?;
+    bytes += writer.write_literal(b":")?;
+    bytes += writer.write_second(dt.time(), modifier::Second::default())?;
+    bytes += writer.write_literal(b".")?;
+    bytes += writer.write_subsecond(dt.time(), modifier::Subsecond::default())?;
+    bytes += writer.write_offset_hour(dt.offset(), modifier::OffsetHour::default())?;
+    bytes += writer.write_literal(b":")?;
+    bytes += writer.write_offset_minute(dt.offset(), modifier::OffsetMinute::default())?;
+
+    let expected = dt.format(fd!(
+        "[weekday], [year]-[month]-[day]T[hour]:[minute]:[second].[subsecond][offset_hour]:\
+         [offset_minute]"
+    ))?;
+    assert_eq!(writer.get_ref(), expected.as_bytes());
+    assert_eq!(bytes, expected.len());
+
+    writer.get_mut().clear();
+    writer.write_two_digits(7, modifier::Padding::Space)?;
+    writer.write_two_digits(123, modifier::Padding::Zero)?;
+    writer.write_unix_timestamp(dt, modifier::UnixTimestamp::default())?;
+    assert_eq!(writer.into_inner(), b" 71231609562045");
+
+    let mut year = modifier::Year::default();
+    year.width = modifier::YearWidth::Four;
+    assert!(matches!(
+        ComponentWriter::new(io::sink()).write_year(date!(+10_000-01-01), year),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn custom_format() -> time::Result<()> {
+    /// Writes whichever of the ordinal date and the hour are present.
+    struct OrdinalHour;
+
+    impl CustomFormattable for OrdinalHour {
+        fn format_into(
+            &self,
+            output: &mut dyn io::Write,
+            date: Option<Date>,
+            time: Option<Time>,
+            _: Option<UtcOffset>,
+        ) -> Result<usize, time::error::Format> {
+            let mut buf = String::new();
+            if let Some(date) = date {
+                buf.push_str(&format!("{}-{:03}", date.year(), date.ordinal()));
+            }
+            if let Some(time) = time {
+                buf.push_str(&format!("T{:02}", time.hour()));
+            }
+            if buf.is_empty() {
+                return Err(time::error::Format::insufficient_type_information());
+            }
+            output.write_all(buf.as_bytes())?;
+            Ok(buf.len())
+        }
+    }
+
+    assert_eq!(date!(2021-01-02).format(&Custom(OrdinalHour))?, "2021-002");
+    assert_eq!(time!(3:04).format(&Custom(OrdinalHour))?, "T03");
+    assert_eq!(
+        datetime!(2021-01-02 03:04).format(&Custom(OrdinalHour))?,
+        "2021-002T03"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04 +1).format(&Custom(OrdinalHour))?,
+        "2021-002T03"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04 +1).format(&Localized::new(Custom(OrdinalHour), English))?,
+        "2021-002T03"
+    );
+    assert!(matches!(
+        offset!(+1).format(&Custom(OrdinalHour)),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    let mut buf = Vec::new();
+    assert_eq!(
+        date!(2021-01-02).format_into(&mut buf, &Custom(OrdinalHour))?,
+        8
+    );
+    assert_eq!(buf, b"2021-002");
+
+    Ok(())
+}
+
+#[test]
+fn boxed_format() -> time::Result<()> {
+    struct Upper;
+
+    impl Locale for Upper {
+        fn month_name(&self, _: Month) -> &str {
+            "MONTH"
+        }
+
+        fn month_abbreviation(&self, _: Month) -> &str {
+            "MON"
+        }
+
+        fn weekday_name(&self, _: Weekday) -> &str {
+            "WEEKDAY"
+        }
+
+        fn weekday_abbreviation(&self, _: Weekday) -> &str {
+            "WKD"
+        }
+    }
+
+    let formats = [
+        BoxedFormat::new(Rfc3339),
+        BoxedFormat::new(fd!("[weekday repr:short] [month repr:short]")),
+        BoxedFormat::new(format_description::parse_owned::<2>("[year]")?),
+        BoxedFormat::new(Iso8601::DATE),
+    ];
+    let datetime = datetime!(2021-01-02 03:04:05 UTC);
+    assert_eq!(
+        formats
+            .iter()
+            .map(|format| datetime.format(format))
+            .collect::<Result<Vec<_>, _>>()?,
+        ["2021-01-02T03:04:05Z", "Sat Jan", "2021", "2021-01-02"]
+    );
+    assert_eq!(datetime.formatted_len(&formats[0])?, 20);
+    assert_eq!(
+        datetime.format(&Localized::new(&formats[1], Upper))?,
+        "WKD MON"
+    );
+    assert!(matches!(
+        date!(2021-01-02).format(&formats[0]),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert_eq!(format!("{:?}", formats[0]), "BoxedFormat { .. }");
+    Ok(())
+}
+
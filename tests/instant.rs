@@ -14,6 +14,46 @@ fn elapsed() {
     assert!(instant.elapsed() >= 1.milliseconds());
 }
 
+#[test]
+fn elapsed_checked() {
+    let instant = Instant::now();
+    thread::sleep(1.std_milliseconds());
+    assert!(instant.elapsed_checked() >= Some(1.milliseconds()));
+    assert!((instant + 1.days()).elapsed_checked() < Some(Duration::ZERO));
+}
+
+#[test]
+fn checked_duration_since() {
+    let now = Instant::now();
+    assert_eq!(now.checked_duration_since(now), Some(Duration::ZERO));
+    assert_eq!(
+        (now + 5.seconds()).checked_duration_since(now),
+        Some(5.seconds())
+    );
+    assert_eq!(
+        (now - 5.seconds()).checked_duration_since(now),
+        Some((-5).seconds())
+    );
+    assert_eq!(
+        (now + 1.nanoseconds()).checked_duration_since(now - 1.nanoseconds()),
+        Some(2.nanoseconds())
+    );
+}
+
+#[test]
+fn saturating_duration_since() {
+    let now = Instant::now();
+    assert_eq!(now.saturating_duration_since(now), Duration::ZERO);
+    assert_eq!(
+        (now + 5.seconds()).saturating_duration_since(now),
+        5.seconds()
+    );
+    assert_eq!(
+        (now - 5.seconds()).saturating_duration_since(now),
+        (-5).seconds()
+    );
+}
+
 #[test]
 fn checked_add() {
     let now = Instant::now();
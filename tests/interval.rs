@@ -0,0 +1,88 @@
+use rstest::rstest;
+use time::ext::NumericalDuration;
+use time::interval::Interval;
+use time::macros::datetime;
+use time::{Duration, OffsetDateTime};
+
+#[rstest]
+#[case(0, &[])]
+#[case(1, &[datetime!(2024-01-01 0:00 UTC)])]
+#[case(2, &[datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-01 0:00:01 UTC)])]
+#[case(3, &[
+    datetime!(2024-01-01 0:00 UTC),
+    datetime!(2024-01-01 0:00:00.5 UTC),
+    datetime!(2024-01-01 0:00:01 UTC),
+])]
+#[case(4, &[
+    datetime!(2024-01-01 0:00 UTC),
+    datetime!(2024-01-01 0:00:00.333_333_333 UTC),
+    datetime!(2024-01-01 0:00:00.666_666_666 UTC),
+    datetime!(2024-01-01 0:00:01 UTC),
+])]
+fn sample_evenly(#[case] n: usize, #[case] expected: &[OffsetDateTime]) {
+    let interval = Interval::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-01 0:00:01 UTC));
+    let samples = interval.sample_evenly(n);
+    assert_eq!(samples.len(), n);
+    assert_eq!(samples.collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn sample_evenly_extremes() {
+    let interval = Interval::new(
+        datetime!(-9999-01-01 0:00 UTC),
+        datetime!(+9999-12-31 23:59:59.999_999_999 UTC),
+    );
+    let mut samples = interval.sample_evenly(usize::MAX);
+    assert_eq!(samples.next(), Some(interval.start()));
+    assert_eq!(samples.len(), usize::MAX - 1);
+    assert_eq!(samples.nth(usize::MAX / 2 - 1), Some(interval.start() + interval.duration() / 2));
+    assert_eq!(samples.last(), Some(interval.end()));
+    assert_eq!(interval.sample_evenly(0).last(), None);
+
+    let empty = Interval::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-01 0:00 UTC));
+    assert_eq!(
+        empty.sample_evenly(3).collect::<Vec<_>>(),
+        [empty.start(), empty.start(), empty.start()]
+    );
+}
+
+#[test]
+fn split() {
+    let interval = Interval::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-01 3:00 +1));
+    assert_eq!(
+        interval.split(1.hours()).collect::<Vec<_>>(),
+        [
+            Interval::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-01 1:00 UTC)),
+            Interval::new(datetime!(2024-01-01 1:00 UTC), datetime!(2024-01-01 2:00 UTC)),
+        ]
+    );
+    assert_eq!(interval.split(2.hours()).count(), 1);
+    assert_eq!(interval.split(Duration::MAX).count(), 1);
+    assert_eq!(interval.split(1.nanoseconds()).take(5).count(), 5);
+
+    let empty = Interval::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-01 0:00 UTC));
+    assert_eq!(empty.split(1.hours()).next(), None);
+}
+
+#[test]
+fn contains() {
+    let interval = Interval::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-01 1:00 UTC));
+    assert!(interval.contains(datetime!(2024-01-01 1:00 +1)));
+    assert!(interval.contains(datetime!(2024-01-01 0:59:59.999_999_999 UTC)));
+    assert!(!interval.contains(datetime!(2024-01-01 1:00 UTC)));
+    assert!(!interval.contains(datetime!(2023-12-31 23:59:59.999_999_999 UTC)));
+    assert_eq!(interval.duration(), 1.hours());
+}
+
+#[test]
+#[should_panic = "the end of an interval must not be before its start"]
+fn new_reversed() {
+    let _ = Interval::new(datetime!(2024-01-01 1:00 UTC), datetime!(2024-01-01 0:00 UTC));
+}
+
+#[test]
+#[should_panic = "the length of a sub-interval must be positive"]
+fn split_zero() {
+    let interval = Interval::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-01 1:00 UTC));
+    let _ = interval.split(Duration::ZERO);
+}
@@ -3,7 +3,7 @@ use core::num::NonZeroU16;
 use rstest::rstest;
 use time::format_description::modifier::*;
 use time::format_description::{BorrowedFormatItem, Component};
-use time::macros::{date, format_description, time};
+use time::macros::{date, datetime, format_description, offset, time, utc_datetime};
 use time::{Date, Time};
 
 #[rstest]
@@ -43,6 +43,31 @@ fn format_description_version() {
     );
 }
 
+#[rstest]
+fn concatenated_string() {
+    assert_eq!(
+        format_description!("[hour]", ":", "[minute]"),
+        format_description!("[hour]:[minute]")
+    );
+    assert_eq!(
+        format_description!("[hour]:", r"[minute]",),
+        format_description!("[hour]:[minute]")
+    );
+    assert_eq!(
+        format_description!(concat!("[year]-", "[month]")),
+        format_description!("[year]-[month]")
+    );
+    assert_eq!(
+        format_description!(concat!("[year]", concat!("-", "[month]")), " ", "[day]"),
+        format_description!("[year]-[month] [day]")
+    );
+    assert_eq!(
+        format_description!(version = 2, concat!("[hour]", r"\\"), "[minute]"),
+        format_description!(version = 2, r"[hour]\\[minute]")
+    );
+    assert!(format_description!(concat!()).is_empty());
+}
+
 #[rstest]
 fn nested_v1() {
     assert_eq!(
@@ -364,4 +389,119 @@ fn date_coverage() {
 fn time_coverage() {
     assert_eq!(time!(12 AM), Time::MIDNIGHT);
     assert_eq!(Ok(time!(12 PM)), Time::from_hms(12, 0, 0));
+    assert_eq!(
+        Ok(time!(0:00:01.123_456_789)),
+        Time::from_hms_nano(0, 0, 1, 123_456_789)
+    );
+    assert_eq!(
+        Ok(time!(0:00:59.999_999_999_000)),
+        Time::from_hms_nano(0, 0, 59, 999_999_999)
+    );
+    assert_eq!(Ok(time!(0:00:0_5.5)), Time::from_hms_milli(0, 0, 5, 500));
+}
+
+#[rstest]
+fn offset_expression() {
+    const HOURS: i8 = 5;
+    const fn minutes() -> i8 {
+        -30
+    }
+
+    assert_eq!(offset!(hours = HOURS), offset!(+5));
+    assert_eq!(offset!(hours = HOURS,), offset!(+5));
+    assert_eq!(offset!(hours = -HOURS, minutes = minutes()), offset!(-5:30));
+    assert_eq!(offset!(minutes = minutes(), hours = -1 - 1), offset!(-2:30));
+    assert_eq!(
+        offset!(hours = 1, minutes = 2, seconds = [3, 4][0]),
+        offset!(+1:02:03)
+    );
+    assert_eq!(
+        datetime!(2000-01-01 0:00 hours = -HOURS, minutes = minutes()),
+        datetime!(2000-01-01 0:00 -5:30)
+    );
+}
+
+#[rstest]
+fn nanosecond_expression() {
+    const NANOS: u32 = 5;
+
+    assert_eq!(time!(0:00, nanosecond = NANOS), time!(0:00:00.000_000_005));
+    assert_eq!(
+        time!(1:02:03 pm, nanosecond = NANOS * 100,),
+        time!(1:02:03.000_000_5 pm)
+    );
+    assert_eq!(
+        datetime!(2000-01-01 0:00, nanosecond = NANOS),
+        datetime!(2000-01-01 0:00:00.000_000_005)
+    );
+    assert_eq!(
+        datetime!(2000-01-01 0:00, nanosecond = NANOS, -5:30),
+        datetime!(2000-01-01 0:00:00.000_000_005 -5:30)
+    );
+    assert_eq!(
+        datetime!(2000-01-01 0:00, nanosecond = NANOS, UTC),
+        datetime!(2000-01-01 0:00:00.000_000_005 UTC)
+    );
+    assert_eq!(datetime!(2000-01-01 0:00, -5:30), datetime!(2000-01-01 0:00 -5:30));
+    assert_eq!(datetime!(2000-01-01 0:00, UTC), datetime!(2000-01-01 0:00 UTC));
+    assert_eq!(
+        datetime!(2000-01-01 0:00, nanosecond = [1, NANOS][1], hours = -5, minutes = -30),
+        datetime!(2000-01-01 0:00:00.000_000_005 -5:30)
+    );
+    assert_eq!(
+        utc_datetime!(2000-01-01 0:00, nanosecond = NANOS),
+        utc_datetime!(2000-01-01 0:00:00.000_000_005)
+    );
+}
+
+mod reexport {
+    pub(crate) use ::time::*;
+}
+
+#[rstest]
+fn crate_path() {
+    assert_eq!(date!(crate = reexport, 2020-01-01), date!(2020-01-01));
+    assert_eq!(time!(crate = reexport, 12:34:56), time!(12:34:56));
+    assert_eq!(offset!(crate = reexport, +1:02:03), offset!(+1:02:03));
+    assert_eq!(
+        datetime!(crate = reexport, 2020-01-01 0:00 +1),
+        datetime!(2020-01-01 0:00 +1)
+    );
+    assert_eq!(
+        utc_datetime!(crate = reexport, 2020-01-01 0:00),
+        utc_datetime!(2020-01-01 0:00)
+    );
+    assert_eq!(
+        format_description!(crate = reexport, "[year]"),
+        format_description!("[year]")
+    );
+    assert_eq!(
+        format_description!(crate = reexport, version = 2, "[year]"),
+        format_description!(version = 2, "[year]")
+    );
+}
+
+#[rstest]
+fn crate_path_in_macro_rules() {
+    macro_rules! wrapped {
+        ($macro:ident!($($input:tt)*)) => {
+            ::time::macros::$macro!(crate = $crate::macros::reexport, $($input)*)
+        };
+    }
+
+    assert_eq!(wrapped!(date!(2020-01-01)), date!(2020-01-01));
+    assert_eq!(wrapped!(time!(12:34:56)), time!(12:34:56));
+    assert_eq!(wrapped!(offset!(hours = 1)), offset!(+1));
+    assert_eq!(
+        wrapped!(datetime!(2020-01-01 0:00 +1)),
+        datetime!(2020-01-01 0:00 +1)
+    );
+    assert_eq!(
+        wrapped!(utc_datetime!(2020-01-01 0:00)),
+        utc_datetime!(2020-01-01 0:00)
+    );
+    assert_eq!(
+        wrapped!(format_description!("[year] [ignore count:2]")),
+        format_description!("[year] [ignore count:2]")
+    );
 }
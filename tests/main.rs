@@ -9,6 +9,10 @@
 #[cfg(not(all(
     feature = "default",
     feature = "alloc",
+    feature = "bench-hooks",
+    feature = "borsh",
+    feature = "chrono-interop",
+    feature = "compat-0-2",
     feature = "formatting",
     feature = "large-dates",
     feature = "local-offset",
@@ -18,7 +22,10 @@
     feature = "serde-human-readable",
     feature = "serde-well-known",
     feature = "std",
+    feature = "test-util",
+    feature = "tracing",
     feature = "rand",
+    feature = "rkyv",
     feature = "serde",
 )))]
 #[test]
@@ -55,6 +62,10 @@ macro_rules! require_all_features {
         #[cfg(all(
             feature = "default",
             feature = "alloc",
+            feature = "bench-hooks",
+            feature = "borsh",
+            feature = "chrono-interop",
+            feature = "compat-0-2",
             feature = "formatting",
             feature = "large-dates",
             feature = "local-offset",
@@ -64,7 +75,10 @@ macro_rules! require_all_features {
             feature = "serde-human-readable",
             feature = "serde-well-known",
             feature = "std",
+            feature = "test-util",
+            feature = "tracing",
             feature = "rand",
+            feature = "rkyv",
             feature = "serde",
         ))]
         $x
@@ -111,6 +125,11 @@ require_all_features! {
         }}
     }
 
+    mod bench_hooks;
+    mod borsh;
+    mod chrono;
+    mod clock;
+    mod compat_0_2;
     mod date;
     mod derives;
     mod duration;
@@ -119,6 +138,7 @@ require_all_features! {
     mod format_description;
     mod formatting;
     mod instant;
+    mod interval;
     mod macros;
     mod meta;
     mod month;
@@ -130,12 +150,23 @@ require_all_features! {
     #[path = "quickcheck.rs"]
     mod quickcheck_mod;
     mod rand;
+    mod rkyv;
+    mod schedule;
     mod serde;
     mod serde_helpers;
+    mod test_util;
     mod time;
+    mod time_zone;
+    mod tracing;
     mod utc_date_time;
     mod utc_offset;
     mod util;
+    #[cfg(all(
+        target_family = "wasm",
+        not(any(target_os = "emscripten", target_os = "wasi")),
+        feature = "wasm-bindgen"
+    ))]
+    mod wasm_instant;
     mod weekday;
 
     #[cfg(__ui_tests)]
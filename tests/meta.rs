@@ -16,7 +16,7 @@ use serde::{Deserialize, Serialize};
 use time::format_description::well_known::iso8601;
 use time::format_description::{modifier, well_known, BorrowedFormatItem, Component};
 use time::formatting::Formattable;
-use time::parsing::{Parsable, Parsed};
+use time::parsing::{Parsable, Parsed, TryInOrder};
 #[allow(deprecated)]
 use time::Instant;
 use time::{
@@ -65,12 +65,15 @@ fn alignment() {
     assert_alignment!(modifier::WeekNumber, 1);
     assert_alignment!(modifier::Weekday, 1);
     assert_alignment!(modifier::Year, 1);
+    assert_alignment!(well_known::CommonFormat, 1);
+    assert_alignment!(well_known::HttpDate, 1);
     assert_alignment!(well_known::Rfc2822, 1);
     assert_alignment!(well_known::Rfc3339, 1);
     assert_alignment!(
         well_known::Iso8601<{ iso8601::Config::DEFAULT.encode() }>,
         1
     );
+    assert_alignment!(well_known::Iso8601Runtime, 1);
     assert_alignment!(iso8601::Config, 1);
     assert_alignment!(iso8601::DateKind, 1);
     assert_alignment!(iso8601::FormattedComponents, 1);
@@ -80,6 +83,7 @@ fn alignment() {
     assert_alignment!(Month, 1);
     assert_alignment!(Weekday, 1);
     assert_alignment!(Error, 8);
+    assert_alignment!(error::Expected, 8);
     assert_alignment!(error::Format, 8);
     assert_alignment!(error::InvalidFormatDescription, 8);
     assert_alignment!(error::Parse, 8);
@@ -126,11 +130,11 @@ fn size() {
     assert_size!(UtcDateTime, 12, 12);
     assert_size!(Time, 8, 8);
     assert_size!(UtcOffset, 3, 4);
-    assert_size!(error::ComponentRange, 56, 56);
+    assert_size!(error::ComponentRange, 64, 64);
     assert_size!(error::ConversionRange, 0, 1);
     assert_size!(error::DifferentVariant, 0, 1);
     assert_size!(error::IndeterminateOffset, 0, 1);
-    assert_size!(modifier::Day, 1, 1);
+    assert_size!(modifier::Day, 2, 2);
     assert_size!(modifier::Hour, 2, 2);
     assert_size!(modifier::Minute, 1, 1);
     assert_size!(modifier::Month, 3, 3);
@@ -141,9 +145,11 @@ fn size() {
     assert_size!(modifier::Period, 2, 2);
     assert_size!(modifier::Second, 1, 1);
     assert_size!(modifier::Subsecond, 1, 1);
-    assert_size!(modifier::WeekNumber, 2, 2);
+    assert_size!(modifier::WeekNumber, 3, 3);
     assert_size!(modifier::Weekday, 3, 3);
-    assert_size!(modifier::Year, 5, 5);
+    assert_size!(modifier::Year, 8, 8);
+    assert_size!(well_known::CommonFormat, 1, 1);
+    assert_size!(well_known::HttpDate, 0, 1);
     assert_size!(well_known::Rfc2822, 0, 1);
     assert_size!(well_known::Rfc3339, 0, 1);
     assert_size!(
@@ -151,7 +157,8 @@ fn size() {
         0,
         1
     );
-    assert_size!(iso8601::Config, 7, 7);
+    assert_size!(well_known::Iso8601Runtime, 10, 10);
+    assert_size!(iso8601::Config, 10, 10);
     assert_size!(iso8601::DateKind, 1, 1);
     assert_size!(iso8601::FormattedComponents, 1, 1);
     assert_size!(iso8601::OffsetPrecision, 1, 1);
@@ -159,13 +166,14 @@ fn size() {
     assert_size!(Parsed, 64, 64);
     assert_size!(Month, 1, 1);
     assert_size!(Weekday, 1, 1);
-    assert_size!(Error, 64, 64);
+    assert_size!(Error, 72, 72);
+    assert_size!(error::Expected, 24, 24);
     assert_size!(error::Format, 24, 24);
     assert_size!(error::InvalidFormatDescription, 48, 48);
     assert_size!(error::Parse, 64, 64);
-    assert_size!(error::ParseFromDescription, 24, 24);
-    assert_size!(error::TryFromParsed, 56, 64);
-    assert_size!(Component, 6, 6); // TODO Size is 4 starting with rustc 1.71.
+    assert_size!(error::ParseFromDescription, 32, 32);
+    assert_size!(error::TryFromParsed, 64, 64);
+    assert_size!(Component, 8, 8); // The year modifier alone is eight bytes.
     assert_size!(BorrowedFormatItem<'_>, 24, 24);
     assert_size!(modifier::MonthRepr, 1, 1);
     assert_size!(modifier::Padding, 1, 1);
@@ -713,6 +721,35 @@ assert_impl! { modifier::Year:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { well_known::CommonFormat:
+    Clone,
+    Debug,
+    Hash,
+    PartialEq<well_known::CommonFormat>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { well_known::HttpDate:
+    Clone,
+    Debug,
+    PartialEq<well_known::HttpDate>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { well_known::Rfc2822:
     Clone,
     Debug,
@@ -755,8 +792,28 @@ assert_impl! { well_known::Iso8601::<{ iso8601::Config::DEFAULT.encode() }>:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { well_known::Iso8601Runtime:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<well_known::Iso8601Runtime>,
+    Copy,
+    Eq,
+    From<well_known::Iso8601<{ iso8601::Config::DEFAULT.encode() }>>,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { iso8601::Config:
+    Clone,
     Debug,
+    PartialEq<iso8601::Config>,
+    Copy,
+    Eq,
     RefUnwindSafe,
     Send,
     Sync,
@@ -821,6 +878,20 @@ assert_impl! { Parsed:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { TryInOrder<(well_known::CommonFormat, well_known::CommonFormat)>:
+    Clone,
+    Debug,
+    Hash,
+    PartialEq<TryInOrder<(well_known::CommonFormat, well_known::CommonFormat)>>,
+    Copy,
+    Eq,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { @'a; Month:
     Arbitrary,
     Clone,
@@ -913,6 +984,20 @@ assert_impl! { error::Parse:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { error::Expected:
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq<error::Expected>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { error::ParseFromDescription:
     Clone,
     Debug,
@@ -1124,3 +1209,142 @@ assert_impl! { f32:
 assert_impl! { f64:
     Mul<Duration>,
 }
+
+/// Every public type must be usable across threads and unwind boundaries. These bounds matter for
+/// asynchronous code in particular, so they must not change accidentally, such as by adding a
+/// field that contains an `Rc`.
+///
+/// The only exceptions are `Error` and `error::Format`, which may contain a `std::io::Error` and
+/// are therefore not unwind safe. They are asserted to be `Send`, `Sync`, and `Unpin` above.
+macro_rules! assert_thread_safe {
+    ($($(#[$meta:meta])* $type:ty),+ $(,)?) => {$(
+        assert_impl! { $(#[$meta])* $type: Send, Sync, Unpin, RefUnwindSafe, UnwindSafe }
+    )+};
+}
+
+assert_thread_safe! {
+    Date,
+    Duration,
+    #[allow(deprecated)]
+    Instant,
+    Month,
+    OffsetDateTime,
+    PrimitiveDateTime,
+    Time,
+    UtcDateTime,
+    UtcOffset,
+    Weekday,
+    time::Backoff,
+    time::CalendarDate,
+    time::DisplaySeconds,
+    time::HumanDuration,
+    time::Iso8601Duration,
+    time::Iso8601SignPlacement,
+    time::IsoWeekDate,
+    time::Rounding,
+    time::bench_hooks::Counters,
+    time::clock::FixedClock,
+    time::clock::SystemClock,
+    time::convert::Nanosecond,
+    time::convert::Microsecond,
+    time::convert::Millisecond,
+    time::convert::Second,
+    time::convert::Minute,
+    time::convert::Hour,
+    time::convert::Day,
+    time::convert::Week,
+    error::ComponentRange,
+    error::ComponentRanges,
+    error::ConversionRange,
+    error::DifferentVariant,
+    error::Expected,
+    error::IndeterminateOffset,
+    error::InvalidDuration,
+    error::InvalidFormatDescription,
+    error::InvalidLocalTime,
+    error::InvalidOffset,
+    error::InvalidSchedule,
+    error::InvalidVariant,
+    error::Parse,
+    error::ParseFromDescription,
+    error::TryFromParsed,
+    BorrowedFormatItem<'static>,
+    Component,
+    time::format_description::Custom<well_known::Rfc3339>,
+    time::format_description::OwnedFormatItem,
+    modifier::Day,
+    modifier::End,
+    modifier::Hour,
+    modifier::Ignore,
+    modifier::Minute,
+    modifier::Month,
+    modifier::MonthRepr,
+    modifier::OffsetHour,
+    modifier::OffsetMinute,
+    modifier::OffsetSecond,
+    modifier::Ordinal,
+    modifier::Padding,
+    modifier::Period,
+    modifier::Second,
+    modifier::Subsecond,
+    modifier::SubsecondDigits,
+    modifier::UnixTimestamp,
+    modifier::UnixTimestampPrecision,
+    modifier::WeekNumber,
+    modifier::WeekNumberRepr,
+    modifier::Weekday,
+    modifier::WeekdayRepr,
+    modifier::Year,
+    modifier::YearGroupSeparator,
+    modifier::YearNotation,
+    modifier::YearRange,
+    modifier::YearRepr,
+    modifier::YearWidth,
+    well_known::CommonFormat,
+    well_known::HttpDate,
+    well_known::Iso8601,
+    well_known::Iso8601Runtime,
+    well_known::Rfc2822,
+    well_known::Rfc3339,
+    well_known::Rfc3339Lenient,
+    well_known::Rfc9557,
+    iso8601::Config,
+    iso8601::DateKind,
+    iso8601::FormattedComponents,
+    iso8601::OffsetPrecision,
+    iso8601::TimePrecision,
+    well_known::rfc9557::Annotations,
+    time::formatting::BoxedFormat<'static>,
+    time::formatting::ComponentWriter<Vec<u8>>,
+    time::formatting::English,
+    time::formatting::Localized<well_known::Rfc3339, time::formatting::English>,
+    time::interval::Interval,
+    time::interval::SampleEvenly,
+    time::interval::Split,
+    Parsed,
+    time::parsing::ParserOptions,
+    time::parsing::WeekdayMode,
+    TryInOrder<(well_known::Rfc3339, well_known::Rfc2822)>,
+    time::rand::UniformDate,
+    time::rand::UniformDuration,
+    time::rand::UniformOffsetDateTime,
+    time::rand::UniformPrimitiveDateTime,
+    time::rand::UniformTime,
+    time::rkyv::ArchivedDate,
+    time::rkyv::ArchivedDuration,
+    time::rkyv::ArchivedOffsetDateTime,
+    time::rkyv::ArchivedPrimitiveDateTime,
+    time::rkyv::ArchivedTime,
+    time::rkyv::ArchivedUtcDateTime,
+    time::rkyv::ArchivedUtcOffset,
+    time::schedule::Occurrences,
+    time::schedule::Schedule,
+    time::test_util::MockClock,
+    time::time_zone::DstRules,
+    time::time_zone::LocalResult,
+    time::time_zone::SimpleTz,
+    time::time_zone::TransitionRule,
+    time::tracing::Timestamp<well_known::Rfc3339>,
+    #[allow(deprecated)]
+    time::util::local_offset::Soundness,
+}
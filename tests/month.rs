@@ -131,7 +131,22 @@ fn display(#[case] month: Month, #[case] expected: &str) {
 #[case("October", Ok(October))]
 #[case("November", Ok(November))]
 #[case("December", Ok(December))]
+#[case("january", Ok(January))]
+#[case("FEBRUARY", Ok(February))]
+#[case("Mar", Ok(March))]
+#[case("apr", Ok(April))]
+#[case("MAY", Ok(May))]
+#[case("jUn", Ok(June))]
+#[case("Jul", Ok(July))]
+#[case("aug", Ok(August))]
+#[case("SEP", Ok(September))]
+#[case("Oct", Ok(October))]
+#[case("nov", Ok(November))]
+#[case("dec", Ok(December))]
 #[case("foo", Err(time::error::InvalidVariant))]
+#[case("Ja", Err(time::error::InvalidVariant))]
+#[case("Janu", Err(time::error::InvalidVariant))]
+#[case("", Err(time::error::InvalidVariant))]
 fn from_str(#[case] s: &str, #[case] expected: Result<Month, time::error::InvalidVariant>) {
     assert_eq!(s.parse::<Month>(), expected);
 }
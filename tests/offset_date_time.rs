@@ -3,7 +3,9 @@ use std::time::{Duration as StdDuration, SystemTime};
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, offset, time, utc_datetime};
-use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Weekday};
+use time::{
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset, Weekday,
+};
 
 #[test]
 fn new_utc() {
@@ -21,6 +23,33 @@ fn new_in_offset() {
     assert_eq!(dt.offset().whole_hours(), -4);
 }
 
+#[test]
+fn const_construction() {
+    /// Unwrap a result in a `const` context.
+    macro_rules! const_unwrap {
+        ($e:expr) => {
+            match $e {
+                Ok(value) => value,
+                Err(_) => panic!("invalid component"),
+            }
+        };
+    }
+
+    const DATE_TIME: OffsetDateTime = {
+        let date = const_unwrap!(Date::from_iso_week_date(2020, 53, Weekday::Friday));
+        let time = const_unwrap!(Time::from_hms_nano(12, 59, 59, 500_000_000));
+        let offset = const_unwrap!(UtcOffset::from_hms(-5, 0, 0));
+        PrimitiveDateTime::new(date, time).assume_offset(offset)
+    };
+    const JULIAN_DAY: OffsetDateTime = {
+        let date = const_unwrap!(Date::from_julian_day(2_459_216));
+        const_unwrap!(date.with_hms(12, 59, 59)).assume_utc()
+    };
+
+    assert_eq!(DATE_TIME, datetime!(2021-01-01 12:59:59.5 -5));
+    assert_eq!(JULIAN_DAY, datetime!(2021-01-01 12:59:59 UTC));
+}
+
 #[test]
 fn now_utc() {
     assert!(OffsetDateTime::now_utc().year() >= 2019);
@@ -148,6 +177,40 @@ fn from_unix_timestamp_nanos() {
     assert!(OffsetDateTime::from_unix_timestamp_nanos(i128::MAX).is_err());
 }
 
+#[test]
+fn from_unix_timestamp_millis() {
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_millis(0),
+        Ok(OffsetDateTime::UNIX_EPOCH),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_millis(1_546_300_800_123),
+        Ok(datetime!(2019-01-01 0:00:00.123 UTC)),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_millis(-1),
+        Ok(datetime!(1969-12-31 23:59:59.999 UTC)),
+    );
+    assert!(OffsetDateTime::from_unix_timestamp_millis(i64::MAX).is_err());
+    assert!(OffsetDateTime::from_unix_timestamp_millis(i64::MIN).is_err());
+}
+
+#[test]
+fn from_unix_timestamp_micros() {
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_micros(0),
+        Ok(OffsetDateTime::UNIX_EPOCH),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_micros(1_546_300_800_123_456),
+        Ok(datetime!(2019-01-01 0:00:00.123_456 UTC)),
+    );
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_micros(-1),
+        Ok(datetime!(1969-12-31 23:59:59.999_999 UTC)),
+    );
+}
+
 #[test]
 fn offset() {
     assert_eq!(datetime!(2019-01-01 0:00 UTC).offset(), offset!(UTC));
@@ -183,6 +246,46 @@ fn unix_timestamp_nanos() {
     );
 }
 
+#[test]
+fn unix_timestamp_millis() {
+    assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_millis(), 0);
+    assert_eq!(
+        datetime!(1970-01-01 1:00 UTC)
+            .to_offset(offset!(-1))
+            .unix_timestamp_millis(),
+        3_600_000,
+    );
+    assert_eq!(
+        datetime!(1969-12-31 23:59:59.999_9 UTC).unix_timestamp_millis(),
+        -1
+    );
+    let max = OffsetDateTime::new_utc(Date::MAX, Time::MAX);
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_millis(max.unix_timestamp_millis()),
+        Ok(max.replace_nanosecond(999_000_000).expect("valid nanosecond"))
+    );
+    let min = OffsetDateTime::new_utc(Date::MIN, Time::MIDNIGHT);
+    assert_eq!(
+        OffsetDateTime::from_unix_timestamp_millis(min.unix_timestamp_millis()),
+        Ok(min)
+    );
+}
+
+#[test]
+fn unix_timestamp_micros() {
+    assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_micros(), 0);
+    assert_eq!(
+        datetime!(1970-01-01 1:00 UTC)
+            .to_offset(offset!(-1))
+            .unix_timestamp_micros(),
+        3_600_000_000,
+    );
+    assert_eq!(
+        datetime!(1969-12-31 23:59:59.999_999_9 UTC).unix_timestamp_micros(),
+        -1
+    );
+}
+
 #[test]
 fn date() {
     assert_eq!(datetime!(2019-01-01 0:00 UTC).date(), date!(2019-01-01));
@@ -201,6 +304,28 @@ fn time() {
     );
 }
 
+#[test]
+fn as_tuple() {
+    assert_eq!(
+        datetime!(2019-01-01 0:00 UTC).as_tuple(),
+        (date!(2019-01-01), time!(0:00), offset!(UTC))
+    );
+    assert_eq!(
+        datetime!(2019-01-01 0:00 UTC).to_offset(offset!(-1)).as_tuple(),
+        (date!(2018-12-31), time!(23:00), offset!(-1))
+    );
+}
+
+#[test]
+fn from_tuple() {
+    assert_eq!(
+        OffsetDateTime::from((date!(2019-01-01), time!(0:00), offset!(-1))),
+        datetime!(2019-01-01 0:00 -1)
+    );
+    let datetime = datetime!(2019-10-04 12:34:56.789 +5:30);
+    assert_eq!(OffsetDateTime::from(datetime.as_tuple()), datetime);
+}
+
 #[test]
 fn year() {
     assert_eq!(datetime!(2019-01-01 0:00 UTC).year(), 2019);
@@ -224,6 +349,17 @@ fn month() {
     );
 }
 
+#[test]
+fn month0() {
+    assert_eq!(datetime!(2019-01-01 0:00 UTC).month0(), 0);
+    assert_eq!(
+        datetime!(2019-12-31 23:00 UTC)
+            .to_offset(offset!(+1))
+            .month0(),
+        0,
+    );
+}
+
 #[test]
 fn day() {
     assert_eq!(datetime!(2019-01-01 0:00 UTC).day(), 1);
@@ -244,6 +380,50 @@ fn ordinal() {
     );
 }
 
+#[test]
+fn ordinal0() {
+    assert_eq!(datetime!(2019-01-01 0:00 UTC).ordinal0(), 0);
+    assert_eq!(
+        datetime!(2019-12-31 23:00 UTC)
+            .to_offset(offset!(+1))
+            .ordinal0(),
+        0,
+    );
+}
+
+#[test]
+fn is_leap_year() {
+    assert!(datetime!(2020-01-01 0:00 UTC).is_leap_year());
+    assert!(!datetime!(2019-12-31 23:00 UTC).is_leap_year());
+    assert!(datetime!(2019-12-31 23:00 UTC)
+        .to_offset(offset!(+1))
+        .is_leap_year());
+}
+
+#[test]
+fn min_max() {
+    assert_eq!(OffsetDateTime::MIN.date(), Date::MIN);
+    assert_eq!(OffsetDateTime::MIN.time(), Time::MIDNIGHT);
+    assert_eq!(OffsetDateTime::MIN.offset(), UtcOffset::UTC);
+    assert_eq!(OffsetDateTime::MAX.date(), Date::MAX);
+    assert_eq!(OffsetDateTime::MAX.time(), Time::MAX);
+    assert_eq!(OffsetDateTime::MAX.offset(), UtcOffset::UTC);
+
+    assert_eq!(OffsetDateTime::MIN, PrimitiveDateTime::MIN.assume_utc());
+    assert_eq!(OffsetDateTime::MAX, PrimitiveDateTime::MAX.assume_utc());
+
+    assert_eq!(OffsetDateTime::MIN.to_utc(), UtcDateTime::MIN);
+    assert_eq!(OffsetDateTime::MAX.to_utc(), UtcDateTime::MAX);
+    assert_eq!(
+        OffsetDateTime::MIN.checked_to_offset(offset!(UTC)),
+        Some(OffsetDateTime::MIN)
+    );
+    assert_eq!(
+        OffsetDateTime::MAX.checked_to_offset(offset!(UTC)),
+        Some(OffsetDateTime::MAX)
+    );
+}
+
 #[test]
 fn iso_week() {
     assert_eq!(datetime!(2019-01-01 0:00 UTC).iso_week(), 1);
@@ -326,11 +506,45 @@ fn to_julian_day() {
     assert_eq!(datetime!(2019-12-31 0:00 UTC).to_julian_day(), 2_458_849);
 }
 
+#[test]
+fn from_julian_day() {
+    assert_eq!(
+        OffsetDateTime::from_julian_day(2_451_545, time!(12:00), offset!(-5)),
+        Ok(datetime!(2000-01-01 12:00 -5))
+    );
+    assert!(OffsetDateTime::from_julian_day(i32::MAX, time!(0:00), offset!(UTC)).is_err());
+}
+
+#[test]
+fn from_julian_date() {
+    assert_eq!(
+        OffsetDateTime::from_julian_date(2_451_545.0),
+        Ok(datetime!(2000-01-01 12:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(2_451_544.75),
+        Ok(datetime!(2000-01-01 6:00 UTC))
+    );
+    assert!(OffsetDateTime::from_julian_date(f64::NAN).is_err());
+}
+
+#[test]
+fn to_julian_date() {
+    assert_eq!(datetime!(2000-01-01 12:00 UTC).to_julian_date(), 2_451_545.0);
+    assert_eq!(datetime!(2000-01-01 18:00 +6).to_julian_date(), 2_451_545.0);
+    assert_eq!(datetime!(2000-01-01 0:00 -12).to_julian_date(), 2_451_545.0);
+}
+
 #[test]
 fn to_hms() {
     assert_eq!(datetime!(2020-01-01 1:02:03 UTC).to_hms(), (1, 2, 3));
 }
 
+#[test]
+fn to_hm() {
+    assert_eq!(datetime!(2020-01-01 1:02:03 UTC).to_hm(), (1, 2));
+}
+
 #[test]
 fn to_hms_milli() {
     assert_eq!(
@@ -1076,6 +1290,37 @@ fn to_std() {
     );
 }
 
+#[test]
+fn try_from_std() {
+    assert_eq!(
+        OffsetDateTime::try_from_system_time(SystemTime::UNIX_EPOCH - 1.std_days()),
+        Ok(OffsetDateTime::UNIX_EPOCH - 1.days())
+    );
+    assert_eq!(
+        OffsetDateTime::saturating_from_system_time(SystemTime::UNIX_EPOCH + 1.std_days()),
+        OffsetDateTime::UNIX_EPOCH + 1.days()
+    );
+    if let Some(system_time) = SystemTime::UNIX_EPOCH.checked_add(StdDuration::from_secs(1 << 62)) {
+        assert!(OffsetDateTime::try_from_system_time(system_time).is_err());
+        assert_eq!(
+            OffsetDateTime::saturating_from_system_time(system_time),
+            PrimitiveDateTime::MAX.assume_utc()
+        );
+    }
+}
+
+#[test]
+fn try_to_std() {
+    assert_eq!(
+        datetime!(1970-01-01 1:00 +1).try_to_system_time(),
+        Ok(SystemTime::UNIX_EPOCH)
+    );
+    assert_eq!(
+        datetime!(1969-12-31 0:00 UTC).try_to_system_time(),
+        Ok(SystemTime::UNIX_EPOCH - 1.std_days())
+    );
+}
+
 #[test]
 fn checked_add_duration() {
     // Successful addition
@@ -1226,6 +1471,155 @@ fn checked_sub_duration() {
     );
 }
 
+#[test]
+fn checked_add_std() {
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45 +1).checked_add_std(10.std_hours()),
+        Some(datetime!(2021-10-26 00:01:53.45 +1))
+    );
+    assert_eq!(
+        datetime!(+999_999 - 12 - 31 23:59:59.999_999_999 -10:00)
+            .checked_add_std(1.std_nanoseconds()),
+        None
+    );
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45 UTC).checked_add_std(StdDuration::MAX),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_std() {
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45 +1).checked_sub_std(15.std_hours()),
+        Some(datetime!(2021-10-24 23:01:53.45 +1))
+    );
+    assert_eq!(
+        datetime!(-999_999 - 01 - 01 0:00 +10:00).checked_sub_std(1.std_nanoseconds()),
+        None
+    );
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45 UTC).checked_sub_std(StdDuration::MAX),
+        None
+    );
+}
+
+#[test]
+fn truncate_to() {
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45 +1).truncate_to(1.seconds()),
+        datetime!(2021-10-25 14:01:53 +1)
+    );
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45 +5:45).truncate_to(1.hours()),
+        datetime!(2021-10-25 14:00 +5:45)
+    );
+}
+
+#[test]
+fn round_to() {
+    assert_eq!(
+        datetime!(2021-10-25 14:07:30 -1).round_to(15.minutes()),
+        datetime!(2021-10-25 14:15 -1)
+    );
+    assert_eq!(
+        datetime!(2021-12-31 23:59:30 +5:45).round_to(1.minutes()),
+        datetime!(2022-01-01 0:00 +5:45)
+    );
+}
+
+#[test]
+fn to_offset_truncated() {
+    let value =
+        datetime!(2021-10-25 14:01:53.45 +5:45).to_offset_truncated(offset!(+5:30), 1.hours());
+    assert_eq!(value, datetime!(2021-10-25 13:00 +5:30));
+    assert_eq!(value.offset(), offset!(+5:30));
+}
+
+#[test]
+fn to_offset_rounded() {
+    let value =
+        datetime!(2021-10-25 14:01:53.45 +5:45).to_offset_rounded(offset!(+5:30), 1.hours());
+    assert_eq!(value, datetime!(2021-10-25 14:00 +5:30));
+    assert_eq!(value.offset(), offset!(+5:30));
+    assert_eq!(
+        datetime!(2021-12-31 18:29:30 UTC).to_offset_rounded(offset!(+5:30), 1.minutes()),
+        datetime!(2022-01-01 0:00 +5:30)
+    );
+}
+
+#[test]
+fn calendar_days_between() {
+    let start = datetime!(2024-03-01 0:00 UTC);
+    assert_eq!(start.calendar_days_between(start), 0);
+    assert_eq!(start.calendar_days_between(datetime!(2024-03-01 23:59 UTC)), 0);
+    assert_eq!(start.calendar_days_between(datetime!(2024-02-28 23:59 UTC)), -2);
+    assert_eq!(start.calendar_days_between(datetime!(2025-03-01 0:00 UTC)), 365);
+    // The date of `other` is determined in the offset of `self`.
+    assert_eq!(start.calendar_days_between(datetime!(2024-03-02 0:30 +1)), 0);
+    assert_eq!(
+        datetime!(2024-03-01 0:00 +1).calendar_days_between(datetime!(2024-02-29 22:30 UTC)),
+        -1
+    );
+    // The date of `other` need not be representable in the offset of `self`.
+    assert_eq!(
+        datetime!(-999999-01-01 0:00 +23:59)
+            .calendar_days_between(datetime!(+999999-12-31 23:59 UTC)),
+        Date::MAX.to_julian_day() - Date::MIN.to_julian_day() + 1
+    );
+}
+
+#[test]
+fn whole_days_between() {
+    let start = datetime!(2024-03-01 12:00 UTC);
+    assert_eq!(start.whole_days_between(datetime!(2024-03-02 11:59 UTC)), 0);
+    assert_eq!(start.whole_days_between(datetime!(2024-03-02 12:00 UTC)), 1);
+    assert_eq!(start.whole_days_between(datetime!(2024-03-02 12:00 +1)), 0);
+    assert_eq!(start.whole_days_between(datetime!(2024-02-28 12:00 UTC)), -2);
+}
+
+#[test]
+fn whole_hours_between() {
+    let start = datetime!(2024-03-01 12:00 UTC);
+    assert_eq!(start.whole_hours_between(start), 0);
+    assert_eq!(start.whole_hours_between(datetime!(2024-03-01 12:59 UTC)), 0);
+    assert_eq!(start.whole_hours_between(datetime!(2024-03-02 12:00 UTC)), 24);
+    assert_eq!(start.whole_hours_between(datetime!(2024-03-01 13:00 +1)), 0);
+    assert_eq!(start.whole_hours_between(datetime!(2024-03-01 10:01 UTC)), -1);
+}
+
+#[test]
+fn months_between() {
+    let start = datetime!(2024-01-15 12:00 UTC);
+    assert_eq!(start.months_between(start), 0);
+    assert_eq!(start.months_between(datetime!(2024-02-15 11:59 UTC)), 0);
+    assert_eq!(start.months_between(datetime!(2024-02-15 12:00 UTC)), 1);
+    assert_eq!(start.months_between(datetime!(2025-01-15 12:00 UTC)), 12);
+    assert_eq!(start.months_between(datetime!(2023-12-15 12:01 UTC)), 0);
+    assert_eq!(start.months_between(datetime!(2023-12-15 12:00 UTC)), -1);
+    assert_eq!(start.months_between(datetime!(2022-01-01 0:00 UTC)), -24);
+
+    // `other` is converted to the offset of `self`.
+    assert_eq!(start.months_between(datetime!(2024-02-15 12:30 +1)), 0);
+    assert_eq!(start.months_between(datetime!(2024-02-15 11:30 -1)), 1);
+
+    // The last day of a month does not complete a month begun on a later day.
+    let end_of_month = datetime!(2024-01-31 0:00 UTC);
+    assert_eq!(end_of_month.months_between(datetime!(2024-02-29 0:00 UTC)), 0);
+    assert_eq!(end_of_month.months_between(datetime!(2024-03-01 0:00 UTC)), 1);
+    assert_eq!(
+        datetime!(2024-03-31 0:00 UTC).months_between(datetime!(2024-02-29 0:00 UTC)),
+        -1
+    );
+
+    assert_eq!(
+        PrimitiveDateTime::MIN
+            .assume_utc()
+            .months_between(PrimitiveDateTime::MAX.assume_utc()),
+        1_999_998 * 12 + 11
+    );
+}
+
 #[test]
 fn saturating_add_duration() {
     assert_eq!(
@@ -1299,3 +1693,28 @@ fn saturating_sub_duration() {
 fn issue_621() {
     let _ = OffsetDateTime::UNIX_EPOCH + StdDuration::from_secs(18_157_382_926_370_278_155);
 }
+
+#[test]
+fn try_from_components() {
+    assert_eq!(
+        OffsetDateTime::try_from((2021, 1, 2, 3, 4, 5, 6, -3_600)),
+        Ok(datetime!(2021-01-02 03:04:05.000_000_006 -1))
+    );
+    assert_eq!(
+        OffsetDateTime::try_from((2021, 1, 2, 3, 4, 5, 6, 0)),
+        Ok(datetime!(2021-01-02 03:04:05.000_000_006 UTC))
+    );
+
+    let err = OffsetDateTime::try_from((2021, 4, 31, 0, 0, 0, 0, 100_000))
+        .expect_err("day and offset are invalid");
+    assert_eq!(
+        err.iter().map(|err| err.name()).collect::<Vec<_>>(),
+        ["day", "seconds"]
+    );
+    assert_eq!(
+        OffsetDateTime::try_from((2021, 1, 1, 0, 0, 0, 0, -100_000))
+            .expect_err("offset is invalid")
+            .errors(),
+        [UtcOffset::from_whole_seconds(-100_000).expect_err("offset is invalid")]
+    );
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::too_many_arguments)] // tests take one argument per modifier
+
 use core::num::NonZeroU16;
 
 use rstest::rstest;
@@ -35,6 +37,11 @@ fn modifiers(
         (Padding::None, "padding:none"),
     )]
     padding: _,
+    #[values(
+        (false, "ordinal_suffix:false"),
+        (true, "ordinal_suffix:true"),
+    )]
+    day_ordinal_suffix: _,
     #[values(
         (false, "repr:24"),
         (true, "repr:12"),
@@ -77,6 +84,11 @@ fn modifiers(
         (WeekNumberRepr::Monday, "repr:monday"),
     )]
     week_number_repr: _,
+    #[values(
+        (false, "weekday:required"),
+        (true, "weekday:optional"),
+    )]
+    week_number_weekday_is_optional: _,
     #[values(
         (YearRepr::Full, "repr:full"),
         (YearRepr::Century, "repr:century"),
@@ -98,6 +110,26 @@ fn modifiers(
         (true, "sign:mandatory"),
     )]
     sign_is_mandatory: _,
+    #[values(
+        (YearWidth::Automatic, "width:auto"),
+        (YearWidth::Four, "width:4"),
+        (YearWidth::Five, "width:5"),
+        (YearWidth::Six, "width:6"),
+    )]
+    year_width: _,
+    #[values(
+        (YearGroupSeparator::None, "group_separator:none"),
+        (YearGroupSeparator::Underscore, "group_separator:underscore"),
+        (YearGroupSeparator::Comma, "group_separator:comma"),
+        (YearGroupSeparator::Period, "group_separator:period"),
+        (YearGroupSeparator::Space, "group_separator:space"),
+    )]
+    year_group_separator: _,
+    #[values(
+        (YearNotation::Astronomical, "notation:astronomical"),
+        (YearNotation::Bce, "notation:bce"),
+    )]
+    year_notation: _,
     #[values(
         (true, "one_indexed:true"),
         (false, "one_indexed:false"),
@@ -135,6 +167,47 @@ fn empty() {
     );
 }
 
+#[rstest]
+fn versions() {
+    assert_eq!(format_description::DEFAULT_VERSION, 1);
+    assert_eq!(format_description::LATEST_VERSION, 2);
+
+    assert_eq!(
+        format_description::parse_v1("a[[b"),
+        Ok(vec![
+            BorrowedFormatItem::Literal(b"a"),
+            BorrowedFormatItem::Literal(b"["),
+            BorrowedFormatItem::Literal(b"b"),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_v1("a[[b"),
+        format_description::parse("a[[b")
+    );
+    assert!(format_description::parse_v2("a[[b").is_err());
+
+    assert_eq!(
+        format_description::parse_v2(r"a\[b"),
+        Ok(vec![
+            BorrowedFormatItem::Literal(b"a"),
+            BorrowedFormatItem::Literal(b"["),
+            BorrowedFormatItem::Literal(b"b"),
+        ])
+    );
+    assert_eq!(
+        format_description::parse_latest(r"a\[b"),
+        format_description::parse_borrowed::<{ format_description::LATEST_VERSION }>(r"a\[b")
+    );
+    assert_eq!(
+        format_description::parse_v2(r"a\[b"),
+        Ok(format_description!(version = 2, r"a\[b").to_vec())
+    );
+    assert_eq!(
+        format_description::parse_v1("a[[b"),
+        Ok(format_description!(version = 1, "a[[b").to_vec())
+    );
+}
+
 #[rstest]
 #[case("foo bar", [b"foo bar".as_slice()])]
 #[case("  leading spaces", [b"  leading spaces".as_slice()])]
@@ -200,8 +273,8 @@ fn errors() {
         "[ ", MissingComponentName { index: 1, .. },
         "[]", MissingComponentName { index: 0, .. },
         "[day sign:mandatory]", InvalidModifier { value, index: 5, .. } if value == "sign",
-        "[day sign:]", InvalidModifier { value, index: 9,.. } if value.is_empty(),
-        "[day :mandatory]", InvalidModifier { value, index: 5,.. } if value.is_empty(),
+        "[day sign:]", Expected { what: "modifier value", index: 9, .. },
+        "[day :mandatory]", Expected { what: "modifier key", index: 5, .. },
         "[day sign:mandatory", UnclosedOpeningBracket { index: 0, .. },
         "[day padding:invalid]", InvalidModifier { value, index: 13, .. } if value == "invalid",
         "[ignore]", MissingRequiredModifier { name: "count", index: 1, .. },
@@ -233,11 +306,14 @@ macro_rules! parse_with_modifiers {
 }
 
 #[apply(modifiers)]
-fn day_component(padding: M<Padding>) {
+fn day_component(padding: M<Padding>, day_ordinal_suffix: M<bool>) {
     assert_eq!(
-        parse_with_modifiers!("day", padding),
+        parse_with_modifiers!("day", padding, day_ordinal_suffix),
         Ok(vec![BorrowedFormatItem::Component(Component::Day(
-            modifier_m!(Day { padding })
+            modifier_m!(Day {
+                padding,
+                ordinal_suffix: day_ordinal_suffix
+            })
         ))])
     );
 }
@@ -356,13 +432,23 @@ fn weekday_component(
 }
 
 #[apply(modifiers)]
-fn week_number_component(padding: M<Padding>, week_number_repr: M<WeekNumberRepr>) {
+fn week_number_component(
+    padding: M<Padding>,
+    week_number_repr: M<WeekNumberRepr>,
+    week_number_weekday_is_optional: M<bool>,
+) {
     assert_eq!(
-        parse_with_modifiers!("week_number", padding, week_number_repr),
+        parse_with_modifiers!(
+            "week_number",
+            padding,
+            week_number_repr,
+            week_number_weekday_is_optional
+        ),
         Ok(vec![BorrowedFormatItem::Component(Component::WeekNumber(
             modifier_m!(WeekNumber {
                 padding,
-                repr: week_number_repr
+                repr: week_number_repr,
+                weekday_is_optional: week_number_weekday_is_optional
             })
         ))])
     );
@@ -388,6 +474,9 @@ fn year_component(
     year_range: M<YearRange>,
     year_is_iso_week_based: M<bool>,
     sign_is_mandatory: M<bool>,
+    year_width: M<YearWidth>,
+    year_group_separator: M<YearGroupSeparator>,
+    year_notation: M<YearNotation>,
 ) {
     assert_eq!(
         parse_with_modifiers!(
@@ -396,7 +485,10 @@ fn year_component(
             year_repr,
             year_range,
             year_is_iso_week_based,
-            sign_is_mandatory
+            sign_is_mandatory,
+            year_width,
+            year_group_separator,
+            year_notation
         ),
         Ok(vec![BorrowedFormatItem::Component(Component::Year(
             modifier_m!(Year {
@@ -404,7 +496,10 @@ fn year_component(
                 repr: year_repr,
                 range: year_range,
                 iso_week_based: year_is_iso_week_based,
-                sign_is_mandatory
+                sign_is_mandatory,
+                width: year_width,
+                group_separator: year_group_separator,
+                notation: year_notation
             })
         ))])
     );
@@ -756,6 +851,44 @@ fn error_display_owned(#[case] format_description: &str, #[case] error: &str) {
     test();
 }
 
+#[rstest]
+#[case("[", 0..0, Some("add a component name, such as `year`"))]
+#[case("[year]-[month", 7..8, Some("add a closing bracket"))]
+#[case("[yaer]", 1..5, Some("did you mean `year`?"))]
+#[case("[YEARS]", 1..6, Some("did you mean `year`?"))]
+#[case("[offset_minutes]", 1..15, Some("did you mean `offset_minute`?"))]
+#[case("[foo]", 1..4, None)]
+#[case("[x]", 1..2, None)]
+#[case("[day bar]", 5..8, None)]
+#[case("[ignore]", 1..1, Some("add the `count` modifier"))]
+#[case("[day sign:]", 9..9, Some("add a modifier value"))]
+#[case("[day :mandatory]", 5..5, Some("add a modifier key"))]
+#[case("[optional []]", 0..0, None)]
+fn error_span_and_suggestion(
+    #[case] format_description: &str,
+    #[case] span: std::ops::Range<usize>,
+    #[case] suggestion: Option<&str>,
+) {
+    // la10736/rstest#217
+    #[allow(clippy::unwrap_used)] // It's the point of the test.
+    let test = || {
+        let err = format_description::parse(format_description).unwrap_err();
+        assert_eq!(err.index(), span.start);
+        assert_eq!(err.span(), span);
+        assert_eq!(err.suggestion().as_deref(), suggestion);
+    };
+
+    test();
+}
+
+#[rstest]
+fn error_suggestion_owned() {
+    let err = format_description::parse_owned::<2>("[optional ")
+        .expect_err("format description is invalid");
+    assert_eq!(err.span(), 9..9);
+    assert_eq!(err.suggestion().as_deref(), Some("add an opening bracket"));
+}
+
 #[rstest]
 fn rfc_3339() {
     assert_eq!(
@@ -840,6 +973,7 @@ fn rfc_3339() {
 #[case("%W", format_description!("[week_number repr:monday]"))]
 #[case("%y", format_description!("[year repr:last_two]"))]
 #[case("%Y", format_description!("[year]"))]
+#[case("%:::z", format_description!("[offset_hour sign:mandatory]"))]
 #[case("%%", format_description!("%"))]
 fn strftime_equivalence(
     #[case] strftime: &str,
@@ -867,6 +1001,8 @@ fn strftime_equivalence(
 #[case("%x", "[month]/[day]/[year repr:last_two]")]
 #[case("%X", "[hour]:[minute]:[second]")]
 #[case("%z", "[offset_hour sign:mandatory][offset_minute]")]
+#[case("%:z", "[offset_hour sign:mandatory]:[offset_minute]")]
+#[case("%::z", "[offset_hour sign:mandatory]:[offset_minute]:[offset_second]")]
 fn strftime_compound_equivalence(#[case] strftime: &str, #[case] custom: &str) -> time::Result<()> {
     let borrowed = format_description::parse_strftime_borrowed(strftime)?;
     let owned = format_description::parse_strftime_owned(strftime)?;
@@ -879,3 +1015,20 @@ fn strftime_compound_equivalence(#[case] strftime: &str, #[case] custom: &str) -
 
     Ok(())
 }
+
+#[rstest]
+#[case("%:d", 1)]
+#[case("%#Y", 1)]
+#[case("%::::z", 1)]
+#[case("%:#z", 2)]
+#[case("%#:z", 2)]
+fn strftime_flag_err(#[case] strftime: &str, #[case] index: usize) {
+    assert!(matches!(
+        format_description::parse_strftime_borrowed(strftime),
+        Err(InvalidFormatDescription::NotSupported {
+            what: "flag",
+            index: error_index,
+            ..
+        }) if error_index == index
+    ));
+}
@@ -2,8 +2,9 @@ use std::num::{NonZeroU16, NonZeroU8};
 
 use time::format_description::modifier::WeekNumberRepr;
 use time::format_description::{BorrowedFormatItem, Component};
-use time::parsing::Parsed;
-use time::{error, Month, Time, Weekday};
+use time::macros::{date, datetime, format_description as fd, time, utc_datetime};
+use time::parsing::{Parsed, ParserOptions, WeekdayMode};
+use time::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, Weekday};
 
 #[test]
 fn getters_setters() {
@@ -146,12 +147,14 @@ fn component_err() {
     macro_rules! assert_invalid_component {
         ($component_name:expr, $component:expr $(, $input:expr)?) => {{
             let mut parsed = Parsed::new();
-            assert_eq!(
+            assert!(matches!(
                 parsed.parse_component(input_or_empty!($($input)?), $component),
-                Err(error::ParseFromDescription::InvalidComponent(
-                    $component_name
-                ))
-            );
+                Err(error::ParseFromDescription::InvalidComponentAt {
+                    name,
+                    position: 0,
+                    ..
+                }) if name == $component_name
+            ));
         }};
     }
 
@@ -184,3 +187,188 @@ fn component_err() {
         b"00"
     );
 }
+
+#[test]
+fn carry() -> time::Result<()> {
+    let carrying = ParserOptions::DEFAULT.with_carry_allowed(true);
+
+    assert!(Parsed::new().with_hour_24(24).is_none());
+    assert!(Parsed::new().with_minute(60).is_none());
+    assert!(matches!(
+        Parsed::new().parse_items(b"08:60", fd!("[hour]:[minute]")),
+        Err(error::ParseFromDescription::InvalidComponentAt {
+            name: "minute",
+            position: 3,
+            ..
+        })
+    ));
+    assert!(Parsed::new()
+        .parse_items_with_options(b"08:61", fd!("[hour]:[minute]"), carrying)
+        .is_err());
+    assert!(Parsed::new()
+        .parse_items_with_options(b"25:00", fd!("[hour]:[minute]"), carrying)
+        .is_err());
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items_with_options(b"08:60", fd!("[hour]:[minute]"), carrying)?;
+    assert_eq!(parsed.minute(), Some(60));
+    // Conversions using `TryFrom` use the default options, which do not permit carrying.
+    assert!(Time::try_from(parsed).is_err());
+
+    assert_eq!(
+        Time::parse_with_options("08:60", fd!("[hour]:[minute]"), carrying)?,
+        time!(9:00)
+    );
+    assert_eq!(
+        Time::parse_with_options(
+            "08:59:60.5",
+            fd!("[hour]:[minute]:[second].[subsecond]"),
+            carrying
+        )?,
+        time!(9:00:00.5)
+    );
+    assert!(matches!(
+        Time::parse_with_options("23:59:60", fd!("[hour]:[minute]:[second]"), carrying),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(err)))
+            if err.name() == "hour"
+    ));
+    assert_eq!(
+        PrimitiveDateTime::parse_with_options(
+            "2024-02-28 24:00",
+            fd!("[year]-[month]-[day] [hour]:[minute]"),
+            carrying
+        )?,
+        datetime!(2024-02-29 0:00)
+    );
+
+    let input = "2024-12-31 23:59:60 +01:00";
+    let format = fd!("[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour]:[offset_minute]");
+    assert_eq!(
+        OffsetDateTime::parse_with_options(input, format, carrying)?,
+        datetime!(2025-01-01 0:00 +1)
+    );
+    assert_eq!(
+        UtcDateTime::parse_with_options(input, format, carrying)?,
+        utc_datetime!(2024-12-31 23:00)
+    );
+
+    let input = format!("{:+}-{:03} 24:00", Date::MAX.year(), Date::MAX.ordinal());
+    assert!(PrimitiveDateTime::parse_with_options(
+        &input,
+        fd!("[year range:extended sign:mandatory]-[ordinal] [hour]:[minute]"),
+        carrying
+    )
+    .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn weekday_mode() -> time::Result<()> {
+    let parse = |mode, input: &str| -> time::Result<Date> {
+        Ok(Date::parse_with_options(
+            input,
+            fd!("[weekday repr:short] [year]-[ordinal]"),
+            ParserOptions::DEFAULT.with_weekday_mode(mode),
+        )?)
+    };
+
+    assert_eq!(parse(WeekdayMode::Ignore, "Sun 2024-001")?, date!(2024-001));
+    assert_eq!(parse(WeekdayMode::Validate, "Mon 2024-001")?, date!(2024-001));
+    assert!(matches!(
+        parse(WeekdayMode::Validate, "Sun 2024-001"),
+        Err(time::Error::TryFromParsed(
+            error::TryFromParsed::WeekdayMismatch {
+                parsed: Weekday::Sunday,
+                actual: Weekday::Monday,
+                ..
+            }
+        ))
+    ));
+    assert_eq!(parse(WeekdayMode::Prefer, "Mon 2024-001")?, date!(2024-001));
+    assert_eq!(parse(WeekdayMode::Prefer, "Thu 2024-001")?, date!(2024-004));
+    assert_eq!(parse(WeekdayMode::Prefer, "Fri 2024-001")?, date!(2023-12-29));
+    assert_eq!(parse(WeekdayMode::Prefer, "Sun 2024-001")?, date!(2023-12-31));
+
+    // Conversions using `TryFrom` use the default options, which ignore the weekday.
+    let mut parsed = Parsed::new();
+    parsed.parse_items_with_options(
+        b"Sun 2024-001",
+        fd!("[weekday repr:short] [year]-[ordinal]"),
+        ParserOptions::DEFAULT.with_weekday_mode(WeekdayMode::Validate),
+    )?;
+    assert_eq!(Date::try_from(parsed)?, date!(2024-001));
+
+    let validate = ParserOptions::DEFAULT.with_weekday_mode(WeekdayMode::Validate);
+    // The weekday is always used with a week number, so it is never inconsistent.
+    assert_eq!(
+        Date::parse_with_options(
+            "2024-W01-7",
+            fd!("[year base:iso_week]-W[week_number]-[weekday repr:monday]"),
+            validate
+        )?,
+        date!(2024-01-07)
+    );
+
+    // A weekday defaulted from an optional modifier is not validated.
+    assert_eq!(
+        Date::parse_with_options("2024-01-03", fd!("[year]-[month]-[day]"), validate)?,
+        date!(2024-01-03)
+    );
+
+    let prefer = ParserOptions::DEFAULT.with_weekday_mode(WeekdayMode::Prefer);
+    // The value is out of range when the nearest day with the weekday is not supported.
+    let input = format!(
+        "{} {:+}-001",
+        Date::MIN.weekday().previous(),
+        Date::MIN.year()
+    );
+    assert!(matches!(
+        Date::parse_with_options(
+            &input,
+            fd!("[weekday] [year range:extended sign:mandatory]-[ordinal]"),
+            prefer
+        ),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(_)
+        ))
+    ));
+
+    assert_eq!(
+        PrimitiveDateTime::parse_with_options(
+            "Fri 2024-001 12:00",
+            fd!("[weekday repr:short] [year]-[ordinal] [hour]:[minute]"),
+            prefer
+        )?,
+        datetime!(2023-12-29 12:00)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn case_insensitive_literals() -> time::Result<()> {
+    assert_eq!(Parsed::parse_literal_insensitive(b"abc", b"AbC"), Ok(&b""[..]));
+    assert_eq!(Parsed::parse_literal_insensitive(b"UTC+1", b"utc"), Ok(&b"+1"[..]));
+    assert!(Parsed::parse_literal_insensitive(b"ut", b"utc").is_err());
+    assert!(Parsed::parse_literal_insensitive(b"\xC3\xA9", b"\xC3\x89").is_err());
+
+    let options = ParserOptions::DEFAULT.with_case_insensitive_literals(true);
+    let format = fd!("[year]-[month]-[day]T[hour]:[minute][optional [Z]]");
+    let mut parsed = Parsed::new();
+    assert!(parsed.parse_items(b"2024-05-01t10:30z", format).is_err());
+
+    let mut parsed = Parsed::new();
+    let remaining = parsed.parse_items_with_options(b"2024-05-01t10:30z", format, options)?;
+    assert!(remaining.is_empty());
+    assert_eq!(
+        PrimitiveDateTime::try_from(parsed)?,
+        datetime!(2024-05-01 10:30)
+    );
+
+    // Components are unaffected.
+    assert!(Parsed::new()
+        .parse_items_with_options(b"T-jan", fd!("T-[month repr:short]"), options)
+        .is_err());
+    Ok(())
+}
@@ -1,10 +1,12 @@
 use std::num::{NonZeroU16, NonZeroU8};
 
 use time::format_description::modifier::Ignore;
-use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
-use time::format_description::{modifier, BorrowedFormatItem, Component, OwnedFormatItem};
-use time::macros::{date, datetime, offset, time, utc_datetime};
-use time::parsing::Parsed;
+use time::format_description::well_known::{
+    HttpDate, Iso8601, Rfc2822, Rfc3339, Rfc3339Lenient, Rfc9557,
+};
+use time::format_description::{modifier, BorrowedFormatItem, Component, Custom, OwnedFormatItem};
+use time::macros::{date, datetime, format_description, offset, time, utc_datetime};
+use time::parsing::{CustomParsable, Parsable, Parsed, ParserOptions, TryInOrder, WeekdayMode};
 use time::{
     error, format_description as fd, Date, Month, OffsetDateTime, PrimitiveDateTime, Time,
     UtcDateTime, UtcOffset, Weekday,
@@ -20,7 +22,7 @@ macro_rules! invalid_literal {
 macro_rules! invalid_component {
     ($name:literal) => {
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent($name),
+            error::ParseFromDescription::InvalidComponentAt { name: $name, .. },
         ))
     };
 }
@@ -277,6 +279,93 @@ fn rfc_2822_err() {
     ));
 }
 
+#[test]
+fn http_date() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC)
+    );
+    assert_eq!(
+        UtcDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+        utc_datetime!(1994-11-06 08:49:37)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Tuesday, 01-Jan-30 00:00:00 GMT", &HttpDate)?,
+        datetime!(2030-01-01 00:00:00 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sun Nov 16 08:49:37 1994", &HttpDate)?,
+        datetime!(1994-11-16 08:49:37 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 31 Dec 2016 23:59:60 GMT", &HttpDate)?,
+        datetime!(2016-12-31 23:59:59.999_999_999 UTC)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn http_date_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("sun, 06 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("weekday")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 6 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06-Nov-94 08:49:37 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sunday, 06 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 94 08:49:37 GMT", &HttpDate),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 UTC", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 +0000", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun Nov 6 08:49:37 1994", &HttpDate),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 GMT", &HttpDate),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sat, 30 Dec 2016 23:59:60 GMT", &HttpDate),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "second" && component.is_conditional()
+    ));
+}
+
 #[test]
 fn rfc_3339() -> time::Result<()> {
     assert_eq!(
@@ -578,6 +667,380 @@ fn rfc_3339_err() {
     ));
 }
 
+#[test]
+fn rfc_3339_lenient() -> time::Result<()> {
+    let lenient = Rfc3339Lenient::DEFAULT.allow_missing_seconds(true);
+
+    for (input, expected) in [
+        ("2024-05-01T10:30Z", datetime!(2024-05-01 10:30 UTC)),
+        ("2024-05-01T10:30z", datetime!(2024-05-01 10:30 UTC)),
+        ("2024-05-01 10:30Z", datetime!(2024-05-01 10:30 UTC)),
+        ("2024-05-01t10:30Z", datetime!(2024-05-01 10:30 UTC)),
+        ("2024-05-01T10:30+02:00", datetime!(2024-05-01 10:30 +02:00)),
+        ("2024-05-01T10:30-05:30", datetime!(2024-05-01 10:30 -05:30)),
+        ("2024-05-01T23:59+00:00", datetime!(2024-05-01 23:59 UTC)),
+        ("2024-05-01T10:30:15Z", datetime!(2024-05-01 10:30:15 UTC)),
+        ("2024-05-01T10:30:15.25Z", datetime!(2024-05-01 10:30:15.25 UTC)),
+    ] {
+        assert_eq!(OffsetDateTime::parse(input, &lenient)?, expected, "{input}");
+        assert_eq!(
+            PrimitiveDateTime::parse(input, &lenient)?,
+            PrimitiveDateTime::new(expected.date(), expected.time()),
+            "{input}"
+        );
+        // Without the flag, the input is only accepted if it is valid RFC 3339.
+        assert_eq!(
+            OffsetDateTime::parse(input, &Rfc3339Lenient::DEFAULT).ok(),
+            OffsetDateTime::parse(input, &Rfc3339).ok(),
+            "{input}"
+        );
+    }
+
+    assert!(matches!(
+        OffsetDateTime::parse("2024-05-01T10:30Z", &Rfc3339Lenient::DEFAULT),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2024-05-01T10:30Z", &Rfc3339Lenient::default()),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2024-05-01T10:30.5Z", &lenient),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2024-05-01T10:30.5Z", &lenient),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2024-05-01T10:30:Z", &lenient),
+        invalid_component!("second")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2024-05-01T10Z", &lenient),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2024-05-01T10:30", &lenient),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2024-05-01T10:30+0200", &lenient),
+        invalid_literal!()
+    ));
+
+    assert_eq!(Rfc3339Lenient::from(Rfc3339), Rfc3339Lenient::DEFAULT);
+    assert!(!Rfc3339Lenient::DEFAULT.allows_missing_seconds());
+    assert!(lenient.allows_missing_seconds());
+    // Formatting always includes the seconds.
+    assert_eq!(
+        datetime!(2024-05-01 10:30 UTC).format(&lenient)?,
+        "2024-05-01T10:30:00Z"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn well_known_dedicated_parsers() {
+    use time::format_description::well_known::CommonFormat;
+
+    /// Parse the input via `Parsed`, bypassing the dedicated parser of the format.
+    fn generic(input: &str, format: &impl Parsable) -> Option<OffsetDateTime> {
+        let mut parsed = Parsed::new();
+        match parsed.parse(input.as_bytes(), format) {
+            Ok([]) => parsed.try_into().ok(),
+            _ => None,
+        }
+    }
+
+    /// Assert that every parsing method agrees with the generic path.
+    fn check(format: &impl Parsable, input: &str) {
+        let expected = generic(input, format);
+        assert_eq!(
+            OffsetDateTime::parse(input, format).ok(),
+            expected,
+            "{input}"
+        );
+        assert_eq!(
+            OffsetDateTime::parse(input, &format).ok(),
+            expected,
+            "{input}"
+        );
+        assert_eq!(
+            UtcDateTime::parse(input, format).ok(),
+            expected.map(OffsetDateTime::to_utc),
+            "{input}"
+        );
+        assert_eq!(
+            UtcDateTime::parse(input, &format).ok(),
+            expected.map(OffsetDateTime::to_utc),
+            "{input}"
+        );
+    }
+
+    for input in [
+        "2021-01-02T03:04:05Z",
+        "2021-01-02 03:04:05.123456789+01:02",
+        "2021-01-02T03:04:05.1-23:59",
+        "2021-12-31T23:59:60z",
+        "2021-12-31T23:59:60+01:00",
+        "2021-12-31T23:59:60.5Z",
+        "2021-01-02T03:04:05+24:00",
+        "2021-01-02T03:04:05+01:60",
+        "2021-13-02T03:04:05Z",
+        "2021-02-29T03:04:05Z",
+        "2021-01-02T03:04:05.Z",
+        "2021-01-02T03:04:05Z ",
+        "2021-01-02T03:04Z",
+    ] {
+        check(&Rfc3339, input);
+        check(&CommonFormat::Rfc3339, input);
+    }
+
+    for input in [
+        "Sat, 02 Jan 2021 03:04:05 +0607",
+        "sat, 2 jan 2021 03:04:05 -0607",
+        "SAT,  02\tJAN 2021 03:04 GMT",
+        " 02 Feb 21 03:04:05 (comment) est ",
+        "Sat, 02 Jan 2021 03 : 04 : 05 PDT",
+        "Fri, 31 Dec 2021 23:59:60 Z",
+        "Fri, 31 Dec 2021 17:52:60 -0607",
+        "Sat, 02 Jan 2021 03:04:05 A",
+        "Sat, 02 Jan 2021 03:04:05 J",
+        "Sat, 02 Foo 2021 03:04:05 +0000",
+        "Sat 02 Jan 2021 03:04:05 +0000",
+        "Sax, 02 Jan 2021 03:04:05 +0000",
+        "Sat, 02 Jan 2021 03:04:05 +0000x",
+        "Sat, 30 Feb 2021 03:04:05 +0000",
+        "Sat, 02 Jan 2021 03:04:60 +0000",
+        "Sat, 02 Jan 1899 03:04:05 +0000",
+        "Sat, 02 Jan 2021 03:04:05",
+    ] {
+        check(&Rfc2822, input);
+        check(&CommonFormat::Rfc2822, input);
+    }
+}
+
+#[test]
+fn common_format() -> time::Result<()> {
+    use time::format_description::well_known::CommonFormat;
+
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+06:00", &CommonFormat::Rfc3339)?,
+        datetime!(2021-01-02 03:04:05 +6)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 +0600", &CommonFormat::Rfc2822)?,
+        datetime!(2021-01-02 03:04:05 +6)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 GMT", &CommonFormat::HttpDate)?,
+        datetime!(2021-01-02 03:04:05 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+06:00", &CommonFormat::Iso8601)?,
+        datetime!(2021-01-02 03:04:05 +6)
+    );
+    assert_eq!(
+        Date::parse("2021-01-02", &CommonFormat::IsoDate)?,
+        date!(2021-01-02)
+    );
+    assert_eq!(
+        Time::parse("03:04:05", &CommonFormat::IsoTime)?,
+        time!(03:04:05)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-01-02T03:04:05", &CommonFormat::IsoDateTime)?,
+        datetime!(2021-01-02 03:04:05)
+    );
+    assert_eq!(
+        UtcOffset::parse("+06:00", &CommonFormat::IsoOffset)?,
+        offset!(+6)
+    );
+    assert!(OffsetDateTime::parse("2021-01-02T03:04:05Z", &CommonFormat::Rfc2822).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn try_in_order() -> time::Result<()> {
+    let format = TryInOrder((
+        Rfc3339,
+        Rfc2822,
+        format_description!("[year]-[month]-[day] [hour]:[minute] [offset_hour sign:mandatory]"),
+    ));
+
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+06:00", &format)?,
+        datetime!(2021-01-02 03:04:05 +6)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 +0600", &format)?,
+        datetime!(2021-01-02 03:04:05 +6)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02 03:04 +06", &format)?,
+        datetime!(2021-01-02 03:04 +6)
+    );
+    assert_eq!(
+        UtcDateTime::parse("2021-01-02 03:04 +06", &format)?,
+        utc_datetime!(2021-01-01 21:04)
+    );
+
+    // A later format is used when an earlier one parses only a prefix of the input.
+    let format = TryInOrder((format_description!("[year]-[month]-[day]"), Rfc3339));
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &format)?,
+        datetime!(2021-01-02 03:04:05 UTC)
+    );
+    assert_eq!(Date::parse("2021-01-02", &format)?, date!(2021-01-02));
+
+    // The first format to succeed is used when parsing only part of the input.
+    let mut parsed = Parsed::new();
+    let remaining = parsed.parse(b"2021-01-02T03:04:05Z", &format)?;
+    assert_eq!(remaining, b"T03:04:05Z");
+    assert_eq!(parsed.hour_24(), None);
+
+    // Components parsed by a failed format are discarded.
+    let format = TryInOrder((
+        format_description!("[year]-[month]-[day]x"),
+        format_description!("[year]"),
+    ));
+    let mut parsed = Parsed::new();
+    assert_eq!(parsed.parse(b"2021-01-02", &format)?, b"-01-02");
+    assert_eq!(parsed.month(), None);
+
+    // The error from the first format is returned if all formats fail.
+    let format = TryInOrder((Rfc3339, format_description!("[year]")));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02", &format),
+        invalid_component!("separator")
+    ));
+    assert!(matches!(
+        Time::parse("x", &TryInOrder((format_description!("[hour]"),))),
+        invalid_component!("hour")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn rfc_9557() -> time::Result<()> {
+    let annotations = |input: &'static str| -> time::Result<_> {
+        let (remaining, annotations) =
+            Rfc9557::DEFAULT.parse_annotated(input.as_bytes(), &mut Parsed::new())?;
+        assert!(remaining.is_empty(), "{input}");
+        Ok((
+            annotations.time_zone().map(|range| &input[range]),
+            annotations.calendar().map(|range| &input[range]),
+        ))
+    };
+
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &Rfc9557::DEFAULT)?,
+        datetime!(2021-01-02 03:04:05 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2021-01-02T03:04:05.5-05:00[America/New_York][u-ca=gregory]",
+            &Rfc9557::DEFAULT
+        )?,
+        datetime!(2021-01-02 03:04:05.5 -5)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-01-02T03:04:05+01:00[!Europe/Paris]", &Rfc9557::DEFAULT)?,
+        datetime!(2021-01-02 03:04:05)
+    );
+    // The offset of the timestamp is used, even if the annotation disagrees.
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+01:00[+02:00]", &Rfc9557::DEFAULT)?,
+        datetime!(2021-01-02 03:04:05 +1)
+    );
+
+    assert_eq!(annotations("2021-01-02T03:04:05Z")?, (None, None));
+    assert_eq!(
+        annotations("2021-01-02T03:04:05Z[Etc/UTC]")?,
+        (Some("Etc/UTC"), None)
+    );
+    assert_eq!(
+        annotations("2021-01-02T03:04:05-05:00[!America/New_York][u-ca=gregory]")?,
+        (Some("America/New_York"), Some("gregory"))
+    );
+    assert_eq!(
+        annotations("2021-01-02T03:04:05+05:30[!+05:30][!u-ca=iso8601]")?,
+        (Some("+05:30"), Some("iso8601"))
+    );
+    assert_eq!(
+        annotations("2021-01-02T03:04:05Z[u-ca=japanese][u-ca=gregory][_foo=bar-baz1]")?,
+        (None, Some("japanese"))
+    );
+
+    let mut parsed = Parsed::new();
+    let (remaining, annotations) =
+        Rfc9557::DEFAULT.parse_annotated(b"2021-01-02T03:04:05Z[Etc/UTC] rest", &mut parsed)?;
+    assert_eq!(remaining, b" rest");
+    assert_eq!(annotations.time_zone(), Some(21..28));
+    assert_eq!(parsed.hour_24(), Some(3));
+
+    // The struct is left unchanged if parsing fails.
+    let mut parsed = Parsed::new();
+    assert!(Rfc9557::DEFAULT
+        .parse_annotated(b"2021-01-02T03:04:05Z[", &mut parsed)
+        .is_err());
+    assert_eq!(parsed.year(), None);
+
+    Ok(())
+}
+
+#[test]
+fn rfc_9557_err() {
+    for (input, component) in [
+        ("2021-01-02T03:04:05Z[", "annotation"),
+        ("2021-01-02T03:04:05Z[Etc/UTC", "annotation"),
+        ("2021-01-02T03:04:05Z[]", "time zone"),
+        ("2021-01-02T03:04:05Z[!]", "time zone"),
+        ("2021-01-02T03:04:05Z[1Zone]", "time zone"),
+        ("2021-01-02T03:04:05Z[Etc/]", "time zone"),
+        ("2021-01-02T03:04:05Z[+0100]", "time zone"),
+        ("2021-01-02T03:04:05+01:00[!+02:00]", "time zone"),
+        ("2021-01-02T03:04:05Z[Etc/UTC][Etc/UTC]", "annotation"),
+        ("2021-01-02T03:04:05Z[U-CA=gregory]", "annotation"),
+        ("2021-01-02T03:04:05Z[u-ca=]", "annotation"),
+        ("2021-01-02T03:04:05Z[u-ca=greg--ory]", "annotation"),
+        ("2021-01-02T03:04:05Z[!u-ca=japanese]", "calendar"),
+        ("2021-01-02T03:04:05Z[!foo=bar]", "annotation"),
+    ] {
+        assert!(
+            matches!(
+                OffsetDateTime::parse(input, &Rfc9557::DEFAULT),
+                Err(error::Parse::ParseFromDescription(
+                    error::ParseFromDescription::InvalidComponentAt { name, .. }
+                )) if name == component
+            ),
+            "{input}"
+        );
+    }
+
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z[Etc/UTC]x", &Rfc9557::DEFAULT),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { position: 29, .. }
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05[Etc/UTC]", &Rfc9557::DEFAULT),
+        invalid_component!("offset hour")
+    ));
+
+    // The struct is not mutated on failure.
+    let mut parsed = Parsed::new();
+    assert!(parsed
+        .parse(b"2021-01-02T03:04:05Z[!foo=bar]", &Rfc9557::DEFAULT)
+        .is_err());
+    assert_eq!(parsed.year(), None);
+}
+
 #[test]
 fn iso_8601() {
     assert_eq!(
@@ -802,6 +1265,68 @@ fn iso_8601_error() {
     ));
 }
 
+#[test]
+fn iso_8601_space_separator() {
+    use time::format_description::well_known::iso8601::{Config, EncodedConfig};
+
+    const SPACE: EncodedConfig = Config::DEFAULT.set_allow_space_separator(true).encode();
+
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02 03:04:05+05", &Iso8601::<SPACE>),
+        Ok(datetime!(2021-01-02 03:04:05 +5))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02 03:04:05+0530", &Iso8601::<SPACE>),
+        Ok(datetime!(2021-01-02 03:04:05 +5:30))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05-05:30", &Iso8601::<SPACE>),
+        Ok(datetime!(2021-01-02 03:04:05 -5:30))
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("20210102 030405", &Iso8601::<SPACE>),
+        Ok(datetime!(2021-01-02 03:04:05))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+0530", &Iso8601::DEFAULT),
+        Ok(datetime!(2021-01-02 03:04:05 +5:30))
+    );
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02 03:04:05+05", &Iso8601::DEFAULT),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { .. }
+        ))
+    ));
+}
+
+#[test]
+fn iso_8601_runtime() {
+    use time::format_description::well_known::iso8601::{Config, EncodedConfig};
+    use time::format_description::well_known::Iso8601Runtime;
+
+    const SPACE: EncodedConfig = Config::DEFAULT.set_allow_space_separator(true).encode();
+
+    let space = Iso8601Runtime::new(Config::DEFAULT.set_allow_space_separator(true));
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02 03:04:05+05", &space),
+        OffsetDateTime::parse("2021-01-02 03:04:05+05", &Iso8601::<SPACE>),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-W01-6T03:04:05.123Z", &Iso8601Runtime::default()),
+        Ok(datetime!(2021-01-09 03:04:05.123 UTC))
+    );
+    assert_eq!(
+        Date::parse("2021-002", &Iso8601Runtime::from(Iso8601::PARSING)),
+        Ok(date!(2021-01-02))
+    );
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02 03:04:05+05", &Iso8601Runtime::default()),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { .. }
+        ))
+    ));
+}
+
 #[test]
 fn parse_time() -> time::Result<()> {
     let format_input_output = [
@@ -874,7 +1399,7 @@ fn parse_time_err() -> time::Result<()> {
     assert!(matches!(
         Time::parse("13 PM", &fd::parse("[hour repr:12] [period]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("hour")
+            error::ParseFromDescription::InvalidComponentAt { name: "hour", .. }
         ))
     ));
     assert!(matches!(
@@ -886,19 +1411,19 @@ fn parse_time_err() -> time::Result<()> {
     assert!(matches!(
         Time::parse("a", &fd::parse("[subsecond digits:1]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
+            error::ParseFromDescription::InvalidComponentAt { name: "subsecond", .. }
         ))
     ));
     assert!(matches!(
         Time::parse("1a", &fd::parse("[subsecond digits:2]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
+            error::ParseFromDescription::InvalidComponentAt { name: "subsecond", .. }
         ))
     ));
     assert!(matches!(
         Time::parse("1a", &fd::parse_owned::<2>("[subsecond digits:2]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
+            error::ParseFromDescription::InvalidComponentAt { name: "subsecond", .. }
         ))
     ));
     assert!(matches!(
@@ -907,49 +1432,49 @@ fn parse_time_err() -> time::Result<()> {
             [fd::parse_owned::<2>("[subsecond digits:2]")?].as_slice()
         ),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
+            error::ParseFromDescription::InvalidComponentAt { name: "subsecond", .. }
         ))
     ));
     assert!(matches!(
         Time::parse("12a", &fd::parse("[subsecond digits:3]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
+            error::ParseFromDescription::InvalidComponentAt { name: "subsecond", .. }
         ))
     ));
     assert!(matches!(
         Time::parse("123a", &fd::parse("[subsecond digits:4]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
+            error::ParseFromDescription::InvalidComponentAt { name: "subsecond", .. }
         ))
     ));
     assert!(matches!(
         Time::parse("1234a", &fd::parse("[subsecond digits:5]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
+            error::ParseFromDescription::InvalidComponentAt { name: "subsecond", .. }
         ))
     ));
     assert!(matches!(
         Time::parse("12345a", &fd::parse("[subsecond digits:6]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
+            error::ParseFromDescription::InvalidComponentAt { name: "subsecond", .. }
         ))
     ));
     assert!(matches!(
         Time::parse("123456a", &fd::parse("[subsecond digits:7]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
+            error::ParseFromDescription::InvalidComponentAt { name: "subsecond", .. }
         ))
     ));
     assert!(matches!(
         Time::parse("1234567a", &fd::parse("[subsecond digits:8]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
+            error::ParseFromDescription::InvalidComponentAt { name: "subsecond", .. }
         ))
     ));
     assert!(matches!(
         Time::parse("12345678a", &fd::parse("[subsecond digits:9]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("subsecond")
+            error::ParseFromDescription::InvalidComponentAt { name: "subsecond", .. }
         ))
     ));
 
@@ -1052,19 +1577,19 @@ fn parse_date_err() -> time::Result<()> {
     assert!(matches!(
         Date::parse("a", &fd::parse("[year]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
+            error::ParseFromDescription::InvalidComponentAt { name: "year", .. }
         ))
     ));
     assert!(matches!(
         Date::parse("0001", &fd::parse("[year sign:mandatory]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
+            error::ParseFromDescription::InvalidComponentAt { name: "year", .. }
         ))
     ));
     assert!(matches!(
         Date::parse("0a", &fd::parse("[year repr:last_two]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
+            error::ParseFromDescription::InvalidComponentAt { name: "year", .. }
         ))
     ));
     assert!(matches!(
@@ -1076,7 +1601,7 @@ fn parse_date_err() -> time::Result<()> {
     assert!(matches!(
         Date::parse("2021-12-32", &fd::parse("[year]-[month]-[day]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("day")
+            error::ParseFromDescription::InvalidComponentAt { name: "day", .. }
         ))
     ));
     assert!(matches!(
@@ -1097,7 +1622,7 @@ fn parse_date_err() -> time::Result<()> {
             &fd::parse("[year base:iso_week]-W[week_number]-[weekday repr:monday]")?
         ),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("week number")
+            error::ParseFromDescription::InvalidComponentAt { name: "week number", .. }
         ))
     ));
     assert!(matches!(
@@ -1112,7 +1637,7 @@ fn parse_date_err() -> time::Result<()> {
             &fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:monday]")?
         ),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("week number")
+            error::ParseFromDescription::InvalidComponentAt { name: "week number", .. }
         ))
     ));
     assert!(matches!(
@@ -1127,19 +1652,19 @@ fn parse_date_err() -> time::Result<()> {
             &fd::parse("[year]-W[week_number repr:monday]-[weekday repr:monday]")?
         ),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("week number")
+            error::ParseFromDescription::InvalidComponentAt { name: "week number", .. }
         ))
     ));
     assert!(matches!(
         Date::parse("Ja", &fd::parse("[month repr:short]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("month")
+            error::ParseFromDescription::InvalidComponentAt { name: "month", .. }
         ))
     ));
     assert!(matches!(
         Date::parse("  2a21", &fd::parse("[year padding:space]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
+            error::ParseFromDescription::InvalidComponentAt { name: "year", .. }
         ))
     ));
 
@@ -1147,12 +1672,208 @@ fn parse_date_err() -> time::Result<()> {
 }
 
 #[test]
-fn parse_offset() -> time::Result<()> {
-    // Regression check for #522.
+fn parse_date_weekday_mode() -> time::Result<()> {
+    let input = "Sun, 02 Jan 2021 03:04:05 +0000";
     assert_eq!(
-        UtcOffset::parse(
-            "-00:01",
-            &fd::parse("[offset_hour sign:mandatory]:[offset_minute]")?,
+        Date::parse_with_weekday_mode(input, &Rfc2822, WeekdayMode::Ignore)?,
+        date!(2021-01-02)
+    );
+    assert!(matches!(
+        Date::parse_with_weekday_mode(input, &Rfc2822, WeekdayMode::Validate),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::WeekdayMismatch {
+                parsed: Weekday::Sunday,
+                actual: Weekday::Saturday,
+                ..
+            }
+        ))
+    ));
+    assert_eq!(
+        Date::parse_with_weekday_mode(input, &Rfc2822, WeekdayMode::Prefer)?,
+        date!(2021-01-03)
+    );
+    assert_eq!(
+        Date::parse_with_weekday_mode(
+            "Sat, 02 Jan 2021 03:04:05 +0000",
+            &Rfc2822,
+            WeekdayMode::Validate
+        )?,
+        date!(2021-01-02)
+    );
+    assert!(matches!(
+        Date::parse_with_weekday_mode("Sun, 02 Jan 2021", &Rfc2822, WeekdayMode::Prefer),
+        Err(error::Parse::ParseFromDescription(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn parse_week_without_weekday() -> time::Result<()> {
+    assert_eq!(
+        Date::parse(
+            "2021-W05",
+            &fd::parse("[year base:iso_week]-W[week_number weekday:optional]")?
+        )?,
+        date!(2021-02-01)
+    );
+    assert_eq!(
+        Date::parse(
+            "2021-W05-3",
+            &fd::parse_owned::<2>(
+                "[year base:iso_week]-W[week_number weekday:optional][optional [-[weekday \
+                 repr:monday]]]"
+            )?
+        )?,
+        date!(2021-02-03)
+    );
+    assert_eq!(
+        Date::parse(
+            "2021-05",
+            &fd::parse("[year]-[week_number repr:monday weekday:optional]")?
+        )?,
+        date!(2021-02-01)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse(
+            "2021-W05 12:00",
+            &fd::parse("[year base:iso_week]-W[week_number weekday:optional] [hour]:[minute]")?
+        )?,
+        datetime!(2021-02-01 12:00)
+    );
+    assert!(matches!(
+        Date::parse("2021-W05", &fd::parse("[year base:iso_week]-W[week_number]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_day_ordinal_suffix() -> time::Result<()> {
+    let format = fd::parse("[month repr:long] [day padding:none ordinal_suffix:true] [year]")?;
+    for (input, expected) in [
+        ("January 1st 2019", date!(2019-01-01)),
+        ("January 2nd 2019", date!(2019-01-02)),
+        ("January 3rd 2019", date!(2019-01-03)),
+        ("January 12th 2019", date!(2019-01-12)),
+        ("January 22nd 2019", date!(2019-01-22)),
+        ("January 31st 2019", date!(2019-01-31)),
+    ] {
+        assert_eq!(Date::parse(input, &format)?, expected);
+    }
+    for input in ["January 1 2019", "January 1nd 2019", "January 11st 2019"] {
+        assert!(matches!(
+            Date::parse(input, &format),
+            Err(error::Parse::ParseFromDescription(
+                error::ParseFromDescription::InvalidComponentAt { name: "day", .. }
+            ))
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_year_group_separator() -> time::Result<()> {
+    let format = fd::parse("[year group_separator:underscore]-[month]-[day]")?;
+    assert_eq!(Date::parse("2024-01-02", &format)?, date!(2024-01-02));
+    assert!(matches!(
+        Date::parse("2_024-01-02", &format),
+        invalid_component!("year")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_year_width() -> time::Result<()> {
+    assert_eq!(
+        Date::parse("10000-001", &fd::parse("[year width:5]-[ordinal]")?)?,
+        date!(+10_000-001)
+    );
+    assert_eq!(
+        Date::parse("02021-001", &fd::parse("[year width:5]-[ordinal]")?)?,
+        date!(2021-001)
+    );
+    assert_eq!(
+        Date::parse("-000001-001", &fd::parse("[year width:6]-[ordinal]")?)?,
+        date!(-0001-001)
+    );
+    assert_eq!(
+        Date::parse("+2021-001", &fd::parse("[year width:4 sign:mandatory]-[ordinal]")?)?,
+        date!(2021-001)
+    );
+
+    assert!(matches!(
+        Date::parse("2021-001", &fd::parse("[year width:5]-[ordinal]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponentAt { name: "year", .. }
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("02021-001", &fd::parse("[year width:5 sign:mandatory]-[ordinal]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponentAt { name: "year", .. }
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_year_notation() -> time::Result<()> {
+    let format = fd::parse("[year notation:bce]-[month]-[day]")?;
+    assert_eq!(Date::parse("0001 BCE-01-02", &format)?, date!(0000-01-02));
+    assert_eq!(Date::parse("0002 BCE-01-02", &format)?, date!(-0001-01-02));
+    assert_eq!(Date::parse("10000 BCE-01-02", &format)?, date!(-9999-01-02));
+    assert_eq!(Date::parse("2024-01-02", &format)?, date!(2024-01-02));
+    assert_eq!(
+        Date::parse(
+            "2 BCE-001",
+            &fd::parse("[year notation:bce padding:none]-[ordinal]")?
+        )?,
+        date!(-0001-001)
+    );
+    assert_eq!(
+        Date::parse(
+            "   2 BCE-001",
+            &fd::parse("[year notation:bce width:4 padding:space]-[ordinal]")?
+        )?,
+        date!(-0001-001)
+    );
+    assert_eq!(
+        Date::parse(
+            "-0001-01-02",
+            &fd::parse("[year notation:astronomical]-[month]-[day]")?
+        )?,
+        date!(-0001-01-02)
+    );
+
+    assert!(matches!(
+        Date::parse("0000 BCE-01-02", &format),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        Date::parse("-0001-01-02", &format),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        Date::parse("002 BCE-001", &fd::parse("[year notation:bce width:4]-[ordinal]")?),
+        invalid_component!("year")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_offset() -> time::Result<()> {
+    // Regression check for #522.
+    assert_eq!(
+        UtcOffset::parse(
+            "-00:01",
+            &fd::parse("[offset_hour sign:mandatory]:[offset_minute]")?,
         ),
         Ok(offset!(-00:01)),
     );
@@ -1175,22 +1896,22 @@ fn parse_offset_err() -> time::Result<()> {
             error::TryFromParsed::InsufficientInformation { .. }
         ))
     ));
-    assert_eq!(
+    assert!(matches!(
         UtcOffset::parse("01", &fd::parse("[offset_hour sign:mandatory]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("offset hour")
+            error::ParseFromDescription::InvalidComponentAt { name: "offset hour", .. }
         ))
-    );
+    ));
     assert!(matches!(
         UtcOffset::parse("24", &fd::parse("[offset_hour]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("offset hour")
+            error::ParseFromDescription::InvalidComponentAt { name: "offset hour", .. }
         ))
     ));
     assert!(matches!(
         UtcOffset::parse("00:60", &fd::parse("[offset_hour]:[offset_minute]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("offset minute")
+            error::ParseFromDescription::InvalidComponentAt { name: "offset minute", .. }
         ))
     ));
     assert!(matches!(
@@ -1199,7 +1920,7 @@ fn parse_offset_err() -> time::Result<()> {
             &fd::parse("[offset_hour]:[offset_minute]:[offset_second]")?
         ),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("offset second")
+            error::ParseFromDescription::InvalidComponentAt { name: "offset second", .. }
         ))
     ));
 
@@ -1230,7 +1951,7 @@ fn parse_primitive_date_time_err() -> time::Result<()> {
             &fd::parse("[year]-[ordinal] [hour repr:12] [period]")?
         ),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("hour")
+            error::ParseFromDescription::InvalidComponentAt { name: "hour", .. }
         ))
     ));
     assert!(matches!(
@@ -1257,7 +1978,7 @@ fn parse_offset_date_time_err() -> time::Result<()> {
     assert!(matches!(
         OffsetDateTime::parse("x", &fd::parse("[year]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
+            error::ParseFromDescription::InvalidComponentAt { name: "year", .. }
         ))
     ));
     assert!(matches!(
@@ -1266,7 +1987,7 @@ fn parse_offset_date_time_err() -> time::Result<()> {
             &fd::parse("[year]-[ordinal] [hour repr:12] [period] [offset_hour sign:mandatory]")?
         ),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("offset hour")
+            error::ParseFromDescription::InvalidComponentAt { name: "offset hour", .. }
         ))
     ));
 
@@ -1297,7 +2018,7 @@ fn parse_utc_date_time_err() -> time::Result<()> {
             &fd::parse("[year]-[ordinal] [hour repr:12] [period]")?
         ),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("hour")
+            error::ParseFromDescription::InvalidComponentAt { name: "hour", .. }
         ))
     ));
     assert!(matches!(
@@ -1312,7 +2033,7 @@ fn parse_utc_date_time_err() -> time::Result<()> {
     assert!(matches!(
         UtcDateTime::parse("x", &fd::parse("[year]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
+            error::ParseFromDescription::InvalidComponentAt { name: "year", .. }
         ))
     ));
     assert!(matches!(
@@ -1321,7 +2042,7 @@ fn parse_utc_date_time_err() -> time::Result<()> {
             &fd::parse("[year]-[ordinal] [hour repr:12] [period] [offset_hour sign:mandatory]")?
         ),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("offset hour")
+            error::ParseFromDescription::InvalidComponentAt { name: "offset hour", .. }
         ))
     ));
 
@@ -1649,7 +2370,11 @@ fn parse_components() -> time::Result<()> {
     );
     assert!(matches!(
         result,
-        Err(error::ParseFromDescription::InvalidComponent("ignore"))
+        Err(error::ParseFromDescription::InvalidComponentAt {
+            name: "ignore",
+            position: 0,
+            ..
+        })
     ));
     parse_component!(
         Component::UnixTimestamp(modifier!(UnixTimestamp {
@@ -1834,7 +2559,14 @@ fn parse_first() -> time::Result<()> {
             ]),
         )
         .expect_err("parsing should fail");
-    assert_eq!(err, error::ParseFromDescription::InvalidComponent("period"));
+    assert!(matches!(
+        err,
+        error::ParseFromDescription::InvalidComponentAt {
+            name: "period",
+            position: 0,
+            ..
+        }
+    ));
 
     let mut parsed = Parsed::new();
     let err = parsed
@@ -1846,7 +2578,14 @@ fn parse_first() -> time::Result<()> {
             ])),
         )
         .expect_err("parsing should fail");
-    assert_eq!(err, error::ParseFromDescription::InvalidComponent("period"));
+    assert!(matches!(
+        err,
+        error::ParseFromDescription::InvalidComponentAt {
+            name: "period",
+            position: 0,
+            ..
+        }
+    ));
 
     Ok(())
 }
@@ -1884,36 +2623,36 @@ fn parse_unix_timestamp() -> time::Result<()> {
 
 #[test]
 fn parse_unix_timestamp_err() -> time::Result<()> {
-    assert_eq!(
+    assert!(matches!(
         OffsetDateTime::parse("1234567890", &fd::parse("[unix_timestamp sign:mandatory]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("unix_timestamp")
+            error::ParseFromDescription::InvalidComponentAt { name: "unix_timestamp", .. }
         ))
-    );
-    assert_eq!(
+    ));
+    assert!(matches!(
         OffsetDateTime::parse("a", &fd::parse("[unix_timestamp precision:second]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("unix_timestamp")
+            error::ParseFromDescription::InvalidComponentAt { name: "unix_timestamp", .. }
         ))
-    );
-    assert_eq!(
+    ));
+    assert!(matches!(
         OffsetDateTime::parse("a", &fd::parse("[unix_timestamp precision:millisecond]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("unix_timestamp")
+            error::ParseFromDescription::InvalidComponentAt { name: "unix_timestamp", .. }
         ))
-    );
-    assert_eq!(
+    ));
+    assert!(matches!(
         OffsetDateTime::parse("a", &fd::parse("[unix_timestamp precision:microsecond]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("unix_timestamp")
+            error::ParseFromDescription::InvalidComponentAt { name: "unix_timestamp", .. }
         ))
-    );
-    assert_eq!(
+    ));
+    assert!(matches!(
         OffsetDateTime::parse("a", &fd::parse("[unix_timestamp precision:nanosecond]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("unix_timestamp")
+            error::ParseFromDescription::InvalidComponentAt { name: "unix_timestamp", .. }
         ))
-    );
+    ));
 
     Ok(())
 }
@@ -1928,6 +2667,90 @@ fn issue_601() {
     assert_eq!(date, Ok(datetime!(2009-02-13 23:31:30.123 +00:00:00)));
 }
 
+#[test]
+fn parse_unix_timestamp_signed() -> time::Result<()> {
+    let description = fd::parse("[unix_timestamp].[subsecond]")?;
+    assert_eq!(
+        OffsetDateTime::parse("-1234567890", &fd::parse("[unix_timestamp]")?)?,
+        datetime!(1930-11-18 00:28:30 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("+1.5", &description)?,
+        datetime!(1970-01-01 0:00:01.5 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("-1.5", &description)?,
+        datetime!(1969-12-31 23:59:58.5 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("-0.25", &description)?,
+        datetime!(1969-12-31 23:59:59.75 UTC)
+    );
+    assert_eq!(
+        UtcDateTime::parse("-0.25", &description)?,
+        utc_datetime!(1969-12-31 23:59:59.75)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "-1234567890123",
+            &fd::parse("[unix_timestamp precision:millisecond]")?
+        )?,
+        datetime!(1930-11-18 00:28:29.877 UTC)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_unix_timestamp_with_offset() -> time::Result<()> {
+    let description = fd::parse("[unix_timestamp] [offset_hour]:[offset_minute]")?;
+    let datetime = OffsetDateTime::parse("1234567890 -05:30", &description)?;
+    assert_eq!(datetime, datetime!(2009-02-13 23:31:30 UTC));
+    assert_eq!(datetime.offset(), offset!(-5:30));
+    assert_eq!(
+        OffsetDateTime::parse(&datetime.format(&description)?, &description)?.offset(),
+        offset!(-5:30)
+    );
+    assert_eq!(
+        UtcDateTime::parse("1234567890 -05:30", &description)?,
+        utc_datetime!(2009-02-13 23:31:30)
+    );
+
+    let max = OffsetDateTime::new_utc(Date::MAX, Time::MAX).unix_timestamp();
+    assert!(matches!(
+        OffsetDateTime::parse(
+            &format!("{max} +01:00"),
+            &fd::parse("[unix_timestamp] [offset_hour]:[offset_minute]")?
+        ),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(
+            error::ComponentRange { .. }
+        )))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn unix_timestamp_with_offset_keeps_offset() -> time::Result<()> {
+    let parsed = Parsed::new()
+        .with_unix_timestamp_nanos(1_234_567_890_000_000_000)
+        .and_then(|parsed| parsed.with_offset_hour(-5))
+        .and_then(|parsed| parsed.with_offset_minute_signed(-30))
+        .expect("valid components");
+    let datetime = OffsetDateTime::try_from(parsed)?;
+
+    // The moment in time is the one described by the timestamp, as it was previously.
+    assert_eq!(datetime, datetime!(2009-02-13 23:31:30 UTC));
+    // Previously the offset was discarded and the value was always in UTC. It is now represented
+    // in the parsed offset.
+    assert_ne!(datetime.offset(), UtcOffset::UTC);
+    assert_eq!(datetime.offset(), offset!(-5:30));
+    assert_eq!(datetime.date(), date!(2009-02-13));
+    assert_eq!(datetime.time(), time!(18:01:30));
+
+    Ok(())
+}
+
 #[test]
 fn end() -> time::Result<()> {
     let mut parsed = Parsed::new();
@@ -1960,3 +2783,346 @@ fn end() -> time::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn parse_strftime_offset() -> time::Result<()> {
+    let alternate = fd::parse_strftime_borrowed("%#z")?;
+    assert_eq!(UtcOffset::parse("+01", &alternate), Ok(offset!(+1)));
+    assert_eq!(UtcOffset::parse("-0130", &alternate), Ok(offset!(-1:30)));
+    assert_eq!(UtcOffset::parse("+01:30", &alternate), Ok(offset!(+1:30)));
+    assert!(UtcOffset::parse("01:30", &alternate).is_err());
+
+    for (strftime, offset, formatted) in [
+        ("%z", offset!(-1:30), "-0130"),
+        ("%:z", offset!(+1:30), "+01:30"),
+        ("%::z", offset!(+1:30:45), "+01:30:45"),
+        ("%:::z", offset!(-5), "-05"),
+        ("%#z", offset!(+1:30), "+0130"),
+    ] {
+        let description = fd::parse_strftime_borrowed(strftime)?;
+        assert_eq!(offset.format(&description)?, formatted);
+        assert_eq!(UtcOffset::parse(formatted, &description), Ok(offset));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn custom_format() {
+    /// A compact ordinal date, optionally followed by an hour.
+    struct OrdinalHour;
+
+    impl CustomParsable for OrdinalHour {
+        fn parse_into<'a>(
+            &self,
+            input: &'a [u8],
+            parsed: &mut Parsed,
+        ) -> Result<&'a [u8], error::Parse> {
+            let input = parsed.parse_items(input, time::macros::format_description!("[year][ordinal]"))?;
+            match Parsed::parse_literal(input, b"T") {
+                Ok(input) => Ok(parsed.parse_items(
+                    input,
+                    time::macros::format_description!("[hour]"),
+                )?),
+                Err(_) => Ok(input),
+            }
+        }
+    }
+
+    assert_eq!(
+        Date::parse("2021002", &Custom(OrdinalHour)),
+        Ok(date!(2021-002))
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021002T03", &Custom(OrdinalHour)),
+        Ok(datetime!(2021-002 03:00))
+    );
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021002", &Custom(OrdinalHour)),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("2021002T", &Custom(OrdinalHour)),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponentAt { name: "hour", .. }
+        ))
+    ));
+}
+
+#[test]
+fn dyn_parsable() {
+    let formats: Vec<Box<dyn Parsable>> = vec![
+        Box::new(Rfc3339),
+        Box::new(Rfc2822),
+        Box::new(time::macros::format_description!(
+            "[year]-[ordinal] [hour]:[minute] [offset_hour]"
+        )),
+    ];
+    let parse = |input| {
+        formats
+            .iter()
+            .find_map(|format| OffsetDateTime::parse(input, format).ok())
+    };
+
+    assert_eq!(parse("2021-01-02T03:04:05Z"), Some(datetime!(2021-01-02 03:04:05 UTC)));
+    assert_eq!(
+        parse("Sat, 02 Jan 2021 03:04:05 +0100"),
+        Some(datetime!(2021-01-02 03:04:05 +1))
+    );
+    assert_eq!(parse("2021-002 03:04 -02"), Some(datetime!(2021-002 03:04 -2)));
+    assert_eq!(parse("2021-01-02"), None);
+}
+
+#[test]
+fn error_position() -> time::Result<()> {
+    macro_rules! assert_position {
+        ($result:expr, $position:expr, $name:expr $(,)?) => {{
+            let err = $result.expect_err("parsing should fail");
+            assert_eq!(err.position(), Some($position));
+            assert_eq!(err.component_name(), $name);
+        }};
+    }
+
+    let description = fd::parse("[year]-[month]-[day] [hour]:[minute]")?;
+    assert_position!(
+        PrimitiveDateTime::parse("2021-13-01 00:00", &description),
+        5,
+        Some("month"),
+    );
+    assert_position!(
+        PrimitiveDateTime::parse("2021-01-01 00-00", &description),
+        13,
+        None,
+    );
+    assert_position!(
+        PrimitiveDateTime::parse("2021-01-01 00:00:00", &description),
+        16,
+        None,
+    );
+    // Values that are out of range are only detected after parsing.
+    let err = PrimitiveDateTime::parse("2021-02-30 00:00", &description)
+        .expect_err("February 30 does not exist");
+    assert_eq!(err.position(), None);
+    assert_eq!(err.component_name(), Some("day"));
+
+    assert_position!(
+        OffsetDateTime::parse("2021-01-02T03:04:5Z", &Rfc3339),
+        17,
+        Some("second"),
+    );
+    assert_position!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+01-00", &Rfc3339),
+        22,
+        None,
+    );
+    assert_position!(
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 GMTx", &Rfc2822),
+        29,
+        None,
+    );
+    assert_position!(
+        OffsetDateTime::parse("Sat, 02 Foo 2021 03:04:05 GMT", &Rfc2822),
+        8,
+        Some("month"),
+    );
+    assert_position!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+1", &Iso8601::DEFAULT),
+        19,
+        None,
+    );
+    // The time is incomplete, so only the date is parsed.
+    assert_position!(
+        OffsetDateTime::parse("2021-01-02T03:04:", &Iso8601::DEFAULT),
+        10,
+        None,
+    );
+
+    Ok(())
+}
+
+#[test]
+fn error_expected() -> time::Result<()> {
+    use time::error::Expected;
+
+    let description = fd::parse("[year]-[month]-[day] [hour]:[minute]")?;
+    let expected = |input| {
+        PrimitiveDateTime::parse(input, &description)
+            .expect_err("parsing should fail")
+            .expected()
+    };
+    assert_eq!(
+        expected("2021-13-01 00:00"),
+        Some(Expected::Component("month"))
+    );
+    assert_eq!(expected("2021-01-01 00-00"), Some(Expected::UnspecifiedLiteral));
+    assert_eq!(expected("2021-01-01 00:00:00"), Some(Expected::EndOfInput));
+    assert_eq!(expected("2021-02-30 00:00"), None);
+
+    let expected = |input| {
+        OffsetDateTime::parse(input, &Rfc3339)
+            .expect_err("parsing should fail")
+            .expected()
+    };
+    assert_eq!(
+        expected("2021-01-02T03:04:5Z"),
+        Some(Expected::Component("second"))
+    );
+    assert_eq!(
+        expected("2021-01-02T03:04:05+01-00"),
+        Some(Expected::UnspecifiedLiteral)
+    );
+    assert_eq!(
+        expected("2021-01-02T03:04:05Zx"),
+        Some(Expected::EndOfInput)
+    );
+
+    let err = PrimitiveDateTime::parse_with_options(
+        "2021-01-01 00:00",
+        &description,
+        ParserOptions::new().with_max_input_len(10),
+    )
+    .expect_err("input is too long");
+    assert_eq!(err.expected(), Some(Expected::EndOfInput));
+
+    Ok(())
+}
+
+#[test]
+fn parser_options_default() -> time::Result<()> {
+    assert_eq!(ParserOptions::default(), ParserOptions::DEFAULT);
+    assert_eq!(ParserOptions::new(), ParserOptions::DEFAULT);
+    assert_eq!(ParserOptions::DEFAULT.max_input_len(), usize::MAX);
+    assert_eq!(ParserOptions::DEFAULT.max_repetitions(), usize::MAX);
+
+    let options = ParserOptions::DEFAULT
+        .with_max_input_len(5)
+        .with_max_repetitions(6);
+    assert_eq!(options.max_input_len(), 5);
+    assert_eq!(options.max_repetitions(), 6);
+    assert!(!options.case_insensitive_literals());
+    assert!(options
+        .with_case_insensitive_literals(true)
+        .case_insensitive_literals());
+    assert!(!options.carry_allowed());
+    assert!(options.with_carry_allowed(true).carry_allowed());
+    assert_eq!(options.weekday_mode(), WeekdayMode::Ignore);
+    assert_eq!(
+        options
+            .with_weekday_mode(WeekdayMode::Prefer)
+            .weekday_mode(),
+        WeekdayMode::Prefer
+    );
+
+    let input = "2021-01-02T03:04:05.123456789012Z";
+    assert_eq!(
+        OffsetDateTime::parse_with_options(input, &Rfc3339, ParserOptions::DEFAULT)?,
+        OffsetDateTime::parse(input, &Rfc3339)?,
+    );
+    Ok(())
+}
+
+#[test]
+fn parser_options_max_input_len() -> time::Result<()> {
+    let options = ParserOptions::DEFAULT.with_max_input_len(10);
+    let description = fd::parse("[year]-[month]-[day]")?;
+
+    assert_eq!(
+        Date::parse_with_options("2021-01-02", &description, options)?,
+        date!(2021-01-02)
+    );
+    let err = Date::parse_with_options("+2021-01-02", &description, options)
+        .expect_err("input is too long");
+    assert!(matches!(
+        err,
+        error::Parse::ParseFromDescription(error::ParseFromDescription::InputTooLong { .. })
+    ));
+    assert_eq!(err.position(), Some(10));
+    assert_eq!(err.component_name(), None);
+    assert_eq!(
+        err.to_string(),
+        "the input is longer than the maximum permitted length"
+    );
+
+    assert!(Time::parse_with_options("03:04:05.0000", &Rfc3339, options).is_err());
+    assert!(UtcOffset::parse_with_options("2021-01-02T03:04:05Z", &Rfc3339, options).is_err());
+    assert!(
+        PrimitiveDateTime::parse_with_options("2021-01-02T03:04:05Z", &Rfc3339, options).is_err()
+    );
+    assert!(UtcDateTime::parse_with_options("2021-01-02T03:04:05Z", &Rfc3339, options).is_err());
+    assert!(
+        OffsetDateTime::parse_with_options("2021-01-02T03:04:05Z", &Rfc2822, options).is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn parser_options_max_repetitions() -> time::Result<()> {
+    let limited = ParserOptions::DEFAULT.with_max_repetitions(9);
+    let relaxed = ParserOptions::DEFAULT.with_max_repetitions(10);
+
+    let description = fd::parse("[hour]:[minute]:[second].[subsecond]")?;
+    assert_eq!(
+        Time::parse_with_options("03:04:05.123456789", &description, limited)?,
+        time!(03:04:05.123456789)
+    );
+    assert!(matches!(
+        Time::parse_with_options("03:04:05.1234567890", &description, limited),
+        invalid_component!("subsecond")
+    ));
+    assert!(Time::parse_with_options("03:04:05.1234567890", &description, relaxed).is_ok());
+
+    let input = "2021-01-02T03:04:05.1234567890Z";
+    assert!(matches!(
+        OffsetDateTime::parse_with_options(input, &Rfc3339, limited),
+        invalid_component!("subsecond")
+    ));
+    assert!(OffsetDateTime::parse_with_options(input, &Rfc3339, relaxed).is_ok());
+    assert!(OffsetDateTime::parse_with_options(input, &Iso8601::DEFAULT, limited).is_err());
+    assert!(OffsetDateTime::parse_with_options(input, &Iso8601::DEFAULT, relaxed).is_ok());
+
+    let input = "Sat, 02 Jan 2021 03:04:05 (((comment))) GMT";
+    assert!(OffsetDateTime::parse_with_options(input, &Rfc2822, limited).is_err());
+    assert!(OffsetDateTime::parse_with_options(input, &Rfc2822, ParserOptions::DEFAULT).is_ok());
+    let input = "Sat, 02 Jan 2021 03:04:05           GMT";
+    assert!(OffsetDateTime::parse_with_options(input, &Rfc2822, limited).is_err());
+    assert!(OffsetDateTime::parse_with_options(input, &Rfc2822, ParserOptions::DEFAULT).is_ok());
+
+    let input = "2021-01-02T03:04:05Z[UTC][u-ca=gregory][foo=bar]";
+    assert!(matches!(
+        OffsetDateTime::parse_with_options(
+            input,
+            &Rfc9557::DEFAULT,
+            ParserOptions::DEFAULT.with_max_repetitions(2)
+        ),
+        invalid_component!("annotation")
+    ));
+    assert!(OffsetDateTime::parse_with_options(
+        input,
+        &Rfc9557::DEFAULT,
+        ParserOptions::DEFAULT.with_max_repetitions(3)
+    )
+    .is_ok());
+    Ok(())
+}
+
+#[test]
+fn parser_options_case_insensitive_literals() -> time::Result<()> {
+    let description = fd::parse("[year]-[month]-[day]T[hour]:[minute]Z")?;
+    let options = ParserOptions::DEFAULT.with_case_insensitive_literals(true);
+
+    assert!(matches!(
+        PrimitiveDateTime::parse("2024-05-01t10:30z", &description),
+        invalid_literal!()
+    ));
+    assert_eq!(
+        PrimitiveDateTime::parse_with_options("2024-05-01t10:30z", &description, options)?,
+        datetime!(2024-05-01 10:30)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_with_options("2024-05-01T10:30Z", &description, options)?,
+        datetime!(2024-05-01 10:30)
+    );
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::time::Duration as StdDuration;
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, offset, time};
@@ -35,6 +36,12 @@ fn month() {
     assert_eq!(datetime!(2019-12-31 0:00).month(), Month::December);
 }
 
+#[test]
+fn month0() {
+    assert_eq!(datetime!(2019-01-01 0:00).month0(), 0);
+    assert_eq!(datetime!(2019-12-31 0:00).month0(), 11);
+}
+
 #[test]
 fn day() {
     assert_eq!(datetime!(2019-01-01 0:00).day(), 1);
@@ -47,6 +54,18 @@ fn ordinal() {
     assert_eq!(datetime!(2019-12-31 0:00).ordinal(), 365);
 }
 
+#[test]
+fn ordinal0() {
+    assert_eq!(datetime!(2019-01-01 0:00).ordinal0(), 0);
+    assert_eq!(datetime!(2019-12-31 0:00).ordinal0(), 364);
+}
+
+#[test]
+fn is_leap_year() {
+    assert!(datetime!(2020-01-01 0:00).is_leap_year());
+    assert!(!datetime!(2019-12-31 23:59).is_leap_year());
+}
+
 #[test]
 fn iso_week() {
     assert_eq!(datetime!(2019-01-01 0:00).iso_week(), 1);
@@ -136,11 +155,71 @@ fn to_julian_day() {
     assert_eq!(datetime!(2019-12-31 0:00).to_julian_day(), 2_458_849);
 }
 
+#[test]
+fn from_julian_day() {
+    assert_eq!(
+        PrimitiveDateTime::from_julian_day(0, time!(1:02:03)),
+        Ok(datetime!(-4713-11-24 1:02:03))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_julian_day(2_451_545, time!(12:00)),
+        Ok(datetime!(2000-01-01 12:00))
+    );
+    assert!(PrimitiveDateTime::from_julian_day(-363_521_075, time!(0:00)).is_err());
+    assert!(PrimitiveDateTime::from_julian_day(i32::MAX, time!(0:00)).is_err());
+}
+
+#[test]
+fn from_julian_date() {
+    assert_eq!(
+        PrimitiveDateTime::from_julian_date(2_451_545.0),
+        Ok(datetime!(2000-01-01 12:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_julian_date(2_451_544.5),
+        Ok(datetime!(2000-01-01 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_julian_date(2_451_545.25),
+        Ok(datetime!(2000-01-01 18:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_julian_date(-0.5),
+        Ok(datetime!(-4713-11-24 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_julian_date(-1.25),
+        Ok(datetime!(-4713-11-23 6:00))
+    );
+    assert!(PrimitiveDateTime::from_julian_date(f64::NAN).is_err());
+    assert!(PrimitiveDateTime::from_julian_date(f64::INFINITY).is_err());
+    assert!(PrimitiveDateTime::from_julian_date(f64::NEG_INFINITY).is_err());
+    assert!(PrimitiveDateTime::from_julian_date(1e12).is_err());
+
+    let dt = datetime!(2019-05-06 7:08:09.5);
+    let roundtrip = PrimitiveDateTime::from_julian_date(dt.to_julian_date()).expect("in range");
+    assert!((roundtrip - dt).abs() < 100.microseconds());
+}
+
+#[test]
+fn to_julian_date() {
+    assert_eq!(datetime!(-4713-11-24 12:00).to_julian_date(), 0.0);
+    assert_eq!(datetime!(-4713-11-24 0:00).to_julian_date(), -0.5);
+    assert_eq!(datetime!(2000-01-01 12:00).to_julian_date(), 2_451_545.0);
+    assert_eq!(datetime!(2000-01-01 0:00).to_julian_date(), 2_451_544.5);
+    assert_eq!(datetime!(2000-01-01 6:00).to_julian_date(), 2_451_544.75);
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(datetime!(2020-01-01 1:02:03).as_hms(), (1, 2, 3));
 }
 
+#[test]
+fn as_hm() {
+    assert_eq!(datetime!(2020-01-01 1:02:03).as_hm(), (1, 2));
+}
+
 #[test]
 fn as_hms_milli() {
     assert_eq!(
@@ -728,6 +807,84 @@ fn checked_sub_duration() {
     );
 }
 
+#[test]
+fn checked_add_std() {
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45).checked_add_std(5.std_nanoseconds()),
+        Some(datetime!(2021-10-25 14:01:53.450_000_005))
+    );
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45).checked_add_std(10.std_hours()),
+        Some(datetime!(2021-10-26 00:01:53.45))
+    );
+    assert_eq!(
+        datetime!(+999_999 - 12 - 31 23:59:59.999_999_999).checked_add_std(1.std_nanoseconds()),
+        None
+    );
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45).checked_add_std(StdDuration::MAX),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_std() {
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45).checked_sub_std(5.std_nanoseconds()),
+        Some(datetime!(2021-10-25 14:01:53.449_999_995))
+    );
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45).checked_sub_std(15.std_hours()),
+        Some(datetime!(2021-10-24 23:01:53.45))
+    );
+    assert_eq!(
+        datetime!(-999_999 - 01 - 01 0:00).checked_sub_std(1.std_nanoseconds()),
+        None
+    );
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45).checked_sub_std(StdDuration::MAX),
+        None
+    );
+}
+
+#[test]
+fn truncate_to() {
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45).truncate_to(1.seconds()),
+        datetime!(2021-10-25 14:01:53)
+    );
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45).truncate_to(15.minutes()),
+        datetime!(2021-10-25 14:00)
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX.truncate_to(1.days()),
+        PrimitiveDateTime::MAX.replace_time(time!(0:00))
+    );
+}
+
+#[test]
+fn round_to() {
+    assert_eq!(
+        datetime!(2021-10-25 14:01:53.45).round_to(1.seconds()),
+        datetime!(2021-10-25 14:01:53)
+    );
+    assert_eq!(
+        datetime!(2021-10-25 14:07:30).round_to(15.minutes()),
+        datetime!(2021-10-25 14:15)
+    );
+    assert_eq!(
+        datetime!(2021-12-31 23:59:30).round_to(1.minutes()),
+        datetime!(2022-01-01 0:00)
+    );
+}
+
+#[test]
+#[should_panic]
+fn round_to_overflow() {
+    let _ = PrimitiveDateTime::MAX.round_to(1.seconds());
+}
+
 #[test]
 fn saturating_add_duration() {
     assert_eq!(
@@ -795,3 +952,40 @@ fn saturating_sub_duration() {
         PrimitiveDateTime::MAX
     );
 }
+
+#[test]
+fn try_from_components() {
+    assert_eq!(
+        PrimitiveDateTime::try_from((2021, 1, 2, 3, 4, 5, 6)),
+        Ok(datetime!(2021-01-02 03:04:05.000_000_006))
+    );
+    assert_eq!(
+        PrimitiveDateTime::try_from((2024, 2, 29, 23, 59, 59, 999_999_999)),
+        Ok(datetime!(2024-02-29 23:59:59.999_999_999))
+    );
+
+    let names = |components: (i32, u8, u8, u8, u8, u8, u32)| {
+        PrimitiveDateTime::try_from(components)
+            .expect_err("components are invalid")
+            .iter()
+            .map(|err| err.name())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names((2021, 2, 29, 0, 0, 0, 0)), ["day"]);
+    assert_eq!(
+        names((1_000_000, 13, 32, 24, 60, 60, 1_000_000_000)),
+        [
+            "year",
+            "month",
+            "day",
+            "hour",
+            "minute",
+            "second",
+            "nanosecond"
+        ]
+    );
+    assert_eq!(names((1_000_000, 2, 30, 0, 0, 0, 0)), ["year", "day"]);
+    assert_eq!(names((1_000_000, 2, 29, 0, 0, 0, 0)), ["year"]);
+    assert_eq!(names((2021, 0, 31, 0, 0, 0, 0)), ["month"]);
+    assert_eq!(names((2021, 0, 0, 0, 0, 0, 0)), ["month", "day"]);
+}
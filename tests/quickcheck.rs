@@ -1,9 +1,13 @@
 use num_conv::prelude::*;
 use quickcheck::{Arbitrary, TestResult};
 use quickcheck_macros::quickcheck;
-use time::macros::{format_description, time};
+use time::macros::{datetime, format_description, time};
+#[allow(deprecated)]
+use time::Instant;
 use time::Weekday::*;
-use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+use time::{
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset, Weekday,
+};
 
 macro_rules! test_shrink {
     ($type:ty,
@@ -31,6 +35,14 @@ macro_rules! test_shrink {
     (@min_or_zero $min:literal) => { $min };
 }
 
+/// Repeatedly replace the value with its first shrunk value until it can no longer be shrunk.
+fn fully_shrink<T: Arbitrary>(mut value: T) -> T {
+    while let Some(shrunk) = value.shrink().next() {
+        value = shrunk;
+    }
+    value
+}
+
 macro_rules! no_panic {
     ($($x:tt)*) => {
         std::panic::catch_unwind(|| {
@@ -109,6 +121,12 @@ fn duration_roundtrip(d: Duration) -> bool {
     Duration::new(d.whole_seconds(), d.subsec_nanoseconds()) == d
 }
 
+#[quickcheck]
+fn duration_serde_human_readable_roundtrip(d: Duration) -> bool {
+    let serialized = serde_json::to_string(&d).expect("serialization is infallible");
+    serde_json::from_str::<Duration>(&serialized).ok() == Some(d)
+}
+
 #[quickcheck]
 fn time_roundtrip(t: Time) -> bool {
     Time::from_hms_nano(t.hour(), t.minute(), t.second(), t.nanosecond()) == Ok(t)
@@ -333,7 +351,6 @@ fn odt_replace_offset_no_panic(odt: OffsetDateTime, offset: UtcOffset) -> TestRe
     })
 }
 
-test_shrink!(Date, date_can_shrink_year, year());
 test_shrink!(Date, date_can_shrink_ordinal, ordinal(), min = 1);
 
 test_shrink!(Duration, duration_can_shrink_seconds, whole_seconds());
@@ -344,11 +361,6 @@ test_shrink!(Time, time_can_shrink_minute, minute());
 test_shrink!(Time, time_can_shrink_second, second());
 test_shrink!(Time, time_can_shrink_nanosecond, nanosecond());
 
-test_shrink!(
-    PrimitiveDateTime,
-    primitive_date_time_can_shrink_year,
-    year()
-);
 test_shrink!(
     PrimitiveDateTime,
     primitive_date_time_can_shrink_ordinal,
@@ -383,7 +395,6 @@ test_shrink!(
     offset_date_time_can_shrink_offset,
     offset().whole_seconds()
 );
-test_shrink!(OffsetDateTime, offset_date_time_can_shrink_year, year());
 test_shrink!(
     OffsetDateTime,
     offset_date_time_can_shrink_ordinal,
@@ -398,3 +409,69 @@ test_shrink!(
     offset_date_time_can_shrink_nanosecond,
     nanosecond()
 );
+
+#[quickcheck]
+fn date_can_shrink_year(d: Date) -> TestResult {
+    if d.year() == 1970 {
+        return TestResult::discard();
+    }
+    TestResult::from_bool(
+        d.shrink()
+            .any(|shrunk| (shrunk.year() - 1970).abs() < (d.year() - 1970).abs()),
+    )
+}
+
+#[quickcheck]
+fn date_shrinks_to_unix_epoch(d: Date) -> bool {
+    fully_shrink(d) == OffsetDateTime::UNIX_EPOCH.date()
+}
+
+#[quickcheck]
+fn duration_shrinks_to_zero(d: Duration) -> bool {
+    fully_shrink(d) == Duration::ZERO
+}
+
+#[quickcheck]
+fn time_shrinks_to_midnight(t: Time) -> bool {
+    fully_shrink(t) == Time::MIDNIGHT
+}
+
+#[quickcheck]
+fn primitive_date_time_shrinks_to_unix_epoch(pdt: PrimitiveDateTime) -> bool {
+    fully_shrink(pdt) == datetime!(1970-01-01 0:00)
+}
+
+#[quickcheck]
+fn utc_offset_shrinks_to_utc(o: UtcOffset) -> bool {
+    fully_shrink(o) == UtcOffset::UTC
+}
+
+#[quickcheck]
+fn offset_date_time_shrinks_to_unix_epoch(odt: OffsetDateTime) -> bool {
+    let shrunk = fully_shrink(odt);
+    shrunk == OffsetDateTime::UNIX_EPOCH && shrunk.offset() == UtcOffset::UTC
+}
+
+#[quickcheck]
+fn utc_date_time_shrinks_to_unix_epoch(udt: UtcDateTime) -> bool {
+    fully_shrink(udt) == UtcDateTime::UNIX_EPOCH
+}
+
+#[quickcheck]
+#[allow(deprecated)]
+fn instant_shrinks_to_common_instant(a: Instant, b: Instant) -> bool {
+    fully_shrink(a) == fully_shrink(b)
+}
+
+#[quickcheck]
+#[allow(deprecated)]
+fn instant_can_shrink(i: Instant) -> TestResult {
+    let anchor = fully_shrink(i);
+    if i == anchor {
+        return TestResult::discard();
+    }
+    TestResult::from_bool(
+        i.shrink()
+            .any(|shrunk| (shrunk - anchor).abs() < (i - anchor).abs()),
+    )
+}
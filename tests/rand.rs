@@ -1,4 +1,6 @@
 use rand::Rng;
+use time::ext::NumericalDuration;
+use time::macros::{date, datetime, offset, time};
 use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 #[test]
@@ -19,3 +21,39 @@ fn support() {
     let _ = rng.r#gen::<OffsetDateTime>();
     let _ = rng.r#gen::<Duration>();
 }
+
+#[test]
+fn uniform() {
+    // Work around rust-random/rand#1020.
+    let mut rng = rand::rngs::mock::StepRng::new(0, 656_175_560);
+
+    macro_rules! assert_in_range {
+        ($($range:expr;)*) => {$(
+            for _ in 0..16 {
+                assert!($range.contains(&rng.gen_range($range)));
+            }
+        )*};
+    }
+
+    assert_in_range! {
+        date!(2020-01-01)..date!(2021-01-01);
+        date!(2020-01-01)..=date!(2020-01-01);
+        Date::MIN..=Date::MAX;
+        time!(9:00)..time!(17:00);
+        time!(0:00)..=time!(23:59:59.999_999_999);
+        datetime!(2020-01-01 0:00)..datetime!(2020-01-02 0:00);
+        PrimitiveDateTime::MIN..=PrimitiveDateTime::MAX;
+        datetime!(2020-01-01 0:00 +1)..datetime!(2020-01-01 0:00 -1);
+        Duration::MIN..=Duration::MAX;
+        (-1).seconds()..1.seconds();
+    }
+
+    let low = PrimitiveDateTime::MIN.assume_offset(offset!(+23:59:59));
+    let high = PrimitiveDateTime::MAX.assume_offset(offset!(-23:59:59));
+    for _ in 0..16 {
+        let value = rng.gen_range(low..=high);
+        assert!((low..=high).contains(&value));
+        assert!(value.offset() == low.offset() || value.offset() == high.offset());
+    }
+    assert_eq!(rng.gen_range(high..=high).offset(), high.offset());
+}
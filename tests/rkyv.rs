@@ -0,0 +1,144 @@
+use rkyv::rancor::Error;
+use rkyv::util::AlignedVec;
+use time::macros::{date, datetime, offset, time, utc_datetime};
+use time::rkyv::{
+    ArchivedDate, ArchivedDuration, ArchivedOffsetDateTime, ArchivedPrimitiveDateTime,
+    ArchivedTime, ArchivedUtcDateTime, ArchivedUtcOffset,
+};
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset};
+
+/// Copy the bytes into an aligned buffer, as is required for accessing an archive.
+fn aligned(bytes: &[u8]) -> AlignedVec {
+    let mut vec = AlignedVec::new();
+    vec.extend_from_slice(bytes);
+    vec
+}
+
+#[test]
+fn date() -> Result<(), Error> {
+    for value in [date!(2024-01-02), Date::MIN, Date::MAX] {
+        let bytes = rkyv::to_bytes::<Error>(&value)?;
+        assert_eq!(rkyv::from_bytes::<Date, Error>(&bytes)?, value);
+    }
+
+    let bytes = rkyv::to_bytes::<Error>(&date!(2024-01-02))?;
+    assert_eq!(&bytes[..], 2_460_312_i32.to_le_bytes());
+
+    let invalid = aligned(&(Date::MAX.to_julian_day() + 1).to_le_bytes());
+    assert!(rkyv::access::<ArchivedDate, Error>(&invalid).is_err());
+    Ok(())
+}
+
+#[test]
+fn time() -> Result<(), Error> {
+    for value in [time!(0:00), time!(23:59:59.999_999_999), time!(12:34:56.789)] {
+        let bytes = rkyv::to_bytes::<Error>(&value)?;
+        assert_eq!(rkyv::from_bytes::<Time, Error>(&bytes)?, value);
+    }
+
+    let bytes = rkyv::to_bytes::<Error>(&time!(1:02:03.000_000_004))?;
+    assert_eq!(&bytes[..], [4, 0, 0, 0, 1, 2, 3, 0]);
+
+    let invalid = aligned(&[0, 0, 0, 0, 24, 0, 0, 0]);
+    assert!(rkyv::access::<ArchivedTime, Error>(&invalid).is_err());
+    let invalid = aligned(&[0x00, 0xCA, 0x9A, 0x3B, 0, 0, 0, 0]);
+    assert!(rkyv::access::<ArchivedTime, Error>(&invalid).is_err());
+    Ok(())
+}
+
+#[test]
+fn utc_offset() -> Result<(), Error> {
+    for value in [offset!(UTC), offset!(+25:59:59), offset!(-1:02:03)] {
+        let bytes = rkyv::to_bytes::<Error>(&value)?;
+        assert_eq!(rkyv::from_bytes::<UtcOffset, Error>(&bytes)?, value);
+    }
+
+    let bytes = rkyv::to_bytes::<Error>(&offset!(-1:02:03))?;
+    assert_eq!(&bytes[..], [-1_i8 as u8, -2_i8 as u8, -3_i8 as u8]);
+
+    let invalid = aligned(&[26, 0, 0]);
+    assert!(rkyv::access::<ArchivedUtcOffset, Error>(&invalid).is_err());
+    Ok(())
+}
+
+#[test]
+fn primitive_date_time() -> Result<(), Error> {
+    for value in [
+        datetime!(2024-01-02 03:04:05.678),
+        PrimitiveDateTime::MIN,
+        PrimitiveDateTime::MAX,
+    ] {
+        let bytes = rkyv::to_bytes::<Error>(&value)?;
+        assert_eq!(rkyv::from_bytes::<PrimitiveDateTime, Error>(&bytes)?, value);
+
+        let archived = rkyv::access::<ArchivedPrimitiveDateTime, Error>(&bytes)?;
+        assert_eq!(
+            rkyv::deserialize::<PrimitiveDateTime, Error>(archived)?,
+            value
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn offset_date_time() -> Result<(), Error> {
+    for value in [
+        datetime!(2024-01-02 03:04:05.678 +9:30),
+        datetime!(2024-01-02 03:04:05.678 -23:59:59),
+        PrimitiveDateTime::MIN.assume_offset(offset!(+23:59:59)),
+        PrimitiveDateTime::MAX.assume_offset(offset!(-23:59:59)),
+    ] {
+        let bytes = rkyv::to_bytes::<Error>(&value)?;
+        let deserialized = rkyv::from_bytes::<OffsetDateTime, Error>(&bytes)?;
+        assert_eq!(deserialized, value);
+        assert_eq!(deserialized.offset(), value.offset());
+
+        let archived = rkyv::access::<ArchivedOffsetDateTime, Error>(&bytes)?;
+        assert_eq!(rkyv::deserialize::<OffsetDateTime, Error>(archived)?, value);
+    }
+    Ok(())
+}
+
+#[test]
+fn utc_date_time() -> Result<(), Error> {
+    for value in [
+        utc_datetime!(2024-01-02 03:04:05.678),
+        UtcDateTime::MIN,
+        UtcDateTime::MAX,
+    ] {
+        let bytes = rkyv::to_bytes::<Error>(&value)?;
+        assert_eq!(rkyv::from_bytes::<UtcDateTime, Error>(&bytes)?, value);
+
+        let archived = rkyv::access::<ArchivedUtcDateTime, Error>(&bytes)?;
+        assert_eq!(rkyv::deserialize::<UtcDateTime, Error>(archived)?, value);
+    }
+    Ok(())
+}
+
+#[test]
+fn duration() -> Result<(), Error> {
+    for value in [
+        Duration::ZERO,
+        Duration::MIN,
+        Duration::MAX,
+        Duration::new(1, 500_000_000),
+        Duration::new(-1, -500_000_000),
+        Duration::new(0, -1),
+    ] {
+        let bytes = rkyv::to_bytes::<Error>(&value)?;
+        assert_eq!(rkyv::from_bytes::<Duration, Error>(&bytes)?, value);
+    }
+
+    let valid = [1_i64.to_le_bytes().as_slice(), &1_i32.to_le_bytes(), &[0; 4]].concat();
+    assert!(rkyv::access::<ArchivedDuration, Error>(&aligned(&valid)).is_ok());
+    let invalid = [1_i64.to_le_bytes().as_slice(), &(-1_i32).to_le_bytes(), &[0; 4]].concat();
+    assert!(rkyv::access::<ArchivedDuration, Error>(&aligned(&invalid)).is_err());
+    let invalid = [
+        0_i64.to_le_bytes().as_slice(),
+        &1_000_000_000_i32.to_le_bytes(),
+        &[0; 4],
+    ]
+    .concat();
+    assert!(rkyv::access::<ArchivedDuration, Error>(&aligned(&invalid)).is_err());
+    Ok(())
+}
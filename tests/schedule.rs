@@ -0,0 +1,166 @@
+use rstest::rstest;
+use time::error::InvalidSchedule;
+use time::macros::{datetime, offset, time};
+use time::schedule::Schedule;
+use time::{Month, OffsetDateTime, Weekday};
+
+fn occurrences(expression: &str, after: OffsetDateTime, n: usize) -> Vec<OffsetDateTime> {
+    expression
+        .parse::<Schedule>()
+        .expect("expression should be valid")
+        .after(after)
+        .take(n)
+        .collect()
+}
+
+#[test]
+fn default() {
+    assert_eq!(Schedule::default(), Schedule::new());
+    assert_eq!(Ok(Schedule::new()), "* * * * *".parse());
+}
+
+#[rstest]
+#[case("* * * * *", datetime!(2024-05-01 10:30:59.999 UTC), datetime!(2024-05-01 10:31 UTC))]
+#[case("* * * * *", datetime!(2024-05-01 23:59 UTC), datetime!(2024-05-02 0:00 UTC))]
+#[case("0 * * * *", datetime!(2024-05-01 10:00 UTC), datetime!(2024-05-01 11:00 UTC))]
+#[case("0 0 * * *", datetime!(2024-12-31 12:00 UTC), datetime!(2025-01-01 0:00 UTC))]
+#[case("5,10 * * * *", datetime!(2024-05-01 10:07 UTC), datetime!(2024-05-01 10:10 UTC))]
+#[case("5,10 * * * *", datetime!(2024-05-01 10:10 UTC), datetime!(2024-05-01 11:05 UTC))]
+#[case("*/20 * * * *", datetime!(2024-05-01 10:41 UTC), datetime!(2024-05-01 11:00 UTC))]
+#[case("10-30/10 * * * *", datetime!(2024-05-01 10:20 UTC), datetime!(2024-05-01 10:30 UTC))]
+#[case("50/5 * * * *", datetime!(2024-05-01 10:56 UTC), datetime!(2024-05-01 11:50 UTC))]
+#[case("0 0 29 2 *", datetime!(2024-03-01 0:00 UTC), datetime!(2028-02-29 0:00 UTC))]
+#[case("0 0 31 * *", datetime!(2024-04-01 0:00 UTC), datetime!(2024-05-31 0:00 UTC))]
+#[case("0 0 * * 0", datetime!(2024-05-01 0:00 UTC), datetime!(2024-05-05 0:00 UTC))]
+#[case("0 0 * * 7", datetime!(2024-05-01 0:00 UTC), datetime!(2024-05-05 0:00 UTC))]
+#[case("0 0 * * sun", datetime!(2024-05-01 0:00 UTC), datetime!(2024-05-05 0:00 UTC))]
+#[case("0 0 * * 5-7", datetime!(2024-05-01 0:00 UTC), datetime!(2024-05-03 0:00 UTC))]
+#[case("0 0 1 jan-mar *", datetime!(2024-04-01 0:00 UTC), datetime!(2025-01-01 0:00 UTC))]
+#[case("0 9 * * *", datetime!(2024-05-01 9:00 +2), datetime!(2024-05-01 9:00 UTC))]
+#[case("0 9 * * *", datetime!(2024-05-01 9:00 -2), datetime!(2024-05-02 9:00 UTC))]
+#[case("@hourly", datetime!(2024-05-01 10:00 UTC), datetime!(2024-05-01 11:00 UTC))]
+#[case("@Daily", datetime!(2024-05-01 10:00 UTC), datetime!(2024-05-02 0:00 UTC))]
+#[case("@weekly", datetime!(2024-05-01 10:00 UTC), datetime!(2024-05-05 0:00 UTC))]
+#[case("@monthly", datetime!(2024-05-01 10:00 UTC), datetime!(2024-06-01 0:00 UTC))]
+#[case("@yearly", datetime!(2024-05-01 10:00 UTC), datetime!(2025-01-01 0:00 UTC))]
+#[case("  0  0\t* *  * ", datetime!(2024-05-01 10:00 UTC), datetime!(2024-05-02 0:00 UTC))]
+fn next_after(
+    #[case] expression: &str,
+    #[case] after: OffsetDateTime,
+    #[case] expected: OffsetDateTime,
+) {
+    assert_eq!(occurrences(expression, after, 1), [expected]);
+}
+
+#[test]
+fn day_of_month_or_week() {
+    // When both are restricted, either may match.
+    assert_eq!(
+        occurrences("0 0 13 * FRI", datetime!(2024-09-01 0:00 UTC), 3),
+        [
+            datetime!(2024-09-06 0:00 UTC),
+            datetime!(2024-09-13 0:00 UTC),
+            datetime!(2024-09-20 0:00 UTC),
+        ]
+    );
+    // When only one is restricted, only it applies.
+    assert_eq!(
+        occurrences("0 0 */10 * *", datetime!(2024-09-01 0:00 UTC), 3),
+        [
+            datetime!(2024-09-11 0:00 UTC),
+            datetime!(2024-09-21 0:00 UTC),
+            datetime!(2024-10-01 0:00 UTC),
+        ]
+    );
+    assert_eq!(
+        occurrences("0 0 * * */3", datetime!(2024-09-01 0:00 UTC), 3),
+        [
+            datetime!(2024-09-04 0:00 UTC),
+            datetime!(2024-09-07 0:00 UTC),
+            datetime!(2024-09-08 0:00 UTC),
+        ]
+    );
+}
+
+#[test]
+fn builder() -> time::Result<()> {
+    let schedule = Schedule::new()
+        .weekdays(&[Weekday::Monday])
+        .at(time!(9:00:59.999))
+        .offset(offset!(+5:30));
+    assert_eq!(
+        schedule.after(datetime!(2024-05-06 3:30 UTC)).take(2).collect::<Vec<_>>(),
+        [datetime!(2024-05-13 9:00 +5:30), datetime!(2024-05-20 9:00 +5:30)]
+    );
+    assert_eq!(schedule, "0 9 * * 1".parse::<Schedule>()?.offset(offset!(+5:30)));
+
+    let schedule = Schedule::new()
+        .minutes(&[15, 45])?
+        .hours(&[12])?
+        .days(&[1, 15])?
+        .months(&[Month::February, Month::August]);
+    assert_eq!(schedule, "15,45 12 1,15 FEB,AUG *".parse()?);
+
+    assert!(Schedule::new().minutes(&[60]).is_err());
+    assert!(Schedule::new().hours(&[24]).is_err());
+    assert!(Schedule::new().days(&[0]).is_err());
+    assert!(Schedule::new().days(&[32]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn never() -> time::Result<()> {
+    assert!(occurrences("0 0 30 2 *", datetime!(2024-01-01 0:00 UTC), 1).is_empty());
+    assert!(occurrences("0 0 31 4,6,9,11 *", datetime!(2024-01-01 0:00 UTC), 1).is_empty());
+    assert_eq!(Schedule::new().minutes(&[])?.next_after(datetime!(2024-01-01 0:00 UTC)), None);
+    assert_eq!(Schedule::new().months(&[]).next_after(datetime!(2024-01-01 0:00 UTC)), None);
+
+    let mut occurrences = Schedule::new().at(time!(0:00)).after(datetime!(2024-01-01 0:00 UTC));
+    assert_eq!(occurrences.next(), Some(datetime!(2024-01-02 0:00 UTC)));
+    let mut occurrences = Schedule::new().after(OffsetDateTime::new_utc(
+        time::Date::MAX,
+        time!(23:58),
+    ));
+    assert_eq!(occurrences.next(), Some(OffsetDateTime::new_utc(time::Date::MAX, time!(23:59))));
+    assert_eq!(occurrences.next(), None);
+    assert_eq!(occurrences.next(), None);
+
+    Ok(())
+}
+
+#[rstest]
+#[case("", InvalidSchedule::InvalidFieldCount)]
+#[case("* * * *", InvalidSchedule::InvalidFieldCount)]
+#[case("* * * * * *", InvalidSchedule::InvalidFieldCount)]
+#[case("@reboot", InvalidSchedule::UnknownNickname)]
+fn from_str_error(#[case] expression: &str, #[case] expected: InvalidSchedule) {
+    assert_eq!(expression.parse::<Schedule>(), Err(expected));
+}
+
+#[rstest]
+#[case("60 * * * *", "minute")]
+#[case("a * * * *", "minute")]
+#[case("-1 * * * *", "minute")]
+#[case("+1 * * * *", "minute")]
+#[case("1,,2 * * * *", "minute")]
+#[case("*/0 * * * *", "minute")]
+#[case("*/ * * * *", "minute")]
+#[case("*/-1 * * * *", "minute")]
+#[case("*-5 * * * *", "minute")]
+#[case("30-10 * * * *", "minute")]
+#[case("* 24 * * *", "hour")]
+#[case("* * 0 * *", "day of the month")]
+#[case("* * 32 * *", "day of the month")]
+#[case("* * * 0 *", "month")]
+#[case("* * * 13 *", "month")]
+#[case("* * * JANUARY *", "month")]
+#[case("* * * * 8", "day of the week")]
+#[case("* * * * MON-", "day of the week")]
+#[case("* * * * FRI-SUN", "day of the week")]
+fn from_str_invalid_field(#[case] expression: &str, #[case] name: &str) {
+    assert!(matches!(
+        expression.parse::<Schedule>(),
+        Err(InvalidSchedule::InvalidField { name: actual, .. }) if actual == name
+    ));
+}
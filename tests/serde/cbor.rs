@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact, Configure, Token,
+};
+use time::macros::datetime;
+use time::serde::cbor;
+use time::OffsetDateTime;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+struct Test {
+    #[serde(with = "cbor")]
+    dt: OffsetDateTime,
+    #[serde(with = "cbor::option")]
+    option: Option<OffsetDateTime>,
+}
+
+fn tagged(value: Token) -> [Token; 4] {
+    [
+        Token::TupleVariant {
+            name: "@@TAG@@",
+            variant: "@@TAGGED@@",
+            len: 2,
+        },
+        Token::U64(1),
+        value,
+        Token::TupleVariantEnd,
+    ]
+}
+
+#[test]
+fn serialize_tagged() {
+    let value = Test {
+        dt: datetime!(2000-01-01 00:00:00 UTC),
+        option: Some(datetime!(2000-01-01 00:00:00.5 UTC)),
+    };
+    let mut tokens = vec![
+        Token::Struct {
+            name: "Test",
+            len: 2,
+        },
+        Token::Str("dt"),
+    ];
+    tokens.extend(tagged(Token::I64(946_684_800)));
+    tokens.extend([Token::Str("option"), Token::Some]);
+    tokens.extend(tagged(Token::F64(946_684_800.5)));
+    tokens.push(Token::StructEnd);
+    assert_tokens(&value.compact(), &tokens);
+
+    let value = Test {
+        dt: datetime!(1969-12-31 23:59:59 UTC),
+        option: None,
+    };
+    let mut tokens = vec![
+        Token::Struct {
+            name: "Test",
+            len: 2,
+        },
+        Token::Str("dt"),
+    ];
+    tokens.extend(tagged(Token::I64(-1)));
+    tokens.extend([Token::Str("option"), Token::None, Token::StructEnd]);
+    assert_tokens(&value.compact(), &tokens);
+}
+
+#[test]
+fn serialize_readable() {
+    let value = Test {
+        dt: datetime!(2000-01-01 00:00:00 UTC),
+        option: Some(datetime!(2000-01-01 00:00:00.5 UTC)),
+    };
+    assert_tokens(
+        &value.readable(),
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::I64(946_684_800),
+            Token::Str("option"),
+            Token::Some,
+            Token::F64(946_684_800.5),
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn deserialize_alternatives() {
+    let value = Test {
+        dt: datetime!(2000-01-01 00:00:00 UTC),
+        option: None,
+    };
+    assert_de_tokens(
+        &value.compact(),
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::NewtypeVariant {
+                name: "@@TAG@@",
+                variant: "@@UNTAGGED@@",
+            },
+            Token::U64(946_684_800),
+            Token::Str("option"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+    assert_de_tokens(
+        &value.compact(),
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::TupleVariant {
+                name: "@@TAG@@",
+                variant: "@@TAGGED@@",
+                len: 2,
+            },
+            Token::U64(0),
+            Token::Str("2000-01-01T00:00:00Z"),
+            Token::TupleVariantEnd,
+            Token::Str("option"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+    assert_de_tokens(
+        &Test {
+            dt: datetime!(2000-01-01 00:00:00 +01:00),
+            option: None,
+        }
+        .readable(),
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::Str("2000-01-01T00:00:00+01:00"),
+            Token::Str("option"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn deserialize_error() {
+    assert_de_tokens_error::<Compact<Test>>(
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::TupleVariant {
+                name: "@@TAG@@",
+                variant: "@@TAGGED@@",
+                len: 2,
+            },
+            Token::U64(2),
+        ],
+        "invalid value: integer `2`, expected CBOR tag 0 or 1",
+    );
+    assert_de_tokens_error::<Compact<Test>>(
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::Bool(true),
+        ],
+        "invalid type: boolean `true`, expected a CBOR date/time",
+    );
+    assert_de_tokens_error::<Compact<Test>>(
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::F64(f64::NAN),
+        ],
+        "invalid value: floating point `NaN`, expected a Unix timestamp",
+    );
+}
@@ -0,0 +1,250 @@
+use serde::{Deserialize, Serialize};
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token};
+use time::macros::{datetime, utc_datetime};
+use time::serde::fields;
+use time::{OffsetDateTime, PrimitiveDateTime, UtcDateTime};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+struct Test {
+    #[serde(with = "fields")]
+    dt: OffsetDateTime,
+    #[serde(with = "fields::option")]
+    option_dt: Option<OffsetDateTime>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct Flattened {
+    name: String,
+    #[serde(flatten, with = "fields")]
+    dt: OffsetDateTime,
+}
+
+fn datetime_tokens() -> [Token; 20] {
+    [
+        Token::Struct {
+            name: "OffsetDateTime",
+            len: 9,
+        },
+        Token::Str("year"),
+        Token::I32(2000),
+        Token::Str("ordinal"),
+        Token::U16(1),
+        Token::Str("hour"),
+        Token::U8(2),
+        Token::Str("minute"),
+        Token::U8(3),
+        Token::Str("second"),
+        Token::U8(4),
+        Token::Str("nanosecond"),
+        Token::U32(5),
+        Token::Str("offset_hours"),
+        Token::I8(6),
+        Token::Str("offset_minutes"),
+        Token::I8(7),
+        Token::Str("offset_seconds"),
+        Token::I8(8),
+        Token::StructEnd,
+    ]
+}
+
+#[test]
+fn serialize_deserialize() {
+    let dt = datetime!(2000-01-01 02:03:04.000_000_005 +06:07:08);
+    let mut tokens = vec![
+        Token::Struct {
+            name: "Test",
+            len: 2,
+        },
+        Token::Str("dt"),
+    ];
+    tokens.extend(datetime_tokens());
+    tokens.extend([Token::Str("option_dt"), Token::Some]);
+    tokens.extend(datetime_tokens());
+    tokens.push(Token::StructEnd);
+    let value = Test {
+        dt,
+        option_dt: Some(dt),
+    };
+    assert_tokens(&value.compact(), &tokens);
+    assert_tokens(&value.readable(), &tokens);
+
+    let mut tokens = vec![
+        Token::Struct {
+            name: "Test",
+            len: 2,
+        },
+        Token::Str("dt"),
+    ];
+    tokens.extend(datetime_tokens());
+    tokens.extend([Token::Str("option_dt"), Token::None, Token::StructEnd]);
+    assert_tokens(
+        &Test {
+            dt,
+            option_dt: None,
+        }
+        .compact(),
+        &tokens,
+    );
+}
+
+#[test]
+fn deserialize_alternate_forms() {
+    let dt = datetime!(2000-01-01 02:03:04.000_000_005 +06:07:08);
+    // Fields may be in any order, and unknown fields are ignored.
+    let mut tokens = datetime_tokens().to_vec();
+    tokens.swap(1, 17);
+    tokens.swap(2, 18);
+    tokens.splice(1..1, [Token::Str("unknown"), Token::Bool(true)]);
+    let tokens = tokens
+        .into_iter()
+        .map(|token| match token {
+            Token::Struct { .. } => Token::Map { len: Some(10) },
+            Token::StructEnd => Token::MapEnd,
+            token => token,
+        })
+        .collect::<Vec<_>>();
+    assert_de_tokens(&dt.compact_fields(), &tokens);
+
+    assert_de_tokens(
+        &dt.compact_fields(),
+        &[
+            Token::Seq { len: Some(9) },
+            Token::I32(2000),
+            Token::U16(1),
+            Token::U8(2),
+            Token::U8(3),
+            Token::U8(4),
+            Token::U32(5),
+            Token::I8(6),
+            Token::I8(7),
+            Token::I8(8),
+            Token::SeqEnd,
+        ],
+    );
+}
+
+#[test]
+fn flatten() -> serde_json::Result<()> {
+    let value = Flattened {
+        name: "launch".into(),
+        dt: datetime!(2000-01-01 02:03:04.000_000_005 +06:07:08),
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(
+        json,
+        r#"{"name":"launch","year":2000,"ordinal":1,"hour":2,"minute":3,"second":4,"nanosecond":5,"offset_hours":6,"offset_minutes":7,"offset_seconds":8}"#
+    );
+    assert_eq!(serde_json::from_str::<Flattened>(&json)?, value);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let mut tokens = datetime_tokens().to_vec();
+    tokens.drain(1..3);
+    assert_de_tokens_error::<Wrapper>(&tokens, "missing field `year`");
+
+    let err = serde_json::from_str::<Wrapper>(r#"{"hour":2,"hour":2}"#)
+        .expect_err("duplicate fields should be rejected");
+    assert!(err.to_string().starts_with("duplicate field `hour`"));
+
+    let mut tokens = datetime_tokens().to_vec();
+    tokens[6] = Token::U8(24);
+    assert_de_tokens_error::<Wrapper>(
+        &tokens,
+        "invalid value: integer `24`, expected a value in the range 0..=23",
+    );
+
+    assert_de_tokens_error::<Wrapper>(
+        &[Token::Seq { len: Some(1) }, Token::I32(2000), Token::SeqEnd],
+        "expected ordinal",
+    );
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+struct WithoutOffset {
+    #[serde(with = "fields::primitive_date_time")]
+    primitive: PrimitiveDateTime,
+    #[serde(with = "fields::primitive_date_time::option")]
+    option_primitive: Option<PrimitiveDateTime>,
+    #[serde(with = "fields::utc_date_time")]
+    utc: UtcDateTime,
+    #[serde(with = "fields::utc_date_time::option")]
+    option_utc: Option<UtcDateTime>,
+}
+
+fn datetime_without_offset_tokens(name: &'static str) -> [Token; 14] {
+    [
+        Token::Struct { name, len: 6 },
+        Token::Str("year"),
+        Token::I32(2000),
+        Token::Str("ordinal"),
+        Token::U16(1),
+        Token::Str("hour"),
+        Token::U8(2),
+        Token::Str("minute"),
+        Token::U8(3),
+        Token::Str("second"),
+        Token::U8(4),
+        Token::Str("nanosecond"),
+        Token::U32(5),
+        Token::StructEnd,
+    ]
+}
+
+#[test]
+fn serialize_deserialize_without_offset() {
+    let mut tokens = vec![
+        Token::Struct {
+            name: "WithoutOffset",
+            len: 4,
+        },
+        Token::Str("primitive"),
+    ];
+    tokens.extend(datetime_without_offset_tokens("PrimitiveDateTime"));
+    tokens.extend([Token::Str("option_primitive"), Token::Some]);
+    tokens.extend(datetime_without_offset_tokens("PrimitiveDateTime"));
+    tokens.push(Token::Str("utc"));
+    tokens.extend(datetime_without_offset_tokens("UtcDateTime"));
+    tokens.extend([Token::Str("option_utc"), Token::None, Token::StructEnd]);
+    let value = WithoutOffset {
+        primitive: datetime!(2000-01-01 02:03:04.000_000_005),
+        option_primitive: Some(datetime!(2000-01-01 02:03:04.000_000_005)),
+        utc: utc_datetime!(2000-01-01 02:03:04.000_000_005),
+        option_utc: None,
+    };
+    assert_tokens(&value.compact(), &tokens);
+    assert_tokens(&value.readable(), &tokens);
+}
+
+#[test]
+fn deserialize_without_offset_ignores_offset_fields() -> serde_json::Result<()> {
+    #[derive(Deserialize, Debug, PartialEq)]
+    #[serde(transparent)]
+    struct Primitive(#[serde(with = "fields::primitive_date_time")] PrimitiveDateTime);
+
+    let json = r#"{"year":2000,"ordinal":1,"hour":2,"minute":3,"second":4,"nanosecond":5,"offset_hours":6,"offset_minutes":7,"offset_seconds":8}"#;
+    assert_eq!(
+        serde_json::from_str::<Primitive>(json)?,
+        Primitive(datetime!(2000-01-01 02:03:04.000_000_005))
+    );
+    assert_de_tokens_error::<Primitive>(
+        &[Token::Seq { len: Some(1) }, Token::I32(2000), Token::SeqEnd],
+        "expected ordinal",
+    );
+    Ok(())
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(transparent)]
+struct Wrapper(#[serde(with = "fields")] OffsetDateTime);
+
+trait CompactFields {
+    fn compact_fields(self) -> serde_test::Compact<Wrapper>;
+}
+
+impl CompactFields for OffsetDateTime {
+    fn compact_fields(self) -> serde_test::Compact<Wrapper> {
+        Wrapper(self).compact()
+    }
+}
@@ -146,3 +146,77 @@ fn date_json() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+/// Deserialize from a reader, which is never able to borrow strings from the input.
+fn deserialize_owned<T: for<'a> Deserialize<'a>>(from: &str) -> serde_json::Result<T> {
+    let mut de = serde_json::Deserializer::from_reader(from.as_bytes());
+    T::deserialize((&mut de).readable())
+}
+
+#[test]
+fn owned_strings() -> Result<(), Box<dyn Error>> {
+    assert_eq!(deserialize_owned::<Weekday>("\"Monday\"")?, Weekday::Monday);
+    assert_eq!(deserialize_owned::<Month>("\"March\"")?, Month::March);
+    assert_eq!(deserialize_owned::<Time>("\"12:40:20.0\"")?, time!(12:40:20));
+    assert_eq!(deserialize_owned::<Date>("\"2022-04-05\"")?, date!(2022-04-05));
+    assert_eq!(
+        deserialize_owned::<Duration>("\"50.000000000\"")?,
+        Duration::new(50, 0)
+    );
+    assert_eq!(
+        deserialize_owned::<PrimitiveDateTime>("\"2022-05-20 12:40:20.0\"")?,
+        datetime!(2022-05-20 12:40:20)
+    );
+    assert_eq!(
+        deserialize_owned::<OffsetDateTime>("\"2022-05-20 12:40:20.0 +00:00:00\"")?,
+        datetime!(2022-05-20 12:40:20 UTC)
+    );
+    assert_eq!(
+        deserialize_owned::<Option<OffsetDateTime>>("\"2022-05-20 12:40:20.0 +00:00:00\"")?,
+        Some(datetime!(2022-05-20 12:40:20 UTC))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn escaped_strings() -> Result<(), Box<dyn Error>> {
+    time::serde::format_description!(custom, Date, "[year]/[month]/[day]");
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Test {
+        #[serde(with = "time::serde::rfc3339")]
+        rfc3339: OffsetDateTime,
+        #[serde(with = "time::serde::rfc2822")]
+        rfc2822: OffsetDateTime,
+        #[serde(with = "time::serde::iso8601::option")]
+        iso8601: Option<OffsetDateTime>,
+        #[serde(with = "custom")]
+        custom: Date,
+        default: OffsetDateTime,
+    }
+
+    // The escape sequences prevent the strings from being borrowed from the input.
+    let value: Test = serde_json::from_str(
+        r#"{
+            "rfc3339": "2022-05-20T12:40:20\u005A",
+            "rfc2822": "Fri, 20 May 2022 12:40:20 \u002B0000",
+            "iso8601": "\u002B002022-05-20T12:40:20.000000000Z",
+            "custom": "2022\/05\/20",
+            "default": "2022-05-20 12:40:20.0 \u002B00:00:00"
+        }"#,
+    )?;
+    let expected = datetime!(2022-05-20 12:40:20 UTC);
+    assert_eq!(
+        value,
+        Test {
+            rfc3339: expected,
+            rfc2822: expected,
+            iso8601: Some(expected),
+            custom: date!(2022-05-20),
+            default: expected,
+        }
+    );
+
+    Ok(())
+}
@@ -297,3 +297,58 @@ fn nested() {
         ],
     );
 }
+
+mod reexport {
+    pub(crate) use ::time::*;
+}
+
+mod formats {
+    pub(crate) mod time {
+        use ::time::format_description::BorrowedFormatItem;
+
+        pub(crate) const FORMAT: &[BorrowedFormatItem<'_>] =
+            ::time::macros::format_description!("[hour]:[minute]");
+    }
+}
+
+serde::format_description!(
+    crate = crate::serde::macros::reexport,
+    crate_path_string,
+    Time,
+    "[hour]:[minute]"
+);
+serde::format_description!(
+    crate = crate::serde::macros::reexport,
+    crate_path_path,
+    Time,
+    formats::time::FORMAT
+);
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct TestCratePath {
+    #[serde(with = "crate_path_string")]
+    time_1: Time,
+    #[serde(with = "crate_path_path")]
+    time_2: Time,
+}
+
+#[test]
+fn crate_path() {
+    assert_tokens(
+        &TestCratePath {
+            time_1: time!(12:34),
+            time_2: time!(12:34),
+        },
+        &[
+            Token::Struct {
+                name: "TestCratePath",
+                len: 2,
+            },
+            Token::Str("time_1"),
+            Token::Str("12:34"),
+            Token::Str("time_2"),
+            Token::Str("12:34"),
+            Token::StructEnd,
+        ],
+    );
+}
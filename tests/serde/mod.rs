@@ -4,10 +4,13 @@ use serde_test::{
 use time::macros::{date, datetime, offset, time};
 use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
+mod cbor;
 mod error_conditions;
+mod fields;
 mod iso8601;
 mod json;
 mod macros;
+mod msgpack;
 mod rfc2822;
 mod rfc3339;
 mod timestamps;
@@ -850,6 +853,22 @@ fn duration() {
         &Duration::nanoseconds(-123).readable(),
         &[Token::BorrowedStr("-0.000000123")],
     );
+    assert_tokens(
+        &Duration::milliseconds(-500).readable(),
+        &[Token::BorrowedStr("-0.500000000")],
+    );
+    assert_tokens(
+        &Duration::milliseconds(-1_500).readable(),
+        &[Token::BorrowedStr("-1.500000000")],
+    );
+    assert_de_tokens(
+        &Duration::ZERO.readable(),
+        &[Token::BorrowedStr("-0.000000000")],
+    );
+    assert_de_tokens(
+        &Duration::seconds(1).readable(),
+        &[Token::BorrowedStr("0001.000000000")],
+    );
 }
 
 #[test]
@@ -864,7 +883,63 @@ fn duration_error() {
     );
     assert_de_tokens_error::<Readable<Duration>>(
         &[Token::BorrowedStr("0.x")],
-        r#"invalid value: string "x", expected nanoseconds"#,
+        r#"invalid value: string "x", expected nine digits of nanoseconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("+1.000000000")],
+        r#"invalid value: string "+1", expected seconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("-.000000000")],
+        r#"invalid value: string "", expected seconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr(".000000000")],
+        r#"invalid value: string "", expected seconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("--1.000000000")],
+        r#"invalid value: string "-1", expected seconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("1 .000000000")],
+        r#"invalid value: string "1 ", expected seconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("9223372036854775808.000000000")],
+        r#"invalid value: string "9223372036854775808", expected seconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("-9223372036854775809.000000000")],
+        r#"invalid value: string "9223372036854775809", expected seconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("0.5")],
+        r#"invalid value: string "5", expected nine digits of nanoseconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("0.-500000000")],
+        r#"invalid value: string "-500000000", expected nine digits of nanoseconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("0.+50000000")],
+        r#"invalid value: string "+50000000", expected nine digits of nanoseconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("0.1000000000")],
+        r#"invalid value: string "1000000000", expected nine digits of nanoseconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("0.")],
+        r#"invalid value: string "", expected nine digits of nanoseconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("NaN.000000000")],
+        r#"invalid value: string "NaN", expected seconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("inf")],
+        r#"invalid value: string "inf", expected a decimal point"#,
     );
     assert_de_tokens_error::<Readable<Duration>>(
         &[Token::Bool(false)],
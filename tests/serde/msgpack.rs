@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact, Configure, Token,
+};
+use time::macros::datetime;
+use time::serde::msgpack;
+use time::OffsetDateTime;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Test {
+    #[serde(with = "msgpack")]
+    dt: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TestOption {
+    #[serde(with = "msgpack::option")]
+    dt: Option<OffsetDateTime>,
+}
+
+fn ext(data: &'static [u8]) -> [Token; 8] {
+    [
+        Token::Struct {
+            name: "Test",
+            len: 1,
+        },
+        Token::Str("dt"),
+        Token::NewtypeStruct {
+            name: "_ExtStruct",
+        },
+        Token::Tuple { len: 2 },
+        Token::I8(-1),
+        Token::Bytes(data),
+        Token::TupleEnd,
+        Token::StructEnd,
+    ]
+}
+
+#[test]
+fn serialize_ext() {
+    assert_tokens(
+        &Test {
+            dt: datetime!(2000-01-01 00:00:00 UTC),
+        }
+        .compact(),
+        &ext(&[0x38, 0x6D, 0x43, 0x80]),
+    );
+    assert_tokens(
+        &Test {
+            dt: datetime!(2000-01-01 00:00:00.5 UTC),
+        }
+        .compact(),
+        &ext(&[0x77, 0x35, 0x94, 0x00, 0x38, 0x6D, 0x43, 0x80]),
+    );
+    assert_tokens(
+        &Test {
+            dt: datetime!(1969-12-31 23:59:59.000_000_001 UTC),
+        }
+        .compact(),
+        &ext(&[0, 0, 0, 1, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]),
+    );
+    assert_tokens(
+        &TestOption { dt: None }.compact(),
+        &[
+            Token::Struct {
+                name: "TestOption",
+                len: 1,
+            },
+            Token::Str("dt"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn deserialize_ext() {
+    // The 96-bit format is permitted even when a smaller one would suffice.
+    assert_de_tokens(
+        &Test {
+            dt: datetime!(2000-01-01 00:00:00 UTC),
+        }
+        .compact(),
+        &ext(&[0, 0, 0, 0, 0, 0, 0, 0, 0x38, 0x6D, 0x43, 0x80]),
+    );
+    assert_de_tokens(
+        &TestOption {
+            dt: Some(datetime!(2000-01-01 00:00:00 UTC)),
+        }
+        .compact(),
+        &[
+            Token::Struct {
+                name: "TestOption",
+                len: 1,
+            },
+            Token::Str("dt"),
+            Token::Some,
+            Token::NewtypeStruct {
+                name: "_ExtStruct",
+            },
+            Token::Tuple { len: 2 },
+            Token::I8(-1),
+            Token::Seq { len: Some(4) },
+            Token::U8(0x38),
+            Token::U8(0x6D),
+            Token::U8(0x43),
+            Token::U8(0x80),
+            Token::SeqEnd,
+            Token::TupleEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn serialize_readable() {
+    assert_tokens(
+        &Test {
+            dt: datetime!(2000-01-01 00:00:00.5 UTC),
+        }
+        .readable(),
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 1,
+            },
+            Token::Str("dt"),
+            Token::Tuple { len: 2 },
+            Token::I64(946_684_800),
+            Token::U32(500_000_000),
+            Token::TupleEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn deserialize_error() {
+    assert_de_tokens_error::<Compact<Test>>(
+        &ext(&[0, 0, 0])[..6],
+        "invalid length 3, expected 4, 8, or 12 bytes",
+    );
+    assert_de_tokens_error::<Compact<Test>>(
+        &ext(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0])[..6],
+        "invalid value: integer `1073741823`, expected a value in the range 0..=999999999",
+    );
+    assert_de_tokens_error::<Compact<Test>>(
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 1,
+            },
+            Token::Str("dt"),
+            Token::NewtypeStruct {
+                name: "_ExtStruct",
+            },
+            Token::Tuple { len: 2 },
+            Token::I8(5),
+        ],
+        "invalid value: integer `5`, expected the timestamp extension type -1",
+    );
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
-use serde_test::{assert_tokens, Configure, Token};
+use serde_test::{
+    assert_de_tokens_error, assert_ser_tokens_error, assert_tokens, Configure, Token,
+};
 use time::serde::rfc2822;
 use time::OffsetDateTime;
 use time_macros::datetime;
@@ -33,6 +35,91 @@ fn serialize_deserialize() {
             Token::StructEnd,
         ],
     );
+    let value = Test {
+        dt: datetime!(2000-01-01 00:00:00 -5),
+        option_dt: None,
+    };
+    assert_tokens(
+        &value.compact(),
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::BorrowedStr("Sat, 01 Jan 2000 00:00:00 -0500"),
+            Token::Str("option_dt"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn serialize_error() {
+    let value = Test {
+        dt: datetime!(1899-12-31 00:00:00 UTC),
+        option_dt: None,
+    };
+    assert_ser_tokens_error::<Test>(
+        &value,
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 2,
+            },
+            Token::Str("dt"),
+        ],
+        "The year component cannot be formatted into the requested format.",
+    );
+    let value = Test {
+        dt: datetime!(2000-01-01 00:00:00 UTC),
+        option_dt: Some(datetime!(2000-01-01 00:00:00 +00:00:01)),
+    };
+    assert_ser_tokens_error::<Test>(
+        &value,
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::BorrowedStr("Sat, 01 Jan 2000 00:00:00 +0000"),
+            Token::Str("option_dt"),
+        ],
+        "The offset_second component cannot be formatted into the requested format.",
+    );
+}
+
+#[test]
+fn deserialize_error() {
+    assert_de_tokens_error::<Test>(
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::BorrowedStr("bad"),
+            Token::StructEnd,
+        ],
+        "the 'day' component could not be parsed",
+    );
+    assert_de_tokens_error::<Test>(
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::BorrowedStr("Sat, 01 Jan 2000 00:00:00 +0000"),
+            Token::Str("option_dt"),
+            Token::Some,
+            Token::BorrowedStr("Sat, 01 Jan 2000 00:00:00"),
+            Token::StructEnd,
+        ],
+        "a character literal was not valid",
+    );
 }
 
 #[test]
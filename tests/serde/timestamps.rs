@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
-use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
+use serde_test::{
+    assert_de_tokens_error, assert_ser_tokens_error, assert_tokens, Configure, Token,
+};
 use time::macros::datetime;
 use time::serde::timestamp;
 use time::OffsetDateTime;
@@ -136,3 +138,102 @@ fn serialize_timestamp_nanoseconds() -> serde_json::Result<()> {
     assert_eq!(serialized_nanoseconds, nanoseconds_str);
     Ok(())
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+struct TestI64 {
+    #[serde(with = "timestamp::milliseconds_i64")]
+    milliseconds: OffsetDateTime,
+    #[serde(with = "timestamp::microseconds_i64")]
+    microseconds: OffsetDateTime,
+    #[serde(with = "timestamp::nanoseconds_i64")]
+    nanoseconds: OffsetDateTime,
+    #[serde(with = "timestamp::milliseconds_i64::option")]
+    option: Option<OffsetDateTime>,
+}
+
+#[test]
+fn serialize_timestamp_i64() {
+    let value = TestI64 {
+        milliseconds: datetime!(2000-01-01 00:00:00.999 UTC),
+        microseconds: datetime!(1969-12-31 23:59:59.999_999 UTC),
+        nanoseconds: datetime!(2000-01-01 00:00:00.999_999_999 UTC),
+        option: Some(datetime!(2000-01-01 00:00:00.999 UTC)),
+    };
+    assert_tokens(
+        &value.compact(),
+        &[
+            Token::Struct {
+                name: "TestI64",
+                len: 4,
+            },
+            Token::Str("milliseconds"),
+            Token::I64(946_684_800_999),
+            Token::Str("microseconds"),
+            Token::I64(-1),
+            Token::Str("nanoseconds"),
+            Token::I64(946_684_800_999_999_999),
+            Token::Str("option"),
+            Token::Some,
+            Token::I64(946_684_800_999),
+            Token::StructEnd,
+        ],
+    );
+    assert_tokens(
+        &TestI64 {
+            option: None,
+            ..value
+        }
+        .compact(),
+        &[
+            Token::Struct {
+                name: "TestI64",
+                len: 4,
+            },
+            Token::Str("milliseconds"),
+            Token::I64(946_684_800_999),
+            Token::Str("microseconds"),
+            Token::I64(-1),
+            Token::Str("nanoseconds"),
+            Token::I64(946_684_800_999_999_999),
+            Token::Str("option"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn serialize_timestamp_i64_error() {
+    let value = TestI64 {
+        milliseconds: datetime!(2000-01-01 0:00 UTC),
+        microseconds: datetime!(2000-01-01 0:00 UTC),
+        nanoseconds: datetime!(2300-01-01 0:00 UTC),
+        option: None,
+    };
+    assert_ser_tokens_error(
+        &value.compact(),
+        &[
+            Token::Struct {
+                name: "TestI64",
+                len: 4,
+            },
+            Token::Str("milliseconds"),
+            Token::I64(946_684_800_000),
+            Token::Str("microseconds"),
+            Token::I64(946_684_800_000_000),
+            Token::Str("nanoseconds"),
+        ],
+        "timestamp with nanoseconds does not fit in an `i64`",
+    );
+    assert_de_tokens_error::<TestI64>(
+        &[
+            Token::Struct {
+                name: "TestI64",
+                len: 4,
+            },
+            Token::Str("milliseconds"),
+            Token::Str("bad"),
+        ],
+        "invalid type: string \"bad\", expected i64",
+    );
+}
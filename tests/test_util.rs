@@ -0,0 +1,86 @@
+#![allow(deprecated)]
+
+use std::thread;
+
+use time::ext::{NumericalDuration, NumericalStdDuration};
+use time::macros::{datetime, utc_datetime};
+use time::test_util::MockClock;
+use time::{Instant, OffsetDateTime, PrimitiveDateTime, UtcDateTime};
+
+#[test]
+fn set() {
+    MockClock::set(datetime!(2024-01-02 03:04:05 +1));
+    assert!(MockClock::is_frozen());
+    assert_eq!(UtcDateTime::now(), utc_datetime!(2024-01-02 02:04:05));
+    assert_eq!(
+        OffsetDateTime::now_utc(),
+        datetime!(2024-01-02 02:04:05 UTC)
+    );
+
+    let instant = Instant::now();
+    MockClock::set(datetime!(2000-01-01 0:00 UTC));
+    assert_eq!(OffsetDateTime::now_utc(), datetime!(2000-01-01 0:00 UTC));
+    assert_eq!(Instant::now(), instant);
+}
+
+#[test]
+fn freeze() {
+    MockClock::freeze();
+    assert!(MockClock::is_frozen());
+    let now = OffsetDateTime::now_utc();
+    let instant = Instant::now();
+    thread::sleep(1.std_milliseconds());
+    assert_eq!(OffsetDateTime::now_utc(), now);
+    assert_eq!(Instant::now(), instant);
+
+    MockClock::freeze();
+    assert_eq!(OffsetDateTime::now_utc(), now);
+}
+
+#[test]
+fn advance() {
+    MockClock::set(datetime!(2024-01-01 0:00 UTC));
+    let instant = Instant::now();
+
+    MockClock::advance(90.minutes());
+    assert_eq!(OffsetDateTime::now_utc(), datetime!(2024-01-01 1:30 UTC));
+    assert_eq!(Instant::now() - instant, 90.minutes());
+
+    MockClock::advance((-2).days());
+    assert_eq!(OffsetDateTime::now_utc(), datetime!(2023-12-30 1:30 UTC));
+    assert_eq!(instant - Instant::now(), 2.days() - 90.minutes());
+}
+
+#[test]
+fn advance_unfrozen() {
+    let before = OffsetDateTime::now_utc();
+    MockClock::advance(1.days());
+    assert!(MockClock::is_frozen());
+    assert!(OffsetDateTime::now_utc() >= before + 1.days());
+}
+
+#[test]
+fn reset() {
+    MockClock::set(datetime!(2000-01-01 0:00 UTC));
+    MockClock::reset();
+    assert!(!MockClock::is_frozen());
+    assert!(OffsetDateTime::now_utc().year() >= 2019);
+}
+
+#[test]
+fn per_thread() {
+    MockClock::set(datetime!(2000-01-01 0:00 UTC));
+    let (is_frozen, now) = thread::spawn(|| (MockClock::is_frozen(), OffsetDateTime::now_utc()))
+        .join()
+        .expect("thread should not panic");
+    assert!(!is_frozen);
+    assert!(now.year() >= 2019);
+    assert_eq!(OffsetDateTime::now_utc(), datetime!(2000-01-01 0:00 UTC));
+}
+
+#[test]
+#[should_panic]
+fn advance_panics() {
+    MockClock::set(PrimitiveDateTime::MAX.assume_utc());
+    MockClock::advance(1.seconds());
+}
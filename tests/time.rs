@@ -1,6 +1,6 @@
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::time;
-use time::{Result, Time};
+use time::{Duration, Result, Time};
 
 #[test]
 fn from_hms() -> Result<()> {
@@ -63,11 +63,95 @@ fn from_hms_nano() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn from_millisecond_of_day() -> Result<()> {
+    assert_eq!(Time::from_millisecond_of_day(0)?, time!(0:00));
+    assert_eq!(
+        Time::from_millisecond_of_day(3_723_004)?,
+        time!(1:02:03.004)
+    );
+    assert_eq!(
+        Time::from_millisecond_of_day(86_399_999)?,
+        time!(23:59:59.999)
+    );
+    assert!(Time::from_millisecond_of_day(86_400_000).is_err());
+    assert!(Time::from_millisecond_of_day(u32::MAX).is_err());
+    Ok(())
+}
+
+#[test]
+fn from_microsecond_of_day() -> Result<()> {
+    assert_eq!(Time::from_microsecond_of_day(0)?, time!(0:00));
+    assert_eq!(
+        Time::from_microsecond_of_day(3_723_000_004)?,
+        time!(1:02:03.000_004)
+    );
+    assert_eq!(
+        Time::from_microsecond_of_day(86_399_999_999)?,
+        time!(23:59:59.999_999)
+    );
+    assert!(Time::from_microsecond_of_day(86_400_000_000).is_err());
+    assert!(Time::from_microsecond_of_day(u64::MAX).is_err());
+    Ok(())
+}
+
+#[test]
+fn from_nanosecond_of_day() -> Result<()> {
+    assert_eq!(Time::from_nanosecond_of_day(0)?, time!(0:00));
+    assert_eq!(
+        Time::from_nanosecond_of_day(3_723_000_000_004)?,
+        time!(1:02:03.000_000_004)
+    );
+    assert_eq!(
+        Time::from_nanosecond_of_day(86_399_999_999_999)?,
+        time!(23:59:59.999_999_999)
+    );
+    assert!(matches!(
+        Time::from_nanosecond_of_day(86_400_000_000_000),
+        Err(err) if err.name() == "nanosecond_of_day"
+    ));
+    assert!(Time::from_nanosecond_of_day(u64::MAX).is_err());
+    Ok(())
+}
+
+#[test]
+fn every() {
+    assert_eq!(
+        Time::every(6.hours()).collect::<Vec<_>>(),
+        [time!(0:00), time!(6:00), time!(12:00), time!(18:00)]
+    );
+    assert_eq!(Time::every(7.hours()).last(), Some(time!(21:00)));
+    assert_eq!(Time::every(1.seconds()).count(), 86_400);
+    assert_eq!(Time::every(1.seconds()).last(), Some(time!(23:59:59)));
+    assert_eq!(Time::every(1.days()).collect::<Vec<_>>(), [time!(0:00)]);
+    assert_eq!(
+        Time::every(Duration::MAX).collect::<Vec<_>>(),
+        [time!(0:00)]
+    );
+}
+
+#[test]
+#[should_panic]
+fn every_zero() {
+    Time::every(Duration::ZERO).next();
+}
+
+#[test]
+#[should_panic]
+fn every_negative() {
+    Time::every((-1).seconds()).next();
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(time!(1:02:03).as_hms(), (1, 2, 3));
 }
 
+#[test]
+fn as_hm() {
+    assert_eq!(time!(1:02:03).as_hm(), (1, 2));
+}
+
 #[test]
 fn as_hms_milli() {
     assert_eq!(time!(1:02:03.004).as_hms_milli(), (1, 2, 3, 4));
@@ -86,6 +170,20 @@ fn as_hms_nano() {
     );
 }
 
+#[test]
+fn try_from_hms_nano() {
+    assert_eq!(
+        Time::try_from((1, 2, 3, 4_005_006)),
+        Ok(time!(1:02:03.004_005_006))
+    );
+    assert!(Time::try_from((24, 0, 0, 0)).is_err());
+    assert!(Time::try_from((0, 60, 0, 0)).is_err());
+    assert!(Time::try_from((0, 0, 60, 0)).is_err());
+    assert!(Time::try_from((0, 0, 0, 1_000_000_000)).is_err());
+    let time = time!(23:59:59.999_999_999);
+    assert_eq!(Time::try_from(time.as_hms_nano()), Ok(time));
+}
+
 #[test]
 fn hour() -> Result<()> {
     for hour in 0..24 {
@@ -146,6 +244,34 @@ fn nanosecond() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn of_day() -> Result<()> {
+    assert_eq!(time!(0:00).millisecond_of_day(), 0);
+    assert_eq!(time!(0:00).microsecond_of_day(), 0);
+    assert_eq!(time!(0:00).nanosecond_of_day(), 0);
+
+    let time = time!(1:02:03.004_005_006);
+    assert_eq!(time.millisecond_of_day(), 3_723_004);
+    assert_eq!(time.microsecond_of_day(), 3_723_004_005);
+    assert_eq!(time.nanosecond_of_day(), 3_723_004_005_006);
+
+    for time in Time::every(997.seconds() + 123_456_789.nanoseconds()) {
+        assert_eq!(
+            Time::from_nanosecond_of_day(time.nanosecond_of_day())?,
+            time
+        );
+        assert_eq!(
+            Time::from_microsecond_of_day(time.microsecond_of_day())?,
+            time.truncate_to(1.microseconds())
+        );
+        assert_eq!(
+            Time::from_millisecond_of_day(time.millisecond_of_day())?,
+            time.truncate_to(1.milliseconds())
+        );
+    }
+    Ok(())
+}
+
 #[test]
 fn replace_hour() {
     assert_eq!(
@@ -244,6 +370,82 @@ fn add_assign_duration() {
     assert_eq!(time, time!(1:01:01));
 }
 
+#[test]
+fn add_with_wrap() {
+    assert_eq!(time!(0:00).add_with_wrap(1.seconds()), (time!(0:00:01), 0));
+    assert_eq!(
+        time!(23:59:59.999_999_999).add_with_wrap(1.nanoseconds()),
+        (time!(0:00), 1)
+    );
+    assert_eq!(time!(0:00).add_with_wrap((-1).nanoseconds()), (time!(23:59:59.999_999_999), -1));
+    assert_eq!(time!(12:00).add_with_wrap(1.days()), (time!(12:00), 0));
+    assert_eq!(time!(12:00).add_with_wrap(3.days() + 13.hours()), (time!(1:00), 1));
+
+    const TIME: (Time, i8) = time!(23:00).add_with_wrap(Duration::HOUR);
+    assert_eq!(TIME, (time!(0:00), 1));
+}
+
+#[test]
+fn sub_with_wrap() {
+    assert_eq!(time!(0:00:01).sub_with_wrap(1.seconds()), (time!(0:00), 0));
+    assert_eq!(time!(0:00).sub_with_wrap(1.nanoseconds()), (time!(23:59:59.999_999_999), -1));
+    assert_eq!(
+        time!(23:59:59.999_999_999).sub_with_wrap((-1).nanoseconds()),
+        (time!(0:00), 1)
+    );
+    assert_eq!(time!(12:00).sub_with_wrap(2.days() + 13.hours()), (time!(23:00), -1));
+
+    const TIME: (Time, i8) = time!(0:00).sub_with_wrap(Duration::HOUR);
+    assert_eq!(TIME, (time!(23:00), -1));
+}
+
+#[test]
+fn truncate_to() {
+    assert_eq!(time!(12:34:56.789).truncate_to(1.seconds()), time!(12:34:56));
+    assert_eq!(time!(12:34:56.789).truncate_to(1.minutes()), time!(12:34));
+    assert_eq!(time!(12:34:56.789).truncate_to(15.minutes()), time!(12:30));
+    assert_eq!(time!(12:30).truncate_to(15.minutes()), time!(12:30));
+    assert_eq!(time!(23:59:59.999_999_999).truncate_to(7.hours()), time!(21:00));
+    assert_eq!(time!(12:34).truncate_to(1.days()), time!(0:00));
+    assert_eq!(time!(12:34).truncate_to(Duration::MAX), time!(0:00));
+    assert_eq!(time!(12:34:56.789).truncate_to(1.nanoseconds()), time!(12:34:56.789));
+
+    const TIME: Time = time!(12:34:56).truncate_to(Duration::HOUR);
+    assert_eq!(TIME, time!(12:00));
+}
+
+#[test]
+fn round_to() {
+    assert_eq!(time!(12:34:56.789).round_to(1.seconds()), time!(12:34:57));
+    assert_eq!(time!(12:34:56.499).round_to(1.seconds()), time!(12:34:56));
+    assert_eq!(time!(12:34:29).round_to(1.minutes()), time!(12:34));
+    assert_eq!(time!(12:34:30).round_to(1.minutes()), time!(12:35));
+    assert_eq!(time!(12:37:29).round_to(15.minutes()), time!(12:30));
+    assert_eq!(time!(12:37:30).round_to(15.minutes()), time!(12:45));
+    assert_eq!(time!(23:59:30).round_to(1.minutes()), time!(0:00));
+    assert_eq!(time!(23:00).round_to(7.hours()), time!(0:00));
+    assert_eq!(time!(22:29).round_to(7.hours()), time!(21:00));
+    assert_eq!(time!(22:30).round_to(7.hours()), time!(0:00));
+    assert_eq!(time!(12:00).round_to(1.days()), time!(0:00));
+    assert_eq!(time!(11:59).round_to(1.days()), time!(0:00));
+    assert_eq!(time!(12:34).round_to(Duration::MAX), time!(0:00));
+
+    const TIME: Time = time!(12:34:56).round_to(Duration::HOUR);
+    assert_eq!(TIME, time!(13:00));
+}
+
+#[test]
+#[should_panic]
+fn truncate_to_zero() {
+    let _ = time!(12:00).truncate_to(Duration::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn round_to_negative() {
+    let _ = time!(12:00).round_to((-1).seconds());
+}
+
 #[test]
 fn sub_duration() {
     assert_eq!(time!(12:00) - 1.hours(), time!(11:00));
@@ -0,0 +1,240 @@
+use time::error::InvalidLocalTime;
+use time::macros::{datetime, offset, time, utc_datetime};
+use time::time_zone::{DstRules, LocalResult, SimpleTz, TimeZone, TransitionRule};
+use time::{Month, PrimitiveDateTime, UtcDateTime, UtcOffset, Weekday};
+
+/// A time zone that is one hour ahead of UTC, except between the last Sundays of March and October
+/// 2021, when it is two hours ahead.
+struct Cet2021;
+
+impl TimeZone for Cet2021 {
+    fn offset_at(&self, datetime: UtcDateTime) -> UtcOffset {
+        if datetime >= utc_datetime!(2021-03-28 1:00) && datetime < utc_datetime!(2021-10-31 1:00) {
+            offset!(+2)
+        } else {
+            offset!(+1)
+        }
+    }
+}
+
+#[test]
+fn to_timezone() {
+    assert_eq!(
+        datetime!(2021-01-01 0:00 UTC).to_timezone(&Cet2021),
+        datetime!(2021-01-01 1:00 +1)
+    );
+    assert_eq!(
+        datetime!(2021-07-01 0:00 UTC)
+            .to_timezone(&Cet2021)
+            .offset(),
+        offset!(+2)
+    );
+    assert_eq!(
+        datetime!(2021-03-28 0:59:59 UTC).to_timezone(&Cet2021),
+        datetime!(2021-03-28 1:59:59 +1)
+    );
+    assert_eq!(
+        datetime!(2021-03-28 1:00 UTC).to_timezone(&Cet2021),
+        datetime!(2021-03-28 3:00 +2)
+    );
+    assert_eq!(
+        datetime!(2021-01-01 0:00 UTC)
+            .to_timezone(&offset!(-5))
+            .offset(),
+        offset!(-5)
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX
+            .assume_utc()
+            .checked_to_timezone(&Cet2021),
+        None
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX
+            .assume_offset(offset!(+1))
+            .checked_to_timezone(&Cet2021),
+        Some(PrimitiveDateTime::MAX.assume_offset(offset!(+1)))
+    );
+}
+
+#[test]
+#[should_panic = "local datetime out of valid range"]
+fn to_timezone_panic() {
+    PrimitiveDateTime::MAX.assume_utc().to_timezone(&Cet2021);
+}
+
+#[test]
+fn assume_timezone() {
+    assert_eq!(
+        datetime!(2021-01-01 12:00).assume_timezone(&Cet2021),
+        LocalResult::Unique(datetime!(2021-01-01 12:00 +1))
+    );
+    assert_eq!(
+        datetime!(2021-07-01 12:00).assume_timezone(&Cet2021),
+        LocalResult::Unique(datetime!(2021-07-01 12:00 +2))
+    );
+    assert_eq!(
+        datetime!(2021-03-28 1:59:59).assume_timezone(&Cet2021),
+        LocalResult::Unique(datetime!(2021-03-28 1:59:59 +1))
+    );
+    assert_eq!(
+        datetime!(2021-03-28 2:00).assume_timezone(&Cet2021),
+        LocalResult::Gap
+    );
+    assert_eq!(
+        datetime!(2021-03-28 2:59:59).assume_timezone(&Cet2021),
+        LocalResult::Gap
+    );
+    assert_eq!(
+        datetime!(2021-03-28 3:00).assume_timezone(&Cet2021),
+        LocalResult::Unique(datetime!(2021-03-28 3:00 +2))
+    );
+    assert_eq!(
+        datetime!(2021-10-31 1:59:59).assume_timezone(&Cet2021),
+        LocalResult::Unique(datetime!(2021-10-31 1:59:59 +2))
+    );
+    assert_eq!(
+        datetime!(2021-10-31 2:00).assume_timezone(&Cet2021),
+        LocalResult::Ambiguous(datetime!(2021-10-31 2:00 +2), datetime!(2021-10-31 2:00 +1))
+    );
+    assert_eq!(
+        datetime!(2021-10-31 3:00).assume_timezone(&Cet2021),
+        LocalResult::Unique(datetime!(2021-10-31 3:00 +1))
+    );
+    assert_eq!(
+        PrimitiveDateTime::MIN.assume_timezone(&offset!(-1)),
+        LocalResult::Unique(PrimitiveDateTime::MIN.assume_offset(offset!(-1)))
+    );
+    assert_eq!(
+        PrimitiveDateTime::MIN.assume_timezone(&offset!(+1)),
+        LocalResult::Gap
+    );
+    let time_zone: &dyn TimeZone = &Cet2021;
+    assert_eq!(
+        PrimitiveDateTime::MAX.assume_timezone(time_zone),
+        LocalResult::Unique(PrimitiveDateTime::MAX.assume_offset(offset!(+1)))
+    );
+}
+
+#[test]
+fn assume_timezone_checked() {
+    assert_eq!(
+        datetime!(2021-01-01 12:00).assume_timezone_checked(&Cet2021),
+        Ok(datetime!(2021-01-01 12:00 +1))
+    );
+    assert_eq!(
+        datetime!(2021-03-28 2:30).assume_timezone_checked(&Cet2021),
+        Err(InvalidLocalTime::Nonexistent)
+    );
+    assert_eq!(
+        datetime!(2021-10-31 2:30).assume_timezone_checked(&Cet2021),
+        Err(InvalidLocalTime::Ambiguous)
+    );
+}
+
+#[test]
+fn local_result() {
+    let unique = datetime!(2021-01-01 12:00).assume_timezone(&Cet2021);
+    assert_eq!(unique.unique(), Some(datetime!(2021-01-01 12:00 +1)));
+    assert_eq!(unique.earliest(), Some(datetime!(2021-01-01 12:00 +1)));
+    assert_eq!(unique.latest(), Some(datetime!(2021-01-01 12:00 +1)));
+
+    let ambiguous = datetime!(2021-10-31 2:30).assume_timezone(&Cet2021);
+    assert_eq!(ambiguous.unique(), None);
+    assert_eq!(ambiguous.earliest(), Some(datetime!(2021-10-31 2:30 +2)));
+    assert_eq!(ambiguous.latest(), Some(datetime!(2021-10-31 2:30 +1)));
+
+    let gap = datetime!(2021-03-28 2:30).assume_timezone(&Cet2021);
+    assert_eq!(gap.unique(), None);
+    assert_eq!(gap.earliest(), None);
+    assert_eq!(gap.latest(), None);
+}
+
+#[test]
+fn simple_tz_us() {
+    let tz = SimpleTz::US_EASTERN;
+    assert_eq!(tz.standard(), offset!(-5));
+    assert_eq!(tz.daylight(), offset!(-4));
+    assert_eq!(tz.rules(), DstRules::US);
+
+    assert_eq!(tz.offset_at(utc_datetime!(2021-01-01 0:00)), offset!(-5));
+    assert_eq!(tz.offset_at(utc_datetime!(2021-03-14 6:59:59)), offset!(-5));
+    assert_eq!(tz.offset_at(utc_datetime!(2021-03-14 7:00)), offset!(-4));
+    assert_eq!(tz.offset_at(utc_datetime!(2021-11-07 5:59:59)), offset!(-4));
+    assert_eq!(tz.offset_at(utc_datetime!(2021-11-07 6:00)), offset!(-5));
+    assert_eq!(tz.offset_at(utc_datetime!(2024-03-10 7:00)), offset!(-4));
+
+    assert_eq!(
+        datetime!(2021-03-14 2:30).assume_timezone(&tz),
+        LocalResult::Gap
+    );
+    assert_eq!(
+        datetime!(2021-11-07 1:30).assume_timezone(&tz),
+        LocalResult::Ambiguous(datetime!(2021-11-07 1:30 -4), datetime!(2021-11-07 1:30 -5))
+    );
+    assert_eq!(
+        datetime!(2021-11-07 1:30 -4).to_timezone(&SimpleTz::US_PACIFIC),
+        datetime!(2021-11-06 22:30 -7)
+    );
+}
+
+#[test]
+fn simple_tz_eu() {
+    let tz = SimpleTz::EU_CENTRAL;
+    for datetime in [
+        utc_datetime!(2021-01-01 0:00),
+        utc_datetime!(2021-03-28 1:00),
+        utc_datetime!(2021-07-01 0:00),
+        utc_datetime!(2021-10-31 0:59:59),
+        utc_datetime!(2021-10-31 1:00),
+    ] {
+        assert_eq!(tz.offset_at(datetime), Cet2021.offset_at(datetime));
+    }
+    assert_eq!(
+        SimpleTz::EU_WESTERN.offset_at(utc_datetime!(2021-03-28 1:00)),
+        offset!(+1)
+    );
+    assert_eq!(
+        SimpleTz::EU_EASTERN.offset_at(utc_datetime!(2021-03-28 0:59:59)),
+        offset!(+2)
+    );
+}
+
+#[test]
+fn simple_tz_southern_hemisphere() {
+    let rules = DstRules::new(
+        TransitionRule::local(Month::October, 1, Weekday::Sunday, time!(2:00)),
+        TransitionRule::local(Month::April, 1, Weekday::Sunday, time!(3:00)),
+    );
+    assert_eq!(rules.start().month(), Month::October);
+    assert_eq!(rules.start().week(), 1);
+    assert_eq!(rules.start().weekday(), Weekday::Sunday);
+    assert_eq!(rules.start().time(), time!(2:00));
+    assert!(!rules.start().is_utc());
+    assert!(DstRules::EU.end().is_utc());
+
+    let tz = SimpleTz::new(offset!(+10), offset!(+11), rules);
+    assert_eq!(tz.offset_at(utc_datetime!(2021-01-01 0:00)), offset!(+11));
+    assert_eq!(tz.offset_at(utc_datetime!(2021-04-03 15:59:59)), offset!(+11));
+    assert_eq!(tz.offset_at(utc_datetime!(2021-04-03 16:00)), offset!(+10));
+    assert_eq!(tz.offset_at(utc_datetime!(2021-10-02 15:59:59)), offset!(+10));
+    assert_eq!(tz.offset_at(utc_datetime!(2021-10-02 16:00)), offset!(+11));
+}
+
+#[test]
+fn simple_tz_extremes() {
+    assert_eq!(
+        SimpleTz::US_EASTERN.offset_at(UtcDateTime::MIN),
+        offset!(-5)
+    );
+    assert_eq!(
+        SimpleTz::US_EASTERN.offset_at(UtcDateTime::MAX),
+        offset!(-5)
+    );
+}
+
+#[test]
+#[should_panic = "week must be in the range 1..=5"]
+fn transition_rule_invalid_week() {
+    let _ = TransitionRule::utc(Month::March, 6, Weekday::Sunday, time!(1:00));
+}
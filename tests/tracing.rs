@@ -0,0 +1,58 @@
+use time::format_description::well_known::Rfc3339;
+use time::macros::{format_description, offset};
+use time::tracing::Timestamp;
+use time::{OffsetDateTime, UtcOffset};
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::time::FormatTime;
+
+fn format_time(timer: &impl FormatTime) -> Result<String, std::fmt::Error> {
+    let mut output = String::new();
+    timer.format_time(&mut Writer::new(&mut output))?;
+    Ok(output)
+}
+
+#[test]
+fn utc() -> Result<(), Box<dyn std::error::Error>> {
+    let timer = Timestamp::utc(Rfc3339);
+    assert_eq!(timer.offset(), UtcOffset::UTC);
+    assert_eq!(timer.format(), &Rfc3339);
+
+    let before = OffsetDateTime::now_utc();
+    let output = format_time(&timer)?;
+    let after = OffsetDateTime::now_utc();
+
+    assert!(output.ends_with('Z'));
+    let written = OffsetDateTime::parse(&output, &Rfc3339)?;
+    assert!(before <= written && written <= after);
+    Ok(())
+}
+
+#[test]
+fn offset() -> Result<(), Box<dyn std::error::Error>> {
+    let timer = Timestamp::new(Rfc3339, offset!(+5:30));
+    let output = format_time(&timer)?;
+    assert!(output.ends_with("+05:30"));
+    assert_eq!(
+        OffsetDateTime::parse(&output, &Rfc3339)?.offset(),
+        offset!(+5:30)
+    );
+
+    let timer = Timestamp::new(format_description!("[offset_hour]:[offset_minute]"), offset!(-2));
+    assert_eq!(format_time(&timer)?, "-02:00");
+    Ok(())
+}
+
+#[test]
+fn local() {
+    // The local offset may not be available in the test environment.
+    if let Ok(timer) = Timestamp::local(Rfc3339) {
+        assert!(format_time(&timer).is_ok());
+    }
+}
+
+#[test]
+fn error() {
+    // RFC 3339 cannot represent offsets with a nonzero second.
+    let timer = Timestamp::new(Rfc3339, offset!(+0:00:01));
+    assert!(format_time(&timer).is_err());
+}
@@ -3,7 +3,7 @@ use std::time::{Duration as StdDuration, SystemTime};
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, offset, time, utc_datetime};
-use time::{Duration, Month, OffsetDateTime, UtcDateTime, Weekday};
+use time::{error, Duration, Month, OffsetDateTime, UtcDateTime, Weekday};
 
 #[test]
 fn new() {
@@ -72,6 +72,31 @@ fn from_unix_timestamp_nanos() {
     assert!(UtcDateTime::from_unix_timestamp_nanos(i128::MAX).is_err());
 }
 
+#[test]
+fn from_unix_timestamp_millis() {
+    assert_eq!(
+        UtcDateTime::from_unix_timestamp_millis(0),
+        Ok(UtcDateTime::UNIX_EPOCH),
+    );
+    assert_eq!(
+        UtcDateTime::from_unix_timestamp_millis(-1),
+        Ok(utc_datetime!(1969-12-31 23:59:59.999)),
+    );
+    assert!(UtcDateTime::from_unix_timestamp_millis(i64::MAX).is_err());
+}
+
+#[test]
+fn from_unix_timestamp_micros() {
+    assert_eq!(
+        UtcDateTime::from_unix_timestamp_micros(0),
+        Ok(UtcDateTime::UNIX_EPOCH),
+    );
+    assert_eq!(
+        UtcDateTime::from_unix_timestamp_micros(-1),
+        Ok(utc_datetime!(1969-12-31 23:59:59.999_999)),
+    );
+}
+
 #[test]
 fn unix_timestamp() {
     assert_eq!(UtcDateTime::UNIX_EPOCH.unix_timestamp(), 0);
@@ -82,6 +107,24 @@ fn unix_timestamp_nanos() {
     assert_eq!(UtcDateTime::UNIX_EPOCH.unix_timestamp_nanos(), 0);
 }
 
+#[test]
+fn unix_timestamp_millis() {
+    assert_eq!(UtcDateTime::UNIX_EPOCH.unix_timestamp_millis(), 0);
+    assert_eq!(
+        utc_datetime!(1969-12-31 23:59:59.999_9).unix_timestamp_millis(),
+        -1
+    );
+}
+
+#[test]
+fn unix_timestamp_micros() {
+    assert_eq!(UtcDateTime::UNIX_EPOCH.unix_timestamp_micros(), 0);
+    assert_eq!(
+        utc_datetime!(1969-12-31 23:59:59.999_999_9).unix_timestamp_micros(),
+        -1
+    );
+}
+
 #[test]
 fn date() {
     assert_eq!(utc_datetime!(2019-01-01 0:00).date(), date!(2019-01-01));
@@ -102,6 +145,11 @@ fn month() {
     assert_eq!(utc_datetime!(2019-01-01 0:00).month(), Month::January);
 }
 
+#[test]
+fn month0() {
+    assert_eq!(utc_datetime!(2019-01-01 0:00).month0(), 0);
+}
+
 #[test]
 fn day() {
     assert_eq!(utc_datetime!(2019-01-01 0:00).day(), 1);
@@ -112,6 +160,17 @@ fn ordinal() {
     assert_eq!(utc_datetime!(2019-01-01 0:00).ordinal(), 1);
 }
 
+#[test]
+fn ordinal0() {
+    assert_eq!(utc_datetime!(2019-01-01 0:00).ordinal0(), 0);
+}
+
+#[test]
+fn is_leap_year() {
+    assert!(utc_datetime!(2020-01-01 0:00).is_leap_year());
+    assert!(!utc_datetime!(2019-12-31 23:59).is_leap_year());
+}
+
 #[test]
 fn iso_week() {
     assert_eq!(utc_datetime!(2019-01-01 0:00).iso_week(), 1);
@@ -194,11 +253,40 @@ fn to_julian_day() {
     assert_eq!(utc_datetime!(2019-12-31 0:00).to_julian_day(), 2_458_849);
 }
 
+#[test]
+fn from_julian_day() {
+    assert_eq!(
+        UtcDateTime::from_julian_day(2_451_545, time!(12:00)),
+        Ok(utc_datetime!(2000-01-01 12:00))
+    );
+    assert!(UtcDateTime::from_julian_day(i32::MAX, time!(0:00)).is_err());
+}
+
+#[test]
+fn from_julian_date() {
+    assert_eq!(
+        UtcDateTime::from_julian_date(2_451_545.0),
+        Ok(utc_datetime!(2000-01-01 12:00))
+    );
+    assert!(UtcDateTime::from_julian_date(f64::NAN).is_err());
+}
+
+#[test]
+fn to_julian_date() {
+    assert_eq!(utc_datetime!(2000-01-01 12:00).to_julian_date(), 2_451_545.0);
+    assert_eq!(utc_datetime!(2000-01-01 0:00).to_julian_date(), 2_451_544.5);
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(utc_datetime!(2020-01-01 1:02:03).as_hms(), (1, 2, 3));
 }
 
+#[test]
+fn as_hm() {
+    assert_eq!(utc_datetime!(2020-01-01 1:02:03).as_hm(), (1, 2));
+}
+
 #[test]
 fn as_hms_milli() {
     assert_eq!(
@@ -956,6 +1044,100 @@ fn to_std() {
     );
 }
 
+#[test]
+fn std_precision() {
+    let system_time = SystemTime::UNIX_EPOCH + StdDuration::new(1, 123_456_789);
+    let pre_epoch = SystemTime::UNIX_EPOCH - StdDuration::new(1, 123_456_789);
+
+    // `SystemTime` is stored in 100 nanosecond ticks on Windows, so any further precision is lost
+    // when it is created.
+    #[cfg(windows)]
+    let (expected, pre_epoch_expected) = (
+        utc_datetime!(1970-01-01 0:00:01.123_456_7),
+        utc_datetime!(1969-12-31 23:59:58.876_543_3),
+    );
+    #[cfg(not(windows))]
+    let (expected, pre_epoch_expected) = (
+        utc_datetime!(1970-01-01 0:00:01.123_456_789),
+        utc_datetime!(1969-12-31 23:59:58.876_543_211),
+    );
+
+    assert_eq!(UtcDateTime::from(system_time), expected);
+    assert_eq!(UtcDateTime::try_from_system_time(system_time), Ok(expected));
+    assert_eq!(SystemTime::from(expected), system_time);
+    assert_eq!(expected.try_to_system_time(), Ok(system_time));
+
+    assert_eq!(UtcDateTime::from(pre_epoch), pre_epoch_expected);
+    assert_eq!(
+        UtcDateTime::try_from_system_time(pre_epoch),
+        Ok(pre_epoch_expected)
+    );
+    assert_eq!(pre_epoch_expected.try_to_system_time(), Ok(pre_epoch));
+}
+
+#[test]
+fn try_from_std() {
+    assert_eq!(
+        UtcDateTime::try_from_system_time(SystemTime::UNIX_EPOCH),
+        Ok(UtcDateTime::UNIX_EPOCH)
+    );
+    assert_eq!(
+        UtcDateTime::try_from_system_time(SystemTime::UNIX_EPOCH - 1.std_days()),
+        Ok(UtcDateTime::UNIX_EPOCH - 1.days())
+    );
+    if let Some(system_time) = SystemTime::UNIX_EPOCH.checked_add(StdDuration::from_secs(1 << 62)) {
+        assert_eq!(
+            UtcDateTime::try_from_system_time(system_time),
+            Err(error::ConversionRange)
+        );
+    }
+    if let Some(system_time) = SystemTime::UNIX_EPOCH.checked_sub(StdDuration::from_secs(1 << 62)) {
+        assert_eq!(
+            UtcDateTime::try_from_system_time(system_time),
+            Err(error::ConversionRange)
+        );
+    }
+}
+
+#[test]
+fn saturating_from_std() {
+    assert_eq!(
+        UtcDateTime::saturating_from_system_time(SystemTime::UNIX_EPOCH + 1.std_days()),
+        UtcDateTime::UNIX_EPOCH + 1.days()
+    );
+    if let Some(system_time) = SystemTime::UNIX_EPOCH.checked_add(StdDuration::from_secs(1 << 62)) {
+        assert_eq!(
+            UtcDateTime::saturating_from_system_time(system_time),
+            UtcDateTime::MAX
+        );
+    }
+    if let Some(system_time) = SystemTime::UNIX_EPOCH.checked_sub(StdDuration::from_secs(1 << 62)) {
+        assert_eq!(
+            UtcDateTime::saturating_from_system_time(system_time),
+            UtcDateTime::MIN
+        );
+    }
+}
+
+#[test]
+fn try_to_std() {
+    assert_eq!(
+        UtcDateTime::UNIX_EPOCH.try_to_system_time(),
+        Ok(SystemTime::UNIX_EPOCH)
+    );
+    assert_eq!(
+        (UtcDateTime::UNIX_EPOCH + 1.days()).try_to_system_time(),
+        Ok(SystemTime::UNIX_EPOCH + 1.std_days())
+    );
+    // Whether the extremes can be represented is platform-specific, but the conversion must never
+    // panic and must round-trip when it succeeds.
+    for datetime in [UtcDateTime::MIN, UtcDateTime::MAX] {
+        if let Ok(system_time) = datetime.try_to_system_time() {
+            assert_eq!(UtcDateTime::try_from_system_time(system_time), Ok(datetime));
+        }
+    }
+}
+
 #[test]
 fn from_odt() {
     assert_eq!(
@@ -1128,6 +1310,56 @@ fn checked_sub_duration() {
     );
 }
 
+#[test]
+fn checked_add_std() {
+    assert_eq!(
+        utc_datetime!(2021-10-25 14:01:53.45).checked_add_std(10.std_hours()),
+        Some(utc_datetime!(2021-10-26 00:01:53.45))
+    );
+    assert_eq!(UtcDateTime::MAX.checked_add_std(1.std_nanoseconds()), None);
+    assert_eq!(
+        utc_datetime!(2021-10-25 14:01:53.45).checked_add_std(StdDuration::MAX),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_std() {
+    assert_eq!(
+        utc_datetime!(2021-10-25 14:01:53.45).checked_sub_std(15.std_hours()),
+        Some(utc_datetime!(2021-10-24 23:01:53.45))
+    );
+    assert_eq!(UtcDateTime::MIN.checked_sub_std(1.std_nanoseconds()), None);
+    assert_eq!(
+        utc_datetime!(2021-10-25 14:01:53.45).checked_sub_std(StdDuration::MAX),
+        None
+    );
+}
+
+#[test]
+fn truncate_to() {
+    assert_eq!(
+        utc_datetime!(2021-10-25 14:01:53.45).truncate_to(1.seconds()),
+        utc_datetime!(2021-10-25 14:01:53)
+    );
+    assert_eq!(
+        utc_datetime!(2021-10-25 14:01:53.45).truncate_to(15.minutes()),
+        utc_datetime!(2021-10-25 14:00)
+    );
+}
+
+#[test]
+fn round_to() {
+    assert_eq!(
+        utc_datetime!(2021-10-25 14:07:30).round_to(15.minutes()),
+        utc_datetime!(2021-10-25 14:15)
+    );
+    assert_eq!(
+        utc_datetime!(2021-12-31 23:59:30).round_to(1.minutes()),
+        utc_datetime!(2022-01-01 0:00)
+    );
+}
+
 #[test]
 fn saturating_add_duration() {
     assert_eq!(
@@ -1,5 +1,6 @@
 use rstest::rstest;
 use time::macros::offset;
+use time::error::InvalidOffset;
 use time::{OffsetDateTime, UtcOffset};
 
 #[test]
@@ -7,6 +8,15 @@ fn utc_is_zero() {
     assert_eq!(offset!(UTC), offset!(+0));
 }
 
+#[test]
+fn min_max() {
+    assert_eq!(UtcOffset::MIN, offset!(-25:59:59));
+    assert_eq!(UtcOffset::MAX, offset!(+25:59:59));
+    assert_eq!(UtcOffset::MIN, -UtcOffset::MAX);
+    assert!(UtcOffset::from_whole_seconds(UtcOffset::MIN.whole_seconds() - 1).is_err());
+    assert!(UtcOffset::from_whole_seconds(UtcOffset::MAX.whole_seconds() + 1).is_err());
+}
+
 #[rstest]
 #[case(0, 0, 0, offset!(UTC))]
 #[case(0, 0, 1, offset!(+0:00:01))]
@@ -43,6 +53,63 @@ fn from_whole_seconds(#[case] seconds: i32, #[case] expected: UtcOffset) {
     assert_eq!(UtcOffset::from_whole_seconds(seconds), Ok(expected));
 }
 
+#[rstest]
+#[case(0, offset!(UTC))]
+#[case(1, offset!(+0:01))]
+#[case(-1, offset!(-0:01))]
+#[case(330, offset!(+5:30))]
+#[case(-62, offset!(-1:02))]
+#[case(1_559, offset!(+25:59))]
+#[case(-1_559, offset!(-25:59))]
+fn from_whole_minutes(#[case] minutes: i16, #[case] expected: UtcOffset) {
+    assert_eq!(UtcOffset::from_whole_minutes(minutes), Ok(expected));
+    assert_eq!(expected.whole_minutes(), minutes);
+}
+
+#[test]
+fn from_whole_minutes_error() {
+    assert!(UtcOffset::from_whole_minutes(1_560).is_err());
+    assert!(UtcOffset::from_whole_minutes(-1_560).is_err());
+    assert!(UtcOffset::from_whole_minutes(i16::MAX).is_err());
+}
+
+#[test]
+fn const_context() {
+    const OFFSETS: [UtcOffset; 2] = [
+        match UtcOffset::from_whole_seconds(-18_000) {
+            Ok(offset) => offset,
+            Err(_) => panic!(),
+        },
+        match UtcOffset::from_whole_minutes(330) {
+            Ok(offset) => offset,
+            Err(_) => panic!(),
+        },
+    ];
+    const HMS: (i8, i8, i8) = OFFSETS[1].as_hms();
+    const MINUTES: i16 = OFFSETS[0].whole_minutes();
+
+    assert_eq!(OFFSETS, [offset!(-5), offset!(+5:30)]);
+    assert_eq!(HMS, (5, 30, 0));
+    assert_eq!(MINUTES, -300);
+}
+
+#[test]
+fn all_whole_minutes() {
+    let offsets = UtcOffset::all_whole_minutes().collect::<Vec<_>>();
+    assert_eq!(offsets.len(), 3_119);
+    assert_eq!(offsets.first(), Some(&offset!(-25:59)));
+    assert_eq!(offsets.last(), Some(&offset!(+25:59)));
+    assert!(offsets.contains(&offset!(UTC)));
+    assert!(offsets.contains(&offset!(-0:01)));
+    assert!(offsets.contains(&offset!(+5:45)));
+    assert!(offsets.iter().all(|offset| offset.seconds_past_minute() == 0));
+    assert!(
+        offsets
+            .windows(2)
+            .all(|pair| pair[1].whole_minutes() == pair[0].whole_minutes() + 1)
+    );
+}
+
 #[rstest]
 #[case(offset!(UTC), (0, 0, 0))]
 #[case(offset!(+0:00:01), (0, 0, 1))]
@@ -155,6 +222,58 @@ fn neg(#[case] offset: UtcOffset, #[case] expected: UtcOffset) {
     assert_eq!(-offset, expected);
 }
 
+#[rstest]
+#[case("+00:00:00", offset!(UTC))]
+#[case("-00:00:00", offset!(UTC))]
+#[case("+01:02:03", offset!(+1:02:03))]
+#[case("-01:02:03", offset!(-1:02:03))]
+#[case("-00:30", offset!(-0:30))]
+#[case("+25", offset!(+25))]
+#[case("UTC", offset!(UTC))]
+#[case("utc", offset!(UTC))]
+#[case("Z", offset!(UTC))]
+#[case("z", offset!(UTC))]
+fn from_str(#[case] input: &str, #[case] expected: UtcOffset) {
+    assert_eq!(input.parse(), Ok(expected));
+}
+
+#[rstest]
+#[case("")]
+#[case("01:00")]
+#[case("+1")]
+#[case("+01:0")]
+#[case("+01:00:")]
+#[case("+01:00:00.5")]
+#[case("+01-00")]
+#[case("+0a")]
+#[case("GMT")]
+fn from_str_invalid_syntax(#[case] input: &str) {
+    assert_eq!(
+        input.parse::<UtcOffset>(),
+        Err(InvalidOffset::InvalidSyntax)
+    );
+}
+
+#[rstest]
+#[case("+26")]
+#[case("-01:60")]
+#[case("+01:00:60")]
+fn from_str_out_of_range(#[case] input: &str) {
+    assert!(matches!(
+        input.parse::<UtcOffset>(),
+        Err(InvalidOffset::ComponentRange(_))
+    ));
+}
+
+#[rstest]
+#[case(offset!(UTC))]
+#[case(offset!(+1:02:03))]
+#[case(offset!(-0:00:01))]
+#[case(offset!(-25:59:59))]
+fn display_round_trip(#[case] offset: UtcOffset) {
+    assert_eq!(offset.to_string().parse(), Ok(offset));
+}
+
 #[test]
 fn local_offset_at() {
     assert!(UtcOffset::local_offset_at(OffsetDateTime::UNIX_EPOCH).is_ok());
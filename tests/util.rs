@@ -44,6 +44,46 @@ fn is_leap_year(#[case] year: i32, #[case] expected: bool) {
     assert_eq!(util::is_leap_year(year), expected);
 }
 
+#[rstest]
+#[case(1895, Some(1896), Some(1892))]
+#[case(1896, Some(1904), Some(1892))]
+#[case(1900, Some(1904), Some(1896))]
+#[case(1999, Some(2000), Some(1996))]
+#[case(2000, Some(2004), Some(1996))]
+#[case(2100, Some(2104), Some(2096))]
+#[case(-1, Some(0), Some(-4))]
+#[case(0, Some(4), Some(-4))]
+#[case(i32::MAX, None, Some(i32::MAX - 3))]
+#[case(i32::MIN, Some(i32::MIN + 4), None)]
+fn adjacent_leap_year(
+    #[case] year: i32,
+    #[case] next: Option<i32>,
+    #[case] previous: Option<i32>,
+) {
+    assert_eq!(util::next_leap_year(year), next);
+    assert_eq!(util::previous_leap_year(year), previous);
+}
+
+#[rstest]
+#[case(2024, Some(12_024))]
+#[case(0, Some(10_000))]
+#[case(-10_000, Some(0))]
+#[case(i32::MAX - 10_000, Some(i32::MAX))]
+#[case(i32::MAX, None)]
+fn holocene_year(#[case] year: i32, #[case] expected: Option<i32>) {
+    assert_eq!(util::holocene_year(year), expected);
+    if let Some(holocene) = expected {
+        assert_eq!(util::year_from_holocene(holocene), Some(year));
+    }
+}
+
+#[test]
+fn year_from_holocene() {
+    assert_eq!(util::year_from_holocene(1), Some(-9_999));
+    assert_eq!(util::year_from_holocene(i32::MIN + 10_000), Some(i32::MIN));
+    assert_eq!(util::year_from_holocene(i32::MIN), None);
+}
+
 #[rstest]
 #[case(1900, 365)]
 #[case(2000, 366)]
@@ -82,6 +122,12 @@ fn weeks_in_year() {
     }
 }
 
+#[rstest]
+fn is_large_dates_enabled() {
+    // Tests are run with all features enabled.
+    assert!(util::is_large_dates_enabled());
+}
+
 #[rstest]
 #[allow(deprecated)]
 fn local_offset_soundness() {
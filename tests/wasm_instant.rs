@@ -0,0 +1,59 @@
+use time::ext::{NumericalDuration, NumericalStdDuration};
+use time::{Duration, WasmInstant};
+
+#[test]
+fn elapsed() {
+    let instant = WasmInstant::now();
+    assert!(instant.elapsed() >= Duration::ZERO);
+    assert!((instant + 1.days()).elapsed() < Duration::ZERO);
+}
+
+#[test]
+fn checked_duration_since() {
+    let now = WasmInstant::now() + 1.seconds();
+    assert_eq!(now.checked_duration_since(now), Some(Duration::ZERO));
+    assert_eq!(
+        (now + 5.seconds()).checked_duration_since(now),
+        Some(5.seconds())
+    );
+    assert_eq!(
+        (now - 1.seconds()).checked_duration_since(now),
+        Some((-1).seconds())
+    );
+}
+
+#[test]
+fn saturating_duration_since() {
+    let now = WasmInstant::now() + 1.seconds();
+    assert_eq!(
+        (now + 5.seconds()).saturating_duration_since(now),
+        5.seconds()
+    );
+    assert_eq!(
+        (now - 1.seconds()).saturating_duration_since(now),
+        (-1).seconds()
+    );
+}
+
+#[test]
+fn checked_arithmetic() {
+    let now = WasmInstant::now();
+    assert_eq!(now.checked_add(0.seconds()), Some(now));
+    assert_eq!(now.checked_add(5.seconds()), Some(now + 5.seconds()));
+    assert_eq!(now.checked_sub((-5).seconds()), Some(now + 5.seconds()));
+    assert_eq!(now.checked_add(Duration::MAX), None);
+    // Instants preceding the time origin cannot be represented.
+    assert_eq!(now.checked_sub(Duration::MAX), None);
+}
+
+#[test]
+fn std_duration_arithmetic() {
+    let now = WasmInstant::now();
+    assert_eq!(now + 5.std_seconds(), now + 5.seconds());
+    assert_eq!(now + 5.std_seconds() - 5.std_seconds(), now);
+
+    let mut instant = now;
+    instant += 5.std_seconds();
+    instant -= 2.seconds();
+    assert_eq!(instant - now, 3.seconds());
+}
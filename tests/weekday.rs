@@ -71,6 +71,25 @@ fn nth_prev(#[case] current: Weekday, #[case] n: u8, #[case] expected: Weekday)
     assert_eq!(current.nth_prev(n), expected);
 }
 
+#[rstest]
+#[case(1, Monday, Monday)]
+#[case(7, Monday, Sunday)]
+#[case(1, Sunday, Sunday)]
+#[case(2, Sunday, Monday)]
+#[case(7, Sunday, Saturday)]
+#[case(3, Friday, Sunday)]
+fn from_number(#[case] n: u8, #[case] week_start: Weekday, #[case] expected: Weekday) {
+    assert_eq!(Weekday::from_number(n, week_start), Ok(expected));
+}
+
+#[rstest]
+#[case(0)]
+#[case(8)]
+#[case(u8::MAX)]
+fn from_number_error(#[case] n: u8) {
+    assert!(Weekday::from_number(n, Monday).is_err());
+}
+
 #[rstest]
 #[case(Monday, 1)]
 #[case(Tuesday, 2)]
@@ -139,7 +158,17 @@ fn display(#[case] weekday: Weekday, #[case] expected: &str) {
 #[case("Friday", Ok(Friday))]
 #[case("Saturday", Ok(Saturday))]
 #[case("Sunday", Ok(Sunday))]
+#[case("monday", Ok(Monday))]
+#[case("TUESDAY", Ok(Tuesday))]
+#[case("Wed", Ok(Wednesday))]
+#[case("thu", Ok(Thursday))]
+#[case("FRI", Ok(Friday))]
+#[case("sAt", Ok(Saturday))]
+#[case("Sun", Ok(Sunday))]
 #[case("foo", Err(time::error::InvalidVariant))]
+#[case("Mo", Err(time::error::InvalidVariant))]
+#[case("Mond", Err(time::error::InvalidVariant))]
+#[case("", Err(time::error::InvalidVariant))]
 fn from_str(#[case] input: &str, #[case] expected: Result<Weekday, time::error::InvalidVariant>) {
     assert_eq!(input.parse::<Weekday>(), expected);
 }
@@ -0,0 +1,86 @@
+//! Helpers for describing errors in format descriptions. These are shared by `time` and
+//! `time-macros` so that errors are identical at runtime and at compile time.
+
+use core::fmt;
+
+/// The names of all components, used to suggest a replacement for an invalid one.
+pub const COMPONENT_NAMES: [&str; 19] = [
+    "day",
+    "end",
+    "first",
+    "hour",
+    "ignore",
+    "minute",
+    "month",
+    "offset_hour",
+    "offset_minute",
+    "offset_second",
+    "optional",
+    "ordinal",
+    "period",
+    "second",
+    "subsecond",
+    "unix_timestamp",
+    "week_number",
+    "weekday",
+    "year",
+];
+
+/// An upper bound on the length of the names in [`COMPONENT_NAMES`].
+const MAX_COMPONENT_NAME_LEN: usize = 14;
+
+const _: () = {
+    let mut i = 0;
+    while i < COMPONENT_NAMES.len() {
+        assert!(COMPONENT_NAMES[i].len() <= MAX_COMPONENT_NAME_LEN);
+        i += 1;
+    }
+};
+
+/// Obtain the component name that is closest to the provided one, if any is close enough to
+/// plausibly be what was intended. The comparison ignores ASCII case.
+pub fn closest_component_name(name: &str) -> Option<&'static str> {
+    COMPONENT_NAMES
+        .iter()
+        .map(|&candidate| (candidate, edit_distance(name, candidate)))
+        .filter(|&(_, distance)| distance <= 2 && distance < name.len())
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// The Levenshtein distance between a string and a component name, counted in bytes and ignoring
+/// ASCII case.
+fn edit_distance(a: &str, component_name: &str) -> usize {
+    let b = component_name.as_bytes();
+    let mut row = [0; MAX_COMPONENT_NAME_LEN + 1];
+    for (j, cell) in row.iter_mut().enumerate().take(b.len() + 1) {
+        *cell = j;
+    }
+    for (i, a_byte) in a.bytes().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_byte) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(!a_byte.eq_ignore_ascii_case(b_byte));
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Write a suggestion to add something that was expected but not found, such as "add an opening
+/// bracket".
+pub fn write_expected_suggestion(f: &mut impl fmt::Write, what: &str) -> fmt::Result {
+    // Uncountable nouns take no article.
+    if what.starts_with("whitespace") {
+        return write!(f, "add {what}");
+    }
+    let starts_with_vowel = what
+        .bytes()
+        .find(u8::is_ascii_alphabetic)
+        .map_or(false, |byte| {
+            matches!(byte.to_ascii_lowercase(), b'a' | b'e' | b'i' | b'o' | b'u')
+        });
+    let article = if starts_with_vowel { "an" } else { "a" };
+    write!(f, "add {article} {what}")
+}
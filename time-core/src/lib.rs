@@ -8,4 +8,5 @@
 #![doc(test(attr(deny(warnings))))]
 
 pub mod convert;
+pub mod format_description;
 pub mod util;
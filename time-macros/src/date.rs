@@ -1,13 +1,13 @@
 use std::iter::Peekable;
 
 use num_conv::Truncate;
-use proc_macro::{token_stream, TokenTree};
+use proc_macro::{token_stream, TokenStream};
 use time_core::util::{days_in_year, weeks_in_year};
 
 use crate::helpers::{
     consume_any_ident, consume_number, consume_punct, days_in_year_month, ymd_to_yo, ywd_to_yo,
 };
-use crate::to_tokens::ToTokenTree;
+use crate::to_tokens::ToTokenStreamWithPath;
 use crate::Error;
 
 #[cfg(feature = "large-dates")]
@@ -34,6 +34,12 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Date
         return Err(Error::InvalidComponent {
             name: "year",
             value: year.to_string(),
+            range: Some((-i64::from(MAX_YEAR), i64::from(MAX_YEAR))),
+            help: (!cfg!(feature = "large-dates") && year.abs() <= 999_999).then_some(
+                "enable the `large-dates` feature of `time` to use years with more than four \
+                 digits"
+                    .into(),
+            ),
             span_start: Some(year_sign_span.unwrap_or(year_span)),
             span_end: Some(year_span),
         });
@@ -54,10 +60,12 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Date
         consume_punct('-', chars)?;
         let (day_span, day) = consume_number::<u8>("day", chars)?;
 
-        if week > weeks_in_year(year) {
+        if week == 0 || week > weeks_in_year(year) {
             return Err(Error::InvalidComponent {
                 name: "week",
                 value: week.to_string(),
+                range: Some((1, weeks_in_year(year).into())),
+                help: (week == 53).then(|| format!("{year} has 52 ISO weeks").into()),
                 span_start: Some(w_span),
                 span_end: Some(week_span),
             });
@@ -66,6 +74,8 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Date
             return Err(Error::InvalidComponent {
                 name: "day",
                 value: day.to_string(),
+                range: Some((1, 7)),
+                help: (day == 0).then_some("Sunday is day 7 of the ISO week".into()),
                 span_start: Some(day_span),
                 span_end: Some(day_span),
             });
@@ -89,6 +99,8 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Date
             return Err(Error::InvalidComponent {
                 name: "month",
                 value: month.to_string(),
+                range: Some((1, 12)),
+                help: None,
                 span_start: Some(month_span),
                 span_end: Some(month_span),
             });
@@ -98,6 +110,9 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Date
             return Err(Error::InvalidComponent {
                 name: "day",
                 value: day.to_string(),
+                range: Some((1, days_in_year_month(year, month).into())),
+                help: (month == 2 && day == 29)
+                    .then(|| format!("{year} is not a leap year").into()),
                 span_start: Some(day_span),
                 span_end: Some(day_span),
             });
@@ -115,6 +130,8 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Date
             return Err(Error::InvalidComponent {
                 name: "ordinal",
                 value: ordinal.to_string(),
+                range: Some((1, days_in_year(year).into())),
+                help: (ordinal == 366).then(|| format!("{year} is not a leap year").into()),
                 span_start: Some(ordinal_span),
                 span_end: Some(ordinal_span),
             });
@@ -124,16 +141,20 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Date
     }
 }
 
-impl ToTokenTree for Date {
-    fn into_token_tree(self) -> TokenTree {
-        quote_group! {{
-            const DATE: ::time::Date = unsafe {
-                ::time::Date::__from_ordinal_date_unchecked(
+impl ToTokenStreamWithPath for Date {
+    fn append_to_with_path(self, ts: &mut TokenStream, crate_path: &TokenStream) {
+        ts.extend([quote_group! {{
+            const DATE: #S(crate_path.clone())::Date = if let ::core::result::Result::Ok(date) =
+                #S(crate_path.clone())::Date::from_ordinal_date(
                     #(self.year),
                     #(self.ordinal),
                 )
+            {
+                date
+            } else {
+                ::core::panic!("invalid date")
             };
             DATE
-        }}
+        }}]);
     }
 }
@@ -1,18 +1,18 @@
 use std::iter::Peekable;
 
-use proc_macro::{token_stream, Ident, Span, TokenTree};
+use proc_macro::{token_stream, Ident, Span, TokenStream};
 
 use crate::date::Date;
 use crate::error::Error;
-use crate::offset::Offset;
+use crate::offset::OffsetExpression;
 use crate::time::Time;
-use crate::to_tokens::ToTokenTree;
+use crate::to_tokens::ToTokenStreamWithPath;
 use crate::{date, offset, time};
 
 pub(crate) struct DateTime {
     date: Date,
     time: Time,
-    offset: Option<Offset>,
+    offset: Option<OffsetExpression>,
 }
 
 pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<DateTime, Error> {
@@ -33,12 +33,12 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Date
     Ok(DateTime { date, time, offset })
 }
 
-impl ToTokenTree for DateTime {
-    fn into_token_tree(self) -> TokenTree {
+impl ToTokenStreamWithPath for DateTime {
+    fn append_to_with_path(self, ts: &mut TokenStream, crate_path: &TokenStream) {
         let (type_name, maybe_offset) = match self.offset {
             Some(offset) => (
                 Ident::new("OffsetDateTime", Span::mixed_site()),
-                quote!(.assume_offset(#(offset))),
+                quote!(.assume_offset(#S(offset.into_token_stream_with_path(crate_path)))),
             ),
             None => (
                 Ident::new("PrimitiveDateTime", Span::mixed_site()),
@@ -46,12 +46,13 @@ impl ToTokenTree for DateTime {
             ),
         };
 
-        quote_group! {{
-            const DATE_TIME: ::time::#(type_name) = ::time::PrimitiveDateTime::new(
-                #(self.date),
-                #(self.time),
-            ) #S(maybe_offset);
+        ts.extend([quote_group! {{
+            const DATE_TIME: #S(crate_path.clone())::#(type_name) =
+                #S(crate_path.clone())::PrimitiveDateTime::new(
+                    #S(self.date.into_token_stream_with_path(crate_path)),
+                    #S(self.time.into_token_stream_with_path(crate_path)),
+                ) #S(maybe_offset);
             DATE_TIME
-        }}
+        }}]);
     }
 }
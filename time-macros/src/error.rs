@@ -23,6 +23,10 @@ pub(crate) enum Error {
     InvalidComponent {
         name: &'static str,
         value: String,
+        /// The inclusive range of values the component may take, if known.
+        range: Option<(i64, i64)>,
+        /// A suggestion of how to correct the value.
+        help: Option<Cow<'static, str>>,
         span_start: Option<Span>,
         span_end: Option<Span>,
     },
@@ -46,8 +50,21 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::MissingComponent { name, .. } => write!(f, "missing component: {name}"),
-            Self::InvalidComponent { name, value, .. } => {
-                write!(f, "invalid component: {name} was {value}")
+            Self::InvalidComponent {
+                name,
+                value,
+                range,
+                help,
+                ..
+            } => {
+                write!(f, "invalid component: {name} was {value}")?;
+                if let Some((min, max)) = range {
+                    write!(f, ", but must be in the range {min}..={max}")?;
+                }
+                if let Some(help) = help {
+                    write!(f, "\nhelp: {help}")?;
+                }
+                Ok(())
             }
             #[cfg(any(feature = "formatting", feature = "parsing"))]
             Self::ExpectedString { .. } => f.write_str("expected string literal"),
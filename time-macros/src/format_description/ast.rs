@@ -1,6 +1,8 @@
 use std::iter;
 
-use super::{lexer, unused, Error, Location, Spanned, SpannedValue, Unused};
+use super::{
+    lexer, unused, Error, InvalidFormatDescription, Location, Spanned, SpannedValue, Unused,
+};
 
 pub(super) enum Item<'a> {
     Literal(Spanned<&'a [u8]>),
@@ -131,18 +133,27 @@ fn parse_component<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, cons
             Some(Spanned { value: _, span }) => span,
             None => opening_bracket.to(opening_bracket),
         };
-        return Err(span.error("expected component name"));
+        return Err(span.error(InvalidFormatDescription::MissingComponentName {
+            index: span.start.byte as _,
+        }));
     };
 
     if *name == b"optional" {
         let Some(whitespace) = tokens.next_if_whitespace() else {
-            return Err(name.span.error("expected whitespace after `optional`"));
+            return Err(name.span.error(InvalidFormatDescription::Expected {
+                what: "whitespace after `optional`",
+                index: name.span.end.byte as _,
+            }));
         };
 
         let nested = parse_nested::<_, VERSION>(whitespace.span.end, tokens)?;
 
         let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
-            return Err(opening_bracket.error("unclosed bracket"));
+            return Err(
+                opening_bracket.error(InvalidFormatDescription::UnclosedOpeningBracket {
+                    index: opening_bracket.byte as _,
+                }),
+            );
         };
 
         return Ok(Item::Optional {
@@ -157,7 +168,10 @@ fn parse_component<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, cons
 
     if *name == b"first" {
         let Some(whitespace) = tokens.next_if_whitespace() else {
-            return Err(name.span.error("expected whitespace after `first`"));
+            return Err(name.span.error(InvalidFormatDescription::Expected {
+                what: "whitespace after `first`",
+                index: name.span.end.byte as _,
+            }));
         };
 
         let mut nested_format_descriptions = Vec::new();
@@ -166,7 +180,11 @@ fn parse_component<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, cons
         }
 
         let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
-            return Err(opening_bracket.error("unclosed bracket"));
+            return Err(
+                opening_bracket.error(InvalidFormatDescription::UnclosedOpeningBracket {
+                    index: opening_bracket.byte as _,
+                }),
+            );
         };
 
         return Ok(Item::First {
@@ -188,7 +206,10 @@ fn parse_component<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, cons
         if let Some(location) = tokens.next_if_opening_bracket() {
             return Err(location
                 .to(location)
-                .error("modifier must be of the form `key:value`"));
+                .error(InvalidFormatDescription::InvalidModifier {
+                    value: String::from("["),
+                    index: location.byte as _,
+                }));
         }
 
         let Some(Spanned { value, span }) = tokens.next_if_not_whitespace() else {
@@ -196,16 +217,29 @@ fn parse_component<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, cons
         };
 
         let Some(colon_index) = value.iter().position(|&b| b == b':') else {
-            return Err(span.error("modifier must be of the form `key:value`"));
+            return Err(span.error(InvalidFormatDescription::InvalidModifier {
+                value: String::from_utf8_lossy(value).into_owned(),
+                index: span.start.byte as _,
+            }));
         };
         let key = &value[..colon_index];
         let value = &value[colon_index + 1..];
 
         if key.is_empty() {
-            return Err(span.shrink_to_start().error("expected modifier key"));
+            return Err(span
+                .shrink_to_start()
+                .error(InvalidFormatDescription::Expected {
+                    what: "modifier key",
+                    index: span.start.byte as _,
+                }));
         }
         if value.is_empty() {
-            return Err(span.shrink_to_end().error("expected modifier value"));
+            return Err(span
+                .shrink_to_end()
+                .error(InvalidFormatDescription::Expected {
+                    what: "modifier value",
+                    index: span.shrink_to_end().start.byte as _,
+                }));
         }
 
         modifiers.push(Modifier {
@@ -217,7 +251,11 @@ fn parse_component<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, cons
     };
 
     let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
-        return Err(opening_bracket.error("unclosed bracket"));
+        return Err(
+            opening_bracket.error(InvalidFormatDescription::UnclosedOpeningBracket {
+                index: opening_bracket.byte as _,
+            }),
+        );
     };
 
     Ok(Item::Component {
@@ -235,11 +273,18 @@ fn parse_nested<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, const V
     tokens: &mut lexer::Lexed<I>,
 ) -> Result<NestedFormatDescription<'a>, Error> {
     let Some(opening_bracket) = tokens.next_if_opening_bracket() else {
-        return Err(last_location.error("expected opening bracket"));
+        return Err(last_location.error(InvalidFormatDescription::Expected {
+            what: "opening bracket",
+            index: last_location.byte as _,
+        }));
     };
     let items = parse_inner::<_, true, VERSION>(tokens).collect::<Result<_, _>>()?;
     let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
-        return Err(opening_bracket.error("unclosed bracket"));
+        return Err(
+            opening_bracket.error(InvalidFormatDescription::UnclosedOpeningBracket {
+                index: opening_bracket.byte as _,
+            }),
+        );
     };
     let trailing_whitespace = tokens.next_if_whitespace();
 
@@ -1,7 +1,7 @@
 use std::num::NonZeroU16;
 use std::str::{self, FromStr};
 
-use super::{ast, unused, Error, Span, Spanned, Unused};
+use super::{ast, unused, Error, InvalidFormatDescription, Span, Spanned, Unused};
 
 pub(super) fn parse<'a>(
     ast_items: impl Iterator<Item = Result<ast::Item<'a>, Error>>,
@@ -153,12 +153,20 @@ macro_rules! component_definition {
                             });
                         continue;
                     })*
-                    return Err(modifier.key.span.error("invalid modifier key"));
+                    return Err(modifier.key.span.error(InvalidFormatDescription::InvalidModifier {
+                        value: String::from_utf8_lossy(*modifier.key).into_owned(),
+                        index: modifier.key.span.start.byte as _,
+                    }));
                 }
 
                 $(component_definition! { @if_required $($required)? then {
                     if this.$field.is_none() {
-                        return Err(_component_span.error("missing required modifier"));
+                        return Err(_component_span.error(
+                            InvalidFormatDescription::MissingRequiredModifier {
+                                name: $parse_field,
+                                index: _component_span.start.byte as _,
+                            }
+                        ));
                     }
                 }})*
 
@@ -197,7 +205,10 @@ macro_rules! component_definition {
             if name.eq_ignore_ascii_case($parse_variant.as_bytes()) {
                 return Ok(Component::$variant($variant::with_modifiers(&modifiers, name.span)?));
             })*
-            Err(name.span.error("invalid component"))
+            Err(name.span.error(InvalidFormatDescription::InvalidComponentName {
+                name: String::from_utf8_lossy(name).into_owned(),
+                index: name.span.start.byte as _,
+            }))
         }
     }
 }
@@ -206,6 +217,7 @@ component_definition! {
     pub(super) enum Component {
         Day = "day" {
             padding = "padding": Option<Padding> => padding,
+            ordinal_suffix = "ordinal_suffix": Option<DayOrdinalSuffix> => ordinal_suffix,
         },
         End = "end" {},
         Hour = "hour" {
@@ -259,6 +271,7 @@ component_definition! {
         WeekNumber = "week_number" {
             padding = "padding": Option<Padding> => padding,
             repr = "repr": Option<WeekNumberRepr> => repr,
+            weekday = "weekday": Option<WeekNumberWeekday> => weekday_is_optional,
         },
         Year = "year" {
             padding = "padding": Option<Padding> => padding,
@@ -266,6 +279,9 @@ component_definition! {
             range = "range": Option<YearRange> => range,
             base = "base": Option<YearBase> => iso_week_based,
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
+            width = "width": Option<YearWidth> => width,
+            group_separator = "group_separator": Option<YearGroupSeparator> => group_separator,
+            notation = "notation": Option<YearNotation> => notation,
         },
     }
 }
@@ -309,7 +325,10 @@ macro_rules! modifier {
                 $(if value.eq_ignore_ascii_case($parse_variant) {
                     return Ok(Some(Self::$variant));
                 })*
-                Err(value.span.error("invalid modifier value"))
+                Err(value.span.error(InvalidFormatDescription::InvalidModifier {
+                    value: String::from_utf8_lossy(value).into_owned(),
+                    index: value.span.start.byte as _,
+                }))
             }
         }
 
@@ -324,6 +343,12 @@ macro_rules! modifier {
 }
 
 modifier! {
+    enum DayOrdinalSuffix(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum HourBase(bool) {
         Twelve(true) = b"12",
         #[default]
@@ -397,6 +422,12 @@ modifier! {
         Monday = b"monday",
     }
 
+    enum WeekNumberWeekday(bool) {
+        #[default]
+        Required(false) = b"required",
+        Optional(true) = b"optional",
+    }
+
     enum WeekdayCaseSensitive(bool) {
         False(false) = b"false",
         #[default]
@@ -435,6 +466,29 @@ modifier! {
         #[default]
         Extended = b"extended",
     }
+
+    enum YearWidth {
+        #[default]
+        Automatic = b"auto",
+        Four = b"4",
+        Five = b"5",
+        Six = b"6",
+    }
+
+    enum YearGroupSeparator {
+        #[default]
+        None = b"none",
+        Underscore = b"underscore",
+        Comma = b"comma",
+        Period = b"period",
+        Space = b"space",
+    }
+
+    enum YearNotation {
+        #[default]
+        Astronomical = b"astronomical",
+        Bce = b"bce",
+    }
 }
 
 fn parse_from_modifier_value<T: FromStr>(value: &Spanned<&[u8]>) -> Result<Option<T>, Error> {
@@ -442,5 +496,10 @@ fn parse_from_modifier_value<T: FromStr>(value: &Spanned<&[u8]>) -> Result<Optio
         .ok()
         .and_then(|val| val.parse::<T>().ok())
         .map(|val| Some(val))
-        .ok_or_else(|| value.span.error("invalid modifier value"))
+        .ok_or_else(|| {
+            value.span.error(InvalidFormatDescription::InvalidModifier {
+                value: String::from_utf8_lossy(value).into_owned(),
+                index: value.span.start.byte as _,
+            })
+        })
 }
@@ -0,0 +1,64 @@
+//! A mirror of `time::error::InvalidFormatDescription`, so that errors emitted at compile time are
+//! identical to those returned at runtime.
+
+use std::fmt;
+
+use time_core::format_description::{closest_component_name, write_expected_suggestion};
+
+pub(super) enum InvalidFormatDescription {
+    UnclosedOpeningBracket { index: usize },
+    InvalidComponentName { name: String, index: usize },
+    InvalidModifier { value: String, index: usize },
+    MissingComponentName { index: usize },
+    MissingRequiredModifier { name: &'static str, index: usize },
+    Expected { what: &'static str, index: usize },
+}
+
+impl InvalidFormatDescription {
+    pub(super) fn suggestion(&self) -> Option<String> {
+        match self {
+            Self::UnclosedOpeningBracket { .. } => Some(String::from("add a closing bracket")),
+            Self::InvalidComponentName { name, .. } => {
+                closest_component_name(name).map(|name| format!("did you mean `{name}`?"))
+            }
+            Self::MissingComponentName { .. } => {
+                Some(String::from("add a component name, such as `year`"))
+            }
+            Self::MissingRequiredModifier { name, .. } => {
+                Some(format!("add the `{name}` modifier"))
+            }
+            Self::Expected { what, .. } => {
+                let mut suggestion = String::new();
+                write_expected_suggestion(&mut suggestion, what).ok()?;
+                Some(suggestion)
+            }
+            Self::InvalidModifier { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for InvalidFormatDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedOpeningBracket { index } => {
+                write!(f, "unclosed opening bracket at byte index {index}")
+            }
+            Self::InvalidComponentName { name, index } => {
+                write!(f, "invalid component name `{name}` at byte index {index}")
+            }
+            Self::InvalidModifier { value, index } => {
+                write!(f, "invalid modifier `{value}` at byte index {index}")
+            }
+            Self::MissingComponentName { index } => {
+                write!(f, "missing component name at byte index {index}")
+            }
+            Self::MissingRequiredModifier { name, index } => {
+                write!(
+                    f,
+                    "missing required modifier `{name}` for component at byte index {index}"
+                )
+            }
+            Self::Expected { what, index } => write!(f, "expected {what} at byte index {index}"),
+        }
+    }
+}
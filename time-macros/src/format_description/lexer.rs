@@ -1,6 +1,6 @@
 use core::iter;
 
-use super::{Error, Location, Spanned, SpannedValue};
+use super::{Error, InvalidFormatDescription, Location, Spanned, SpannedValue};
 
 pub(super) struct Lexed<I: Iterator> {
     iter: iter::Peekable<I>,
@@ -157,10 +157,18 @@ pub(super) fn lex<const VERSION: u8>(
                     }
                 }
                 Some((_, loc)) => {
-                    return Some(Err(loc.error("invalid escape sequence")));
+                    return Some(Err(loc.error(InvalidFormatDescription::Expected {
+                        what: "valid escape sequence",
+                        index: loc.byte as _,
+                    })));
                 }
                 None => {
-                    return Some(Err(backslash_loc.error("unexpected end of input")));
+                    return Some(Err(backslash_loc.error(
+                        InvalidFormatDescription::Expected {
+                            what: "valid escape sequence",
+                            index: backslash_loc.byte as _,
+                        },
+                    )));
                 }
             },
             (b'[', location) if version!(..=1) => {
@@ -8,9 +8,12 @@ macro_rules! version {
 
 mod ast;
 mod format_item;
+mod invalid_format_description;
 mod lexer;
 mod public;
 
+use self::invalid_format_description::InvalidFormatDescription;
+
 pub(crate) fn parse_with_version(
     version: Option<crate::FormatDescriptionVersion>,
     s: &[u8],
@@ -53,9 +56,9 @@ impl Location {
         }
     }
 
-    fn error(self, message: &'static str) -> Error {
+    fn error(self, public: InvalidFormatDescription) -> Error {
         Error {
-            message,
+            public,
             _span: unused(Span {
                 start: self,
                 end: self,
@@ -110,9 +113,9 @@ impl Span {
         }
     }
 
-    fn error(self, message: &'static str) -> Error {
+    fn error(self, public: InvalidFormatDescription) -> Error {
         Error {
-            message,
+            public,
             _span: unused(self),
             proc_span: self.start.proc_span,
         }
@@ -144,15 +147,19 @@ impl<T> SpannedValue for T {
 }
 
 struct Error {
-    message: &'static str,
+    public: InvalidFormatDescription,
     _span: Unused<Span>,
     proc_span: proc_macro::Span,
 }
 
 impl From<Error> for crate::Error {
     fn from(error: Error) -> Self {
+        let message = match error.public.suggestion() {
+            Some(suggestion) => format!("{}\nhelp: {suggestion}", error.public),
+            None => error.public.to_string(),
+        };
         Self::Custom {
-            message: error.message.into(),
+            message: message.into(),
             span_start: Some(error.proc_span),
             span_end: Some(error.proc_span),
         }
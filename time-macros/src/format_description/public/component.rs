@@ -1,7 +1,7 @@
 use proc_macro::{Ident, Span, TokenStream};
 
 use super::modifier;
-use crate::to_tokens::ToTokenStream;
+use crate::to_tokens::ToTokenStreamWithPath;
 
 macro_rules! declare_component {
     ($($name:ident)*) => {
@@ -9,20 +9,20 @@ macro_rules! declare_component {
             $name(modifier::$name),
         )*}
 
-        impl ToTokenStream for Component {
-            fn append_to(self, ts: &mut TokenStream) {
+        impl ToTokenStreamWithPath for Component {
+            fn append_to_with_path(self, ts: &mut TokenStream, crate_path: &TokenStream) {
                 let mut mts = TokenStream::new();
 
                 let component = match self {$(
                     Self::$name(modifier) => {
-                        modifier.append_to(&mut mts);
+                        modifier.append_to_with_path(&mut mts, crate_path);
                         stringify!($name)
                     }
                 )*};
                 let component = Ident::new(component, Span::mixed_site());
 
                 quote_append! { ts
-                    ::time::format_description::Component::#(component)(#S(mts))
+                    #S(crate_path.clone())::format_description::Component::#(component)(#S(mts))
                 }
             }
         }
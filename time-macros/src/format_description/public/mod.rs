@@ -4,7 +4,7 @@ pub(super) mod modifier;
 use proc_macro::{Literal, TokenStream};
 
 pub(crate) use self::component::Component;
-use crate::to_tokens::ToTokenStream;
+use crate::to_tokens::ToTokenStreamWithPath;
 
 pub(crate) enum OwnedFormatItem {
     Literal(Box<[u8]>),
@@ -14,38 +14,46 @@ pub(crate) enum OwnedFormatItem {
     First(Box<[Self]>),
 }
 
-impl ToTokenStream for OwnedFormatItem {
-    fn append_to(self, ts: &mut TokenStream) {
+impl ToTokenStreamWithPath for OwnedFormatItem {
+    fn append_to_with_path(self, ts: &mut TokenStream, crate_path: &TokenStream) {
         match self {
             Self::Literal(bytes) => quote_append! { ts
-                ::time::format_description::BorrowedFormatItem::Literal {
+                #S(crate_path.clone())::format_description::BorrowedFormatItem::Literal {
                     0: #(Literal::byte_string(bytes.as_ref()))
                 }
             },
             Self::Component(component) => quote_append! { ts
-                ::time::format_description::BorrowedFormatItem::Component { 0: #S(component) }
+                #S(crate_path.clone())::format_description::BorrowedFormatItem::Component {
+                    0: #S(component.into_token_stream_with_path(crate_path))
+                }
             },
             Self::Compound(items) => {
                 let items = items
                     .into_vec()
                     .into_iter()
-                    .map(|item| quote! { #S(item), })
+                    .map(|item| quote! { #S(item.into_token_stream_with_path(crate_path)), })
                     .collect::<TokenStream>();
                 quote_append! { ts
-                    ::time::format_description::BorrowedFormatItem::Compound { 0: &[#S(items)] }
+                    #S(crate_path.clone())::format_description::BorrowedFormatItem::Compound {
+                        0: &[#S(items)]
+                    }
                 }
             }
             Self::Optional(item) => quote_append! {ts
-                ::time::format_description::BorrowedFormatItem::Optional { 0: &#S(*item) }
+                #S(crate_path.clone())::format_description::BorrowedFormatItem::Optional {
+                    0: &#S(item.into_token_stream_with_path(crate_path))
+                }
             },
             Self::First(items) => {
                 let items = items
                     .into_vec()
                     .into_iter()
-                    .map(|item| quote! { #S(item), })
+                    .map(|item| quote! { #S(item.into_token_stream_with_path(crate_path)), })
                     .collect::<TokenStream>();
                 quote_append! { ts
-                    ::time::format_description::BorrowedFormatItem::First { 0: &[#S(items)] }
+                    #S(crate_path.clone())::format_description::BorrowedFormatItem::First {
+                        0: &[#S(items)]
+                    }
                 }
             }
         }
@@ -2,7 +2,7 @@ use std::num::NonZeroU16;
 
 use proc_macro::{Ident, Span, TokenStream, TokenTree};
 
-use crate::to_tokens::{ToTokenStream, ToTokenTree};
+use crate::to_tokens::ToTokenStreamWithPath;
 
 macro_rules! to_tokens {
     (
@@ -18,25 +18,25 @@ macro_rules! to_tokens {
             $field_vis $field_name: $field_ty
         ),*}
 
-        impl ToTokenTree for $struct_name {
-            fn into_token_tree(self) -> TokenTree {
-                let mut tokens = TokenStream::new();
+        impl ToTokenStreamWithPath for $struct_name {
+            fn append_to_with_path(self, ts: &mut TokenStream, crate_path: &TokenStream) {
                 let Self {$($field_name),*} = self;
 
-                quote_append! { tokens
-                    let mut value = ::time::format_description::modifier::$struct_name::default();
+                let mut tokens = quote! {
+                    let mut value = #S(crate_path.clone())::format_description::modifier
+                        ::$struct_name::default();
                 };
                 $(
                     quote_append!(tokens value.$field_name =);
-                    $field_name.append_to(&mut tokens);
+                    $field_name.append_to_with_path(&mut tokens, crate_path);
                     quote_append!(tokens ;);
                 )*
                 quote_append!(tokens value);
 
-                proc_macro::TokenTree::Group(proc_macro::Group::new(
+                ts.extend([TokenTree::Group(proc_macro::Group::new(
                     proc_macro::Delimiter::Brace,
                     tokens,
-                ))
+                ))]);
             }
         }
     };
@@ -54,10 +54,10 @@ macro_rules! to_tokens {
             $variant_name
         ),+}
 
-        impl ToTokenStream for $enum_name {
-            fn append_to(self, ts: &mut TokenStream) {
+        impl ToTokenStreamWithPath for $enum_name {
+            fn append_to_with_path(self, ts: &mut TokenStream, crate_path: &TokenStream) {
                 quote_append! { ts
-                    ::time::format_description::modifier::$enum_name::
+                    #S(crate_path.clone())::format_description::modifier::$enum_name::
                 };
                 let name = match self {
                     $(Self::$variant_name => stringify!($variant_name)),+
@@ -71,6 +71,7 @@ macro_rules! to_tokens {
 to_tokens! {
     pub(crate) struct Day {
         pub(crate) padding: Padding,
+        pub(crate) ordinal_suffix: bool,
     }
 }
 
@@ -125,6 +126,7 @@ to_tokens! {
     pub(crate) struct WeekNumber {
         pub(crate) padding: Padding,
         pub(crate) repr: WeekNumberRepr,
+        pub(crate) weekday_is_optional: bool,
     }
 }
 
@@ -143,6 +145,32 @@ to_tokens! {
     }
 }
 
+to_tokens! {
+    pub(crate) enum YearWidth {
+        Automatic,
+        Four,
+        Five,
+        Six,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum YearGroupSeparator {
+        None,
+        Underscore,
+        Comma,
+        Period,
+        Space,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum YearNotation {
+        Astronomical,
+        Bce,
+    }
+}
+
 to_tokens! {
     pub(crate) struct Year {
         pub(crate) padding: Padding,
@@ -150,6 +178,9 @@ to_tokens! {
         pub(crate) range: YearRange,
         pub(crate) iso_week_based: bool,
         pub(crate) sign_is_mandatory: bool,
+        pub(crate) width: YearWidth,
+        pub(crate) group_separator: YearGroupSeparator,
+        pub(crate) notation: YearNotation,
     }
 }
 
@@ -231,11 +262,11 @@ pub(crate) struct Ignore {
     pub(crate) count: NonZeroU16,
 }
 
-impl ToTokenTree for Ignore {
-    fn into_token_tree(self) -> TokenTree {
-        quote_group! {{
-            ::time::format_description::modifier::Ignore::count(#(self.count))
-        }}
+impl ToTokenStreamWithPath for Ignore {
+    fn append_to_with_path(self, ts: &mut TokenStream, crate_path: &TokenStream) {
+        ts.extend([quote_group! {{
+            #S(crate_path.clone())::format_description::modifier::Ignore::count(#(self.count))
+        }}]);
     }
 }
 
@@ -5,12 +5,14 @@ use std::iter::Peekable;
 use std::str::FromStr;
 
 use num_conv::prelude::*;
-use proc_macro::{token_stream, Span, TokenTree};
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+use proc_macro::Delimiter;
+use proc_macro::{token_stream, Span, TokenStream, TokenTree};
 use time_core::util::{days_in_year, is_leap_year};
 
 use crate::Error;
 
-#[cfg(any(feature = "formatting", feature = "parsing"))]
+#[cfg(all(feature = "serde", any(feature = "formatting", feature = "parsing")))]
 pub(crate) fn get_string_literal(
     mut tokens: impl Iterator<Item = TokenTree>,
 ) -> Result<(Span, Vec<u8>), Error> {
@@ -28,6 +30,102 @@ pub(crate) fn get_string_literal(
     }
 }
 
+/// Obtain the string formed by concatenating one or more comma-separated pieces. Each piece is
+/// either a string literal or an invocation of `concat!` whose arguments are themselves pieces.
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+pub(crate) fn get_concatenated_string(
+    tokens: impl Iterator<Item = TokenTree>,
+) -> Result<(Span, Vec<u8>), Error> {
+    let mut tokens = tokens.peekable();
+    if tokens.peek().is_none() {
+        return Err(Error::ExpectedString {
+            span_start: None,
+            span_end: None,
+        });
+    }
+
+    let mut spans = Vec::new();
+    let mut string = Vec::new();
+    concatenate_pieces(&mut tokens, false, &mut spans, &mut string)?;
+
+    // If there is more than one piece, there is no single literal to point to.
+    let span = match spans.as_slice() {
+        [span] => *span,
+        _ => Span::call_site(),
+    };
+    Ok((span, string))
+}
+
+/// Append the contents of all comma-separated pieces in `tokens` to `string`, recording the span
+/// of each string literal encountered. A trailing comma is permitted. As with the real `concat!`,
+/// byte string literals are rejected within its arguments.
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+fn concatenate_pieces(
+    tokens: &mut Peekable<impl Iterator<Item = TokenTree>>,
+    in_concat: bool,
+    spans: &mut Vec<Span>,
+    string: &mut Vec<u8>,
+) -> Result<(), Error> {
+    while let Some(tree) = tokens.next() {
+        match tree {
+            TokenTree::Literal(literal) => {
+                if in_concat && literal.to_string().starts_with('b') {
+                    return Err(Error::Custom {
+                        message: "cannot concatenate a byte string literal".into(),
+                        span_start: Some(literal.span()),
+                        span_end: Some(literal.span()),
+                    });
+                }
+                let (span, value) = string::parse(&literal)?;
+                spans.push(span);
+                string.extend(value);
+            }
+            // Fragments passed through `macro_rules!` may be wrapped in an invisible group.
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                concatenate_pieces(
+                    &mut group.stream().into_iter().peekable(),
+                    in_concat,
+                    spans,
+                    string,
+                )?;
+            }
+            TokenTree::Ident(ident) if ident.to_string() == "concat" => {
+                match tokens.next() {
+                    Some(TokenTree::Punct(punct)) if punct.as_char() == '!' => {}
+                    Some(tree) => return Err(Error::UnexpectedToken { tree }),
+                    None => return Err(Error::UnexpectedEndOfInput),
+                }
+                match tokens.next() {
+                    Some(TokenTree::Group(group)) if group.delimiter() != Delimiter::None => {
+                        concatenate_pieces(
+                            &mut group.stream().into_iter().peekable(),
+                            true,
+                            spans,
+                            string,
+                        )?;
+                    }
+                    Some(tree) => return Err(Error::UnexpectedToken { tree }),
+                    None => return Err(Error::UnexpectedEndOfInput),
+                }
+            }
+            tree => {
+                return Err(Error::ExpectedString {
+                    span_start: Some(tree.span()),
+                    span_end: Some(tree.span()),
+                });
+            }
+        }
+
+        match tokens.next() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => {}
+            Some(tree) => return Err(Error::UnexpectedToken { tree }),
+            None => break,
+        }
+    }
+
+    Ok(())
+}
+
 pub(crate) fn consume_number<T: FromStr>(
     component_name: &'static str,
     chars: &mut Peekable<token_stream::IntoIter>,
@@ -41,12 +139,69 @@ pub(crate) fn consume_number<T: FromStr>(
     if let Ok(value) = digits.replace('_', "").parse() {
         Ok((span, value))
     } else {
-        Err(Error::InvalidComponent {
-            name: component_name,
-            value: digits,
+        Err(invalid_number(component_name, digits, span))
+    }
+}
+
+/// Consume the second component of a time, which may have a fractional part. Digits beyond the
+/// ninth fractional digit are accepted only if they are zero, as they would otherwise be lost.
+pub(crate) fn consume_second(
+    chars: &mut Peekable<token_stream::IntoIter>,
+) -> Result<(Span, u8, u32), Error> {
+    let (span, literal) = match chars.next() {
+        Some(TokenTree::Literal(literal)) => (literal.span(), literal.to_string()),
+        Some(tree) => return Err(Error::UnexpectedToken { tree }),
+        None => return Err(Error::UnexpectedEndOfInput),
+    };
+
+    let (whole, fraction) = literal.split_once('.').unwrap_or((&literal, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit() || b == b'_');
+    if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) || fraction.starts_with('_') {
+        return Err(invalid_number("second", literal, span));
+    }
+    let Ok(second) = whole.replace('_', "").parse() else {
+        return Err(invalid_number("second", literal, span));
+    };
+
+    let fraction = fraction.replace('_', "");
+    let (nanos, excess) = fraction.split_at(fraction.len().min(9));
+    if excess.bytes().any(|b| b != b'0') {
+        let help = format!(
+            "at most nine fractional digits are supported; the value truncated to nanoseconds is \
+             `{whole}.{nanos}`"
+        );
+        return Err(Error::InvalidComponent {
+            name: "second",
+            value: literal,
+            range: None,
+            help: Some(help.into()),
             span_start: Some(span),
             span_end: Some(span),
-        })
+        });
+    }
+    // The digits have been validated, so this cannot fail.
+    let nanosecond = format!("{nanos:0<9}").parse().unwrap_or_default();
+
+    Ok((span, second, nanosecond))
+}
+
+/// The error for a literal that could not be parsed as the given component.
+fn invalid_number(component_name: &'static str, value: String, span: Span) -> Error {
+    let help = if value
+        .bytes()
+        .all(|b| b.is_ascii_digit() || b == b'_' || b == b'.')
+    {
+        None
+    } else {
+        Some("use a number consisting of digits, optionally separated by `_`".into())
+    };
+    Error::InvalidComponent {
+        name: component_name,
+        value,
+        range: None,
+        help,
+        span_start: Some(span),
+        span_end: Some(span),
     }
 }
 
@@ -80,6 +235,62 @@ pub(crate) fn consume_punct(
     }
 }
 
+/// Consume the expression given for the named component, which continues until the next
+/// top-level comma. `name_span` is the span of the component's name, used if the expression is
+/// missing.
+pub(crate) fn consume_expression(
+    name: &'static str,
+    name_span: Span,
+    chars: &mut Peekable<token_stream::IntoIter>,
+) -> Result<TokenStream, Error> {
+    let mut expression = TokenStream::new();
+    while let Some(tree) =
+        chars.next_if(|tree| !matches!(tree, TokenTree::Punct(punct) if *punct == ','))
+    {
+        expression.extend([tree]);
+    }
+    if expression.is_empty() {
+        return Err(Error::MissingComponent {
+            name,
+            span_start: Some(name_span),
+            span_end: Some(name_span),
+        });
+    }
+    Ok(expression)
+}
+
+/// Parse the optional `crate = path::to::time,` argument that may precede the input of any macro.
+/// This allows the macros to be used when `time` is not available as `::time`, such as when it is
+/// renamed or only accessible through a re-export. The path is returned as-is, so it retains the
+/// hygiene of its tokens (including `$crate`). When no path is provided, `::time` is returned.
+pub(crate) fn parse_crate_path(
+    tokens: &mut Peekable<token_stream::IntoIter>,
+) -> Result<TokenStream, Error> {
+    match tokens.peek() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "crate" => drop(tokens.next()),
+        _ => return Ok(quote! { ::time }),
+    }
+    let equals_span = consume_punct('=', tokens)?;
+
+    let mut path = TokenStream::new();
+    loop {
+        match tokens.next() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => break,
+            Some(tree) => path.extend([tree]),
+            None => return Err(Error::UnexpectedEndOfInput),
+        }
+    }
+
+    if path.is_empty() {
+        return Err(Error::Custom {
+            message: "expected a path to the `time` crate".into(),
+            span_start: Some(equals_span),
+            span_end: Some(equals_span),
+        });
+    }
+    Ok(path)
+}
+
 fn jan_weekday(year: i32, ordinal: i32) -> u8 {
     macro_rules! div_floor {
         ($a:expr, $b:expr) => {{
@@ -39,18 +39,21 @@ use proc_macro::TokenStream;
 use proc_macro::{Ident, TokenTree};
 
 use self::error::Error;
+use self::to_tokens::ToTokenStreamWithPath;
 
 macro_rules! impl_macros {
     ($($name:ident)*) => {$(
         #[proc_macro]
         pub fn $name(input: TokenStream) -> TokenStream {
-            use crate::to_tokens::ToTokenTree;
-
             let mut iter = input.into_iter().peekable();
+            let crate_path = match helpers::parse_crate_path(&mut iter) {
+                Ok(crate_path) => crate_path,
+                Err(err) => return err.to_compile_error(),
+            };
             match $name::parse(&mut iter) {
                 Ok(value) => match iter.peek() {
                     Some(tree) => Error::UnexpectedToken { tree: tree.clone() }.to_compile_error(),
-                    None => TokenStream::from(value.into_token_tree()),
+                    None => value.into_token_stream_with_path(&crate_path),
                 },
                 Err(err) => err.to_compile_error(),
             }
@@ -142,20 +145,23 @@ fn parse_format_description_version<const NO_EQUALS_IS_MOD_NAME: bool>(
 pub fn format_description(input: TokenStream) -> TokenStream {
     (|| {
         let mut input = input.into_iter().peekable();
+        let crate_path = helpers::parse_crate_path(&mut input)?;
         let version = match parse_format_description_version::<false>(&mut input)? {
             Some(VersionOrModuleName::Version(version)) => Some(version),
             None => None,
             // This branch should never occur here, as `false` is the provided as a const parameter.
             Some(VersionOrModuleName::ModuleName(_)) => bug!("branch should never occur"),
         };
-        let (span, string) = helpers::get_string_literal(input)?;
+        let (span, string) = helpers::get_concatenated_string(input)?;
         let items = format_description::parse_with_version(version, &string, span)?;
 
         Ok(quote! {{
-            const DESCRIPTION: &[::time::format_description::BorrowedFormatItem<'_>] = &[#S(
+            const DESCRIPTION: &[
+                #S(crate_path.clone())::format_description::BorrowedFormatItem<'_>
+            ] = &[#S(
                 items
                     .into_iter()
-                    .map(|item| quote! { #S(item), })
+                    .map(|item| quote! { #S(item.into_token_stream_with_path(&crate_path)), })
                     .collect::<TokenStream>()
             )];
             DESCRIPTION
@@ -170,7 +176,10 @@ pub fn serde_format_description(input: TokenStream) -> TokenStream {
     (|| {
         let mut tokens = input.into_iter().peekable();
 
-        // First, the optional format description version.
+        // First, the optional path to the `time` crate.
+        let crate_path = helpers::parse_crate_path(&mut tokens)?;
+
+        // Next, the optional format description version.
         let version = parse_format_description_version::<true>(&mut tokens)?;
         let (version, mod_name) = match version {
             Some(VersionOrModuleName::ModuleName(module_name)) => (None, Some(module_name)),
@@ -210,11 +219,14 @@ pub fn serde_format_description(input: TokenStream) -> TokenStream {
             Some(TokenTree::Literal(_)) => {
                 let (span, format_string) = helpers::get_string_literal(tokens)?;
                 let items = format_description::parse_with_version(version, &format_string, span)?;
-                let items: TokenStream =
-                    items.into_iter().map(|item| quote! { #S(item), }).collect();
+                let items: TokenStream = items
+                    .into_iter()
+                    .map(|item| quote! { #S(item.into_token_stream_with_path(&crate_path)), })
+                    .collect();
                 let items = quote! {
-                    const ITEMS: &[::time::format_description::BorrowedFormatItem<'_>]
-                        = &[#S(items)];
+                    const ITEMS: &[
+                        #S(crate_path.clone())::format_description::BorrowedFormatItem<'_>
+                    ] = &[#S(items)];
                     ITEMS
                 };
 
@@ -230,6 +242,7 @@ pub fn serde_format_description(input: TokenStream) -> TokenStream {
         };
 
         Ok(serde_format_description::build(
+            &crate_path,
             mod_name,
             formattable,
             format,
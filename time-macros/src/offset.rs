@@ -1,20 +1,46 @@
 use std::iter::Peekable;
 
 use num_conv::prelude::*;
-use proc_macro::{token_stream, Span, TokenTree};
+use proc_macro::{token_stream, Span, TokenStream, TokenTree};
 use time_core::convert::*;
 
-use crate::helpers::{consume_any_ident, consume_number, consume_punct};
-use crate::to_tokens::ToTokenTree;
+use crate::helpers::{consume_any_ident, consume_expression, consume_number, consume_punct};
+use crate::to_tokens::ToTokenStreamWithPath;
 use crate::Error;
 
+/// The names of the components accepted in the expression form, in the order they are passed to
+/// `UtcOffset::from_hms`.
+const EXPRESSION_COMPONENTS: [&str; 3] = ["hours", "minutes", "seconds"];
+
 pub(crate) struct Offset {
     pub(crate) hours: i8,
     pub(crate) minutes: i8,
     pub(crate) seconds: i8,
 }
 
-pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Offset, Error> {
+/// An offset whose components are constant expressions. The components are validated when the
+/// expanded code is evaluated at compile time.
+pub(crate) struct OffsetExpression {
+    hours: TokenStream,
+    minutes: TokenStream,
+    seconds: TokenStream,
+}
+
+/// Parse an offset, which is either a literal offset or an offset whose components are given as
+/// expressions. A literal offset is converted to the latter form.
+pub(crate) fn parse(
+    chars: &mut Peekable<token_stream::IntoIter>,
+) -> Result<OffsetExpression, Error> {
+    if matches!(
+        chars.peek(),
+        Some(TokenTree::Ident(ident)) if EXPRESSION_COMPONENTS.contains(&ident.to_string().as_str())
+    ) {
+        return parse_expression(chars);
+    }
+    parse_literal(chars).map(OffsetExpression::from)
+}
+
+fn parse_literal(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Offset, Error> {
     if consume_any_ident(&["utc", "UTC"], chars).is_ok() {
         return Ok(Offset {
             hours: 0,
@@ -57,6 +83,8 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Offs
         Err(Error::InvalidComponent {
             name: "hour",
             value: hours.to_string(),
+            range: Some((0, 25)),
+            help: None,
             span_start: Some(hours_span),
             span_end: Some(hours_span),
         })
@@ -64,6 +92,8 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Offs
         Err(Error::InvalidComponent {
             name: "minute",
             value: minutes.to_string(),
+            range: Some((0, i64::from(Minute::per(Hour)) - 1)),
+            help: None,
             span_start: Some(minutes_span),
             span_end: Some(minutes_span),
         })
@@ -71,6 +101,8 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Offs
         Err(Error::InvalidComponent {
             name: "second",
             value: seconds.to_string(),
+            range: Some((0, i64::from(Second::per(Minute)) - 1)),
+            help: None,
             span_start: Some(seconds_span),
             span_end: Some(seconds_span),
         })
@@ -83,17 +115,81 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Offs
     }
 }
 
-impl ToTokenTree for Offset {
-    fn into_token_tree(self) -> TokenTree {
-        quote_group! {{
-            const OFFSET: ::time::UtcOffset = unsafe {
-                ::time::UtcOffset::__from_hms_unchecked(
-                    #(self.hours),
-                    #(self.minutes),
-                    #(self.seconds),
-                )
-            };
+/// Parse an offset of the form `hours = EXPR, minutes = EXPR, seconds = EXPR`, where `minutes`
+/// and `seconds` are optional. Each expression continues until the next top-level comma.
+fn parse_expression(
+    chars: &mut Peekable<token_stream::IntoIter>,
+) -> Result<OffsetExpression, Error> {
+    let mut components: [Option<TokenStream>; 3] = [None, None, None];
+
+    while let Some(tree) = chars.next() {
+        let index = match &tree {
+            TokenTree::Ident(ident) => EXPRESSION_COMPONENTS
+                .iter()
+                .position(|&name| ident.to_string() == name),
+            _ => None,
+        };
+        let Some(index) = index else {
+            return Err(Error::UnexpectedToken { tree });
+        };
+        let name = EXPRESSION_COMPONENTS[index];
+        if components[index].is_some() {
+            return Err(Error::Custom {
+                message: format!("duplicate component: {name}").into(),
+                span_start: Some(tree.span()),
+                span_end: Some(tree.span()),
+            });
+        }
+        consume_punct('=', chars)?;
+
+        components[index] = Some(consume_expression(name, tree.span(), chars)?);
+
+        if consume_punct(',', chars).is_err() {
+            break;
+        }
+    }
+
+    let [hours, minutes, seconds] = components;
+    let Some(hours) = hours else {
+        return Err(Error::MissingComponent {
+            name: "hours",
+            span_start: None,
+            span_end: None,
+        });
+    };
+    Ok(OffsetExpression {
+        hours,
+        minutes: minutes.unwrap_or_else(|| quote!(0)),
+        seconds: seconds.unwrap_or_else(|| quote!(0)),
+    })
+}
+
+impl From<Offset> for OffsetExpression {
+    fn from(offset: Offset) -> Self {
+        Self {
+            hours: quote!(#(offset.hours)),
+            minutes: quote!(#(offset.minutes)),
+            seconds: quote!(#(offset.seconds)),
+        }
+    }
+}
+
+impl ToTokenStreamWithPath for OffsetExpression {
+    fn append_to_with_path(self, ts: &mut TokenStream, crate_path: &TokenStream) {
+        ts.extend([quote_group! {{
+            const OFFSET: #S(crate_path.clone())::UtcOffset =
+                if let ::core::result::Result::Ok(offset) =
+                    #S(crate_path.clone())::UtcOffset::from_hms(
+                        #S(self.hours),
+                        #S(self.minutes),
+                        #S(self.seconds),
+                    )
+                {
+                    offset
+                } else {
+                    ::core::panic!("invalid UTC offset")
+                };
             OFFSET
-        }}
+        }}]);
     }
 }
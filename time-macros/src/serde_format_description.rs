@@ -1,6 +1,7 @@
 use proc_macro::{Ident, TokenStream, TokenTree};
 
 pub(crate) fn build(
+    crate_path: &TokenStream,
     mod_name: Ident,
     ty: TokenTree,
     format: TokenStream,
@@ -80,7 +81,7 @@ pub(crate) fn build(
                 use ::serde::Serialize;
                 datetime
                     .format(&description())
-                    .map_err(::time::error::Format::into_invalid_serde_value::<S>)?
+                    .map_err(#S(crate_path.clone())::error::Format::into_invalid_serde_value::<S>)?
                     .serialize(serializer)
             }
         }
@@ -110,7 +111,7 @@ pub(crate) fn build(
                 use ::serde::Serialize;
                 option.map(|datetime| datetime.format(&description()))
                     .transpose()
-                    .map_err(::time::error::Format::into_invalid_serde_value::<S>)?
+                    .map_err(#S(crate_path.clone())::error::Format::into_invalid_serde_value::<S>)?
                     .serialize(serializer)
             }
         }
@@ -143,16 +144,19 @@ pub(crate) fn build(
         (false, false) => {
             bug!("serde_format_description::build called without formatting or parsing enabled")
         }
-        (false, true) => quote! { ::time::parsing::Parsable },
-        (true, false) => quote! { ::time::formatting::Formattable },
-        (true, true) => quote! { ::time::formatting::Formattable + ::time::parsing::Parsable },
+        (false, true) => quote! { #S(crate_path.clone())::parsing::Parsable },
+        (true, false) => quote! { #S(crate_path.clone())::formatting::Formattable },
+        (true, true) => quote! {
+            #S(crate_path.clone())::formatting::Formattable
+                + #S(crate_path.clone())::parsing::Parsable
+        },
     };
 
     quote! {
         mod #(mod_name) {
             use super::*;
             // TODO Remove the prefix, forcing the user to import the type themself.
-            use ::time::#(ty) as __TimeSerdeType;
+            use #S(crate_path.clone())::#(ty) as __TimeSerdeType;
 
             const fn description() -> impl #S(fd_traits) {
                 #S(format)
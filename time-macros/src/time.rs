@@ -1,12 +1,16 @@
+use std::borrow::Cow;
 use std::iter::Peekable;
 
-use proc_macro::{token_stream, Span, TokenTree};
+use proc_macro::{token_stream, Span, TokenStream, TokenTree};
 use time_core::convert::*;
 
-use crate::helpers::{consume_any_ident, consume_number, consume_punct};
-use crate::to_tokens::ToTokenTree;
+use crate::helpers::{
+    consume_any_ident, consume_expression, consume_number, consume_punct, consume_second,
+};
+use crate::to_tokens::ToTokenStreamWithPath;
 use crate::Error;
 
+#[derive(Clone, Copy)]
 enum Period {
     Am,
     Pm,
@@ -17,7 +21,7 @@ pub(crate) struct Time {
     pub(crate) hour: u8,
     pub(crate) minute: u8,
     pub(crate) second: u8,
-    pub(crate) nanosecond: u32,
+    pub(crate) nanosecond: TokenStream,
 }
 
 pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Time, Error> {
@@ -33,36 +37,43 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Time
 
     let (hour_span, hour) = consume_number("hour", chars)?;
 
-    let ((minute_span, minute), (second_span, second), (period_span, period)) =
+    let ((minute_span, minute), (second_span, second, nanosecond), (period_span, period)) =
         match consume_period(chars) {
             // Nothing but the 12-hour clock hour and AM/PM
             (period_span @ Some(_), period) => (
                 (Span::mixed_site(), 0),
-                (Span::mixed_site(), 0.),
+                (Span::mixed_site(), 0, 0),
                 (period_span, period),
             ),
             (None, _) => {
                 consume_punct(':', chars)?;
                 let (minute_span, minute) = consume_number::<u8>("minute", chars)?;
-                let (second_span, second): (_, f64) = if consume_punct(':', chars).is_ok() {
-                    consume_number("second", chars)?
+                let second = if consume_punct(':', chars).is_ok() {
+                    consume_second(chars)?
                 } else {
-                    (Span::mixed_site(), 0.)
+                    (Span::mixed_site(), 0, 0)
                 };
                 let (period_span, period) = consume_period(chars);
-                (
-                    (minute_span, minute),
-                    (second_span, second),
-                    (period_span, period),
-                )
+                ((minute_span, minute), second, (period_span, period))
             }
         };
 
     let hour = match (hour, period) {
-        (0, Period::Am | Period::Pm) => {
+        (0 | 13.., Period::Am | Period::Pm) => {
+            let help = match (hour, period) {
+                (0, _) => Cow::Borrowed("use `12 am` for midnight or `12 pm` for noon"),
+                (13..=23, Period::Am) => Cow::Owned(format!(
+                    "remove `am` to use the 24-hour clock, or use `{} pm`",
+                    hour - 12
+                )),
+                (13..=23, _) => Cow::Borrowed("remove `pm` to use the 24-hour clock"),
+                _ => Cow::Borrowed("the 12-hour clock uses hours 1 through 12"),
+            };
             return Err(Error::InvalidComponent {
                 name: "hour",
                 value: hour.to_string(),
+                range: Some((1, 12)),
+                help: Some(help),
                 span_start: Some(hour_span),
                 span_end: Some(period_span.unwrap_or(hour_span)),
             });
@@ -74,9 +85,14 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Time
     };
 
     if hour >= Hour::per(Day) {
+        let help = (hour == 24 && minute == 0 && second == 0 && nanosecond == 0).then_some(
+            Cow::Borrowed("midnight at the start of a day is written `0:00`"),
+        );
         Err(Error::InvalidComponent {
             name: "hour",
             value: hour.to_string(),
+            range: Some((0, i64::from(Hour::per(Day)) - 1)),
+            help,
             span_start: Some(hour_span),
             span_end: Some(period_span.unwrap_or(hour_span)),
         })
@@ -84,38 +100,102 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Time
         Err(Error::InvalidComponent {
             name: "minute",
             value: minute.to_string(),
+            range: Some((0, i64::from(Minute::per(Hour)) - 1)),
+            help: None,
             span_start: Some(minute_span),
             span_end: Some(minute_span),
         })
-    } else if second >= Second::per(Minute) as _ {
+    } else if second >= Second::per(Minute) {
+        let help = (second == 60).then_some(Cow::Borrowed("leap seconds cannot be represented"));
         Err(Error::InvalidComponent {
             name: "second",
             value: second.to_string(),
+            range: Some((0, i64::from(Second::per(Minute)) - 1)),
+            help,
             span_start: Some(second_span),
             span_end: Some(second_span),
         })
     } else {
+        let nanosecond = parse_nanosecond(nanosecond, chars)?;
+        // A trailing comma is permitted. In `datetime!`, it separates the time from the offset.
+        drop(consume_punct(',', chars));
         Ok(Time {
             hour,
             minute,
-            second: second.trunc() as _,
-            nanosecond: (second.fract() * Nanosecond::per(Second) as f64).round() as _,
+            second,
+            nanosecond,
         })
     }
 }
 
-impl ToTokenTree for Time {
-    fn into_token_tree(self) -> TokenTree {
-        quote_group! {{
-            const TIME: ::time::Time = unsafe {
-                ::time::Time::__from_hms_nanos_unchecked(
+/// Parse the optional `, nanosecond = EXPR` that may follow a time, where the expression continues
+/// until the next top-level comma. A comma that is not followed by `nanosecond` is left in place, as
+/// it may separate the time from an offset in `datetime!`.
+fn parse_nanosecond(
+    literal_nanosecond: u32,
+    chars: &mut Peekable<token_stream::IntoIter>,
+) -> Result<TokenStream, Error> {
+    let mut lookahead = chars.clone();
+    if consume_punct(',', &mut lookahead).is_err()
+        || consume_any_ident(&["nanosecond"], &mut lookahead).is_err()
+    {
+        return Ok(quote!(#(literal_nanosecond)));
+    }
+    drop(chars.next());
+    let name_span = consume_any_ident(&["nanosecond"], chars)?;
+    if literal_nanosecond != 0 {
+        return Err(Error::Custom {
+            message: "the second cannot have a fractional part when `nanosecond` is provided"
+                .into(),
+            span_start: Some(name_span),
+            span_end: Some(name_span),
+        });
+    }
+    consume_punct('=', chars)?;
+    let expression = consume_expression("nanosecond", name_span, chars)?;
+
+    // An offset directly following the expression would otherwise be treated as part of it,
+    // resulting in a confusing error. Signed offsets such as `+1` cannot be detected, as they are
+    // valid as part of the expression.
+    let mut trees = expression.clone().into_iter().peekable();
+    while let Some(tree) = trees.next() {
+        let TokenTree::Ident(ident) = tree else {
+            continue;
+        };
+        let ident_str = ident.to_string();
+        let is_offset = matches!(ident_str.as_str(), "utc" | "UTC")
+            || (matches!(ident_str.as_str(), "hours" | "minutes" | "seconds")
+                && matches!(trees.peek(), Some(TokenTree::Punct(punct)) if *punct == '='));
+        if is_offset {
+            return Err(Error::Custom {
+                message: "an offset following `nanosecond = EXPR` must be separated from it by a \
+                          comma"
+                    .into(),
+                span_start: Some(ident.span()),
+                span_end: Some(ident.span()),
+            });
+        }
+    }
+
+    Ok(expression)
+}
+
+impl ToTokenStreamWithPath for Time {
+    fn append_to_with_path(self, ts: &mut TokenStream, crate_path: &TokenStream) {
+        ts.extend([quote_group! {{
+            const TIME: #S(crate_path.clone())::Time = if let ::core::result::Result::Ok(time) =
+                #S(crate_path.clone())::Time::from_hms_nano(
                     #(self.hour),
                     #(self.minute),
                     #(self.second),
-                    #(self.nanosecond),
+                    #S(self.nanosecond),
                 )
+            {
+                time
+            } else {
+                ::core::panic!("invalid time")
             };
             TIME
-        }}
+        }}]);
     }
 }
@@ -17,6 +17,24 @@ impl<T: ToTokenTree> ToTokenStream for T {
     }
 }
 
+/// Turn a type that refers to items of the `time` crate into a [`TokenStream`]. The root of the
+/// crate is the provided path, which is `::time` unless overridden by the `crate` argument.
+pub(crate) trait ToTokenStreamWithPath: Sized {
+    fn append_to_with_path(self, ts: &mut TokenStream, crate_path: &TokenStream);
+
+    fn into_token_stream_with_path(self, crate_path: &TokenStream) -> TokenStream {
+        let mut ts = TokenStream::new();
+        self.append_to_with_path(&mut ts, crate_path);
+        ts
+    }
+}
+
+impl<T: ToTokenStream> ToTokenStreamWithPath for T {
+    fn append_to_with_path(self, ts: &mut TokenStream, _: &TokenStream) {
+        self.append_to(ts)
+    }
+}
+
 impl ToTokenTree for bool {
     fn into_token_tree(self) -> TokenTree {
         let lit = if self { "true" } else { "false" };
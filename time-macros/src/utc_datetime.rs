@@ -1,11 +1,11 @@
 use std::iter::Peekable;
 
-use proc_macro::{token_stream, TokenTree};
+use proc_macro::{token_stream, TokenStream};
 
 use crate::date::Date;
 use crate::error::Error;
 use crate::time::Time;
-use crate::to_tokens::ToTokenTree;
+use crate::to_tokens::ToTokenStreamWithPath;
 use crate::{date, time};
 
 pub(crate) struct UtcDateTime {
@@ -26,14 +26,15 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<UtcD
     Ok(UtcDateTime { date, time })
 }
 
-impl ToTokenTree for UtcDateTime {
-    fn into_token_tree(self) -> TokenTree {
-        quote_group! {{
-            const DATE_TIME: ::time::UtcDateTime = ::time::UtcDateTime::new(
-                #(self.date),
-                #(self.time),
-            );
+impl ToTokenStreamWithPath for UtcDateTime {
+    fn append_to_with_path(self, ts: &mut TokenStream, crate_path: &TokenStream) {
+        ts.extend([quote_group! {{
+            const DATE_TIME: #S(crate_path.clone())::UtcDateTime =
+                #S(crate_path.clone())::UtcDateTime::new(
+                    #S(self.date.into_token_stream_with_path(crate_path)),
+                    #S(self.time.into_token_stream_with_path(crate_path)),
+                );
             DATE_TIME
-        }}
+        }}]);
     }
 }
@@ -0,0 +1,105 @@
+//! The [`Backoff`] struct and its associated `impl`s.
+
+use core::iter::FusedIterator;
+
+use crate::Duration;
+
+/// An infinite iterator of exponentially increasing [`Duration`]s, such as is used for retrying
+/// an operation or refilling a token bucket. This is obtained via [`Duration::backoff`].
+///
+/// The first value yielded is the base duration. Each subsequent value is the previous one
+/// multiplied by the [factor](Self::factor), which is 2 by default. Values are capped at the
+/// [maximum](Self::max); once it is reached, it is yielded indefinitely. If no maximum is set,
+/// values saturate at [`Duration::MAX`].
+///
+/// ```rust
+/// # use time::ext::NumericalDuration;
+/// let delays = 1.seconds().backoff().factor(3.).max(20.seconds());
+/// assert_eq!(
+///     delays.take(5).collect::<Vec<_>>(),
+///     [1.seconds(), 3.seconds(), 9.seconds(), 20.seconds(), 20.seconds()]
+/// );
+///
+/// let delays = 250.milliseconds().backoff();
+/// assert_eq!(
+///     delays.take(4).collect::<Vec<_>>(),
+///     [250.milliseconds(), 500.milliseconds(), 1.seconds(), 2.seconds()]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backoff {
+    /// The next value to be yielded, not yet capped at the maximum.
+    next: Duration,
+    /// The value by which each duration is multiplied to obtain the next.
+    factor: f64,
+    /// The largest duration that will be yielded.
+    max: Duration,
+}
+
+impl Backoff {
+    /// Create a sequence starting at the provided duration with the default factor and no maximum.
+    pub(crate) const fn new(base: Duration) -> Self {
+        Self {
+            next: base,
+            factor: 2.,
+            max: Duration::MAX,
+        }
+    }
+
+    /// Set the value by which each duration is multiplied to obtain the next.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the factor is negative or not finite.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// let mut delays = 100.milliseconds().backoff().factor(1.5);
+    /// assert_eq!(delays.next(), Some(100.milliseconds()));
+    /// assert_eq!(delays.next(), Some(150.milliseconds()));
+    /// assert_eq!(delays.next(), Some(225.milliseconds()));
+    /// ```
+    pub fn factor(self, factor: f64) -> Self {
+        assert!(
+            factor.is_finite() && factor >= 0.,
+            "backoff factor must be finite and non-negative"
+        );
+        Self { factor, ..self }
+    }
+
+    /// Set the largest duration that will be yielded.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// let mut delays = 45.seconds().backoff().max(1.minutes());
+    /// assert_eq!(delays.next(), Some(45.seconds()));
+    /// assert_eq!(delays.next(), Some(1.minutes()));
+    /// assert_eq!(delays.next(), Some(1.minutes()));
+    /// ```
+    pub const fn max(self, max: Duration) -> Self {
+        Self { max, ..self }
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = if self.next > self.max {
+            self.max
+        } else {
+            self.next
+        };
+        // Once the maximum is reached, there is no need to continue multiplying.
+        if value < self.max {
+            self.next = Duration::saturating_seconds_f64(value.as_seconds_f64() * self.factor);
+        }
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for Backoff {}
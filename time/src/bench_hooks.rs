@@ -0,0 +1,101 @@
+//! Counters describing the work performed by formatting and parsing.
+//!
+//! These are intended for performance smoke tests that need something lighter than a full
+//! benchmarking harness. Counters are tracked per thread, so tests running in parallel do not
+//! interfere with one another.
+//!
+//! Only the public `format`, `format_into`, `parse`, and `parse_with_options` methods of
+//! [`Date`](crate::Date), [`Time`](crate::Time), [`PrimitiveDateTime`](crate::PrimitiveDateTime),
+//! [`OffsetDateTime`](crate::OffsetDateTime), [`UtcDateTime`](crate::UtcDateTime), and
+//! [`UtcOffset`](crate::UtcOffset) are counted. The values are not part of the stability
+//! guarantees of this crate and may change between releases.
+//!
+//! ```rust
+//! # use time::bench_hooks;
+//! # use time::format_description::well_known::Rfc3339;
+//! # use time::OffsetDateTime;
+//! # use time_macros::datetime;
+//! bench_hooks::reset();
+//! let mut buf = Vec::new();
+//! datetime!(2021-01-02 03:04:05 UTC).format_into(&mut buf, &Rfc3339)?;
+//! OffsetDateTime::parse("2021-01-02T03:04:05Z", &Rfc3339)?;
+//!
+//! let counters = bench_hooks::counters();
+//! assert_eq!(counters.formats, 1);
+//! assert_eq!(counters.allocations_avoided, 1);
+//! assert_eq!(counters.bytes_formatted, 20);
+//! assert_eq!(counters.parses, 1);
+//! assert_eq!(counters.bytes_parsed, 20);
+//! # Ok::<_, time::Error>(())
+//! ```
+
+use core::cell::Cell;
+
+/// A snapshot of the counters for the current thread.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Counters {
+    /// The number of values formatted, whether successfully or not.
+    pub formats: usize,
+    /// The number of values formatted into a caller-provided output rather than a newly
+    /// allocated `String`.
+    pub allocations_avoided: usize,
+    /// The number of bytes written by successful formatting.
+    pub bytes_formatted: usize,
+    /// The number of values parsed, whether successfully or not.
+    pub parses: usize,
+    /// The number of bytes of input provided to parsing.
+    pub bytes_parsed: usize,
+}
+
+std::thread_local! {
+    /// The counters for the current thread.
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters {
+        formats: 0,
+        allocations_avoided: 0,
+        bytes_formatted: 0,
+        parses: 0,
+        bytes_parsed: 0,
+    }) };
+}
+
+/// Obtain the counters for the current thread.
+pub fn counters() -> Counters {
+    COUNTERS.with(Cell::get)
+}
+
+/// Reset the counters for the current thread to zero.
+pub fn reset() {
+    COUNTERS.with(|counters| counters.set(Counters::default()));
+}
+
+/// Update the counters for the current thread.
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+fn update(f: impl FnOnce(&mut Counters)) {
+    COUNTERS.with(|counters| {
+        let mut value = counters.get();
+        f(&mut value);
+        counters.set(value);
+    });
+}
+
+/// Record a value being formatted. `bytes` is `None` if formatting failed.
+#[cfg(feature = "formatting")]
+pub(crate) fn record_format(allocated: bool, bytes: Option<usize>) {
+    update(|counters| {
+        counters.formats = counters.formats.saturating_add(1);
+        if !allocated {
+            counters.allocations_avoided = counters.allocations_avoided.saturating_add(1);
+        }
+        counters.bytes_formatted = counters.bytes_formatted.saturating_add(bytes.unwrap_or(0));
+    });
+}
+
+/// Record a value being parsed from an input of the given length.
+#[cfg(feature = "parsing")]
+pub(crate) fn record_parse(bytes: usize) {
+    update(|counters| {
+        counters.parses = counters.parses.saturating_add(1);
+        counters.bytes_parsed = counters.bytes_parsed.saturating_add(bytes);
+    });
+}
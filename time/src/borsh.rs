@@ -0,0 +1,148 @@
+//! Implementations of [`BorshSerialize`] and [`BorshDeserialize`] for various structs.
+//!
+//! The encoding of each type is identical to the non-human-readable serde representation, with
+//! each component written as a fixed-width little-endian integer:
+//!
+//! | Type                | Encoding                                                          |
+//! |---------------------|-------------------------------------------------------------------|
+//! | `Date`              | year (`i32`), ordinal (`u16`)                                     |
+//! | `Time`              | hour (`u8`), minute (`u8`), second (`u8`), nanosecond (`u32`)     |
+//! | `UtcOffset`         | hours (`i8`), minutes (`i8`), seconds (`i8`)                      |
+//! | `PrimitiveDateTime` | `Date`, `Time`                                                    |
+//! | `UtcDateTime`       | `Date`, `Time`                                                    |
+//! | `OffsetDateTime`    | local `Date`, local `Time`, `UtcOffset`                           |
+//! | `Duration`          | whole seconds (`i64`), subsecond nanoseconds (`i32`)              |
+//! | `Month`             | month number (`u8`), starting at 1                                |
+//! | `Weekday`           | weekday number (`u8`), starting at 1 for Monday                   |
+
+use alloc::string::ToString;
+
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    error, Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset,
+    Weekday,
+};
+
+/// Convert an error from a component being out of range into an I/O error.
+fn invalid_data(err: error::ComponentRange) -> Error {
+    Error::new(ErrorKind::InvalidData, err.to_string())
+}
+
+impl BorshSerialize for Date {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.year(), self.ordinal()).serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Date {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let (year, ordinal) = BorshDeserialize::deserialize_reader(reader)?;
+        Self::from_ordinal_date(year, ordinal).map_err(invalid_data)
+    }
+}
+
+impl BorshSerialize for Time {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.hour(), self.minute(), self.second(), self.nanosecond()).serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Time {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let (hour, minute, second, nanosecond) = BorshDeserialize::deserialize_reader(reader)?;
+        Self::from_hms_nano(hour, minute, second, nanosecond).map_err(invalid_data)
+    }
+}
+
+impl BorshSerialize for UtcOffset {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_hms().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for UtcOffset {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let (hours, minutes, seconds) = BorshDeserialize::deserialize_reader(reader)?;
+        Self::from_hms(hours, minutes, seconds).map_err(invalid_data)
+    }
+}
+
+impl BorshSerialize for PrimitiveDateTime {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.date(), self.time()).serialize(writer)
+    }
+}
+
+impl BorshDeserialize for PrimitiveDateTime {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let (date, time) = BorshDeserialize::deserialize_reader(reader)?;
+        Ok(Self::new(date, time))
+    }
+}
+
+impl BorshSerialize for UtcDateTime {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.date(), self.time()).serialize(writer)
+    }
+}
+
+impl BorshDeserialize for UtcDateTime {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let (date, time) = BorshDeserialize::deserialize_reader(reader)?;
+        Ok(Self::new(date, time))
+    }
+}
+
+impl BorshSerialize for OffsetDateTime {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.date(), self.time(), self.offset()).serialize(writer)
+    }
+}
+
+impl BorshDeserialize for OffsetDateTime {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let (date, time, offset) = BorshDeserialize::deserialize_reader(reader)?;
+        Ok(Self::new_in_offset(date, time, offset))
+    }
+}
+
+impl BorshSerialize for Duration {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        (self.whole_seconds(), self.subsec_nanoseconds()).serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Duration {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let (seconds, nanoseconds): (i64, i32) = BorshDeserialize::deserialize_reader(reader)?;
+        Self::seconds(seconds)
+            .checked_add(Self::nanoseconds(nanoseconds.into()))
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "duration overflowed"))
+    }
+}
+
+impl BorshSerialize for Month {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        u8::from(*self).serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Month {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Self::try_from(u8::deserialize_reader(reader)?).map_err(invalid_data)
+    }
+}
+
+impl BorshSerialize for Weekday {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.number_from_monday().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Weekday {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Self::from_number(u8::deserialize_reader(reader)?, Self::Monday).map_err(invalid_data)
+    }
+}
@@ -0,0 +1,143 @@
+//! The [`CalendarDate`] struct and its associated `impl`s.
+
+use core::cmp::{self, Ordering};
+use core::fmt;
+
+use num_conv::prelude::*;
+use powerfmt::ext::FormatterExt;
+use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
+
+use crate::ext::DigitCount;
+use crate::util::DisplayFn;
+use crate::{error, Date, Month};
+
+/// A date expressed as its year, month, and day.
+///
+/// This is the structured counterpart of [`Date::to_calendar_date`]. Unlike a tuple, the meaning of
+/// each field is explicit, so the values cannot be accidentally transposed.
+///
+/// As the fields are public, a `CalendarDate` may not represent a valid date. Validity is checked
+/// when converting to a [`Date`].
+///
+/// ```rust
+/// # use time::{Date, Month};
+/// # use time_macros::date;
+/// let calendar_date = date!(2019-01-02).calendar_date();
+/// assert_eq!(calendar_date.year, 2019);
+/// assert_eq!(calendar_date.month, Month::January);
+/// assert_eq!(calendar_date.day, 2);
+/// assert_eq!(calendar_date.to_string(), "2019-01-02");
+/// assert_eq!(Date::try_from(calendar_date), Ok(date!(2019-01-02)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CalendarDate {
+    /// The year.
+    pub year: i32,
+    /// The month of the year.
+    pub month: Month,
+    /// The day of the month.
+    pub day: u8,
+}
+
+impl CalendarDate {
+    /// Convert the `CalendarDate` to a [`Date`], returning an error if it does not represent a
+    /// valid date.
+    ///
+    /// ```rust
+    /// # use time::{CalendarDate, Month};
+    /// # use time_macros::date;
+    /// let calendar_date = CalendarDate {
+    ///     year: 2019,
+    ///     month: Month::February,
+    ///     day: 28,
+    /// };
+    /// assert_eq!(calendar_date.to_date(), Ok(date!(2019-02-28)));
+    ///
+    /// let calendar_date = CalendarDate { day: 29, ..calendar_date };
+    /// assert!(calendar_date.to_date().is_err());
+    /// ```
+    pub const fn to_date(self) -> Result<Date, error::ComponentRange> {
+        Date::from_calendar_date(self.year, self.month, self.day)
+    }
+
+    /// Write the date without padding.
+    fn fmt_unpadded(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let Self { year, month, day } = *self;
+        let month = month as u8;
+        if (0..10_000).contains(&year) {
+            write!(f, "{year:04}-{month:02}-{day:02}")
+        } else {
+            write!(f, "{year:+05}-{month:02}-{day:02}")
+        }
+    }
+}
+
+impl PartialOrd for CalendarDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CalendarDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.year
+            .cmp(&other.year)
+            .then_with(|| (self.month as u8).cmp(&(other.month as u8)))
+            .then_with(|| self.day.cmp(&other.day))
+    }
+}
+
+impl SmartDisplay for CalendarDate {
+    type Metadata = ();
+
+    fn metadata(&self, _: FormatterOptions) -> Metadata<Self> {
+        // There is a minimum of four digits for any year, plus the sign if one is displayed.
+        let year_width = cmp::max(self.year.unsigned_abs().num_digits(), 4)
+            + u8::from(!(0..10_000).contains(&self.year));
+        let width = year_width.extend::<usize>()
+            + smart_display::padded_width_of!(
+                "-",
+                self.month as u8 => width(2),
+                "-",
+                self.day => width(2),
+            );
+        Metadata::new(width, self, ())
+    }
+
+    fn fmt_with_metadata(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        metadata: Metadata<Self>,
+    ) -> fmt::Result {
+        f.pad_with_width(
+            metadata.unpadded_width(),
+            format_args!(
+                "{}",
+                DisplayFn(|f: &mut dyn fmt::Write| self.fmt_unpadded(f))
+            ),
+        )
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() {
+            return self.fmt_unpadded(f);
+        }
+        SmartDisplay::fmt(self, f)
+    }
+}
+
+impl From<Date> for CalendarDate {
+    fn from(date: Date) -> Self {
+        date.calendar_date()
+    }
+}
+
+impl TryFrom<CalendarDate> for Date {
+    type Error = error::ComponentRange;
+
+    fn try_from(calendar_date: CalendarDate) -> Result<Self, Self::Error> {
+        calendar_date.to_date()
+    }
+}
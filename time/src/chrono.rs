@@ -0,0 +1,256 @@
+//! Conversions between the types of this crate and those of [chrono](https://docs.rs/chrono).
+//!
+//! | `time`              | `chrono`                | `time` → `chrono` | `chrono` → `time` |
+//! |---------------------|-------------------------|-------------------|-------------------|
+//! | `Date`              | `NaiveDate`             | `TryFrom`         | `TryFrom`         |
+//! | `Time`              | `NaiveTime`             | `From`            | `TryFrom`         |
+//! | `PrimitiveDateTime` | `NaiveDateTime`         | `TryFrom`         | `TryFrom`         |
+//! | `UtcOffset`         | `FixedOffset`           | `TryFrom`         | `From`            |
+//! | `UtcDateTime`       | `DateTime<Utc>`         | `TryFrom`         | `TryFrom`         |
+//! | `OffsetDateTime`    | `DateTime<FixedOffset>` | `TryFrom`         | `TryFrom`         |
+//! | `OffsetDateTime`    | `DateTime<Utc>`         | `TryFrom`         |                   |
+//! | `Duration`          | `TimeDelta`             | `TryFrom`         | `From`            |
+//! | `Weekday`           | `Weekday`               | `From`            | `From`            |
+//! | `Month`             | `Month`                 | `From`            | `From`            |
+//!
+//! Any `DateTime<Tz>` can be converted into an `OffsetDateTime`, retaining its offset. Conversions
+//! fail with [`error::ConversionRange`] when the value cannot be represented by the other crate,
+//! such as a year outside the supported range or an offset of a day or more. As this crate does not
+//! support leap seconds, a chrono value representing a leap second is likewise rejected rather
+//! than being silently adjusted.
+
+use ::chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeDelta,
+    TimeZone, Timelike, Utc,
+};
+
+use crate::{
+    error, Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset,
+    Weekday,
+};
+
+impl TryFrom<Date> for NaiveDate {
+    type Error = error::ConversionRange;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        Self::from_yo_opt(date.year(), date.ordinal().into()).ok_or(error::ConversionRange)
+    }
+}
+
+impl TryFrom<NaiveDate> for Date {
+    type Error = error::ConversionRange;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        Self::from_ordinal_date(date.year(), date.ordinal() as u16)
+            .map_err(|_| error::ConversionRange)
+    }
+}
+
+impl From<Time> for NaiveTime {
+    fn from(time: Time) -> Self {
+        Self::from_hms_nano_opt(
+            time.hour().into(),
+            time.minute().into(),
+            time.second().into(),
+            time.nanosecond(),
+        )
+        .expect("all valid times are representable")
+    }
+}
+
+impl TryFrom<NaiveTime> for Time {
+    type Error = error::ConversionRange;
+
+    fn try_from(time: NaiveTime) -> Result<Self, Self::Error> {
+        // A leap second is represented by a nanosecond value of at least one billion, which is
+        // rejected here.
+        Self::from_hms_nano(
+            time.hour() as u8,
+            time.minute() as u8,
+            time.second() as u8,
+            time.nanosecond(),
+        )
+        .map_err(|_| error::ConversionRange)
+    }
+}
+
+impl TryFrom<PrimitiveDateTime> for NaiveDateTime {
+    type Error = error::ConversionRange;
+
+    fn try_from(datetime: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        Ok(NaiveDate::try_from(datetime.date())?.and_time(datetime.time().into()))
+    }
+}
+
+impl TryFrom<NaiveDateTime> for PrimitiveDateTime {
+    type Error = error::ConversionRange;
+
+    fn try_from(datetime: NaiveDateTime) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            datetime.date().try_into()?,
+            datetime.time().try_into()?,
+        ))
+    }
+}
+
+impl TryFrom<UtcOffset> for FixedOffset {
+    type Error = error::ConversionRange;
+
+    fn try_from(offset: UtcOffset) -> Result<Self, Self::Error> {
+        Self::east_opt(offset.whole_seconds()).ok_or(error::ConversionRange)
+    }
+}
+
+impl From<FixedOffset> for UtcOffset {
+    fn from(offset: FixedOffset) -> Self {
+        Self::from_whole_seconds(offset.local_minus_utc())
+            .expect("all fixed offsets are less than one day")
+    }
+}
+
+impl TryFrom<UtcDateTime> for DateTime<Utc> {
+    type Error = error::ConversionRange;
+
+    fn try_from(datetime: UtcDateTime) -> Result<Self, Self::Error> {
+        let naive =
+            NaiveDateTime::try_from(PrimitiveDateTime::new(datetime.date(), datetime.time()))?;
+        Ok(Self::from_naive_utc_and_offset(naive, Utc))
+    }
+}
+
+impl TryFrom<DateTime<Utc>> for UtcDateTime {
+    type Error = error::ConversionRange;
+
+    fn try_from(datetime: DateTime<Utc>) -> Result<Self, Self::Error> {
+        Ok(PrimitiveDateTime::try_from(datetime.naive_utc())?.as_utc())
+    }
+}
+
+impl TryFrom<OffsetDateTime> for DateTime<FixedOffset> {
+    type Error = error::ConversionRange;
+
+    fn try_from(datetime: OffsetDateTime) -> Result<Self, Self::Error> {
+        let offset = FixedOffset::try_from(datetime.offset())?;
+        let naive =
+            NaiveDateTime::try_from(PrimitiveDateTime::new(datetime.date(), datetime.time()))?;
+        offset
+            .from_local_datetime(&naive)
+            .single()
+            .ok_or(error::ConversionRange)
+    }
+}
+
+impl TryFrom<OffsetDateTime> for DateTime<Utc> {
+    type Error = error::ConversionRange;
+
+    fn try_from(datetime: OffsetDateTime) -> Result<Self, Self::Error> {
+        datetime
+            .checked_to_utc()
+            .ok_or(error::ConversionRange)?
+            .try_into()
+    }
+}
+
+impl<Tz: TimeZone> TryFrom<DateTime<Tz>> for OffsetDateTime {
+    type Error = error::ConversionRange;
+
+    fn try_from(datetime: DateTime<Tz>) -> Result<Self, Self::Error> {
+        let offset = UtcOffset::from(datetime.offset().fix());
+        Ok(PrimitiveDateTime::try_from(datetime.naive_local())?.assume_offset(offset))
+    }
+}
+
+impl TryFrom<Duration> for TimeDelta {
+    type Error = error::ConversionRange;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        // Unlike this crate, chrono always stores a non-negative number of nanoseconds.
+        let (seconds, nanoseconds) = if duration.subsec_nanoseconds() < 0 {
+            (
+                duration
+                    .whole_seconds()
+                    .checked_sub(1)
+                    .ok_or(error::ConversionRange)?,
+                (duration.subsec_nanoseconds() + 1_000_000_000) as u32,
+            )
+        } else {
+            (
+                duration.whole_seconds(),
+                duration.subsec_nanoseconds() as u32,
+            )
+        };
+        Self::new(seconds, nanoseconds).ok_or(error::ConversionRange)
+    }
+}
+
+impl From<TimeDelta> for Duration {
+    fn from(time_delta: TimeDelta) -> Self {
+        Self::new(time_delta.num_seconds(), time_delta.subsec_nanos())
+    }
+}
+
+impl From<Weekday> for ::chrono::Weekday {
+    fn from(weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Monday => Self::Mon,
+            Weekday::Tuesday => Self::Tue,
+            Weekday::Wednesday => Self::Wed,
+            Weekday::Thursday => Self::Thu,
+            Weekday::Friday => Self::Fri,
+            Weekday::Saturday => Self::Sat,
+            Weekday::Sunday => Self::Sun,
+        }
+    }
+}
+
+impl From<::chrono::Weekday> for Weekday {
+    fn from(weekday: ::chrono::Weekday) -> Self {
+        match weekday {
+            ::chrono::Weekday::Mon => Self::Monday,
+            ::chrono::Weekday::Tue => Self::Tuesday,
+            ::chrono::Weekday::Wed => Self::Wednesday,
+            ::chrono::Weekday::Thu => Self::Thursday,
+            ::chrono::Weekday::Fri => Self::Friday,
+            ::chrono::Weekday::Sat => Self::Saturday,
+            ::chrono::Weekday::Sun => Self::Sunday,
+        }
+    }
+}
+
+impl From<Month> for ::chrono::Month {
+    fn from(month: Month) -> Self {
+        match month {
+            Month::January => Self::January,
+            Month::February => Self::February,
+            Month::March => Self::March,
+            Month::April => Self::April,
+            Month::May => Self::May,
+            Month::June => Self::June,
+            Month::July => Self::July,
+            Month::August => Self::August,
+            Month::September => Self::September,
+            Month::October => Self::October,
+            Month::November => Self::November,
+            Month::December => Self::December,
+        }
+    }
+}
+
+impl From<::chrono::Month> for Month {
+    fn from(month: ::chrono::Month) -> Self {
+        match month {
+            ::chrono::Month::January => Self::January,
+            ::chrono::Month::February => Self::February,
+            ::chrono::Month::March => Self::March,
+            ::chrono::Month::April => Self::April,
+            ::chrono::Month::May => Self::May,
+            ::chrono::Month::June => Self::June,
+            ::chrono::Month::July => Self::July,
+            ::chrono::Month::August => Self::August,
+            ::chrono::Month::September => Self::September,
+            ::chrono::Month::October => Self::October,
+            ::chrono::Month::November => Self::November,
+            ::chrono::Month::December => Self::December,
+        }
+    }
+}
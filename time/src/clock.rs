@@ -0,0 +1,119 @@
+//! Sources of the current time.
+//!
+//! [`OffsetDateTime::now_utc`](crate::OffsetDateTime::now_utc) and [`UtcDateTime::now`] consult
+//! the global [`Clock`], which is the [`SystemClock`] unless another has been installed with
+//! [`set_global_clock`]. This permits obtaining the current time on targets where the system
+//! clock is unavailable, such as WebAssembly without the `wasm-bindgen` feature, and freezing
+//! time in tests.
+//!
+//! The [`Clock`] trait and [`FixedClock`] do not require the standard library, so that `no_std`
+//! code can accept a source of the current time. The global clock and [`SystemClock`] require the
+//! `std` feature.
+//!
+//! ```rust
+//! # use time::clock::{self, FixedClock};
+//! # use time::OffsetDateTime;
+//! # use time_macros::{datetime, utc_datetime};
+//! static FROZEN: FixedClock = FixedClock::new(utc_datetime!(2024-01-01 0:00));
+//!
+//! clock::set_global_clock(&FROZEN);
+//! assert_eq!(OffsetDateTime::now_utc(), datetime!(2024-01-01 0:00 UTC));
+//! clock::reset_global_clock();
+//! ```
+
+#[cfg(feature = "std")]
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "std")]
+use std::sync::{PoisonError, RwLock};
+
+#[cfg(feature = "std")]
+use crate::internal_macros::cfg_wasm_bindgen;
+use crate::UtcDateTime;
+
+/// A source of the current time.
+pub trait Clock: Sync {
+    /// Obtain the current date and time in UTC.
+    fn now_utc(&self) -> UtcDateTime;
+}
+
+/// The clock provided by the platform. This uses JavaScript's `Date` on WebAssembly when the
+/// `wasm-bindgen` feature is enabled and [`SystemTime`](std::time::SystemTime) otherwise.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now_utc(&self) -> UtcDateTime {
+        cfg_wasm_bindgen! {{
+            fn now() -> UtcDateTime {
+                js_sys::Date::new_0().into()
+            }
+        } else {
+            fn now() -> UtcDateTime {
+                std::time::SystemTime::now().into()
+            }
+        }}
+        now()
+    }
+}
+
+/// A clock that always returns the same time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedClock {
+    /// The time returned by the clock.
+    now: UtcDateTime,
+}
+
+impl FixedClock {
+    /// Create a clock that always returns the provided time.
+    pub const fn new(now: UtcDateTime) -> Self {
+        Self { now }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now_utc(&self) -> UtcDateTime {
+        self.now
+    }
+}
+
+/// The clock installed by [`set_global_clock`], if any.
+#[cfg(feature = "std")]
+static GLOBAL_CLOCK: RwLock<Option<&'static dyn Clock>> = RwLock::new(None);
+/// Whether a clock has been installed. This avoids acquiring the lock in the common case.
+#[cfg(feature = "std")]
+static GLOBAL_CLOCK_IS_SET: AtomicBool = AtomicBool::new(false);
+
+/// Install the provided clock as the source of the current time for all threads.
+///
+/// This is intended for applications and tests. Libraries should not call this method, as doing
+/// so would affect all other users of this crate.
+#[cfg(feature = "std")]
+pub fn set_global_clock(clock: &'static dyn Clock) {
+    *GLOBAL_CLOCK.write().unwrap_or_else(PoisonError::into_inner) = Some(clock);
+    GLOBAL_CLOCK_IS_SET.store(true, Ordering::Release);
+}
+
+/// Restore the [`SystemClock`] as the source of the current time for all threads.
+#[cfg(feature = "std")]
+pub fn reset_global_clock() {
+    *GLOBAL_CLOCK.write().unwrap_or_else(PoisonError::into_inner) = None;
+    GLOBAL_CLOCK_IS_SET.store(false, Ordering::Release);
+}
+
+/// Obtain the current date and time in UTC from the global clock.
+#[cfg(feature = "std")]
+pub(crate) fn now_utc() -> UtcDateTime {
+    #[cfg(feature = "test-util")]
+    if let Some(now) = crate::test_util::now_utc() {
+        return now;
+    }
+    if GLOBAL_CLOCK_IS_SET.load(Ordering::Acquire) {
+        let global_clock = *GLOBAL_CLOCK.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some(clock) = global_clock {
+            return clock.now_utc();
+        }
+    }
+    SystemClock.now_utc()
+}
@@ -0,0 +1,110 @@
+//! Formatting and parsing using the `%`-based format strings of `time` 0.2.
+//!
+//! This module exists to ease staged migrations from `time` 0.2, where the inherent `format` and
+//! `parse` methods accepted a format string directly. Format strings are interpreted by
+//! [`parse_strftime_borrowed`], so the full set of components supported there is available.
+//! Existing calls can be rewritten mechanically:
+//!
+//! ```rust
+//! # use time::compat_0_2;
+//! # use time_macros::time;
+//! # use time::Time;
+//! // time 0.2: time.format("%T")
+//! assert_eq!(compat_0_2::format(&time!(12:34:56), "%T")?, "12:34:56");
+//! // time 0.2: Time::parse("12:34:56", "%T")
+//! assert_eq!(
+//!     compat_0_2::parse::<Time>("12:34:56", "%T")?,
+//!     time!(12:34:56)
+//! );
+//! # Ok::<_, time::Error>(())
+//! ```
+//!
+//! New code should use [`format_description!`](crate::macros::format_description) or
+//! [`format_description::parse`](crate::format_description::parse) instead, as format
+//! descriptions can then be validated once rather than on every call.
+
+use alloc::string::String;
+
+use crate::format_description::parse_strftime_borrowed;
+use crate::{Date, Error, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+/// Sealed trait to prevent downstream implementations.
+mod sealed {
+    /// A trait that cannot be implemented by downstream users.
+    pub trait Sealed: Sized {}
+    impl Sealed for super::Date {}
+    impl Sealed for super::Time {}
+    impl Sealed for super::PrimitiveDateTime {}
+    impl Sealed for super::OffsetDateTime {}
+    impl Sealed for super::UtcOffset {}
+}
+
+/// A type that can be formatted and parsed using a `time` 0.2 format string.
+///
+/// This trait is implemented for the types that existed in `time` 0.2. The [`format`] and
+/// [`parse`] functions are generally more convenient than calling these methods directly.
+pub trait Compat02: sealed::Sealed {
+    /// Format the value using the provided format string.
+    fn format_0_2(&self, format: &str) -> Result<String, Error>;
+
+    /// Parse a value from the input using the provided format string.
+    fn parse_0_2(input: &str, format: &str) -> Result<Self, Error>;
+}
+
+/// Implement [`Compat02`] for the given types in terms of their inherent `format` and `parse`
+/// methods.
+macro_rules! impl_compat {
+    ($($type:ty),+ $(,)?) => {$(
+        impl Compat02 for $type {
+            fn format_0_2(&self, format: &str) -> Result<String, Error> {
+                let items = parse_strftime_borrowed(format)?;
+                Ok(<$type>::format(*self, &items)?)
+            }
+
+            fn parse_0_2(input: &str, format: &str) -> Result<Self, Error> {
+                let items = parse_strftime_borrowed(format)?;
+                Ok(<$type>::parse(input, &items)?)
+            }
+        }
+    )+};
+}
+
+impl_compat!(Date, Time, PrimitiveDateTime, OffsetDateTime, UtcOffset);
+
+/// Format the value using the provided `time` 0.2 format string.
+///
+/// This is equivalent to the inherent `format` methods of `time` 0.2, except that an invalid
+/// format string or a value lacking the necessary information results in an error rather than a
+/// panic.
+///
+/// ```rust
+/// # use time::compat_0_2;
+/// # use time_macros::{date, datetime};
+/// assert_eq!(compat_0_2::format(&date!(2019-01-02), "%Y-%m-%d")?, "2019-01-02");
+/// assert_eq!(
+///     compat_0_2::format(&datetime!(2019-01-02 3:04:05 +6), "%a, %d %b %Y %T %z")?,
+///     "Wed, 02 Jan 2019 03:04:05 +0600"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+pub fn format<T: Compat02>(value: &T, format: &str) -> Result<String, Error> {
+    value.format_0_2(format)
+}
+
+/// Parse a value from the input using the provided `time` 0.2 format string.
+///
+/// This is equivalent to both the `time::parse` function and the inherent `parse` methods of
+/// `time` 0.2.
+///
+/// ```rust
+/// # use time::{compat_0_2, Date};
+/// # use time_macros::date;
+/// assert_eq!(
+///     compat_0_2::parse::<Date>("2019-01-02", "%Y-%m-%d")?,
+///     date!(2019-01-02)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+pub fn parse<T: Compat02>(input: &str, format: &str) -> Result<T, Error> {
+    T::parse_0_2(input, format)
+}
@@ -2,6 +2,7 @@
 
 #[cfg(feature = "formatting")]
 use alloc::string::String;
+use core::iter::FusedIterator;
 use core::num::{NonZeroI32, NonZeroU8};
 use core::ops::{Add, Sub};
 use core::time::Duration as StdDuration;
@@ -23,9 +24,11 @@ use crate::internal_macros::{
     impl_sub_assign,
 };
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
-use crate::util::{days_in_year, is_leap_year, weeks_in_year};
-use crate::{error, hint, Duration, Month, PrimitiveDateTime, Time, Weekday};
+use crate::parsing::{FromParsed, Parsable, ParserOptions, WeekdayMode};
+use crate::util::{days_in_year, is_leap_year, next_leap_year, weeks_in_year};
+use crate::{
+    error, hint, CalendarDate, Duration, IsoWeekDate, Month, PrimitiveDateTime, Time, Weekday,
+};
 
 type Year = RangedI32<MIN_YEAR, MAX_YEAR>;
 
@@ -128,6 +131,7 @@ impl Date {
                     maximum: month.length(year) as _,
                     value: day as _,
                     conditional_message: Some("for the given month and year"),
+                    conditional_date: Some((year, Some(month))),
                 });
             }
         }
@@ -142,6 +146,38 @@ impl Date {
         })
     }
 
+    /// Attempt to create a `Date` from the year, zero-based month, and day. The month is in the
+    /// range `0..=11`, as is the case for the `tm` struct in C and the `Date` object in JavaScript.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::date;
+    /// assert_eq!(Date::from_calendar_date0(2019, 0, 1), Ok(date!(2019-01-01)));
+    /// assert_eq!(Date::from_calendar_date0(2019, 11, 31), Ok(date!(2019-12-31)));
+    /// ```
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// assert!(Date::from_calendar_date0(2019, 12, 1).is_err()); // There is no thirteenth month.
+    /// ```
+    pub const fn from_calendar_date0(
+        year: i32,
+        month0: u8,
+        day: u8,
+    ) -> Result<Self, error::ComponentRange> {
+        if month0 > 11 {
+            return Err(error::ComponentRange {
+                name: "month0",
+                minimum: 0,
+                maximum: 11,
+                value: month0 as _,
+                conditional_message: None,
+                conditional_date: None,
+            });
+        }
+        Self::from_calendar_date(year, Month::January.nth_next(month0), day)
+    }
+
     /// Attempt to create a `Date` from the year and ordinal day number.
     ///
     /// ```rust
@@ -166,6 +202,7 @@ impl Date {
                     maximum: days_in_year(year) as _,
                     value: ordinal as _,
                     conditional_message: Some("for the given year"),
+                    conditional_date: Some((year, None)),
                 });
             }
         }
@@ -187,6 +224,18 @@ impl Date {
     /// # use time::{Date, Weekday::*};
     /// assert!(Date::from_iso_week_date(2019, 53, Monday).is_err()); // 2019 doesn't have 53 weeks.
     /// ```
+    ///
+    /// As with the other constructors, this can be used in `const` contexts.
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday::*};
+    /// const DATE: Date = match Date::from_iso_week_date(2020, 53, Friday) {
+    ///     Ok(date) => date,
+    ///     Err(_) => panic!("invalid date"),
+    /// };
+    /// assert_eq!(DATE.to_iso_week_date(), (2020, 53, Friday));
+    /// ```
+    #[doc(alias = "from_iso_week")]
     pub const fn from_iso_week_date(
         year: i32,
         week: u8,
@@ -203,6 +252,7 @@ impl Date {
                     maximum: weeks_in_year(year) as _,
                     value: week as _,
                     conditional_message: Some("for the given year"),
+                    conditional_date: Some((year, None)),
                 });
             }
         }
@@ -221,6 +271,28 @@ impl Date {
         };
         let ordinal = week as i16 * 7 + weekday.number_from_monday() as i16 - jan_4;
 
+        // The week may extend beyond the range of representable dates.
+        if ordinal <= 0 && year == MIN_YEAR {
+            return Err(error::ComponentRange {
+                name: "weekday",
+                minimum: (jan_4 - 6) as _,
+                maximum: 7,
+                value: weekday.number_from_monday() as _,
+                conditional_message: Some("for the given year and week"),
+                conditional_date: None,
+            });
+        }
+        if ordinal > days_in_year(year) as i16 && year == MAX_YEAR {
+            return Err(error::ComponentRange {
+                name: "weekday",
+                minimum: 1,
+                maximum: (days_in_year(year) as i16 + jan_4 - week as i16 * 7) as _,
+                value: weekday.number_from_monday() as _,
+                conditional_message: Some("for the given year and week"),
+                conditional_date: None,
+            });
+        }
+
         Ok(if ordinal <= 0 {
             // Safety: `ordinal` is not zero.
             unsafe {
@@ -240,6 +312,34 @@ impl Date {
         })
     }
 
+    /// Attempt to create a `Date` for the first day (Monday) of the provided ISO year and week.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::date;
+    /// assert_eq!(Date::first_day_of_iso_week(2019, 1), Ok(date!(2018-12-31)));
+    /// assert_eq!(Date::first_day_of_iso_week(2020, 53), Ok(date!(2020-12-28)));
+    /// assert!(Date::first_day_of_iso_week(2019, 53).is_err()); // 2019 doesn't have 53 weeks.
+    /// ```
+    #[doc(alias = "monday_of_week")]
+    pub const fn first_day_of_iso_week(year: i32, week: u8) -> Result<Self, error::ComponentRange> {
+        Self::from_iso_week_date(year, week, Weekday::Monday)
+    }
+
+    /// Attempt to create a `Date` for the last day (Sunday) of the provided ISO year and week.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::date;
+    /// assert_eq!(Date::last_day_of_iso_week(2019, 1), Ok(date!(2019-01-06)));
+    /// assert_eq!(Date::last_day_of_iso_week(2020, 53), Ok(date!(2021-01-03)));
+    /// assert!(Date::last_day_of_iso_week(2019, 53).is_err()); // 2019 doesn't have 53 weeks.
+    /// ```
+    #[doc(alias = "sunday_of_week")]
+    pub const fn last_day_of_iso_week(year: i32, week: u8) -> Result<Self, error::ComponentRange> {
+        Self::from_iso_week_date(year, week, Weekday::Sunday)
+    }
+
     /// Create a `Date` from the Julian day.
     ///
     /// The algorithm to perform this conversion is derived from one provided by Peter Baum; it is
@@ -260,6 +360,31 @@ impl Date {
         Ok(Self::from_julian_day_unchecked(julian_day))
     }
 
+    /// Obtain an iterator over every date in the provided year, in order.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::date;
+    /// let mut dates = Date::all_in_year(2024)?;
+    /// assert_eq!(dates.len(), 366);
+    /// assert_eq!(dates.next(), Some(date!(2024-01-01)));
+    /// assert_eq!(dates.next_back(), Some(date!(2024-12-31)));
+    /// assert_eq!(Date::all_in_year(2023)?.len(), 365);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn all_in_year(
+        year: i32,
+    ) -> Result<
+        impl DoubleEndedIterator<Item = Self> + ExactSizeIterator + FusedIterator + Clone,
+        error::ComponentRange,
+    > {
+        ensure_ranged!(Year: year);
+        Ok((1..=days_in_year(year)).map(move |ordinal| {
+            // Safety: `ordinal` is not zero.
+            unsafe { Self::__from_ordinal_date_unchecked(year, ordinal) }
+        }))
+    }
+
     /// Create a `Date` from the Julian day.
     ///
     /// This does not check the validity of the provided Julian day, and as such may result in an
@@ -342,6 +467,20 @@ impl Date {
         }
     }
 
+    /// Get the zero-based month, as used by the `tm` struct in C and the `Date` object in
+    /// JavaScript.
+    ///
+    /// The returned value will always be in the range `0..=11`.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019-01-01).month0(), 0);
+    /// assert_eq!(date!(2019-12-31).month0(), 11);
+    /// ```
+    pub const fn month0(self) -> u8 {
+        self.month() as u8 - 1
+    }
+
     /// Get the day of the month.
     ///
     /// The returned value will always be in the range `1..=31`.
@@ -368,6 +507,48 @@ impl Date {
         (self.value.get() & 0x1FF) as _
     }
 
+    /// Get the zero-based day of the year, as used by the `tm` struct in C.
+    ///
+    /// The returned value will always be in the range `0..=365` (`0..=364` for common years).
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019-01-01).ordinal0(), 0);
+    /// assert_eq!(date!(2019-12-31).ordinal0(), 364);
+    /// ```
+    pub const fn ordinal0(self) -> u16 {
+        self.ordinal() - 1
+    }
+
+    /// Get the number of days in the month of the date.
+    ///
+    /// The returned value will always be in the range `28..=31`.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019-01-15).days_in_month(), 31);
+    /// assert_eq!(date!(2019-02-15).days_in_month(), 28);
+    /// assert_eq!(date!(2020-02-15).days_in_month(), 29);
+    /// assert_eq!(date!(2019-04-15).days_in_month(), 30);
+    /// ```
+    pub const fn days_in_month(self) -> u8 {
+        let (year, month, _) = self.to_calendar_date();
+        month.length(year)
+    }
+
+    /// Whether the year of the date is a leap year in the proleptic Gregorian calendar.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert!(date!(2020-06-15).is_leap_year());
+    /// assert!(!date!(2019-06-15).is_leap_year());
+    /// assert!(!date!(1900-06-15).is_leap_year());
+    /// assert!(date!(2000-06-15).is_leap_year());
+    /// ```
+    pub const fn is_leap_year(self) -> bool {
+        is_leap_year(self.year())
+    }
+
     /// Get the ISO 8601 year and week number.
     pub(crate) const fn iso_year_week(self) -> (i32, u8) {
         let (year, ordinal) = self.to_ordinal_date();
@@ -395,6 +576,23 @@ impl Date {
         self.iso_year_week().1
     }
 
+    /// Get the number of weeks in the ISO week-numbering year of the date. This is the year
+    /// returned by [`Date::to_iso_week_date`], which may differ from the calendar year near its
+    /// start and end.
+    ///
+    /// The returned value will always be either 52 or 53.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019-06-01).weeks_in_year(), 52);
+    /// assert_eq!(date!(2020-06-01).weeks_in_year(), 53);
+    /// assert_eq!(date!(2021-01-01).weeks_in_year(), 53); // 2020-W53-5
+    /// assert_eq!(date!(2024-12-31).weeks_in_year(), 52); // 2025-W01-2
+    /// ```
+    pub const fn weeks_in_year(self) -> u8 {
+        weeks_in_year(self.iso_year_week().0)
+    }
+
     /// Get the week number where week 1 begins on the first Sunday.
     ///
     /// The returned value will always be in the range `0..=53`.
@@ -461,6 +659,41 @@ impl Date {
         )
     }
 
+    /// Get the year, month, and day, with the month as a number from 1 to 12.
+    ///
+    /// This is the inverse of `Date::try_from((year, month, day))`.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019-01-01).as_ymd(), (2019, 1, 1));
+    /// assert_eq!(date!(2019-12-31).as_ymd(), (2019, 12, 31));
+    /// ```
+    pub const fn as_ymd(self) -> (i32, u8, u8) {
+        let (year, month, day) = self.to_calendar_date();
+        (year, month as u8, day)
+    }
+
+    /// Get the year, month, and day as a [`CalendarDate`].
+    ///
+    /// This is equivalent to [`Date::to_calendar_date`], but the components are named.
+    ///
+    /// ```rust
+    /// # use time::{CalendarDate, Month};
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     date!(2019-01-02).calendar_date(),
+    ///     CalendarDate {
+    ///         year: 2019,
+    ///         month: Month::January,
+    ///         day: 2,
+    ///     }
+    /// );
+    /// ```
+    pub const fn calendar_date(self) -> CalendarDate {
+        let (year, month, day) = self.to_calendar_date();
+        CalendarDate { year, month, day }
+    }
+
     /// Get the year and ordinal day number.
     ///
     /// ```rust
@@ -493,6 +726,31 @@ impl Date {
         }
     }
 
+    /// Get the ISO 8601 year, week number, and weekday as an [`IsoWeekDate`].
+    ///
+    /// This is equivalent to [`Date::to_iso_week_date`], but the components are named.
+    ///
+    /// ```rust
+    /// # use time::{IsoWeekDate, Weekday};
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     date!(2021-01-01).iso_week_date(),
+    ///     IsoWeekDate {
+    ///         year: 2020,
+    ///         week: 53,
+    ///         weekday: Weekday::Friday,
+    ///     }
+    /// );
+    /// ```
+    pub const fn iso_week_date(self) -> IsoWeekDate {
+        let (year, week, weekday) = self.to_iso_week_date();
+        IsoWeekDate {
+            year,
+            week,
+            weekday,
+        }
+    }
+
     /// Get the weekday.
     ///
     /// ```rust
@@ -578,6 +836,31 @@ impl Date {
         }
     }
 
+    /// Get the first February 29 that is strictly later than the `Date`, or `None` if it is after
+    /// [`Date::MAX`].
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2023-06-28).next_february_29(), Some(date!(2024-02-29)));
+    /// assert_eq!(date!(2024-01-01).next_february_29(), Some(date!(2024-02-29)));
+    /// assert_eq!(date!(2024-02-29).next_february_29(), Some(date!(2028-02-29)));
+    /// assert_eq!(date!(2097-01-01).next_february_29(), Some(date!(2104-02-29)));
+    /// assert_eq!(Date::MAX.next_february_29(), None);
+    /// ```
+    pub const fn next_february_29(self) -> Option<Self> {
+        let year = if is_leap_year(self.year()) && self.ordinal() < 60 {
+            self.year()
+        } else {
+            const_try_opt!(next_leap_year(self.year()))
+        };
+        if year > MAX_YEAR {
+            return None;
+        }
+        // Safety: `ordinal` is not zero.
+        Some(unsafe { Self::__from_ordinal_date_unchecked(year, 60) })
+    }
+
     /// Calculates the first occurrence of a weekday that is strictly later than a given `Date`.
     ///
     /// # Panics
@@ -1056,6 +1339,7 @@ impl Date {
                 minimum: 1,
                 maximum: 28,
                 conditional_message: Some("for the given month and year"),
+                conditional_date: Some((year, Some(Month::February))),
             }),
             // We're going from a common year to a leap year. Shift dates in March and later by
             // one day.
@@ -1068,6 +1352,36 @@ impl Date {
         }
     }
 
+    /// Replace the year, changing February 29 to February 28 if the new year is not a leap year.
+    /// Otherwise, the month and day will be unchanged.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     date!(2024-02-29).with_year_clamped(2023),
+    ///     Ok(date!(2023-02-28))
+    /// );
+    /// assert_eq!(
+    ///     date!(2024-02-29).with_year_clamped(2028),
+    ///     Ok(date!(2028-02-29))
+    /// );
+    /// assert_eq!(
+    ///     date!(2024-03-01).with_year_clamped(2023),
+    ///     Ok(date!(2023-03-01))
+    /// );
+    /// assert!(date!(2024-02-29).with_year_clamped(1_000_000_000).is_err()); // 1_000_000_000 isn't a valid year
+    /// ```
+    #[must_use = "This method does not mutate the original `Date`."]
+    pub const fn with_year_clamped(self, year: i32) -> Result<Self, error::ComponentRange> {
+        ensure_ranged!(Year: year);
+
+        if self.ordinal() == 60 && is_leap_year(self.year()) && !is_leap_year(year) {
+            // Safety: `ordinal` is not zero.
+            return Ok(unsafe { Self::__from_ordinal_date_unchecked(year, 59) });
+        }
+        self.replace_year(year)
+    }
+
     /// Replace the month of the year.
     ///
     /// ```rust
@@ -1107,6 +1421,7 @@ impl Date {
                     maximum: self.month().length(self.year()) as _,
                     value: day as _,
                     conditional_message: Some("for the given month and year"),
+                    conditional_date: Some((self.year(), Some(self.month()))),
                 });
             }
         }
@@ -1140,6 +1455,7 @@ impl Date {
                     maximum: days_in_year(self.year()) as _,
                     value: ordinal as _,
                     conditional_message: Some("for the given year"),
+                    conditional_date: Some((self.year(), None)),
                 });
             }
         }
@@ -1267,7 +1583,10 @@ impl Date {
         output: &mut impl io::Write,
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(output, Some(self), None, None)
+        let result = format.format_into_buffered(output, Some(self), None, None);
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_format(false, result.as_ref().ok().copied());
+        result
     }
 
     /// Format the `Date` using the provided [format description](crate::format_description).
@@ -1280,7 +1599,33 @@ impl Date {
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(Some(self), None, None)
+        let result = format.format(Some(self), None, None);
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_format(true, result.as_ref().ok().map(String::len));
+        result
+    }
+
+    /// Determine the number of bytes the `Date` would be formatted as using the provided [format
+    /// description](crate::format_description).
+    ///
+    /// The length is exact, so it can be used to allocate a buffer of the correct size before
+    /// calling [`format_into`](Self::format_into).
+    ///
+    /// The length is determined by formatting the value and discarding the output, so calling this
+    /// costs as much as formatting.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::date;
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// assert_eq!(date!(2020-01-02).formatted_len(&format)?, "2020-01-02".len());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn formatted_len(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.formatted_len(Some(self), None, None)
     }
 }
 
@@ -1300,8 +1645,73 @@ impl Date {
         input: &str,
         description: &(impl Parsable + ?Sized),
     ) -> Result<Self, error::Parse> {
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_parse(input.len());
         description.parse_date(input.as_bytes())
     }
+
+    /// Parse a `Date` from the input using the provided [format
+    /// description](crate::format_description), subject to the provided [`ParserOptions`]. With
+    /// limits set, this should be preferred over [`Date::parse`] when the input is untrusted.
+    ///
+    /// ```rust
+    /// # use time::parsing::ParserOptions;
+    /// # use time::Date;
+    /// # use time_macros::{date, format_description};
+    /// let format = format_description!("[year]-[month]-[day]");
+    /// let options = ParserOptions::DEFAULT.with_max_input_len(10);
+    /// assert_eq!(
+    ///     Date::parse_with_options("2020-01-02", &format, options)?,
+    ///     date!(2020-01-02)
+    /// );
+    /// assert!(Date::parse_with_options("2020-01-02 ", &format, options).is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_options(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+        options: ParserOptions,
+    ) -> Result<Self, error::Parse> {
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_parse(input.len());
+        let parsed = description.parse_with_options(input.as_bytes(), options)?;
+        Ok(Self::from_parsed(parsed, options)?)
+    }
+
+    /// Parse a `Date` from the input using the provided [format
+    /// description](crate::format_description), handling a parsed weekday as indicated by the
+    /// [`WeekdayMode`]. [`Date::parse`] is equivalent to using [`WeekdayMode::Ignore`].
+    ///
+    /// ```rust
+    /// # use time_macros::{date, format_description};
+    /// # use time::parsing::WeekdayMode;
+    /// # use time::Date;
+    /// let format = format_description!("[weekday], [month repr:long] [day padding:none], [year]");
+    /// assert_eq!(
+    ///     Date::parse_with_weekday_mode("Friday, March 1, 2024", &format, WeekdayMode::Validate)?,
+    ///     date!(2024-03-01)
+    /// );
+    /// assert!(
+    ///     Date::parse_with_weekday_mode("Monday, March 1, 2024", &format, WeekdayMode::Validate)
+    ///         .is_err()
+    /// );
+    /// assert_eq!(
+    ///     Date::parse_with_weekday_mode("Monday, March 1, 2024", &format, WeekdayMode::Prefer)?,
+    ///     date!(2024-03-04)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_weekday_mode(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+        mode: WeekdayMode,
+    ) -> Result<Self, error::Parse> {
+        Self::parse_with_options(
+            input,
+            description,
+            ParserOptions::DEFAULT.with_weekday_mode(mode),
+        )
+    }
 }
 
 mod private {
@@ -1457,4 +1867,12 @@ impl Sub for Date {
         Duration::days((self.to_julian_day() - other.to_julian_day()).extend())
     }
 }
+
+impl TryFrom<(i32, u8, u8)> for Date {
+    type Error = error::ComponentRange;
+
+    fn try_from((year, month, day): (i32, u8, u8)) -> Result<Self, Self::Error> {
+        Self::from_calendar_date(year, Month::try_from(month)?, day)
+    }
+}
 // endregion trait impls
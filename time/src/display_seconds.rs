@@ -0,0 +1,126 @@
+//! The [`DisplaySeconds`] struct and its associated `impl`s.
+
+use core::fmt;
+
+use powerfmt::ext::FormatterExt;
+use powerfmt::smart_display::{FormatterOptions, Metadata, SmartDisplay};
+
+use crate::convert::*;
+use crate::human_duration::fmt_decimal;
+use crate::util::{DisplayFn, WidthCounter};
+use crate::{Duration, Rounding};
+
+/// A [`Duration`] that is displayed as a number of seconds, without a unit, such as `90.5`. This
+/// is obtained via [`Duration::display_seconds`].
+///
+/// By default the output is exact: the fractional part includes every nonzero digit down to the
+/// nanosecond, and is omitted if there are none. When a precision is provided, exactly that many
+/// fractional digits are written, with the value rounded half away from zero. This computation is
+/// performed without the use of floating point, so `{:.3}` always writes the number of seconds
+/// rounded to the nearest millisecond.
+///
+/// Width, fill, and alignment apply to the output as a whole.
+///
+/// ```rust
+/// # use time::ext::NumericalDuration;
+/// let duration = 90.seconds() + 123_456_789.nanoseconds();
+/// assert_eq!(duration.display_seconds().to_string(), "90.123456789");
+/// assert_eq!(format!("{:.3}", duration.display_seconds()), "90.123");
+/// assert_eq!(format!("{:.0}", duration.display_seconds()), "90");
+/// assert_eq!(format!("{:.3}", 1.9996.seconds().display_seconds()), "2.000");
+/// assert_eq!(format!("{}", (-1.5).seconds().display_seconds()), "-1.5");
+/// assert_eq!(format!("[{:>6}]", 2.seconds().display_seconds()), "[     2]");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisplaySeconds {
+    /// The wrapped duration.
+    duration: Duration,
+}
+
+impl DisplaySeconds {
+    /// Wrap the provided duration.
+    pub(crate) const fn new(duration: Duration) -> Self {
+        Self { duration }
+    }
+
+    /// Write the duration without padding, using the provided precision in place of that of a
+    /// formatter.
+    fn fmt_unpadded(&self, f: &mut dyn fmt::Write, precision: Option<usize>) -> fmt::Result {
+        let duration = self.duration;
+        if duration.is_negative() {
+            f.write_str("-")?;
+        }
+
+        let mut seconds = duration.whole_seconds().unsigned_abs();
+        let mut nanoseconds = duration.subsec_nanoseconds().unsigned_abs();
+
+        let Some(precision) = precision else {
+            return fmt_decimal(f, seconds, nanoseconds, Nanosecond::per(Second));
+        };
+
+        // Round half away from zero to the number of digits that can be nonzero.
+        let digits = precision.min(9);
+        let divisor = 10_u32.pow((9 - digits) as u32);
+        let remainder = nanoseconds % divisor;
+        nanoseconds -= remainder;
+        if Rounding::HalfAwayFromZero.rounds_up(remainder, divisor, false) {
+            nanoseconds += divisor;
+            if nanoseconds == Nanosecond::per(Second) {
+                nanoseconds = 0;
+                seconds += 1;
+            }
+        }
+
+        write!(f, "{seconds}")?;
+        if precision != 0 {
+            write!(f, ".{:0digits$}", nanoseconds / divisor)?;
+            for _ in digits..precision {
+                f.write_str("0")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<DisplaySeconds> for Duration {
+    fn from(value: DisplaySeconds) -> Self {
+        value.duration
+    }
+}
+
+impl SmartDisplay for DisplaySeconds {
+    type Metadata = ();
+
+    fn metadata(&self, f: FormatterOptions) -> Metadata<Self> {
+        let mut width = WidthCounter::default();
+        // Writing to the counter never fails.
+        let _ = self.fmt_unpadded(&mut width, f.precision());
+        Metadata::new(width.0, self, ())
+    }
+
+    fn fmt_with_metadata(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        metadata: Metadata<Self>,
+    ) -> fmt::Result {
+        let precision = f.precision();
+        f.pad_with_width(
+            metadata.unpadded_width(),
+            format_args!(
+                "{}",
+                DisplayFn(|f: &mut dyn fmt::Write| self.fmt_unpadded(f, precision))
+            ),
+        )
+    }
+}
+
+impl fmt::Display for DisplaySeconds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() {
+            let precision = f.precision();
+            return self.fmt_unpadded(f, precision);
+        }
+        SmartDisplay::fmt(self, f)
+    }
+}
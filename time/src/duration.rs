@@ -8,15 +8,19 @@ use core::time::Duration as StdDuration;
 
 use deranged::RangedI32;
 use num_conv::prelude::*;
+use powerfmt::ext::FormatterExt;
+use powerfmt::smart_display::{FormatterOptions, Metadata, SmartDisplay};
 
 use crate::convert::*;
 use crate::error;
 use crate::internal_macros::{
     const_try_opt, expect_opt, impl_add_assign, impl_div_assign, impl_mul_assign, impl_sub_assign,
 };
+use crate::util::{DisplayFn, WidthCounter};
 #[cfg(feature = "std")]
 #[allow(deprecated)]
 use crate::Instant;
+use crate::{Backoff, DisplaySeconds, HumanDuration, Iso8601Duration, Rounding};
 
 /// By explicitly inserting this enum where padding is expected, the compiler is able to better
 /// perform niche value optimization.
@@ -338,6 +342,22 @@ impl Duration {
             self.nanoseconds.get().unsigned_abs(),
         )
     }
+
+    /// Convert the existing `Duration` to a `std::time::Duration`, returning `None` if the value is
+    /// negative. This is the `const` equivalent of the [`TryFrom`] implementation.
+    ///
+    /// ```rust
+    /// # use time::ext::{NumericalDuration, NumericalStdDuration};
+    /// assert_eq!(1.5.seconds().as_std_checked(), Some(1_500.std_milliseconds()));
+    /// assert_eq!(0.seconds().as_std_checked(), Some(0.std_seconds()));
+    /// assert_eq!((-1).nanoseconds().as_std_checked(), None);
+    /// ```
+    pub const fn as_std_checked(self) -> Option<StdDuration> {
+        if self.is_negative() {
+            return None;
+        }
+        Some(self.unsigned_abs())
+    }
     // endregion abs
 
     // region: constructors
@@ -683,6 +703,38 @@ impl Duration {
         ))
     }
 
+    /// Creates a new `Duration` from the specified number of seconds
+    /// represented as `f64`. Returns an error if the value is `NaN` or the
+    /// `Duration` can't be represented.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::try_seconds_f64(0.5), Ok(0.5.seconds()));
+    /// assert_eq!(Duration::try_seconds_f64(-0.5), Ok((-0.5).seconds()));
+    /// assert!(Duration::try_seconds_f64(f64::NAN).is_err());
+    /// assert!(Duration::try_seconds_f64(f64::NEG_INFINITY).is_err());
+    /// assert!(Duration::try_seconds_f64(f64::INFINITY).is_err());
+    /// ```
+    pub fn try_seconds_f64(seconds: f64) -> Result<Self, error::ConversionRange> {
+        Self::checked_seconds_f64(seconds).ok_or(error::ConversionRange)
+    }
+
+    /// Creates a new `Duration` from the specified number of seconds
+    /// represented as `f32`. Returns an error if the value is `NaN` or the
+    /// `Duration` can't be represented.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::try_seconds_f32(0.5), Ok(0.5.seconds()));
+    /// assert_eq!(Duration::try_seconds_f32(-0.5), Ok((-0.5).seconds()));
+    /// assert!(Duration::try_seconds_f32(f32::NAN).is_err());
+    /// assert!(Duration::try_seconds_f32(f32::NEG_INFINITY).is_err());
+    /// assert!(Duration::try_seconds_f32(f32::INFINITY).is_err());
+    /// ```
+    pub fn try_seconds_f32(seconds: f32) -> Result<Self, error::ConversionRange> {
+        Self::checked_seconds_f32(seconds).ok_or(error::ConversionRange)
+    }
+
     /// Create a new `Duration` with the given number of milliseconds.
     ///
     /// ```rust
@@ -751,6 +803,20 @@ impl Duration {
         // Safety: `nanoseconds` is guaranteed to be in range because of the modulus above.
         unsafe { Self::new_unchecked(seconds as _, nanoseconds as _) }
     }
+
+    /// Create a new `Duration` with the given number of nanoseconds, returning `None` if the
+    /// number of seconds does not fit in an `i64`.
+    pub(crate) const fn checked_nanoseconds_i128(nanoseconds: i128) -> Option<Self> {
+        let seconds = nanoseconds / Nanosecond::per(Second) as i128;
+        let nanoseconds = nanoseconds % Nanosecond::per(Second) as i128;
+
+        if seconds > i64::MAX as i128 || seconds < i64::MIN as i128 {
+            return None;
+        }
+
+        // Safety: `nanoseconds` is guaranteed to be in range because of the modulus above.
+        Some(unsafe { Self::new_unchecked(seconds as _, nanoseconds as _) })
+    }
     // endregion constructors
 
     // region: getters
@@ -1164,6 +1230,151 @@ impl Duration {
     }
     // endregion saturating arithmetic
 
+    // region: floating point arithmetic
+    /// Computes `self * rhs`, returning `None` if `rhs` is NaN or if an overflow occurred. The
+    /// result is rounded in the same manner as `self * rhs`.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(5.seconds().checked_mul_f64(1.5), Some(7.5.seconds()));
+    /// assert_eq!(5.seconds().checked_mul_f64(-0.5), Some((-2.5).seconds()));
+    /// assert_eq!(5.seconds().checked_mul_f64(f64::NAN), None);
+    /// assert_eq!(Duration::MAX.checked_mul_f64(2.), None);
+    /// ```
+    pub fn checked_mul_f64(self, rhs: f64) -> Option<Self> {
+        Self::checked_seconds_f64(self.as_seconds_f64() * rhs)
+    }
+
+    /// Computes `self * rhs`, rounding the result to a whole number of nanoseconds as specified.
+    ///
+    /// The product is computed with the precision of an `f64`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is NaN or if the result would overflow.
+    ///
+    /// ```rust
+    /// # use time::{Rounding, ext::NumericalDuration};
+    /// assert_eq!(3.nanoseconds().mul_f64_with(0.5, Rounding::Floor), 1.nanoseconds());
+    /// assert_eq!(3.nanoseconds().mul_f64_with(0.5, Rounding::Ceiling), 2.nanoseconds());
+    /// assert_eq!(3.nanoseconds().mul_f64_with(0.5, Rounding::HalfEven), 2.nanoseconds());
+    /// assert_eq!(
+    ///     (-3).nanoseconds().mul_f64_with(0.5, Rounding::TowardZero),
+    ///     (-1).nanoseconds()
+    /// );
+    /// ```
+    pub fn mul_f64_with(self, rhs: f64, rounding: Rounding) -> Self {
+        Self::from_nanoseconds_f64(
+            self.whole_nanoseconds() as f64 * rhs,
+            rounding,
+            "passed NaN to `time::Duration::mul_f64_with`",
+        )
+    }
+
+    /// Computes `self / rhs`, rounding the result to a whole number of nanoseconds as specified.
+    ///
+    /// The quotient is computed with the precision of an `f64`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the quotient is NaN or if the result would overflow, including when
+    /// dividing a nonzero duration by zero.
+    ///
+    /// ```rust
+    /// # use time::{Rounding, ext::NumericalDuration};
+    /// assert_eq!(10.nanoseconds().div_f64_with(3., Rounding::Floor), 3.nanoseconds());
+    /// assert_eq!(10.nanoseconds().div_f64_with(3., Rounding::Ceiling), 4.nanoseconds());
+    /// assert_eq!(
+    ///     10.nanoseconds().div_f64_with(4., Rounding::HalfAwayFromZero),
+    ///     3.nanoseconds()
+    /// );
+    /// ```
+    pub fn div_f64_with(self, rhs: f64, rounding: Rounding) -> Self {
+        Self::from_nanoseconds_f64(
+            self.whole_nanoseconds() as f64 / rhs,
+            rounding,
+            "passed NaN to `time::Duration::div_f64_with`",
+        )
+    }
+
+    /// Computes the ratio of `self` to `rhs`. This is equivalent to `self / rhs`.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(3.seconds().div_duration_f64(2.seconds()), 1.5);
+    /// assert_eq!(1.seconds().div_duration_f64((-4).seconds()), -0.25);
+    /// assert!(1.seconds().div_duration_f64(0.seconds()).is_infinite());
+    /// ```
+    pub fn div_duration_f64(self, rhs: Self) -> f64 {
+        self.whole_nanoseconds() as f64 / rhs.whole_nanoseconds() as f64
+    }
+
+    /// Create a `Duration` from a number of nanoseconds that may not be whole, rounding it as
+    /// specified.
+    fn from_nanoseconds_f64(nanoseconds: f64, rounding: Rounding, nan_message: &str) -> Self {
+        if nanoseconds.is_nan() {
+            crate::expect_failed(nan_message);
+        }
+        let nanoseconds = rounding.round(nanoseconds);
+        if nanoseconds < Self::MIN.whole_nanoseconds() || nanoseconds > Self::MAX.whole_nanoseconds()
+        {
+            crate::expect_failed("overflow constructing `time::Duration`");
+        }
+        Self::nanoseconds_i128(nanoseconds)
+    }
+    // endregion floating point arithmetic
+
+    /// Obtain a wrapper that displays and parses the duration in a human-readable form, such as
+    /// `2d 3h 45m 12.5s`. See [`HumanDuration`] for details.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(90.minutes().human().to_string(), "1h 30m");
+    /// assert_eq!(1.5.seconds().human().to_string(), "1.5s");
+    /// assert_eq!(250.microseconds().human().to_string(), "250µs");
+    /// ```
+    pub const fn human(self) -> HumanDuration {
+        HumanDuration::new(self)
+    }
+
+    /// Obtain a wrapper that displays the duration as a number of seconds, such as `90.5`. A
+    /// precision specifier rounds to that many decimal places, so `{:.3}` displays the value to the
+    /// nearest millisecond. See [`DisplaySeconds`] for details.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(90.5.seconds().display_seconds().to_string(), "90.5");
+    /// assert_eq!(format!("{:.3}", 1.23456.seconds().display_seconds()), "1.235");
+    /// ```
+    pub const fn display_seconds(self) -> DisplaySeconds {
+        DisplaySeconds::new(self)
+    }
+
+    /// Obtain a wrapper that displays and parses the duration in the duration format of ISO 8601,
+    /// such as `P1DT2H30M`. See [`Iso8601Duration`] for details.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(90.minutes().iso8601().to_string(), "PT1H30M");
+    /// assert_eq!((-1.5).seconds().iso8601().to_string(), "-PT1.5S");
+    /// assert_eq!(0.seconds().iso8601().to_string(), "PT0S");
+    /// ```
+    pub const fn iso8601(self) -> Iso8601Duration {
+        Iso8601Duration::new(self)
+    }
+
+    /// Obtain an infinite iterator of durations that begins with this one and grows exponentially,
+    /// as is commonly used when retrying an operation. See [`Backoff`] for details.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// let delays = 1.seconds().backoff().max(1.minutes());
+    /// assert_eq!(delays.take(8).last(), Some(1.minutes()));
+    /// ```
+    pub const fn backoff(self) -> Backoff {
+        Backoff::new(self)
+    }
+
     /// Runs a closure, returning the duration of time it took to run. The return value of the
     /// closure is provided in the second part of the tuple.
     #[doc(hidden)]
@@ -1183,40 +1394,52 @@ impl Duration {
 }
 
 // region: trait impls
-/// The format returned by this implementation is not stable and must not be relied upon.
-///
-/// By default this produces an exact, full-precision printout of the duration.
-/// For a concise, rounded printout instead, you can use the `.N` format specifier:
-///
-/// ```
-/// # use time::Duration;
-/// #
-/// let duration = Duration::new(123456, 789011223);
-/// println!("{duration:.3}");
-/// ```
-///
-/// For the purposes of this implementation, a day is exactly 24 hours and a minute is exactly 60
-/// seconds.
-impl fmt::Display for Duration {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Duration {
+    /// Write the duration without padding, using the provided precision and alternate flag in place
+    /// of those of a formatter.
+    fn fmt_unpadded(
+        &self,
+        f: &mut dyn fmt::Write,
+        precision: Option<usize>,
+        alternate: bool,
+    ) -> fmt::Result {
         if self.is_negative() {
             f.write_str("-")?;
         }
 
-        if let Some(_precision) = f.precision() {
+        if let Some(precision) = precision {
             // Concise, rounded representation.
 
             if self.is_zero() {
                 // Write a zero value with the requested precision.
-                return (0.).fmt(f).and_then(|_| f.write_str("s"));
+                return write!(f, "{:.precision$}", 0.)
+                    .and_then(|_| f.write_str(if alternate { " seconds" } else { "s" }));
+            }
+
+            // Values that exceed one by less than this are printed as exactly one at the requested
+            // precision, so the singular name of the unit is used for them.
+            let mut rounding_error = 0.5;
+            for _ in 0..precision {
+                if rounding_error == 0.0 {
+                    break;
+                }
+                rounding_error /= 10.0;
             }
 
             /// Format the first item that produces a value greater than 1 and then break.
             macro_rules! item {
-                ($name:literal, $value:expr) => {
+                ($name:literal, $long_name:literal, $value:expr) => {
                     let value = $value;
                     if value >= 1.0 {
-                        return value.fmt(f).and_then(|_| f.write_str($name));
+                        return write!(f, "{value:.precision$}").and_then(|_| {
+                            if !alternate {
+                                f.write_str($name)
+                            } else if value == 1.0 || value - 1.0 < rounding_error {
+                                f.write_str(concat!(" ", $long_name))
+                            } else {
+                                f.write_str(concat!(" ", $long_name, "s"))
+                            }
+                        });
                     }
                 };
             }
@@ -1224,59 +1447,178 @@ impl fmt::Display for Duration {
             // Even if this produces a de-normal float, because we're rounding we don't really care.
             let seconds = self.unsigned_abs().as_secs_f64();
 
-            item!("d", seconds / Second::per(Day) as f64);
-            item!("h", seconds / Second::per(Hour) as f64);
-            item!("m", seconds / Second::per(Minute) as f64);
-            item!("s", seconds);
-            item!("ms", seconds * Millisecond::per(Second) as f64);
-            item!("µs", seconds * Microsecond::per(Second) as f64);
-            item!("ns", seconds * Nanosecond::per(Second) as f64);
+            item!("d", "day", seconds / Second::per(Day) as f64);
+            item!("h", "hour", seconds / Second::per(Hour) as f64);
+            item!("m", "minute", seconds / Second::per(Minute) as f64);
+            item!("s", "second", seconds);
+            item!(
+                "ms",
+                "millisecond",
+                seconds * Millisecond::per(Second) as f64
+            );
+            item!(
+                "µs",
+                "microsecond",
+                seconds * Microsecond::per(Second) as f64
+            );
+            item!("ns", "nanosecond", seconds * Nanosecond::per(Second) as f64);
         } else {
             // Precise, but verbose representation.
 
             if self.is_zero() {
-                return f.write_str("0s");
-            }
-
-            /// Format a single item.
-            macro_rules! item {
-                ($name:literal, $value:expr) => {
-                    match $value {
-                        0 => Ok(()),
-                        value => value.fmt(f).and_then(|_| f.write_str($name)),
-                    }
-                };
+                return f.write_str(if alternate { "0 seconds" } else { "0s" });
             }
 
             let seconds = self.seconds.unsigned_abs();
             let nanoseconds = self.nanoseconds.get().unsigned_abs();
 
-            item!("d", seconds / Second::per(Day).extend::<u64>())?;
-            item!(
-                "h",
-                seconds / Second::per(Hour).extend::<u64>() % Hour::per(Day).extend::<u64>()
-            )?;
-            item!(
-                "m",
-                seconds / Second::per(Minute).extend::<u64>() % Minute::per(Hour).extend::<u64>()
-            )?;
-            item!("s", seconds % Second::per(Minute).extend::<u64>())?;
-            item!("ms", nanoseconds / Nanosecond::per(Millisecond))?;
-            item!(
-                "µs",
-                nanoseconds / Nanosecond::per(Microsecond).extend::<u32>()
-                    % Microsecond::per(Millisecond).extend::<u32>()
-            )?;
-            item!(
-                "ns",
-                nanoseconds % Nanosecond::per(Microsecond).extend::<u32>()
-            )?;
+            let items: [(&str, &str, u64); 7] = [
+                ("d", "day", seconds / Second::per(Day).extend::<u64>()),
+                (
+                    "h",
+                    "hour",
+                    seconds / Second::per(Hour).extend::<u64>() % Hour::per(Day).extend::<u64>(),
+                ),
+                (
+                    "m",
+                    "minute",
+                    seconds / Second::per(Minute).extend::<u64>()
+                        % Minute::per(Hour).extend::<u64>(),
+                ),
+                ("s", "second", seconds % Second::per(Minute).extend::<u64>()),
+                (
+                    "ms",
+                    "millisecond",
+                    (nanoseconds / Nanosecond::per(Millisecond)).extend(),
+                ),
+                (
+                    "µs",
+                    "microsecond",
+                    (nanoseconds / Nanosecond::per(Microsecond).extend::<u32>()
+                        % Microsecond::per(Millisecond).extend::<u32>())
+                    .extend(),
+                ),
+                (
+                    "ns",
+                    "nanosecond",
+                    (nanoseconds % Nanosecond::per(Microsecond).extend::<u32>()).extend(),
+                ),
+            ];
+
+            // Whether a separator must be written before the next item in the alternate form.
+            let mut needs_separator = false;
+
+            for (name, long_name, value) in items {
+                if value == 0 {
+                    continue;
+                }
+
+                if alternate {
+                    if needs_separator {
+                        f.write_str(" ")?;
+                    }
+                    needs_separator = true;
+                    write!(f, "{value}")?;
+                    f.write_str(" ")?;
+                    f.write_str(long_name)?;
+                    if value != 1 {
+                        f.write_str("s")?;
+                    }
+                } else {
+                    write!(f, "{value}")?;
+                    f.write_str(name)?;
+                }
+            }
         }
 
         Ok(())
     }
 }
 
+impl SmartDisplay for Duration {
+    type Metadata = ();
+
+    fn metadata(&self, f: FormatterOptions) -> Metadata<Self> {
+        let mut width = WidthCounter::default();
+        // Writing to the counter never fails.
+        let _ = self.fmt_unpadded(&mut width, f.precision(), f.alternate());
+        Metadata::new(width.0, self, ())
+    }
+
+    fn fmt_with_metadata(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        metadata: Metadata<Self>,
+    ) -> fmt::Result {
+        let (precision, alternate) = (f.precision(), f.alternate());
+        f.pad_with_width(
+            metadata.unpadded_width(),
+            format_args!(
+                "{}",
+                DisplayFn(|f: &mut dyn fmt::Write| self.fmt_unpadded(f, precision, alternate))
+            ),
+        )
+    }
+}
+
+/// The format returned by this implementation is not stable and must not be relied upon.
+///
+/// By default this produces an exact, full-precision printout of the duration: every nonzero unit
+/// down to the nanosecond is written, so no information is lost. For a concise, rounded printout
+/// instead, you can use the `.N` format specifier. The value is then written in the largest unit for
+/// which it is at least one, rounded to `N` decimal places:
+///
+/// ```
+/// # use time::Duration;
+/// #
+/// let duration = Duration::new(123456, 789011223);
+/// assert_eq!(format!("{duration}"), "1d10h17m36s789ms11µs223ns");
+/// assert_eq!(format!("{duration:.3}"), "1.429d");
+/// assert_eq!(format!("{:.3}", Duration::new(1, 500_000_000)), "1.500s");
+/// ```
+///
+/// To instead control the precision of a fixed unit, such as displaying the number of seconds to
+/// the nearest millisecond, use [`Duration::display_seconds`].
+///
+/// The alternate flag (`{:#}`) spells out the name of each unit, which may be combined with the
+/// precision:
+///
+/// ```
+/// # use time::ext::NumericalDuration;
+/// #
+/// let duration = 1.hours() + 2.minutes() + 3_500.milliseconds();
+/// assert_eq!(format!("{duration}"), "1h2m3s500ms");
+/// assert_eq!(
+///     format!("{duration:#}"),
+///     "1 hour 2 minutes 3 seconds 500 milliseconds"
+/// );
+/// assert_eq!(format!("{duration:#.2}"), "1.03 hours");
+/// ```
+///
+/// The width, fill, and alignment apply to the output as a whole:
+///
+/// ```
+/// # use time::ext::NumericalDuration;
+/// #
+/// assert_eq!(format!("[{:>8}]", 90.minutes()), "[   1h30m]");
+/// assert_eq!(format!("[{:<8.2}]", 90.minutes()), "[1.50h   ]");
+/// ```
+///
+/// For the purposes of this implementation, a day is exactly 24 hours and a minute is exactly 60
+/// seconds.
+///
+/// Unlike this implementation, the output of [`Debug`](fmt::Debug) is always the exact number of
+/// seconds and nanoseconds that make up the duration.
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() {
+            let (precision, alternate) = (f.precision(), f.alternate());
+            return self.fmt_unpadded(f, precision, alternate);
+        }
+        SmartDisplay::fmt(self, f)
+    }
+}
+
 impl TryFrom<StdDuration> for Duration {
     type Error = error::ConversionRange;
 
@@ -1295,17 +1637,7 @@ impl TryFrom<Duration> for StdDuration {
     type Error = error::ConversionRange;
 
     fn try_from(duration: Duration) -> Result<Self, error::ConversionRange> {
-        Ok(Self::new(
-            duration
-                .seconds
-                .try_into()
-                .map_err(|_| error::ConversionRange)?,
-            duration
-                .nanoseconds
-                .get()
-                .try_into()
-                .map_err(|_| error::ConversionRange)?,
-        ))
+        duration.as_std_checked().ok_or(error::ConversionRange)
     }
 }
 
@@ -2,7 +2,7 @@
 
 use core::{fmt, hash};
 
-use crate::error;
+use crate::{error, Month};
 
 /// An error type indicating that a component provided to a method was out of range, causing a
 /// failure.
@@ -20,6 +20,10 @@ pub struct ComponentRange {
     /// The minimum and/or maximum value is conditional on the value of other
     /// parameters.
     pub(crate) conditional_message: Option<&'static str>,
+    /// The year and, if relevant, the month on which the minimum and/or maximum value is
+    /// conditional. These are stored together, which limits the growth of the error to eight bytes
+    /// rather than the sixteen needed for separate fields.
+    pub(crate) conditional_date: Option<(i32, Option<Month>)>,
 }
 
 impl ComponentRange {
@@ -33,6 +37,39 @@ impl ComponentRange {
     pub const fn is_conditional(self) -> bool {
         self.conditional_message.is_some()
     }
+
+    /// Obtain the year on which the value's permitted range is conditional, if any.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month};
+    /// let err = Date::from_calendar_date(2021, Month::April, 31).unwrap_err();
+    /// assert_eq!(err.conditional_year(), Some(2021));
+    /// assert_eq!(err.to_string(), "day must be in the range 1..=30 for April 2021");
+    /// ```
+    pub const fn conditional_year(self) -> Option<i32> {
+        match self.conditional_date {
+            Some((year, _)) => Some(year),
+            None => None,
+        }
+    }
+
+    /// Obtain the month on which the value's permitted range is conditional, if any.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month};
+    /// let err = Date::from_calendar_date(2021, Month::April, 31).unwrap_err();
+    /// assert_eq!(err.conditional_month(), Some(Month::April));
+    ///
+    /// let err = Date::from_ordinal_date(2021, 366).unwrap_err();
+    /// assert_eq!(err.conditional_month(), None);
+    /// assert_eq!(err.to_string(), "ordinal must be in the range 1..=365 for 2021");
+    /// ```
+    pub const fn conditional_month(self) -> Option<Month> {
+        match self.conditional_date {
+            Some((_, month)) => month,
+            None => None,
+        }
+    }
 }
 
 impl PartialEq for ComponentRange {
@@ -43,6 +80,7 @@ impl PartialEq for ComponentRange {
         && self.value == other.value
         // Skip the contents of the message when comparing for equality.
         && self.conditional_message.is_some() == other.conditional_message.is_some()
+        && self.conditional_date == other.conditional_date
     }
 }
 
@@ -54,10 +92,13 @@ impl hash::Hash for ComponentRange {
         self.value.hash(state);
         // Skip the contents of the message when comparing for equality.
         self.conditional_message.is_some().hash(state);
+        self.conditional_date.hash(state);
     }
 }
 
 impl fmt::Display for ComponentRange {
+    // The `Debug` output of a month is its name, which is available even with `--cfg time_no_names`.
+    #[allow(clippy::use_debug)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -65,8 +106,12 @@ impl fmt::Display for ComponentRange {
             self.name, self.minimum, self.maximum
         )?;
 
-        if let Some(message) = self.conditional_message {
-            write!(f, " {message}")?;
+        // The year and month, when known, are more useful than the generic message.
+        match (self.conditional_date, self.conditional_message) {
+            (Some((year, Some(month))), _) => write!(f, " for {month:?} {year}")?,
+            (Some((year, None)), _) => write!(f, " for {year}")?,
+            (None, Some(message)) => write!(f, " {message}")?,
+            (None, None) => {}
         }
 
         Ok(())
@@ -0,0 +1,124 @@
+//! Component ranges error
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::slice;
+
+use crate::error::{self, ComponentRange};
+
+/// An error type indicating that one or more components provided to a method were out of range.
+///
+/// Unlike [`ComponentRange`], which describes only the first invalid component, this error
+/// describes every invalid component, in the order they were provided. It is never empty.
+///
+/// ```rust
+/// # use time::PrimitiveDateTime;
+/// let err = PrimitiveDateTime::try_from((2021, 13, 1, 24, 0, 0, 0)).unwrap_err();
+/// assert_eq!(err.len(), 2);
+/// assert_eq!(err.errors()[0].name(), "month");
+/// assert_eq!(err.errors()[1].name(), "hour");
+/// assert_eq!(
+///     err.to_string(),
+///     "month must be in the range 1..=12; hour must be in the range 0..=23"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ComponentRanges {
+    /// The errors for each component that was out of range.
+    errors: Vec<ComponentRange>,
+}
+
+impl ComponentRanges {
+    /// Create an error from the provided errors, returning `None` if there are none.
+    pub(crate) fn from_errors(errors: Vec<ComponentRange>) -> Option<Self> {
+        if errors.is_empty() {
+            None
+        } else {
+            Some(Self { errors })
+        }
+    }
+
+    /// Obtain the errors for each component that was out of range, in the order the components
+    /// were provided.
+    pub fn errors(&self) -> &[ComponentRange] {
+        &self.errors
+    }
+
+    /// Obtain the number of components that were out of range. This is always at least one.
+    #[allow(clippy::len_without_is_empty)] // the error is never empty
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Obtain the error for the first component that was out of range.
+    pub fn first(&self) -> ComponentRange {
+        self.errors[0]
+    }
+
+    /// Obtain an iterator over the errors for each component that was out of range.
+    pub fn iter(&self) -> slice::Iter<'_, ComponentRange> {
+        self.errors.iter()
+    }
+}
+
+impl From<ComponentRange> for ComponentRanges {
+    fn from(err: ComponentRange) -> Self {
+        Self {
+            errors: alloc::vec![err],
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a ComponentRanges {
+    type Item = &'a ComponentRange;
+    type IntoIter = slice::Iter<'a, ComponentRange>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for ComponentRanges {
+    type Item = ComponentRange;
+    type IntoIter = alloc::vec::IntoIter<ComponentRange>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl fmt::Display for ComponentRanges {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, err) in self.errors.iter().enumerate() {
+            if i != 0 {
+                f.write_str("; ")?;
+            }
+            err.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<ComponentRanges> for crate::Error {
+    fn from(original: ComponentRanges) -> Self {
+        Self::ComponentRanges(original)
+    }
+}
+
+impl TryFrom<crate::Error> for ComponentRanges {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::ComponentRanges(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ComponentRanges {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.errors[0])
+    }
+}
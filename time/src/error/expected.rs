@@ -0,0 +1,48 @@
+//! What the parser expected to find when an error occurred.
+
+use core::fmt;
+
+/// What the parser expected to find at the position of a parsing error.
+///
+/// This is obtained via
+/// [`ParseFromDescription::expected`](crate::error::ParseFromDescription::expected) and is
+/// intended for building messages such as "expected the 'month' component at byte 5".
+///
+/// ```rust
+/// # use time::error::Expected;
+/// # use time::format_description::well_known::Rfc3339;
+/// # use time::OffsetDateTime;
+/// let input = "2021-01-02T03:04:05Z!";
+/// let err = OffsetDateTime::parse(input, &Rfc3339).unwrap_err();
+/// assert_eq!(err.expected(), Some(Expected::EndOfInput));
+/// assert_eq!(
+///     format!(
+///         "expected {}, found {:?} at byte {}",
+///         err.expected().unwrap(),
+///         &input[err.position().unwrap()..],
+///         err.position().unwrap(),
+///     ),
+///     r#"expected the end of input, found "!" at byte 20"#,
+/// );
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Expected {
+    /// A literal, such as a separator between components. The bytes of the literal that was
+    /// expected are not recorded.
+    UnspecifiedLiteral,
+    /// The component with the provided name.
+    Component(&'static str),
+    /// The end of the input.
+    EndOfInput,
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnspecifiedLiteral => f.write_str("a literal"),
+            Self::Component(name) => write!(f, "the '{name}' component"),
+            Self::EndOfInput => f.write_str("the end of input"),
+        }
+    }
+}
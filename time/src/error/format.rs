@@ -11,11 +11,15 @@ use crate::error;
 #[derive(Debug)]
 pub enum Format {
     /// The type being formatted does not contain sufficient information to format a component.
+    ///
+    /// Implementations of [`CustomFormattable`](crate::formatting::CustomFormattable) can obtain
+    /// this variant via [`Format::insufficient_type_information`].
     #[non_exhaustive]
     InsufficientTypeInformation,
     /// The component named has a value that cannot be formatted into the requested format.
     ///
-    /// This variant is only returned when using well-known formats.
+    /// This variant is returned when using well-known formats or when a year does not fit in the
+    /// [width](crate::format_description::modifier::YearWidth) requested by a format description.
     InvalidComponent(&'static str),
     /// A component provided was out of range.
     ComponentRange(Box<error::ComponentRange>),
@@ -40,6 +44,15 @@ impl fmt::Display for Format {
     }
 }
 
+impl Format {
+    /// Obtain an error indicating that the type being formatted does not contain sufficient
+    /// information to format a component. This is intended for use by implementations of
+    /// [`CustomFormattable`](crate::formatting::CustomFormattable).
+    pub const fn insufficient_type_information() -> Self {
+        Self::InsufficientTypeInformation
+    }
+}
+
 impl From<error::ComponentRange> for Format {
     fn from(err: error::ComponentRange) -> Self {
         Self::ComponentRange(Box::new(err))
@@ -0,0 +1,44 @@
+//! Invalid duration error
+
+use core::fmt;
+
+/// An error type indicating that a [`FromStr`](core::str::FromStr) call for a
+/// [`HumanDuration`](crate::HumanDuration) or [`Iso8601Duration`](crate::Iso8601Duration) failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidDuration {
+    /// The string was not in the expected format.
+    InvalidSyntax,
+    /// The duration represented by the string is too large to be represented by a
+    /// [`Duration`](crate::Duration).
+    OutOfRange,
+}
+
+impl fmt::Display for InvalidDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax => f.write_str("value was not a valid duration"),
+            Self::OutOfRange => f.write_str("duration was out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidDuration {}
+
+impl From<InvalidDuration> for crate::Error {
+    fn from(err: InvalidDuration) -> Self {
+        Self::InvalidDuration(err)
+    }
+}
+
+impl TryFrom<crate::Error> for InvalidDuration {
+    type Error = crate::error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::InvalidDuration(err) => Ok(err),
+            _ => Err(crate::error::DifferentVariant),
+        }
+    }
+}
@@ -1,7 +1,11 @@
 //! Invalid format description
 
+use alloc::format;
 use alloc::string::String;
 use core::fmt;
+use core::ops::Range;
+
+use time_core::format_description::{closest_component_name, write_expected_suggestion};
 
 use crate::error;
 
@@ -65,6 +69,81 @@ pub enum InvalidFormatDescription {
     },
 }
 
+impl InvalidFormatDescription {
+    /// Obtain the zero-based byte index in the format description at which the error occurred.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// let err = format_description::parse("[year]-[month]-[day").unwrap_err();
+    /// assert_eq!(err.index(), 15);
+    /// ```
+    pub const fn index(&self) -> usize {
+        match self {
+            Self::UnclosedOpeningBracket { index }
+            | Self::InvalidComponentName { index, .. }
+            | Self::InvalidModifier { index, .. }
+            | Self::MissingComponentName { index }
+            | Self::MissingRequiredModifier { index, .. }
+            | Self::Expected { index, .. }
+            | Self::NotSupported { index, .. } => *index,
+        }
+    }
+
+    /// Obtain the range of bytes in the format description that the error applies to. When the
+    /// error is due to something that is missing, the range is empty and begins where the missing
+    /// item was expected.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// let description = "[year]-[mnoth]-[day]";
+    /// let err = format_description::parse(description).unwrap_err();
+    /// assert_eq!(&description[err.span()], "mnoth");
+    /// ```
+    pub fn span(&self) -> Range<usize> {
+        let index = self.index();
+        match self {
+            Self::UnclosedOpeningBracket { .. } => index..index + 1,
+            Self::InvalidComponentName { name, .. } => index..index + name.len(),
+            Self::InvalidModifier { value, .. } => index..index + value.len(),
+            Self::MissingComponentName { .. }
+            | Self::MissingRequiredModifier { .. }
+            | Self::Expected { .. }
+            | Self::NotSupported { .. } => index..index,
+        }
+    }
+
+    /// Obtain a suggestion for how the format description could be fixed, if one is available.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// let err = format_description::parse("[year]-[mnoth]-[day]").unwrap_err();
+    /// assert_eq!(err.suggestion().as_deref(), Some("did you mean `month`?"));
+    ///
+    /// let err = format_description::parse("[year]-[month]-[day").unwrap_err();
+    /// assert_eq!(err.suggestion().as_deref(), Some("add a closing bracket"));
+    /// ```
+    pub fn suggestion(&self) -> Option<String> {
+        match self {
+            Self::UnclosedOpeningBracket { .. } => Some(String::from("add a closing bracket")),
+            Self::InvalidComponentName { name, .. } => {
+                closest_component_name(name).map(|name| format!("did you mean `{name}`?"))
+            }
+            Self::MissingComponentName { .. } => {
+                Some(String::from("add a component name, such as `year`"))
+            }
+            Self::MissingRequiredModifier { name, .. } => {
+                Some(format!("add the `{name}` modifier"))
+            }
+            Self::Expected { what, .. } => {
+                let mut suggestion = String::new();
+                write_expected_suggestion(&mut suggestion, what).ok()?;
+                Some(suggestion)
+            }
+            Self::InvalidModifier { .. } | Self::NotSupported { .. } => None,
+        }
+    }
+}
+
 impl From<InvalidFormatDescription> for crate::Error {
     fn from(original: InvalidFormatDescription) -> Self {
         Self::InvalidFormatDescription(original)
@@ -0,0 +1,45 @@
+//! Invalid local time error
+
+use core::fmt;
+
+/// An error type indicating that a local date-time does not correspond to exactly one instant in a
+/// [`TimeZone`](crate::time_zone::TimeZone).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidLocalTime {
+    /// The local date-time does not occur in the time zone, such as when clocks are turned
+    /// forward.
+    Nonexistent,
+    /// The local date-time occurs more than once in the time zone, such as when clocks are turned
+    /// back.
+    Ambiguous,
+}
+
+impl fmt::Display for InvalidLocalTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nonexistent => f.write_str("local date-time does not exist in the time zone"),
+            Self::Ambiguous => f.write_str("local date-time is ambiguous in the time zone"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidLocalTime {}
+
+impl From<InvalidLocalTime> for crate::Error {
+    fn from(err: InvalidLocalTime) -> Self {
+        Self::InvalidLocalTime(err)
+    }
+}
+
+impl TryFrom<crate::Error> for InvalidLocalTime {
+    type Error = crate::error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::InvalidLocalTime(err) => Ok(err),
+            _ => Err(crate::error::DifferentVariant),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Invalid offset error
+
+use core::fmt;
+
+use crate::error;
+
+/// An error type indicating that a [`FromStr`](core::str::FromStr) call for a
+/// [`UtcOffset`](crate::UtcOffset) failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidOffset {
+    /// The string was not in the expected format.
+    InvalidSyntax,
+    /// A component of the offset was out of range.
+    ComponentRange(error::ComponentRange),
+}
+
+impl fmt::Display for InvalidOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax => f.write_str("value was not a valid UTC offset"),
+            Self::ComponentRange(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidOffset {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidSyntax => None,
+            Self::ComponentRange(err) => Some(err),
+        }
+    }
+}
+
+impl From<error::ComponentRange> for InvalidOffset {
+    fn from(err: error::ComponentRange) -> Self {
+        Self::ComponentRange(err)
+    }
+}
+
+impl From<InvalidOffset> for crate::Error {
+    fn from(err: InvalidOffset) -> Self {
+        Self::InvalidOffset(err)
+    }
+}
+
+impl TryFrom<crate::Error> for InvalidOffset {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::InvalidOffset(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Invalid schedule error
+
+use core::fmt;
+
+/// An error type indicating that a [`FromStr`](core::str::FromStr) call for a
+/// [`Schedule`](crate::schedule::Schedule) failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidSchedule {
+    /// The cron expression did not have exactly five fields.
+    InvalidFieldCount,
+    /// A field of the cron expression was not in the expected format or was out of range.
+    #[non_exhaustive]
+    InvalidField {
+        /// The name of the field.
+        name: &'static str,
+    },
+    /// The cron expression was a nickname, such as `@daily`, that is not recognized.
+    UnknownNickname,
+}
+
+impl fmt::Display for InvalidSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFieldCount => f.write_str("cron expression did not have five fields"),
+            Self::InvalidField { name } => write!(f, "{name} field of cron expression was invalid"),
+            Self::UnknownNickname => f.write_str("cron expression nickname was not recognized"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidSchedule {}
+
+impl From<InvalidSchedule> for crate::Error {
+    fn from(err: InvalidSchedule) -> Self {
+        Self::InvalidSchedule(err)
+    }
+}
+
+impl TryFrom<crate::Error> for InvalidSchedule {
+    type Error = crate::error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::InvalidSchedule(err) => Ok(err),
+            _ => Err(crate::error::DifferentVariant),
+        }
+    }
+}
@@ -1,14 +1,22 @@
 //! Various error types returned by methods in the time crate.
 
 mod component_range;
+#[cfg(feature = "alloc")]
+mod component_ranges;
 mod conversion_range;
 mod different_variant;
+#[cfg(feature = "parsing")]
+mod expected;
 #[cfg(feature = "formatting")]
 mod format;
 #[cfg(feature = "local-offset")]
 mod indeterminate_offset;
+mod invalid_duration;
 #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
 mod invalid_format_description;
+mod invalid_local_time;
+mod invalid_offset;
+mod invalid_schedule;
 mod invalid_variant;
 #[cfg(feature = "parsing")]
 mod parse;
@@ -22,14 +30,22 @@ use core::convert::Infallible;
 use core::fmt;
 
 pub use component_range::ComponentRange;
+#[cfg(feature = "alloc")]
+pub use component_ranges::ComponentRanges;
 pub use conversion_range::ConversionRange;
 pub use different_variant::DifferentVariant;
+#[cfg(feature = "parsing")]
+pub use expected::Expected;
 #[cfg(feature = "formatting")]
 pub use format::Format;
 #[cfg(feature = "local-offset")]
 pub use indeterminate_offset::IndeterminateOffset;
+pub use invalid_duration::InvalidDuration;
 #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
 pub use invalid_format_description::InvalidFormatDescription;
+pub use invalid_local_time::InvalidLocalTime;
+pub use invalid_offset::InvalidOffset;
+pub use invalid_schedule::InvalidSchedule;
 pub use invalid_variant::InvalidVariant;
 #[cfg(feature = "parsing")]
 pub use parse::Parse;
@@ -49,6 +65,9 @@ pub enum Error {
     ConversionRange(ConversionRange),
     #[allow(missing_docs)]
     ComponentRange(ComponentRange),
+    #[cfg(feature = "alloc")]
+    #[allow(missing_docs)]
+    ComponentRanges(ComponentRanges),
     #[cfg(feature = "local-offset")]
     #[allow(missing_docs)]
     IndeterminateOffset(IndeterminateOffset),
@@ -79,6 +98,14 @@ pub enum Error {
     DifferentVariant(DifferentVariant),
     #[allow(missing_docs)]
     InvalidVariant(InvalidVariant),
+    #[allow(missing_docs)]
+    InvalidDuration(InvalidDuration),
+    #[allow(missing_docs)]
+    InvalidLocalTime(InvalidLocalTime),
+    #[allow(missing_docs)]
+    InvalidOffset(InvalidOffset),
+    #[allow(missing_docs)]
+    InvalidSchedule(InvalidSchedule),
 }
 
 impl fmt::Display for Error {
@@ -86,6 +113,8 @@ impl fmt::Display for Error {
         match self {
             Self::ConversionRange(e) => e.fmt(f),
             Self::ComponentRange(e) => e.fmt(f),
+            #[cfg(feature = "alloc")]
+            Self::ComponentRanges(e) => e.fmt(f),
             #[cfg(feature = "local-offset")]
             Self::IndeterminateOffset(e) => e.fmt(f),
             #[cfg(feature = "formatting")]
@@ -101,6 +130,10 @@ impl fmt::Display for Error {
             Self::InvalidFormatDescription(e) => e.fmt(f),
             Self::DifferentVariant(e) => e.fmt(f),
             Self::InvalidVariant(e) => e.fmt(f),
+            Self::InvalidDuration(e) => e.fmt(f),
+            Self::InvalidLocalTime(e) => e.fmt(f),
+            Self::InvalidOffset(e) => e.fmt(f),
+            Self::InvalidSchedule(e) => e.fmt(f),
         }
     }
 }
@@ -111,6 +144,8 @@ impl std::error::Error for Error {
         match self {
             Self::ConversionRange(err) => Some(err),
             Self::ComponentRange(err) => Some(err),
+            #[cfg(feature = "alloc")]
+            Self::ComponentRanges(err) => Some(err),
             #[cfg(feature = "local-offset")]
             Self::IndeterminateOffset(err) => Some(err),
             #[cfg(feature = "formatting")]
@@ -126,6 +161,10 @@ impl std::error::Error for Error {
             Self::InvalidFormatDescription(err) => Some(err),
             Self::DifferentVariant(err) => Some(err),
             Self::InvalidVariant(err) => Some(err),
+            Self::InvalidDuration(err) => Some(err),
+            Self::InvalidLocalTime(err) => Some(err),
+            Self::InvalidOffset(err) => Some(err),
+            Self::InvalidSchedule(err) => Some(err),
         }
     }
 }
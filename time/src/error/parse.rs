@@ -3,7 +3,7 @@
 use core::convert::Infallible;
 use core::fmt;
 
-use crate::error::{self, ParseFromDescription, TryFromParsed};
+use crate::error::{self, Expected, ParseFromDescription, TryFromParsed};
 
 /// An error that occurred at some stage of parsing.
 #[non_exhaustive]
@@ -25,6 +25,74 @@ pub enum Parse {
     },
 }
 
+impl Parse {
+    /// Obtain the byte offset in the input at which the error occurred. This is only present if
+    /// the error occurred while parsing the input, rather than while constructing the resulting
+    /// value.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time::OffsetDateTime;
+    /// let input = "2021-01-02T03:04:5Z";
+    /// let err = OffsetDateTime::parse(input, &Rfc3339).unwrap_err();
+    /// assert_eq!(err.position(), Some(17));
+    /// assert_eq!(err.component_name(), Some("second"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn position(&self) -> Option<usize> {
+        match self {
+            Self::ParseFromDescription(err) => err.position(),
+            Self::TryFromParsed(_) => None,
+            #[allow(deprecated)]
+            Self::UnexpectedTrailingCharacters { never } => match *never {},
+        }
+    }
+
+    /// Obtain the name of the component that could not be parsed or was out of range, if any.
+    pub const fn component_name(&self) -> Option<&'static str> {
+        match self {
+            Self::ParseFromDescription(err) => err.component_name(),
+            Self::TryFromParsed(TryFromParsed::ComponentRange(err)) => Some(err.name()),
+            Self::TryFromParsed(TryFromParsed::WeekdayMismatch { .. }) => Some("weekday"),
+            Self::TryFromParsed(_) => None,
+            #[allow(deprecated)]
+            Self::UnexpectedTrailingCharacters { never } => match *never {},
+        }
+    }
+
+    /// Obtain a description of what the parser expected to find at the [position of the
+    /// error](Self::position). This is only present if the error occurred while parsing the input,
+    /// rather than while constructing the resulting value.
+    ///
+    /// ```rust
+    /// # use time::error::Expected;
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time::OffsetDateTime;
+    /// let err = OffsetDateTime::parse("2021-01-02T03:04:5Z", &Rfc3339).unwrap_err();
+    /// assert_eq!(err.expected(), Some(Expected::Component("second")));
+    ///
+    /// let err = OffsetDateTime::parse("2021-02-30T03:04:05Z", &Rfc3339).unwrap_err();
+    /// assert_eq!(err.expected(), None);
+    /// ```
+    pub const fn expected(&self) -> Option<Expected> {
+        match self {
+            Self::ParseFromDescription(err) => err.expected(),
+            Self::TryFromParsed(_) => None,
+            #[allow(deprecated)]
+            Self::UnexpectedTrailingCharacters { never } => match *never {},
+        }
+    }
+
+    /// Move the position of the error later by the provided number of bytes. This is used when
+    /// the error occurred in a suffix of the input.
+    pub(crate) const fn offset_by(self, offset: usize) -> Self {
+        match self {
+            Self::ParseFromDescription(err) => Self::ParseFromDescription(err.offset_by(offset)),
+            err => err,
+        }
+    }
+}
+
 impl fmt::Display for Parse {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
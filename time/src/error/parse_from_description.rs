@@ -2,32 +2,204 @@
 
 use core::fmt;
 
-use crate::error;
+use crate::error::{self, Expected};
 
 /// An error that occurred while parsing the input into a [`Parsed`](crate::parsing::Parsed) struct.
+///
+/// Errors returned by this crate record the byte offset in the input at which they occurred, which
+/// is available via [`ParseFromDescription::position`]. The offset is relative to the start of the
+/// input that was passed to the method returning the error.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ParseFromDescription {
     /// A string literal was not what was expected.
     #[non_exhaustive]
-    InvalidLiteral,
+    InvalidLiteral {
+        /// The byte offset at which the literal was expected.
+        position: usize,
+    },
     /// A dynamic component was not valid.
+    #[deprecated(
+        since = "0.3.38",
+        note = "no longer output. moved to the `InvalidComponentAt` variant, which also records \
+                the position"
+    )]
     InvalidComponent(&'static str),
     /// The input was expected to have ended, but there are characters that remain.
     #[non_exhaustive]
-    UnexpectedTrailingCharacters,
+    UnexpectedTrailingCharacters {
+        /// The byte offset of the first character that remains.
+        position: usize,
+    },
+    /// The input contains a year with more digits than are supported without the `large-dates`
+    /// feature. This is only returned when the year has an explicit sign and the
+    /// [`Extended`](crate::format_description::modifier::YearRange::Extended) range, as the year
+    /// would otherwise be silently truncated.
+    ///
+    #[cfg_attr(not(feature = "large-dates"), doc = "```rust")]
+    #[cfg_attr(feature = "large-dates", doc = "```rust,ignore")]
+    /// # use time::error::ParseFromDescription;
+    /// # use time::format_description;
+    /// # use time::parsing::Parsed;
+    /// // Assuming `large-dates` feature is disabled.
+    /// let format =
+    ///     format_description::parse("[year range:extended sign:mandatory]-[month]-[day]")?;
+    /// let err = Parsed::new()
+    ///     .parse_items(b"+10000-01-01", &format)
+    ///     .unwrap_err();
+    /// assert!(matches!(
+    ///     err,
+    ///     ParseFromDescription::LargeDatesNotEnabled { .. }
+    /// ));
+    /// assert_eq!(err.position(), Some(0));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[non_exhaustive]
+    LargeDatesNotEnabled {
+        /// The byte offset at which the year begins.
+        position: usize,
+    },
+    /// The input is longer than permitted by the
+    /// [`ParserOptions`](crate::parsing::ParserOptions) in use.
+    #[non_exhaustive]
+    InputTooLong {
+        /// The byte offset immediately following the longest permitted input.
+        position: usize,
+    },
+    /// A dynamic component was not valid.
+    #[non_exhaustive]
+    InvalidComponentAt {
+        /// The name of the component.
+        name: &'static str,
+        /// The byte offset at which the component was expected.
+        position: usize,
+    },
+}
+
+impl ParseFromDescription {
+    /// Obtain the byte offset in the input at which the error occurred. This is only absent for
+    /// the deprecated [`InvalidComponent`](Self::InvalidComponent) variant, which is never returned
+    /// by this crate.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time::parsing::Parsed;
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// let err = Parsed::new()
+    ///     .parse_items(b"2021/01/01", &format)
+    ///     .unwrap_err();
+    /// assert_eq!(err.position(), Some(4));
+    ///
+    /// let err = Parsed::new()
+    ///     .parse_items(b"2021-13-01", &format)
+    ///     .unwrap_err();
+    /// assert_eq!(err.position(), Some(5));
+    /// assert_eq!(err.component_name(), Some("month"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn position(&self) -> Option<usize> {
+        match *self {
+            Self::InvalidLiteral { position }
+            | Self::InvalidComponentAt { position, .. }
+            | Self::UnexpectedTrailingCharacters { position }
+            | Self::LargeDatesNotEnabled { position }
+            | Self::InputTooLong { position } => Some(position),
+            #[allow(deprecated)]
+            Self::InvalidComponent(_) => None,
+        }
+    }
+
+    /// Obtain the name of the component that could not be parsed, if any.
+    pub const fn component_name(&self) -> Option<&'static str> {
+        match *self {
+            #[allow(deprecated)]
+            Self::InvalidComponent(name) | Self::InvalidComponentAt { name, .. } => Some(name),
+            Self::InvalidLiteral { .. }
+            | Self::UnexpectedTrailingCharacters { .. }
+            | Self::LargeDatesNotEnabled { .. }
+            | Self::InputTooLong { .. } => None,
+        }
+    }
+
+    /// Obtain a description of what the parser expected to find at the [position of the
+    /// error](Self::position), if any.
+    ///
+    /// ```rust
+    /// # use time::error::Expected;
+    /// # use time::format_description;
+    /// # use time::parsing::Parsed;
+    /// let format = format_description::parse("[year]-[month]-[day]")?;
+    /// let err = Parsed::new()
+    ///     .parse_items(b"2021-13-01", &format)
+    ///     .unwrap_err();
+    /// assert_eq!(err.expected(), Some(Expected::Component("month")));
+    ///
+    /// let err = Parsed::new()
+    ///     .parse_items(b"2021/01/01", &format)
+    ///     .unwrap_err();
+    /// assert_eq!(err.expected(), Some(Expected::UnspecifiedLiteral));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn expected(&self) -> Option<Expected> {
+        match *self {
+            Self::InvalidLiteral { .. } => Some(Expected::UnspecifiedLiteral),
+            #[allow(deprecated)]
+            Self::InvalidComponent(name) | Self::InvalidComponentAt { name, .. } => {
+                Some(Expected::Component(name))
+            }
+            Self::UnexpectedTrailingCharacters { .. } | Self::InputTooLong { .. } => {
+                Some(Expected::EndOfInput)
+            }
+            // The year was found, but cannot be represented.
+            Self::LargeDatesNotEnabled { .. } => None,
+        }
+    }
+
+    /// Move the position of the error later by the provided number of bytes. This is used when
+    /// the error occurred in a suffix of the input.
+    pub(crate) const fn offset_by(self, offset: usize) -> Self {
+        match self {
+            Self::InvalidLiteral { position } => Self::InvalidLiteral {
+                position: position + offset,
+            },
+            #[allow(deprecated)]
+            Self::InvalidComponent(name) => Self::InvalidComponent(name),
+            Self::InvalidComponentAt { name, position } => Self::InvalidComponentAt {
+                name,
+                position: position + offset,
+            },
+            Self::UnexpectedTrailingCharacters { position } => {
+                Self::UnexpectedTrailingCharacters {
+                    position: position + offset,
+                }
+            }
+            Self::LargeDatesNotEnabled { position } => Self::LargeDatesNotEnabled {
+                position: position + offset,
+            },
+            Self::InputTooLong { position } => Self::InputTooLong {
+                position: position + offset,
+            },
+        }
+    }
 }
 
 impl fmt::Display for ParseFromDescription {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::InvalidLiteral => f.write_str("a character literal was not valid"),
-            Self::InvalidComponent(name) => {
+            Self::InvalidLiteral { .. } => f.write_str("a character literal was not valid"),
+            #[allow(deprecated)]
+            Self::InvalidComponent(name) | Self::InvalidComponentAt { name, .. } => {
                 write!(f, "the '{name}' component could not be parsed")
             }
-            Self::UnexpectedTrailingCharacters => {
+            Self::UnexpectedTrailingCharacters { .. } => {
                 f.write_str("unexpected trailing characters; the end of input was expected")
             }
+            Self::LargeDatesNotEnabled { .. } => f.write_str(
+                "the year has more digits than are supported without the `large-dates` feature",
+            ),
+            Self::InputTooLong { .. } => {
+                f.write_str("the input is longer than the maximum permitted length")
+            }
         }
     }
 }
@@ -2,7 +2,7 @@
 
 use core::fmt;
 
-use crate::error;
+use crate::{error, Weekday};
 
 /// An error that occurred when converting a [`Parsed`](crate::parsing::Parsed) to another type.
 #[non_exhaustive]
@@ -13,15 +13,30 @@ pub enum TryFromParsed {
     InsufficientInformation,
     /// Some component contained an invalid value for the type.
     ComponentRange(error::ComponentRange),
+    /// The parsed weekday did not match the date determined by the other components. This is only
+    /// returned when [`WeekdayMode::Validate`](crate::parsing::WeekdayMode::Validate) is used.
+    #[non_exhaustive]
+    WeekdayMismatch {
+        /// The weekday that was parsed.
+        parsed: Weekday,
+        /// The weekday of the date determined by the other components.
+        actual: Weekday,
+    },
 }
 
 impl fmt::Display for TryFromParsed {
+    // The `Debug` output of a weekday is its name, which is available even with `--cfg time_no_names`.
+    #[allow(clippy::use_debug)]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::InsufficientInformation => f.write_str(
                 "the `Parsed` struct did not include enough information to construct the type",
             ),
             Self::ComponentRange(err) => err.fmt(f),
+            Self::WeekdayMismatch { parsed, actual } => write!(
+                f,
+                "the parsed weekday ({parsed:?}) does not match the date, which is a {actual:?}"
+            ),
         }
     }
 }
@@ -47,7 +62,7 @@ impl TryFrom<TryFromParsed> for error::ComponentRange {
 impl std::error::Error for TryFromParsed {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Self::InsufficientInformation => None,
+            Self::InsufficientInformation | Self::WeekdayMismatch { .. } => None,
             Self::ComponentRange(err) => Some(err),
         }
     }
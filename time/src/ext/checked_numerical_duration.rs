@@ -0,0 +1,164 @@
+use crate::convert::*;
+use crate::Duration;
+
+/// Sealed trait to prevent downstream implementations.
+mod sealed {
+    /// A trait that cannot be implemented by downstream users.
+    pub trait Sealed {}
+    impl Sealed for u64 {}
+    impl Sealed for f64 {}
+}
+
+/// Create [`Duration`]s from unsigned integers and floating point values, returning `None` if the
+/// value is out of range.
+///
+/// This is useful when the value originates from a source that uses unsigned integers, such as a
+/// configuration file or [`std::time::Duration::as_secs`], as it avoids a separate conversion to
+/// `i64`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use time::{Duration, ext::CheckedNumericalDuration};
+/// assert_eq!(5.checked_nanoseconds(), Some(Duration::nanoseconds(5)));
+/// assert_eq!(5.checked_microseconds(), Some(Duration::microseconds(5)));
+/// assert_eq!(5.checked_milliseconds(), Some(Duration::milliseconds(5)));
+/// assert_eq!(5.checked_seconds(), Some(Duration::seconds(5)));
+/// assert_eq!(5.checked_minutes(), Some(Duration::minutes(5)));
+/// assert_eq!(5.checked_hours(), Some(Duration::hours(5)));
+/// assert_eq!(5.checked_days(), Some(Duration::days(5)));
+/// assert_eq!(5.checked_weeks(), Some(Duration::weeks(5)));
+/// ```
+///
+/// Values that cannot be represented return `None`.
+///
+/// ```rust
+/// # use time::ext::CheckedNumericalDuration;
+/// assert_eq!(u64::MAX.checked_seconds(), None);
+/// assert_eq!((u64::MAX / 2).checked_weeks(), None);
+/// ```
+///
+/// Floating point values are truncated toward zero to a whole number of nanoseconds, as with
+/// [`NumericalDuration`](crate::ext::NumericalDuration). Rather than saturating, `NaN`, infinite,
+/// and out of range values return `None`.
+///
+/// ```rust
+/// # use time::{Duration, ext::CheckedNumericalDuration};
+/// assert_eq!(2.5.checked_minutes(), Some(Duration::seconds(150)));
+/// assert_eq!((-1.9).checked_nanoseconds(), Some(Duration::nanoseconds(-1)));
+/// assert_eq!(f64::NAN.checked_seconds(), None);
+/// assert_eq!(f64::INFINITY.checked_seconds(), None);
+/// assert_eq!(1e20.checked_seconds(), None);
+/// ```
+pub trait CheckedNumericalDuration: sealed::Sealed {
+    /// Create a [`Duration`] from the number of nanoseconds.
+    fn checked_nanoseconds(self) -> Option<Duration>;
+    /// Create a [`Duration`] from the number of microseconds.
+    fn checked_microseconds(self) -> Option<Duration>;
+    /// Create a [`Duration`] from the number of milliseconds.
+    fn checked_milliseconds(self) -> Option<Duration>;
+    /// Create a [`Duration`] from the number of seconds.
+    fn checked_seconds(self) -> Option<Duration>;
+    /// Create a [`Duration`] from the number of minutes.
+    fn checked_minutes(self) -> Option<Duration>;
+    /// Create a [`Duration`] from the number of hours.
+    fn checked_hours(self) -> Option<Duration>;
+    /// Create a [`Duration`] from the number of days.
+    fn checked_days(self) -> Option<Duration>;
+    /// Create a [`Duration`] from the number of weeks.
+    fn checked_weeks(self) -> Option<Duration>;
+}
+
+impl CheckedNumericalDuration for u64 {
+    fn checked_nanoseconds(self) -> Option<Duration> {
+        Some(Duration::nanoseconds(self.try_into().ok()?))
+    }
+
+    fn checked_microseconds(self) -> Option<Duration> {
+        Some(Duration::microseconds(self.try_into().ok()?))
+    }
+
+    fn checked_milliseconds(self) -> Option<Duration> {
+        Some(Duration::milliseconds(self.try_into().ok()?))
+    }
+
+    fn checked_seconds(self) -> Option<Duration> {
+        Some(Duration::seconds(self.try_into().ok()?))
+    }
+
+    fn checked_minutes(self) -> Option<Duration> {
+        Some(Duration::seconds(
+            self.checked_mul(Second::per(Minute).into())?
+                .try_into()
+                .ok()?,
+        ))
+    }
+
+    fn checked_hours(self) -> Option<Duration> {
+        Some(Duration::seconds(
+            self.checked_mul(Second::per(Hour).into())?
+                .try_into()
+                .ok()?,
+        ))
+    }
+
+    fn checked_days(self) -> Option<Duration> {
+        Some(Duration::seconds(
+            self.checked_mul(Second::per(Day).into())?.try_into().ok()?,
+        ))
+    }
+
+    fn checked_weeks(self) -> Option<Duration> {
+        Some(Duration::seconds(
+            self.checked_mul(Second::per(Week).into())?
+                .try_into()
+                .ok()?,
+        ))
+    }
+}
+
+impl CheckedNumericalDuration for f64 {
+    fn checked_nanoseconds(self) -> Option<Duration> {
+        checked_from_f64(self, 1)
+    }
+
+    fn checked_microseconds(self) -> Option<Duration> {
+        checked_from_f64(self, Nanosecond::per(Microsecond).into())
+    }
+
+    fn checked_milliseconds(self) -> Option<Duration> {
+        checked_from_f64(self, Nanosecond::per(Millisecond).into())
+    }
+
+    fn checked_seconds(self) -> Option<Duration> {
+        checked_from_f64(self, Nanosecond::per(Second).into())
+    }
+
+    fn checked_minutes(self) -> Option<Duration> {
+        checked_from_f64(self, Nanosecond::per(Minute))
+    }
+
+    fn checked_hours(self) -> Option<Duration> {
+        checked_from_f64(self, Nanosecond::per(Hour))
+    }
+
+    fn checked_days(self) -> Option<Duration> {
+        checked_from_f64(self, Nanosecond::per(Day))
+    }
+
+    fn checked_weeks(self) -> Option<Duration> {
+        checked_from_f64(self, Nanosecond::per(Week))
+    }
+}
+
+/// Create a [`Duration`] from a floating point number of units, each of which is the provided
+/// number of nanoseconds. The result is truncated toward zero.
+fn checked_from_f64(value: f64, nanoseconds_per_unit: u64) -> Option<Duration> {
+    let nanoseconds = value * nanoseconds_per_unit as f64;
+    if !nanoseconds.is_finite() {
+        return None;
+    }
+    // Finite values outside the range of `i128` saturate, which is then caught by the range check
+    // when constructing the `Duration`.
+    Duration::checked_nanoseconds_i128(nanoseconds as i128)
+}
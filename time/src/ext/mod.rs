@@ -1,13 +1,17 @@
 //! Extension traits.
 
+mod checked_numerical_duration;
 mod digit_count;
 #[cfg(feature = "std")]
 mod instant;
 mod numerical_duration;
 mod numerical_std_duration;
+mod numerical_std_duration_shim;
 
+pub use self::checked_numerical_duration::CheckedNumericalDuration;
 pub(crate) use self::digit_count::DigitCount;
 #[cfg(feature = "std")]
 pub use self::instant::InstantExt;
 pub use self::numerical_duration::NumericalDuration;
 pub use self::numerical_std_duration::NumericalStdDuration;
+pub use self::numerical_std_duration_shim::NumericalStdDurationShim;
@@ -49,9 +49,21 @@ mod sealed {
 /// assert_eq!(2.seconds() - 500.milliseconds(), 1_500.milliseconds());
 /// ```
 ///
-/// When called on floating point values, any remainder of the floating point value will be
-/// truncated. Keep in mind that floating point numbers are inherently imprecise and have
+/// When called on floating point values, the result is truncated toward zero to a whole number of
+/// nanoseconds. Keep in mind that floating point numbers are inherently imprecise and have
 /// limited capacity.
+///
+/// ```rust
+/// # use time::{Duration, ext::NumericalDuration};
+/// assert_eq!(2.5.minutes(), Duration::seconds(150));
+/// assert_eq!(1.9.nanoseconds(), Duration::nanoseconds(1));
+/// assert_eq!((-1.9).nanoseconds(), Duration::nanoseconds(-1));
+/// ```
+///
+/// A floating point value whose number of nanoseconds does not fit in an `i64` (roughly 292 years)
+/// saturates, and `NaN` results in a zero duration. Use
+/// [`CheckedNumericalDuration`](crate::ext::CheckedNumericalDuration) to detect these cases
+/// instead.
 pub trait NumericalDuration: sealed::Sealed {
     /// Create a [`Duration`] from the number of nanoseconds.
     fn nanoseconds(self) -> Duration;
@@ -45,9 +45,18 @@ mod sealed {
 /// );
 /// ```
 ///
-/// When called on floating point values, any remainder of the floating point value will be
-/// truncated. Keep in mind that floating point numbers are inherently imprecise and have
-/// limited capacity.
+/// When called on floating point values, the result is truncated to a whole number of nanoseconds.
+/// Keep in mind that floating point numbers are inherently imprecise and have limited capacity.
+///
+/// ```rust
+/// # use time::ext::NumericalStdDuration;
+/// # use core::time::Duration;
+/// assert_eq!(2.5.std_minutes(), Duration::from_secs(150));
+/// assert_eq!(1.9.std_nanoseconds(), Duration::from_nanos(1));
+/// ```
+///
+/// A floating point value whose number of nanoseconds does not fit in a `u64` (roughly 584 years)
+/// saturates. Negative values and `NaN` cause a panic.
 pub trait NumericalStdDuration: sealed::Sealed {
     /// Create a [`std::time::Duration`] from the number of nanoseconds.
     fn std_nanoseconds(self) -> StdDuration;
@@ -128,7 +137,7 @@ impl NumericalStdDuration for u64 {
 impl NumericalStdDuration for f64 {
     /// # Panics
     ///
-    /// This will panic if self is negative.
+    /// This will panic if self is negative or `NaN`.
     fn std_nanoseconds(self) -> StdDuration {
         assert!(self >= 0.);
         StdDuration::from_nanos(self as _)
@@ -136,7 +145,7 @@ impl NumericalStdDuration for f64 {
 
     /// # Panics
     ///
-    /// This will panic if self is negative.
+    /// This will panic if self is negative or `NaN`.
     fn std_microseconds(self) -> StdDuration {
         assert!(self >= 0.);
         StdDuration::from_nanos((self * Nanosecond::per(Microsecond) as Self) as _)
@@ -144,7 +153,7 @@ impl NumericalStdDuration for f64 {
 
     /// # Panics
     ///
-    /// This will panic if self is negative.
+    /// This will panic if self is negative or `NaN`.
     fn std_milliseconds(self) -> StdDuration {
         assert!(self >= 0.);
         StdDuration::from_nanos((self * Nanosecond::per(Millisecond) as Self) as _)
@@ -152,7 +161,7 @@ impl NumericalStdDuration for f64 {
 
     /// # Panics
     ///
-    /// This will panic if self is negative.
+    /// This will panic if self is negative or `NaN`.
     fn std_seconds(self) -> StdDuration {
         assert!(self >= 0.);
         StdDuration::from_nanos((self * Nanosecond::per(Second) as Self) as _)
@@ -160,7 +169,7 @@ impl NumericalStdDuration for f64 {
 
     /// # Panics
     ///
-    /// This will panic if self is negative.
+    /// This will panic if self is negative or `NaN`.
     fn std_minutes(self) -> StdDuration {
         assert!(self >= 0.);
         StdDuration::from_nanos((self * Nanosecond::per(Minute) as Self) as _)
@@ -168,7 +177,7 @@ impl NumericalStdDuration for f64 {
 
     /// # Panics
     ///
-    /// This will panic if self is negative.
+    /// This will panic if self is negative or `NaN`.
     fn std_hours(self) -> StdDuration {
         assert!(self >= 0.);
         StdDuration::from_nanos((self * Nanosecond::per(Hour) as Self) as _)
@@ -176,7 +185,7 @@ impl NumericalStdDuration for f64 {
 
     /// # Panics
     ///
-    /// This will panic if self is negative.
+    /// This will panic if self is negative or `NaN`.
     fn std_days(self) -> StdDuration {
         assert!(self >= 0.);
         StdDuration::from_nanos((self * Nanosecond::per(Day) as Self) as _)
@@ -184,7 +193,7 @@ impl NumericalStdDuration for f64 {
 
     /// # Panics
     ///
-    /// This will panic if self is negative.
+    /// This will panic if self is negative or `NaN`.
     fn std_weeks(self) -> StdDuration {
         assert!(self >= 0.);
         StdDuration::from_nanos((self * Nanosecond::per(Week) as Self) as _)
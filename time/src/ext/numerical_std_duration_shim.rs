@@ -0,0 +1,81 @@
+use core::time::Duration as StdDuration;
+
+use crate::convert::*;
+use crate::Duration;
+
+/// Sealed trait to prevent downstream implementations.
+mod sealed {
+    /// A trait that cannot be implemented by downstream users.
+    pub trait Sealed {}
+    impl Sealed for u128 {}
+}
+
+/// Create [`Duration`]s and [`std::time::Duration`]s from a `u128` number of nanoseconds.
+///
+/// [`std::time::Duration::as_nanos`] returns a `u128`, but there is no corresponding constructor
+/// in the standard library. This trait fills that gap, allowing a value to round-trip without
+/// first being split into seconds and nanoseconds.
+///
+/// # Examples
+///
+/// ```rust
+/// # use core::time::Duration as StdDuration;
+/// # use time::{Duration, ext::NumericalStdDurationShim};
+/// let std = StdDuration::new(5, 500);
+/// assert_eq!(std.as_nanos().std_nanoseconds_u128(), std);
+/// assert_eq!(5_000_000_500.nanoseconds_u128(), Duration::new(5, 500));
+/// ```
+///
+/// Values that cannot be represented return `None` from the checked variants.
+///
+/// ```rust
+/// # use time::ext::NumericalStdDurationShim;
+/// assert_eq!(u128::MAX.checked_std_nanoseconds_u128(), None);
+/// assert_eq!(u128::MAX.checked_nanoseconds_u128(), None);
+/// ```
+pub trait NumericalStdDurationShim: sealed::Sealed {
+    /// Create a [`std::time::Duration`] from the number of nanoseconds.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the number of seconds does not fit in a `u64`.
+    fn std_nanoseconds_u128(self) -> StdDuration;
+    /// Create a [`std::time::Duration`] from the number of nanoseconds, returning `None` if the
+    /// number of seconds does not fit in a `u64`.
+    fn checked_std_nanoseconds_u128(self) -> Option<StdDuration>;
+    /// Create a [`Duration`] from the number of nanoseconds.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the number of seconds does not fit in an `i64`.
+    fn nanoseconds_u128(self) -> Duration;
+    /// Create a [`Duration`] from the number of nanoseconds, returning `None` if the number of
+    /// seconds does not fit in an `i64`.
+    fn checked_nanoseconds_u128(self) -> Option<Duration>;
+}
+
+impl NumericalStdDurationShim for u128 {
+    fn std_nanoseconds_u128(self) -> StdDuration {
+        self.checked_std_nanoseconds_u128()
+            .expect("overflow constructing `core::time::Duration`")
+    }
+
+    fn checked_std_nanoseconds_u128(self) -> Option<StdDuration> {
+        let seconds = self / Nanosecond::per(Second) as Self;
+        let nanoseconds = self % Nanosecond::per(Second) as Self;
+        Some(StdDuration::new(
+            seconds.try_into().ok()?,
+            // Guaranteed to be in range because of the modulus above.
+            nanoseconds as _,
+        ))
+    }
+
+    fn nanoseconds_u128(self) -> Duration {
+        self.checked_nanoseconds_u128()
+            .expect("overflow constructing `time::Duration`")
+    }
+
+    fn checked_nanoseconds_u128(self) -> Option<Duration> {
+        Duration::checked_nanoseconds_i128(self.try_into().ok()?)
+    }
+}
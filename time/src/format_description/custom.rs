@@ -0,0 +1,75 @@
+//! A format description defined outside of this crate.
+
+/// A format description whose formatting and parsing are implemented outside of this crate.
+///
+/// [`Formattable`](crate::formatting::Formattable) and [`Parsable`](crate::parsing::Parsable) are
+/// sealed, so other crates cannot implement them directly. Instead, implement
+/// [`CustomFormattable`](crate::formatting::CustomFormattable) and/or
+/// [`CustomParsable`](crate::parsing::CustomParsable) for a type, then wrap it in `Custom` to use
+/// it anywhere a format description is accepted.
+///
+#[cfg_attr(
+    all(feature = "formatting", feature = "parsing", feature = "macros"),
+    doc = "```rust"
+)]
+#[cfg_attr(
+    not(all(feature = "formatting", feature = "parsing", feature = "macros")),
+    doc = "```rust,ignore"
+)]
+/// # use std::io;
+/// # use time::format_description::{BorrowedFormatItem, Custom};
+/// # use time::formatting::CustomFormattable;
+/// # use time::parsing::{CustomParsable, Parsed};
+/// # use time::macros::{datetime, format_description};
+/// # use time::{error, Date, OffsetDateTime, Time, UtcOffset};
+/// /// A date-time with an offset, enclosed in square brackets.
+/// struct Bracketed;
+///
+/// const DESCRIPTION: &[BorrowedFormatItem<'_>] = format_description!(
+///     "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour sign:mandatory]:[offset_minute]"
+/// );
+///
+/// impl CustomFormattable for Bracketed {
+///     fn format_into(
+///         &self,
+///         output: &mut dyn io::Write,
+///         date: Option<Date>,
+///         time: Option<Time>,
+///         offset: Option<UtcOffset>,
+///     ) -> Result<usize, error::Format> {
+///         let (Some(date), Some(time), Some(offset)) = (date, time, offset) else {
+///             return Err(error::Format::insufficient_type_information());
+///         };
+///         let formatted = OffsetDateTime::new_in_offset(date, time, offset).format(DESCRIPTION)?;
+///         output.write_all(b"[")?;
+///         output.write_all(formatted.as_bytes())?;
+///         output.write_all(b"]")?;
+///         Ok(formatted.len() + 2)
+///     }
+/// }
+///
+/// impl CustomParsable for Bracketed {
+///     fn parse_into<'a>(
+///         &self,
+///         input: &'a [u8],
+///         parsed: &mut Parsed,
+///     ) -> Result<&'a [u8], error::Parse> {
+///         let input = Parsed::parse_literal(input, b"[")?;
+///         let input = parsed.parse_items(input, DESCRIPTION)?;
+///         Ok(Parsed::parse_literal(input, b"]")?)
+///     }
+/// }
+///
+/// let datetime = datetime!(2021-01-02 03:04:05 +06:07);
+/// assert_eq!(
+///     datetime.format(&Custom(Bracketed))?,
+///     "[2021-01-02 03:04:05 +06:07]"
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("[2021-01-02 03:04:05 +06:07]", &Custom(Bracketed))?,
+///     datetime
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Custom<T>(pub T);
@@ -0,0 +1,614 @@
+//! Introspection of format descriptions.
+
+use core::ops::Deref;
+
+use crate::format_description::modifier::{
+    MonthRepr, Padding, SubsecondDigits, UnixTimestampPrecision, WeekNumberRepr, WeekdayRepr,
+    YearRange, YearRepr, YearWidth,
+};
+use crate::format_description::well_known::iso8601::{
+    Config, DateKind, EncodedConfig, TimePrecision,
+};
+#[cfg(not(time_no_names))]
+use crate::format_description::well_known::{HttpDate, Rfc2822};
+use crate::format_description::well_known::{
+    Iso8601, Iso8601Runtime, Rfc3339, Rfc3339Lenient, Rfc9557,
+};
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
+use crate::format_description::{BorrowedFormatItem, Component};
+use crate::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset};
+
+/// A format description that can be inspected without formatting or parsing a value.
+///
+/// This trait is implemented for all format descriptions provided by this crate, other than
+/// [`Custom`](crate::format_description::Custom) and
+/// [`Localized`](crate::formatting::Localized).
+pub trait Introspect: sealed::Sealed {
+    /// Whether every value of type `T` that is formatted with this description can be parsed
+    /// back into exactly the same value.
+    ///
+    /// This requires that the description captures every component of the value, including the
+    /// subsecond and all components of the UTC offset. Values that cannot be formatted at all,
+    /// such as a year that does not fit in the number of digits available, are not considered.
+    /// However, a description that cannot format `T` at all, such as one that requires a UTC
+    /// offset when formatting a [`Date`], is never a roundtrip.
+    ///
+    #[cfg_attr(feature = "macros", doc = "```rust")]
+    #[cfg_attr(not(feature = "macros"), doc = "```rust,ignore")]
+    /// # use time::format_description::well_known::{Rfc2822, Rfc3339};
+    /// # use time::format_description::Introspect;
+    /// # use time::macros::format_description;
+    /// # use time::{Date, OffsetDateTime, PrimitiveDateTime};
+    /// assert!(Rfc3339.is_roundtrip_for::<OffsetDateTime>());
+    /// // The subsecond is not included.
+    /// assert!(!Rfc2822.is_roundtrip_for::<OffsetDateTime>());
+    ///
+    /// let description = format_description!("[year]-[month]-[day]");
+    /// assert!(description.is_roundtrip_for::<Date>());
+    /// // The time is not included.
+    /// assert!(!description.is_roundtrip_for::<PrimitiveDateTime>());
+    ///
+    /// let description = format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    /// // The date cannot be formatted on its own, as the description includes the time.
+    /// assert!(!description.is_roundtrip_for::<Date>());
+    /// // The subsecond is not included.
+    /// assert!(!description.is_roundtrip_for::<PrimitiveDateTime>());
+    /// ```
+    fn is_roundtrip_for<T: RoundtripTarget>(&self) -> bool {
+        let mut coverage = Coverage::default();
+        self.add_to(&mut coverage);
+        T::is_reconstructed_from(&coverage)
+    }
+}
+
+/// A type whose values can be checked for a roundtrip through a format description.
+///
+/// This trait is sealed and is implemented for [`Date`], [`Time`], [`UtcOffset`],
+/// [`PrimitiveDateTime`], [`UtcDateTime`], and [`OffsetDateTime`].
+pub trait RoundtripTarget: sealed::Target {}
+
+impl RoundtripTarget for Date {}
+impl RoundtripTarget for Time {}
+impl RoundtripTarget for UtcOffset {}
+impl RoundtripTarget for PrimitiveDateTime {}
+impl RoundtripTarget for UtcDateTime {}
+impl RoundtripTarget for OffsetDateTime {}
+
+impl Introspect for BorrowedFormatItem<'_> {}
+impl Introspect for [BorrowedFormatItem<'_>] {}
+#[cfg(feature = "alloc")]
+impl Introspect for OwnedFormatItem {}
+#[cfg(feature = "alloc")]
+impl Introspect for [OwnedFormatItem] {}
+impl Introspect for Rfc3339 {}
+impl Introspect for Rfc3339Lenient {}
+impl Introspect for Rfc9557<'_> {}
+#[cfg(not(time_no_names))]
+impl Introspect for Rfc2822 {}
+#[cfg(not(time_no_names))]
+impl Introspect for HttpDate {}
+impl<const CONFIG: EncodedConfig> Introspect for Iso8601<CONFIG> {}
+impl Introspect for Iso8601Runtime {}
+impl<T: Deref> Introspect for T where T::Target: Introspect {}
+
+/// Seal the traits to prevent downstream implementations.
+mod sealed {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Record what a format description captures.
+    pub trait Sealed {
+        /// Record the components that are written when formatting and recovered when parsing.
+        fn add_to(&self, coverage: &mut Coverage);
+    }
+
+    /// Determine whether a value can be rebuilt from what a format description captures.
+    pub trait Target {
+        /// Whether the value is rebuilt exactly when parsing.
+        fn is_reconstructed_from(coverage: &Coverage) -> bool;
+    }
+}
+
+// region: coverage
+/// The components written by a format description, along with the information it requires.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Coverage {
+    /// The components that are written in full, as a bitset of the associated constants.
+    components: u32,
+    /// Whether a date is needed to format the description.
+    requires_date: bool,
+    /// Whether a time is needed to format the description.
+    requires_time: bool,
+    /// Whether a UTC offset is needed to format the description.
+    requires_offset: bool,
+    /// Whether the formatted output may not be parsed back as written.
+    is_lossy: bool,
+    /// Whether the previous item was a number with a variable number of digits.
+    follows_variable_width: bool,
+}
+
+impl Coverage {
+    /// The full calendar year.
+    const YEAR: u32 = 1 << 0;
+    /// The century of the calendar year.
+    const YEAR_CENTURY: u32 = 1 << 1;
+    /// The last two digits of the calendar year.
+    const YEAR_LAST_TWO: u32 = 1 << 2;
+    /// The full ISO week-based year.
+    const ISO_YEAR: u32 = 1 << 3;
+    /// The century of the ISO week-based year.
+    const ISO_YEAR_CENTURY: u32 = 1 << 4;
+    /// The last two digits of the ISO week-based year.
+    const ISO_YEAR_LAST_TWO: u32 = 1 << 5;
+    /// The month of the year.
+    const MONTH: u32 = 1 << 6;
+    /// The day of the month.
+    const DAY: u32 = 1 << 7;
+    /// The day of the year.
+    const ORDINAL: u32 = 1 << 8;
+    /// The day of the week.
+    const WEEKDAY: u32 = 1 << 9;
+    /// The ISO week number.
+    const ISO_WEEK_NUMBER: u32 = 1 << 10;
+    /// The week number, with weeks starting on Sunday.
+    const SUNDAY_WEEK_NUMBER: u32 = 1 << 11;
+    /// The week number, with weeks starting on Monday.
+    const MONDAY_WEEK_NUMBER: u32 = 1 << 12;
+    /// The hour on a 24-hour clock.
+    const HOUR_24: u32 = 1 << 13;
+    /// The hour on a 12-hour clock.
+    const HOUR_12: u32 = 1 << 14;
+    /// Whether the hour is AM or PM.
+    const PERIOD: u32 = 1 << 15;
+    /// The minute within the hour.
+    const MINUTE: u32 = 1 << 16;
+    /// The second within the minute.
+    const SECOND: u32 = 1 << 17;
+    /// The subsecond with nanosecond precision.
+    const SUBSECOND: u32 = 1 << 18;
+    /// The hour of the UTC offset.
+    const OFFSET_HOUR: u32 = 1 << 19;
+    /// The minute of the UTC offset.
+    const OFFSET_MINUTE: u32 = 1 << 20;
+    /// The second of the UTC offset.
+    const OFFSET_SECOND: u32 = 1 << 21;
+    /// The Unix timestamp with less than nanosecond precision.
+    const UNIX_TIMESTAMP: u32 = 1 << 22;
+    /// The Unix timestamp with nanosecond precision.
+    const UNIX_TIMESTAMP_NANOS: u32 = 1 << 23;
+
+    /// The date components of RFC 3339 and ISO 8601 calendar dates.
+    const CALENDAR_DATE: u32 = Self::YEAR | Self::MONTH | Self::DAY;
+    /// All components of the UTC offset.
+    const OFFSET: u32 = Self::OFFSET_HOUR | Self::OFFSET_MINUTE | Self::OFFSET_SECOND;
+
+    /// Whether all of the provided components are written.
+    const fn has(&self, components: u32) -> bool {
+        self.components & components == components
+    }
+
+    /// Whether any of the provided components are written.
+    const fn has_any(&self, components: u32) -> bool {
+        self.components & components != 0
+    }
+
+    /// Record that the provided components are written in full.
+    fn insert(&mut self, components: u32) {
+        self.components |= components;
+    }
+
+    /// Record a literal.
+    fn add_literal(&mut self, literal: &[u8]) {
+        if literal.is_empty() {
+            return;
+        }
+        // A variable-width number would consume the leading digit of the literal when parsing.
+        if self.follows_variable_width && literal[0].is_ascii_digit() {
+            self.is_lossy = true;
+        }
+        self.follows_variable_width = false;
+    }
+
+    /// Record a component.
+    fn add_component(&mut self, component: Component) {
+        if self.follows_variable_width && may_start_with_digit(component) {
+            self.is_lossy = true;
+        }
+        self.follows_variable_width = is_variable_width(component);
+
+        match component {
+            Component::Day(_)
+            | Component::Month(_)
+            | Component::Ordinal(_)
+            | Component::Weekday(_)
+            | Component::WeekNumber(_)
+            | Component::Year(_) => self.requires_date = true,
+            Component::Hour(_)
+            | Component::Minute(_)
+            | Component::Period(_)
+            | Component::Second(_)
+            | Component::Subsecond(_) => self.requires_time = true,
+            Component::OffsetHour(_) | Component::OffsetMinute(_) | Component::OffsetSecond(_) => {
+                self.requires_offset = true;
+            }
+            Component::UnixTimestamp(_) => {
+                self.requires_date = true;
+                self.requires_time = true;
+                self.requires_offset = true;
+            }
+            Component::Ignore(_) | Component::End(_) => {}
+        }
+
+        match component {
+            Component::Day(_) => self.insert(Self::DAY),
+            Component::Month(_) => self.insert(Self::MONTH),
+            Component::Ordinal(_) => self.insert(Self::ORDINAL),
+            Component::Weekday(_) => self.insert(Self::WEEKDAY),
+            Component::WeekNumber(modifier) => self.insert(match modifier.repr {
+                WeekNumberRepr::Iso => Self::ISO_WEEK_NUMBER,
+                WeekNumberRepr::Sunday => Self::SUNDAY_WEEK_NUMBER,
+                WeekNumberRepr::Monday => Self::MONDAY_WEEK_NUMBER,
+            }),
+            Component::Year(modifier) => {
+                self.insert(match (modifier.repr, modifier.iso_week_based) {
+                    (YearRepr::Full, false) => Self::YEAR,
+                    (YearRepr::Century, false) => Self::YEAR_CENTURY,
+                    (YearRepr::LastTwo, false) => Self::YEAR_LAST_TWO,
+                    (YearRepr::Full, true) => Self::ISO_YEAR,
+                    (YearRepr::Century, true) => Self::ISO_YEAR_CENTURY,
+                    (YearRepr::LastTwo, true) => Self::ISO_YEAR_LAST_TWO,
+                })
+            }
+            Component::Hour(modifier) if modifier.is_12_hour_clock => self.insert(Self::HOUR_12),
+            Component::Hour(_) => self.insert(Self::HOUR_24),
+            Component::Minute(_) => self.insert(Self::MINUTE),
+            Component::Period(_) => self.insert(Self::PERIOD),
+            Component::Second(_) => self.insert(Self::SECOND),
+            Component::Subsecond(modifier) => {
+                if matches!(
+                    modifier.digits,
+                    SubsecondDigits::Nine | SubsecondDigits::OneOrMore
+                ) {
+                    self.insert(Self::SUBSECOND);
+                }
+            }
+            Component::OffsetHour(_) => self.insert(Self::OFFSET_HOUR),
+            Component::OffsetMinute(_) => self.insert(Self::OFFSET_MINUTE),
+            Component::OffsetSecond(_) => self.insert(Self::OFFSET_SECOND),
+            // Nothing is written when formatting, but input is consumed when parsing.
+            Component::Ignore(_) => self.is_lossy = true,
+            Component::UnixTimestamp(modifier) => {
+                self.insert(match modifier.precision {
+                    UnixTimestampPrecision::Nanosecond => Self::UNIX_TIMESTAMP_NANOS,
+                    _ => Self::UNIX_TIMESTAMP,
+                });
+            }
+            Component::End(_) => {}
+        }
+    }
+
+    /// Record the components of an ISO 8601 configuration.
+    fn add_iso8601(&mut self, config: &Config) {
+        // Formatting a parsing-only configuration panics.
+        if !config.formats_date() && !config.formats_time() && !config.formats_offset() {
+            self.is_lossy = true;
+        }
+
+        if config.formats_date() {
+            self.requires_date = true;
+            self.insert(match config.date_kind {
+                DateKind::Calendar => Self::CALENDAR_DATE,
+                DateKind::Week => Self::ISO_YEAR | Self::ISO_WEEK_NUMBER | Self::WEEKDAY,
+                DateKind::Ordinal => Self::YEAR | Self::ORDINAL,
+            });
+        }
+        if config.formats_time() {
+            self.requires_time = true;
+            // Decimal fractions are written after a lossy conversion to a float, so the subsecond
+            // is never recovered exactly.
+            self.insert(match config.time_precision {
+                TimePrecision::Hour { .. } => Self::HOUR_24,
+                TimePrecision::Minute { .. } => Self::HOUR_24 | Self::MINUTE,
+                TimePrecision::Second { .. } => Self::HOUR_24 | Self::MINUTE | Self::SECOND,
+            });
+        }
+        if config.formats_offset() {
+            self.requires_offset = true;
+            // Any components of the offset that are not written must be zero, as formatting fails
+            // otherwise.
+            self.insert(Self::OFFSET);
+        }
+    }
+
+    /// Whether the value can be formatted and its date parsed back exactly.
+    const fn has_date(&self) -> bool {
+        let has_year = self.has(Self::YEAR) || self.has(Self::YEAR_CENTURY | Self::YEAR_LAST_TWO);
+        let has_iso_year =
+            self.has(Self::ISO_YEAR) || self.has(Self::ISO_YEAR_CENTURY | Self::ISO_YEAR_LAST_TWO);
+
+        (has_year
+            && (self.has(Self::MONTH | Self::DAY)
+                || self.has(Self::ORDINAL)
+                || (self.has(Self::WEEKDAY)
+                    && self.has_any(Self::SUNDAY_WEEK_NUMBER | Self::MONDAY_WEEK_NUMBER))))
+            || (has_iso_year && self.has(Self::ISO_WEEK_NUMBER | Self::WEEKDAY))
+    }
+
+    /// Whether the value can be formatted and its time parsed back exactly.
+    const fn has_time(&self) -> bool {
+        (self.has(Self::HOUR_24) || self.has(Self::HOUR_12 | Self::PERIOD))
+            && self.has(Self::MINUTE | Self::SECOND | Self::SUBSECOND)
+    }
+
+    /// Whether the value can be formatted and its UTC offset parsed back exactly.
+    const fn has_offset(&self) -> bool {
+        self.has(Self::OFFSET)
+    }
+
+    /// Whether the description can be formatted using only the provided information, with the
+    /// output being parsed back as written.
+    const fn is_formattable_with(&self, date: bool, time: bool, offset: bool) -> bool {
+        !self.is_lossy
+            && (date || !self.requires_date)
+            && (time || !self.requires_time)
+            && (offset || !self.requires_offset)
+    }
+}
+
+/// Whether the component may be written starting with a digit.
+const fn may_start_with_digit(component: Component) -> bool {
+    match component {
+        Component::Month(modifier) => matches!(modifier.repr, MonthRepr::Numerical),
+        Component::Weekday(modifier) => {
+            matches!(modifier.repr, WeekdayRepr::Sunday | WeekdayRepr::Monday)
+        }
+        Component::Year(modifier) => {
+            !modifier.sign_is_mandatory || matches!(modifier.repr, YearRepr::LastTwo)
+        }
+        Component::OffsetHour(modifier) => !modifier.sign_is_mandatory,
+        Component::UnixTimestamp(modifier) => !modifier.sign_is_mandatory,
+        Component::Day(_)
+        | Component::Ordinal(_)
+        | Component::WeekNumber(_)
+        | Component::Hour(_)
+        | Component::Minute(_)
+        | Component::Second(_)
+        | Component::Subsecond(_)
+        | Component::OffsetMinute(_)
+        | Component::OffsetSecond(_) => true,
+        Component::Period(_) | Component::Ignore(_) | Component::End(_) => false,
+    }
+}
+
+/// Whether the component is a number whose number of digits depends on the value.
+const fn is_variable_width(component: Component) -> bool {
+    match component {
+        Component::Month(modifier) => {
+            matches!(modifier.repr, MonthRepr::Numerical)
+                && matches!(modifier.padding, Padding::None)
+        }
+        Component::Day(modifier) => matches!(modifier.padding, Padding::None),
+        Component::Ordinal(modifier) => matches!(modifier.padding, Padding::None),
+        Component::WeekNumber(modifier) => matches!(modifier.padding, Padding::None),
+        Component::Year(modifier) => {
+            matches!(modifier.padding, Padding::None)
+                || (cfg!(feature = "large-dates")
+                    && matches!(modifier.repr, YearRepr::Full)
+                    && matches!(modifier.range, YearRange::Extended)
+                    && matches!(modifier.width, YearWidth::Automatic))
+        }
+        Component::Hour(modifier) => matches!(modifier.padding, Padding::None),
+        Component::Minute(modifier) => matches!(modifier.padding, Padding::None),
+        Component::Second(modifier) => matches!(modifier.padding, Padding::None),
+        Component::Subsecond(modifier) => matches!(modifier.digits, SubsecondDigits::OneOrMore),
+        Component::OffsetHour(modifier) => matches!(modifier.padding, Padding::None),
+        Component::OffsetMinute(modifier) => matches!(modifier.padding, Padding::None),
+        Component::OffsetSecond(modifier) => matches!(modifier.padding, Padding::None),
+        Component::UnixTimestamp(_) => true,
+        Component::Weekday(_) | Component::Period(_) | Component::Ignore(_) | Component::End(_) => {
+            false
+        }
+    }
+}
+// endregion coverage
+
+// region: format descriptions
+impl sealed::Sealed for BorrowedFormatItem<'_> {
+    fn add_to(&self, coverage: &mut Coverage) {
+        match *self {
+            Self::Literal(literal) => coverage.add_literal(literal),
+            Self::Component(component) => coverage.add_component(component),
+            Self::Compound(items) => items.add_to(coverage),
+            // Optional items are always written when formatting.
+            Self::Optional(item) => item.add_to(coverage),
+            // Only the first item is written when formatting.
+            Self::First(items) => {
+                if let Some(item) = items.first() {
+                    item.add_to(coverage);
+                }
+            }
+        }
+    }
+}
+
+impl sealed::Sealed for [BorrowedFormatItem<'_>] {
+    fn add_to(&self, coverage: &mut Coverage) {
+        for item in self {
+            item.add_to(coverage);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for OwnedFormatItem {
+    fn add_to(&self, coverage: &mut Coverage) {
+        match self {
+            Self::Literal(literal) => coverage.add_literal(literal),
+            Self::Component(component) => coverage.add_component(*component),
+            Self::Compound(items) => items.add_to(coverage),
+            // Optional items are always written when formatting.
+            Self::Optional(item) => item.add_to(coverage),
+            // Only the first item is written when formatting.
+            Self::First(items) => {
+                if let Some(item) = items.first() {
+                    item.add_to(coverage);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for [OwnedFormatItem] {
+    fn add_to(&self, coverage: &mut Coverage) {
+        for item in self {
+            item.add_to(coverage);
+        }
+    }
+}
+
+impl<T: Deref> sealed::Sealed for T
+where
+    T::Target: sealed::Sealed,
+{
+    fn add_to(&self, coverage: &mut Coverage) {
+        self.deref().add_to(coverage);
+    }
+}
+
+// Formatting fails for any offset with a nonzero second and any year outside of 0..=9999, so
+// neither is a concern for the well-known formats.
+impl sealed::Sealed for Rfc3339 {
+    fn add_to(&self, coverage: &mut Coverage) {
+        coverage.requires_date = true;
+        coverage.requires_time = true;
+        coverage.requires_offset = true;
+        coverage.insert(
+            Coverage::CALENDAR_DATE
+                | Coverage::HOUR_24
+                | Coverage::MINUTE
+                | Coverage::SECOND
+                | Coverage::SUBSECOND
+                | Coverage::OFFSET,
+        );
+    }
+}
+
+impl sealed::Sealed for Rfc3339Lenient {
+    fn add_to(&self, coverage: &mut Coverage) {
+        Rfc3339.add_to(coverage);
+    }
+}
+
+// The time zone annotation is not used to determine the value, so it has no effect on coverage.
+impl sealed::Sealed for Rfc9557<'_> {
+    fn add_to(&self, coverage: &mut Coverage) {
+        Rfc3339.add_to(coverage);
+    }
+}
+
+#[cfg(not(time_no_names))]
+impl sealed::Sealed for Rfc2822 {
+    fn add_to(&self, coverage: &mut Coverage) {
+        coverage.requires_date = true;
+        coverage.requires_time = true;
+        coverage.requires_offset = true;
+        coverage.insert(
+            Coverage::CALENDAR_DATE
+                | Coverage::WEEKDAY
+                | Coverage::HOUR_24
+                | Coverage::MINUTE
+                | Coverage::SECOND
+                | Coverage::OFFSET,
+        );
+    }
+}
+
+#[cfg(not(time_no_names))]
+impl sealed::Sealed for HttpDate {
+    fn add_to(&self, coverage: &mut Coverage) {
+        coverage.requires_date = true;
+        coverage.requires_time = true;
+        coverage.requires_offset = true;
+        // The offset is not preserved, as the value is converted to UTC when formatting.
+        coverage.insert(
+            Coverage::CALENDAR_DATE
+                | Coverage::WEEKDAY
+                | Coverage::HOUR_24
+                | Coverage::MINUTE
+                | Coverage::SECOND,
+        );
+    }
+}
+
+impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
+    fn add_to(&self, coverage: &mut Coverage) {
+        coverage.add_iso8601(&Self::CONFIG);
+    }
+}
+
+impl sealed::Sealed for Iso8601Runtime {
+    fn add_to(&self, coverage: &mut Coverage) {
+        coverage.add_iso8601(self.config());
+    }
+}
+// endregion format descriptions
+
+// region: targets
+impl sealed::Target for Date {
+    fn is_reconstructed_from(coverage: &Coverage) -> bool {
+        coverage.is_formattable_with(true, false, false) && coverage.has_date()
+    }
+}
+
+impl sealed::Target for Time {
+    fn is_reconstructed_from(coverage: &Coverage) -> bool {
+        coverage.is_formattable_with(false, true, false) && coverage.has_time()
+    }
+}
+
+impl sealed::Target for UtcOffset {
+    fn is_reconstructed_from(coverage: &Coverage) -> bool {
+        coverage.is_formattable_with(false, false, true) && coverage.has_offset()
+    }
+}
+
+impl sealed::Target for PrimitiveDateTime {
+    fn is_reconstructed_from(coverage: &Coverage) -> bool {
+        coverage.is_formattable_with(true, true, false)
+            && coverage.has_date()
+            && coverage.has_time()
+    }
+}
+
+impl sealed::Target for UtcDateTime {
+    fn is_reconstructed_from(coverage: &Coverage) -> bool {
+        if !coverage.is_formattable_with(true, true, true) {
+            return false;
+        }
+
+        // When present, the Unix timestamp takes precedence over all other components. The offset
+        // is always UTC, so it is irrelevant whether it is written.
+        if coverage.has(Coverage::UNIX_TIMESTAMP_NANOS) {
+            true
+        } else if coverage.has(Coverage::UNIX_TIMESTAMP) {
+            coverage.has(Coverage::SUBSECOND)
+        } else {
+            coverage.has_date() && coverage.has_time()
+        }
+    }
+}
+
+impl sealed::Target for OffsetDateTime {
+    fn is_reconstructed_from(coverage: &Coverage) -> bool {
+        // When present, the Unix timestamp takes precedence over all other components, and the
+        // parsed value is always in UTC.
+        coverage.is_formattable_with(true, true, true)
+            && !coverage.has_any(Coverage::UNIX_TIMESTAMP | Coverage::UNIX_TIMESTAMP_NANOS)
+            && coverage.has_date()
+            && coverage.has_time()
+            && coverage.has_offset()
+    }
+}
+// endregion targets
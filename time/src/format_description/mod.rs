@@ -9,6 +9,9 @@
 
 mod borrowed_format_item;
 mod component;
+mod custom;
+#[cfg(all(feature = "formatting", feature = "parsing"))]
+mod introspection;
 pub mod modifier;
 #[cfg(feature = "alloc")]
 mod owned_format_item;
@@ -23,19 +26,48 @@ pub use borrowed_format_item::BorrowedFormatItem as FormatItem;
 pub use owned_format_item::OwnedFormatItem;
 
 pub use self::component::Component;
+pub use self::custom::Custom;
+#[cfg(all(feature = "formatting", feature = "parsing"))]
+pub use self::introspection::{Introspect, RoundtripTarget};
 #[cfg(feature = "alloc")]
 pub use self::parse::{
-    parse, parse_borrowed, parse_owned, parse_strftime_borrowed, parse_strftime_owned,
+    parse, parse_borrowed, parse_latest, parse_owned, parse_strftime_borrowed,
+    parse_strftime_owned, parse_v1, parse_v2,
 };
 
+/// The version of the format description grammar that is used when no version is specified.
+///
+/// This is the version used by [`parse`](crate::format_description::parse()) and by the
+/// [`format_description!`](crate::macros::format_description) macro when no `version` argument
+/// is provided. It is retained for backward compatibility; new code should prefer
+/// [`LATEST_VERSION`].
+pub const DEFAULT_VERSION: usize = 1;
+
+/// The most recent version of the format description grammar.
+///
+/// Version 2 differs from version 1 in that backslash escapes are supported and `[[` is no longer
+/// an escape for a literal `[`.
+pub const LATEST_VERSION: usize = 2;
+
 /// Well-known formats, typically standards.
 pub mod well_known {
+    mod common_format;
+    #[cfg(not(time_no_names))]
+    mod http_date;
     pub mod iso8601;
+    #[cfg(not(time_no_names))]
     mod rfc2822;
     mod rfc3339;
+    pub mod rfc9557;
 
+    pub use common_format::CommonFormat;
+    #[cfg(not(time_no_names))]
+    pub use http_date::HttpDate;
     #[doc(inline)]
-    pub use iso8601::Iso8601;
+    pub use iso8601::{Iso8601, Iso8601Runtime};
+    #[cfg(not(time_no_names))]
     pub use rfc2822::Rfc2822;
-    pub use rfc3339::Rfc3339;
+    pub use rfc3339::{Rfc3339, Rfc3339Lenient};
+    #[doc(inline)]
+    pub use rfc9557::Rfc9557;
 }
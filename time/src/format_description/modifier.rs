@@ -9,6 +9,8 @@ use core::num::NonZeroU16;
 pub struct Day {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// Is the value followed by its English ordinal suffix (e.g. "1st", "22nd")?
+    pub ordinal_suffix: bool,
 }
 
 /// The representation of a month.
@@ -93,6 +95,9 @@ pub struct WeekNumber {
     pub padding: Padding,
     /// What kind of representation should be used?
     pub repr: WeekNumberRepr,
+    /// Is the weekday optional when parsing? If it is and no weekday is present, the date is the
+    /// Monday of the week.
+    pub weekday_is_optional: bool,
 }
 
 /// The representation used for a year value.
@@ -122,6 +127,62 @@ pub enum YearRange {
     Extended,
 }
 
+/// The number of digits used for a full year.
+///
+/// This modifier has no effect unless the year repr is [`Full`](YearRepr::Full).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearWidth {
+    /// The number of digits is determined by the year and the [range](YearRange) in use. When the
+    /// `large-dates` feature is enabled, years with more than four digits are preceded by a sign.
+    Automatic,
+    /// Exactly four digits. The sign is only present if the year is negative or the sign is
+    /// mandatory.
+    Four,
+    /// Exactly five digits. The sign is only present if the year is negative or the sign is
+    /// mandatory.
+    Five,
+    /// Exactly six digits. The sign is only present if the year is negative or the sign is
+    /// mandatory.
+    Six,
+}
+
+/// The separator placed between each group of three digits of a year, counting from the right.
+///
+/// Separators are only placed between significant digits, so any padding is not separated. With
+/// the default padding, the year 999 is formatted as `0999` rather than `0_999`.
+///
+/// This modifier only affects formatting. When parsing, the digits of the year must not be
+/// separated.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearGroupSeparator {
+    /// The digits are not separated.
+    None,
+    /// An underscore, such as `12_024`.
+    Underscore,
+    /// A comma, such as `12,024`.
+    Comma,
+    /// A period, such as `12.024`.
+    Period,
+    /// A space, such as `12 024`.
+    Space,
+}
+
+/// How years before 1 CE are numbered.
+///
+/// This modifier has no effect unless the year repr is [`Full`](YearRepr::Full).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearNotation {
+    /// Astronomical year numbering, where 1 BCE is year zero and earlier years are negative, such
+    /// as `-0001` for 2 BCE.
+    Astronomical,
+    /// Years before 1 CE are numbered from one, counting backwards, and followed by ` BCE`, such as
+    /// `0002 BCE`. Later years are unaffected.
+    Bce,
+}
+
 /// Year of the date.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -136,6 +197,12 @@ pub struct Year {
     pub iso_week_based: bool,
     /// Whether the `+` sign is present when a positive year contains fewer than five digits.
     pub sign_is_mandatory: bool,
+    /// How many digits are used for a full year? Formatting fails if the year does not fit.
+    pub width: YearWidth,
+    /// The separator placed between each group of three digits when formatting.
+    pub group_separator: YearGroupSeparator,
+    /// How years before 1 CE are numbered.
+    pub notation: YearNotation,
 }
 // endregion date modifiers
 
@@ -206,6 +273,12 @@ pub enum SubsecondDigits {
 }
 
 /// Subsecond within the second.
+///
+/// When formatting, digits beyond those requested are truncated. Rounding is not available here,
+/// as each component is formatted independently and rounding may need to carry into the second,
+/// minute, hour, or date. [ISO 8601](crate::format_description::well_known::Iso8601) formatting
+/// supports rounding via
+/// [`Config::set_subsecond_rounding`](crate::format_description::well_known::iso8601::Config::set_subsecond_rounding).
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Subsecond {
@@ -345,8 +418,12 @@ macro_rules! impl_const_default {
 }
 
 impl_const_default! {
-    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
-    @pub Day => Self { padding: Padding::Zero };
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero) and has
+    /// no ordinal suffix.
+    @pub Day => Self {
+        padding: Padding::Zero,
+        ordinal_suffix: false,
+    };
     /// Creates a modifier that indicates the value uses the
     /// [`Numerical`](Self::Numerical) representation.
     MonthRepr => Self::Numerical;
@@ -372,25 +449,40 @@ impl_const_default! {
     };
     /// Creates a modifier that indicates that the value uses the [`Iso`](Self::Iso) representation.
     WeekNumberRepr => Self::Iso;
-    /// Creates a modifier that indicates that the value is [padded with zeroes](Padding::Zero)
-            /// and uses the [`Iso`](WeekNumberRepr::Iso) representation.
+    /// Creates a modifier that indicates that the value is [padded with zeroes](Padding::Zero),
+    /// uses the [`Iso`](WeekNumberRepr::Iso) representation, and requires the weekday when
+    /// parsing.
     @pub WeekNumber => Self {
         padding: Padding::Zero,
         repr: WeekNumberRepr::Iso,
+        weekday_is_optional: false,
     };
     /// Creates a modifier that indicates the value uses the [`Full`](Self::Full) representation.
     YearRepr => Self::Full;
     /// Creates a modifier that indicates the value uses the [`Extended`](Self::Extended) range.
     YearRange => Self::Extended;
+    /// Creates a modifier that indicates the number of digits is [determined
+    /// automatically](Self::Automatic).
+    YearWidth => Self::Automatic;
+    /// Creates a modifier that indicates the digits are [not separated](Self::None).
+    YearGroupSeparator => Self::None;
+    /// Creates a modifier that indicates the value uses [astronomical](Self::Astronomical) year
+    /// numbering.
+    YearNotation => Self::Astronomical;
     /// Creates a modifier that indicates the value uses the [`Full`](YearRepr::Full)
     /// representation, is [padded with zeroes](Padding::Zero), uses the Gregorian calendar as its
-    /// base, and only includes the year's sign if necessary.
+    /// base, only includes the year's sign if necessary, has its width [determined
+    /// automatically](YearWidth::Automatic), does [not separate](YearGroupSeparator::None) its
+    /// digits, and uses [astronomical](YearNotation::Astronomical) year numbering.
     @pub Year => Self {
         padding: Padding::Zero,
         repr: YearRepr::Full,
         range: YearRange::Extended,
         iso_week_based: false,
         sign_is_mandatory: false,
+        width: YearWidth::Automatic,
+        group_separator: YearGroupSeparator::None,
+        notation: YearNotation::Astronomical,
     };
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero) and
     /// has the 24-hour representation.
@@ -306,8 +306,8 @@ fn parse_component<
         if key.is_empty() {
             return Err(Error {
                 _inner: unused(span.shrink_to_start().error("expected modifier key")),
-                public: crate::error::InvalidFormatDescription::InvalidModifier {
-                    value: String::new(),
+                public: crate::error::InvalidFormatDescription::Expected {
+                    what: "modifier key",
                     index: span.start.byte as _,
                 },
             });
@@ -315,8 +315,8 @@ fn parse_component<
         if value.is_empty() {
             return Err(Error {
                 _inner: unused(span.shrink_to_end().error("expected modifier value")),
-                public: crate::error::InvalidFormatDescription::InvalidModifier {
-                    value: String::new(),
+                public: crate::error::InvalidFormatDescription::Expected {
+                    what: "modifier value",
                     index: span.shrink_to_end().start.byte as _,
                 },
             });
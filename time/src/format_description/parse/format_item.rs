@@ -48,7 +48,22 @@ impl Item<'_> {
                 modifiers,
                 _trailing_whitespace: _,
                 _closing_bracket: _,
-            } => Item::Component(component_from_ast(&name, &modifiers)?),
+            } => {
+                let component = component_from_ast(&name, &modifiers)?;
+                if cfg!(time_no_names) {
+                    if let Some(what) = component.required_names() {
+                        return Err(Error {
+                            _inner: unused(name.span.error("names are not available")),
+                            public: crate::error::InvalidFormatDescription::NotSupported {
+                                what,
+                                context: "builds with `--cfg time_no_names`",
+                                index: name.span.start.byte as _,
+                            },
+                        });
+                    }
+                }
+                Item::Component(component)
+            }
             ast::Item::Literal(Spanned { value, span: _ }) => Item::Literal(value),
             ast::Item::EscapedBracket {
                 _first: _,
@@ -272,11 +287,29 @@ macro_rules! component_definition {
     }
 }
 
+impl Component {
+    /// If the component uses the names of months or weekdays, a description of what it is.
+    const fn required_names(&self) -> Option<&'static str> {
+        match self {
+            Self::Month(Month {
+                repr: Some(MonthRepr::Long | MonthRepr::Short),
+                ..
+            }) => Some("textual month"),
+            Self::Weekday(Weekday {
+                repr: None | Some(WeekdayRepr::Long | WeekdayRepr::Short),
+                ..
+            }) => Some("textual weekday"),
+            _ => None,
+        }
+    }
+}
+
 // Keep in alphabetical order.
 component_definition! {
     pub(super) enum Component {
         Day = "day" {
             padding = "padding": Option<Padding> => padding,
+            ordinal_suffix = "ordinal_suffix": Option<DayOrdinalSuffix> => ordinal_suffix,
         },
         End = "end" {},
         Hour = "hour" {
@@ -330,6 +363,7 @@ component_definition! {
         WeekNumber = "week_number" {
             padding = "padding": Option<Padding> => padding,
             repr = "repr": Option<WeekNumberRepr> => repr,
+            weekday = "weekday": Option<WeekNumberWeekday> => weekday_is_optional,
         },
         Year = "year" {
             padding = "padding": Option<Padding> => padding,
@@ -337,6 +371,9 @@ component_definition! {
             range = "range": Option<YearRange> => range,
             base = "base": Option<YearBase> => iso_week_based,
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
+            width = "width": Option<YearWidth> => width,
+            group_separator = "group_separator": Option<YearGroupSeparator> => group_separator,
+            notation = "notation": Option<YearNotation> => notation,
         },
     }
 }
@@ -420,6 +457,12 @@ macro_rules! modifier {
 
 // Keep in alphabetical order.
 modifier! {
+    enum DayOrdinalSuffix(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum HourBase(bool) {
         Twelve(true) = b"12",
         #[default]
@@ -493,6 +536,12 @@ modifier! {
         Monday = b"monday",
     }
 
+    enum WeekNumberWeekday(bool) {
+        #[default]
+        Required(false) = b"required",
+        Optional(true) = b"optional",
+    }
+
     enum WeekdayCaseSensitive(bool) {
         False(false) = b"false",
         #[default]
@@ -531,6 +580,29 @@ modifier! {
         #[default]
         Extended = b"extended",
     }
+
+    enum YearWidth {
+        #[default]
+        Automatic = b"auto",
+        Four = b"4",
+        Five = b"5",
+        Six = b"6",
+    }
+
+    enum YearGroupSeparator {
+        #[default]
+        None = b"none",
+        Underscore = b"underscore",
+        Comma = b"comma",
+        Period = b"period",
+        Space = b"space",
+    }
+
+    enum YearNotation {
+        #[default]
+        Astronomical = b"astronomical",
+        Bce = b"bce",
+    }
 }
 
 /// Parse a modifier value using `FromStr`. Requires the modifier value to be valid UTF-8.
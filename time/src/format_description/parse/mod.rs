@@ -4,6 +4,7 @@ use alloc::boxed::Box;
 use alloc::vec::Vec;
 
 pub use self::strftime::{parse_strftime_borrowed, parse_strftime_owned};
+use crate::format_description::{DEFAULT_VERSION, LATEST_VERSION};
 use crate::{error, format_description};
 
 /// A helper macro to make version restrictions simpler to read and write.
@@ -30,7 +31,7 @@ struct Version<const N: usize>;
 impl<const N: usize> Version<N> {
     /// A constant that panics if the version is not valid. This results in a post-monomorphization
     /// error.
-    const IS_VALID: () = assert!(N >= 1 && N <= 2);
+    const IS_VALID: () = assert!(N >= 1 && N <= LATEST_VERSION);
 }
 
 /// Parse a sequence of items from the format description.
@@ -39,14 +40,44 @@ impl<const N: usize> Version<N> {
 /// book](https://time-rs.github.io/book/api/format-description.html).
 ///
 /// This function exists for backward compatibility reasons. It is equivalent to calling
-/// `parse_borrowed::<1>(s)`. In the future, this function will be deprecated in favor of
-/// `parse_borrowed`.
+/// `parse_borrowed::<DEFAULT_VERSION>(s)`, where [`DEFAULT_VERSION`] is currently 1. In the
+/// future, this function will be deprecated in favor of `parse_borrowed`.
 pub fn parse(
     s: &str,
+) -> Result<Vec<format_description::BorrowedFormatItem<'_>>, error::InvalidFormatDescription> {
+    parse_borrowed::<DEFAULT_VERSION>(s)
+}
+
+/// Parse a sequence of items from a version 1 format description.
+///
+/// This is equivalent to calling `parse_borrowed::<1>(s)`.
+pub fn parse_v1(
+    s: &str,
 ) -> Result<Vec<format_description::BorrowedFormatItem<'_>>, error::InvalidFormatDescription> {
     parse_borrowed::<1>(s)
 }
 
+/// Parse a sequence of items from a version 2 format description.
+///
+/// This is equivalent to calling `parse_borrowed::<2>(s)`.
+pub fn parse_v2(
+    s: &str,
+) -> Result<Vec<format_description::BorrowedFormatItem<'_>>, error::InvalidFormatDescription> {
+    parse_borrowed::<2>(s)
+}
+
+/// Parse a sequence of items from a format description using the most recent version of the
+/// grammar.
+///
+/// This is equivalent to calling `parse_borrowed::<LATEST_VERSION>(s)`, where [`LATEST_VERSION`]
+/// is currently 2. Note that the accepted syntax will change if a new version is introduced; use
+/// [`parse_v2`] if this is undesirable.
+pub fn parse_latest(
+    s: &str,
+) -> Result<Vec<format_description::BorrowedFormatItem<'_>>, error::InvalidFormatDescription> {
+    parse_borrowed::<LATEST_VERSION>(s)
+}
+
 /// Parse a sequence of items from the format description.
 ///
 /// The syntax for the format description can be found in [the
@@ -47,10 +47,21 @@ enum Padding {
     Zeroes,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Flag {
+    /// No flag was provided. Indicated by no character.
+    None,
+    /// Separate the components of an offset with colons. Indicated by one or more colons.
+    Colons(u8),
+    /// Permit the minute of an offset to be omitted when parsing. Indicated by a hash.
+    Alternate,
+}
+
 enum Token<'a> {
     Literal(Spanned<&'a [u8]>),
     Component {
         _percent: Unused<Location>,
+        flag: Spanned<Flag>,
         padding: Spanned<Padding>,
         component: Spanned<u8>,
     },
@@ -61,41 +72,73 @@ fn lex(mut input: &[u8]) -> iter::Peekable<impl Iterator<Item = Result<Token<'_>
 
     iter::from_fn(move || {
         Some(Ok(match iter.next()? {
-            (b'%', percent_loc) => match iter.next() {
-                Some((padding @ (b'_' | b'-' | b'0'), padding_loc)) => {
-                    let padding = match padding {
-                        b'_' => Padding::Spaces,
-                        b'-' => Padding::None,
-                        b'0' => Padding::Zeroes,
-                        _ => unreachable!(),
+            (b'%', percent_loc) => {
+                let mut flag = Flag::None.spanned(percent_loc.to_self());
+                let mut consumed = 1;
+                while let Some((&byte, flag_loc)) =
+                    iter.next_if(|&(&byte, _)| byte == b':' || byte == b'#')
+                {
+                    let value = match (*flag, byte) {
+                        (Flag::None, b':') => Flag::Colons(1),
+                        (Flag::Colons(colons), b':') => Flag::Colons(colons.saturating_add(1)),
+                        (Flag::None, b'#') => Flag::Alternate,
+                        _ => {
+                            return Some(Err(Error {
+                                _inner: unused(flag_loc.error("conflicting flags")),
+                                public: InvalidFormatDescription::NotSupported {
+                                    what: "flag",
+                                    context: "",
+                                    index: flag_loc.byte as _,
+                                },
+                            }));
+                        }
                     };
-                    let (&component, component_loc) = iter.next()?;
-                    input = &input[3..];
-                    Token::Component {
-                        _percent: unused(percent_loc),
-                        padding: padding.spanned(padding_loc.to_self()),
-                        component: component.spanned(component_loc.to_self()),
-                    }
+                    let start = match *flag {
+                        Flag::None => flag_loc,
+                        _ => flag.span.start,
+                    };
+                    flag = value.spanned(start.to(flag_loc));
+                    consumed += 1;
                 }
-                Some((&component, component_loc)) => {
-                    input = &input[2..];
-                    let span = component_loc.to_self();
-                    Token::Component {
-                        _percent: unused(percent_loc),
-                        padding: Padding::Default.spanned(span),
-                        component: component.spanned(span),
+
+                match iter.next() {
+                    Some((padding @ (b'_' | b'-' | b'0'), padding_loc)) => {
+                        let padding = match padding {
+                            b'_' => Padding::Spaces,
+                            b'-' => Padding::None,
+                            b'0' => Padding::Zeroes,
+                            _ => unreachable!(),
+                        };
+                        let (&component, component_loc) = iter.next()?;
+                        input = &input[consumed + 2..];
+                        Token::Component {
+                            _percent: unused(percent_loc),
+                            flag,
+                            padding: padding.spanned(padding_loc.to_self()),
+                            component: component.spanned(component_loc.to_self()),
+                        }
+                    }
+                    Some((&component, component_loc)) => {
+                        input = &input[consumed + 1..];
+                        let span = component_loc.to_self();
+                        Token::Component {
+                            _percent: unused(percent_loc),
+                            flag,
+                            padding: Padding::Default.spanned(span),
+                            component: component.spanned(span),
+                        }
+                    }
+                    None => {
+                        return Some(Err(Error {
+                            _inner: unused(percent_loc.error("unexpected end of input")),
+                            public: InvalidFormatDescription::Expected {
+                                what: "valid escape sequence",
+                                index: percent_loc.byte as _,
+                            },
+                        }));
                     }
                 }
-                None => {
-                    return Some(Err(Error {
-                        _inner: unused(percent_loc.error("unexpected end of input")),
-                        public: InvalidFormatDescription::Expected {
-                            what: "valid escape sequence",
-                            index: percent_loc.byte as _,
-                        },
-                    }));
-                }
-            },
+            }
             (_, start_location) => {
                 let mut bytes = 1;
                 let mut end_location = start_location;
@@ -128,17 +171,49 @@ fn into_items<'iter, 'token: 'iter>(
             Token::Literal(spanned) => Ok(BorrowedFormatItem::Literal(*spanned)),
             Token::Component {
                 _percent,
+                flag,
                 padding,
                 component,
-            } => parse_component(padding, component),
+            } => parse_component(flag, padding, component),
         })
     })
 }
 
 fn parse_component(
+    flag: Spanned<Flag>,
     padding: Spanned<Padding>,
     component: Spanned<u8>,
 ) -> Result<BorrowedFormatItem<'static>, Error> {
+    // Flags are only meaningful for the offset.
+    if *flag != Flag::None && *component != b'z' {
+        return Err(Error {
+            _inner: unused(ErrorInner {
+                _message: "flag is only supported on `z`",
+                _span: flag.span,
+            }),
+            public: InvalidFormatDescription::NotSupported {
+                what: "flag",
+                context: "",
+                index: flag.span.start.byte as _,
+            },
+        });
+    }
+
+    // These components are formatted and parsed using the names of months and weekdays.
+    if cfg!(time_no_names) && matches!(*component, b'a' | b'A' | b'b' | b'B' | b'c' | b'h') {
+        return Err(Error {
+            _inner: unused(ErrorInner {
+                _message: "names are not available",
+                _span: component.span,
+            }),
+            public: InvalidFormatDescription::NotSupported {
+                what: "textual month or weekday",
+                context: "builds with `--cfg time_no_names`",
+                index: component.span.start.byte as _,
+            },
+        });
+    }
+
     let padding_or_default = |padding: Padding, default| match padding {
         Padding::Default => default,
         Padding::Spaces => modifier::Padding::Space,
@@ -191,7 +266,8 @@ fn parse_component(
             }),
             BorrowedFormatItem::Literal(b" "),
             component!(Day {
-                padding: modifier::Padding::Space
+                padding: modifier::Padding::Space,
+                ordinal_suffix: false,
             }),
             BorrowedFormatItem::Literal(b" "),
             component!(Hour {
@@ -213,6 +289,9 @@ fn parse_component(
                 range: modifier::YearRange::Extended,
                 iso_week_based: false,
                 sign_is_mandatory: false,
+                width: modifier::YearWidth::Automatic,
+                group_separator: modifier::YearGroupSeparator::None,
+                notation: modifier::YearNotation::Astronomical,
             }),
         ]),
         b'C' => component!(Year {
@@ -221,9 +300,13 @@ fn parse_component(
             range: modifier::YearRange::Extended,
             iso_week_based: false,
             sign_is_mandatory: false,
+            width: modifier::YearWidth::Automatic,
+            group_separator: modifier::YearGroupSeparator::None,
+            notation: modifier::YearNotation::Astronomical,
         }),
         b'd' => component!(Day {
             padding: padding_or_default(*padding, modifier::Padding::Zero),
+            ordinal_suffix: false,
         }),
         b'D' => BorrowedFormatItem::Compound(&[
             component!(Month {
@@ -234,6 +317,7 @@ fn parse_component(
             BorrowedFormatItem::Literal(b"/"),
             component!(Day {
                 padding: modifier::Padding::Zero,
+                ordinal_suffix: false,
             }),
             BorrowedFormatItem::Literal(b"/"),
             component!(Year {
@@ -242,10 +326,14 @@ fn parse_component(
                 range: modifier::YearRange::Extended,
                 iso_week_based: false,
                 sign_is_mandatory: false,
+                width: modifier::YearWidth::Automatic,
+                group_separator: modifier::YearGroupSeparator::None,
+                notation: modifier::YearNotation::Astronomical,
             }),
         ]),
         b'e' => component!(Day {
             padding: padding_or_default(*padding, modifier::Padding::Space),
+            ordinal_suffix: false,
         }),
         b'F' => BorrowedFormatItem::Compound(&[
             component!(Year {
@@ -254,6 +342,9 @@ fn parse_component(
                 range: modifier::YearRange::Extended,
                 iso_week_based: false,
                 sign_is_mandatory: false,
+                width: modifier::YearWidth::Automatic,
+                group_separator: modifier::YearGroupSeparator::None,
+                notation: modifier::YearNotation::Astronomical,
             }),
             BorrowedFormatItem::Literal(b"-"),
             component!(Month {
@@ -264,6 +355,7 @@ fn parse_component(
             BorrowedFormatItem::Literal(b"-"),
             component!(Day {
                 padding: modifier::Padding::Zero,
+                ordinal_suffix: false,
             }),
         ]),
         b'g' => component!(Year {
@@ -272,6 +364,9 @@ fn parse_component(
             range: modifier::YearRange::Extended,
             iso_week_based: true,
             sign_is_mandatory: false,
+            width: modifier::YearWidth::Automatic,
+            group_separator: modifier::YearGroupSeparator::None,
+            notation: modifier::YearNotation::Astronomical,
         }),
         b'G' => component!(Year {
             padding: modifier::Padding::Zero,
@@ -279,6 +374,9 @@ fn parse_component(
             range: modifier::YearRange::Extended,
             iso_week_based: true,
             sign_is_mandatory: false,
+            width: modifier::YearWidth::Automatic,
+            group_separator: modifier::YearGroupSeparator::None,
+            notation: modifier::YearNotation::Astronomical,
         }),
         b'H' => component!(Hour {
             padding: padding_or_default(*padding, modifier::Padding::Zero),
@@ -388,10 +486,12 @@ fn parse_component(
         b'U' => component!(WeekNumber {
             padding: padding_or_default(*padding, modifier::Padding::Zero),
             repr: modifier::WeekNumberRepr::Sunday,
+            weekday_is_optional: false,
         }),
         b'V' => component!(WeekNumber {
             padding: padding_or_default(*padding, modifier::Padding::Zero),
             repr: modifier::WeekNumberRepr::Iso,
+            weekday_is_optional: false,
         }),
         b'w' => component!(Weekday {
             repr: modifier::WeekdayRepr::Sunday,
@@ -401,6 +501,7 @@ fn parse_component(
         b'W' => component!(WeekNumber {
             padding: padding_or_default(*padding, modifier::Padding::Zero),
             repr: modifier::WeekNumberRepr::Monday,
+            weekday_is_optional: false,
         }),
         b'x' => BorrowedFormatItem::Compound(&[
             component!(Month {
@@ -410,7 +511,8 @@ fn parse_component(
             }),
             BorrowedFormatItem::Literal(b"/"),
             component!(Day {
-                padding: modifier::Padding::Zero
+                padding: modifier::Padding::Zero,
+                ordinal_suffix: false,
             }),
             BorrowedFormatItem::Literal(b"/"),
             component!(Year {
@@ -419,6 +521,9 @@ fn parse_component(
                 range: modifier::YearRange::Extended,
                 iso_week_based: false,
                 sign_is_mandatory: false,
+                width: modifier::YearWidth::Automatic,
+                group_separator: modifier::YearGroupSeparator::None,
+                notation: modifier::YearNotation::Astronomical,
             }),
         ]),
         b'X' => BorrowedFormatItem::Compound(&[
@@ -441,6 +546,9 @@ fn parse_component(
             range: modifier::YearRange::Extended,
             iso_week_based: false,
             sign_is_mandatory: false,
+            width: modifier::YearWidth::Automatic,
+            group_separator: modifier::YearGroupSeparator::None,
+            notation: modifier::YearNotation::Astronomical,
         }),
         b'Y' => component!(Year {
             padding: modifier::Padding::Zero,
@@ -448,16 +556,81 @@ fn parse_component(
             range: modifier::YearRange::Extended,
             iso_week_based: false,
             sign_is_mandatory: false,
+            width: modifier::YearWidth::Automatic,
+            group_separator: modifier::YearGroupSeparator::None,
+            notation: modifier::YearNotation::Astronomical,
         }),
-        b'z' => BorrowedFormatItem::Compound(&[
-            component!(OffsetHour {
+        b'z' => match *flag {
+            Flag::None => BorrowedFormatItem::Compound(&[
+                component!(OffsetHour {
+                    sign_is_mandatory: true,
+                    padding: modifier::Padding::Zero,
+                }),
+                component!(OffsetMinute {
+                    padding: modifier::Padding::Zero,
+                }),
+            ]),
+            Flag::Colons(1) => BorrowedFormatItem::Compound(&[
+                component!(OffsetHour {
+                    sign_is_mandatory: true,
+                    padding: modifier::Padding::Zero,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(OffsetMinute {
+                    padding: modifier::Padding::Zero,
+                }),
+            ]),
+            Flag::Colons(2) => BorrowedFormatItem::Compound(&[
+                component!(OffsetHour {
+                    sign_is_mandatory: true,
+                    padding: modifier::Padding::Zero,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(OffsetMinute {
+                    padding: modifier::Padding::Zero,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(OffsetSecond {
+                    padding: modifier::Padding::Zero,
+                }),
+            ]),
+            Flag::Colons(3) => component!(OffsetHour {
                 sign_is_mandatory: true,
                 padding: modifier::Padding::Zero,
             }),
-            component!(OffsetMinute {
-                padding: modifier::Padding::Zero,
-            }),
-        ]),
+            // Formats identically to `%z`, but the minute may be omitted or preceded by a colon
+            // when parsing.
+            Flag::Alternate => BorrowedFormatItem::Compound(&[
+                component!(OffsetHour {
+                    sign_is_mandatory: true,
+                    padding: modifier::Padding::Zero,
+                }),
+                BorrowedFormatItem::Optional(&BorrowedFormatItem::First(&[
+                    component!(OffsetMinute {
+                        padding: modifier::Padding::Zero,
+                    }),
+                    BorrowedFormatItem::Compound(&[
+                        BorrowedFormatItem::Literal(b":"),
+                        component!(OffsetMinute {
+                            padding: modifier::Padding::Zero,
+                        }),
+                    ]),
+                ])),
+            ]),
+            Flag::Colons(_) => {
+                return Err(Error {
+                    _inner: unused(ErrorInner {
+                        _message: "too many colons",
+                        _span: flag.span,
+                    }),
+                    public: InvalidFormatDescription::NotSupported {
+                        what: "flag",
+                        context: "",
+                        index: flag.span.start.byte as _,
+                    },
+                })
+            }
+        },
         b'Z' => {
             return Err(Error {
                 _inner: unused(ErrorInner {
@@ -0,0 +1,66 @@
+//! A selection of commonly used well-known formats.
+
+/// A selection of commonly used well-known formats.
+///
+/// Each variant behaves identically to the format it names. Unlike a format description, this type
+/// is `Copy`, neither borrows nor allocates, and can be matched on, making it suitable for storing
+/// in configuration.
+///
+/// # Examples
+#[cfg_attr(all(feature = "formatting", feature = "parsing"), doc = "```rust")]
+#[cfg_attr(
+    not(all(feature = "formatting", feature = "parsing")),
+    doc = "```rust,ignore"
+)]
+/// # use time::format_description::well_known::CommonFormat;
+/// # use time::Date;
+/// # use time_macros::{date, datetime};
+/// #[derive(Clone, Copy)]
+/// struct Config {
+///     date_format: CommonFormat,
+///     timestamp_format: CommonFormat,
+/// }
+///
+/// let config = Config {
+///     date_format: CommonFormat::IsoDate,
+///     timestamp_format: CommonFormat::Rfc3339,
+/// };
+///
+/// assert_eq!(date!(2024-05-01).format(&config.date_format)?, "2024-05-01");
+/// assert_eq!(
+///     datetime!(2024-05-01 10:30 UTC).format(&config.timestamp_format)?,
+///     "2024-05-01T10:30:00Z"
+/// );
+/// assert_eq!(
+///     Date::parse("2024-05-01", &config.date_format)?,
+///     date!(2024-05-01)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommonFormat {
+    /// The format described in RFC 3339. See [`Rfc3339`](super::Rfc3339).
+    Rfc3339,
+    /// The format described in RFC 2822. See [`Rfc2822`](super::Rfc2822).
+    #[cfg(not(time_no_names))]
+    Rfc2822,
+    /// The HTTP-date format described in RFC 9110. See [`HttpDate`](super::HttpDate).
+    #[cfg(not(time_no_names))]
+    HttpDate,
+    /// The date, time, and UTC offset in the format described in ISO 8601. See
+    /// [`Iso8601::DEFAULT`](super::Iso8601::DEFAULT).
+    Iso8601,
+    /// The date in the format described in ISO 8601. See
+    /// [`Iso8601::DATE`](super::Iso8601::DATE).
+    IsoDate,
+    /// The time in the format described in ISO 8601. See
+    /// [`Iso8601::TIME`](super::Iso8601::TIME).
+    IsoTime,
+    /// The date and time in the format described in ISO 8601. See
+    /// [`Iso8601::DATE_TIME`](super::Iso8601::DATE_TIME).
+    IsoDateTime,
+    /// The UTC offset in the format described in ISO 8601. See
+    /// [`Iso8601::OFFSET`](super::Iso8601::OFFSET).
+    IsoOffset,
+}
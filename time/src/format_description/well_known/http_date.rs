@@ -0,0 +1,53 @@
+//! The HTTP-date format described in RFC 9110.
+
+/// The HTTP-date format described in [RFC 9110], as used by headers such as `Date`,
+/// `Last-Modified`, and `Expires`.
+///
+/// Example: Sun, 06 Nov 1994 08:49:37 GMT
+///
+/// Formatting always produces the preferred IMF-fixdate form. The value is converted to UTC
+/// beforehand, as HTTP dates are always expressed in GMT. Parsing additionally accepts the two
+/// obsolete forms that recipients are required to support: the RFC 850 form (`Sunday, 06-Nov-94
+/// 08:49:37 GMT`) and the ANSI C `asctime()` form (`Sun Nov  6 08:49:37 1994`). A two-digit year
+/// in the RFC 850 form is interpreted as 2000–2049 if it is less than 50 and 1950–1999 otherwise.
+///
+/// This fixed pivot deviates from [RFC 9110], which interprets a two-digit year relative to the
+/// current date: a year that appears to be more than 50 years in the future is instead the most
+/// recent past year with the same last two digits. The results differ for some years, such as `50`
+/// being interpreted as 1950 rather than 2050. The fixed pivot is used so that parsing does not
+/// depend on the system clock, which is not available without the `std` feature.
+///
+/// [RFC 9110]: https://www.rfc-editor.org/rfc/rfc9110#section-5.6.7
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::HttpDate, OffsetDateTime};
+/// use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::HttpDate;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(1994-11-06 10:49:37 +02:00).format(&HttpDate)?,
+///     "Sun, 06 Nov 1994 08:49:37 GMT"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HttpDate;
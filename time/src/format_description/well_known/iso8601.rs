@@ -4,6 +4,8 @@ mod adt_hack;
 
 use core::num::NonZeroU8;
 
+use crate::Rounding;
+
 #[doc(hidden, no_inline)]
 pub use self::adt_hack::DoNotRelyOnWhatThisIs;
 pub use self::adt_hack::EncodedConfig;
@@ -111,11 +113,11 @@ pub enum FormattedComponents {
 /// Which format to use for the date.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DateKind {
-    /// Use the year-month-day format.
+    /// Use the year-month-day format, such as `2021-02-04`.
     Calendar,
-    /// Use the year-week-weekday format.
+    /// Use the year-week-weekday format, such as `2021-W05-4`.
     Week,
-    /// Use the week-ordinal format.
+    /// Use the year-ordinal format, such as `2021-035`.
     Ordinal,
 }
 
@@ -151,12 +153,9 @@ pub enum OffsetPrecision {
     Minute,
 }
 
-/// Configuration for [`Iso8601`].
-// This is only used as a const generic, so there's no need to have a number of implementations on
-// it.
-#[allow(missing_copy_implementations)]
+/// Configuration for [`Iso8601`] and [`Iso8601Runtime`].
 #[doc(alias = "EncodedConfig")] // People will likely search for `EncodedConfig`, so show them this.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Config {
     /// Which components, if any, will be formatted.
     pub(crate) formatted_components: FormattedComponents,
@@ -170,6 +169,12 @@ pub struct Config {
     pub(crate) time_precision: TimePrecision,
     /// The precision for the UTC offset.
     pub(crate) offset_precision: OffsetPrecision,
+    /// Whether a space is accepted in place of `T` between the date and time when parsing.
+    pub(crate) allow_space_separator: bool,
+    /// Whether midnight is formatted as `24:00` of the preceding day.
+    pub(crate) midnight_as_24: bool,
+    /// How the time is rounded when the decimal digits of the seconds are limited.
+    pub(crate) subsecond_rounding: Rounding,
 }
 
 impl Config {
@@ -184,6 +189,9 @@ impl Config {
     /// - The date uses the calendar format.
     /// - The time has precision to the second and nine decimal digits.
     /// - The UTC offset has precision to the minute.
+    /// - When parsing, the date and time must be separated by `T`.
+    /// - Midnight is formatted as `00:00` of the day that is beginning.
+    /// - Digits of the seconds beyond the configured decimal digits are truncated.
     ///
     /// If you need different behavior, use the setter methods on this struct.
     pub const DEFAULT: Self = Self {
@@ -195,6 +203,9 @@ impl Config {
             decimal_digits: NonZeroU8::new(9),
         },
         offset_precision: OffsetPrecision::Minute,
+        allow_space_separator: false,
+        midnight_as_24: false,
+        subsecond_rounding: Rounding::TowardZero,
     };
 
     /// A configuration that can only be used for parsing. Using this to format a value is
@@ -208,6 +219,9 @@ impl Config {
             decimal_digits: None,
         },
         offset_precision: OffsetPrecision::Hour,
+        allow_space_separator: false,
+        midnight_as_24: false,
+        subsecond_rounding: Rounding::TowardZero,
     };
 
     /// Set whether the format the date, time, and/or UTC offset.
@@ -235,6 +249,21 @@ impl Config {
     }
 
     /// Set the format used for the date.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::iso8601::{
+    /// #     Config, DateKind, EncodedConfig, FormattedComponents,
+    /// # };
+    /// # use time::format_description::well_known::Iso8601;
+    /// # use time_macros::date;
+    /// const DATE: Config = Config::DEFAULT.set_formatted_components(FormattedComponents::Date);
+    /// const WEEK: EncodedConfig = DATE.set_date_kind(DateKind::Week).encode();
+    /// const ORDINAL: EncodedConfig = DATE.set_date_kind(DateKind::Ordinal).encode();
+    ///
+    /// assert_eq!(date!(2021-02-03).format(&Iso8601::<WEEK>)?, "2021-W05-3");
+    /// assert_eq!(date!(2021-02-04).format(&Iso8601::<ORDINAL>)?, "2021-035");
+    /// # Ok::<_, time::Error>(())
+    /// ```
     pub const fn set_date_kind(self, date_kind: DateKind) -> Self {
         Self { date_kind, ..self }
     }
@@ -254,4 +283,174 @@ impl Config {
             ..self
         }
     }
+
+    /// Set whether a space is accepted in place of `T` between the date and time when parsing.
+    /// This has no effect on formatting, which always uses `T`.
+    pub const fn set_allow_space_separator(self, allow_space_separator: bool) -> Self {
+        Self {
+            allow_space_separator,
+            ..self
+        }
+    }
+
+    /// Set whether midnight is formatted as `24:00` of the preceding day, representing the end of
+    /// that day, rather than `00:00` of the day that is beginning. This has no effect on parsing,
+    /// where `24:00` is accepted only if [carrying is
+    /// allowed](crate::parsing::ParserOptions::with_carry_allowed).
+    ///
+    /// Formatting midnight on the first day supported by this crate fails when the date is
+    /// formatted, as the preceding day cannot be represented.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::iso8601::{Config, EncodedConfig};
+    /// # use time::format_description::well_known::Iso8601;
+    /// # use time_macros::datetime;
+    /// const END_OF_DAY: EncodedConfig = Config::DEFAULT.set_midnight_as_24(true).encode();
+    ///
+    /// assert_eq!(
+    ///     datetime!(2021-01-02 0:00 UTC).format(&Iso8601::<END_OF_DAY>)?,
+    ///     "2021-01-01T24:00:00.000000000Z"
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2021-01-02 0:00:01 UTC).format(&Iso8601::<END_OF_DAY>)?,
+    ///     "2021-01-02T00:00:01.000000000Z"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn set_midnight_as_24(self, midnight_as_24: bool) -> Self {
+        Self {
+            midnight_as_24,
+            ..self
+        }
+    }
+
+    /// Set how the time is rounded when formatting it with precision to the second and fewer than
+    /// nine decimal digits. By default, the extra digits are truncated. Rounding may carry into the
+    /// seconds, minutes, hours, and date. This has no effect on parsing, nor on fractional hours or
+    /// minutes, which are always truncated.
+    ///
+    /// As the time is never negative, [`Rounding::Floor`] is the same as [`Rounding::TowardZero`]
+    /// and [`Rounding::Ceiling`] is the same as [`Rounding::AwayFromZero`].
+    ///
+    /// Only ISO 8601 formatting supports rounding. The `[subsecond]` component of a format
+    /// description always truncates.
+    ///
+    /// ```rust
+    /// # use core::num::NonZeroU8;
+    /// # use time::format_description::well_known::iso8601::{Config, EncodedConfig, TimePrecision};
+    /// # use time::format_description::well_known::Iso8601;
+    /// # use time::Rounding;
+    /// # use time_macros::datetime;
+    /// const MILLIS: Config = Config::DEFAULT.set_time_precision(TimePrecision::Second {
+    ///     decimal_digits: NonZeroU8::new(3),
+    /// });
+    /// const TRUNCATE: EncodedConfig = MILLIS.encode();
+    /// const HALF_UP: EncodedConfig = MILLIS
+    ///     .set_subsecond_rounding(Rounding::HalfAwayFromZero)
+    ///     .encode();
+    ///
+    /// let datetime = datetime!(2021-01-02 23:59:59.999_5 UTC);
+    /// assert_eq!(
+    ///     datetime.format(&Iso8601::<TRUNCATE>)?,
+    ///     "2021-01-02T23:59:59.999Z"
+    /// );
+    /// assert_eq!(
+    ///     datetime.format(&Iso8601::<HALF_UP>)?,
+    ///     "2021-01-03T00:00:00.000Z"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn set_subsecond_rounding(self, subsecond_rounding: Rounding) -> Self {
+        Self {
+            subsecond_rounding,
+            ..self
+        }
+    }
+
+    /// Whether the date should be formatted.
+    #[cfg(feature = "formatting")]
+    pub(crate) const fn formats_date(&self) -> bool {
+        matches!(
+            self.formatted_components,
+            FormattedComponents::Date
+                | FormattedComponents::DateTime
+                | FormattedComponents::DateTimeOffset
+        )
+    }
+
+    /// Whether the time should be formatted.
+    #[cfg(feature = "formatting")]
+    pub(crate) const fn formats_time(&self) -> bool {
+        matches!(
+            self.formatted_components,
+            FormattedComponents::Time
+                | FormattedComponents::DateTime
+                | FormattedComponents::DateTimeOffset
+                | FormattedComponents::TimeOffset
+        )
+    }
+
+    /// Whether the UTC offset should be formatted.
+    #[cfg(feature = "formatting")]
+    pub(crate) const fn formats_offset(&self) -> bool {
+        matches!(
+            self.formatted_components,
+            FormattedComponents::Offset
+                | FormattedComponents::DateTimeOffset
+                | FormattedComponents::TimeOffset
+        )
+    }
+}
+
+/// The format described in ISO 8601, configured at runtime.
+///
+/// This behaves identically to [`Iso8601`], but the [`Config`] is stored as a value rather than
+/// encoded in a const parameter. This is useful when the configuration is not known until runtime,
+/// such as when it is read from a user's settings.
+///
+/// # Examples
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::iso8601::{Config, TimePrecision};
+/// # use time::format_description::well_known::Iso8601Runtime;
+/// # use time_macros::datetime;
+/// # use core::num::NonZeroU8;
+/// let decimal_digits = NonZeroU8::new(3);
+/// let format = Iso8601Runtime::new(
+///     Config::DEFAULT.set_time_precision(TimePrecision::Second { decimal_digits }),
+/// );
+/// assert_eq!(
+///     datetime!(1997-11-12 9:55:06 -6:00).format(&format)?,
+///     "1997-11-12T09:55:06.000-06:00"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Iso8601Runtime {
+    /// The configuration used when formatting and parsing.
+    config: Config,
+}
+
+impl Iso8601Runtime {
+    /// Create a new ISO 8601 format with the provided configuration.
+    pub const fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    /// Obtain the configuration used when formatting and parsing.
+    pub const fn config(&self) -> &Config {
+        &self.config
+    }
+}
+
+impl Default for Iso8601Runtime {
+    fn default() -> Self {
+        Self::new(Config::DEFAULT)
+    }
+}
+
+impl<const CONFIG: EncodedConfig> From<Iso8601<CONFIG>> for Iso8601Runtime {
+    fn from(_: Iso8601<CONFIG>) -> Self {
+        Self::new(Config::decode(CONFIG))
+    }
 }
@@ -2,9 +2,10 @@
 
 use core::num::NonZeroU8;
 
-#[cfg(feature = "formatting")]
+#[cfg(any(feature = "formatting", feature = "parsing"))]
 use super::Iso8601;
 use super::{Config, DateKind, FormattedComponents as FC, OffsetPrecision, TimePrecision};
+use crate::Rounding;
 
 // This provides a way to include `EncodedConfig` in documentation without displaying the type it is
 // aliased to.
@@ -17,35 +18,10 @@ pub type DoNotRelyOnWhatThisIs = u128;
 /// notice.
 pub type EncodedConfig = DoNotRelyOnWhatThisIs;
 
-#[cfg(feature = "formatting")]
+#[cfg(any(feature = "formatting", feature = "parsing"))]
 impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     /// The user-provided configuration for the ISO 8601 format.
-    const CONFIG: Config = Config::decode(CONFIG);
-    /// Whether the date should be formatted.
-    pub(crate) const FORMAT_DATE: bool = matches!(
-        Self::CONFIG.formatted_components,
-        FC::Date | FC::DateTime | FC::DateTimeOffset
-    );
-    /// Whether the time should be formatted.
-    pub(crate) const FORMAT_TIME: bool = matches!(
-        Self::CONFIG.formatted_components,
-        FC::Time | FC::DateTime | FC::DateTimeOffset | FC::TimeOffset
-    );
-    /// Whether the UTC offset should be formatted.
-    pub(crate) const FORMAT_OFFSET: bool = matches!(
-        Self::CONFIG.formatted_components,
-        FC::Offset | FC::DateTimeOffset | FC::TimeOffset
-    );
-    /// Whether the year is six digits.
-    pub(crate) const YEAR_IS_SIX_DIGITS: bool = Self::CONFIG.year_is_six_digits;
-    /// Whether the format contains separators (such as `-` or `:`).
-    pub(crate) const USE_SEPARATORS: bool = Self::CONFIG.use_separators;
-    /// Which format to use for the date.
-    pub(crate) const DATE_KIND: DateKind = Self::CONFIG.date_kind;
-    /// The precision and number of decimal digits to use for the time.
-    pub(crate) const TIME_PRECISION: TimePrecision = Self::CONFIG.time_precision;
-    /// The precision for the UTC offset.
-    pub(crate) const OFFSET_PRECISION: OffsetPrecision = Self::CONFIG.offset_precision;
+    pub(crate) const CONFIG: Config = Config::decode(CONFIG);
 }
 
 impl Config {
@@ -89,6 +65,16 @@ impl Config {
             OffsetPrecision::Hour => 0,
             OffsetPrecision::Minute => 1,
         };
+        bytes[7] = self.allow_space_separator as _;
+        bytes[8] = self.midnight_as_24 as _;
+        bytes[9] = match self.subsecond_rounding {
+            Rounding::TowardZero => 0,
+            Rounding::Floor => 1,
+            Rounding::Ceiling => 2,
+            Rounding::AwayFromZero => 3,
+            Rounding::HalfAwayFromZero => 4,
+            Rounding::HalfEven => 5,
+        };
 
         EncodedConfig::from_be_bytes(bytes)
     }
@@ -141,9 +127,28 @@ impl Config {
             1 => OffsetPrecision::Minute,
             _ => panic!("invalid configuration"),
         };
+        let allow_space_separator = match bytes[7] {
+            0 => false,
+            1 => true,
+            _ => panic!("invalid configuration"),
+        };
+        let midnight_as_24 = match bytes[8] {
+            0 => false,
+            1 => true,
+            _ => panic!("invalid configuration"),
+        };
+        let subsecond_rounding = match bytes[9] {
+            0 => Rounding::TowardZero,
+            1 => Rounding::Floor,
+            2 => Rounding::Ceiling,
+            3 => Rounding::AwayFromZero,
+            4 => Rounding::HalfAwayFromZero,
+            5 => Rounding::HalfEven,
+            _ => panic!("invalid configuration"),
+        };
 
         // No `for` loops in `const fn`.
-        let mut idx = 7; // first unused byte
+        let mut idx = 10; // first unused byte
         while idx < EncodedConfig::BITS as usize / 8 {
             assert!(bytes[idx] == 0, "invalid configuration");
             idx += 1;
@@ -156,6 +161,9 @@ impl Config {
             date_kind,
             time_precision,
             offset_precision,
+            allow_space_separator,
+            midnight_as_24,
+            subsecond_rounding,
         }
     }
 }
@@ -174,6 +182,9 @@ mod tests {
                 && a.date_kind == b.date_kind
                 && a.time_precision == b.time_precision
                 && a.offset_precision == b.offset_precision
+                && a.allow_space_separator == b.allow_space_separator
+                && a.midnight_as_24 == b.midnight_as_24
+                && a.subsecond_rounding == b.subsecond_rounding
         }};
     }
 
@@ -223,6 +234,16 @@ mod tests {
         }));
         assert_roundtrip!(Config::DEFAULT.set_offset_precision(OffsetPrecision::Hour));
         assert_roundtrip!(Config::DEFAULT.set_offset_precision(OffsetPrecision::Minute));
+        assert_roundtrip!(Config::DEFAULT.set_allow_space_separator(false));
+        assert_roundtrip!(Config::DEFAULT.set_allow_space_separator(true));
+        assert_roundtrip!(Config::DEFAULT.set_midnight_as_24(false));
+        assert_roundtrip!(Config::DEFAULT.set_midnight_as_24(true));
+        assert_roundtrip!(Config::DEFAULT.set_subsecond_rounding(Rounding::TowardZero));
+        assert_roundtrip!(Config::DEFAULT.set_subsecond_rounding(Rounding::Floor));
+        assert_roundtrip!(Config::DEFAULT.set_subsecond_rounding(Rounding::Ceiling));
+        assert_roundtrip!(Config::DEFAULT.set_subsecond_rounding(Rounding::AwayFromZero));
+        assert_roundtrip!(Config::DEFAULT.set_subsecond_rounding(Rounding::HalfAwayFromZero));
+        assert_roundtrip!(Config::DEFAULT.set_subsecond_rounding(Rounding::HalfEven));
     }
 
     macro_rules! assert_decode_fail {
@@ -242,6 +263,9 @@ mod tests {
         assert_decode_fail!(0x00_00_00_03_00_00_00_00_00_00_00_00_00_00_00_00);
         assert_decode_fail!(0x00_00_00_00_03_00_00_00_00_00_00_00_00_00_00_00);
         assert_decode_fail!(0x00_00_00_00_00_00_02_00_00_00_00_00_00_00_00_00);
-        assert_decode_fail!(0x00_00_00_00_00_00_00_01_00_00_00_00_00_00_00_00);
+        assert_decode_fail!(0x00_00_00_00_00_00_00_02_00_00_00_00_00_00_00_00);
+        assert_decode_fail!(0x00_00_00_00_00_00_00_00_02_00_00_00_00_00_00_00);
+        assert_decode_fail!(0x00_00_00_00_00_00_00_00_00_06_00_00_00_00_00_00);
+        assert_decode_fail!(0x00_00_00_00_00_00_00_00_00_00_01_00_00_00_00_00);
     }
 }
@@ -28,3 +28,65 @@
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Rfc3339;
+
+/// The format described in RFC 3339, with configurable leniency when parsing.
+///
+/// With the default configuration, this behaves identically to [`Rfc3339`]. Each option relaxes
+/// the parser to accept input that is common in practice but not permitted by the RFC. Formatting
+/// is not affected by any option, so formatted values are always valid RFC 3339.
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Rfc3339Lenient;
+/// # use time::OffsetDateTime;
+/// # use time_macros::datetime;
+/// let format = Rfc3339Lenient::DEFAULT.allow_missing_seconds(true);
+/// assert_eq!(
+///     OffsetDateTime::parse("2024-05-01T10:30Z", &format)?,
+///     datetime!(2024-05-01 10:30 UTC)
+/// );
+/// assert!(OffsetDateTime::parse("2024-05-01T10:30Z", &Rfc3339Lenient::DEFAULT).is_err());
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc3339Lenient {
+    /// Whether the seconds may be omitted when parsing.
+    allow_missing_seconds: bool,
+}
+
+impl Rfc3339Lenient {
+    /// A configuration that is no more lenient than [`Rfc3339`].
+    pub const DEFAULT: Self = Self::new();
+
+    /// Create a new configuration that is no more lenient than [`Rfc3339`].
+    pub const fn new() -> Self {
+        Self {
+            allow_missing_seconds: false,
+        }
+    }
+
+    /// Set whether the seconds may be omitted when parsing, as in `2024-05-01T10:30Z`. Missing
+    /// seconds are treated as zero. A subsecond is not permitted without seconds.
+    pub const fn allow_missing_seconds(mut self, allow_missing_seconds: bool) -> Self {
+        self.allow_missing_seconds = allow_missing_seconds;
+        self
+    }
+
+    /// Obtain whether the seconds may be omitted when parsing.
+    pub const fn allows_missing_seconds(&self) -> bool {
+        self.allow_missing_seconds
+    }
+}
+
+impl Default for Rfc3339Lenient {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl From<Rfc3339> for Rfc3339Lenient {
+    fn from(_: Rfc3339) -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,238 @@
+//! The format described in RFC 9557.
+
+#[cfg(feature = "parsing")]
+use core::ops::Range;
+
+#[cfg(feature = "parsing")]
+use crate::error;
+#[cfg(feature = "parsing")]
+use crate::parsing::Parsed;
+
+/// The Internet Extended Date/Time Format (IXDTF) described in [RFC 9557].
+///
+/// Format example: 1996-12-19T16:39:57-08:00[America/Los_Angeles]
+///
+/// This extends [RFC 3339](super::Rfc3339) with a suffix of bracketed annotations. The first
+/// annotation may name a time zone, either as a time zone name or as a numeric offset. Any further
+/// annotations are key-value pairs, such as `[u-ca=gregory]` to indicate the calendar. An
+/// annotation prefixed with `!` is critical, meaning that it must not be ignored.
+///
+/// When formatting, the time zone annotation is written if one has been configured with
+/// [`Rfc9557::with_time_zone`]. The name is not checked against any time zone database, so it is
+/// the caller's responsibility to ensure that it is consistent with the offset of the value.
+///
+/// When parsing, the configuration is ignored. The annotations are checked for validity, and
+/// their locations are available via [`Rfc9557::parse_annotated`]. A critical numeric offset
+/// annotation must match the offset of the timestamp. Critical annotations other than the time
+/// zone and calendar are rejected, as are critical calendars other than `gregory` and `iso8601`.
+/// The offset of the timestamp is always used to determine the value.
+///
+/// [RFC 9557]: https://www.rfc-editor.org/rfc/rfc9557
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Rfc9557;
+/// # use time::parsing::Parsed;
+/// # use time::OffsetDateTime;
+/// # use time_macros::datetime;
+/// let input = "1996-12-19T16:39:57-08:00[America/Los_Angeles][u-ca=gregory]";
+/// assert_eq!(
+///     OffsetDateTime::parse(input, &Rfc9557::DEFAULT)?,
+///     datetime!(1996-12-19 16:39:57 -8)
+/// );
+///
+/// let mut parsed = Parsed::new();
+/// let (_, annotations) = Rfc9557::DEFAULT.parse_annotated(input.as_bytes(), &mut parsed)?;
+/// let time_zone = annotations.time_zone().map(|range| &input[range]);
+/// assert_eq!(time_zone, Some("America/Los_Angeles"));
+/// let calendar = annotations.calendar().map(|range| &input[range]);
+/// assert_eq!(calendar, Some("gregory"));
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Rfc9557;
+/// # use time_macros::datetime;
+/// let value = datetime!(1996-12-19 16:39:57 -8);
+/// assert_eq!(
+///     value.format(&Rfc9557::DEFAULT)?,
+///     "1996-12-19T16:39:57-08:00"
+/// );
+/// assert_eq!(
+///     value.format(&Rfc9557::DEFAULT.with_time_zone("America/Los_Angeles"))?,
+///     "1996-12-19T16:39:57-08:00[America/Los_Angeles]"
+/// );
+/// assert_eq!(
+///     value.format(&Rfc9557::DEFAULT.with_time_zone("-08:00").critical(true))?,
+///     "1996-12-19T16:39:57-08:00[!-08:00]"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc9557<'a> {
+    /// The time zone annotation written when formatting.
+    time_zone: Option<&'a str>,
+    /// Whether the time zone annotation is marked as critical when formatting.
+    critical: bool,
+}
+
+impl Rfc9557<'static> {
+    /// A configuration that formats no annotations.
+    pub const DEFAULT: Self = Self::new();
+
+    /// Create a new configuration that formats no annotations.
+    pub const fn new() -> Self {
+        Self {
+            time_zone: None,
+            critical: false,
+        }
+    }
+}
+
+impl<'a> Rfc9557<'a> {
+    /// Set the time zone annotation that is written when formatting. This may be a time zone name,
+    /// such as `America/New_York`, or a numeric offset, such as `+05:30`.
+    ///
+    /// If the value is not a valid time zone annotation, formatting will fail.
+    pub const fn with_time_zone<'b>(self, time_zone: &'b str) -> Rfc9557<'b> {
+        Rfc9557 {
+            time_zone: Some(time_zone),
+            critical: self.critical,
+        }
+    }
+
+    /// Set whether the time zone annotation is marked as critical when formatting.
+    pub const fn critical(mut self, critical: bool) -> Self {
+        self.critical = critical;
+        self
+    }
+
+    /// Obtain the time zone annotation that is written when formatting, if any.
+    pub const fn time_zone(&self) -> Option<&'a str> {
+        self.time_zone
+    }
+
+    /// Obtain whether the time zone annotation is marked as critical when formatting.
+    pub const fn is_critical(&self) -> bool {
+        self.critical
+    }
+
+    /// Parse an RFC 9557 timestamp into the provided [`Parsed`], returning the remaining input and
+    /// the locations of the annotations. This behaves as [`Parsed::parse`], which discards the
+    /// locations of the annotations.
+    #[cfg(feature = "parsing")]
+    pub fn parse_annotated<'b>(
+        &self,
+        input: &'b [u8],
+        parsed: &mut Parsed,
+    ) -> Result<(&'b [u8], Annotations), error::Parse> {
+        let mut this = *parsed;
+        let (remaining, annotations) = crate::parsing::parsable::parse_rfc9557(
+            input,
+            &mut this,
+            crate::parsing::ParserOptions::DEFAULT,
+        )?;
+        *parsed = this;
+        Ok((remaining, annotations))
+    }
+}
+
+impl Default for Rfc9557<'_> {
+    fn default() -> Self {
+        Rfc9557::DEFAULT
+    }
+}
+
+/// The locations of the annotations of an [RFC 9557] timestamp within the input, as byte offsets.
+/// This is obtained from [`Rfc9557::parse_annotated`].
+///
+/// [RFC 9557]: https://www.rfc-editor.org/rfc/rfc9557
+#[cfg(feature = "parsing")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Annotations {
+    /// The start and end of the time zone annotation.
+    pub(crate) time_zone: Option<(usize, usize)>,
+    /// The start and end of the value of the calendar annotation.
+    pub(crate) calendar: Option<(usize, usize)>,
+}
+
+#[cfg(feature = "parsing")]
+impl Annotations {
+    /// Obtain the location of the time zone annotation, excluding the brackets and any critical
+    /// flag. This is either a time zone name, such as `America/New_York`, or a numeric offset, such
+    /// as `+05:30`.
+    pub const fn time_zone(&self) -> Option<Range<usize>> {
+        match self.time_zone {
+            Some((start, end)) => Some(start..end),
+            None => None,
+        }
+    }
+
+    /// Obtain the location of the value of the calendar annotation (`u-ca`), such as `gregory`. If
+    /// the calendar is annotated more than once, the first is used.
+    pub const fn calendar(&self) -> Option<Range<usize>> {
+        match self.calendar {
+            Some((start, end)) => Some(start..end),
+            None => None,
+        }
+    }
+}
+
+/// Whether the value is a valid `time-zone-name` as defined in RFC 9557.
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+pub(crate) fn is_time_zone_name(value: &[u8]) -> bool {
+    !value.is_empty()
+        && value.split(|&byte| byte == b'/').all(|part| {
+            matches!(part, [b'a'..=b'z' | b'A'..=b'Z' | b'.' | b'_', ..])
+                && part.iter().all(|byte| {
+                    matches!(
+                        byte,
+                        b'a'..=b'z' | b'A'..=b'Z' | b'.' | b'_' | b'0'..=b'9' | b'-' | b'+'
+                    )
+                })
+                && part != b"."
+                && part != b".."
+        })
+}
+
+/// Parse a `time-numoffset` as defined in RFC 9557, returning the offset in minutes.
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+pub(crate) const fn time_numoffset(value: &[u8]) -> Option<i16> {
+    let [sign @ (b'+' | b'-'), hour_tens, hour_ones, b':', minute_tens, minute_ones] = *value
+    else {
+        return None;
+    };
+    if !(hour_tens.is_ascii_digit()
+        && hour_ones.is_ascii_digit()
+        && minute_tens.is_ascii_digit()
+        && minute_ones.is_ascii_digit())
+    {
+        return None;
+    }
+    let hours = ((hour_tens - b'0') * 10 + (hour_ones - b'0')) as i16;
+    let minutes = ((minute_tens - b'0') * 10 + (minute_ones - b'0')) as i16;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    let value = hours * 60 + minutes;
+    Some(if sign == b'-' { -value } else { value })
+}
+
+/// Whether the value is a valid `suffix-key` as defined in RFC 9557.
+#[cfg(feature = "parsing")]
+pub(crate) fn is_suffix_key(value: &[u8]) -> bool {
+    matches!(value, [b'a'..=b'z' | b'_', ..])
+        && value
+            .iter()
+            .all(|byte| matches!(byte, b'a'..=b'z' | b'_' | b'0'..=b'9' | b'-'))
+}
+
+/// Whether the value is a valid `suffix-values` as defined in RFC 9557.
+#[cfg(feature = "parsing")]
+pub(crate) fn is_suffix_values(value: &[u8]) -> bool {
+    value
+        .split(|&byte| byte == b'-')
+        .all(|part| !part.is_empty() && part.iter().all(u8::is_ascii_alphanumeric))
+}
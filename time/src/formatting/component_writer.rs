@@ -0,0 +1,225 @@
+//! The [`ComponentWriter`] struct and its associated `impl`s.
+
+use std::io;
+
+use crate::format_description::modifier;
+use crate::formatting::{
+    fmt_day, fmt_hour, fmt_minute, fmt_month, fmt_offset_hour, fmt_offset_minute,
+    fmt_offset_second, fmt_ordinal, fmt_period, fmt_second, fmt_subsecond, fmt_unix_timestamp,
+    fmt_week_number, fmt_weekday, fmt_year, format_number, write, English,
+};
+use crate::{error, Date, OffsetDateTime, Time, UtcOffset};
+
+/// A writer that formats individual components into an output, one call at a time.
+///
+/// This performs the same formatting as a [format description](crate::format_description), but
+/// without interpreting one. It is intended for building timestamps into existing buffers where
+/// the layout is known ahead of time. Each method returns the number of bytes written, or an error
+/// if writing to the output failed. The names of months and weekdays are always in English.
+///
+/// ```rust
+/// # use time::format_description::modifier;
+/// # use time::formatting::ComponentWriter;
+/// # use time_macros::datetime;
+/// let datetime = datetime!(2021-03-04 05:06:07);
+/// let mut buffer = Vec::new();
+///
+/// let mut writer = ComponentWriter::new(&mut buffer);
+/// writer.write_year(datetime.date(), modifier::Year::default())?;
+/// writer.write_literal(b"-")?;
+/// writer.write_month(datetime.date(), modifier::Month::default())?;
+/// writer.write_literal(b"-")?;
+/// writer.write_day(datetime.date(), modifier::Day::default())?;
+/// writer.write_literal(b" ")?;
+/// writer.write_two_digits(datetime.hour(), modifier::Padding::Zero)?;
+/// writer.write_literal(b":")?;
+/// writer.write_two_digits(datetime.minute(), modifier::Padding::Zero)?;
+///
+/// assert_eq!(buffer, b"2021-03-04 05:06");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct ComponentWriter<W> {
+    /// The output being written to.
+    output: W,
+}
+
+impl<W: io::Write> ComponentWriter<W> {
+    /// Create a writer that writes to the provided output.
+    pub const fn new(output: W) -> Self {
+        Self { output }
+    }
+
+    /// Obtain a reference to the output.
+    pub const fn get_ref(&self) -> &W {
+        &self.output
+    }
+
+    /// Obtain a mutable reference to the output.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.output
+    }
+
+    /// Consume the writer, returning the output.
+    pub fn into_inner(self) -> W {
+        self.output
+    }
+
+    /// Write the bytes as-is.
+    pub fn write_literal(&mut self, bytes: &[u8]) -> Result<usize, error::Format> {
+        Ok(write(&mut self.output, bytes)?)
+    }
+
+    /// Write a number that is padded to a width of two digits. Values larger than 99 are written
+    /// in full.
+    pub fn write_two_digits(
+        &mut self,
+        value: u8,
+        padding: modifier::Padding,
+    ) -> Result<usize, error::Format> {
+        Ok(format_number::<2>(&mut self.output, value, padding)?)
+    }
+
+    /// Write the day of the month of the date.
+    pub fn write_day(
+        &mut self,
+        date: Date,
+        modifier: modifier::Day,
+    ) -> Result<usize, error::Format> {
+        Ok(fmt_day(&mut self.output, date, modifier)?)
+    }
+
+    /// Write the month of the date.
+    pub fn write_month(
+        &mut self,
+        date: Date,
+        modifier: modifier::Month,
+    ) -> Result<usize, error::Format> {
+        fmt_month(&mut self.output, date, modifier, &English)
+    }
+
+    /// Write the day of the year of the date.
+    pub fn write_ordinal(
+        &mut self,
+        date: Date,
+        modifier: modifier::Ordinal,
+    ) -> Result<usize, error::Format> {
+        Ok(fmt_ordinal(&mut self.output, date, modifier)?)
+    }
+
+    /// Write the weekday of the date.
+    pub fn write_weekday(
+        &mut self,
+        date: Date,
+        modifier: modifier::Weekday,
+    ) -> Result<usize, error::Format> {
+        fmt_weekday(&mut self.output, date, modifier, &English)
+    }
+
+    /// Write the week number of the date.
+    pub fn write_week_number(
+        &mut self,
+        date: Date,
+        modifier: modifier::WeekNumber,
+    ) -> Result<usize, error::Format> {
+        Ok(fmt_week_number(&mut self.output, date, modifier)?)
+    }
+
+    /// Write the year of the date.
+    ///
+    /// An error is returned if the year cannot be represented with the provided modifier.
+    pub fn write_year(
+        &mut self,
+        date: Date,
+        modifier: modifier::Year,
+    ) -> Result<usize, error::Format> {
+        fmt_year(&mut self.output, date, modifier)
+    }
+
+    /// Write the hour of the time.
+    pub fn write_hour(
+        &mut self,
+        time: Time,
+        modifier: modifier::Hour,
+    ) -> Result<usize, error::Format> {
+        Ok(fmt_hour(&mut self.output, time, modifier)?)
+    }
+
+    /// Write the minute of the time.
+    pub fn write_minute(
+        &mut self,
+        time: Time,
+        modifier: modifier::Minute,
+    ) -> Result<usize, error::Format> {
+        Ok(fmt_minute(&mut self.output, time, modifier)?)
+    }
+
+    /// Write the period (AM/PM) of the time.
+    pub fn write_period(
+        &mut self,
+        time: Time,
+        modifier: modifier::Period,
+    ) -> Result<usize, error::Format> {
+        Ok(fmt_period(&mut self.output, time, modifier)?)
+    }
+
+    /// Write the second of the time.
+    pub fn write_second(
+        &mut self,
+        time: Time,
+        modifier: modifier::Second,
+    ) -> Result<usize, error::Format> {
+        Ok(fmt_second(&mut self.output, time, modifier)?)
+    }
+
+    /// Write the subsecond of the time.
+    pub fn write_subsecond(
+        &mut self,
+        time: Time,
+        modifier: modifier::Subsecond,
+    ) -> Result<usize, error::Format> {
+        Ok(fmt_subsecond(&mut self.output, time, modifier)?)
+    }
+
+    /// Write the hour of the offset.
+    pub fn write_offset_hour(
+        &mut self,
+        offset: UtcOffset,
+        modifier: modifier::OffsetHour,
+    ) -> Result<usize, error::Format> {
+        Ok(fmt_offset_hour(&mut self.output, offset, modifier)?)
+    }
+
+    /// Write the minute of the offset.
+    pub fn write_offset_minute(
+        &mut self,
+        offset: UtcOffset,
+        modifier: modifier::OffsetMinute,
+    ) -> Result<usize, error::Format> {
+        Ok(fmt_offset_minute(&mut self.output, offset, modifier)?)
+    }
+
+    /// Write the second of the offset.
+    pub fn write_offset_second(
+        &mut self,
+        offset: UtcOffset,
+        modifier: modifier::OffsetSecond,
+    ) -> Result<usize, error::Format> {
+        Ok(fmt_offset_second(&mut self.output, offset, modifier)?)
+    }
+
+    /// Write the Unix timestamp of the date-time.
+    pub fn write_unix_timestamp(
+        &mut self,
+        date_time: OffsetDateTime,
+        modifier: modifier::UnixTimestamp,
+    ) -> Result<usize, error::Format> {
+        Ok(fmt_unix_timestamp(
+            &mut self.output,
+            date_time.date(),
+            date_time.time(),
+            date_time.offset(),
+            modifier,
+        )?)
+    }
+}
@@ -1,18 +1,31 @@
 //! A trait that can be used to format an item from its components.
 
+use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::fmt;
 use core::ops::Deref;
+use core::panic::{RefUnwindSafe, UnwindSafe};
 use std::io;
 
+#[cfg(not(time_no_names))]
 use num_conv::prelude::*;
 
 use crate::format_description::well_known::iso8601::EncodedConfig;
-use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
-use crate::format_description::{BorrowedFormatItem, OwnedFormatItem};
+use crate::format_description::well_known::rfc9557::{is_time_zone_name, time_numoffset};
+#[cfg(not(time_no_names))]
+use crate::format_description::well_known::{HttpDate, Rfc2822};
+use crate::format_description::well_known::{
+    CommonFormat, Iso8601, Iso8601Runtime, Rfc3339, Rfc3339Lenient, Rfc9557,
+};
+use crate::format_description::{BorrowedFormatItem, Custom, OwnedFormatItem};
 use crate::formatting::{
-    format_component, format_number_pad_zero, iso8601, write, MONTH_NAMES, WEEKDAY_NAMES,
+    format_component, format_number_pad_zero, iso8601, write, English, Locale, WriteBuffer,
 };
+#[cfg(not(time_no_names))]
+use crate::formatting::{Localized, MONTH_NAMES, WEEKDAY_NAMES};
+#[cfg(not(time_no_names))]
+use crate::OffsetDateTime;
 use crate::{error, Date, Time, UtcOffset};
 
 /// A type that describes a format.
@@ -21,6 +34,15 @@ use crate::{error, Date, Time, UtcOffset};
 ///
 /// [`Date::format`] and [`Time::format`] each use a format description to generate
 /// a String from their data. See the respective methods for usage examples.
+///
+/// This trait is sealed and cannot be implemented outside of this crate. To define a new format,
+/// implement [`CustomFormattable`] and wrap the type in [`Custom`].
+///
+/// ```compile_fail
+/// struct MyFormat;
+///
+/// impl time::formatting::Formattable for MyFormat {}
+/// ```
 #[cfg_attr(docsrs, doc(notable_trait))]
 pub trait Formattable: sealed::Sealed {}
 impl Formattable for BorrowedFormatItem<'_> {}
@@ -28,10 +50,137 @@ impl Formattable for [BorrowedFormatItem<'_>] {}
 impl Formattable for OwnedFormatItem {}
 impl Formattable for [OwnedFormatItem] {}
 impl Formattable for Rfc3339 {}
+impl Formattable for Rfc3339Lenient {}
+impl Formattable for Rfc9557<'_> {}
+#[cfg(not(time_no_names))]
+impl Formattable for HttpDate {}
+#[cfg(not(time_no_names))]
 impl Formattable for Rfc2822 {}
 impl<const CONFIG: EncodedConfig> Formattable for Iso8601<CONFIG> {}
+impl Formattable for Iso8601Runtime {}
+impl Formattable for CommonFormat {}
+#[cfg(not(time_no_names))]
+impl<D: Formattable, L: Locale> Formattable for Localized<D, L> {}
+impl<T: CustomFormattable> Formattable for Custom<T> {}
+impl Formattable for BoxedFormat<'_> {}
 impl<T: Deref> Formattable for T where T::Target: Formattable {}
 
+/// A format that is defined outside of this crate. Wrap the type in [`Custom`] to use it as a
+/// [`Formattable`].
+///
+/// See [`Custom`] for an example.
+pub trait CustomFormattable {
+    /// Format the provided components into the output, returning the number of bytes written.
+    ///
+    /// Components are `None` when the value being formatted does not have them, such as the
+    /// offset of a [`PrimitiveDateTime`](crate::PrimitiveDateTime). If a component that the format
+    /// requires is not present, [`error::Format::InsufficientTypeInformation`] should be returned,
+    /// which can be obtained via [`error::Format::insufficient_type_information`].
+    fn format_into(
+        &self,
+        output: &mut dyn io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format>;
+}
+
+/// A [`Formattable`] whose concrete type has been erased, allowing formats of different types to
+/// be stored together and selected at runtime.
+///
+/// [`Formattable`] has generic methods, so `dyn Formattable` cannot be used. Wrap any format in
+/// `BoxedFormat` instead. [`Parsable`](crate::parsing::Parsable) does not have this restriction,
+/// so `Box<dyn Parsable>` can be used directly.
+///
+/// Like all types in this crate, `BoxedFormat` is `Send`, `Sync`, and unwind safe, so the wrapped
+/// format must be as well.
+///
+/// ```rust
+/// # use time::format_description::well_known::{Rfc2822, Rfc3339};
+/// # use time::formatting::BoxedFormat;
+/// # use time_macros::{datetime, format_description};
+/// let formats = vec![
+///     BoxedFormat::new(Rfc3339),
+///     BoxedFormat::new(Rfc2822),
+///     BoxedFormat::new(format_description!("[year]-[month]-[day]")),
+/// ];
+/// let datetime = datetime!(2021-01-02 03:04:05 UTC);
+/// assert_eq!(
+///     formats
+///         .iter()
+///         .map(|format| datetime.format(format))
+///         .collect::<Result<Vec<_>, _>>()?,
+///     [
+///         "2021-01-02T03:04:05Z",
+///         "Sat, 02 Jan 2021 03:04:05 +0000",
+///         "2021-01-02",
+///     ]
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+pub struct BoxedFormat<'a>(
+    Box<dyn ErasedFormattable + Send + Sync + RefUnwindSafe + UnwindSafe + 'a>,
+);
+
+impl<'a> BoxedFormat<'a> {
+    /// Erase the type of the provided format.
+    pub fn new(format: impl Formattable + Send + Sync + RefUnwindSafe + UnwindSafe + 'a) -> Self {
+        Self(Box::new(format))
+    }
+}
+
+impl fmt::Debug for BoxedFormat<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxedFormat").finish_non_exhaustive()
+    }
+}
+
+/// An object-safe equivalent of the sealed trait, used to implement [`BoxedFormat`].
+trait ErasedFormattable {
+    /// Format the item into the provided output, returning the number of bytes written.
+    fn format_into_dyn(
+        &self,
+        output: &mut dyn io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format>;
+
+    /// Format the item into the provided output using the provided locale, returning the number
+    /// of bytes written.
+    fn format_into_localized_dyn(
+        &self,
+        output: &mut dyn io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &dyn Locale,
+    ) -> Result<usize, error::Format>;
+}
+
+impl<T: sealed::Sealed> ErasedFormattable for T {
+    fn format_into_dyn(
+        &self,
+        mut output: &mut dyn io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        sealed::Sealed::format_into(self, &mut output, date, time, offset)
+    }
+
+    fn format_into_localized_dyn(
+        &self,
+        mut output: &mut dyn io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &dyn Locale,
+    ) -> Result<usize, error::Format> {
+        sealed::Sealed::format_into_localized(self, &mut output, date, time, offset, locale)
+    }
+}
+
 /// Seal the trait to prevent downstream users from implementing it.
 mod sealed {
     #[allow(clippy::wildcard_imports)]
@@ -48,6 +197,47 @@ mod sealed {
             offset: Option<UtcOffset>,
         ) -> Result<usize, error::Format>;
 
+        /// Format the item into the provided output, obtaining the names of months and weekdays
+        /// from the provided locale. Returns the number of bytes written.
+        ///
+        /// Formats that do not use localized names ignore the locale.
+        fn format_into_localized(
+            &self,
+            output: &mut impl io::Write,
+            date: Option<Date>,
+            time: Option<Time>,
+            offset: Option<UtcOffset>,
+            _locale: &(impl Locale + ?Sized),
+        ) -> Result<usize, error::Format> {
+            self.format_into(output, date, time, offset)
+        }
+
+        /// Format the item into the provided output, returning the number of bytes written. Writes
+        /// are batched so that the output receives as few calls as possible.
+        fn format_into_buffered(
+            &self,
+            output: &mut impl io::Write,
+            date: Option<Date>,
+            time: Option<Time>,
+            offset: Option<UtcOffset>,
+        ) -> Result<usize, error::Format> {
+            let mut buffer = WriteBuffer::new(output);
+            let bytes = self.format_into(&mut buffer, date, time, offset)?;
+            buffer.finish()?;
+            Ok(bytes)
+        }
+
+        /// Determine the number of bytes the item would be formatted as, without writing it
+        /// anywhere. This performs a full format, discarding the output.
+        fn formatted_len(
+            &self,
+            date: Option<Date>,
+            time: Option<Time>,
+            offset: Option<UtcOffset>,
+        ) -> Result<usize, error::Format> {
+            self.format_into(&mut io::sink(), date, time, offset)
+        }
+
         /// Format the item directly to a `String`.
         fn format(
             &self,
@@ -70,15 +260,32 @@ impl sealed::Sealed for BorrowedFormatItem<'_> {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.format_into_localized(output, date, time, offset, &English)
+    }
+
+    fn format_into_localized(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &(impl Locale + ?Sized),
     ) -> Result<usize, error::Format> {
         Ok(match *self {
             Self::Literal(literal) => write(output, literal)?,
-            Self::Component(component) => format_component(output, component, date, time, offset)?,
-            Self::Compound(items) => items.format_into(output, date, time, offset)?,
-            Self::Optional(item) => item.format_into(output, date, time, offset)?,
+            Self::Component(component) => {
+                format_component(output, component, date, time, offset, locale)?
+            }
+            Self::Compound(items) => {
+                items.format_into_localized(output, date, time, offset, locale)?
+            }
+            Self::Optional(item) => {
+                item.format_into_localized(output, date, time, offset, locale)?
+            }
             Self::First(items) => match items {
                 [] => 0,
-                [item, ..] => item.format_into(output, date, time, offset)?,
+                [item, ..] => item.format_into_localized(output, date, time, offset, locale)?,
             },
         })
     }
@@ -91,10 +298,21 @@ impl sealed::Sealed for [BorrowedFormatItem<'_>] {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.format_into_localized(output, date, time, offset, &English)
+    }
+
+    fn format_into_localized(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &(impl Locale + ?Sized),
     ) -> Result<usize, error::Format> {
         let mut bytes = 0;
         for item in self.iter() {
-            bytes += item.format_into(output, date, time, offset)?;
+            bytes += item.format_into_localized(output, date, time, offset, locale)?;
         }
         Ok(bytes)
     }
@@ -107,15 +325,30 @@ impl sealed::Sealed for OwnedFormatItem {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.format_into_localized(output, date, time, offset, &English)
+    }
+
+    fn format_into_localized(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &(impl Locale + ?Sized),
     ) -> Result<usize, error::Format> {
         match self {
             Self::Literal(literal) => Ok(write(output, literal)?),
-            Self::Component(component) => format_component(output, *component, date, time, offset),
-            Self::Compound(items) => items.format_into(output, date, time, offset),
-            Self::Optional(item) => item.format_into(output, date, time, offset),
+            Self::Component(component) => {
+                format_component(output, *component, date, time, offset, locale)
+            }
+            Self::Compound(items) => {
+                items.format_into_localized(output, date, time, offset, locale)
+            }
+            Self::Optional(item) => item.format_into_localized(output, date, time, offset, locale),
             Self::First(items) => match &**items {
                 [] => Ok(0),
-                [item, ..] => item.format_into(output, date, time, offset),
+                [item, ..] => item.format_into_localized(output, date, time, offset, locale),
             },
         }
     }
@@ -128,10 +361,21 @@ impl sealed::Sealed for [OwnedFormatItem] {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.format_into_localized(output, date, time, offset, &English)
+    }
+
+    fn format_into_localized(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &(impl Locale + ?Sized),
     ) -> Result<usize, error::Format> {
         let mut bytes = 0;
         for item in self.iter() {
-            bytes += item.format_into(output, date, time, offset)?;
+            bytes += item.format_into_localized(output, date, time, offset, locale)?;
         }
         Ok(bytes)
     }
@@ -150,10 +394,74 @@ where
     ) -> Result<usize, error::Format> {
         self.deref().format_into(output, date, time, offset)
     }
+
+    fn format_into_localized(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &(impl Locale + ?Sized),
+    ) -> Result<usize, error::Format> {
+        self.deref()
+            .format_into_localized(output, date, time, offset, locale)
+    }
+}
+
+#[cfg(not(time_no_names))]
+impl<D: sealed::Sealed, L: Locale> sealed::Sealed for Localized<D, L> {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.description
+            .format_into_localized(output, date, time, offset, &self.locale)
+    }
+}
+
+impl<T: CustomFormattable> sealed::Sealed for Custom<T> {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.0.format_into(output, date, time, offset)
+    }
+}
+
+impl sealed::Sealed for BoxedFormat<'_> {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        self.0.format_into_dyn(output, date, time, offset)
+    }
+
+    fn format_into_localized(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        locale: &(impl Locale + ?Sized),
+    ) -> Result<usize, error::Format> {
+        // A reference to the locale is used, as the locale itself may be unsized.
+        self.0
+            .format_into_localized_dyn(output, date, time, offset, &locale)
+    }
 }
 // endregion custom formats
 
 // region: well-known formats
+#[cfg(not(time_no_names))]
 impl sealed::Sealed for Rfc2822 {
     fn format_into(
         &self,
@@ -179,14 +487,15 @@ impl sealed::Sealed for Rfc2822 {
 
         bytes += write(
             output,
-            &WEEKDAY_NAMES[date.weekday().number_days_from_monday().extend::<usize>()][..3],
+            &WEEKDAY_NAMES[date.weekday().number_days_from_monday().extend::<usize>()].as_bytes()
+                [..3],
         )?;
         bytes += write(output, b", ")?;
         bytes += format_number_pad_zero::<2>(output, day)?;
         bytes += write(output, b" ")?;
         bytes += write(
             output,
-            &MONTH_NAMES[u8::from(month).extend::<usize>() - 1][..3],
+            &MONTH_NAMES[u8::from(month).extend::<usize>() - 1].as_bytes()[..3],
         )?;
         bytes += write(output, b" ")?;
         bytes += format_number_pad_zero::<4>(output, year.cast_unsigned())?;
@@ -205,6 +514,63 @@ impl sealed::Sealed for Rfc2822 {
     }
 }
 
+#[cfg(not(time_no_names))]
+impl sealed::Sealed for HttpDate {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+
+        // HTTP dates are always in GMT.
+        let (date, time) = if offset.is_utc() {
+            (date, time)
+        } else {
+            let datetime = OffsetDateTime::new_in_offset(date, time, offset)
+                .checked_to_offset(UtcOffset::UTC)
+                .ok_or(error::Format::InvalidComponent("year"))?;
+            (datetime.date(), datetime.time())
+        };
+
+        let mut bytes = 0;
+
+        let (year, month, day) = date.to_calendar_date();
+
+        if !(0..10_000).contains(&year) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+
+        bytes += write(
+            output,
+            &WEEKDAY_NAMES[date.weekday().number_days_from_monday().extend::<usize>()].as_bytes()
+                [..3],
+        )?;
+        bytes += write(output, b", ")?;
+        bytes += format_number_pad_zero::<2>(output, day)?;
+        bytes += write(output, b" ")?;
+        bytes += write(
+            output,
+            &MONTH_NAMES[u8::from(month).extend::<usize>() - 1].as_bytes()[..3],
+        )?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<4>(output, year.unsigned_abs())?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<2>(output, time.hour())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.minute())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.second())?;
+        bytes += write(output, b" GMT")?;
+
+        Ok(bytes)
+    }
+}
+
 impl sealed::Sealed for Rfc3339 {
     fn format_into(
         &self,
@@ -281,7 +647,7 @@ impl sealed::Sealed for Rfc3339 {
     }
 }
 
-impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
+impl sealed::Sealed for Rfc3339Lenient {
     fn format_into(
         &self,
         output: &mut impl io::Write,
@@ -289,28 +655,81 @@ impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
         time: Option<Time>,
         offset: Option<UtcOffset>,
     ) -> Result<usize, error::Format> {
-        let mut bytes = 0;
+        Rfc3339.format_into(output, date, time, offset)
+    }
+}
 
-        if Self::FORMAT_DATE {
-            let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
-            bytes += iso8601::format_date::<CONFIG>(output, date)?;
-        }
-        if Self::FORMAT_TIME {
-            let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
-            bytes += iso8601::format_time::<CONFIG>(output, time)?;
-        }
-        if Self::FORMAT_OFFSET {
-            let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
-            bytes += iso8601::format_offset::<CONFIG>(output, offset)?;
+impl sealed::Sealed for Rfc9557<'_> {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        let time_zone = self.time_zone().map(str::as_bytes);
+        if let Some(time_zone) = time_zone {
+            if !is_time_zone_name(time_zone) && time_numoffset(time_zone).is_none() {
+                return Err(error::Format::InvalidComponent("time_zone"));
+            }
         }
 
-        if bytes == 0 {
-            // The only reason there would be no bytes written is if the format was only for
-            // parsing.
-            panic!("attempted to format a parsing-only format description");
+        let mut bytes = Rfc3339.format_into(output, date, time, offset)?;
+
+        if let Some(time_zone) = time_zone {
+            bytes += write(output, if self.is_critical() { b"[!" } else { b"[" })?;
+            bytes += write(output, time_zone)?;
+            bytes += write(output, b"]")?;
         }
 
         Ok(bytes)
     }
 }
+
+impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        iso8601::format_into(output, &Self::CONFIG, date, time, offset)
+    }
+}
+
+impl sealed::Sealed for Iso8601Runtime {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        iso8601::format_into(output, self.config(), date, time, offset)
+    }
+}
+
+impl sealed::Sealed for CommonFormat {
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        match self {
+            Self::Rfc3339 => Rfc3339.format_into(output, date, time, offset),
+            #[cfg(not(time_no_names))]
+            Self::Rfc2822 => Rfc2822.format_into(output, date, time, offset),
+            #[cfg(not(time_no_names))]
+            Self::HttpDate => HttpDate.format_into(output, date, time, offset),
+            Self::Iso8601 => Iso8601::DEFAULT.format_into(output, date, time, offset),
+            Self::IsoDate => Iso8601::DATE.format_into(output, date, time, offset),
+            Self::IsoTime => Iso8601::TIME.format_into(output, date, time, offset),
+            Self::IsoDateTime => Iso8601::DATE_TIME.format_into(output, date, time, offset),
+            Self::IsoOffset => Iso8601::OFFSET.format_into(output, date, time, offset),
+        }
+    }
+}
 // endregion well-known formats
@@ -6,23 +6,110 @@ use num_conv::prelude::*;
 
 use crate::convert::*;
 use crate::format_description::well_known::iso8601::{
-    DateKind, EncodedConfig, OffsetPrecision, TimePrecision,
+    Config, DateKind, OffsetPrecision, TimePrecision,
 };
-use crate::format_description::well_known::Iso8601;
 use crate::formatting::{format_float, format_number_pad_zero, write, write_if, write_if_else};
-use crate::{error, Date, Time, UtcOffset};
+use crate::util::DateAdjustment;
+use crate::{error, Date, Duration, Time, UtcOffset};
+
+/// Format the components of ISO 8601 that are included in the configuration.
+pub(super) fn format_into(
+    output: &mut impl io::Write,
+    config: &Config,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<usize, error::Format> {
+    let mut bytes = 0;
+
+    let (date, time) = round_subsecond(config, date, time)?;
+
+    // Midnight may be formatted as the end of the preceding day.
+    let is_end_of_day =
+        config.midnight_as_24 && config.formats_time() && time == Some(Time::MIDNIGHT);
+
+    if config.formats_date() {
+        let mut date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+        if is_end_of_day {
+            date = date
+                .previous_day()
+                .ok_or(error::Format::InvalidComponent("day"))?;
+        }
+        bytes += format_date(output, config, date)?;
+    }
+    if config.formats_time() {
+        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+        bytes += format_time(output, config, time, is_end_of_day)?;
+    }
+    if config.formats_offset() {
+        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+        bytes += format_offset(output, config, offset)?;
+    }
+
+    if bytes == 0 {
+        // The only reason there would be no bytes written is if the format was only for
+        // parsing.
+        panic!("attempted to format a parsing-only format description");
+    }
+
+    Ok(bytes)
+}
+
+/// Round the time to the number of decimal digits of the seconds in the configuration, carrying
+/// into the date if necessary. Nothing is rounded if the time is not formatted.
+fn round_subsecond(
+    config: &Config,
+    date: Option<Date>,
+    time: Option<Time>,
+) -> Result<(Option<Date>, Option<Time>), error::Format> {
+    if !config.formats_time() {
+        return Ok((date, time));
+    }
+    let (Some(time), TimePrecision::Second { decimal_digits }) = (time, config.time_precision)
+    else {
+        return Ok((date, time));
+    };
+
+    let digits = decimal_digits.map_or(0, |digits| digits.get()).min(9);
+    let divisor = 10_u32.pow(9 - digits.extend::<u32>());
+    let nanosecond = time.nanosecond();
+    let remainder = nanosecond % divisor;
+    let is_odd = if digits == 0 {
+        time.second() % 2 == 1
+    } else {
+        (nanosecond / divisor) % 2 == 1
+    };
+    let rounding = config.subsecond_rounding;
+    if !rounding.rounds_up(remainder, divisor, is_odd) {
+        return Ok((date, Some(time)));
+    }
+
+    let (adjustment, time) =
+        time.adjusting_add(Duration::nanoseconds(i64::from(divisor - remainder)));
+    let date = match (date, adjustment) {
+        (Some(date), DateAdjustment::Next) => match date.next_day() {
+            Some(date) => Some(date),
+            None if config.formats_date() => return Err(error::Format::InvalidComponent("day")),
+            None => Some(date),
+        },
+        (date, _) => date,
+    };
+
+    Ok((date, Some(time)))
+}
 
 /// Format the date portion of ISO 8601.
-pub(super) fn format_date<const CONFIG: EncodedConfig>(
+fn format_date(
     output: &mut impl io::Write,
+    config: &Config,
     date: Date,
 ) -> Result<usize, error::Format> {
     let mut bytes = 0;
 
-    match Iso8601::<CONFIG>::DATE_KIND {
+    match config.date_kind {
         DateKind::Calendar => {
             let (year, month, day) = date.to_calendar_date();
-            if Iso8601::<CONFIG>::YEAR_IS_SIX_DIGITS {
+            if config.year_is_six_digits {
                 bytes += write_if_else(output, year < 0, b"-", b"+")?;
                 bytes += format_number_pad_zero::<6>(output, year.unsigned_abs())?;
             } else if !(0..=9999).contains(&year) {
@@ -30,14 +117,14 @@ pub(super) fn format_date<const CONFIG: EncodedConfig>(
             } else {
                 bytes += format_number_pad_zero::<4>(output, year.cast_unsigned())?;
             }
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b"-")?;
+            bytes += write_if(output, config.use_separators, b"-")?;
             bytes += format_number_pad_zero::<2>(output, u8::from(month))?;
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b"-")?;
+            bytes += write_if(output, config.use_separators, b"-")?;
             bytes += format_number_pad_zero::<2>(output, day)?;
         }
         DateKind::Week => {
             let (year, week, day) = date.to_iso_week_date();
-            if Iso8601::<CONFIG>::YEAR_IS_SIX_DIGITS {
+            if config.year_is_six_digits {
                 bytes += write_if_else(output, year < 0, b"-", b"+")?;
                 bytes += format_number_pad_zero::<6>(output, year.unsigned_abs())?;
             } else if !(0..=9999).contains(&year) {
@@ -45,14 +132,14 @@ pub(super) fn format_date<const CONFIG: EncodedConfig>(
             } else {
                 bytes += format_number_pad_zero::<4>(output, year.cast_unsigned())?;
             }
-            bytes += write_if_else(output, Iso8601::<CONFIG>::USE_SEPARATORS, b"-W", b"W")?;
+            bytes += write_if_else(output, config.use_separators, b"-W", b"W")?;
             bytes += format_number_pad_zero::<2>(output, week)?;
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b"-")?;
+            bytes += write_if(output, config.use_separators, b"-")?;
             bytes += format_number_pad_zero::<1>(output, day.number_from_monday())?;
         }
         DateKind::Ordinal => {
             let (year, day) = date.to_ordinal_date();
-            if Iso8601::<CONFIG>::YEAR_IS_SIX_DIGITS {
+            if config.year_is_six_digits {
                 bytes += write_if_else(output, year < 0, b"-", b"+")?;
                 bytes += format_number_pad_zero::<6>(output, year.unsigned_abs())?;
             } else if !(0..=9999).contains(&year) {
@@ -60,7 +147,7 @@ pub(super) fn format_date<const CONFIG: EncodedConfig>(
             } else {
                 bytes += format_number_pad_zero::<4>(output, year.cast_unsigned())?;
             }
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b"-")?;
+            bytes += write_if(output, config.use_separators, b"-")?;
             bytes += format_number_pad_zero::<3>(output, day)?;
         }
     }
@@ -68,23 +155,27 @@ pub(super) fn format_date<const CONFIG: EncodedConfig>(
     Ok(bytes)
 }
 
-/// Format the time portion of ISO 8601.
-pub(super) fn format_time<const CONFIG: EncodedConfig>(
+/// Format the time portion of ISO 8601. If `is_end_of_day` is set, the time must be midnight and
+/// is formatted as the twenty-fourth hour.
+fn format_time(
     output: &mut impl io::Write,
+    config: &Config,
     time: Time,
+    is_end_of_day: bool,
 ) -> Result<usize, error::Format> {
     let mut bytes = 0;
 
     // The "T" can only be omitted in extended format where there is no date being formatted.
     bytes += write_if(
         output,
-        Iso8601::<CONFIG>::USE_SEPARATORS || Iso8601::<CONFIG>::FORMAT_DATE,
+        config.use_separators || config.formats_date(),
         b"T",
     )?;
 
     let (hours, minutes, seconds, nanoseconds) = time.as_hms_nano();
+    let hours = if is_end_of_day { 24 } else { hours };
 
-    match Iso8601::<CONFIG>::TIME_PRECISION {
+    match config.time_precision {
         TimePrecision::Hour { decimal_digits } => {
             let hours = (hours as f64)
                 + (minutes as f64) / Minute::per(Hour) as f64
@@ -94,7 +185,7 @@ pub(super) fn format_time<const CONFIG: EncodedConfig>(
         }
         TimePrecision::Minute { decimal_digits } => {
             bytes += format_number_pad_zero::<2>(output, hours)?;
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b":")?;
+            bytes += write_if(output, config.use_separators, b":")?;
             let minutes = (minutes as f64)
                 + (seconds as f64) / Second::per(Minute) as f64
                 + (nanoseconds as f64) / Nanosecond::per(Minute) as f64;
@@ -102,9 +193,9 @@ pub(super) fn format_time<const CONFIG: EncodedConfig>(
         }
         TimePrecision::Second { decimal_digits } => {
             bytes += format_number_pad_zero::<2>(output, hours)?;
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b":")?;
+            bytes += write_if(output, config.use_separators, b":")?;
             bytes += format_number_pad_zero::<2>(output, minutes)?;
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b":")?;
+            bytes += write_if(output, config.use_separators, b":")?;
             let seconds = (seconds as f64) + (nanoseconds as f64) / Nanosecond::per(Second) as f64;
             bytes += format_float(output, seconds, 2, decimal_digits)?;
         }
@@ -114,11 +205,12 @@ pub(super) fn format_time<const CONFIG: EncodedConfig>(
 }
 
 /// Format the UTC offset portion of ISO 8601.
-pub(super) fn format_offset<const CONFIG: EncodedConfig>(
+fn format_offset(
     output: &mut impl io::Write,
+    config: &Config,
     offset: UtcOffset,
 ) -> Result<usize, error::Format> {
-    if Iso8601::<CONFIG>::FORMAT_TIME && offset.is_utc() {
+    if config.formats_time() && offset.is_utc() {
         return Ok(write(output, b"Z")?);
     }
 
@@ -131,10 +223,10 @@ pub(super) fn format_offset<const CONFIG: EncodedConfig>(
     bytes += write_if_else(output, offset.is_negative(), b"-", b"+")?;
     bytes += format_number_pad_zero::<2>(output, hours.unsigned_abs())?;
 
-    if Iso8601::<CONFIG>::OFFSET_PRECISION == OffsetPrecision::Hour && minutes != 0 {
+    if config.offset_precision == OffsetPrecision::Hour && minutes != 0 {
         return Err(error::Format::InvalidComponent("offset_minute"));
-    } else if Iso8601::<CONFIG>::OFFSET_PRECISION == OffsetPrecision::Minute {
-        bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b":")?;
+    } else if config.offset_precision == OffsetPrecision::Minute {
+        bytes += write_if(output, config.use_separators, b":")?;
         bytes += format_number_pad_zero::<2>(output, minutes.unsigned_abs())?;
     }
 
@@ -0,0 +1,174 @@
+//! Names of months and weekdays used when formatting.
+
+#[cfg(not(time_no_names))]
+use core::fmt;
+
+#[cfg(not(time_no_names))]
+use num_conv::prelude::*;
+
+#[cfg(not(time_no_names))]
+use crate::formatting::{MONTH_NAMES, WEEKDAY_NAMES};
+#[cfg(not(time_no_names))]
+use crate::{Month, Weekday};
+
+/// A source of the names of months and weekdays.
+///
+/// These names are used by the `[month repr:long]`, `[month repr:short]`, `[weekday repr:long]`,
+/// and `[weekday repr:short]` components of a [format description](crate::format_description).
+/// Well-known formats always use English names, as the relevant specifications require it.
+///
+/// To format using a locale, wrap the format description in [`Localized`].
+///
+/// ```rust
+/// # use time::{Month, Weekday};
+/// # use time::formatting::{Locale, Localized};
+/// # use time_macros::{date, format_description};
+/// struct German;
+///
+/// impl Locale for German {
+///     fn month_name(&self, month: Month) -> &str {
+///         match month {
+///             Month::January => "Januar",
+///             Month::February => "Februar",
+///             Month::March => "März",
+///             // ...
+/// #           _ => unimplemented!(),
+///         }
+///     }
+///
+///     fn month_abbreviation(&self, month: Month) -> &str {
+///         match month {
+///             Month::January => "Jan.",
+///             Month::February => "Feb.",
+///             Month::March => "März",
+///             // ...
+/// #           _ => unimplemented!(),
+///         }
+///     }
+///
+///     fn weekday_name(&self, weekday: Weekday) -> &str {
+///         match weekday {
+///             Weekday::Monday => "Montag",
+///             Weekday::Tuesday => "Dienstag",
+///             // ...
+/// #           _ => unimplemented!(),
+///         }
+///     }
+///
+///     fn weekday_abbreviation(&self, weekday: Weekday) -> &str {
+///         match weekday {
+///             Weekday::Monday => "Mo.",
+///             Weekday::Tuesday => "Di.",
+///             // ...
+/// #           _ => unimplemented!(),
+///         }
+///     }
+/// }
+///
+/// let format = Localized::new(
+///     format_description!("[weekday], [day]. [month repr:long] [year]"),
+///     German,
+/// );
+/// assert_eq!(date!(2021-03-02).format(&format)?, "Dienstag, 02. März 2021");
+/// # Ok::<_, time::Error>(())
+/// ```
+pub trait Locale {
+    /// The full name of the month, such as "January".
+    #[cfg(not(time_no_names))]
+    fn month_name(&self, month: Month) -> &str;
+
+    /// The abbreviated name of the month, such as "Jan".
+    #[cfg(not(time_no_names))]
+    fn month_abbreviation(&self, month: Month) -> &str;
+
+    /// The full name of the weekday, such as "Monday".
+    #[cfg(not(time_no_names))]
+    fn weekday_name(&self, weekday: Weekday) -> &str;
+
+    /// The abbreviated name of the weekday, such as "Mon".
+    #[cfg(not(time_no_names))]
+    fn weekday_abbreviation(&self, weekday: Weekday) -> &str;
+}
+
+impl<L: Locale + ?Sized> Locale for &L {
+    #[cfg(not(time_no_names))]
+    fn month_name(&self, month: Month) -> &str {
+        (**self).month_name(month)
+    }
+
+    #[cfg(not(time_no_names))]
+    fn month_abbreviation(&self, month: Month) -> &str {
+        (**self).month_abbreviation(month)
+    }
+
+    #[cfg(not(time_no_names))]
+    fn weekday_name(&self, weekday: Weekday) -> &str {
+        (**self).weekday_name(weekday)
+    }
+
+    #[cfg(not(time_no_names))]
+    fn weekday_abbreviation(&self, weekday: Weekday) -> &str {
+        (**self).weekday_abbreviation(weekday)
+    }
+}
+
+/// The English names of months and weekdays. This is the locale used when none is specified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(time_no_names, allow(unreachable_pub))]
+pub struct English;
+
+#[cfg(not(time_no_names))]
+impl Locale for English {
+    fn month_name(&self, month: Month) -> &str {
+        MONTH_NAMES[u8::from(month).extend::<usize>() - 1]
+    }
+
+    fn month_abbreviation(&self, month: Month) -> &str {
+        &MONTH_NAMES[u8::from(month).extend::<usize>() - 1][..3]
+    }
+
+    fn weekday_name(&self, weekday: Weekday) -> &str {
+        WEEKDAY_NAMES[weekday.number_days_from_monday().extend::<usize>()]
+    }
+
+    fn weekday_abbreviation(&self, weekday: Weekday) -> &str {
+        &WEEKDAY_NAMES[weekday.number_days_from_monday().extend::<usize>()][..3]
+    }
+}
+
+// Names are not available, so the locale has nothing to provide. Textual components are rejected
+// with `error::Format::InvalidComponent` when formatting.
+#[cfg(time_no_names)]
+impl Locale for English {}
+
+/// A format description that obtains the names of months and weekdays from a [`Locale`].
+///
+/// This can be used anywhere a [`Formattable`](crate::formatting::Formattable) is accepted.
+#[cfg(not(time_no_names))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Localized<D, L> {
+    /// The format description being wrapped.
+    pub description: D,
+    /// The locale used to obtain names.
+    pub locale: L,
+}
+
+#[cfg(not(time_no_names))]
+impl<D, L> Localized<D, L> {
+    /// Use the provided locale when formatting with the format description.
+    pub const fn new(description: D, locale: L) -> Self {
+        Self {
+            description,
+            locale,
+        }
+    }
+}
+
+#[cfg(not(time_no_names))]
+impl<D: fmt::Debug, L> fmt::Debug for Localized<D, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Localized")
+            .field("description", &self.description)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,42 +1,54 @@
 //! Formatting for various types.
 
+mod component_writer;
 pub(crate) mod formattable;
 mod iso8601;
+mod locale;
+mod write_buffer;
 
 use core::num::NonZeroU8;
+use core::slice;
 use std::io;
 
 use num_conv::prelude::*;
 
-pub use self::formattable::Formattable;
+pub use self::component_writer::ComponentWriter;
+pub use self::formattable::{BoxedFormat, CustomFormattable, Formattable};
+#[cfg(not(time_no_names))]
+pub use self::locale::{English, Locale, Localized};
+#[cfg(time_no_names)]
+use self::locale::{English, Locale};
+use self::write_buffer::WriteBuffer;
 use crate::convert::*;
 use crate::ext::DigitCount;
 use crate::format_description::{modifier, Component};
-use crate::{error, Date, OffsetDateTime, Time, UtcOffset};
-
-const MONTH_NAMES: [&[u8]; 12] = [
-    b"January",
-    b"February",
-    b"March",
-    b"April",
-    b"May",
-    b"June",
-    b"July",
-    b"August",
-    b"September",
-    b"October",
-    b"November",
-    b"December",
+use crate::{error, util, Date, OffsetDateTime, Time, UtcOffset};
+
+#[cfg(not(time_no_names))]
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
 ];
 
-const WEEKDAY_NAMES: [&[u8]; 7] = [
-    b"Monday",
-    b"Tuesday",
-    b"Wednesday",
-    b"Thursday",
-    b"Friday",
-    b"Saturday",
-    b"Sunday",
+#[cfg(not(time_no_names))]
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
 ];
 
 /// Write all bytes to the output, returning the number of bytes written.
@@ -158,13 +170,14 @@ pub(crate) fn format_component(
     date: Option<Date>,
     time: Option<Time>,
     offset: Option<UtcOffset>,
+    locale: &(impl Locale + ?Sized),
 ) -> Result<usize, error::Format> {
     use Component::*;
     Ok(match (component, date, time, offset) {
         (Day(modifier), Some(date), ..) => fmt_day(output, date, modifier)?,
-        (Month(modifier), Some(date), ..) => fmt_month(output, date, modifier)?,
+        (Month(modifier), Some(date), ..) => fmt_month(output, date, modifier, locale)?,
         (Ordinal(modifier), Some(date), ..) => fmt_ordinal(output, date, modifier)?,
-        (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier)?,
+        (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier, locale)?,
         (WeekNumber(modifier), Some(date), ..) => fmt_week_number(output, date, modifier)?,
         (Year(modifier), Some(date), ..) => fmt_year(output, date, modifier)?,
         (Hour(modifier), _, Some(time), _) => fmt_hour(output, time, modifier)?,
@@ -197,16 +210,26 @@ pub(crate) fn format_component(
 
 // region: date formatters
 /// Format the day into the designated output.
-fn fmt_day(
+pub(crate) fn fmt_day(
     output: &mut impl io::Write,
     date: Date,
-    modifier::Day { padding }: modifier::Day,
+    modifier::Day {
+        padding,
+        ordinal_suffix,
+    }: modifier::Day,
 ) -> Result<usize, io::Error> {
-    format_number::<2>(output, date.day(), padding)
+    let day = date.day();
+    Ok(format_number::<2>(output, day, padding)?
+        + write_if(
+            output,
+            ordinal_suffix,
+            util::ordinal_suffix(day).as_bytes(),
+        )?)
 }
 
 /// Format the month into the designated output.
-fn fmt_month(
+#[cfg_attr(time_no_names, allow(unused_variables))]
+pub(crate) fn fmt_month(
     output: &mut impl io::Write,
     date: Date,
     modifier::Month {
@@ -214,24 +237,27 @@ fn fmt_month(
         repr,
         case_sensitive: _, // no effect on formatting
     }: modifier::Month,
-) -> Result<usize, io::Error> {
-    match repr {
+    locale: &(impl Locale + ?Sized),
+) -> Result<usize, error::Format> {
+    Ok(match repr {
         modifier::MonthRepr::Numerical => {
-            format_number::<2>(output, u8::from(date.month()), padding)
+            format_number::<2>(output, u8::from(date.month()), padding)?
         }
-        modifier::MonthRepr::Long => write(
-            output,
-            MONTH_NAMES[u8::from(date.month()).extend::<usize>() - 1],
-        ),
-        modifier::MonthRepr::Short => write(
-            output,
-            &MONTH_NAMES[u8::from(date.month()).extend::<usize>() - 1][..3],
-        ),
-    }
+        #[cfg(time_no_names)]
+        modifier::MonthRepr::Long | modifier::MonthRepr::Short => {
+            return Err(error::Format::InvalidComponent("month"));
+        }
+        #[cfg(not(time_no_names))]
+        modifier::MonthRepr::Long => write(output, locale.month_name(date.month()).as_bytes())?,
+        #[cfg(not(time_no_names))]
+        modifier::MonthRepr::Short => {
+            write(output, locale.month_abbreviation(date.month()).as_bytes())?
+        }
+    })
 }
 
 /// Format the ordinal into the designated output.
-fn fmt_ordinal(
+pub(crate) fn fmt_ordinal(
     output: &mut impl io::Write,
     date: Date,
     modifier::Ordinal { padding }: modifier::Ordinal,
@@ -240,7 +266,8 @@ fn fmt_ordinal(
 }
 
 /// Format the weekday into the designated output.
-fn fmt_weekday(
+#[cfg_attr(time_no_names, allow(unused_variables))]
+pub(crate) fn fmt_weekday(
     output: &mut impl io::Write,
     date: Date,
     modifier::Weekday {
@@ -248,34 +275,44 @@ fn fmt_weekday(
         one_indexed,
         case_sensitive: _, // no effect on formatting
     }: modifier::Weekday,
-) -> Result<usize, io::Error> {
-    match repr {
+    locale: &(impl Locale + ?Sized),
+) -> Result<usize, error::Format> {
+    Ok(match repr {
+        #[cfg(time_no_names)]
+        modifier::WeekdayRepr::Short | modifier::WeekdayRepr::Long => {
+            return Err(error::Format::InvalidComponent("weekday"));
+        }
+        #[cfg(not(time_no_names))]
         modifier::WeekdayRepr::Short => write(
             output,
-            &WEEKDAY_NAMES[date.weekday().number_days_from_monday().extend::<usize>()][..3],
-        ),
-        modifier::WeekdayRepr::Long => write(
-            output,
-            WEEKDAY_NAMES[date.weekday().number_days_from_monday().extend::<usize>()],
-        ),
+            locale.weekday_abbreviation(date.weekday()).as_bytes(),
+        )?,
+        #[cfg(not(time_no_names))]
+        modifier::WeekdayRepr::Long => {
+            write(output, locale.weekday_name(date.weekday()).as_bytes())?
+        }
         modifier::WeekdayRepr::Sunday => format_number::<1>(
             output,
             date.weekday().number_days_from_sunday() + u8::from(one_indexed),
             modifier::Padding::None,
-        ),
+        )?,
         modifier::WeekdayRepr::Monday => format_number::<1>(
             output,
             date.weekday().number_days_from_monday() + u8::from(one_indexed),
             modifier::Padding::None,
-        ),
-    }
+        )?,
+    })
 }
 
 /// Format the week number into the designated output.
-fn fmt_week_number(
+pub(crate) fn fmt_week_number(
     output: &mut impl io::Write,
     date: Date,
-    modifier::WeekNumber { padding, repr }: modifier::WeekNumber,
+    modifier::WeekNumber {
+        padding,
+        repr,
+        weekday_is_optional: _, // no effect on formatting
+    }: modifier::WeekNumber,
 ) -> Result<usize, io::Error> {
     format_number::<2>(
         output,
@@ -289,7 +326,7 @@ fn fmt_week_number(
 }
 
 /// Format the year into the designated output.
-fn fmt_year(
+pub(crate) fn fmt_year(
     output: &mut impl io::Write,
     date: Date,
     modifier::Year {
@@ -298,6 +335,9 @@ fn fmt_year(
         range,
         iso_week_based,
         sign_is_mandatory,
+        width,
+        group_separator,
+        notation,
     }: modifier::Year,
 ) -> Result<usize, error::Format> {
     let full_year = if iso_week_based {
@@ -329,6 +369,7 @@ fn fmt_year(
                     maximum: 9999,
                     value: full_year.extend(),
                     conditional_message: Some("when `range:standard` is used"),
+                    conditional_date: None,
                 }
                 .into());
             }
@@ -340,6 +381,22 @@ fn fmt_year(
             modifier::YearRepr::LastTwo => format_number::<2>,
         }
     };
+    if repr == modifier::YearRepr::Full
+        && notation == modifier::YearNotation::Bce
+        && full_year <= 0
+    {
+        return fmt_year_bce(output, full_year, padding, width, group_separator);
+    }
+    if repr == modifier::YearRepr::Full && width != modifier::YearWidth::Automatic {
+        return fmt_year_with_width(
+            output,
+            full_year,
+            padding,
+            sign_is_mandatory,
+            width,
+            group_separator,
+        );
+    }
     let mut bytes = 0;
     if repr != modifier::YearRepr::LastTwo {
         if full_year < 0 {
@@ -348,14 +405,105 @@ fn fmt_year(
             bytes += write(output, b"+")?;
         }
     }
-    bytes += format_number(output, value.unsigned_abs(), padding)?;
+    let mut digits = [0; 6];
+    let len = format_number(&mut &mut digits[..], value.unsigned_abs(), padding)?;
+    bytes += write_year_digits(output, &digits[..len], group_separator)?;
+    Ok(bytes)
+}
+
+/// Format the full year using the explicitly requested number of digits, failing if the year does
+/// not fit.
+fn fmt_year_with_width(
+    output: &mut impl io::Write,
+    full_year: i32,
+    padding: modifier::Padding,
+    sign_is_mandatory: bool,
+    width: modifier::YearWidth,
+    group_separator: modifier::YearGroupSeparator,
+) -> Result<usize, error::Format> {
+    let (format_number, max_value): (fn(_, _, _) -> _, u32) = match width {
+        modifier::YearWidth::Four => (format_number::<4>, 9_999),
+        modifier::YearWidth::Five => (format_number::<5>, 99_999),
+        modifier::YearWidth::Six | modifier::YearWidth::Automatic => (format_number::<6>, 999_999),
+    };
+    if full_year.unsigned_abs() > max_value {
+        return Err(error::Format::InvalidComponent("year"));
+    }
+
+    let mut bytes = 0;
+    if full_year < 0 {
+        bytes += write(output, b"-")?;
+    } else if sign_is_mandatory {
+        bytes += write(output, b"+")?;
+    }
+    let mut digits = [0; 6];
+    let len = format_number(&mut &mut digits[..], full_year.unsigned_abs(), padding)?;
+    bytes += write_year_digits(output, &digits[..len], group_separator)?;
+    Ok(bytes)
+}
+
+/// Format a full year before 1 CE, counting backwards from one and followed by ` BCE`.
+fn fmt_year_bce(
+    output: &mut impl io::Write,
+    full_year: i32,
+    padding: modifier::Padding,
+    width: modifier::YearWidth,
+    group_separator: modifier::YearGroupSeparator,
+) -> Result<usize, error::Format> {
+    let (format_number, max_value): (fn(_, _, _) -> _, u32) = match width {
+        modifier::YearWidth::Automatic => (format_number::<4>, u32::MAX),
+        modifier::YearWidth::Four => (format_number::<4>, 9_999),
+        modifier::YearWidth::Five => (format_number::<5>, 99_999),
+        modifier::YearWidth::Six => (format_number::<6>, 999_999),
+    };
+    // 1 BCE is year zero in the proleptic Gregorian calendar.
+    let year = (1 - full_year).unsigned_abs();
+    if year > max_value {
+        return Err(error::Format::InvalidComponent("year"));
+    }
+
+    let mut digits = [0; 7];
+    let len = format_number(&mut &mut digits[..], year, padding)?;
+    let mut bytes = write_year_digits(output, &digits[..len], group_separator)?;
+    bytes += write(output, b" BCE")?;
+    Ok(bytes)
+}
+
+/// Write the digits of a year, placing the separator between each group of three digits counting
+/// from the right. Separators are only placed after the first significant digit, so padding is
+/// never separated, such as `0999` rather than `0_999`.
+fn write_year_digits(
+    output: &mut impl io::Write,
+    digits: &[u8],
+    group_separator: modifier::YearGroupSeparator,
+) -> io::Result<usize> {
+    let separator: &[u8] = match group_separator {
+        modifier::YearGroupSeparator::None => return write(output, digits),
+        modifier::YearGroupSeparator::Underscore => b"_",
+        modifier::YearGroupSeparator::Comma => b",",
+        modifier::YearGroupSeparator::Period => b".",
+        modifier::YearGroupSeparator::Space => b" ",
+    };
+
+    // The last digit is significant even if it is zero.
+    let first_significant = digits
+        .iter()
+        .position(|&digit| digit.is_ascii_digit() && digit != b'0')
+        .unwrap_or_else(|| digits.len().saturating_sub(1));
+    let mut bytes = 0;
+    for (idx, digit) in digits.iter().enumerate() {
+        if idx > first_significant && (digits.len() - idx) % 3 == 0 {
+            bytes += write(output, separator)?;
+        }
+        bytes += write(output, slice::from_ref(digit))?;
+    }
     Ok(bytes)
 }
 // endregion date formatters
 
 // region: time formatters
 /// Format the hour into the designated output.
-fn fmt_hour(
+pub(crate) fn fmt_hour(
     output: &mut impl io::Write,
     time: Time,
     modifier::Hour {
@@ -373,7 +521,7 @@ fn fmt_hour(
 }
 
 /// Format the minute into the designated output.
-fn fmt_minute(
+pub(crate) fn fmt_minute(
     output: &mut impl io::Write,
     time: Time,
     modifier::Minute { padding }: modifier::Minute,
@@ -382,7 +530,7 @@ fn fmt_minute(
 }
 
 /// Format the period into the designated output.
-fn fmt_period(
+pub(crate) fn fmt_period(
     output: &mut impl io::Write,
     time: Time,
     modifier::Period {
@@ -399,7 +547,7 @@ fn fmt_period(
 }
 
 /// Format the second into the designated output.
-fn fmt_second(
+pub(crate) fn fmt_second(
     output: &mut impl io::Write,
     time: Time,
     modifier::Second { padding }: modifier::Second,
@@ -408,7 +556,7 @@ fn fmt_second(
 }
 
 /// Format the subsecond into the designated output.
-fn fmt_subsecond<W: io::Write>(
+pub(crate) fn fmt_subsecond<W: io::Write>(
     output: &mut W,
     time: Time,
     modifier::Subsecond { digits }: modifier::Subsecond,
@@ -440,7 +588,7 @@ fn fmt_subsecond<W: io::Write>(
 
 // region: offset formatters
 /// Format the offset hour into the designated output.
-fn fmt_offset_hour(
+pub(crate) fn fmt_offset_hour(
     output: &mut impl io::Write,
     offset: UtcOffset,
     modifier::OffsetHour {
@@ -459,7 +607,7 @@ fn fmt_offset_hour(
 }
 
 /// Format the offset minute into the designated output.
-fn fmt_offset_minute(
+pub(crate) fn fmt_offset_minute(
     output: &mut impl io::Write,
     offset: UtcOffset,
     modifier::OffsetMinute { padding }: modifier::OffsetMinute,
@@ -468,7 +616,7 @@ fn fmt_offset_minute(
 }
 
 /// Format the offset second into the designated output.
-fn fmt_offset_second(
+pub(crate) fn fmt_offset_second(
     output: &mut impl io::Write,
     offset: UtcOffset,
     modifier::OffsetSecond { padding }: modifier::OffsetSecond,
@@ -478,7 +626,7 @@ fn fmt_offset_second(
 // endregion offset formatters
 
 /// Format the Unix timestamp into the designated output.
-fn fmt_unix_timestamp(
+pub(crate) fn fmt_unix_timestamp(
     output: &mut impl io::Write,
     date: Date,
     time: Time,
@@ -0,0 +1,72 @@
+//! A stack-allocated buffer for batching writes to an output.
+
+use std::io;
+
+/// The number of bytes that can be buffered before being written to the output. This is large
+/// enough to hold any of the well-known formats in their entirety.
+const CAPACITY: usize = 128;
+
+/// A wrapper around an [`io::Write`] that collects small writes into a stack buffer, writing them
+/// to the output in a single call once the buffer is full or [`WriteBuffer::finish`] is called.
+///
+/// Formatting performs many small writes, each of which may be a system call for unbuffered
+/// outputs such as a `TcpStream`. Writes that do not fit in the buffer are passed directly to the
+/// output after the buffer is emptied. Nothing buffered is written if `finish` is not called.
+pub(crate) struct WriteBuffer<'a, W: io::Write + ?Sized> {
+    /// The output that the buffered bytes are eventually written to.
+    output: &'a mut W,
+    /// The bytes that have not yet been written to the output.
+    buf: [u8; CAPACITY],
+    /// The number of bytes of `buf` that are in use.
+    len: usize,
+}
+
+impl<'a, W: io::Write + ?Sized> WriteBuffer<'a, W> {
+    /// Create a new, empty buffer that writes to the provided output.
+    pub(crate) const fn new(output: &'a mut W) -> Self {
+        Self {
+            output,
+            buf: [0; CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// Write any buffered bytes to the output.
+    fn write_buffered(&mut self) -> io::Result<()> {
+        if self.len != 0 {
+            self.output.write_all(&self.buf[..self.len])?;
+            self.len = 0;
+        }
+        Ok(())
+    }
+
+    /// Write any buffered bytes to the output, consuming the buffer.
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        self.write_buffered()
+    }
+}
+
+impl<W: io::Write + ?Sized> io::Write for WriteBuffer<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        if buf.len() > CAPACITY - self.len {
+            self.write_buffered()?;
+        }
+        if buf.len() >= CAPACITY {
+            return self.output.write_all(buf);
+        }
+
+        self.buf[self.len..self.len + buf.len()].copy_from_slice(buf);
+        self.len += buf.len();
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_buffered()?;
+        self.output.flush()
+    }
+}
@@ -0,0 +1,272 @@
+//! The [`HumanDuration`] struct and its associated `impl`s.
+
+use core::fmt;
+use core::str::FromStr;
+
+use num_conv::prelude::*;
+use powerfmt::ext::FormatterExt;
+use powerfmt::smart_display::{FormatterOptions, Metadata, SmartDisplay};
+
+use crate::convert::*;
+use crate::error::InvalidDuration;
+use crate::util::{DisplayFn, WidthCounter};
+use crate::Duration;
+
+/// A [`Duration`] that is displayed and parsed in a form intended to be read and written by
+/// humans, such as `2d 3h 45m 12.5s`. This is obtained via [`Duration::human`].
+///
+/// When displayed, each nonzero component is written with its unit, largest first, separated by a
+/// space. The number of seconds includes the fractional part if there is one. Durations shorter
+/// than one second are instead written using the largest unit of `ms`, `µs`, or `ns` for which the
+/// value is at least one, such as `1.5ms`. For the purposes of this type, a day is exactly 24
+/// hours.
+///
+/// When parsing, any number of components may be present, each consisting of a number, optionally
+/// with a fractional part, immediately followed by one of the units `d`, `h`, `m`, `s`, `ms`,
+/// `µs` (or `us`), or `ns`. Components may be separated by whitespace and are summed. The value as
+/// a whole may be preceded by a sign. Precision beyond one nanosecond is truncated.
+///
+/// ```rust
+/// # use time::ext::NumericalDuration;
+/// # use time::{Duration, HumanDuration};
+/// let duration = 2.days() + 3.hours() + 45.minutes() + 12.5.seconds();
+/// assert_eq!(duration.human().to_string(), "2d 3h 45m 12.5s");
+/// assert_eq!((-1.5).milliseconds().human().to_string(), "-1.5ms");
+///
+/// let parsed: HumanDuration = "2d 3h 45m 12.5s".parse()?;
+/// assert_eq!(Duration::from(parsed), duration);
+/// assert_eq!(Duration::from("1h30m".parse::<HumanDuration>()?), 90.minutes());
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HumanDuration {
+    /// The wrapped duration.
+    duration: Duration,
+}
+
+impl HumanDuration {
+    /// Wrap the provided duration.
+    pub(crate) const fn new(duration: Duration) -> Self {
+        Self { duration }
+    }
+}
+
+impl From<HumanDuration> for Duration {
+    fn from(value: HumanDuration) -> Self {
+        value.duration
+    }
+}
+
+/// Write a decimal number with the provided whole and fractional parts. The fractional part is
+/// the numerator of a fraction with the provided denominator, which must be a power of ten. Trailing
+/// zeros of the fractional part are omitted.
+pub(crate) fn fmt_decimal(
+    f: &mut dyn fmt::Write,
+    whole: u64,
+    mut fraction: u32,
+    denominator: u32,
+) -> fmt::Result {
+    write!(f, "{whole}")?;
+    if fraction != 0 {
+        let mut digits = denominator.ilog10() as usize;
+        while fraction % 10 == 0 {
+            fraction /= 10;
+            digits -= 1;
+        }
+        write!(f, ".{fraction:0digits$}")?;
+    }
+    Ok(())
+}
+
+impl HumanDuration {
+    /// Write the duration without padding.
+    fn fmt_unpadded(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let duration = self.duration;
+
+        if duration.is_zero() {
+            return f.write_str("0s");
+        }
+        if duration.is_negative() {
+            f.write_str("-")?;
+        }
+
+        let seconds = duration.whole_seconds().unsigned_abs();
+        let nanoseconds = duration.subsec_nanoseconds().unsigned_abs();
+
+        if seconds == 0 {
+            let (unit, denominator) = if nanoseconds >= Nanosecond::per(Millisecond) {
+                ("ms", Nanosecond::per(Millisecond))
+            } else if nanoseconds >= Nanosecond::per(Microsecond).extend() {
+                ("µs", Nanosecond::per(Microsecond).extend())
+            } else {
+                ("ns", 1)
+            };
+            fmt_decimal(
+                f,
+                (nanoseconds / denominator).extend(),
+                nanoseconds % denominator,
+                denominator,
+            )?;
+            return f.write_str(unit);
+        }
+
+        let components = [
+            (seconds / Second::per(Day).extend::<u64>(), "d"),
+            (
+                seconds / Second::per(Hour).extend::<u64>() % Hour::per(Day).extend::<u64>(),
+                "h",
+            ),
+            (
+                seconds / Second::per(Minute).extend::<u64>() % Minute::per(Hour).extend::<u64>(),
+                "m",
+            ),
+        ];
+
+        // Whether a separator must be written before the next component.
+        let mut needs_separator = false;
+
+        for (value, unit) in components {
+            if value == 0 {
+                continue;
+            }
+            if needs_separator {
+                f.write_str(" ")?;
+            }
+            needs_separator = true;
+            write!(f, "{value}{unit}")?;
+        }
+
+        let seconds = seconds % Second::per(Minute).extend::<u64>();
+        if seconds != 0 || nanoseconds != 0 {
+            if needs_separator {
+                f.write_str(" ")?;
+            }
+            fmt_decimal(f, seconds, nanoseconds, Nanosecond::per(Second))?;
+            f.write_str("s")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SmartDisplay for HumanDuration {
+    type Metadata = ();
+
+    fn metadata(&self, _: FormatterOptions) -> Metadata<Self> {
+        let mut width = WidthCounter::default();
+        // Writing to the counter never fails.
+        let _ = self.fmt_unpadded(&mut width);
+        Metadata::new(width.0, self, ())
+    }
+
+    fn fmt_with_metadata(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        metadata: Metadata<Self>,
+    ) -> fmt::Result {
+        f.pad_with_width(
+            metadata.unpadded_width(),
+            format_args!(
+                "{}",
+                DisplayFn(|f: &mut dyn fmt::Write| self.fmt_unpadded(f))
+            ),
+        )
+    }
+}
+
+impl fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() {
+            return self.fmt_unpadded(f);
+        }
+        SmartDisplay::fmt(self, f)
+    }
+}
+
+impl FromStr for HumanDuration {
+    type Err = InvalidDuration;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use InvalidDuration::{InvalidSyntax, OutOfRange};
+
+        /// The accepted units and the number of nanoseconds in each. Units that are a prefix of
+        /// another unit must come later.
+        const UNITS: [(&str, u64); 8] = [
+            ("ms", Nanosecond::per(Millisecond) as _),
+            ("µs", Nanosecond::per(Microsecond) as _),
+            ("us", Nanosecond::per(Microsecond) as _),
+            ("ns", 1),
+            ("d", Nanosecond::per(Day)),
+            ("h", Nanosecond::per(Hour)),
+            ("m", Nanosecond::per(Minute)),
+            ("s", Nanosecond::per(Second) as _),
+        ];
+
+        /// Split the string after the leading ASCII digits.
+        fn split_digits(s: &str) -> (&str, &str) {
+            s.split_at(s.bytes().take_while(u8::is_ascii_digit).count())
+        }
+
+        let s = s.trim();
+        let (is_negative, mut s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if s.is_empty() {
+            return Err(InvalidSyntax);
+        }
+
+        let mut total_nanoseconds = 0_u128;
+        while !s.is_empty() {
+            let (whole, rest) = split_digits(s);
+            if whole.is_empty() {
+                return Err(InvalidSyntax);
+            }
+            let (fraction, rest) = match rest.strip_prefix('.') {
+                Some(rest) => match split_digits(rest) {
+                    ("", _) => return Err(InvalidSyntax),
+                    split => split,
+                },
+                None => ("", rest),
+            };
+            let &(unit, nanoseconds_per_unit) = UNITS
+                .iter()
+                .find(|(unit, _)| rest.starts_with(unit))
+                .ok_or(InvalidSyntax)?;
+            s = rest[unit.len()..].trim_start();
+
+            let whole = whole
+                .bytes()
+                .try_fold(0_u128, |value, digit| {
+                    value.checked_mul(10)?.checked_add((digit - b'0').extend())
+                })
+                .ok_or(OutOfRange)?;
+            let mut numerator = 0_u128;
+            let mut denominator = 1_u128;
+            // No unit is longer than 10^14 nanoseconds, so further digits cannot affect the result.
+            for digit in fraction.bytes().take(18) {
+                numerator = numerator * 10 + (digit - b'0').extend::<u128>();
+                denominator *= 10;
+            }
+
+            let nanoseconds_per_unit = nanoseconds_per_unit.extend::<u128>();
+            total_nanoseconds = whole
+                .checked_mul(nanoseconds_per_unit)
+                .and_then(|value| value.checked_add(numerator * nanoseconds_per_unit / denominator))
+                .and_then(|value| total_nanoseconds.checked_add(value))
+                .ok_or(OutOfRange)?;
+        }
+
+        let mut total_nanoseconds = i128::try_from(total_nanoseconds).map_err(|_| OutOfRange)?;
+        if is_negative {
+            total_nanoseconds = -total_nanoseconds;
+        }
+
+        let nanoseconds_per_second = i128::from(Nanosecond::per(Second));
+        let seconds =
+            i64::try_from(total_nanoseconds / nanoseconds_per_second).map_err(|_| OutOfRange)?;
+        let nanoseconds = (total_nanoseconds % nanoseconds_per_second).truncate();
+
+        Ok(Self::new(Duration::new(seconds, nanoseconds)))
+    }
+}
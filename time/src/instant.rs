@@ -47,6 +47,10 @@ impl Instant {
     /// println!("{:?}", Instant::now());
     /// ```
     pub fn now() -> Self {
+        #[cfg(feature = "test-util")]
+        if let Some(instant) = crate::test_util::instant() {
+            return instant;
+        }
         Self(StdInstant::now())
     }
 
@@ -64,8 +68,67 @@ impl Instant {
     pub fn elapsed(self) -> Duration {
         Self::now() - self
     }
+
+    /// Returns the amount of time elapsed since this instant was created, or `None` if the
+    /// duration cannot be represented. This can only fail for instants that were synthetically
+    /// created.
+    ///
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// # use time::{Instant, ext::{NumericalStdDuration, NumericalDuration}};
+    /// # use std::thread;
+    /// let instant = Instant::now();
+    /// thread::sleep(1.std_milliseconds());
+    /// assert!(instant.elapsed_checked() >= Some(1.milliseconds()));
+    /// ```
+    pub fn elapsed_checked(self) -> Option<Duration> {
+        Self::now().checked_duration_since(self)
+    }
     // endregion delegation
 
+    // region: duration between instants
+    /// Returns the amount of time elapsed from another instant to this one, or `None` if the
+    /// duration cannot be represented. The duration is negative if `earlier` is later than `self`.
+    ///
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// # use time::{Instant, ext::NumericalDuration};
+    /// let now = Instant::now();
+    /// let later = now + 5.seconds();
+    /// assert_eq!(later.checked_duration_since(now), Some(5.seconds()));
+    /// assert_eq!(now.checked_duration_since(later), Some((-5).seconds()));
+    /// ```
+    pub fn checked_duration_since(self, earlier: Self) -> Option<Duration> {
+        match self.0.cmp(&earlier.0) {
+            Ordering::Equal => Some(Duration::ZERO),
+            Ordering::Greater => (self.0 - earlier.0).try_into().ok(),
+            Ordering::Less => Duration::try_from(earlier.0 - self.0)
+                .ok()
+                .map(|duration| -duration),
+        }
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one, saturating to
+    /// [`Duration::MIN`] or [`Duration::MAX`] if the duration cannot be represented. The duration
+    /// is negative if `earlier` is later than `self`.
+    ///
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// # use time::{Instant, ext::NumericalDuration};
+    /// let now = Instant::now();
+    /// let later = now + 5.seconds();
+    /// assert_eq!(later.saturating_duration_since(now), 5.seconds());
+    /// assert_eq!(now.saturating_duration_since(later), (-5).seconds());
+    /// ```
+    pub fn saturating_duration_since(self, earlier: Self) -> Duration {
+        match self.checked_duration_since(earlier) {
+            Some(duration) => duration,
+            None if self.0 > earlier.0 => Duration::MAX,
+            None => Duration::MIN,
+        }
+    }
+    // endregion duration between instants
+
     // region: checked arithmetic
     /// Returns `Some(t)` where `t` is the time `self + duration` if `t` can be represented as
     /// `Instant` (which means it's inside the bounds of the underlying data structure), `None`
@@ -113,6 +113,7 @@ macro_rules! ensure_ranged {
                     maximum: $type::MAX.get() as _,
                     value: $value as _,
                     conditional_message: None,
+                    conditional_date: None,
                 });
             }
         }
@@ -130,6 +131,7 @@ macro_rules! ensure_ranged {
                         maximum: $type::MAX.get() as i64 / $factor as i64,
                         value: $value as _,
                         conditional_message: None,
+                        conditional_date: None,
                     });
                 }
             },
@@ -140,6 +142,7 @@ macro_rules! ensure_ranged {
                     maximum: $type::MAX.get() as i64 / $factor as i64,
                     value: $value as _,
                     conditional_message: None,
+                    conditional_date: None,
                 });
             }
         }
@@ -183,6 +186,33 @@ macro_rules! expect_opt {
     };
 }
 
+/// Compile items only when JavaScript APIs are used to access the system clock: on WebAssembly
+/// targets other than Emscripten and WASI, with the `wasm-bindgen` feature enabled. Items in an
+/// `else` block are compiled in all other cases.
+macro_rules! cfg_wasm_bindgen {
+    ({ $($then:item)* } else { $($else:item)* }) => {
+        $(
+            #[cfg(all(
+                target_family = "wasm",
+                not(any(target_os = "emscripten", target_os = "wasi")),
+                feature = "wasm-bindgen"
+            ))]
+            $then
+        )*
+        $(
+            #[cfg(not(all(
+                target_family = "wasm",
+                not(any(target_os = "emscripten", target_os = "wasi")),
+                feature = "wasm-bindgen"
+            )))]
+            $else
+        )*
+    };
+    ($($item:item)*) => {
+        $crate::internal_macros::cfg_wasm_bindgen! { { $($item)* } else {} }
+    };
+}
+
 /// `unreachable!()`, but better.
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 macro_rules! bug {
@@ -195,6 +225,6 @@ macro_rules! bug {
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 pub(crate) use bug;
 pub(crate) use {
-    __impl_assign, cascade, const_try, const_try_opt, div_floor, ensure_ranged, expect_opt,
-    impl_add_assign, impl_div_assign, impl_mul_assign, impl_sub_assign,
+    __impl_assign, cascade, cfg_wasm_bindgen, const_try, const_try_opt, div_floor, ensure_ranged,
+    expect_opt, impl_add_assign, impl_div_assign, impl_mul_assign, impl_sub_assign,
 };
@@ -0,0 +1,261 @@
+//! A span of time between two instants.
+//!
+//! An [`Interval`] can be sampled at evenly spaced instants or divided into consecutive
+//! sub-intervals of a fixed length, such as when computing the ticks of a chart's axis.
+//!
+//! ```rust
+//! # use time::interval::Interval;
+//! # use time::ext::NumericalDuration;
+//! # use time_macros::datetime;
+//! let interval = Interval::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-02 0:00 UTC));
+//! assert_eq!(
+//!     interval.sample_evenly(3).collect::<Vec<_>>(),
+//!     [
+//!         datetime!(2024-01-01 0:00 UTC),
+//!         datetime!(2024-01-01 12:00 UTC),
+//!         datetime!(2024-01-02 0:00 UTC),
+//!     ]
+//! );
+//! assert_eq!(interval.split(10.hours()).count(), 3);
+//! ```
+
+use core::iter::FusedIterator;
+
+use crate::{Duration, OffsetDateTime};
+
+/// The instants from a start to an end, inclusive of the start and exclusive of the end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    /// The first instant in the interval.
+    start: OffsetDateTime,
+    /// The instant immediately following the interval.
+    end: OffsetDateTime,
+}
+
+impl Interval {
+    /// Create an interval from its start and end.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the end is before the start.
+    ///
+    /// ```rust
+    /// # use time::interval::Interval;
+    /// # use time_macros::datetime;
+    /// let interval = Interval::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-01 2:00 +1));
+    /// assert_eq!(interval.start(), datetime!(2024-01-01 0:00 UTC));
+    /// assert_eq!(interval.end(), datetime!(2024-01-01 2:00 +1));
+    /// ```
+    #[track_caller]
+    pub fn new(start: OffsetDateTime, end: OffsetDateTime) -> Self {
+        assert!(
+            start <= end,
+            "the end of an interval must not be before its start"
+        );
+        Self { start, end }
+    }
+
+    /// Get the first instant in the interval.
+    pub const fn start(self) -> OffsetDateTime {
+        self.start
+    }
+
+    /// Get the instant immediately following the interval.
+    pub const fn end(self) -> OffsetDateTime {
+        self.end
+    }
+
+    /// Get the length of the interval.
+    ///
+    /// ```rust
+    /// # use time::interval::Interval;
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::datetime;
+    /// let interval = Interval::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-01 2:00 +1));
+    /// assert_eq!(interval.duration(), 1.hours());
+    /// ```
+    pub fn duration(self) -> Duration {
+        self.end - self.start
+    }
+
+    /// Whether the interval contains the provided instant.
+    ///
+    /// ```rust
+    /// # use time::interval::Interval;
+    /// # use time_macros::datetime;
+    /// let interval = Interval::new(
+    ///     datetime!(2024-01-01 0:00 UTC),
+    ///     datetime!(2024-01-02 0:00 UTC),
+    /// );
+    /// assert!(interval.contains(datetime!(2024-01-01 0:00 UTC)));
+    /// assert!(interval.contains(datetime!(2024-01-01 23:59 UTC)));
+    /// assert!(!interval.contains(datetime!(2024-01-02 0:00 UTC)));
+    /// ```
+    pub fn contains(self, instant: OffsetDateTime) -> bool {
+        self.start <= instant && instant < self.end
+    }
+
+    /// Obtain `n` evenly spaced instants, beginning with the start and ending with the end of the
+    /// interval. If `n` is one, only the start is yielded. Values are in the offset of the start
+    /// and are rounded down to the nearest nanosecond.
+    ///
+    /// ```rust
+    /// # use time::interval::Interval;
+    /// # use time_macros::datetime;
+    /// let interval = Interval::new(
+    ///     datetime!(2024-01-01 0:00 UTC),
+    ///     datetime!(2024-01-01 0:01 UTC),
+    /// );
+    /// assert_eq!(
+    ///     interval.sample_evenly(4).collect::<Vec<_>>(),
+    ///     [
+    ///         datetime!(2024-01-01 0:00 UTC),
+    ///         datetime!(2024-01-01 0:00:20 UTC),
+    ///         datetime!(2024-01-01 0:00:40 UTC),
+    ///         datetime!(2024-01-01 0:01 UTC),
+    ///     ]
+    /// );
+    /// assert_eq!(interval.sample_evenly(0).next(), None);
+    /// ```
+    pub fn sample_evenly(self, n: usize) -> SampleEvenly {
+        SampleEvenly {
+            start: self.start,
+            length: self.duration().whole_nanoseconds(),
+            n,
+            index: 0,
+        }
+    }
+
+    /// Divide the interval into consecutive sub-intervals of the provided length. The final
+    /// sub-interval ends at the end of the interval, so it may be shorter. Sub-intervals are in the
+    /// offset of the start, other than the end of the final one. An empty interval yields no
+    /// sub-intervals.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the length is not positive.
+    ///
+    /// ```rust
+    /// # use time::interval::Interval;
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::datetime;
+    /// let interval = Interval::new(
+    ///     datetime!(2024-01-01 0:00 UTC),
+    ///     datetime!(2024-01-01 2:30 UTC),
+    /// );
+    /// assert_eq!(
+    ///     interval.split(1.hours()).collect::<Vec<_>>(),
+    ///     [
+    ///         Interval::new(datetime!(2024-01-01 0:00 UTC), datetime!(2024-01-01 1:00 UTC)),
+    ///         Interval::new(datetime!(2024-01-01 1:00 UTC), datetime!(2024-01-01 2:00 UTC)),
+    ///         Interval::new(datetime!(2024-01-01 2:00 UTC), datetime!(2024-01-01 2:30 UTC)),
+    ///     ]
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn split(self, length: Duration) -> Split {
+        assert!(
+            length.is_positive(),
+            "the length of a sub-interval must be positive"
+        );
+        Split {
+            remaining: Some(self),
+            length,
+        }
+    }
+}
+
+/// An iterator over evenly spaced instants in an [`Interval`]. This is obtained via
+/// [`Interval::sample_evenly`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleEvenly {
+    /// The first instant yielded.
+    start: OffsetDateTime,
+    /// The number of nanoseconds between the first and last instants yielded.
+    length: i128,
+    /// The total number of instants yielded.
+    n: usize,
+    /// The index of the next instant to be yielded.
+    index: usize,
+}
+
+impl SampleEvenly {
+    /// Obtain the instant at the provided index, which must be less than the number of instants.
+    fn get(&self, index: usize) -> OffsetDateTime {
+        if index == 0 {
+            return self.start;
+        }
+        // Splitting the length into a quotient and remainder avoids overflow when multiplying by
+        // the index. The remainder is less than the number of gaps, so its product with the index
+        // always fits in a `u128`.
+        let gaps = (self.n - 1) as i128;
+        let (step, remainder) = (self.length / gaps, self.length % gaps);
+        let nanoseconds =
+            step * index as i128 + (remainder as u128 * index as u128 / gaps as u128) as i128;
+        self.start + Duration::nanoseconds_i128(nanoseconds)
+    }
+}
+
+impl Iterator for SampleEvenly {
+    type Item = OffsetDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.n {
+            return None;
+        }
+        self.index += 1;
+        Some(self.get(self.index - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.n - self.index;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.saturating_add(n).min(self.n);
+        self.next()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.nth(self.len().checked_sub(1)?)
+    }
+}
+
+impl ExactSizeIterator for SampleEvenly {}
+
+impl FusedIterator for SampleEvenly {}
+
+/// An iterator over consecutive sub-intervals of an [`Interval`]. This is obtained via
+/// [`Interval::split`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Split {
+    /// The portion of the interval that has not yet been yielded. `None` once the iterator is
+    /// exhausted.
+    remaining: Option<Interval>,
+    /// The length of each sub-interval.
+    length: Duration,
+}
+
+impl Iterator for Split {
+    type Item = Interval;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+        if remaining.duration() <= self.length {
+            self.remaining = None;
+            return (remaining.start != remaining.end).then_some(remaining);
+        }
+        let boundary = remaining.start + self.length;
+        self.remaining = Some(Interval {
+            start: boundary,
+            end: remaining.end,
+        });
+        Some(Interval {
+            start: remaining.start,
+            end: boundary,
+        })
+    }
+}
+
+impl FusedIterator for Split {}
@@ -0,0 +1,303 @@
+//! The [`Iso8601Duration`] struct and its associated `impl`s.
+
+use core::fmt;
+use core::str::FromStr;
+
+use num_conv::prelude::*;
+use powerfmt::ext::FormatterExt;
+use powerfmt::smart_display::{FormatterOptions, Metadata, SmartDisplay};
+
+use crate::convert::*;
+use crate::error::InvalidDuration;
+use crate::human_duration::fmt_decimal;
+use crate::util::{DisplayFn, WidthCounter};
+use crate::Duration;
+
+/// A [`Duration`] that is displayed and parsed in the duration format of ISO 8601, such as
+/// `P1DT2H30M`. This is obtained via [`Duration::iso8601`].
+///
+/// When displayed, the duration is written as days, hours, minutes, and seconds, with the number of
+/// seconds including the fractional part if there is one. Components that are zero are omitted
+/// unless [`set_zero_components`](Self::set_zero_components) is used, though a zero duration is
+/// written as `PT0S`. Where the sign of a negative duration is written is controlled by
+/// [`set_sign_placement`](Self::set_sign_placement). For the purposes of this type, a day is
+/// exactly 24 hours.
+///
+/// When parsing, the sign may precede the `P`, any component, or both, as is accepted by Java's
+/// `Duration.parse`. A sign preceding the `P` negates the entire duration. Days, hours, minutes,
+/// and seconds are accepted in that order, and only the seconds may have a fractional part.
+/// Precision beyond one nanosecond is truncated.
+///
+/// ```rust
+/// # use time::ext::NumericalDuration;
+/// # use time::{Duration, Iso8601Duration, Iso8601SignPlacement};
+/// let duration = -(1.days() + 2.hours() + 30.minutes());
+/// assert_eq!(duration.iso8601().to_string(), "-P1DT2H30M");
+/// assert_eq!(
+///     duration
+///         .iso8601()
+///         .set_sign_placement(Iso8601SignPlacement::Components)
+///         .to_string(),
+///     "P-1DT-2H-30M"
+/// );
+/// assert_eq!(
+///     5.minutes().iso8601().set_zero_components(true).to_string(),
+///     "P0DT0H5M0S"
+/// );
+///
+/// assert_eq!(Duration::from("-PT5M".parse::<Iso8601Duration>()?), -5.minutes());
+/// assert_eq!(Duration::from("PT-5M".parse::<Iso8601Duration>()?), -5.minutes());
+/// assert_eq!(Duration::from("PT1H-1.5S".parse::<Iso8601Duration>()?), 3_598.5.seconds());
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Iso8601Duration {
+    /// The wrapped duration.
+    duration: Duration,
+    /// Where the sign of a negative duration is written.
+    sign_placement: Iso8601SignPlacement,
+    /// Whether components that are zero are written.
+    zero_components: bool,
+}
+
+/// Where the sign of a negative [`Iso8601Duration`] is written.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Iso8601SignPlacement {
+    /// The sign is written once, before the `P`, such as `-PT5M`. This is the form described by
+    /// ISO 8601-2 and used by .NET.
+    #[default]
+    Leading,
+    /// The sign is written before each nonzero component, such as `PT-5M`. This is the form
+    /// written by Java's `Duration.toString`.
+    Components,
+}
+
+impl Iso8601Duration {
+    /// Wrap the provided duration.
+    pub(crate) const fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            sign_placement: Iso8601SignPlacement::Leading,
+            zero_components: false,
+        }
+    }
+
+    /// Set where the sign of a negative duration is written. This has no effect on parsing, which
+    /// accepts either placement.
+    pub const fn set_sign_placement(self, sign_placement: Iso8601SignPlacement) -> Self {
+        Self {
+            sign_placement,
+            ..self
+        }
+    }
+
+    /// Set whether components that are zero are written, such as `P0DT0H5M0S` rather than `PT5M`.
+    /// This has no effect on parsing.
+    pub const fn set_zero_components(self, zero_components: bool) -> Self {
+        Self {
+            zero_components,
+            ..self
+        }
+    }
+}
+
+impl From<Iso8601Duration> for Duration {
+    fn from(value: Iso8601Duration) -> Self {
+        value.duration
+    }
+}
+
+impl Iso8601Duration {
+    /// Write the duration without padding.
+    fn fmt_unpadded(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let duration = self.duration;
+        let sign = if duration.is_negative() { "-" } else { "" };
+        let (leading_sign, component_sign) = match self.sign_placement {
+            Iso8601SignPlacement::Leading => (sign, ""),
+            Iso8601SignPlacement::Components => ("", sign),
+        };
+        // Zero components are never signed.
+        let sign_of = |is_zero: bool| if is_zero { "" } else { component_sign };
+
+        let seconds = duration.whole_seconds().unsigned_abs();
+        let nanoseconds = duration.subsec_nanoseconds().unsigned_abs();
+        let days = seconds / Second::per(Day).extend::<u64>();
+        let hours = seconds / Second::per(Hour).extend::<u64>() % Hour::per(Day).extend::<u64>();
+        let minutes =
+            seconds / Second::per(Minute).extend::<u64>() % Minute::per(Hour).extend::<u64>();
+        let seconds = seconds % Second::per(Minute).extend::<u64>();
+        let seconds_are_zero = seconds == 0 && nanoseconds == 0;
+
+        write!(f, "{leading_sign}P")?;
+        if days != 0 || self.zero_components {
+            write!(f, "{}{days}D", sign_of(days == 0))?;
+        }
+        if hours == 0 && minutes == 0 && seconds_are_zero && days != 0 && !self.zero_components {
+            return Ok(());
+        }
+
+        f.write_str("T")?;
+        if hours != 0 || self.zero_components {
+            write!(f, "{}{hours}H", sign_of(hours == 0))?;
+        }
+        if minutes != 0 || self.zero_components {
+            write!(f, "{}{minutes}M", sign_of(minutes == 0))?;
+        }
+        // The seconds are written for a zero duration, as there must be at least one component.
+        if !seconds_are_zero || self.zero_components || (hours == 0 && minutes == 0 && days == 0) {
+            f.write_str(sign_of(seconds_are_zero))?;
+            fmt_decimal(f, seconds, nanoseconds, Nanosecond::per(Second))?;
+            f.write_str("S")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SmartDisplay for Iso8601Duration {
+    type Metadata = ();
+
+    fn metadata(&self, _: FormatterOptions) -> Metadata<Self> {
+        let mut width = WidthCounter::default();
+        // Writing to the counter never fails.
+        let _ = self.fmt_unpadded(&mut width);
+        Metadata::new(width.0, self, ())
+    }
+
+    fn fmt_with_metadata(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        metadata: Metadata<Self>,
+    ) -> fmt::Result {
+        f.pad_with_width(
+            metadata.unpadded_width(),
+            format_args!(
+                "{}",
+                DisplayFn(|f: &mut dyn fmt::Write| self.fmt_unpadded(f))
+            ),
+        )
+    }
+}
+
+impl fmt::Display for Iso8601Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() {
+            return self.fmt_unpadded(f);
+        }
+        SmartDisplay::fmt(self, f)
+    }
+}
+
+impl FromStr for Iso8601Duration {
+    type Err = InvalidDuration;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use InvalidDuration::{InvalidSyntax, OutOfRange};
+
+        /// Split the input after the leading ASCII digits.
+        fn split_digits(s: &[u8]) -> (&[u8], &[u8]) {
+            s.split_at(s.iter().take_while(|byte| byte.is_ascii_digit()).count())
+        }
+
+        /// Split the input after a leading sign, returning whether the sign was negative.
+        fn split_sign(s: &[u8]) -> (bool, &[u8]) {
+            match s {
+                [b'-', rest @ ..] => (true, rest),
+                [b'+', rest @ ..] => (false, rest),
+                _ => (false, s),
+            }
+        }
+
+        let (is_negative, s) = split_sign(s.as_bytes());
+        let [b'P' | b'p', s @ ..] = s else {
+            return Err(InvalidSyntax);
+        };
+        let mut s = s;
+
+        let mut total_nanoseconds = 0_i128;
+        // Whether the `T` separating the date and time components has been seen.
+        let mut in_time = false;
+        // Whether a component has been parsed since the most recent designator (`P` or `T`).
+        let mut component_present = false;
+        // The smallest unit parsed so far, in nanoseconds. Components must be in descending order.
+        let mut previous_unit = u64::MAX;
+
+        while !s.is_empty() {
+            if let [b'T' | b't', rest @ ..] = s {
+                if in_time {
+                    return Err(InvalidSyntax);
+                }
+                in_time = true;
+                component_present = false;
+                s = rest;
+                continue;
+            }
+
+            let (component_is_negative, rest) = split_sign(s);
+            let (whole, rest) = split_digits(rest);
+            if whole.is_empty() {
+                return Err(InvalidSyntax);
+            }
+            let (fraction, rest) = match rest {
+                [b'.' | b',', rest @ ..] => match split_digits(rest) {
+                    ([], _) => return Err(InvalidSyntax),
+                    split => split,
+                },
+                _ => (&[][..], rest),
+            };
+            let (nanoseconds_per_unit, rest) = match (in_time, rest) {
+                (false, [b'D' | b'd', rest @ ..]) => (Nanosecond::per(Day), rest),
+                (true, [b'H' | b'h', rest @ ..]) => (Nanosecond::per(Hour), rest),
+                (true, [b'M' | b'm', rest @ ..]) => (Nanosecond::per(Minute), rest),
+                (true, [b'S' | b's', rest @ ..]) => (Nanosecond::per(Second).extend(), rest),
+                _ => return Err(InvalidSyntax),
+            };
+            if nanoseconds_per_unit >= previous_unit
+                || (!fraction.is_empty()
+                    && nanoseconds_per_unit != Nanosecond::per(Second).extend::<u64>())
+            {
+                return Err(InvalidSyntax);
+            }
+            previous_unit = nanoseconds_per_unit;
+            component_present = true;
+            s = rest;
+
+            let whole = whole
+                .iter()
+                .try_fold(0_i128, |value, digit| {
+                    value.checked_mul(10)?.checked_add(i128::from(digit - b'0'))
+                })
+                .ok_or(OutOfRange)?;
+            let mut subsecond = 0_i128;
+            let mut multiplier = i128::from(Nanosecond::per(Second) / 10);
+            for digit in fraction.iter().take(9) {
+                subsecond += i128::from(digit - b'0') * multiplier;
+                multiplier /= 10;
+            }
+
+            let mut value = whole
+                .checked_mul(i128::from(nanoseconds_per_unit))
+                .and_then(|value| value.checked_add(subsecond))
+                .ok_or(OutOfRange)?;
+            if component_is_negative {
+                value = -value;
+            }
+            total_nanoseconds = total_nanoseconds.checked_add(value).ok_or(OutOfRange)?;
+        }
+
+        if !component_present {
+            return Err(InvalidSyntax);
+        }
+        if is_negative {
+            total_nanoseconds = -total_nanoseconds;
+        }
+
+        let nanoseconds_per_second = i128::from(Nanosecond::per(Second));
+        let seconds =
+            i64::try_from(total_nanoseconds / nanoseconds_per_second).map_err(|_| OutOfRange)?;
+        let nanoseconds = (total_nanoseconds % nanoseconds_per_second).truncate();
+
+        Ok(Self::new(Duration::new(seconds, nanoseconds)))
+    }
+}
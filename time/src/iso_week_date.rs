@@ -0,0 +1,152 @@
+//! The [`IsoWeekDate`] struct and its associated `impl`s.
+
+use core::cmp::{self, Ordering};
+use core::fmt;
+
+use num_conv::prelude::*;
+use powerfmt::ext::FormatterExt;
+use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
+
+use crate::ext::DigitCount;
+use crate::util::DisplayFn;
+use crate::{error, Date, Weekday};
+
+/// A date expressed as its ISO 8601 year, week number, and weekday.
+///
+/// This is the structured counterpart of [`Date::to_iso_week_date`]. Unlike a tuple, the meaning
+/// of each field is explicit, so the values cannot be accidentally transposed.
+///
+/// As the fields are public, an `IsoWeekDate` may not represent a valid date. Validity is checked
+/// when converting to a [`Date`].
+///
+/// ```rust
+/// # use time::{Date, Weekday};
+/// # use time_macros::date;
+/// let iso_week_date = date!(2021-01-01).iso_week_date();
+/// assert_eq!(iso_week_date.year, 2020);
+/// assert_eq!(iso_week_date.week, 53);
+/// assert_eq!(iso_week_date.weekday, Weekday::Friday);
+/// assert_eq!(iso_week_date.to_string(), "2020-W53-5");
+/// assert_eq!(Date::try_from(iso_week_date), Ok(date!(2021-01-01)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IsoWeekDate {
+    /// The ISO 8601 year, which may differ from the calendar year near the start and end of the
+    /// year.
+    pub year: i32,
+    /// The ISO 8601 week number.
+    pub week: u8,
+    /// The day of the week.
+    pub weekday: Weekday,
+}
+
+impl IsoWeekDate {
+    /// Convert the `IsoWeekDate` to a [`Date`], returning an error if it does not represent a
+    /// valid date.
+    ///
+    /// ```rust
+    /// # use time::{IsoWeekDate, Weekday};
+    /// # use time_macros::date;
+    /// let iso_week_date = IsoWeekDate {
+    ///     year: 2019,
+    ///     week: 1,
+    ///     weekday: Weekday::Tuesday,
+    /// };
+    /// assert_eq!(iso_week_date.to_date(), Ok(date!(2019-01-01)));
+    ///
+    /// let iso_week_date = IsoWeekDate { week: 53, ..iso_week_date };
+    /// assert!(iso_week_date.to_date().is_err());
+    /// ```
+    pub const fn to_date(self) -> Result<Date, error::ComponentRange> {
+        Date::from_iso_week_date(self.year, self.week, self.weekday)
+    }
+
+    /// Write the date without padding.
+    fn fmt_unpadded(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        let Self {
+            year,
+            week,
+            weekday,
+        } = *self;
+        let weekday = weekday.number_from_monday();
+        if (0..10_000).contains(&year) {
+            write!(f, "{year:04}-W{week:02}-{weekday}")
+        } else {
+            write!(f, "{year:+05}-W{week:02}-{weekday}")
+        }
+    }
+}
+
+impl PartialOrd for IsoWeekDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IsoWeekDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.year
+            .cmp(&other.year)
+            .then_with(|| self.week.cmp(&other.week))
+            .then_with(|| {
+                self.weekday
+                    .number_from_monday()
+                    .cmp(&other.weekday.number_from_monday())
+            })
+    }
+}
+
+impl SmartDisplay for IsoWeekDate {
+    type Metadata = ();
+
+    fn metadata(&self, _: FormatterOptions) -> Metadata<Self> {
+        // There is a minimum of four digits for any year, plus the sign if one is displayed.
+        let year_width = cmp::max(self.year.unsigned_abs().num_digits(), 4)
+            + u8::from(!(0..10_000).contains(&self.year));
+        let width = year_width.extend::<usize>()
+            + smart_display::padded_width_of!(
+                "-W",
+                self.week => width(2),
+                "-",
+                self.weekday.number_from_monday(),
+            );
+        Metadata::new(width, self, ())
+    }
+
+    fn fmt_with_metadata(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        metadata: Metadata<Self>,
+    ) -> fmt::Result {
+        f.pad_with_width(
+            metadata.unpadded_width(),
+            format_args!(
+                "{}",
+                DisplayFn(|f: &mut dyn fmt::Write| self.fmt_unpadded(f))
+            ),
+        )
+    }
+}
+
+impl fmt::Display for IsoWeekDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() {
+            return self.fmt_unpadded(f);
+        }
+        SmartDisplay::fmt(self, f)
+    }
+}
+
+impl From<Date> for IsoWeekDate {
+    fn from(date: Date) -> Self {
+        date.iso_week_date()
+    }
+}
+
+impl TryFrom<IsoWeekDate> for Date {
+    type Error = error::ComponentRange;
+
+    fn try_from(iso_week_date: IsoWeekDate) -> Result<Self, Self::Error> {
+        iso_week_date.to_date()
+    }
+}
@@ -26,6 +26,11 @@
 //!
 //!   Enables parsing of most structs.
 //!
+//! - `compat-0-2` (_implicitly enables `formatting` and `parsing`_)
+//!
+//!   Enables the [`compat_0_2`] module, which provides formatting and parsing using the
+//!   `%`-based format strings of `time` 0.2. This is intended to ease staged migrations.
+//!
 //! - `local-offset` (_implicitly enables `std`_)
 //!
 //!   This feature enables a number of methods that allow obtaining the system's UTC offset.
@@ -54,17 +59,73 @@
 //!
 //! - `rand`
 //!
-//!   Enables [rand](https://docs.rs/rand) support for all types.
+//!   Enables [rand](https://docs.rs/rand) support for all types, including sampling from ranges of
+//!   values.
 //!
 //! - `quickcheck` (_implicitly enables `alloc`_)
 //!
 //!   Enables [quickcheck](https://docs.rs/quickcheck) support for all types.
 //!
+//! - `borsh` (_implicitly enables `alloc`_)
+//!
+//!   Enables [borsh](https://docs.rs/borsh) support for all types. Each type is encoded identically
+//!   to its non-human-readable serde representation, using fixed-width little-endian integers.
+//!   This feature requires Rust 1.77 or newer, regardless of the minimum supported Rust version of
+//!   this crate.
+//!
+//! - `rkyv`
+//!
+//!   Enables [rkyv](https://docs.rs/rkyv) support for [`Date`], [`Time`], [`Duration`],
+//!   [`UtcOffset`], [`PrimitiveDateTime`], [`OffsetDateTime`], and [`UtcDateTime`]. The archived
+//!   types are located in the [`rkyv`] module. This feature requires Rust 1.81 or
+//!   newer, regardless of the minimum supported Rust version of this crate.
+//!
+//! - `chrono-interop`
+//!
+//!   Enables conversions to and from the types of [chrono](https://docs.rs/chrono), such as
+//!   `NaiveDate`, `NaiveDateTime`, `DateTime<Utc>`, and `TimeDelta`. Conversions that may not
+//!   succeed, such as those involving dates outside the range supported by both crates, use
+//!   `TryFrom`.
+//!
+//! - `tracing` (_implicitly enables `formatting`_)
+//!
+//!   Enables the [`tracing`](crate::tracing) module, which provides a timestamp formatter for
+//!   [tracing-subscriber](https://docs.rs/tracing-subscriber) that accepts any format description.
+//!
+//! - `bench-hooks` (_implicitly enables `std`_)
+//!
+//!   Enables the [`bench_hooks`](crate::bench_hooks) module, which exposes per-thread counters of
+//!   the work performed by formatting and parsing. This is intended for performance tests and
+//!   should only be enabled as a development dependency.
+//!
+//! - `test-util` (_implicitly enables `std`_)
+//!
+//!   Enables the [`test_util`](crate::test_util) module, which provides a
+//!   [`MockClock`](crate::test_util::MockClock) for freezing and advancing the current time on a
+//!   per-thread basis. This is intended for tests and should only be enabled as a development
+//!   dependency.
+//!
 //! - `wasm-bindgen`
 //!
 //!   Enables [wasm-bindgen](https://github.com/rustwasm/wasm-bindgen) support for converting
 //!   [JavaScript dates](https://rustwasm.github.io/wasm-bindgen/api/js_sys/struct.Date.html), as
-//!   well as obtaining the UTC offset from JavaScript.
+//!   well as obtaining the UTC offset from JavaScript. On targets where [`std::time::Instant`] is
+//!   not implemented, this also provides `WasmInstant`, a monotonic clock backed by
+//!   `performance.now()`. This type does not exist on other targets.
+//!
+//! # Removing month and weekday names
+//!
+//! The English names of months and weekdays are always available by default. Building with
+//! `RUSTFLAGS="--cfg time_no_names"` removes them to reduce binary size. This removes the
+//! [`Display`] and [`FromStr`] implementations of [`Month`] and [`Weekday`], the textual
+//! representations of the `month` and `weekday` components of format descriptions, and the
+//! RFC 2822 well-known format. Format descriptions using a textual month or weekday are then
+//! rejected when parsed at runtime. Those created by the `format_description!` macro are accepted,
+//! but formatting or parsing with them returns an error. Human-readable serialization of [`Month`]
+//! and [`Weekday`] uses their numeric value.
+//!
+//! [`Display`]: core::fmt::Display
+//! [`FromStr`]: core::str::FromStr
 
 #![doc(html_playground_url = "https://play.rust-lang.org")]
 #![cfg_attr(docsrs, feature(doc_auto_cfg, doc_notable_trait))]
@@ -80,7 +141,19 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+mod backoff;
+#[cfg(feature = "bench-hooks")]
+pub mod bench_hooks;
+#[cfg(feature = "borsh")]
+mod borsh;
+mod calendar_date;
+#[cfg(feature = "chrono-interop")]
+mod chrono;
+pub mod clock;
+#[cfg(feature = "compat-0-2")]
+pub mod compat_0_2;
 mod date;
+mod display_seconds;
 mod duration;
 pub mod error;
 pub mod ext;
@@ -89,9 +162,13 @@ pub mod format_description;
 #[cfg(feature = "formatting")]
 pub mod formatting;
 mod hint;
+mod human_duration;
 #[cfg(feature = "std")]
 mod instant;
 mod internal_macros;
+pub mod interval;
+mod iso8601_duration;
+mod iso_week_date;
 #[cfg(feature = "macros")]
 pub mod macros;
 mod month;
@@ -102,33 +179,57 @@ mod primitive_date_time;
 #[cfg(feature = "quickcheck")]
 mod quickcheck;
 #[cfg(feature = "rand")]
-mod rand;
+pub mod rand;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
+mod rounding;
+pub mod schedule;
 #[cfg(feature = "serde")]
 pub mod serde;
 mod sys;
+#[cfg(feature = "test-util")]
+pub mod test_util;
 #[cfg(test)]
 mod tests;
 mod time;
+pub mod time_zone;
+#[cfg(feature = "tracing")]
+pub mod tracing;
 mod utc_date_time;
 mod utc_offset;
 pub mod util;
+internal_macros::cfg_wasm_bindgen! {
+    #[cfg(feature = "std")]
+    mod wasm_instant;
+}
 mod weekday;
 
 pub use time_core::convert;
 
+pub use crate::backoff::Backoff;
+pub use crate::calendar_date::CalendarDate;
 pub use crate::date::Date;
+pub use crate::display_seconds::DisplaySeconds;
 pub use crate::duration::Duration;
 pub use crate::error::Error;
+pub use crate::human_duration::HumanDuration;
 #[doc(hidden)]
 #[cfg(feature = "std")]
 #[allow(deprecated)]
 pub use crate::instant::Instant;
+pub use crate::iso8601_duration::{Iso8601Duration, Iso8601SignPlacement};
+pub use crate::iso_week_date::IsoWeekDate;
 pub use crate::month::Month;
 pub use crate::offset_date_time::OffsetDateTime;
 pub use crate::primitive_date_time::PrimitiveDateTime;
+pub use crate::rounding::Rounding;
 pub use crate::time::Time;
 pub use crate::utc_date_time::UtcDateTime;
 pub use crate::utc_offset::UtcOffset;
+internal_macros::cfg_wasm_bindgen! {
+    #[cfg(feature = "std")]
+    pub use crate::wasm_instant::WasmInstant;
+}
 pub use crate::weekday::Weekday;
 
 /// An alias for [`std::result::Result`] with a generic error from the time crate.
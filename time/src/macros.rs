@@ -1,4 +1,20 @@
 //! Macros to construct statically known values.
+//!
+//! The macros expand to paths beginning with `::time`. When the crate is not available under
+//! that name, such as when it is renamed in `Cargo.toml` or only reachable through a re-export,
+//! the path can be provided as a leading `crate = path::to::time,` argument. This is accepted by
+//! every macro, including [`serde::format_description!`](crate::serde::format_description).
+//!
+//! ```rust
+//! mod reexport {
+//!     pub use ::time::*;
+//! }
+//!
+//! # use time::{Date, Month};
+//! const DATE: time::Date = time::macros::date!(crate = reexport, 2020-01-01);
+//! assert_eq!(DATE, Date::from_calendar_date(2020, Month::January, 1)?);
+//! # Ok::<_, time::Error>(())
+//! ```
 
 /// Construct a [`Date`](crate::Date) with a statically known value.
 ///
@@ -48,6 +64,38 @@ pub use time_macros::date;
 /// );
 /// # Ok::<_, time::Error>(())
 /// ```
+///
+/// The offset may also be given using constant expressions, as described for [`offset!`].
+///
+/// ```rust
+/// # use time::{Date, Month, macros::datetime, UtcOffset};
+/// const HOURS: i8 = -5;
+/// assert_eq!(
+///     datetime!(2020-01-01 0:00 hours = HOURS, minutes = -30),
+///     Date::from_calendar_date(2020, Month::January, 1)?.midnight()
+///         .assume_offset(UtcOffset::from_hms(-5, -30, 0)?)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// Likewise, the subsecond may be given as a constant expression, as described for [`time!`].
+///
+/// The offset may be separated from the time by a comma. This is required when the subsecond is
+/// given as an expression, as the expression otherwise continues to the end of the input. For
+/// example, `nanosecond = NANOS +1` is the expression `NANOS + 1` without an offset.
+///
+/// ```rust
+/// # use time::{Date, Month, macros::datetime, UtcOffset};
+/// const NANOS: u32 = 500_000_000;
+/// assert_eq!(
+///     datetime!(2020-01-01 0:00, nanosecond = NANOS, +1),
+///     Date::from_calendar_date(2020, Month::January, 1)?
+///         .with_hms_nano(0, 0, 0, 500_000_000)?
+///         .assume_offset(UtcOffset::from_hms(1, 0, 0)?)
+/// );
+/// assert_eq!(datetime!(2020-01-01 0:00, +1), datetime!(2020-01-01 0:00 +1));
+/// # Ok::<_, time::Error>(())
+/// ```
 pub use time_macros::datetime;
 /// Equivalent of performing [`format_description::parse()`] at compile time.
 ///
@@ -69,7 +117,48 @@ pub use time_macros::datetime;
 /// The syntax accepted by this macro is the same as [`format_description::parse()`], which can
 /// be found in [the book](https://time-rs.github.io/book/api/format-description.html).
 ///
+/// Rather than a single string literal, multiple comma-separated literals may be provided. These
+/// are joined before being parsed. Invocations of [`concat!`] are accepted in place of any literal.
+/// This allows a shared fragment to be written once, such as in a `macro_rules!` macro that
+/// forwards its input. Note that other macros are not expanded and the contents of a `const`
+/// cannot be inspected; to reuse an existing description, use
+/// [`BorrowedFormatItem::Compound`](crate::format_description::BorrowedFormatItem::Compound).
+///
+#[cfg_attr(feature = "alloc", doc = "```rust")]
+#[cfg_attr(not(feature = "alloc"), doc = "```rust,ignore")]
+/// # use time::{format_description, macros::format_description};
+/// macro_rules! with_date {
+///     ($($rest:literal),*) => {
+///         format_description!("[year]-[month]-[day]", $($rest),*)
+///     };
+/// }
+///
+/// assert_eq!(
+///     with_date!(" ", "[hour]:[minute]"),
+///     format_description::parse("[year]-[month]-[day] [hour]:[minute]")?
+/// );
+/// assert_eq!(
+///     format_description!(concat!("[hour]", ":[minute]"), ":[second]"),
+///     format_description::parse("[hour]:[minute]:[second]")?
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// The version of the format description grammar may be provided as the first argument, such as
+/// `version = 2`. If omitted, [`DEFAULT_VERSION`] is used.
+///
+#[cfg_attr(feature = "alloc", doc = "```rust")]
+#[cfg_attr(not(feature = "alloc"), doc = "```rust,ignore")]
+/// # use time::{format_description, macros::format_description};
+/// assert_eq!(
+///     format_description!(version = 2, r"[hour]\[[minute]"),
+///     format_description::parse_v2(r"[hour]\[[minute]")?
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
 /// [`format_description::parse()`]: crate::format_description::parse()
+/// [`DEFAULT_VERSION`]: crate::format_description::DEFAULT_VERSION
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 pub use time_macros::format_description;
 /// Construct a [`UtcOffset`](crate::UtcOffset) with a statically known value.
@@ -94,6 +183,35 @@ pub use time_macros::format_description;
 /// assert_eq!(offset!(-23:59:59), UtcOffset::from_hms(-23, -59, -59)?);
 /// # Ok::<_, time::Error>(())
 /// ```
+///
+/// Alternatively, the components may be provided as constant expressions in the form
+/// `hours = EXPR, minutes = EXPR, seconds = EXPR`, where `minutes` and `seconds` are optional and
+/// default to zero. This is useful for generated code, as the values do not need to be written
+/// as literals. The values are passed to [`UtcOffset::from_hms`](crate::UtcOffset::from_hms), so
+/// the signs of the components must match. An invalid value results in an error when the constant
+/// is evaluated at compile time. Expressions containing a comma that is not nested within
+/// parentheses, brackets, or braces must be wrapped in parentheses.
+///
+/// ```rust
+/// # use time::{UtcOffset, macros::offset};
+/// const HOURS: i8 = 5;
+/// assert_eq!(offset!(hours = HOURS), UtcOffset::from_hms(5, 0, 0)?);
+/// assert_eq!(
+///     offset!(hours = -HOURS, minutes = -30),
+///     UtcOffset::from_hms(-5, -30, 0)?
+/// );
+/// assert_eq!(
+///     offset!(hours = 1, minutes = 2, seconds = 3),
+///     UtcOffset::from_hms(1, 2, 3)?
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// ```rust,compile_fail
+/// # use time::macros::offset;
+/// const HOURS: i8 = 26;
+/// let _ = offset!(hours = HOURS);
+/// ```
 pub use time_macros::offset;
 /// Construct a [`Time`](crate::Time) with a statically known value.
 ///
@@ -129,6 +247,29 @@ pub use time_macros::offset;
 /// );
 /// # Ok::<_, time::Error>(())
 /// ```
+///
+/// The subsecond may instead be given as a constant expression by following the time with
+/// `, nanosecond = EXPR`, in which case the seconds must not have a fractional part. The value is
+/// passed to [`Time::from_hms_nano`](crate::Time::from_hms_nano), so an invalid value results in
+/// an error when the constant is evaluated at compile time. As with [`offset!`], an expression
+/// containing a comma that is not nested within parentheses, brackets, or braces must be wrapped
+/// in parentheses.
+///
+/// ```rust
+/// # use time::{Time, macros::time};
+/// const MILLIS: u32 = 250;
+/// assert_eq!(
+///     time!(1:02:03, nanosecond = MILLIS * 1_000_000),
+///     Time::from_hms_nano(1, 2, 3, 250_000_000)?
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// ```rust,compile_fail
+/// # use time::macros::time;
+/// const NANOS: u32 = 1_000_000_000;
+/// let _ = time!(0:00, nanosecond = NANOS);
+/// ```
 pub use time_macros::time;
 /// Construct a [`UtcDateTime`] with a statically known value.
 ///
@@ -1,9 +1,12 @@
 //! The `Month` enum and its associated `impl`s.
 
+#[cfg(not(time_no_names))]
 use core::fmt;
 use core::num::NonZeroU8;
+#[cfg(not(time_no_names))]
 use core::str::FromStr;
 
+#[cfg(not(time_no_names))]
 use powerfmt::smart_display::{FormatterOptions, Metadata, SmartDisplay};
 
 use self::Month::*;
@@ -61,6 +64,7 @@ impl Month {
                 maximum: 12,
                 value: n as _,
                 conditional_message: None,
+                conditional_date: None,
             }),
         }
     }
@@ -185,13 +189,16 @@ impl Month {
     }
 }
 
+#[cfg(not(time_no_names))]
 mod private {
     #[non_exhaustive]
     #[derive(Debug, Clone, Copy)]
     pub struct MonthMetadata;
 }
+#[cfg(not(time_no_names))]
 use private::MonthMetadata;
 
+#[cfg(not(time_no_names))]
 impl SmartDisplay for Month {
     type Metadata = MonthMetadata;
 
@@ -230,31 +237,49 @@ impl SmartDisplay for Month {
     }
 }
 
+#[cfg(not(time_no_names))]
 impl fmt::Display for Month {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         SmartDisplay::fmt(self, f)
     }
 }
 
+#[cfg(not(time_no_names))]
 impl FromStr for Month {
     type Err = error::InvalidVariant;
 
+    /// Parse a month from its English name. Both the full name (`"January"`) and the three-letter
+    /// abbreviation (`"Jan"`) are accepted, ignoring ASCII case. The accepted values are not
+    /// affected by the system locale.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!("January".parse(), Ok(Month::January));
+    /// assert_eq!("january".parse(), Ok(Month::January));
+    /// assert_eq!("JAN".parse(), Ok(Month::January));
+    /// assert!("Ja".parse::<Month>().is_err());
+    /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "January" => Ok(January),
-            "February" => Ok(February),
-            "March" => Ok(March),
-            "April" => Ok(April),
-            "May" => Ok(May),
-            "June" => Ok(June),
-            "July" => Ok(July),
-            "August" => Ok(August),
-            "September" => Ok(September),
-            "October" => Ok(October),
-            "November" => Ok(November),
-            "December" => Ok(December),
-            _ => Err(error::InvalidVariant),
-        }
+        const NAMES: [(&str, Month); 12] = [
+            ("January", January),
+            ("February", February),
+            ("March", March),
+            ("April", April),
+            ("May", May),
+            ("June", June),
+            ("July", July),
+            ("August", August),
+            ("September", September),
+            ("October", October),
+            ("November", November),
+            ("December", December),
+        ];
+
+        NAMES
+            .iter()
+            .find(|(name, _)| s.eq_ignore_ascii_case(name) || s.eq_ignore_ascii_case(&name[..3]))
+            .map(|&(_, month)| month)
+            .ok_or(error::InvalidVariant)
     }
 }
 
@@ -276,6 +301,7 @@ impl TryFrom<u8> for Month {
                 maximum: 12,
                 value: 0,
                 conditional_message: None,
+                conditional_date: None,
             }),
         }
     }
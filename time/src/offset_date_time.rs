@@ -25,7 +25,8 @@ use crate::internal_macros::{
     cascade, const_try, const_try_opt, div_floor, ensure_ranged, expect_opt,
 };
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{FromParsed, Parsable, ParserOptions};
+use crate::time_zone::TimeZone;
 use crate::{
     error, util, Date, Duration, Month, PrimitiveDateTime, Time, UtcDateTime, UtcOffset, Weekday,
 };
@@ -82,8 +83,69 @@ impl OffsetDateTime {
         UtcOffset::UTC,
     );
 
+    /// The smallest value that can be represented by `OffsetDateTime`.
+    ///
+    /// Depending on `large-dates` feature flag, value of this constant may vary.
+    ///
+    /// 1. With `large-dates` disabled it is equal to `-9999-01-01 00:00:00.0 +00:00:00`
+    /// 2. With `large-dates` enabled it is equal to `-999999-01-01 00:00:00.0 +00:00:00`
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, PrimitiveDateTime};
+    /// # use time_macros::datetime;
+    #[cfg_attr(
+        feature = "large-dates",
+        doc = "// Assuming `large-dates` feature is enabled."
+    )]
+    #[cfg_attr(
+        feature = "large-dates",
+        doc = "assert_eq!(OffsetDateTime::MIN, datetime!(-999999-01-01 0:00 UTC));"
+    )]
+    #[cfg_attr(
+        not(feature = "large-dates"),
+        doc = "// Assuming `large-dates` feature is disabled."
+    )]
+    #[cfg_attr(
+        not(feature = "large-dates"),
+        doc = "assert_eq!(OffsetDateTime::MIN, datetime!(-9999-01-01 0:00 UTC));"
+    )]
+    /// assert_eq!(OffsetDateTime::MIN, PrimitiveDateTime::MIN.assume_utc());
+    /// ```
+    pub const MIN: Self = PrimitiveDateTime::MIN.assume_utc();
+
+    /// The largest value that can be represented by `OffsetDateTime`.
+    ///
+    /// Depending on `large-dates` feature flag, value of this constant may vary.
+    ///
+    /// 1. With `large-dates` disabled it is equal to `9999-12-31 23:59:59.999_999_999 +00:00:00`
+    /// 2. With `large-dates` enabled it is equal to `999999-12-31 23:59:59.999_999_999 +00:00:00`
+    ///
+    /// ```rust
+    /// # use time::{OffsetDateTime, PrimitiveDateTime};
+    /// # use time_macros::datetime;
+    #[cfg_attr(
+        feature = "large-dates",
+        doc = "// Assuming `large-dates` feature is enabled."
+    )]
+    #[cfg_attr(
+        feature = "large-dates",
+        doc = "assert_eq!(OffsetDateTime::MAX, datetime!(+999999-12-31 23:59:59.999_999_999 UTC));"
+    )]
+    #[cfg_attr(
+        not(feature = "large-dates"),
+        doc = "// Assuming `large-dates` feature is disabled."
+    )]
+    #[cfg_attr(
+        not(feature = "large-dates"),
+        doc = "assert_eq!(OffsetDateTime::MAX, datetime!(+9999-12-31 23:59:59.999_999_999 UTC));"
+    )]
+    /// assert_eq!(OffsetDateTime::MAX, PrimitiveDateTime::MAX.assume_utc());
+    /// ```
+    pub const MAX: Self = PrimitiveDateTime::MAX.assume_utc();
+
     // region: now
-    /// Create a new `OffsetDateTime` with the current date and time in UTC.
+    /// Create a new `OffsetDateTime` with the current date and time in UTC. This is obtained from
+    /// the global [`Clock`](crate::clock::Clock), which is the system clock by default.
     ///
     /// ```rust
     /// # use time::OffsetDateTime;
@@ -93,21 +155,7 @@ impl OffsetDateTime {
     /// ```
     #[cfg(feature = "std")]
     pub fn now_utc() -> Self {
-        #[cfg(all(
-            target_family = "wasm",
-            not(any(target_os = "emscripten", target_os = "wasi")),
-            feature = "wasm-bindgen"
-        ))]
-        {
-            js_sys::Date::new_0().into()
-        }
-
-        #[cfg(not(all(
-            target_family = "wasm",
-            not(any(target_os = "emscripten", target_os = "wasi")),
-            feature = "wasm-bindgen"
-        )))]
-        SystemTime::now().into()
+        crate::clock::now_utc().into()
     }
 
     /// Attempt to create a new `OffsetDateTime` with the current date and time in the local offset.
@@ -126,6 +174,63 @@ impl OffsetDateTime {
     }
     // endregion now
 
+    /// Create a new `OffsetDateTime` in UTC from the provided [`SystemTime`], returning an error
+    /// if the value is outside the range supported by this crate. Unlike the [`From`]
+    /// implementation, this never panics. See [`UtcDateTime::try_from_system_time`] for details.
+    ///
+    /// ```rust
+    /// # use std::time::{Duration, SystemTime};
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::try_from_system_time(SystemTime::UNIX_EPOCH - Duration::from_secs(1)),
+    ///     Ok(datetime!(1969-12-31 23:59:59 UTC))
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_from_system_time(system_time: SystemTime) -> Result<Self, error::ConversionRange> {
+        UtcDateTime::try_from_system_time(system_time).map(Self::from)
+    }
+
+    /// Create a new `OffsetDateTime` in UTC from the provided [`SystemTime`], saturating to the
+    /// minimum or maximum supported value if it is outside the range supported by this crate.
+    ///
+    /// ```rust
+    /// # use std::time::SystemTime;
+    /// # use time::OffsetDateTime;
+    /// assert_eq!(
+    ///     OffsetDateTime::saturating_from_system_time(SystemTime::UNIX_EPOCH),
+    ///     OffsetDateTime::UNIX_EPOCH
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn saturating_from_system_time(system_time: SystemTime) -> Self {
+        UtcDateTime::saturating_from_system_time(system_time).into()
+    }
+
+    /// Convert the `OffsetDateTime` into a [`SystemTime`], returning an error if the value cannot
+    /// be represented. Unlike the [`From`] implementation, this never panics. See
+    /// [`UtcDateTime::try_to_system_time`] for details.
+    ///
+    /// ```rust
+    /// # use std::time::SystemTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(1970-01-01 1:00 +1).try_to_system_time(),
+    ///     Ok(SystemTime::UNIX_EPOCH)
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_to_system_time(self) -> Result<SystemTime, error::ConversionRange> {
+        let duration = self - Self::UNIX_EPOCH;
+        if duration.is_negative() {
+            SystemTime::UNIX_EPOCH.checked_sub(duration.unsigned_abs())
+        } else {
+            SystemTime::UNIX_EPOCH.checked_add(duration.unsigned_abs())
+        }
+        .ok_or(error::ConversionRange)
+    }
+
     /// Create a new `OffsetDateTime` with the given [`Date`], [`Time`], and [`UtcOffset`].
     ///
     /// ```
@@ -162,6 +267,47 @@ impl OffsetDateTime {
         PrimitiveDateTime::new(date, time).assume_utc()
     }
 
+    /// Create a new `OffsetDateTime` from the Julian day and the provided [`Time`] and
+    /// [`UtcOffset`]. The Julian day is that of the local date.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::{datetime, offset, time};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_julian_day(2_451_545, time!(12:00), offset!(+1)),
+    ///     Ok(datetime!(2000-01-01 12:00 +1)),
+    /// );
+    /// ```
+    #[doc(alias = "from_julian_day_with_time")]
+    pub const fn from_julian_day(
+        julian_day: i32,
+        time: Time,
+        offset: UtcOffset,
+    ) -> Result<Self, error::ComponentRange> {
+        Ok(Self::new_in_offset(
+            const_try!(Date::from_julian_day(julian_day)),
+            time,
+            offset,
+        ))
+    }
+
+    /// Create a new `OffsetDateTime` in UTC from the fractional Julian date.
+    ///
+    /// Following the astronomical convention, each Julian date begins at noon UTC. See
+    /// [`PrimitiveDateTime::from_julian_date`] for details on precision.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::from_julian_date(2_451_545.0),
+    ///     Ok(datetime!(2000-01-01 12:00 UTC)),
+    /// );
+    /// ```
+    pub fn from_julian_date(julian_date: f64) -> Result<Self, error::ComponentRange> {
+        PrimitiveDateTime::from_julian_date(julian_date).map(PrimitiveDateTime::assume_utc)
+    }
+
     /// Convert the `OffsetDateTime` from the current [`UtcOffset`] to the provided [`UtcOffset`].
     ///
     /// ```rust
@@ -281,6 +427,53 @@ impl OffsetDateTime {
         )
     }
 
+    /// Convert the `OffsetDateTime` to the offset in effect in the provided [`TimeZone`] at the
+    /// same instant.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!(2000-01-01 0:00 UTC)
+    ///         .to_timezone(&offset!(-1))
+    ///         .year(),
+    ///     1999,
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the UTC date-time or the local date-time in the time zone is outside
+    /// the supported range.
+    pub fn to_timezone(self, time_zone: &(impl TimeZone + ?Sized)) -> Self {
+        self.checked_to_timezone(time_zone)
+            .expect("local datetime out of valid range")
+    }
+
+    /// Convert the `OffsetDateTime` to the offset in effect in the provided [`TimeZone`] at the
+    /// same instant, returning `None` if the date-time in the resulting offset is invalid.
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!(2000-01-01 0:00 UTC)
+    ///         .checked_to_timezone(&offset!(-1))
+    ///         .unwrap()
+    ///         .year(),
+    ///     1999,
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::MAX
+    ///         .assume_utc()
+    ///         .checked_to_timezone(&offset!(+1)),
+    ///     None,
+    /// );
+    /// ```
+    pub fn checked_to_timezone(self, time_zone: &(impl TimeZone + ?Sized)) -> Option<Self> {
+        let utc = self.checked_to_utc()?;
+        utc.checked_to_offset(time_zone.offset_at(utc))
+    }
+
     /// Equivalent to `.to_offset(UtcOffset::UTC)`, but returning the year, ordinal, and time. This
     /// avoids constructing an invalid [`Date`] if the new value is out of range.
     pub(crate) const fn to_offset_raw(self, offset: UtcOffset) -> (i32, u16, Time) {
@@ -425,6 +618,51 @@ impl OffsetDateTime {
             UtcOffset::UTC,
         ))
     }
+
+    /// Construct an `OffsetDateTime` from the provided Unix timestamp (in milliseconds). Calling
+    /// `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// This is commonly used when interoperating with JavaScript's `Date.now()` or Java's
+    /// `System.currentTimeMillis()`.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_millis(0),
+    ///     Ok(OffsetDateTime::UNIX_EPOCH),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_millis(1_546_300_800_123),
+    ///     Ok(datetime!(2019-01-01 0:00:00.123 UTC)),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_millis(-1),
+    ///     Ok(datetime!(1969-12-31 23:59:59.999 UTC)),
+    /// );
+    /// ```
+    pub const fn from_unix_timestamp_millis(timestamp: i64) -> Result<Self, error::ComponentRange> {
+        Self::from_unix_timestamp_nanos(timestamp as i128 * Nanosecond::per(Millisecond) as i128)
+    }
+
+    /// Construct an `OffsetDateTime` from the provided Unix timestamp (in microseconds). Calling
+    /// `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_micros(0),
+    ///     Ok(OffsetDateTime::UNIX_EPOCH),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_unix_timestamp_micros(1_546_300_800_123_456),
+    ///     Ok(datetime!(2019-01-01 0:00:00.123_456 UTC)),
+    /// );
+    /// ```
+    pub const fn from_unix_timestamp_micros(timestamp: i64) -> Result<Self, error::ComponentRange> {
+        Self::from_unix_timestamp_nanos(timestamp as i128 * Nanosecond::per(Microsecond) as i128)
+    }
     // endregion constructors
 
     // region: getters
@@ -470,6 +708,46 @@ impl OffsetDateTime {
         self.unix_timestamp() as i128 * Nanosecond::per(Second) as i128 + self.nanosecond() as i128
     }
 
+    /// Get the Unix timestamp in milliseconds. Any sub-millisecond component is truncated toward
+    /// negative infinity, consistent with [`unix_timestamp`](Self::unix_timestamp).
+    ///
+    /// ```rust
+    /// use time_macros::datetime;
+    /// assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_millis(), 0);
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 0:00:00.123_456 UTC).unix_timestamp_millis(),
+    ///     1_546_300_800_123,
+    /// );
+    /// assert_eq!(
+    ///     datetime!(1969-12-31 23:59:59.999_9 UTC).unix_timestamp_millis(),
+    ///     -1,
+    /// );
+    /// ```
+    pub const fn unix_timestamp_millis(self) -> i64 {
+        self.unix_timestamp() * Millisecond::per(Second) as i64
+            + (self.nanosecond() / Nanosecond::per(Millisecond)) as i64
+    }
+
+    /// Get the Unix timestamp in microseconds. Any sub-microsecond component is truncated toward
+    /// negative infinity, consistent with [`unix_timestamp`](Self::unix_timestamp).
+    ///
+    /// Unlike [`unix_timestamp_millis`](Self::unix_timestamp_millis), the value is returned as an
+    /// `i128`, as it does not fit in an `i64` for all years when the `large-dates` feature is
+    /// enabled.
+    ///
+    /// ```rust
+    /// use time_macros::datetime;
+    /// assert_eq!(datetime!(1970-01-01 0:00 UTC).unix_timestamp_micros(), 0);
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 0:00:00.123_456_789 UTC).unix_timestamp_micros(),
+    ///     1_546_300_800_123_456,
+    /// );
+    /// ```
+    pub const fn unix_timestamp_micros(self) -> i128 {
+        self.unix_timestamp() as i128 * Microsecond::per(Second) as i128
+            + (self.nanosecond() / Nanosecond::per(Microsecond) as u32) as i128
+    }
+
     /// Get the [`PrimitiveDateTime`] in the stored offset.
     pub(crate) const fn date_time(self) -> PrimitiveDateTime {
         self.local_date_time
@@ -507,6 +785,21 @@ impl OffsetDateTime {
         self.date_time().time()
     }
 
+    /// Get the [`Date`], [`Time`], and [`UtcOffset`]. The date and time are in the stored offset.
+    ///
+    /// This is the inverse of `OffsetDateTime::from((date, time, offset))`.
+    ///
+    /// ```rust
+    /// # use time_macros::{date, datetime, offset, time};
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 0:00 +1).as_tuple(),
+    ///     (date!(2019-01-01), time!(0:00), offset!(+1))
+    /// );
+    /// ```
+    pub const fn as_tuple(self) -> (Date, Time, UtcOffset) {
+        (self.date(), self.time(), self.offset())
+    }
+
     // region: date getters
     /// Get the year of the date in the stored offset.
     ///
@@ -542,6 +835,24 @@ impl OffsetDateTime {
         self.date().month()
     }
 
+    /// Get the zero-based month of the date in the stored offset.
+    ///
+    /// The returned value will always be in the range `0..=11`.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(datetime!(2019-01-01 0:00 UTC).month0(), 0);
+    /// assert_eq!(
+    ///     datetime!(2019-12-31 23:00 UTC)
+    ///         .to_offset(offset!(+1))
+    ///         .month0(),
+    ///     0,
+    /// );
+    /// ```
+    pub const fn month0(self) -> u8 {
+        self.date().month0()
+    }
+
     /// Get the day of the date in the stored offset.
     ///
     /// The returned value will always be in the range `1..=31`.
@@ -578,6 +889,41 @@ impl OffsetDateTime {
         self.date().ordinal()
     }
 
+    /// Get the zero-based day of the year of the date in the stored offset.
+    ///
+    /// The returned value will always be in the range `0..=365`.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(datetime!(2019-01-01 0:00 UTC).ordinal0(), 0);
+    /// assert_eq!(
+    ///     datetime!(2019-12-31 23:00 UTC)
+    ///         .to_offset(offset!(+1))
+    ///         .ordinal0(),
+    ///     0,
+    /// );
+    /// ```
+    pub const fn ordinal0(self) -> u16 {
+        self.date().ordinal0()
+    }
+
+    /// Whether the year of the date in the stored offset is a leap year in the proleptic Gregorian
+    /// calendar.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// assert!(datetime!(2020-06-15 0:00 UTC).is_leap_year());
+    /// assert!(!datetime!(2019-06-15 0:00 UTC).is_leap_year());
+    /// assert!(
+    ///     datetime!(2019-12-31 23:00 UTC)
+    ///         .to_offset(offset!(+1))
+    ///         .is_leap_year()
+    /// );
+    /// ```
+    pub const fn is_leap_year(self) -> bool {
+        self.date().is_leap_year()
+    }
+
     /// Get the ISO week number of the date in the stored offset.
     ///
     /// The returned value will always be in the range `1..=53`.
@@ -708,6 +1054,22 @@ impl OffsetDateTime {
     pub const fn to_julian_day(self) -> i32 {
         self.date().to_julian_day()
     }
+
+    /// Get the fractional Julian date. Unlike [`to_julian_day`](Self::to_julian_day), this is
+    /// always relative to UTC.
+    ///
+    /// Following the astronomical convention, each Julian date begins at noon UTC. See
+    /// [`PrimitiveDateTime::to_julian_date`] for details on precision.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2000-01-01 12:00 UTC).to_julian_date(), 2_451_545.0);
+    /// assert_eq!(datetime!(2000-01-01 18:00 +6).to_julian_date(), 2_451_545.0);
+    /// ```
+    pub fn to_julian_date(self) -> f64 {
+        self.local_date_time.to_julian_date()
+            - self.offset.whole_seconds() as f64 / Second::per(Day) as f64
+    }
     // endregion date getters
 
     // region: time getters
@@ -722,6 +1084,17 @@ impl OffsetDateTime {
         self.time().as_hms()
     }
 
+    /// Get the clock hour and minute.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2020-01-01 0:00:00 UTC).to_hm(), (0, 0));
+    /// assert_eq!(datetime!(2020-01-01 23:59:59 UTC).to_hm(), (23, 59));
+    /// ```
+    pub const fn to_hm(self) -> (u8, u8) {
+        self.time().as_hm()
+    }
+
     /// Get the clock hour, minute, second, and millisecond.
     ///
     /// ```rust
@@ -917,6 +1290,44 @@ impl OffsetDateTime {
     pub const fn checked_sub(self, duration: Duration) -> Option<Self> {
         Some(const_try_opt!(self.date_time().checked_sub(duration)).assume_offset(self.offset()))
     }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time_macros::{datetime, offset};
+    /// let datetime = Date::MAX.midnight().assume_offset(offset!(+10));
+    /// assert_eq!(datetime.checked_add_std(2.std_days()), None);
+    ///
+    /// assert_eq!(
+    ///     datetime!(2019-11-25 15:30 +10).checked_add_std(27.std_hours()),
+    ///     Some(datetime!(2019-11-26 18:30 +10))
+    /// );
+    /// ```
+    pub const fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        Some(
+            const_try_opt!(self.date_time().checked_add_std(duration)).assume_offset(self.offset()),
+        )
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time_macros::{datetime, offset};
+    /// let datetime = Date::MIN.midnight().assume_offset(offset!(+10));
+    /// assert_eq!(datetime.checked_sub_std(2.std_days()), None);
+    ///
+    /// assert_eq!(
+    ///     datetime!(2019-11-25 15:30 +10).checked_sub_std(27.std_hours()),
+    ///     Some(datetime!(2019-11-24 12:30 +10))
+    /// );
+    /// ```
+    pub const fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        Some(
+            const_try_opt!(self.date_time().checked_sub_std(duration)).assume_offset(self.offset()),
+        )
+    }
     // endregion: checked arithmetic
 
     // region: saturating arithmetic
@@ -1030,6 +1441,208 @@ impl OffsetDateTime {
         }
     }
     // endregion: saturating arithmetic
+
+    // region: rounding
+    /// Truncate the local time to the previous multiple of the [`Duration`] since midnight,
+    /// preserving the date and offset.
+    ///
+    /// Multiples are counted from midnight in the `OffsetDateTime`'s own offset, not from midnight
+    /// UTC. This matters only for offsets that are not a multiple of the duration.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34:56.789 +1).truncate_to(1.seconds()),
+    ///     datetime!(2019-01-01 12:34:56 +1)
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34:56 +5:45).truncate_to(1.hours()),
+    ///     datetime!(2019-01-01 12:00 +5:45)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `duration` is not positive.
+    #[must_use = "This method does not mutate the original `OffsetDateTime`."]
+    pub const fn truncate_to(self, duration: Duration) -> Self {
+        Self {
+            local_date_time: self.local_date_time.truncate_to(duration),
+            offset: self.offset,
+        }
+    }
+
+    /// Round the local time to the nearest multiple of the [`Duration`] since midnight, preserving
+    /// the offset. Values exactly halfway between two multiples are rounded up. Midnight of the
+    /// following day is treated as the multiple after the last one in the day.
+    ///
+    /// Multiples are counted from midnight in the `OffsetDateTime`'s own offset, not from midnight
+    /// UTC. This matters only for offsets that are not a multiple of the duration.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:37:30 +1).round_to(15.minutes()),
+    ///     datetime!(2019-01-01 12:45 +1)
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2019-12-31 23:59:30 -5).round_to(1.minutes()),
+    ///     datetime!(2020-01-01 0:00 -5)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `duration` is not positive or if the resulting value is out of range.
+    #[must_use = "This method does not mutate the original `OffsetDateTime`."]
+    pub const fn round_to(self, duration: Duration) -> Self {
+        Self {
+            local_date_time: self.local_date_time.round_to(duration),
+            offset: self.offset,
+        }
+    }
+
+    /// Convert the `OffsetDateTime` to the provided [`UtcOffset`], then truncate the local time in
+    /// that offset to the previous multiple of the [`Duration`] since midnight.
+    ///
+    /// This is equivalent to calling [`to_offset`](Self::to_offset) followed by
+    /// [`truncate_to`](Self::truncate_to). Multiples are counted from midnight in the new offset,
+    /// so the result is aligned for display in that offset regardless of the original offset.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34:56.789 +5:45).to_offset_truncated(offset!(+5:30), 1.minutes()),
+    ///     datetime!(2019-01-01 12:19 +5:30)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `duration` is not positive or if the local date-time in the new offset is
+    /// outside the supported range.
+    #[must_use = "This method does not mutate the original `OffsetDateTime`."]
+    pub const fn to_offset_truncated(self, offset: UtcOffset, duration: Duration) -> Self {
+        self.to_offset(offset).truncate_to(duration)
+    }
+
+    /// Convert the `OffsetDateTime` to the provided [`UtcOffset`], then round the local time in
+    /// that offset to the nearest multiple of the [`Duration`] since midnight. Values exactly
+    /// halfway between two multiples are rounded up.
+    ///
+    /// This is equivalent to calling [`to_offset`](Self::to_offset) followed by
+    /// [`round_to`](Self::round_to). Multiples are counted from midnight in the new offset, so the
+    /// result is aligned for display in that offset regardless of the original offset.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34:56.789 +5:45).to_offset_rounded(offset!(+5:30), 1.minutes()),
+    ///     datetime!(2019-01-01 12:20 +5:30)
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2019-12-31 23:59:59 UTC).to_offset_rounded(offset!(-5), 1.hours()),
+    ///     datetime!(2019-12-31 19:00 -5)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `duration` is not positive or if the resulting value is out of range.
+    #[must_use = "This method does not mutate the original `OffsetDateTime`."]
+    pub const fn to_offset_rounded(self, offset: UtcOffset, duration: Duration) -> Self {
+        self.to_offset(offset).round_to(duration)
+    }
+    // endregion rounding
+
+    // region: differences
+    /// Obtain the number of calendar days from `self` to `other`, which is positive if `other` is
+    /// later. Both dates are determined in the offset of `self`, so this counts the number of
+    /// midnights crossed in that offset. Unlike [`OffsetDateTime::whole_days_between`], the time
+    /// of day is not considered.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// let start = datetime!(2024-01-01 23:00 UTC);
+    /// assert_eq!(start.calendar_days_between(datetime!(2024-01-02 1:00 UTC)), 1);
+    /// assert_eq!(start.calendar_days_between(datetime!(2023-12-31 1:00 UTC)), -1);
+    /// // Midnight in the offset of `self` has not yet been crossed.
+    /// assert_eq!(start.calendar_days_between(datetime!(2024-01-02 0:30 +1)), 0);
+    /// ```
+    pub const fn calendar_days_between(self, other: Self) -> i32 {
+        let other_local_seconds = other.unix_timestamp() + self.offset.whole_seconds() as i64;
+        let other_julian_day =
+            UNIX_EPOCH_JULIAN_DAY as i64 + div_floor!(other_local_seconds, Second::per(Day) as i64);
+        (other_julian_day - self.to_julian_day() as i64) as i32
+    }
+
+    /// Obtain the number of whole 24-hour periods from `self` to `other`, rounding toward zero.
+    /// The result is positive if `other` is later. Calendar dates and offsets are not considered,
+    /// so this is equivalent to `(other - self).whole_days()`.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// let start = datetime!(2024-01-01 23:00 UTC);
+    /// assert_eq!(start.whole_days_between(datetime!(2024-01-02 1:00 UTC)), 0);
+    /// assert_eq!(start.whole_days_between(datetime!(2024-01-03 1:00 UTC)), 1);
+    /// assert_eq!(start.whole_days_between(datetime!(2023-12-31 1:00 UTC)), -1);
+    /// ```
+    pub fn whole_days_between(self, other: Self) -> i64 {
+        (other - self).whole_days()
+    }
+
+    /// Obtain the number of whole hours from `self` to `other`, rounding toward zero. The result
+    /// is positive if `other` is later. This is equivalent to `(other - self).whole_hours()`.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// let start = datetime!(2024-01-01 12:00 UTC);
+    /// assert_eq!(start.whole_hours_between(datetime!(2024-01-01 14:59 UTC)), 2);
+    /// assert_eq!(start.whole_hours_between(datetime!(2024-01-01 14:59 +1)), 1);
+    /// assert_eq!(start.whole_hours_between(datetime!(2024-01-01 9:30 UTC)), -2);
+    /// ```
+    pub fn whole_hours_between(self, other: Self) -> i64 {
+        (other - self).whole_hours()
+    }
+
+    /// Obtain the number of whole calendar months from `self` to `other`, rounding toward zero.
+    /// The result is positive if `other` is later.
+    ///
+    /// `other` is first converted to the offset of `self`. A month is complete once the same day
+    /// of the month and time of day is reached. If that day does not exist in a month, such as
+    /// the 31st of February, the month is not complete until the following month begins.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// let start = datetime!(2024-01-31 12:00 UTC);
+    /// assert_eq!(start.months_between(datetime!(2024-03-31 12:00 UTC)), 2);
+    /// assert_eq!(start.months_between(datetime!(2024-03-31 11:59 UTC)), 1);
+    /// assert_eq!(start.months_between(datetime!(2024-02-29 12:00 UTC)), 0);
+    /// assert_eq!(start.months_between(datetime!(2023-11-30 12:00 UTC)), -2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This may panic if `other` cannot be represented in the offset of `self`.
+    pub fn months_between(self, other: Self) -> i32 {
+        /// The position of the value on a scale where each month spans 32 days, preserving the
+        /// order of the day of the month and time of day.
+        fn month_position(datetime: OffsetDateTime) -> i128 {
+            let month = datetime.year() as i128 * 12 + datetime.month() as i128;
+            let nanosecond_of_day = (datetime.time() - Time::MIDNIGHT).whole_nanoseconds();
+            let day = month * 32 + datetime.day() as i128;
+            day * Nanosecond::per(Day) as i128 + nanosecond_of_day
+        }
+
+        let other = other.to_offset(self.offset);
+        ((month_position(other) - month_position(self)) / (32 * Nanosecond::per(Day) as i128))
+            as i32
+    }
+    // endregion differences
 }
 
 // region: replacement
@@ -1288,12 +1901,15 @@ impl OffsetDateTime {
         output: &mut impl io::Write,
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(
+        let result = format.format_into_buffered(
             output,
             Some(self.date()),
             Some(self.time()),
             Some(self.offset()),
-        )
+        );
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_format(false, result.as_ref().ok().copied());
+        result
     }
 
     /// Format the `OffsetDateTime` using the provided [format
@@ -1313,7 +1929,35 @@ impl OffsetDateTime {
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(Some(self.date()), Some(self.time()), Some(self.offset()))
+        let result = format.format(Some(self.date()), Some(self.time()), Some(self.offset()));
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_format(true, result.as_ref().ok().map(String::len));
+        result
+    }
+
+    /// Determine the number of bytes the `OffsetDateTime` would be formatted as using the
+    /// provided [format description](crate::format_description).
+    ///
+    /// The length is exact, so it can be used to allocate a buffer of the correct size before
+    /// calling [`format_into`](Self::format_into).
+    ///
+    /// The length is determined by formatting the value and discarding the output, so calling this
+    /// costs as much as formatting.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::datetime;
+    /// let datetime = datetime!(2020-01-02 03:04:05 +06);
+    /// let mut buf = Vec::with_capacity(datetime.formatted_len(&Rfc3339)?);
+    /// datetime.format_into(&mut buf, &Rfc3339)?;
+    /// assert_eq!(buf, b"2020-01-02T03:04:05+06:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn formatted_len(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.formatted_len(Some(self.date()), Some(self.time()), Some(self.offset()))
     }
 }
 
@@ -1339,9 +1983,45 @@ impl OffsetDateTime {
         input: &str,
         description: &(impl Parsable + ?Sized),
     ) -> Result<Self, error::Parse> {
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_parse(input.len());
         description.parse_offset_date_time(input.as_bytes())
     }
 
+    /// Parse an `OffsetDateTime` from the input using the provided [format
+    /// description](crate::format_description), subject to the provided [`ParserOptions`]. With
+    /// limits set, this should be preferred over [`OffsetDateTime::parse`] when the input is
+    /// untrusted.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time::parsing::ParserOptions;
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// let options = ParserOptions::DEFAULT.with_max_input_len(32);
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_with_options("2020-01-02T03:04:05Z", &Rfc3339, options)?,
+    ///     datetime!(2020-01-02 03:04:05 UTC)
+    /// );
+    /// assert!(OffsetDateTime::parse_with_options(
+    ///     "2020-01-02T03:04:05.000000000000000Z",
+    ///     &Rfc3339,
+    ///     options
+    /// )
+    /// .is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_options(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+        options: ParserOptions,
+    ) -> Result<Self, error::Parse> {
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_parse(input.len());
+        let parsed = description.parse_with_options(input.as_bytes(), options)?;
+        Ok(Self::from_parsed(parsed, options)?)
+    }
+
     /// A helper method to check if the `OffsetDateTime` is a valid representation of a leap second.
     /// Leap seconds, when parsed, are represented as the preceding nanosecond. However, leap
     /// seconds can only occur as the last second of a month UTC.
@@ -1602,8 +2282,70 @@ impl PartialOrd<OffsetDateTime> for SystemTime {
     }
 }
 
+impl From<(Date, Time, UtcOffset)> for OffsetDateTime {
+    fn from((date, time, offset): (Date, Time, UtcOffset)) -> Self {
+        Self::new_in_offset(date, time, offset)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<(i32, u8, u8, u8, u8, u8, u32, i32)> for OffsetDateTime {
+    type Error = error::ComponentRanges;
+
+    /// Create an `OffsetDateTime` from the year, month, day, hour, minute, second, nanosecond, and
+    /// offset from UTC in seconds. Every component is validated, so the error describes all
+    /// components that are out of range rather than only the first.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::try_from((2021, 1, 2, 3, 4, 5, 6, 3_600)),
+    ///     Ok(datetime!(2021-01-02 03:04:05.000_000_006 +1))
+    /// );
+    /// let err = OffsetDateTime::try_from((2021, 0, 1, 24, 0, 0, 0, 100_000)).unwrap_err();
+    /// let names = err.iter().map(|err| err.name()).collect::<Vec<_>>();
+    /// assert_eq!(names, ["month", "hour", "seconds"]);
+    /// ```
+    fn try_from(
+        (year, month, day, hour, minute, second, nanosecond, offset): (
+            i32,
+            u8,
+            u8,
+            u8,
+            u8,
+            u8,
+            u32,
+            i32,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let mut errors = alloc::vec::Vec::new();
+        let datetime = crate::primitive_date_time::from_components(
+            (year, month, day, hour, minute, second, nanosecond),
+            &mut errors,
+        );
+        let offset = UtcOffset::from_whole_seconds(offset)
+            .map_err(|err| errors.push(err))
+            .ok();
+        match (
+            datetime,
+            offset,
+            error::ComponentRanges::from_errors(errors),
+        ) {
+            (Some(datetime), Some(offset), None) => Ok(datetime.assume_offset(offset)),
+            (_, _, Some(err)) => Err(err),
+            _ => unreachable!("an error is recorded for every invalid component"),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl From<SystemTime> for OffsetDateTime {
+    /// # Panics
+    ///
+    /// This may panic if the value is outside the range supported by this crate. Use
+    /// [`OffsetDateTime::try_from_system_time`] or [`OffsetDateTime::saturating_from_system_time`]
+    /// to avoid this.
     fn from(system_time: SystemTime) -> Self {
         match system_time.duration_since(SystemTime::UNIX_EPOCH) {
             Ok(duration) => Self::UNIX_EPOCH + duration,
@@ -1614,6 +2356,10 @@ impl From<SystemTime> for OffsetDateTime {
 
 #[cfg(feature = "std")]
 impl From<OffsetDateTime> for SystemTime {
+    /// # Panics
+    ///
+    /// This may panic if the value cannot be represented by a `SystemTime`, the range of which is
+    /// platform-specific. Use [`OffsetDateTime::try_to_system_time`] to avoid this.
     fn from(datetime: OffsetDateTime) -> Self {
         let duration = datetime - OffsetDateTime::UNIX_EPOCH;
 
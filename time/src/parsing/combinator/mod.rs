@@ -40,6 +40,7 @@ pub(crate) fn first_match<'a, T>(
     }
 }
 
+#[cfg(not(time_no_names))]
 /// Consume zero or more instances of the provided parser. The parser must return the unit value.
 pub(crate) fn zero_or_more<'a, P: Fn(&'a [u8]) -> Option<ParsedItem<'a, ()>>>(
     parser: P,
@@ -52,6 +53,7 @@ pub(crate) fn zero_or_more<'a, P: Fn(&'a [u8]) -> Option<ParsedItem<'a, ()>>>(
     }
 }
 
+#[cfg(not(time_no_names))]
 /// Consume one of or more instances of the provided parser. The parser must produce the unit value.
 pub(crate) fn one_or_more<'a, P: Fn(&'a [u8]) -> Option<ParsedItem<'a, ()>>>(
     parser: P,
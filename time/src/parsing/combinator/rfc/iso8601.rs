@@ -142,8 +142,12 @@ pub(crate) fn min(input: &[u8]) -> Option<ParsedItem<'_, u8>> {
 /// The number must have two digits before the decimal point. If a decimal point is present, at
 /// least one digit must follow.
 ///
-/// The return type is a tuple of the integer part and optional fraction part.
-pub(crate) fn float(input: &[u8]) -> Option<ParsedItem<'_, (u8, Option<f64>)>> {
+/// The return type is a tuple of the integer part and optional fraction part. At most
+/// `max_fractional_digits` digits may follow the decimal point.
+pub(crate) fn float(
+    input: &[u8],
+    max_fractional_digits: usize,
+) -> Option<ParsedItem<'_, (u8, Option<f64>)>> {
     // Two digits before the decimal.
     let ParsedItem(input, integer_part) = match input {
         [first_digit @ b'0'..=b'9', second_digit @ b'0'..=b'9', input @ ..] => {
@@ -158,11 +162,17 @@ pub(crate) fn float(input: &[u8]) -> Option<ParsedItem<'_, (u8, Option<f64>)>> {
             any_digit(input)?.map(|digit| ((digit - b'0') as f64) / 10.);
 
         let mut divisor = 10.;
+        let mut digits = 1;
         // Any number of subsequent digits.
         while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
             input = new_input;
             divisor *= 10.;
             fractional_part += (digit - b'0') as f64 / divisor;
+            digits += 1;
+        }
+
+        if digits > max_fractional_digits {
+            return None;
         }
 
         Some(ParsedItem(input, (integer_part, Some(fractional_part))))
@@ -6,5 +6,7 @@
 //! [RFC 2234]: https://datatracker.ietf.org/doc/html/rfc2234
 
 pub(crate) mod iso8601;
+#[cfg(not(time_no_names))]
 pub(crate) mod rfc2234;
+#[cfg(not(time_no_names))]
 pub(crate) mod rfc2822;
@@ -5,10 +5,45 @@
 use crate::parsing::combinator::rfc::rfc2234::wsp;
 use crate::parsing::combinator::{ascii_char, one_or_more, zero_or_more};
 use crate::parsing::ParsedItem;
+use crate::Month;
 
-/// Consume the `fws` rule.
+/// Consume the `fws` rule, failing if more than `max_len` bytes would be consumed.
+pub(crate) fn fws(input: &[u8], max_len: usize) -> Option<ParsedItem<'_, ()>> {
+    within_len(input, max_len, unbounded_fws(input))
+}
+
+/// Consume the `cfws` rule, failing if more than `max_len` bytes would be consumed or if comments
+/// are nested more than `max_len` levels deep.
+// The full rule is equivalent to any combination of `fws` and `comment` so long as it is not empty.
+pub(crate) fn cfws(input: &[u8], max_len: usize) -> Option<ParsedItem<'_, ()>> {
+    match input {
+        // A single space is by far the most common case, so avoid the general rule for it.
+        [b' ', rest @ ..] if max_len != 0 && !matches!(rest, [b' ' | b'\t' | b'\r' | b'(', ..]) => {
+            return Some(ParsedItem(rest, ()));
+        }
+        [b' ' | b'\t' | b'\r' | b'(', ..] => {}
+        _ => return None,
+    }
+
+    within_len(
+        input,
+        max_len,
+        one_or_more(|input| unbounded_fws(input).or_else(|| comment(input, max_len)))(input),
+    )
+}
+
+/// Discard the parsed item if more than `max_len` bytes of the input were consumed.
+fn within_len<'a>(
+    input: &[u8],
+    max_len: usize,
+    item: Option<ParsedItem<'a, ()>>,
+) -> Option<ParsedItem<'a, ()>> {
+    item.filter(|ParsedItem(remaining, ())| input.len() - remaining.len() <= max_len)
+}
+
+/// Consume the `fws` rule without any limit on its length.
 // The full rule is equivalent to /\r\n[ \t]+|[ \t]+(?:\r\n[ \t]+)*/
-pub(crate) fn fws(mut input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+fn unbounded_fws(mut input: &[u8]) -> Option<ParsedItem<'_, ()>> {
     if let [b'\r', b'\n', rest @ ..] = input {
         one_or_more(wsp)(rest)
     } else {
@@ -20,30 +55,25 @@ pub(crate) fn fws(mut input: &[u8]) -> Option<ParsedItem<'_, ()>> {
     }
 }
 
-/// Consume the `cfws` rule.
-// The full rule is equivalent to any combination of `fws` and `comment` so long as it is not empty.
-pub(crate) fn cfws(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
-    one_or_more(|input| fws(input).or_else(|| comment(input)))(input)
-}
-
-/// Consume the `comment` rule.
-fn comment(mut input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+/// Consume the `comment` rule, permitting at most `max_depth` levels of nesting.
+fn comment(mut input: &[u8], max_depth: usize) -> Option<ParsedItem<'_, ()>> {
+    let max_depth = max_depth.checked_sub(1)?;
     input = ascii_char::<b'('>(input)?.into_inner();
-    input = zero_or_more(fws)(input).into_inner();
-    while let Some(rest) = ccontent(input) {
+    input = zero_or_more(unbounded_fws)(input).into_inner();
+    while let Some(rest) = ccontent(input, max_depth) {
         input = rest.into_inner();
-        input = zero_or_more(fws)(input).into_inner();
+        input = zero_or_more(unbounded_fws)(input).into_inner();
     }
     input = ascii_char::<b')'>(input)?.into_inner();
 
     Some(ParsedItem(input, ()))
 }
 
-/// Consume the `ccontent` rule.
-fn ccontent(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+/// Consume the `ccontent` rule, permitting at most `max_depth` levels of nested comments.
+fn ccontent(input: &[u8], max_depth: usize) -> Option<ParsedItem<'_, ()>> {
     ctext(input)
         .or_else(|| quoted_pair(input))
-        .or_else(|| comment(input))
+        .or_else(|| comment(input, max_depth))
 }
 
 /// Consume the `ctext` rule.
@@ -106,3 +136,45 @@ fn text<'a>(input: &'a [u8]) -> ParsedItem<'a, ()> {
 
     new_text(input).unwrap_or_else(|| obs_text(input))
 }
+
+/// Consume the first three bytes of the input, returning them converted to ASCII lowercase.
+fn three_letter_name(input: &[u8]) -> Option<ParsedItem<'_, [u8; 3]>> {
+    match input {
+        [a, b, c, rest @ ..] => Some(ParsedItem(
+            rest,
+            [
+                a.to_ascii_lowercase(),
+                b.to_ascii_lowercase(),
+                c.to_ascii_lowercase(),
+            ],
+        )),
+        _ => None,
+    }
+}
+
+/// Consume the `day-name` rule, ignoring case.
+pub(crate) fn day_name(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+    three_letter_name(input)?.flat_map(|name| match &name {
+        b"mon" | b"tue" | b"wed" | b"thu" | b"fri" | b"sat" | b"sun" => Some(()),
+        _ => None,
+    })
+}
+
+/// Consume the `month-name` rule, ignoring case.
+pub(crate) fn month_name(input: &[u8]) -> Option<ParsedItem<'_, Month>> {
+    three_letter_name(input)?.flat_map(|name| match &name {
+        b"jan" => Some(Month::January),
+        b"feb" => Some(Month::February),
+        b"mar" => Some(Month::March),
+        b"apr" => Some(Month::April),
+        b"may" => Some(Month::May),
+        b"jun" => Some(Month::June),
+        b"jul" => Some(Month::July),
+        b"aug" => Some(Month::August),
+        b"sep" => Some(Month::September),
+        b"oct" => Some(Month::October),
+        b"nov" => Some(Month::November),
+        b"dec" => Some(Month::December),
+        _ => None,
+    })
+}
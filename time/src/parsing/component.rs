@@ -11,7 +11,7 @@ use crate::parsing::combinator::{
     n_to_m_digits_padded, opt, sign,
 };
 use crate::parsing::ParsedItem;
-use crate::{Month, Weekday};
+use crate::{util, Month, Weekday};
 
 // region: date components
 /// Parse the "year" component of a `Date`.
@@ -19,7 +19,49 @@ pub(crate) fn parse_year(
     input: &[u8],
     modifiers: modifier::Year,
 ) -> Option<ParsedItem<'_, (i32, bool)>> {
+    if modifiers.repr == modifier::YearRepr::Full
+        && modifiers.notation == modifier::YearNotation::Bce
+    {
+        // Years before 1 CE must use the BCE notation. Later years are parsed as usual.
+        return parse_year_bce(input, modifiers).or_else(|| {
+            parse_year(
+                input,
+                modifier::Year {
+                    notation: modifier::YearNotation::Astronomical,
+                    ..modifiers
+                },
+            )
+            .filter(|ParsedItem(_, (year, _))| *year > 0)
+        });
+    }
+
     match modifiers.repr {
+        modifier::YearRepr::Full if modifiers.width != modifier::YearWidth::Automatic => {
+            let ParsedItem(input, sign) = opt(sign)(input);
+            if sign.is_none() && modifiers.sign_is_mandatory {
+                return None;
+            }
+
+            let ParsedItem(input, year) = match modifiers.width {
+                modifier::YearWidth::Four => {
+                    exactly_n_digits_padded::<4, u32>(modifiers.padding)(input)?
+                }
+                modifier::YearWidth::Five => {
+                    exactly_n_digits_padded::<5, u32>(modifiers.padding)(input)?
+                }
+                modifier::YearWidth::Six | modifier::YearWidth::Automatic => {
+                    exactly_n_digits_padded::<6, u32>(modifiers.padding)(input)?
+                }
+            };
+
+            // The year has at most six digits, so it always fits in an `i32`.
+            let year = year as i32;
+            Some(if sign == Some(b'-') {
+                ParsedItem(input, (-year, true))
+            } else {
+                ParsedItem(input, (year, false))
+            })
+        }
         modifier::YearRepr::Full => {
             let ParsedItem(input, sign) = opt(sign)(input);
 
@@ -77,6 +119,57 @@ pub(crate) fn parse_year(
     }
 }
 
+/// Parse a full year before 1 CE, counting backwards from one and followed by ` BCE`.
+fn parse_year_bce(input: &[u8], modifiers: modifier::Year) -> Option<ParsedItem<'_, (i32, bool)>> {
+    let ParsedItem(input, year) = match modifiers.width {
+        modifier::YearWidth::Automatic
+            if cfg!(feature = "large-dates")
+                && modifiers.range == modifier::YearRange::Extended =>
+        {
+            n_to_m_digits_padded::<4, 7, u32>(modifiers.padding)(input)?
+        }
+        modifier::YearWidth::Automatic => {
+            n_to_m_digits_padded::<4, 5, u32>(modifiers.padding)(input)?
+        }
+        modifier::YearWidth::Four => exactly_n_digits_padded::<4, u32>(modifiers.padding)(input)?,
+        modifier::YearWidth::Five => exactly_n_digits_padded::<5, u32>(modifiers.padding)(input)?,
+        modifier::YearWidth::Six => exactly_n_digits_padded::<6, u32>(modifiers.padding)(input)?,
+    };
+    let input = input.strip_prefix(b" BCE")?;
+    if year == 0 {
+        return None;
+    }
+
+    // The year has at most seven digits, so it always fits in an `i32`. 1 BCE is year zero in the
+    // proleptic Gregorian calendar.
+    Some(ParsedItem(input, (1 - year as i32, true)))
+}
+
+/// Whether the input begins with a signed year that has more digits than are supported when the
+/// `large-dates` feature is disabled. Without this check, the year would be silently truncated to
+/// the supported number of digits.
+pub(crate) fn year_requires_large_dates(input: &[u8], modifiers: modifier::Year) -> bool {
+    if cfg!(feature = "large-dates") || modifiers.range != modifier::YearRange::Extended {
+        return false;
+    }
+
+    let max_digits = match modifiers.repr {
+        modifier::YearRepr::Full if modifiers.width != modifier::YearWidth::Automatic => {
+            return false
+        }
+        modifier::YearRepr::Full => 4,
+        modifier::YearRepr::Century => 2,
+        modifier::YearRepr::LastTwo => return false,
+    };
+
+    match input {
+        [b'+' | b'-', rest @ ..] => {
+            rest.iter().take_while(|byte| byte.is_ascii_digit()).count() > max_digits
+        }
+        _ => false,
+    }
+}
+
 /// Parse the "month" component of a `Date`.
 pub(crate) fn parse_month(
     input: &[u8],
@@ -89,6 +182,9 @@ pub(crate) fn parse_month(
                 return exactly_n_digits_padded::<2, _>(modifiers.padding)(input)?
                     .flat_map(|n| Month::from_number(n).ok());
             }
+            modifier::MonthRepr::Long | modifier::MonthRepr::Short if cfg!(time_no_names) => {
+                return None;
+            }
             modifier::MonthRepr::Long => [
                 (b"January".as_slice(), January),
                 (b"February".as_slice(), February),
@@ -138,6 +234,11 @@ pub(crate) fn parse_weekday(
 ) -> Option<ParsedItem<'_, Weekday>> {
     first_match(
         match (modifiers.repr, modifiers.one_indexed) {
+            (modifier::WeekdayRepr::Short | modifier::WeekdayRepr::Long, _)
+                if cfg!(time_no_names) =>
+            {
+                return None;
+            }
             (modifier::WeekdayRepr::Short, _) => [
                 (b"Mon".as_slice(), Weekday::Monday),
                 (b"Tue".as_slice(), Weekday::Tuesday),
@@ -210,7 +311,13 @@ pub(crate) fn parse_day(
     input: &[u8],
     modifiers: modifier::Day,
 ) -> Option<ParsedItem<'_, NonZeroU8>> {
-    exactly_n_digits_padded::<2, _>(modifiers.padding)(input)
+    let ParsedItem(input, day) = exactly_n_digits_padded::<2, NonZeroU8>(modifiers.padding)(input)?;
+    let input = if modifiers.ordinal_suffix {
+        input.strip_prefix(util::ordinal_suffix(day.get()).as_bytes())?
+    } else {
+        input
+    };
+    Some(ParsedItem(input, day))
 }
 // endregion date components
 
@@ -266,10 +373,12 @@ pub(crate) fn parse_period(
     )(input)
 }
 
-/// Parse the "subsecond" component of a `Time`.
+/// Parse the "subsecond" component of a `Time`. At most `max_digits` digits are permitted when the
+/// number of digits is not fixed.
 pub(crate) fn parse_subsecond(
     input: &[u8],
     modifiers: modifier::Subsecond,
+    max_digits: usize,
 ) -> Option<ParsedItem<'_, u32>> {
     use modifier::SubsecondDigits::*;
     Some(match modifiers.digits {
@@ -287,12 +396,17 @@ pub(crate) fn parse_subsecond(
                 any_digit(input)?.map(|v| (v - b'0').extend::<u32>() * 100_000_000);
 
             let mut multiplier = 10_000_000;
+            let mut digits = 1;
             while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
                 value += (digit - b'0').extend::<u32>() * multiplier;
                 input = new_input;
                 multiplier /= 10;
+                digits += 1;
             }
 
+            if digits > max_digits {
+                return None;
+            }
             ParsedItem(input, value)
         }
     })
@@ -349,11 +463,12 @@ pub(crate) fn parse_ignore(
     Some(ParsedItem(input, ()))
 }
 
-/// Parse the Unix timestamp component.
+/// Parse the Unix timestamp component, returning the value in nanoseconds and whether it is
+/// negative. The latter is needed to distinguish "-0" from "0".
 pub(crate) fn parse_unix_timestamp(
     input: &[u8],
     modifiers: modifier::UnixTimestamp,
-) -> Option<ParsedItem<'_, i128>> {
+) -> Option<ParsedItem<'_, (i128, bool)>> {
     let ParsedItem(input, sign) = opt(sign)(input);
     let ParsedItem(input, nano_timestamp) = match modifiers.precision {
         modifier::UnixTimestampPrecision::Second => n_to_m_digits::<1, 14, u128>(input)?
@@ -366,9 +481,9 @@ pub(crate) fn parse_unix_timestamp(
     };
 
     match sign {
-        Some(b'-') => Some(ParsedItem(input, -nano_timestamp.cast_signed())),
+        Some(b'-') => Some(ParsedItem(input, (-nano_timestamp.cast_signed(), true))),
         None if modifiers.sign_is_mandatory => None,
-        _ => Some(ParsedItem(input, nano_timestamp.cast_signed())),
+        _ => Some(ParsedItem(input, (nano_timestamp.cast_signed(), false))),
     }
 }
 
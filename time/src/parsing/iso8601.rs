@@ -4,16 +4,74 @@ use num_conv::prelude::*;
 
 use crate::convert::*;
 use crate::error;
-use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
-use crate::format_description::well_known::iso8601::EncodedConfig;
-use crate::format_description::well_known::Iso8601;
+use crate::format_description::well_known::iso8601::Config;
+use crate::internal_macros::bug;
 use crate::parsing::combinator::rfc::iso8601::{
     day, dayk, dayo, float, hour, min, month, week, year, ExtendedKind,
 };
 use crate::parsing::combinator::{ascii_char, sign};
-use crate::parsing::{Parsed, ParsedItem};
+use crate::parsing::{invalid_component, invalid_literal, Parsed, ParsedItem, ParserOptions};
+
+impl Config {
+    /// Parse the components of ISO 8601 that are present in the input.
+    pub(crate) fn parse_into<'a>(
+        self,
+        mut input: &'a [u8],
+        parsed: &mut Parsed,
+        options: ParserOptions,
+    ) -> Result<&'a [u8], error::Parse> {
+        let mut extended_kind = ExtendedKind::Unknown;
+        let mut date_is_present = false;
+        let mut time_is_present = false;
+        let mut offset_is_present = false;
+        let mut first_error = None;
+        let original = input;
+
+        parsed.leap_second_allowed = true;
+
+        match Self::parse_date(parsed, &mut extended_kind)(input) {
+            Ok(new_input) => {
+                input = new_input;
+                date_is_present = true;
+            }
+            Err(err) => {
+                first_error.get_or_insert_with(|| err.offset_by(original.len() - input.len()));
+            }
+        }
+
+        match self.parse_time(parsed, &mut extended_kind, date_is_present, options)(input) {
+            Ok(new_input) => {
+                input = new_input;
+                time_is_present = true;
+            }
+            Err(err) => {
+                first_error.get_or_insert_with(|| err.offset_by(original.len() - input.len()));
+            }
+        }
+
+        // If a date and offset are present, a time must be as well.
+        if !date_is_present || time_is_present {
+            match Self::parse_offset(parsed, &mut extended_kind)(input) {
+                Ok(new_input) => {
+                    input = new_input;
+                    offset_is_present = true;
+                }
+                Err(err) => {
+                    first_error.get_or_insert_with(|| err.offset_by(original.len() - input.len()));
+                }
+            }
+        }
+
+        if !date_is_present && !time_is_present && !offset_is_present {
+            match first_error {
+                Some(err) => return Err(err),
+                None => bug!("an error should be present if no components were parsed"),
+            }
+        }
+
+        Ok(input)
+    }
 
-impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     // Basic: [year][month][day]
     // Extended: [year]["-"][month]["-"][day]
     // Basic: [year][dayo]
@@ -21,13 +79,15 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     // Basic: [year]["W"][week][dayk]
     // Extended: [year]["-"]["W"][week]["-"][dayk]
     /// Parse a date in the basic or extended format. Reduced precision is permitted.
-    pub(crate) fn parse_date<'a>(
+    fn parse_date<'a>(
         parsed: &'a mut Parsed,
         extended_kind: &'a mut ExtendedKind,
     ) -> impl FnMut(&[u8]) -> Result<&[u8], error::Parse> + 'a {
         move |input| {
+            let original = input;
             // Same for any acceptable format.
-            let ParsedItem(mut input, year) = year(input).ok_or(InvalidComponent("year"))?;
+            let ParsedItem(mut input, year) =
+                year(input).ok_or_else(|| invalid_component("year", original, input))?;
             *extended_kind = match ascii_char::<b'-'>(input) {
                 Some(ParsedItem(new_input, ())) => {
                     input = new_input;
@@ -37,64 +97,66 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
             };
 
             let parsed_month_day = (|| {
-                let ParsedItem(mut input, month) = month(input).ok_or(InvalidComponent("month"))?;
+                let ParsedItem(mut input, month) =
+                    month(input).ok_or_else(|| invalid_component("month", original, input))?;
                 if extended_kind.is_extended() {
                     input = ascii_char::<b'-'>(input)
-                        .ok_or(InvalidLiteral)?
+                        .ok_or_else(|| invalid_literal(original, input))?
                         .into_inner();
                 }
-                let ParsedItem(input, day) = day(input).ok_or(InvalidComponent("day"))?;
+                let ParsedItem(input, day) =
+                    day(input).ok_or_else(|| invalid_component("day", original, input))?;
                 Ok(ParsedItem(input, (month, day)))
             })();
             let mut ret_error = match parsed_month_day {
-                Ok(ParsedItem(input, (month, day))) => {
+                Ok(ParsedItem(remaining, (month, day))) => {
                     *parsed = parsed
                         .with_year(year)
-                        .ok_or(InvalidComponent("year"))?
+                        .ok_or_else(|| invalid_component("year", original, original))?
                         .with_month(month)
-                        .ok_or(InvalidComponent("month"))?
+                        .ok_or_else(|| invalid_component("month", original, input))?
                         .with_day(day)
-                        .ok_or(InvalidComponent("day"))?;
-                    return Ok(input);
+                        .ok_or_else(|| invalid_component("day", original, input))?;
+                    return Ok(remaining);
                 }
                 Err(err) => err,
             };
 
             // Don't check for `None`, as the error from year-month-day will always take priority.
-            if let Some(ParsedItem(input, ordinal)) = dayo(input) {
+            if let Some(ParsedItem(remaining, ordinal)) = dayo(input) {
                 *parsed = parsed
                     .with_year(year)
-                    .ok_or(InvalidComponent("year"))?
+                    .ok_or_else(|| invalid_component("year", original, original))?
                     .with_ordinal(ordinal)
-                    .ok_or(InvalidComponent("ordinal"))?;
-                return Ok(input);
+                    .ok_or_else(|| invalid_component("ordinal", original, input))?;
+                return Ok(remaining);
             }
 
             let parsed_week_weekday = (|| {
                 let input = ascii_char::<b'W'>(input)
-                    .ok_or((false, InvalidLiteral))?
+                    .ok_or_else(|| (false, invalid_literal(original, input)))?
                     .into_inner();
-                let ParsedItem(mut input, week) =
-                    week(input).ok_or((true, InvalidComponent("week")))?;
+                let ParsedItem(mut input, week) = week(input)
+                    .ok_or_else(|| (true, invalid_component("week", original, input)))?;
                 if extended_kind.is_extended() {
                     input = ascii_char::<b'-'>(input)
-                        .ok_or((true, InvalidLiteral))?
+                        .ok_or_else(|| (true, invalid_literal(original, input)))?
                         .into_inner();
                 }
-                let ParsedItem(input, weekday) =
-                    dayk(input).ok_or((true, InvalidComponent("weekday")))?;
+                let ParsedItem(input, weekday) = dayk(input)
+                    .ok_or_else(|| (true, invalid_component("weekday", original, input)))?;
                 Ok(ParsedItem(input, (week, weekday)))
             })();
             match parsed_week_weekday {
-                Ok(ParsedItem(input, (week, weekday))) => {
+                Ok(ParsedItem(remaining, (week, weekday))) => {
                     *parsed = parsed
                         .with_iso_year(year)
-                        .ok_or(InvalidComponent("year"))?
+                        .ok_or_else(|| invalid_component("year", original, original))?
                         .with_iso_week_number(week)
-                        .ok_or(InvalidComponent("week"))?
+                        .ok_or_else(|| invalid_component("week", original, input))?
                         .with_weekday(weekday)
-                        .ok_or(InvalidComponent("weekday"))?;
-                    return Ok(input);
+                        .ok_or_else(|| invalid_component("weekday", original, input))?;
+                    return Ok(remaining);
                 }
                 Err((false, _err)) => {}
                 // This error is more accurate than the one from year-month-day.
@@ -108,39 +170,54 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     // Basic: ["T"][hour][min][sec]
     // Extended: ["T"][hour][":"][min][":"][sec]
     // Reduced precision: components after [hour] (including their preceding separator) can be
-    // omitted. ["T"] can be omitted if there is no date present.
+    // omitted. ["T"] can be omitted if there is no date present. [" "] is accepted in place of
+    // ["T"] if the configuration permits it.
     /// Parse a time in the basic or extended format. Reduced precision is permitted.
-    pub(crate) fn parse_time<'a>(
+    fn parse_time<'a>(
+        self,
         parsed: &'a mut Parsed,
         extended_kind: &'a mut ExtendedKind,
         date_is_present: bool,
+        options: ParserOptions,
     ) -> impl FnMut(&[u8]) -> Result<&[u8], error::Parse> + 'a {
         move |mut input| {
+            let original = input;
             if date_is_present {
                 input = ascii_char::<b'T'>(input)
-                    .ok_or(InvalidLiteral)?
+                    .or_else(|| {
+                        if self.allow_space_separator {
+                            ascii_char::<b' '>(input)
+                        } else {
+                            None
+                        }
+                    })
+                    .ok_or_else(|| invalid_literal(original, input))?
                     .into_inner();
             }
 
-            let ParsedItem(mut input, hour) = float(input).ok_or(InvalidComponent("hour"))?;
+            let hour_start = input;
+            let ParsedItem(mut input, hour) = float(input, options.max_repetitions())
+                .ok_or_else(|| invalid_component("hour", original, hour_start))?;
             match hour {
-                (hour, None) => parsed.set_hour_24(hour).ok_or(InvalidComponent("hour"))?,
+                (hour, None) => parsed
+                    .set_hour_24_carrying(hour, options)
+                    .ok_or_else(|| invalid_component("hour", original, hour_start))?,
                 (hour, Some(fractional_part)) => {
                     *parsed = parsed
                         .with_hour_24(hour)
-                        .ok_or(InvalidComponent("hour"))?
+                        .ok_or_else(|| invalid_component("hour", original, hour_start))?
                         .with_minute((fractional_part * Second::per(Minute) as f64) as _)
-                        .ok_or(InvalidComponent("minute"))?
+                        .ok_or_else(|| invalid_component("minute", original, hour_start))?
                         .with_second(
                             (fractional_part * Second::per(Hour) as f64 % Minute::per(Hour) as f64)
                                 as _,
                         )
-                        .ok_or(InvalidComponent("second"))?
+                        .ok_or_else(|| invalid_component("second", original, hour_start))?
                         .with_subsecond(
                             (fractional_part * Nanosecond::per(Hour) as f64
                                 % Nanosecond::per(Second) as f64) as _,
                         )
-                        .ok_or(InvalidComponent("subsecond"))?;
+                        .ok_or_else(|| invalid_component("subsecond", original, hour_start))?;
                     return Ok(input);
                 }
             };
@@ -148,16 +225,17 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
             if let Some(ParsedItem(new_input, ())) = ascii_char::<b':'>(input) {
                 extended_kind
                     .coerce_extended()
-                    .ok_or(InvalidComponent("minute"))?;
+                    .ok_or_else(|| invalid_component("minute", original, input))?;
                 input = new_input;
             };
 
-            let mut input = match float(input) {
+            let minute_start = input;
+            let mut input = match float(input, options.max_repetitions()) {
                 Some(ParsedItem(input, (minute, None))) => {
                     extended_kind.coerce_basic();
                     parsed
-                        .set_minute(minute)
-                        .ok_or(InvalidComponent("minute"))?;
+                        .set_minute_carrying(minute, options)
+                        .ok_or_else(|| invalid_component("minute", original, minute_start))?;
                     input
                 }
                 Some(ParsedItem(input, (minute, Some(fractional_part)))) => {
@@ -165,31 +243,33 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                     extended_kind.coerce_basic();
                     *parsed = parsed
                         .with_minute(minute)
-                        .ok_or(InvalidComponent("minute"))?
+                        .ok_or_else(|| invalid_component("minute", original, minute_start))?
                         .with_second((fractional_part * Second::per(Minute) as f64) as _)
-                        .ok_or(InvalidComponent("second"))?
+                        .ok_or_else(|| invalid_component("second", original, minute_start))?
                         .with_subsecond(
                             (fractional_part * Nanosecond::per(Minute) as f64
                                 % Nanosecond::per(Second) as f64) as _,
                         )
-                        .ok_or(InvalidComponent("subsecond"))?;
+                        .ok_or_else(|| invalid_component("subsecond", original, minute_start))?;
                     return Ok(input);
                 }
                 // colon was present, so minutes are required
                 None if extended_kind.is_extended() => {
-                    return Err(error::Parse::ParseFromDescription(InvalidComponent(
+                    return Err(error::Parse::ParseFromDescription(invalid_component(
                         "minute",
+                        original,
+                        minute_start,
                     )));
                 }
                 None => {
                     // Missing components are assumed to be zero.
                     *parsed = parsed
                         .with_minute(0)
-                        .ok_or(InvalidComponent("minute"))?
+                        .ok_or_else(|| invalid_component("minute", original, minute_start))?
                         .with_second(0)
-                        .ok_or(InvalidComponent("second"))?
+                        .ok_or_else(|| invalid_component("second", original, minute_start))?
                         .with_subsecond(0)
-                        .ok_or(InvalidComponent("subsecond"))?;
+                        .ok_or_else(|| invalid_component("subsecond", original, minute_start))?;
                     return Ok(input);
                 }
             };
@@ -200,15 +280,16 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                     None => {
                         *parsed = parsed
                             .with_second(0)
-                            .ok_or(InvalidComponent("second"))?
+                            .ok_or_else(|| invalid_component("second", original, input))?
                             .with_subsecond(0)
-                            .ok_or(InvalidComponent("subsecond"))?;
+                            .ok_or_else(|| invalid_component("subsecond", original, input))?;
                         return Ok(input);
                     }
                 }
             }
 
-            let (input, second, subsecond) = match float(input) {
+            let second_start = input;
+            let (input, second, subsecond) = match float(input, options.max_repetitions()) {
                 Some(ParsedItem(input, (second, None))) => (input, second, 0),
                 Some(ParsedItem(input, (second, Some(fractional_part)))) => (
                     input,
@@ -216,8 +297,10 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                     round(fractional_part * Nanosecond::per(Second) as f64) as _,
                 ),
                 None if extended_kind.is_extended() => {
-                    return Err(error::Parse::ParseFromDescription(InvalidComponent(
+                    return Err(error::Parse::ParseFromDescription(invalid_component(
                         "second",
+                        original,
+                        second_start,
                     )));
                 }
                 // Missing components are assumed to be zero.
@@ -225,9 +308,9 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
             };
             *parsed = parsed
                 .with_second(second)
-                .ok_or(InvalidComponent("second"))?
+                .ok_or_else(|| invalid_component("second", original, second_start))?
                 .with_subsecond(subsecond)
-                .ok_or(InvalidComponent("subsecond"))?;
+                .ok_or_else(|| invalid_component("subsecond", original, second_start))?;
 
             Ok(input)
         }
@@ -237,23 +320,25 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     // Extended: [±][hour][":"][min] or ["Z"]
     // Reduced precision: [±][hour] or ["Z"]
     /// Parse a UTC offset in the basic or extended format. Reduced precision is supported.
-    pub(crate) fn parse_offset<'a>(
+    fn parse_offset<'a>(
         parsed: &'a mut Parsed,
         extended_kind: &'a mut ExtendedKind,
     ) -> impl FnMut(&[u8]) -> Result<&[u8], error::Parse> + 'a {
         move |input| {
+            let original = input;
             if let Some(ParsedItem(input, ())) = ascii_char::<b'Z'>(input) {
                 *parsed = parsed
                     .with_offset_hour(0)
-                    .ok_or(InvalidComponent("offset hour"))?
+                    .ok_or_else(|| invalid_component("offset hour", original, original))?
                     .with_offset_minute_signed(0)
-                    .ok_or(InvalidComponent("offset minute"))?
+                    .ok_or_else(|| invalid_component("offset minute", original, original))?
                     .with_offset_second_signed(0)
-                    .ok_or(InvalidComponent("offset second"))?;
+                    .ok_or_else(|| invalid_component("offset second", original, original))?;
                 return Ok(input);
             }
 
-            let ParsedItem(input, sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+            let ParsedItem(input, sign) =
+                sign(input).ok_or_else(|| invalid_component("offset hour", original, input))?;
             let mut input = hour(input)
                 .and_then(|parsed_item| {
                     parsed_item.consume_value(|hour| {
@@ -264,13 +349,13 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                         })
                     })
                 })
-                .ok_or(InvalidComponent("offset hour"))?;
+                .ok_or_else(|| invalid_component("offset hour", original, input))?;
 
             if extended_kind.maybe_extended() {
                 if let Some(ParsedItem(new_input, ())) = ascii_char::<b':'>(input) {
                     extended_kind
                         .coerce_extended()
-                        .ok_or(InvalidComponent("offset minute"))?;
+                        .ok_or_else(|| invalid_component("offset minute", original, input))?;
                     input = new_input;
                 };
             }
@@ -284,7 +369,7 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                         } else {
                             min.cast_signed()
                         })
-                        .ok_or(InvalidComponent("offset minute"))?;
+                        .ok_or_else(|| invalid_component("offset minute", original, input))?;
                 }
                 None => {
                     // Omitted offset minute is assumed to be zero.
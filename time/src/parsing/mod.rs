@@ -3,12 +3,17 @@
 pub(crate) mod combinator;
 pub(crate) mod component;
 mod iso8601;
+mod options;
 pub(crate) mod parsable;
 mod parsed;
 pub(crate) mod shim;
+mod try_in_order;
 
-pub use self::parsable::Parsable;
-pub use self::parsed::Parsed;
+pub use self::options::ParserOptions;
+pub use self::parsable::{CustomParsable, Parsable};
+pub(crate) use self::parsed::FromParsed;
+pub use self::parsed::{Parsed, WeekdayMode};
+pub use self::try_in_order::TryInOrder;
 
 /// An item that has been parsed. Represented as a `(remaining, value)` pair.
 #[derive(Debug)]
@@ -48,6 +53,7 @@ impl<'a> ParsedItem<'a, ()> {
     }
 }
 
+#[cfg(not(time_no_names))]
 impl<'a> ParsedItem<'a, Option<()>> {
     /// Discard the potential unit value, returning the remaining input.
     #[must_use = "this returns the remaining input"]
@@ -55,3 +61,27 @@ impl<'a> ParsedItem<'a, Option<()>> {
         self.0
     }
 }
+
+/// The error for a component that could not be parsed at the start of `remaining`, which must be
+/// a suffix of `input`.
+pub(crate) const fn invalid_component(
+    name: &'static str,
+    input: &[u8],
+    remaining: &[u8],
+) -> crate::error::ParseFromDescription {
+    crate::error::ParseFromDescription::InvalidComponentAt {
+        name,
+        position: input.len() - remaining.len(),
+    }
+}
+
+/// The error for a literal that could not be parsed at the start of `remaining`, which must be a
+/// suffix of `input`.
+pub(crate) const fn invalid_literal(
+    input: &[u8],
+    remaining: &[u8],
+) -> crate::error::ParseFromDescription {
+    crate::error::ParseFromDescription::InvalidLiteral {
+        position: input.len() - remaining.len(),
+    }
+}
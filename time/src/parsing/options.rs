@@ -0,0 +1,183 @@
+//! Options controlling the behavior of parsing.
+
+use crate::parsing::WeekdayMode;
+
+/// Options controlling the behavior of parsing, including limits for use with untrusted input.
+///
+/// The limits guard against inputs that are valid but unreasonably large, such as a subsecond
+/// with thousands of digits or deeply nested comments in an [RFC 2822] timestamp. Parsing such
+/// input is never unsafe, but it may take time proportional to the length of the input or exhaust
+/// the stack. By default, no limits are applied and all other options are disabled, so the
+/// behavior is identical to the `parse` method of each type.
+///
+/// Options are passed to the `parse_with_options` method of each type, such as
+/// [`OffsetDateTime::parse_with_options`](crate::OffsetDateTime::parse_with_options). They may
+/// also be passed to [`Parsed::parse_items_with_options`] and related methods, in which case the
+/// options that take effect when constructing a value are not applied.
+///
+/// [`Parsed::parse_items_with_options`]: crate::parsing::Parsed::parse_items_with_options
+/// [RFC 2822]: crate::format_description::well_known::Rfc2822
+///
+/// ```rust
+/// # use time::format_description::well_known::Rfc3339;
+/// # use time::parsing::ParserOptions;
+/// # use time::{error, OffsetDateTime};
+/// let options = ParserOptions::DEFAULT
+///     .with_max_input_len(64)
+///     .with_max_repetitions(9);
+///
+/// let parse = |input: &str| OffsetDateTime::parse_with_options(input, &Rfc3339, options);
+///
+/// assert!(parse("2021-01-02T03:04:05.123Z").is_ok());
+/// assert!(matches!(
+///     parse(&"0".repeat(65)),
+///     Err(error::Parse::ParseFromDescription(
+///         error::ParseFromDescription::InputTooLong { .. }
+///     ))
+/// ));
+/// assert!(parse("2021-01-02T03:04:05.1234567890Z").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParserOptions {
+    /// The maximum length of the input in bytes.
+    max_input_len: usize,
+    /// The maximum number of times an element may be repeated in a row.
+    max_repetitions: usize,
+    /// Whether literals in a format description are matched without regard to ASCII case.
+    case_insensitive_literals: bool,
+    /// Whether a component that is exactly one unit out of range is carried into the next larger
+    /// unit.
+    carry_allowed: bool,
+    /// How a parsed weekday is reconciled with a date determined by other components.
+    weekday_mode: WeekdayMode,
+}
+
+impl ParserOptions {
+    /// Options that do not limit or alter parsing in any way.
+    pub const DEFAULT: Self = Self::new();
+
+    /// Create options that do not limit or alter parsing in any way.
+    pub const fn new() -> Self {
+        Self {
+            max_input_len: usize::MAX,
+            max_repetitions: usize::MAX,
+            case_insensitive_literals: false,
+            carry_allowed: false,
+            weekday_mode: WeekdayMode::Ignore,
+        }
+    }
+
+    /// Set the maximum length of the input in bytes. Longer inputs are rejected with
+    /// [`ParseFromDescription::InputTooLong`] before parsing begins.
+    ///
+    /// [`ParseFromDescription::InputTooLong`]: crate::error::ParseFromDescription::InputTooLong
+    pub const fn with_max_input_len(mut self, max_input_len: usize) -> Self {
+        self.max_input_len = max_input_len;
+        self
+    }
+
+    /// Set the maximum number of times an element of the input may be repeated in a row. This
+    /// applies to the digits of a subsecond or fractional value of unbounded length, the bytes of
+    /// any whitespace and comments in [RFC 2822](crate::format_description::well_known::Rfc2822)
+    /// (including each level of nested comments), and the annotations of
+    /// [RFC 9557](crate::format_description::well_known::Rfc9557).
+    ///
+    /// Input exceeding this limit is rejected as if it were invalid at that point.
+    pub const fn with_max_repetitions(mut self, max_repetitions: usize) -> Self {
+        self.max_repetitions = max_repetitions;
+        self
+    }
+
+    /// Set whether literals in a format description are matched without regard to ASCII case.
+    /// This allows inputs such as `2024-05-01t10:30z` to match a description containing the
+    /// literals `T` and `Z`. Components, such as month names, are unaffected; use the
+    /// `case_sensitive` modifier for those.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, format_description};
+    /// # use time::parsing::ParserOptions;
+    /// # use time::PrimitiveDateTime;
+    /// let format = format_description!("[year]-[month]-[day]T[hour]:[minute]Z");
+    /// assert!(PrimitiveDateTime::parse("2024-05-01t10:30z", format).is_err());
+    ///
+    /// let options = ParserOptions::DEFAULT.with_case_insensitive_literals(true);
+    /// assert_eq!(
+    ///     PrimitiveDateTime::parse_with_options("2024-05-01t10:30z", format, options)?,
+    ///     datetime!(2024-05-01 10:30)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn with_case_insensitive_literals(mut self, value: bool) -> Self {
+        self.case_insensitive_literals = value;
+        self
+    }
+
+    /// Set whether out-of-range components are carried into the next larger unit.
+    ///
+    /// Some sources emit values such as `08:60` to mean `09:00`. When carrying is allowed, an hour
+    /// of 24, a minute of 60, or a second of 60 is accepted and carried into the next larger unit
+    /// when the final value is constructed. Only a single unit is ever carried; values further out
+    /// of range are rejected as usual.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, format_description, time};
+    /// # use time::parsing::ParserOptions;
+    /// # use time::{PrimitiveDateTime, Time};
+    /// let options = ParserOptions::DEFAULT.with_carry_allowed(true);
+    /// assert_eq!(
+    ///     Time::parse_with_options("08:60", format_description!("[hour]:[minute]"), options)?,
+    ///     time!(9:00)
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::parse_with_options(
+    ///         "2024-12-31 23:59:60",
+    ///         format_description!("[year]-[month]-[day] [hour]:[minute]:[second]"),
+    ///         options,
+    ///     )?,
+    ///     datetime!(2025-01-01 0:00)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn with_carry_allowed(mut self, value: bool) -> Self {
+        self.carry_allowed = value;
+        self
+    }
+
+    /// Set how a parsed weekday is reconciled with the rest of the date. See [`WeekdayMode`] for
+    /// details.
+    pub const fn with_weekday_mode(mut self, value: WeekdayMode) -> Self {
+        self.weekday_mode = value;
+        self
+    }
+
+    /// Obtain the maximum length of the input in bytes.
+    pub const fn max_input_len(&self) -> usize {
+        self.max_input_len
+    }
+
+    /// Obtain the maximum number of times an element of the input may be repeated in a row.
+    pub const fn max_repetitions(&self) -> usize {
+        self.max_repetitions
+    }
+
+    /// Obtain whether literals in a format description are matched without regard to ASCII case.
+    pub const fn case_insensitive_literals(&self) -> bool {
+        self.case_insensitive_literals
+    }
+
+    /// Obtain whether out-of-range components are carried into the next larger unit.
+    pub const fn carry_allowed(&self) -> bool {
+        self.carry_allowed
+    }
+
+    /// Obtain how a parsed weekday is reconciled with the rest of the date.
+    pub const fn weekday_mode(&self) -> WeekdayMode {
+        self.weekday_mode
+    }
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -4,17 +4,33 @@ use core::ops::Deref;
 
 use num_conv::prelude::*;
 
+use crate::date::{MAX_YEAR, MIN_YEAR};
 use crate::error::TryFromParsed;
 use crate::format_description::well_known::iso8601::EncodedConfig;
-use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
-use crate::format_description::BorrowedFormatItem;
+use crate::format_description::well_known::rfc9557::Annotations;
+#[cfg(not(time_no_names))]
+use crate::format_description::well_known::{HttpDate, Rfc2822};
+use crate::format_description::well_known::{
+    CommonFormat, Iso8601, Iso8601Runtime, Rfc3339, Rfc3339Lenient, Rfc9557,
+};
 #[cfg(feature = "alloc")]
 use crate::format_description::OwnedFormatItem;
-use crate::internal_macros::bug;
-use crate::parsing::{Parsed, ParsedItem};
-use crate::{error, Date, Month, OffsetDateTime, Time, UtcOffset, Weekday};
+use crate::format_description::{BorrowedFormatItem, Custom};
+use crate::parsing::{Parsed, ParsedItem, ParserOptions, TryInOrder};
+#[cfg(not(time_no_names))]
+use crate::Weekday;
+use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset};
 
 /// A type that can be parsed.
+///
+/// This trait is sealed and cannot be implemented outside of this crate. To define a new format,
+/// implement [`CustomParsable`] and wrap the type in [`Custom`].
+///
+/// ```compile_fail
+/// struct MyFormat;
+///
+/// impl time::parsing::Parsable for MyFormat {}
+/// ```
 #[cfg_attr(docsrs, doc(notable_trait))]
 #[doc(alias = "Parseable")]
 pub trait Parsable: sealed::Sealed {}
@@ -24,17 +40,40 @@ impl Parsable for [BorrowedFormatItem<'_>] {}
 impl Parsable for OwnedFormatItem {}
 #[cfg(feature = "alloc")]
 impl Parsable for [OwnedFormatItem] {}
+#[cfg(not(time_no_names))]
+impl Parsable for HttpDate {}
+#[cfg(not(time_no_names))]
 impl Parsable for Rfc2822 {}
 impl Parsable for Rfc3339 {}
+impl Parsable for Rfc3339Lenient {}
+impl Parsable for Rfc9557<'_> {}
 impl<const CONFIG: EncodedConfig> Parsable for Iso8601<CONFIG> {}
+impl Parsable for Iso8601Runtime {}
+impl Parsable for CommonFormat {}
+impl<T: CustomParsable> Parsable for Custom<T> {}
 impl<T: Deref> Parsable for T where T::Target: Parsable {}
 
+/// A format that is defined outside of this crate. Wrap the type in [`Custom`] to use it as a
+/// [`Parsable`].
+///
+/// See [`Custom`] for an example.
+pub trait CustomParsable {
+    /// Parse the input into the provided [`Parsed`] struct, returning the remaining input.
+    ///
+    /// Only the components present in the input should be set. Whether the components are
+    /// sufficient to construct the requested type is checked afterwards.
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse>;
+}
+
 /// Seal the trait to prevent downstream users from implementing it, while still allowing it to
 /// exist in generic bounds.
 mod sealed {
     #[allow(clippy::wildcard_imports)]
     use super::*;
-    use crate::{PrimitiveDateTime, UtcDateTime};
 
     /// Parse the item using a format description and an input.
     pub trait Sealed {
@@ -45,6 +84,7 @@ mod sealed {
             &self,
             input: &'a [u8],
             parsed: &mut Parsed,
+            options: ParserOptions,
         ) -> Result<&'a [u8], error::Parse>;
 
         /// Parse the item into a new [`Parsed`] struct.
@@ -52,12 +92,35 @@ mod sealed {
         /// This method can only be used to parse a complete value of a type. If any characters
         /// remain after parsing, an error will be returned.
         fn parse(&self, input: &[u8]) -> Result<Parsed, error::Parse> {
+            self.parse_with_options(input, ParserOptions::DEFAULT)
+        }
+
+        /// Parse the item into a new [`Parsed`] struct, subject to the provided [`ParserOptions`].
+        ///
+        /// This method can only be used to parse a complete value of a type. If any characters
+        /// remain after parsing, an error will be returned.
+        fn parse_with_options(
+            &self,
+            input: &[u8],
+            options: ParserOptions,
+        ) -> Result<Parsed, error::Parse> {
+            if input.len() > options.max_input_len() {
+                return Err(error::Parse::ParseFromDescription(
+                    error::ParseFromDescription::InputTooLong {
+                        position: options.max_input_len(),
+                    },
+                ));
+            }
+
             let mut parsed = Parsed::new();
-            if self.parse_into(input, &mut parsed)?.is_empty() {
+            let remaining = self.parse_into(input, &mut parsed, options)?;
+            if remaining.is_empty() {
                 Ok(parsed)
             } else {
                 Err(error::Parse::ParseFromDescription(
-                    error::ParseFromDescription::UnexpectedTrailingCharacters,
+                    error::ParseFromDescription::UnexpectedTrailingCharacters {
+                        position: input.len() - remaining.len(),
+                    },
                 ))
             }
         }
@@ -103,8 +166,9 @@ impl sealed::Sealed for BorrowedFormatItem<'_> {
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
+        options: ParserOptions,
     ) -> Result<&'a [u8], error::Parse> {
-        Ok(parsed.parse_item(input, self)?)
+        Ok(parsed.parse_item_with_options(input, self, options)?)
     }
 }
 
@@ -113,8 +177,9 @@ impl sealed::Sealed for [BorrowedFormatItem<'_>] {
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
+        options: ParserOptions,
     ) -> Result<&'a [u8], error::Parse> {
-        Ok(parsed.parse_items(input, self)?)
+        Ok(parsed.parse_items_with_options(input, self, options)?)
     }
 }
 
@@ -124,8 +189,9 @@ impl sealed::Sealed for OwnedFormatItem {
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
+        options: ParserOptions,
     ) -> Result<&'a [u8], error::Parse> {
-        Ok(parsed.parse_item(input, self)?)
+        Ok(parsed.parse_item_with_options(input, self, options)?)
     }
 }
 
@@ -135,8 +201,9 @@ impl sealed::Sealed for [OwnedFormatItem] {
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
+        options: ParserOptions,
     ) -> Result<&'a [u8], error::Parse> {
-        Ok(parsed.parse_items(input, self)?)
+        Ok(parsed.parse_items_with_options(input, self, options)?)
     }
 }
 
@@ -148,27 +215,176 @@ where
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
+        options: ParserOptions,
+    ) -> Result<&'a [u8], error::Parse> {
+        self.deref().parse_into(input, parsed, options)
+    }
+
+    fn parse_with_options(
+        &self,
+        input: &[u8],
+        options: ParserOptions,
+    ) -> Result<Parsed, error::Parse> {
+        self.deref().parse_with_options(input, options)
+    }
+
+    fn parse_date(&self, input: &[u8]) -> Result<Date, error::Parse> {
+        self.deref().parse_date(input)
+    }
+
+    fn parse_time(&self, input: &[u8]) -> Result<Time, error::Parse> {
+        self.deref().parse_time(input)
+    }
+
+    fn parse_offset(&self, input: &[u8]) -> Result<UtcOffset, error::Parse> {
+        self.deref().parse_offset(input)
+    }
+
+    fn parse_primitive_date_time(&self, input: &[u8]) -> Result<PrimitiveDateTime, error::Parse> {
+        self.deref().parse_primitive_date_time(input)
+    }
+
+    fn parse_utc_date_time(&self, input: &[u8]) -> Result<UtcDateTime, error::Parse> {
+        self.deref().parse_utc_date_time(input)
+    }
+
+    fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
+        self.deref().parse_offset_date_time(input)
+    }
+}
+
+impl<T: CustomParsable> sealed::Sealed for Custom<T> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        _: ParserOptions,
     ) -> Result<&'a [u8], error::Parse> {
-        self.deref().parse_into(input, parsed)
+        self.0.parse_into(input, parsed)
     }
 }
 // endregion custom formats
 
+// region: combinators
+/// Evaluate `$parse` with `$format` bound to each element of the tuple in order, returning the
+/// first success. If every element fails, the first error is returned.
+macro_rules! try_in_order {
+    ($tuple:expr; $first:tt $(, $rest:tt)*; |$format:ident| $parse:expr) => {{
+        let $format = &$tuple.$first;
+        let first_err = match $parse {
+            Ok(value) => return Ok(value),
+            Err(err) => err,
+        };
+        $(
+            let $format = &$tuple.$rest;
+            if let Ok(value) = $parse {
+                return Ok(value);
+            }
+        )*
+        Err(first_err)
+    }};
+}
+
+/// Implement [`Parsable`] for [`TryInOrder`] wrapping tuples of each provided arity.
+macro_rules! impl_try_in_order {
+    ($($($ty:ident $idx:tt),+;)*) => {$(
+        impl<$($ty: Parsable),+> Parsable for TryInOrder<($($ty,)+)> {}
+
+        impl<$($ty: Parsable),+> sealed::Sealed for TryInOrder<($($ty,)+)> {
+            fn parse_into<'a>(
+                &self,
+                input: &'a [u8],
+                parsed: &mut Parsed,
+                options: ParserOptions,
+            ) -> Result<&'a [u8], error::Parse> {
+                try_in_order!(self.0; $($idx),+; |format| {
+                    // Only the values parsed by the successful format are retained.
+                    let mut attempt = *parsed;
+                    format.parse_into(input, &mut attempt, options).map(|remaining| {
+                        *parsed = attempt;
+                        remaining
+                    })
+                })
+            }
+
+            fn parse_with_options(
+                &self,
+                input: &[u8],
+                options: ParserOptions,
+            ) -> Result<Parsed, error::Parse> {
+                try_in_order!(self.0; $($idx),+; |format| {
+                    format.parse_with_options(input, options)
+                })
+            }
+
+            fn parse_date(&self, input: &[u8]) -> Result<Date, error::Parse> {
+                try_in_order!(self.0; $($idx),+; |format| format.parse_date(input))
+            }
+
+            fn parse_time(&self, input: &[u8]) -> Result<Time, error::Parse> {
+                try_in_order!(self.0; $($idx),+; |format| format.parse_time(input))
+            }
+
+            fn parse_offset(&self, input: &[u8]) -> Result<UtcOffset, error::Parse> {
+                try_in_order!(self.0; $($idx),+; |format| format.parse_offset(input))
+            }
+
+            fn parse_primitive_date_time(
+                &self,
+                input: &[u8],
+            ) -> Result<PrimitiveDateTime, error::Parse> {
+                try_in_order!(self.0; $($idx),+; |format| {
+                    format.parse_primitive_date_time(input)
+                })
+            }
+
+            fn parse_utc_date_time(&self, input: &[u8]) -> Result<UtcDateTime, error::Parse> {
+                try_in_order!(self.0; $($idx),+; |format| format.parse_utc_date_time(input))
+            }
+
+            fn parse_offset_date_time(
+                &self,
+                input: &[u8],
+            ) -> Result<OffsetDateTime, error::Parse> {
+                try_in_order!(self.0; $($idx),+; |format| format.parse_offset_date_time(input))
+            }
+        }
+    )*};
+}
+
+impl_try_in_order! {
+    A 0;
+    A 0, B 1;
+    A 0, B 1, C 2;
+    A 0, B 1, C 2, D 3;
+    A 0, B 1, C 2, D 3, E 4;
+    A 0, B 1, C 2, D 3, E 4, F 5;
+    A 0, B 1, C 2, D 3, E 4, F 5, G 6;
+    A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7;
+}
+// endregion combinators
+
 // region: well-known formats
+#[cfg(not(time_no_names))]
 impl sealed::Sealed for Rfc2822 {
     fn parse_into<'a>(
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
+        options: ParserOptions,
     ) -> Result<&'a [u8], error::Parse> {
-        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
-        use crate::parsing::combinator::rfc::rfc2822::{cfws, fws};
+        use crate::parsing::combinator::rfc::rfc2822;
         use crate::parsing::combinator::{
             ascii_char, exactly_n_digits, first_match, n_to_m_digits, opt, sign,
         };
+        use crate::parsing::{invalid_component, invalid_literal};
 
+        let max_repetitions = options.max_repetitions();
+        let cfws = |input: &'a [u8]| rfc2822::cfws(input, max_repetitions);
+        let fws = |input: &'a [u8]| rfc2822::fws(input, max_repetitions);
         let colon = ascii_char::<b':'>;
         let comma = ascii_char::<b','>;
+        let original = input;
 
         let input = opt(cfws)(input).into_inner();
         let weekday = first_match(
@@ -186,16 +402,20 @@ impl sealed::Sealed for Rfc2822 {
         let input = if let Some(item) = weekday {
             let input = item
                 .consume_value(|value| parsed.set_weekday(value))
-                .ok_or(InvalidComponent("weekday"))?;
-            let input = comma(input).ok_or(InvalidLiteral)?.into_inner();
+                .ok_or_else(|| invalid_component("weekday", original, input))?;
+            let input = comma(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner();
             opt(cfws)(input).into_inner()
         } else {
             input
         };
         let input = n_to_m_digits::<1, 2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
-            .ok_or(InvalidComponent("day"))?;
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or_else(|| invalid_component("day", original, input))?;
+        let input = cfws(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
         let input = first_match(
             [
                 (b"Jan".as_slice(), Month::January),
@@ -214,8 +434,10 @@ impl sealed::Sealed for Rfc2822 {
             false,
         )(input)
         .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
-        .ok_or(InvalidComponent("month"))?;
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+        .ok_or_else(|| invalid_component("month", original, input))?;
+        let input = cfws(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
         let input = match exactly_n_digits::<4, u32>(input) {
             Some(item) => {
                 let input = item
@@ -223,8 +445,10 @@ impl sealed::Sealed for Rfc2822 {
                     .and_then(|item| {
                         item.consume_value(|value| parsed.set_year(value.cast_signed()))
                     })
-                    .ok_or(InvalidComponent("year"))?;
-                fws(input).ok_or(InvalidLiteral)?.into_inner()
+                    .ok_or_else(|| invalid_component("year", original, input))?;
+                fws(input)
+                    .ok_or_else(|| invalid_literal(original, input))?
+                    .into_inner()
             }
             None => {
                 let input = exactly_n_digits::<2, u32>(input)
@@ -233,30 +457,40 @@ impl sealed::Sealed for Rfc2822 {
                             .map(|year| year.cast_signed())
                             .consume_value(|value| parsed.set_year(value))
                     })
-                    .ok_or(InvalidComponent("year"))?;
-                cfws(input).ok_or(InvalidLiteral)?.into_inner()
+                    .ok_or_else(|| invalid_component("year", original, input))?;
+                cfws(input)
+                    .ok_or_else(|| invalid_literal(original, input))?
+                    .into_inner()
             }
         };
 
         let input = exactly_n_digits::<2, _>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
-            .ok_or(InvalidComponent("hour"))?;
+            .and_then(|item| {
+                item.consume_value(|value| parsed.set_hour_24_carrying(value, options))
+            })
+            .ok_or_else(|| invalid_component("hour", original, input))?;
         let input = opt(cfws)(input).into_inner();
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
         let input = opt(cfws)(input).into_inner();
         let input = exactly_n_digits::<2, _>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
-            .ok_or(InvalidComponent("minute"))?;
+            .and_then(|item| item.consume_value(|value| parsed.set_minute_carrying(value, options)))
+            .ok_or_else(|| invalid_component("minute", original, input))?;
 
         let input = if let Some(input) = colon(opt(cfws)(input).into_inner()) {
             let input = input.into_inner(); // discard the colon
             let input = opt(cfws)(input).into_inner();
             let input = exactly_n_digits::<2, _>(input)
                 .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
-                .ok_or(InvalidComponent("second"))?;
-            cfws(input).ok_or(InvalidLiteral)?.into_inner()
+                .ok_or_else(|| invalid_component("second", original, input))?;
+            cfws(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner()
         } else {
-            cfws(input).ok_or(InvalidLiteral)?.into_inner()
+            cfws(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner()
         };
 
         // The RFC explicitly allows leap seconds.
@@ -287,17 +521,18 @@ impl sealed::Sealed for Rfc2822 {
         if let Some(zone_literal) = zone_literal {
             let input = zone_literal
                 .consume_value(|value| parsed.set_offset_hour(value))
-                .ok_or(InvalidComponent("offset hour"))?;
+                .ok_or_else(|| invalid_component("offset hour", original, input))?;
             parsed
                 .set_offset_minute_signed(0)
-                .ok_or(InvalidComponent("offset minute"))?;
+                .ok_or_else(|| invalid_component("offset minute", original, input))?;
             parsed
                 .set_offset_second_signed(0)
-                .ok_or(InvalidComponent("offset second"))?;
-            return Ok(input);
+                .ok_or_else(|| invalid_component("offset second", original, input))?;
+            return Ok(opt(cfws)(input).into_inner());
         }
 
-        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+        let ParsedItem(input, offset_sign) =
+            sign(input).ok_or_else(|| invalid_component("offset hour", original, input))?;
         let input = exactly_n_digits::<2, u8>(input)
             .and_then(|item| {
                 item.map(|offset_hour| {
@@ -309,158 +544,157 @@ impl sealed::Sealed for Rfc2822 {
                 })
                 .consume_value(|value| parsed.set_offset_hour(value))
             })
-            .ok_or(InvalidComponent("offset hour"))?;
+            .ok_or_else(|| invalid_component("offset hour", original, input))?;
         let input = exactly_n_digits::<2, u8>(input)
             .and_then(|item| {
                 item.consume_value(|value| parsed.set_offset_minute_signed(value.cast_signed()))
             })
-            .ok_or(InvalidComponent("offset minute"))?;
+            .ok_or_else(|| invalid_component("offset minute", original, input))?;
 
         let input = opt(cfws)(input).into_inner();
 
         Ok(input)
     }
 
+    fn parse_utc_date_time(&self, input: &[u8]) -> Result<UtcDateTime, error::Parse> {
+        checked_to_utc(self.parse_offset_date_time(input)?)
+    }
+
     fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
-        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
-        use crate::parsing::combinator::rfc::rfc2822::{cfws, fws};
+        use crate::parsing::combinator::rfc::rfc2822;
         use crate::parsing::combinator::{
             ascii_char, exactly_n_digits, first_match, n_to_m_digits, opt, sign,
         };
+        use crate::parsing::{invalid_component, invalid_literal};
 
+        let cfws = |input| rfc2822::cfws(input, usize::MAX);
+        let fws = |input| rfc2822::fws(input, usize::MAX);
         let colon = ascii_char::<b':'>;
         let comma = ascii_char::<b','>;
+        let original = input;
 
         let input = opt(cfws)(input).into_inner();
-        // This parses the weekday, but we don't actually use the value anywhere. Because of this,
-        // just return `()` to avoid unnecessary generated code.
-        let weekday = first_match(
-            [
-                (b"Mon".as_slice(), ()),
-                (b"Tue".as_slice(), ()),
-                (b"Wed".as_slice(), ()),
-                (b"Thu".as_slice(), ()),
-                (b"Fri".as_slice(), ()),
-                (b"Sat".as_slice(), ()),
-                (b"Sun".as_slice(), ()),
-            ],
-            false,
-        )(input);
+        // The weekday is not used, as the date is fully determined by the other components.
+        let weekday = rfc2822::day_name(input);
         let input = if let Some(item) = weekday {
             let input = item.into_inner();
-            let input = comma(input).ok_or(InvalidLiteral)?.into_inner();
+            let input = comma(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner();
             opt(cfws)(input).into_inner()
         } else {
             input
         };
-        let ParsedItem(input, day) =
-            n_to_m_digits::<1, 2, _>(input).ok_or(InvalidComponent("day"))?;
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
-        let ParsedItem(input, month) = first_match(
-            [
-                (b"Jan".as_slice(), Month::January),
-                (b"Feb".as_slice(), Month::February),
-                (b"Mar".as_slice(), Month::March),
-                (b"Apr".as_slice(), Month::April),
-                (b"May".as_slice(), Month::May),
-                (b"Jun".as_slice(), Month::June),
-                (b"Jul".as_slice(), Month::July),
-                (b"Aug".as_slice(), Month::August),
-                (b"Sep".as_slice(), Month::September),
-                (b"Oct".as_slice(), Month::October),
-                (b"Nov".as_slice(), Month::November),
-                (b"Dec".as_slice(), Month::December),
-            ],
-            false,
-        )(input)
-        .ok_or(InvalidComponent("month"))?;
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+        let ParsedItem(input, day) = n_to_m_digits::<1, 2, _>(input)
+            .ok_or_else(|| invalid_component("day", original, input))?;
+        let input = cfws(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
+        let ParsedItem(input, month) = rfc2822::month_name(input)
+            .ok_or_else(|| invalid_component("month", original, input))?;
+        let input = cfws(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
         let (input, year) = match exactly_n_digits::<4, u32>(input) {
             Some(item) => {
                 let ParsedItem(input, year) = item
                     .flat_map(|year| if year >= 1900 { Some(year) } else { None })
-                    .ok_or(InvalidComponent("year"))?;
-                let input = fws(input).ok_or(InvalidLiteral)?.into_inner();
+                    .ok_or_else(|| invalid_component("year", original, input))?;
+                let input = fws(input)
+                    .ok_or_else(|| invalid_literal(original, input))?
+                    .into_inner();
                 (input, year)
             }
             None => {
                 let ParsedItem(input, year) = exactly_n_digits::<2, u32>(input)
                     .map(|item| item.map(|year| if year < 50 { year + 2000 } else { year + 1900 }))
-                    .ok_or(InvalidComponent("year"))?;
-                let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+                    .ok_or_else(|| invalid_component("year", original, input))?;
+                let input = cfws(input)
+                    .ok_or_else(|| invalid_literal(original, input))?
+                    .into_inner();
                 (input, year)
             }
         };
 
-        let ParsedItem(input, hour) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("hour"))?;
+        let ParsedItem(input, hour) = exactly_n_digits::<2, _>(input)
+            .ok_or_else(|| invalid_component("hour", original, input))?;
         let input = opt(cfws)(input).into_inner();
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
         let input = opt(cfws)(input).into_inner();
-        let ParsedItem(input, minute) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("minute"))?;
+        let ParsedItem(input, minute) = exactly_n_digits::<2, _>(input)
+            .ok_or_else(|| invalid_component("minute", original, input))?;
 
         let (input, mut second) = if let Some(input) = colon(opt(cfws)(input).into_inner()) {
             let input = input.into_inner(); // discard the colon
             let input = opt(cfws)(input).into_inner();
-            let ParsedItem(input, second) =
-                exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("second"))?;
-            let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+            let ParsedItem(input, second) = exactly_n_digits::<2, _>(input)
+                .ok_or_else(|| invalid_component("second", original, input))?;
+            let input = cfws(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner();
             (input, second)
         } else {
-            (cfws(input).ok_or(InvalidLiteral)?.into_inner(), 0)
+            (
+                cfws(input)
+                    .ok_or_else(|| invalid_literal(original, input))?
+                    .into_inner(),
+                0,
+            )
         };
 
-        #[allow(clippy::unnecessary_lazy_evaluations)] // rust-lang/rust-clippy#8522
-        let zone_literal = first_match(
-            [
-                (b"UT".as_slice(), 0),
-                (b"GMT".as_slice(), 0),
-                (b"EST".as_slice(), -5),
-                (b"EDT".as_slice(), -4),
-                (b"CST".as_slice(), -6),
-                (b"CDT".as_slice(), -5),
-                (b"MST".as_slice(), -7),
-                (b"MDT".as_slice(), -6),
-                (b"PST".as_slice(), -8),
-                (b"PDT".as_slice(), -7),
-            ],
-            false,
-        )(input)
-        .or_else(|| match input {
-            [b'a'..=b'i' | b'k'..=b'z' | b'A'..=b'I' | b'K'..=b'Z', rest @ ..] => {
-                Some(ParsedItem(rest, 0))
-            }
-            _ => None,
-        });
-
-        let (input, offset_hour, offset_minute) = if let Some(zone_literal) = zone_literal {
-            let ParsedItem(input, offset_hour) = zone_literal;
-            (input, offset_hour, 0)
-        } else {
-            let ParsedItem(input, offset_sign) =
-                sign(input).ok_or(InvalidComponent("offset hour"))?;
-            let ParsedItem(input, offset_hour) = exactly_n_digits::<2, u8>(input)
-                .map(|item| {
-                    item.map(|offset_hour| {
-                        if offset_sign == b'-' {
-                            -offset_hour.cast_signed()
-                        } else {
-                            offset_hour.cast_signed()
-                        }
+        // Numeric offsets are the most common, so they are checked before any of the zone literals.
+        let (input, offset_hour, offset_minute) =
+            if let Some(ParsedItem(input, offset_sign)) = sign(input) {
+                let ParsedItem(input, offset_hour) = exactly_n_digits::<2, u8>(input)
+                    .map(|item| {
+                        item.map(|offset_hour| {
+                            if offset_sign == b'-' {
+                                -offset_hour.cast_signed()
+                            } else {
+                                offset_hour.cast_signed()
+                            }
+                        })
                     })
+                    .ok_or_else(|| invalid_component("offset hour", original, input))?;
+                let ParsedItem(input, offset_minute) = exactly_n_digits::<2, u8>(input)
+                    .ok_or_else(|| invalid_component("offset minute", original, input))?;
+                (input, offset_hour, offset_minute.cast_signed())
+            } else {
+                #[allow(clippy::unnecessary_lazy_evaluations)] // rust-lang/rust-clippy#8522
+                let ParsedItem(input, offset_hour) = first_match(
+                    [
+                        (b"UT".as_slice(), 0),
+                        (b"GMT".as_slice(), 0),
+                        (b"EST".as_slice(), -5),
+                        (b"EDT".as_slice(), -4),
+                        (b"CST".as_slice(), -6),
+                        (b"CDT".as_slice(), -5),
+                        (b"MST".as_slice(), -7),
+                        (b"MDT".as_slice(), -6),
+                        (b"PST".as_slice(), -8),
+                        (b"PDT".as_slice(), -7),
+                    ],
+                    false,
+                )(input)
+                .or_else(|| match input {
+                    [b'a'..=b'i' | b'k'..=b'z' | b'A'..=b'I' | b'K'..=b'Z', rest @ ..] => {
+                        Some(ParsedItem(rest, 0))
+                    }
+                    _ => None,
                 })
-                .ok_or(InvalidComponent("offset hour"))?;
-            let ParsedItem(input, offset_minute) =
-                exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset minute"))?;
-            (input, offset_hour, offset_minute.cast_signed())
-        };
+                .ok_or_else(|| invalid_component("offset hour", original, input))?;
+                (input, offset_hour, 0)
+            };
 
         let input = opt(cfws)(input).into_inner();
 
         if !input.is_empty() {
             return Err(error::Parse::ParseFromDescription(
-                error::ParseFromDescription::UnexpectedTrailingCharacters,
+                error::ParseFromDescription::UnexpectedTrailingCharacters {
+                    position: original.len() - input.len(),
+                },
             ));
         }
 
@@ -489,6 +723,7 @@ impl sealed::Sealed for Rfc2822 {
                     maximum: 59,
                     value: 60,
                     conditional_message: Some("because leap seconds are not supported"),
+                    conditional_date: None,
                 },
             )));
         }
@@ -497,32 +732,238 @@ impl sealed::Sealed for Rfc2822 {
     }
 }
 
+#[cfg(not(time_no_names))]
+impl sealed::Sealed for HttpDate {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        options: ParserOptions,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits, first_match};
+        use crate::parsing::{invalid_component, invalid_literal};
+
+        const MONTHS: [(&[u8], Month); 12] = [
+            (b"Jan", Month::January),
+            (b"Feb", Month::February),
+            (b"Mar", Month::March),
+            (b"Apr", Month::April),
+            (b"May", Month::May),
+            (b"Jun", Month::June),
+            (b"Jul", Month::July),
+            (b"Aug", Month::August),
+            (b"Sep", Month::September),
+            (b"Oct", Month::October),
+            (b"Nov", Month::November),
+            (b"Dec", Month::December),
+        ];
+
+        let colon = ascii_char::<b':'>;
+        let comma = ascii_char::<b','>;
+        let hyphen = ascii_char::<b'-'>;
+        let space = ascii_char::<b' '>;
+        let original = input;
+
+        // Only the obsolete RFC 850 form uses the full name of the weekday. It must be checked
+        // first, as the abbreviated names are prefixes of the full names.
+        let long_weekday = first_match(
+            [
+                (b"Monday".as_slice(), Weekday::Monday),
+                (b"Tuesday".as_slice(), Weekday::Tuesday),
+                (b"Wednesday".as_slice(), Weekday::Wednesday),
+                (b"Thursday".as_slice(), Weekday::Thursday),
+                (b"Friday".as_slice(), Weekday::Friday),
+                (b"Saturday".as_slice(), Weekday::Saturday),
+                (b"Sunday".as_slice(), Weekday::Sunday),
+            ],
+            true,
+        )(input);
+        let is_rfc850 = long_weekday.is_some();
+        let input = long_weekday
+            .or_else(|| {
+                first_match(
+                    [
+                        (b"Mon".as_slice(), Weekday::Monday),
+                        (b"Tue".as_slice(), Weekday::Tuesday),
+                        (b"Wed".as_slice(), Weekday::Wednesday),
+                        (b"Thu".as_slice(), Weekday::Thursday),
+                        (b"Fri".as_slice(), Weekday::Friday),
+                        (b"Sat".as_slice(), Weekday::Saturday),
+                        (b"Sun".as_slice(), Weekday::Sunday),
+                    ],
+                    true,
+                )(input)
+            })
+            .and_then(|item| item.consume_value(|value| parsed.set_weekday(value)))
+            .ok_or_else(|| invalid_component("weekday", original, input))?;
+        // The asctime form is the only one without a comma after the weekday.
+        let is_asctime = !is_rfc850 && space(input).is_some();
+
+        let input = if is_asctime {
+            let input = space(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner();
+            let input = first_match(MONTHS, true)(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+                .ok_or_else(|| invalid_component("month", original, input))?;
+            let input = space(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner();
+            // Single-digit days are padded with a space.
+            let input = match space(input) {
+                Some(item) => {
+                    let input = item.into_inner();
+                    exactly_n_digits::<1, _>(input)
+                        .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+                        .ok_or_else(|| invalid_component("day", original, input))?
+                }
+                None => exactly_n_digits::<2, _>(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+                    .ok_or_else(|| invalid_component("day", original, input))?,
+            };
+            space(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner()
+        } else {
+            let input = comma(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner();
+            let input = space(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner();
+            let input = exactly_n_digits::<2, _>(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+                .ok_or_else(|| invalid_component("day", original, input))?;
+            let separator = if is_rfc850 { hyphen } else { space };
+            let input = separator(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner();
+            let input = first_match(MONTHS, true)(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+                .ok_or_else(|| invalid_component("month", original, input))?;
+            let input = separator(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner();
+            let input = if is_rfc850 {
+                // RFC 9110 interprets the year relative to the current date. A fixed pivot is used
+                // instead so that parsing does not depend on the system clock.
+                exactly_n_digits::<2, u16>(input)
+                    .and_then(|item| {
+                        item.map(|year| if year < 50 { year + 2000 } else { year + 1900 })
+                            .map(i32::from)
+                            .consume_value(|value| parsed.set_year(value))
+                    })
+                    .ok_or_else(|| invalid_component("year", original, input))?
+            } else {
+                exactly_n_digits::<4, u16>(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_year(value.into())))
+                    .ok_or_else(|| invalid_component("year", original, input))?
+            };
+            space(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner()
+        };
+
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| {
+                item.consume_value(|value| parsed.set_hour_24_carrying(value, options))
+            })
+            .ok_or_else(|| invalid_component("hour", original, input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_minute_carrying(value, options)))
+            .ok_or_else(|| invalid_component("minute", original, input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+            .ok_or_else(|| invalid_component("second", original, input))?;
+        let input = space(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
+
+        let input = if is_asctime {
+            exactly_n_digits::<4, u16>(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_year(value.into())))
+                .ok_or_else(|| invalid_component("year", original, input))?
+        } else {
+            input
+                .strip_prefix(b"GMT")
+                .ok_or_else(|| invalid_literal(original, input))?
+        };
+
+        // The RFC explicitly allows leap seconds.
+        parsed.leap_second_allowed = true;
+
+        // All forms are in UTC.
+        parsed
+            .set_offset_hour(0)
+            .ok_or_else(|| invalid_component("offset hour", original, input))?;
+        parsed
+            .set_offset_minute_signed(0)
+            .ok_or_else(|| invalid_component("offset minute", original, input))?;
+        parsed
+            .set_offset_second_signed(0)
+            .ok_or_else(|| invalid_component("offset second", original, input))?;
+
+        Ok(input)
+    }
+}
+
 impl sealed::Sealed for Rfc3339 {
     fn parse_into<'a>(
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
+        options: ParserOptions,
+    ) -> Result<&'a [u8], error::Parse> {
+        Rfc3339Lenient::DEFAULT.parse_into(input, parsed, options)
+    }
+
+    fn parse_utc_date_time(&self, input: &[u8]) -> Result<UtcDateTime, error::Parse> {
+        Rfc3339Lenient::DEFAULT.parse_utc_date_time(input)
+    }
+
+    fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
+        Rfc3339Lenient::DEFAULT.parse_offset_date_time(input)
+    }
+}
+
+impl sealed::Sealed for Rfc3339Lenient {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        options: ParserOptions,
     ) -> Result<&'a [u8], error::Parse> {
-        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
         use crate::parsing::combinator::{
-            any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
+            ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
         };
+        use crate::parsing::{invalid_component, invalid_literal};
 
         let dash = ascii_char::<b'-'>;
         let colon = ascii_char::<b':'>;
+        let original = input;
 
         let input = exactly_n_digits::<4, u32>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_year(value.cast_signed())))
-            .ok_or(InvalidComponent("year"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or_else(|| invalid_component("year", original, input))?;
+        let input = dash(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.flat_map(|value| Month::from_number(value).ok()))
             .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
-            .ok_or(InvalidComponent("month"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or_else(|| invalid_component("month", original, input))?;
+        let input = dash(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
-            .ok_or(InvalidComponent("day"))?;
+            .ok_or_else(|| invalid_component("day", original, input))?;
 
         // RFC3339 allows any separator, not just `T`, not just `space`.
         // cf. Section 5.6: Internet Date/Time Format:
@@ -531,37 +972,42 @@ impl sealed::Sealed for Rfc3339 {
         //   readability, to specify a full-date and full-time separated by
         //   (say) a space character.
         // Specifically, rusqlite uses space separators.
-        let input = input.get(1..).ok_or(InvalidComponent("separator"))?;
+        let input = input
+            .get(1..)
+            .ok_or_else(|| invalid_component("separator", original, input))?;
 
         let input = exactly_n_digits::<2, _>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
-            .ok_or(InvalidComponent("hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = exactly_n_digits::<2, _>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
-            .ok_or(InvalidComponent("minute"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            .and_then(|item| {
+                item.consume_value(|value| parsed.set_hour_24_carrying(value, options))
+            })
+            .ok_or_else(|| invalid_component("hour", original, input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
         let input = exactly_n_digits::<2, _>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
-            .ok_or(InvalidComponent("second"))?;
-        let input = if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
-            let ParsedItem(mut input, mut value) = any_digit(input)
-                .ok_or(InvalidComponent("subsecond"))?
-                .map(|v| (v - b'0').extend::<u32>() * 100_000_000);
-
-            let mut multiplier = 10_000_000;
-            while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
-                value += (digit - b'0').extend::<u32>() * multiplier;
-                input = new_input;
-                multiplier /= 10;
-            }
-
+            .and_then(|item| item.consume_value(|value| parsed.set_minute_carrying(value, options)))
+            .ok_or_else(|| invalid_component("minute", original, input))?;
+        let input = if self.allows_missing_seconds() && colon(input).is_none() {
             parsed
-                .set_subsecond(value)
-                .ok_or(InvalidComponent("subsecond"))?;
+                .set_second(0)
+                .ok_or_else(|| invalid_component("second", original, input))?;
             input
         } else {
-            input
+            let input = colon(input)
+                .ok_or_else(|| invalid_literal(original, input))?
+                .into_inner();
+            let input = exactly_n_digits::<2, _>(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+                .ok_or_else(|| invalid_component("second", original, input))?;
+            match parse_rfc3339_subsecond(input, original, options.max_repetitions())? {
+                Some(ParsedItem(input, value)) => {
+                    parsed
+                        .set_subsecond(value)
+                        .ok_or_else(|| invalid_component("subsecond", original, input))?;
+                    input
+                }
+                None => input,
+            }
         };
 
         // The RFC explicitly allows leap seconds.
@@ -570,17 +1016,18 @@ impl sealed::Sealed for Rfc3339 {
         if let Some(ParsedItem(input, ())) = ascii_char_ignore_case::<b'Z'>(input) {
             parsed
                 .set_offset_hour(0)
-                .ok_or(InvalidComponent("offset hour"))?;
+                .ok_or_else(|| invalid_component("offset hour", original, input))?;
             parsed
                 .set_offset_minute_signed(0)
-                .ok_or(InvalidComponent("offset minute"))?;
+                .ok_or_else(|| invalid_component("offset minute", original, input))?;
             parsed
                 .set_offset_second_signed(0)
-                .ok_or(InvalidComponent("offset second"))?;
+                .ok_or_else(|| invalid_component("offset second", original, input))?;
             return Ok(input);
         }
 
-        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+        let ParsedItem(input, offset_sign) =
+            sign(input).ok_or_else(|| invalid_component("offset hour", original, input))?;
         let input = exactly_n_digits::<2, u8>(input)
             .and_then(|item| {
                 item.filter(|&offset_hour| offset_hour <= 23)?
@@ -593,8 +1040,10 @@ impl sealed::Sealed for Rfc3339 {
                     })
                     .consume_value(|value| parsed.set_offset_hour(value))
             })
-            .ok_or(InvalidComponent("offset hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or_else(|| invalid_component("offset hour", original, input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
         let input = exactly_n_digits::<2, u8>(input)
             .and_then(|item| {
                 item.map(|offset_minute| {
@@ -606,28 +1055,37 @@ impl sealed::Sealed for Rfc3339 {
                 })
                 .consume_value(|value| parsed.set_offset_minute_signed(value))
             })
-            .ok_or(InvalidComponent("offset minute"))?;
+            .ok_or_else(|| invalid_component("offset minute", original, input))?;
 
         Ok(input)
     }
 
+    fn parse_utc_date_time(&self, input: &[u8]) -> Result<UtcDateTime, error::Parse> {
+        checked_to_utc(self.parse_offset_date_time(input)?)
+    }
+
     fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
-        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
         use crate::parsing::combinator::{
-            any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
+            ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
         };
+        use crate::parsing::{invalid_component, invalid_literal};
 
         let dash = ascii_char::<b'-'>;
         let colon = ascii_char::<b':'>;
-
-        let ParsedItem(input, year) =
-            exactly_n_digits::<4, u32>(input).ok_or(InvalidComponent("year"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
-        let ParsedItem(input, month) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("month"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
-        let ParsedItem(input, day) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("day"))?;
+        let original = input;
+
+        let ParsedItem(input, year) = exactly_n_digits::<4, u32>(input)
+            .ok_or_else(|| invalid_component("year", original, input))?;
+        let input = dash(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
+        let ParsedItem(input, month) = exactly_n_digits::<2, _>(input)
+            .ok_or_else(|| invalid_component("month", original, input))?;
+        let input = dash(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
+        let ParsedItem(input, day) = exactly_n_digits::<2, _>(input)
+            .ok_or_else(|| invalid_component("day", original, input))?;
 
         // RFC3339 allows any separator, not just `T`, not just `space`.
         // cf. Section 5.6: Internet Date/Time Format:
@@ -636,45 +1094,44 @@ impl sealed::Sealed for Rfc3339 {
         //   readability, to specify a full-date and full-time separated by
         //   (say) a space character.
         // Specifically, rusqlite uses space separators.
-        let input = input.get(1..).ok_or(InvalidComponent("separator"))?;
-
-        let ParsedItem(input, hour) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-        let ParsedItem(input, minute) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("minute"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-        let ParsedItem(input, mut second) =
-            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("second"))?;
-        let ParsedItem(input, mut nanosecond) =
-            if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
-                let ParsedItem(mut input, mut value) = any_digit(input)
-                    .ok_or(InvalidComponent("subsecond"))?
-                    .map(|v| (v - b'0').extend::<u32>() * 100_000_000);
-
-                let mut multiplier = 10_000_000;
-                while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
-                    value += (digit - b'0').extend::<u32>() * multiplier;
-                    input = new_input;
-                    multiplier /= 10;
-                }
-
-                ParsedItem(input, value)
+        let input = input
+            .get(1..)
+            .ok_or_else(|| invalid_component("separator", original, input))?;
+
+        let ParsedItem(input, hour) = exactly_n_digits::<2, _>(input)
+            .ok_or_else(|| invalid_component("hour", original, input))?;
+        let input = colon(input)
+            .ok_or_else(|| invalid_literal(original, input))?
+            .into_inner();
+        let ParsedItem(input, minute) = exactly_n_digits::<2, _>(input)
+            .ok_or_else(|| invalid_component("minute", original, input))?;
+        let ParsedItem(input, (mut second, mut nanosecond)) =
+            if self.allows_missing_seconds() && colon(input).is_none() {
+                ParsedItem(input, (0, 0))
             } else {
-                ParsedItem(input, 0)
+                let input = colon(input)
+                    .ok_or_else(|| invalid_literal(original, input))?
+                    .into_inner();
+                let ParsedItem(input, second) = exactly_n_digits::<2, _>(input)
+                    .ok_or_else(|| invalid_component("second", original, input))?;
+                parse_rfc3339_subsecond(input, original, usize::MAX)?
+                    .unwrap_or(ParsedItem(input, 0))
+                    .map(|nanosecond| (second, nanosecond))
             };
         let ParsedItem(input, offset) = {
             if let Some(ParsedItem(input, ())) = ascii_char_ignore_case::<b'Z'>(input) {
                 ParsedItem(input, UtcOffset::UTC)
             } else {
                 let ParsedItem(input, offset_sign) =
-                    sign(input).ok_or(InvalidComponent("offset hour"))?;
+                    sign(input).ok_or_else(|| invalid_component("offset hour", original, input))?;
                 let ParsedItem(input, offset_hour) = exactly_n_digits::<2, u8>(input)
                     .and_then(|parsed| parsed.filter(|&offset_hour| offset_hour <= 23))
-                    .ok_or(InvalidComponent("offset hour"))?;
-                let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-                let ParsedItem(input, offset_minute) =
-                    exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset minute"))?;
+                    .ok_or_else(|| invalid_component("offset hour", original, input))?;
+                let input = colon(input)
+                    .ok_or_else(|| invalid_literal(original, input))?
+                    .into_inner();
+                let ParsedItem(input, offset_minute) = exactly_n_digits::<2, u8>(input)
+                    .ok_or_else(|| invalid_component("offset minute", original, input))?;
                 UtcOffset::from_hms(
                     if offset_sign == b'-' {
                         -offset_hour.cast_signed()
@@ -704,7 +1161,9 @@ impl sealed::Sealed for Rfc3339 {
 
         if !input.is_empty() {
             return Err(error::Parse::ParseFromDescription(
-                error::ParseFromDescription::UnexpectedTrailingCharacters,
+                error::ParseFromDescription::UnexpectedTrailingCharacters {
+                    position: original.len() - input.len(),
+                },
             ));
         }
 
@@ -734,6 +1193,7 @@ impl sealed::Sealed for Rfc3339 {
                     maximum: 59,
                     value: 60,
                     conditional_message: Some("because leap seconds are not supported"),
+                    conditional_date: None,
                 },
             )));
         }
@@ -742,63 +1202,212 @@ impl sealed::Sealed for Rfc3339 {
     }
 }
 
-impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
+/// Parse the subsecond of an RFC 3339 timestamp, including the leading `.`, if present. At most
+/// `max_digits` digits are permitted.
+fn parse_rfc3339_subsecond<'a>(
+    input: &'a [u8],
+    original: &[u8],
+    max_digits: usize,
+) -> Result<Option<ParsedItem<'a, u32>>, error::ParseFromDescription> {
+    use crate::parsing::combinator::{any_digit, ascii_char};
+    use crate::parsing::invalid_component;
+
+    let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) else {
+        return Ok(None);
+    };
+
+    let start = input;
+    let ParsedItem(mut input, mut value) = any_digit(input)
+        .ok_or_else(|| invalid_component("subsecond", original, input))?
+        .map(|v| (v - b'0').extend::<u32>() * 100_000_000);
+
+    let mut multiplier = 10_000_000;
+    let mut digits = 1;
+    while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
+        value += (digit - b'0').extend::<u32>() * multiplier;
+        input = new_input;
+        multiplier /= 10;
+        digits += 1;
+    }
+
+    if digits > max_digits {
+        return Err(invalid_component("subsecond", original, start));
+    }
+    Ok(Some(ParsedItem(input, value)))
+}
+
+/// Convert a parsed `OffsetDateTime` to UTC, returning an error if the resulting year is out of
+/// range.
+fn checked_to_utc(value: OffsetDateTime) -> Result<UtcDateTime, error::Parse> {
+    value.checked_to_utc().ok_or_else(|| {
+        error::Parse::TryFromParsed(TryFromParsed::ComponentRange(error::ComponentRange {
+            name: "year",
+            minimum: MIN_YEAR.into(),
+            maximum: MAX_YEAR.into(),
+            value: if value.offset().is_positive() {
+                (MIN_YEAR - 1).into()
+            } else {
+                (MAX_YEAR + 1).into()
+            },
+            conditional_message: Some("when converted to UTC"),
+            conditional_date: None,
+        }))
+    })
+}
+
+impl sealed::Sealed for Rfc9557<'_> {
     fn parse_into<'a>(
         &self,
-        mut input: &'a [u8],
+        input: &'a [u8],
         parsed: &mut Parsed,
+        options: ParserOptions,
     ) -> Result<&'a [u8], error::Parse> {
-        use crate::parsing::combinator::rfc::iso8601::ExtendedKind;
-
-        let mut extended_kind = ExtendedKind::Unknown;
-        let mut date_is_present = false;
-        let mut time_is_present = false;
-        let mut offset_is_present = false;
-        let mut first_error = None;
-
-        parsed.leap_second_allowed = true;
+        parse_rfc9557(input, parsed, options).map(|(remaining, _)| remaining)
+    }
+}
 
-        match Self::parse_date(parsed, &mut extended_kind)(input) {
-            Ok(new_input) => {
-                input = new_input;
-                date_is_present = true;
-            }
-            Err(err) => {
-                first_error.get_or_insert(err);
-            }
+/// Parse an RFC 9557 timestamp into `parsed`, returning the remaining input and the locations of
+/// the annotations.
+pub(crate) fn parse_rfc9557<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+    options: ParserOptions,
+) -> Result<(&'a [u8], Annotations), error::Parse> {
+    use crate::format_description::well_known::rfc9557::{
+        is_suffix_key, is_suffix_values, is_time_zone_name, time_numoffset,
+    };
+    use crate::parsing::combinator::ascii_char;
+    use crate::parsing::invalid_component;
+
+    let original = input;
+    let mut input = sealed::Sealed::parse_into(&Rfc3339, input, parsed, options)?;
+    let mut annotations = Annotations::default();
+
+    let mut count = 0;
+    while let Some(ParsedItem(rest, ())) = ascii_char::<b'['>(input) {
+        if count == options.max_repetitions() {
+            return Err(invalid_component("annotation", original, input).into());
         }
-
-        match Self::parse_time(parsed, &mut extended_kind, date_is_present)(input) {
-            Ok(new_input) => {
-                input = new_input;
-                time_is_present = true;
-            }
-            Err(err) => {
-                first_error.get_or_insert(err);
+        let (is_critical, rest) = match rest {
+            [b'!', rest @ ..] => (true, rest),
+            _ => (false, rest),
+        };
+        let start = original.len() - rest.len();
+        let len = rest
+            .iter()
+            .position(|&byte| byte == b']')
+            .ok_or_else(|| invalid_component("annotation", original, input))?;
+        let annotation = &rest[..len];
+        let end = start + len;
+
+        if let Some(separator) = annotation.iter().position(|&byte| byte == b'=') {
+            let (key, value) = (&annotation[..separator], &annotation[separator + 1..]);
+            if !is_suffix_key(key) || !is_suffix_values(value) {
+                return Err(invalid_component("annotation", original, input).into());
             }
-        }
-
-        // If a date and offset are present, a time must be as well.
-        if !date_is_present || time_is_present {
-            match Self::parse_offset(parsed, &mut extended_kind)(input) {
-                Ok(new_input) => {
-                    input = new_input;
-                    offset_is_present = true;
+            if key == b"u-ca" {
+                if is_critical && value != b"gregory" && value != b"iso8601" {
+                    return Err(invalid_component("calendar", original, input).into());
+                }
+                if annotations.calendar.is_none() {
+                    annotations.calendar = Some((start + separator + 1, end));
                 }
-                Err(err) => {
-                    first_error.get_or_insert(err);
+            } else if is_critical {
+                return Err(invalid_component("annotation", original, input).into());
+            }
+        } else if count == 0 {
+            if let Some(minutes) = time_numoffset(annotation) {
+                let offset = parsed.offset_hour().unwrap_or(0).extend::<i16>() * 60
+                    + parsed.offset_minute_signed().unwrap_or(0).extend::<i16>();
+                if is_critical && minutes != offset {
+                    return Err(invalid_component("time zone", original, input).into());
                 }
+            } else if !is_time_zone_name(annotation) {
+                return Err(invalid_component("time zone", original, input).into());
             }
+            annotations.time_zone = Some((start, end));
+        } else {
+            return Err(invalid_component("annotation", original, input).into());
         }
 
-        if !date_is_present && !time_is_present && !offset_is_present {
-            match first_error {
-                Some(err) => return Err(err),
-                None => bug!("an error should be present if no components were parsed"),
-            }
+        count += 1;
+        input = &rest[len + 1..];
+    }
+
+    Ok((input, annotations))
+}
+
+impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        options: ParserOptions,
+    ) -> Result<&'a [u8], error::Parse> {
+        Self::CONFIG.parse_into(input, parsed, options)
+    }
+}
+
+impl sealed::Sealed for Iso8601Runtime {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        options: ParserOptions,
+    ) -> Result<&'a [u8], error::Parse> {
+        self.config().parse_into(input, parsed, options)
+    }
+}
+
+impl sealed::Sealed for CommonFormat {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        options: ParserOptions,
+    ) -> Result<&'a [u8], error::Parse> {
+        match self {
+            Self::Rfc3339 => Rfc3339.parse_into(input, parsed, options),
+            #[cfg(not(time_no_names))]
+            Self::Rfc2822 => Rfc2822.parse_into(input, parsed, options),
+            #[cfg(not(time_no_names))]
+            Self::HttpDate => HttpDate.parse_into(input, parsed, options),
+            Self::Iso8601 => Iso8601::DEFAULT.parse_into(input, parsed, options),
+            Self::IsoDate => Iso8601::DATE.parse_into(input, parsed, options),
+            Self::IsoTime => Iso8601::TIME.parse_into(input, parsed, options),
+            Self::IsoDateTime => Iso8601::DATE_TIME.parse_into(input, parsed, options),
+            Self::IsoOffset => Iso8601::OFFSET.parse_into(input, parsed, options),
         }
+    }
 
-        Ok(input)
+    fn parse_utc_date_time(&self, input: &[u8]) -> Result<UtcDateTime, error::Parse> {
+        match self {
+            Self::Rfc3339 => Rfc3339.parse_utc_date_time(input),
+            #[cfg(not(time_no_names))]
+            Self::Rfc2822 => Rfc2822.parse_utc_date_time(input),
+            #[cfg(not(time_no_names))]
+            Self::HttpDate => HttpDate.parse_utc_date_time(input),
+            Self::Iso8601 => Iso8601::DEFAULT.parse_utc_date_time(input),
+            Self::IsoDate => Iso8601::DATE.parse_utc_date_time(input),
+            Self::IsoTime => Iso8601::TIME.parse_utc_date_time(input),
+            Self::IsoDateTime => Iso8601::DATE_TIME.parse_utc_date_time(input),
+            Self::IsoOffset => Iso8601::OFFSET.parse_utc_date_time(input),
+        }
+    }
+
+    fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
+        match self {
+            Self::Rfc3339 => Rfc3339.parse_offset_date_time(input),
+            #[cfg(not(time_no_names))]
+            Self::Rfc2822 => Rfc2822.parse_offset_date_time(input),
+            #[cfg(not(time_no_names))]
+            Self::HttpDate => HttpDate.parse_offset_date_time(input),
+            Self::Iso8601 => Iso8601::DEFAULT.parse_offset_date_time(input),
+            Self::IsoDate => Iso8601::DATE.parse_offset_date_time(input),
+            Self::IsoTime => Iso8601::TIME.parse_offset_date_time(input),
+            Self::IsoDateTime => Iso8601::DATE_TIME.parse_offset_date_time(input),
+            Self::IsoOffset => Iso8601::OFFSET.parse_offset_date_time(input),
+        }
     }
 }
 // endregion well-known formats
@@ -19,9 +19,10 @@ use crate::internal_macros::{bug, const_try_opt};
 use crate::parsing::component::{
     parse_day, parse_end, parse_hour, parse_ignore, parse_minute, parse_month, parse_offset_hour,
     parse_offset_minute, parse_offset_second, parse_ordinal, parse_period, parse_second,
-    parse_subsecond, parse_unix_timestamp, parse_week_number, parse_weekday, parse_year, Period,
+    parse_subsecond, parse_unix_timestamp, parse_week_number, parse_weekday, parse_year,
+    year_requires_large_dates, Period,
 };
-use crate::parsing::ParsedItem;
+use crate::parsing::{Parsable, ParsedItem, ParserOptions};
 use crate::{
     error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset, Weekday,
 };
@@ -37,6 +38,7 @@ mod sealed {
             &self,
             parsed: &mut Parsed,
             input: &'a [u8],
+            options: ParserOptions,
         ) -> Result<&'a [u8], error::ParseFromDescription>;
     }
 }
@@ -46,17 +48,22 @@ impl sealed::AnyFormatItem for BorrowedFormatItem<'_> {
         &self,
         parsed: &mut Parsed,
         input: &'a [u8],
+        options: ParserOptions,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
         match self {
-            Self::Literal(literal) => Parsed::parse_literal(input, literal),
-            Self::Component(component) => parsed.parse_component(input, *component),
-            Self::Compound(compound) => parsed.parse_items(input, compound),
-            Self::Optional(item) => parsed.parse_item(input, *item).or(Ok(input)),
+            Self::Literal(literal) => Parsed::parse_configured_literal(input, literal, options),
+            Self::Component(component) => {
+                parsed.parse_component_with_options(input, *component, options)
+            }
+            Self::Compound(compound) => parsed.parse_items_with_options(input, compound, options),
+            Self::Optional(item) => parsed
+                .parse_item_with_options(input, *item, options)
+                .or(Ok(input)),
             Self::First(items) => {
                 let mut first_err = None;
 
                 for item in items.iter() {
-                    match parsed.parse_item(input, item) {
+                    match parsed.parse_item_with_options(input, item, options) {
                         Ok(remaining_input) => return Ok(remaining_input),
                         Err(err) if first_err.is_none() => first_err = Some(err),
                         Err(_) => {}
@@ -80,17 +87,22 @@ impl sealed::AnyFormatItem for OwnedFormatItem {
         &self,
         parsed: &mut Parsed,
         input: &'a [u8],
+        options: ParserOptions,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
         match self {
-            Self::Literal(literal) => Parsed::parse_literal(input, literal),
-            Self::Component(component) => parsed.parse_component(input, *component),
-            Self::Compound(compound) => parsed.parse_items(input, compound),
-            Self::Optional(item) => parsed.parse_item(input, item.as_ref()).or(Ok(input)),
+            Self::Literal(literal) => Parsed::parse_configured_literal(input, literal, options),
+            Self::Component(component) => {
+                parsed.parse_component_with_options(input, *component, options)
+            }
+            Self::Compound(compound) => parsed.parse_items_with_options(input, compound, options),
+            Self::Optional(item) => parsed
+                .parse_item_with_options(input, item.as_ref(), options)
+                .or(Ok(input)),
             Self::First(items) => {
                 let mut first_err = None;
 
                 for item in items.iter() {
-                    match parsed.parse_item(input, item) {
+                    match parsed.parse_item_with_options(input, item, options) {
                         Ok(remaining_input) => return Ok(remaining_input),
                         Err(err) if first_err.is_none() => first_err = Some(err),
                         Err(_) => {}
@@ -143,14 +155,16 @@ pub struct Parsed {
     /// Day of the month.
     day: OptionRangedU8<1, 31>,
     /// Hour within the day.
-    hour_24: OptionRangedU8<0, { Hour::per(Day) - 1 }>,
+    // do not subtract one, as the hour may be carried into the day
+    hour_24: OptionRangedU8<0, { Hour::per(Day) }>,
     /// Hour within the 12-hour period (midnight to noon or vice versa). This is typically used in
     /// conjunction with AM/PM, which is indicated by the `hour_12_is_pm` field.
     hour_12: OptionRangedU8<1, 12>,
     /// Whether the `hour_12` field indicates a time that "PM".
     hour_12_is_pm: Option<bool>,
     /// Minute within the hour.
-    minute: OptionRangedU8<0, { Minute::per(Hour) - 1 }>,
+    // do not subtract one, as the minute may be carried into the hour
+    minute: OptionRangedU8<0, { Minute::per(Hour) }>,
     /// Second within the minute.
     // do not subtract one, as leap seconds may be allowed
     second: OptionRangedU8<0, { Second::per(Minute) }>,
@@ -178,6 +192,9 @@ pub struct Parsed {
     /// Indicates whether the [`UtcOffset`] is negative. This information is obtained when parsing
     /// the offset hour, but may not otherwise be stored due to "-0" being equivalent to "0".
     offset_is_negative: bool,
+    /// Indicates whether the Unix timestamp is negative. This information is obtained when parsing
+    /// the timestamp, but may not otherwise be stored due to "-0" being equivalent to "0".
+    unix_timestamp_is_negative: bool,
     /// Indicates whether the `year_century` component is negative. This information is obtained
     /// when parsing, but may not otherwise be stored due to "-0" being equivalent to "0".
     year_century_is_negative: bool,
@@ -187,6 +204,63 @@ pub struct Parsed {
     /// Indicates whether a leap second is permitted to be parsed. This is required by some
     /// well-known formats.
     pub(super) leap_second_allowed: bool,
+    /// Indicates whether a week number was parsed without requiring the weekday. If no weekday is
+    /// present, the Monday of the week is used.
+    weekday_is_optional: bool,
+}
+
+/// How a parsed weekday is handled when the date is determined by other components, such as when
+/// a format description contains both a weekday and a calendar date.
+///
+/// When the date is determined by a week number, the weekday is always used, as it is needed to
+/// identify the day within the week.
+///
+/// ```rust
+/// # use time_macros::{date, format_description};
+/// # use time::parsing::{ParserOptions, WeekdayMode};
+/// # use time::{error, Date, Weekday};
+/// let format = format_description!("[weekday repr:short], [year]-[month]-[day]");
+/// let input = "Fri, 2024-01-02";
+///
+/// assert_eq!(
+///     Date::parse_with_weekday_mode(input, &format, WeekdayMode::Ignore)?,
+///     date!(2024-01-02)
+/// );
+/// assert!(matches!(
+///     Date::parse_with_weekday_mode(input, &format, WeekdayMode::Validate),
+///     Err(error::Parse::TryFromParsed(
+///         error::TryFromParsed::WeekdayMismatch {
+///             parsed: Weekday::Friday,
+///             actual: Weekday::Tuesday,
+///             ..
+///         }
+///     ))
+/// ));
+/// assert_eq!(
+///     Date::parse_with_weekday_mode(input, &format, WeekdayMode::Prefer)?,
+///     date!(2024-01-05)
+/// );
+///
+/// let options = ParserOptions::DEFAULT.with_weekday_mode(WeekdayMode::Prefer);
+/// assert_eq!(
+///     Date::parse_with_options(input, &format, options)?,
+///     date!(2024-01-05)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WeekdayMode {
+    /// The weekday is ignored. This is the default.
+    #[default]
+    Ignore,
+    /// The weekday must match the date. If it does not,
+    /// [`TryFromParsed::WeekdayMismatch`](error::TryFromParsed::WeekdayMismatch) is returned.
+    Validate,
+    /// The weekday takes precedence over the other components. The date is moved to the nearest
+    /// day with the parsed weekday, which is at most three days before or after the date that was
+    /// otherwise determined.
+    Prefer,
 }
 
 impl Default for Parsed {
@@ -223,9 +297,11 @@ impl Parsed {
             offset_second: OptionRangedI8::None,
             unix_timestamp_nanos: OptionRangedI128::None,
             offset_is_negative: false,
+            unix_timestamp_is_negative: false,
             year_century_is_negative: false,
             iso_year_century_is_negative: false,
             leap_second_allowed: false,
+            weekday_is_optional: false,
         }
     }
 
@@ -239,7 +315,23 @@ impl Parsed {
         input: &'a [u8],
         item: &impl sealed::AnyFormatItem,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
-        item.parse_item(self, input)
+        self.parse_item_with_options(input, item, ParserOptions::DEFAULT)
+    }
+
+    /// Parse a single [`BorrowedFormatItem`] or [`OwnedFormatItem`] subject to the provided
+    /// [`ParserOptions`], mutating the struct. The remaining input is returned as the `Ok` value.
+    ///
+    /// Only the options that affect parsing itself are applied. The [carrying of
+    /// components](ParserOptions::with_carry_allowed) and the [weekday mode](WeekdayMode) take
+    /// effect when the value is constructed, which the `TryFrom<Parsed>` implementations do using
+    /// the default options; use the `parse_with_options` method of each type to apply them.
+    pub fn parse_item_with_options<'a>(
+        &mut self,
+        input: &'a [u8],
+        item: &impl sealed::AnyFormatItem,
+        options: ParserOptions,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        item.parse_item(self, input, options)
     }
 
     /// Parse a sequence of [`BorrowedFormatItem`]s or [`OwnedFormatItem`]s, mutating the struct.
@@ -249,17 +341,82 @@ impl Parsed {
     /// [`OwnedFormatItem`]s fail to parse. `self` will not be mutated in this instance.
     pub fn parse_items<'a>(
         &mut self,
-        mut input: &'a [u8],
+        input: &'a [u8],
         items: &[impl sealed::AnyFormatItem],
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        self.parse_items_with_options(input, items, ParserOptions::DEFAULT)
+    }
+
+    /// Parse a sequence of [`BorrowedFormatItem`]s or [`OwnedFormatItem`]s subject to the provided
+    /// [`ParserOptions`], mutating the struct. The remaining input is returned as the `Ok` value.
+    ///
+    /// This method will fail if any of the contained [`BorrowedFormatItem`]s or
+    /// [`OwnedFormatItem`]s fail to parse. `self` will not be mutated in this instance. See
+    /// [`Parsed::parse_item_with_options`] for which options are applied.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, format_description};
+    /// # use time::parsing::{Parsed, ParserOptions};
+    /// # use time::PrimitiveDateTime;
+    /// let format = format_description!("[year]-[month]-[day]T[hour]:[minute]Z");
+    /// let options = ParserOptions::DEFAULT.with_case_insensitive_literals(true);
+    /// let mut parsed = Parsed::new();
+    /// parsed.parse_items_with_options(b"2024-05-01t10:30z", format, options)?;
+    /// assert_eq!(
+    ///     PrimitiveDateTime::try_from(parsed)?,
+    ///     datetime!(2024-05-01 10:30)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_items_with_options<'a>(
+        &mut self,
+        input: &'a [u8],
+        items: &[impl sealed::AnyFormatItem],
+        options: ParserOptions,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
         // Make a copy that we can mutate. It will only be set to the user's copy if everything
         // succeeds.
         let mut this = *self;
+        let mut remaining = input;
         for item in items {
-            input = this.parse_item(input, item)?;
+            remaining = this
+                .parse_item_with_options(remaining, item, options)
+                .map_err(|err| err.offset_by(input.len() - remaining.len()))?;
         }
         *self = this;
-        Ok(input)
+        Ok(remaining)
+    }
+
+    /// Parse the input using any [`Parsable`] description, mutating the struct. The remaining
+    /// input is returned as the `Ok` value.
+    ///
+    /// Unlike the `parse` methods of the various types, this does not require the entire input to
+    /// be consumed. `self` will not be mutated if parsing fails.
+    pub fn parse<'a>(
+        &mut self,
+        input: &'a [u8],
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<&'a [u8], error::Parse> {
+        self.parse_with_options(input, description, ParserOptions::DEFAULT)
+    }
+
+    /// Parse the input using any [`Parsable`] description subject to the provided
+    /// [`ParserOptions`], mutating the struct. The remaining input is returned as the `Ok` value.
+    ///
+    /// Unlike the `parse_with_options` methods of the various types, this does not require the
+    /// entire input to be consumed, nor does it reject input longer than the [maximum
+    /// length](ParserOptions::with_max_input_len). `self` will not be mutated if parsing fails. See
+    /// [`Parsed::parse_item_with_options`] for which options are applied.
+    pub fn parse_with_options<'a>(
+        &mut self,
+        input: &'a [u8],
+        description: &(impl Parsable + ?Sized),
+        options: ParserOptions,
+    ) -> Result<&'a [u8], error::Parse> {
+        let mut this = *self;
+        let remaining = description.parse_into(input, &mut this, options)?;
+        *self = this;
+        Ok(remaining)
     }
 
     /// Parse a literal byte sequence. The remaining input is returned as the `Ok` value.
@@ -269,7 +426,42 @@ impl Parsed {
     ) -> Result<&'a [u8], error::ParseFromDescription> {
         input
             .strip_prefix(literal)
-            .ok_or(error::ParseFromDescription::InvalidLiteral)
+            .ok_or(error::ParseFromDescription::InvalidLiteral { position: 0 })
+    }
+
+    /// Parse a literal byte sequence, ignoring ASCII case. The remaining input is returned as the
+    /// `Ok` value.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// assert_eq!(Parsed::parse_literal_insensitive(b"tZ", b"T"), Ok(&b"Z"[..]));
+    /// assert!(Parsed::parse_literal_insensitive(b"x", b"T").is_err());
+    /// ```
+    pub fn parse_literal_insensitive<'a>(
+        input: &'a [u8],
+        literal: &[u8],
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        if input.len() >= literal.len() {
+            let (prefix, remaining) = input.split_at(literal.len());
+            if prefix.eq_ignore_ascii_case(literal) {
+                return Ok(remaining);
+            }
+        }
+        Err(error::ParseFromDescription::InvalidLiteral { position: 0 })
+    }
+
+    /// Parse a literal byte sequence from a format description, respecting whether [literals are
+    /// case-insensitive](ParserOptions::with_case_insensitive_literals).
+    fn parse_configured_literal<'a>(
+        input: &'a [u8],
+        literal: &[u8],
+        options: ParserOptions,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        if options.case_insensitive_literals() {
+            Self::parse_literal_insensitive(input, literal)
+        } else {
+            Self::parse_literal(input, literal)
+        }
     }
 
     /// Parse a single component, mutating the struct. The remaining input is returned as the `Ok`
@@ -279,24 +471,39 @@ impl Parsed {
         input: &'a [u8],
         component: Component,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
-        use error::ParseFromDescription::InvalidComponent;
+        self.parse_component_with_options(input, component, ParserOptions::DEFAULT)
+    }
+
+    /// Parse a single component subject to the provided [`ParserOptions`], mutating the struct.
+    /// The remaining input is returned as the `Ok` value. See [`Parsed::parse_item_with_options`]
+    /// for which options are applied.
+    pub fn parse_component_with_options<'a>(
+        &mut self,
+        input: &'a [u8],
+        component: Component,
+        options: ParserOptions,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        /// The component could not be parsed. Any error occurs at the start of the input.
+        const fn invalid(name: &'static str) -> error::ParseFromDescription {
+            error::ParseFromDescription::InvalidComponentAt { name, position: 0 }
+        }
 
         match component {
             Component::Day(modifiers) => parse_day(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_day(value)))
-                .ok_or(InvalidComponent("day")),
+                .ok_or(invalid("day")),
             Component::Month(modifiers) => parse_month(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_month(value)))
-                .ok_or(InvalidComponent("month")),
+                .ok_or(invalid("month")),
             Component::Ordinal(modifiers) => parse_ordinal(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_ordinal(value)))
-                .ok_or(InvalidComponent("ordinal")),
+                .ok_or(invalid("ordinal")),
             Component::Weekday(modifiers) => parse_weekday(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_weekday(value)))
-                .ok_or(InvalidComponent("weekday")),
+                .ok_or(invalid("weekday")),
             Component::WeekNumber(modifiers) => {
                 let ParsedItem(remaining, value) =
-                    parse_week_number(input, modifiers).ok_or(InvalidComponent("week number"))?;
+                    parse_week_number(input, modifiers).ok_or(invalid("week number"))?;
                 match modifiers.repr {
                     modifier::WeekNumberRepr::Iso => {
                         NonZeroU8::new(value).and_then(|value| self.set_iso_week_number(value))
@@ -304,12 +511,18 @@ impl Parsed {
                     modifier::WeekNumberRepr::Sunday => self.set_sunday_week_number(value),
                     modifier::WeekNumberRepr::Monday => self.set_monday_week_number(value),
                 }
-                .ok_or(InvalidComponent("week number"))?;
+                .ok_or(invalid("week number"))?;
+                if modifiers.weekday_is_optional {
+                    self.weekday_is_optional = true;
+                }
                 Ok(remaining)
             }
             Component::Year(modifiers) => {
+                if year_requires_large_dates(input, modifiers) {
+                    return Err(error::ParseFromDescription::LargeDatesNotEnabled { position: 0 });
+                }
                 let ParsedItem(remaining, (value, is_negative)) =
-                    parse_year(input, modifiers).ok_or(InvalidComponent("year"))?;
+                    parse_year(input, modifiers).ok_or(invalid("year"))?;
                 match (modifiers.iso_week_based, modifiers.repr) {
                     (false, modifier::YearRepr::Full) => self.set_year(value),
                     (false, modifier::YearRepr::Century) => {
@@ -326,34 +539,38 @@ impl Parsed {
                         self.set_iso_year_last_two(value.cast_unsigned().truncate())
                     }
                 }
-                .ok_or(InvalidComponent("year"))?;
+                .ok_or(invalid("year"))?;
                 Ok(remaining)
             }
             Component::Hour(modifiers) => {
                 let ParsedItem(remaining, value) =
-                    parse_hour(input, modifiers).ok_or(InvalidComponent("hour"))?;
+                    parse_hour(input, modifiers).ok_or(invalid("hour"))?;
                 if modifiers.is_12_hour_clock {
                     NonZeroU8::new(value).and_then(|value| self.set_hour_12(value))
                 } else {
-                    self.set_hour_24(value)
+                    self.set_hour_24_carrying(value, options)
                 }
-                .ok_or(InvalidComponent("hour"))?;
+                .ok_or(invalid("hour"))?;
                 Ok(remaining)
             }
             Component::Minute(modifiers) => parse_minute(input, modifiers)
-                .and_then(|parsed| parsed.consume_value(|value| self.set_minute(value)))
-                .ok_or(InvalidComponent("minute")),
+                .and_then(|parsed| {
+                    parsed.consume_value(|value| self.set_minute_carrying(value, options))
+                })
+                .ok_or(invalid("minute")),
             Component::Period(modifiers) => parse_period(input, modifiers)
                 .and_then(|parsed| {
                     parsed.consume_value(|value| self.set_hour_12_is_pm(value == Period::Pm))
                 })
-                .ok_or(InvalidComponent("period")),
+                .ok_or(invalid("period")),
             Component::Second(modifiers) => parse_second(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_second(value)))
-                .ok_or(InvalidComponent("second")),
-            Component::Subsecond(modifiers) => parse_subsecond(input, modifiers)
-                .and_then(|parsed| parsed.consume_value(|value| self.set_subsecond(value)))
-                .ok_or(InvalidComponent("subsecond")),
+                .ok_or(invalid("second")),
+            Component::Subsecond(modifiers) => {
+                parse_subsecond(input, modifiers, options.max_repetitions())
+                    .and_then(|parsed| parsed.consume_value(|value| self.set_subsecond(value)))
+                    .ok_or(invalid("subsecond"))
+            }
             Component::OffsetHour(modifiers) => parse_offset_hour(input, modifiers)
                 .and_then(|parsed| {
                     parsed.consume_value(|(value, is_negative)| {
@@ -362,28 +579,32 @@ impl Parsed {
                         Some(())
                     })
                 })
-                .ok_or(InvalidComponent("offset hour")),
+                .ok_or(invalid("offset hour")),
             Component::OffsetMinute(modifiers) => parse_offset_minute(input, modifiers)
                 .and_then(|parsed| {
                     parsed.consume_value(|value| self.set_offset_minute_signed(value))
                 })
-                .ok_or(InvalidComponent("offset minute")),
+                .ok_or(invalid("offset minute")),
             Component::OffsetSecond(modifiers) => parse_offset_second(input, modifiers)
                 .and_then(|parsed| {
                     parsed.consume_value(|value| self.set_offset_second_signed(value))
                 })
-                .ok_or(InvalidComponent("offset second")),
+                .ok_or(invalid("offset second")),
             Component::Ignore(modifiers) => parse_ignore(input, modifiers)
                 .map(ParsedItem::<()>::into_inner)
-                .ok_or(InvalidComponent("ignore")),
+                .ok_or(invalid("ignore")),
             Component::UnixTimestamp(modifiers) => parse_unix_timestamp(input, modifiers)
                 .and_then(|parsed| {
-                    parsed.consume_value(|value| self.set_unix_timestamp_nanos(value))
+                    parsed.consume_value(|(value, is_negative)| {
+                        self.set_unix_timestamp_nanos(value)?;
+                        self.unix_timestamp_is_negative = is_negative;
+                        Some(())
+                    })
                 })
-                .ok_or(InvalidComponent("unix_timestamp")),
+                .ok_or(invalid("unix_timestamp")),
             Component::End(modifiers) => parse_end(input, modifiers)
                 .map(ParsedItem::<()>::into_inner)
-                .ok_or(error::ParseFromDescription::UnexpectedTrailingCharacters),
+                .ok_or(error::ParseFromDescription::UnexpectedTrailingCharacters { position: 0 }),
         }
     }
 }
@@ -759,7 +980,13 @@ impl Parsed {
     }
 
     /// Set the `hour_24` component and return `self`.
+    ///
+    /// A value of 24 is only accepted when parsing with [carrying
+    /// allowed](ParserOptions::with_carry_allowed).
     pub const fn with_hour_24(mut self, value: u8) -> Option<Self> {
+        if value == Hour::per(Day) {
+            return None;
+        }
         self.hour_24 = OptionRangedU8::Some(const_try_opt!(RangedU8::new(value)));
         Some(self)
     }
@@ -777,7 +1004,13 @@ impl Parsed {
     }
 
     /// Set the `minute` component and return `self`.
+    ///
+    /// A value of 60 is only accepted when parsing with [carrying
+    /// allowed](ParserOptions::with_carry_allowed).
     pub const fn with_minute(mut self, value: u8) -> Option<Self> {
+        if value == Minute::per(Hour) {
+            return None;
+        }
         self.minute = OptionRangedU8::Some(const_try_opt!(RangedU8::new(value)));
         Some(self)
     }
@@ -843,14 +1076,120 @@ impl Parsed {
     /// Set the `unix_timestamp_nanos` component and return `self`.
     pub const fn with_unix_timestamp_nanos(mut self, value: i128) -> Option<Self> {
         self.unix_timestamp_nanos = OptionRangedI128::Some(const_try_opt!(RangedI128::new(value)));
+        self.unix_timestamp_is_negative = value.is_negative();
         Some(self)
     }
 }
 
-impl TryFrom<Parsed> for Date {
-    type Error = error::TryFromParsed;
+/// Setters for components that may be carried into the next larger unit.
+impl Parsed {
+    /// Set the `hour_24` component, accepting a value of 24 if [carrying is
+    /// allowed](ParserOptions::with_carry_allowed).
+    pub(crate) fn set_hour_24_carrying(&mut self, value: u8, options: ParserOptions) -> Option<()> {
+        if value == Hour::per(Day) && options.carry_allowed() {
+            self.hour_24 = OptionRangedU8::Some(RangedU8::new(value)?);
+            Some(())
+        } else {
+            self.set_hour_24(value)
+        }
+    }
+
+    /// Set the `minute` component, accepting a value of 60 if [carrying is
+    /// allowed](ParserOptions::with_carry_allowed).
+    pub(crate) fn set_minute_carrying(&mut self, value: u8, options: ParserOptions) -> Option<()> {
+        if value == Minute::per(Hour) && options.carry_allowed() {
+            self.minute = OptionRangedU8::Some(RangedU8::new(value)?);
+            Some(())
+        } else {
+            self.set_minute(value)
+        }
+    }
+}
+
+/// Construct a [`Time`] from its components, carrying any out-of-range component into the next
+/// larger unit if permitted. The returned boolean indicates whether the hour was carried into the
+/// following day.
+fn time_with_carry(
+    carry_allowed: bool,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    subsecond: u32,
+) -> Result<(Time, bool), error::ComponentRange> {
+    if !carry_allowed {
+        return Ok((Time::from_hms_nano(hour, minute, second, subsecond)?, false));
+    }
+
+    let (second, carry) = if second >= Second::per(Minute) {
+        (second - Second::per(Minute), 1)
+    } else {
+        (second, 0)
+    };
+    let minute = minute + carry;
+    let (minute, carry) = if minute >= Minute::per(Hour) {
+        (minute - Minute::per(Hour), 1)
+    } else {
+        (minute, 0)
+    };
+    let hour = hour + carry;
+    let (hour, is_next_day) = if hour >= Hour::per(Day) {
+        (hour - Hour::per(Day), true)
+    } else {
+        (hour, false)
+    };
+
+    Ok((
+        Time::from_hms_nano(hour, minute, second, subsecond)?,
+        is_next_day,
+    ))
+}
+
+/// The error returned when a component carried into the day does not result in a valid value.
+const fn carried_hour_error(conditional_message: &'static str) -> error::TryFromParsed {
+    error::TryFromParsed::ComponentRange(error::ComponentRange {
+        name: "hour",
+        minimum: 0,
+        maximum: Hour::per(Day) as i64 - 1,
+        value: Hour::per(Day) as i64,
+        conditional_message: Some(conditional_message),
+        conditional_date: None,
+    })
+}
+
+/// Construct a value from a [`Parsed`] struct, applying the [`ParserOptions`] that take effect
+/// at that point.
+pub(crate) trait FromParsed: Sized {
+    /// Construct the value from the parsed components.
+    fn from_parsed(parsed: Parsed, options: ParserOptions) -> Result<Self, error::TryFromParsed>;
+}
+
+/// Implement `TryFrom<Parsed>` using the default [`ParserOptions`].
+macro_rules! impl_try_from_parsed {
+    ($($type:ty),* $(,)?) => {$(
+        impl TryFrom<Parsed> for $type {
+            type Error = error::TryFromParsed;
+
+            fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+                Self::from_parsed(parsed, ParserOptions::DEFAULT)
+            }
+        }
+    )*};
+}
 
-    fn try_from(mut parsed: Parsed) -> Result<Self, Self::Error> {
+impl_try_from_parsed![
+    Date,
+    Time,
+    UtcOffset,
+    PrimitiveDateTime,
+    UtcDateTime,
+    OffsetDateTime,
+];
+
+impl FromParsed for Date {
+    fn from_parsed(
+        mut parsed: Parsed,
+        options: ParserOptions,
+    ) -> Result<Self, error::TryFromParsed> {
         /// Match on the components that need to be present.
         macro_rules! match_ {
             (_ => $catch_all:expr $(,)?) => {
@@ -909,66 +1248,114 @@ impl TryFrom<Parsed> for Date {
             parsed.iso_year = OptionRangedI32::from(RangedI32::new(iso_year));
         }
 
-        match_! {
-            (year, ordinal) => Ok(Self::from_ordinal_date(year, ordinal.get())?),
-            (year, month, day) => Ok(Self::from_calendar_date(year, month, day.get())?),
-            (iso_year, iso_week_number, weekday) => Ok(Self::from_iso_week_date(
+        // Only a weekday that was actually parsed is reconciled with the date.
+        let parsed_weekday = parsed.weekday;
+
+        // A week without a weekday refers to its Monday if the weekday is optional.
+        if parsed.weekday_is_optional && parsed.weekday.is_none() {
+            parsed.weekday = Some(Weekday::Monday);
+        }
+
+        let date = match_! {
+            (year, ordinal) => Self::from_ordinal_date(year, ordinal.get())?,
+            (year, month, day) => Self::from_calendar_date(year, month, day.get())?,
+            (iso_year, iso_week_number, weekday) => Self::from_iso_week_date(
                 iso_year,
                 iso_week_number.get(),
                 weekday,
-            )?),
-            (year, sunday_week_number, weekday) => Ok(Self::from_ordinal_date(
+            )?,
+            (year, sunday_week_number, weekday) => Self::from_ordinal_date(
                 year,
                 (sunday_week_number.cast_signed().extend::<i16>() * 7
                     + weekday.number_days_from_sunday().cast_signed().extend::<i16>()
                     - adjustment(year)
                     + 1).cast_unsigned(),
-            )?),
-            (year, monday_week_number, weekday) => Ok(Self::from_ordinal_date(
+            )?,
+            (year, monday_week_number, weekday) => Self::from_ordinal_date(
                 year,
                 (monday_week_number.cast_signed().extend::<i16>() * 7
                     + weekday.number_days_from_monday().cast_signed().extend::<i16>()
                     - adjustment(year)
                     + 1).cast_unsigned(),
-            )?),
-            _ => Err(InsufficientInformation),
+            )?,
+            _ => return Err(InsufficientInformation),
+        };
+
+        match (options.weekday_mode(), parsed_weekday) {
+            (WeekdayMode::Ignore, _) | (_, None) => Ok(date),
+            (_, Some(weekday)) if weekday == date.weekday() => Ok(date),
+            (WeekdayMode::Validate, Some(weekday)) => Err(error::TryFromParsed::WeekdayMismatch {
+                parsed: weekday,
+                actual: date.weekday(),
+            }),
+            (WeekdayMode::Prefer, Some(weekday)) => {
+                let mut days = weekday.number_days_from_monday() as i8
+                    - date.weekday().number_days_from_monday() as i8;
+                if days > 3 {
+                    days -= 7;
+                } else if days < -3 {
+                    days += 7;
+                }
+                Ok(Self::from_julian_day(
+                    date.to_julian_day() + days.extend::<i32>(),
+                )?)
+            }
         }
     }
 }
 
-impl TryFrom<Parsed> for Time {
-    type Error = error::TryFromParsed;
-
-    fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
-        let hour = match (parsed.hour_24(), parsed.hour_12(), parsed.hour_12_is_pm()) {
-            (Some(hour), _, _) => hour,
-            (_, Some(hour), Some(false)) if hour.get() == 12 => 0,
-            (_, Some(hour), Some(true)) if hour.get() == 12 => 12,
-            (_, Some(hour), Some(false)) => hour.get(),
-            (_, Some(hour), Some(true)) => hour.get() + 12,
-            _ => return Err(InsufficientInformation),
-        };
-
-        if parsed.hour_24().is_none()
-            && parsed.hour_12().is_some()
-            && parsed.hour_12_is_pm().is_some()
-            && parsed.minute().is_none()
-            && parsed.second().is_none()
-            && parsed.subsecond().is_none()
-        {
-            return Ok(Self::from_hms_nano(hour, 0, 0, 0)?);
+impl FromParsed for Time {
+    fn from_parsed(parsed: Parsed, options: ParserOptions) -> Result<Self, error::TryFromParsed> {
+        match time_try_from_parsed(parsed, options)? {
+            (time, false) => Ok(time),
+            (_, true) => Err(carried_hour_error(
+                "because the carried value would be on the following day",
+            )),
         }
+    }
+}
 
-        // Reject combinations such as hour-second with minute omitted.
-        match (parsed.minute(), parsed.second(), parsed.subsecond()) {
-            (None, None, None) => Ok(Self::from_hms_nano(hour, 0, 0, 0)?),
-            (Some(minute), None, None) => Ok(Self::from_hms_nano(hour, minute, 0, 0)?),
-            (Some(minute), Some(second), None) => Ok(Self::from_hms_nano(hour, minute, second, 0)?),
-            (Some(minute), Some(second), Some(subsecond)) => {
-                Ok(Self::from_hms_nano(hour, minute, second, subsecond)?)
-            }
-            _ => Err(InsufficientInformation),
+/// Resolve the [`Time`] from the parsed components. The returned boolean indicates whether a
+/// component was carried into the following day.
+fn time_try_from_parsed(
+    parsed: Parsed,
+    options: ParserOptions,
+) -> Result<(Time, bool), error::TryFromParsed> {
+    let carry_allowed = options.carry_allowed();
+    let hour = match (parsed.hour_24(), parsed.hour_12(), parsed.hour_12_is_pm()) {
+        (Some(hour), _, _) => hour,
+        (_, Some(hour), Some(false)) if hour.get() == 12 => 0,
+        (_, Some(hour), Some(true)) if hour.get() == 12 => 12,
+        (_, Some(hour), Some(false)) => hour.get(),
+        (_, Some(hour), Some(true)) => hour.get() + 12,
+        _ => return Err(InsufficientInformation),
+    };
+
+    if parsed.hour_24().is_none()
+        && parsed.hour_12().is_some()
+        && parsed.hour_12_is_pm().is_some()
+        && parsed.minute().is_none()
+        && parsed.second().is_none()
+        && parsed.subsecond().is_none()
+    {
+        return Ok(time_with_carry(carry_allowed, hour, 0, 0, 0)?);
+    }
+
+    // Reject combinations such as hour-second with minute omitted.
+    match (parsed.minute(), parsed.second(), parsed.subsecond()) {
+        (None, None, None) => Ok(time_with_carry(carry_allowed, hour, 0, 0, 0)?),
+        (Some(minute), None, None) => Ok(time_with_carry(carry_allowed, hour, minute, 0, 0)?),
+        (Some(minute), Some(second), None) => {
+            Ok(time_with_carry(carry_allowed, hour, minute, second, 0)?)
         }
+        (Some(minute), Some(second), Some(subsecond)) => Ok(time_with_carry(
+            carry_allowed,
+            hour,
+            minute,
+            second,
+            subsecond,
+        )?),
+        _ => Err(InsufficientInformation),
     }
 }
 
@@ -1004,32 +1391,57 @@ fn utc_offset_try_from_parsed<const REQUIRED: bool>(
     })
 }
 
-impl TryFrom<Parsed> for UtcOffset {
-    type Error = error::TryFromParsed;
-
-    fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+impl FromParsed for UtcOffset {
+    fn from_parsed(parsed: Parsed, _: ParserOptions) -> Result<Self, error::TryFromParsed> {
         utc_offset_try_from_parsed::<true>(parsed)
     }
 }
 
-impl TryFrom<Parsed> for PrimitiveDateTime {
-    type Error = error::TryFromParsed;
-
-    fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
-        Ok(Self::new(parsed.try_into()?, parsed.try_into()?))
+impl FromParsed for PrimitiveDateTime {
+    fn from_parsed(parsed: Parsed, options: ParserOptions) -> Result<Self, error::TryFromParsed> {
+        let mut date = Date::from_parsed(parsed, options)?;
+        let (time, is_next_day) = time_try_from_parsed(parsed, options)?;
+        if is_next_day {
+            date = date.next_day().ok_or_else(|| {
+                carried_hour_error("because the carried value would be beyond the maximum date")
+            })?;
+        }
+        Ok(Self::new(date, time))
     }
 }
 
-impl TryFrom<Parsed> for UtcDateTime {
-    type Error = error::TryFromParsed;
-
-    fn try_from(mut parsed: Parsed) -> Result<Self, Self::Error> {
-        if let Some(timestamp) = parsed.unix_timestamp_nanos() {
-            let mut value = Self::from_unix_timestamp_nanos(timestamp)?;
-            if let Some(subsecond) = parsed.subsecond() {
-                value = value.replace_nanosecond(subsecond)?;
+/// Obtain the moment described by the Unix timestamp, if one was parsed. The subsecond component,
+/// if present, replaces the fractional part of the timestamp. For negative timestamps the
+/// subsecond is applied away from zero, such that `-1.5` is one and a half seconds before the
+/// epoch.
+fn unix_timestamp_try_from_parsed(
+    parsed: Parsed,
+) -> Result<Option<OffsetDateTime>, error::TryFromParsed> {
+    let Some(timestamp) = parsed.unix_timestamp_nanos() else {
+        return Ok(None);
+    };
+    let timestamp = match parsed.subsecond() {
+        Some(subsecond) => {
+            let nanos_per_second = i128::from(Nanosecond::per(Second));
+            let whole_seconds = timestamp / nanos_per_second * nanos_per_second;
+            if parsed.unix_timestamp_is_negative {
+                whole_seconds - i128::from(subsecond)
+            } else {
+                whole_seconds + i128::from(subsecond)
             }
-            return Ok(value);
+        }
+        None => timestamp,
+    };
+    Ok(Some(OffsetDateTime::from_unix_timestamp_nanos(timestamp)?))
+}
+
+impl FromParsed for UtcDateTime {
+    fn from_parsed(
+        mut parsed: Parsed,
+        options: ParserOptions,
+    ) -> Result<Self, error::TryFromParsed> {
+        if let Some(value) = unix_timestamp_try_from_parsed(parsed)? {
+            return Ok(value.to_utc());
         }
 
         // Some well-known formats explicitly allow leap seconds. We don't currently support them,
@@ -1047,12 +1459,9 @@ impl TryFrom<Parsed> for UtcDateTime {
             false
         };
 
-        let dt = OffsetDateTime::new_in_offset(
-            Date::try_from(parsed)?,
-            Time::try_from(parsed)?,
-            utc_offset_try_from_parsed::<false>(parsed)?,
-        )
-        .to_utc();
+        let dt = PrimitiveDateTime::from_parsed(parsed, options)?
+            .assume_offset(utc_offset_try_from_parsed::<false>(parsed)?)
+            .to_utc();
 
         if leap_second_input && !dt.is_valid_leap_second_stand_in() {
             return Err(error::TryFromParsed::ComponentRange(
@@ -1062,6 +1471,7 @@ impl TryFrom<Parsed> for UtcDateTime {
                     maximum: 59,
                     value: 60,
                     conditional_message: Some("because leap seconds are not supported"),
+                    conditional_date: None,
                 },
             ));
         }
@@ -1069,16 +1479,29 @@ impl TryFrom<Parsed> for UtcDateTime {
     }
 }
 
-impl TryFrom<Parsed> for OffsetDateTime {
-    type Error = error::TryFromParsed;
-
-    fn try_from(mut parsed: Parsed) -> Result<Self, Self::Error> {
-        if let Some(timestamp) = parsed.unix_timestamp_nanos() {
-            let mut value = Self::from_unix_timestamp_nanos(timestamp)?;
-            if let Some(subsecond) = parsed.subsecond() {
-                value = value.replace_nanosecond(subsecond)?;
-            }
-            return Ok(value);
+impl FromParsed for OffsetDateTime {
+    fn from_parsed(
+        mut parsed: Parsed,
+        options: ParserOptions,
+    ) -> Result<Self, error::TryFromParsed> {
+        // The timestamp determines the moment in time, while the offset (if any) only determines
+        // how it is represented.
+        if let Some(value) = unix_timestamp_try_from_parsed(parsed)? {
+            let offset = utc_offset_try_from_parsed::<false>(parsed)?;
+            return value.checked_to_offset(offset).ok_or_else(|| {
+                error::TryFromParsed::ComponentRange(error::ComponentRange {
+                    name: "year",
+                    minimum: MIN_YEAR.into(),
+                    maximum: MAX_YEAR.into(),
+                    value: if offset.is_negative() {
+                        (MIN_YEAR - 1).into()
+                    } else {
+                        (MAX_YEAR + 1).into()
+                    },
+                    conditional_message: Some("when converted to the parsed UTC offset"),
+                    conditional_date: None,
+                })
+            });
         }
 
         // Some well-known formats explicitly allow leap seconds. We don't currently support them,
@@ -1096,11 +1519,8 @@ impl TryFrom<Parsed> for OffsetDateTime {
             false
         };
 
-        let dt = Self::new_in_offset(
-            Date::try_from(parsed)?,
-            Time::try_from(parsed)?,
-            UtcOffset::try_from(parsed)?,
-        );
+        let dt = PrimitiveDateTime::from_parsed(parsed, options)?
+            .assume_offset(UtcOffset::from_parsed(parsed, options)?);
 
         if leap_second_input && !dt.is_valid_leap_second_stand_in() {
             return Err(error::TryFromParsed::ComponentRange(
@@ -1110,6 +1530,7 @@ impl TryFrom<Parsed> for OffsetDateTime {
                     maximum: 59,
                     value: 60,
                     conditional_message: Some("because leap seconds are not supported"),
+                    conditional_date: None,
                 },
             ));
         }
@@ -0,0 +1,47 @@
+//! A format that tries several formats in order.
+
+/// A format that tries each of several formats in order, using the first that succeeds.
+///
+/// The formats are provided as a tuple of up to eight values, each of which must implement
+/// [`Parsable`](crate::parsing::Parsable). When parsing a value such as an
+/// [`OffsetDateTime`](crate::OffsetDateTime), a format only succeeds if the entire input is parsed
+/// and the value can be constructed from the result, so later formats are tried even if an earlier
+/// one parses a prefix of the input. If every format fails, the error from the first format is
+/// returned.
+///
+/// When used with [`Parsed::parse_items`](crate::parsing::Parsed::parse_items) or another method
+/// that parses only part of the input, the first format that parses successfully is used, and any
+/// remaining input is returned.
+///
+#[cfg_attr(all(feature = "macros", not(time_no_names)), doc = "```rust")]
+#[cfg_attr(
+    not(all(feature = "macros", not(time_no_names))),
+    doc = "```rust,ignore"
+)]
+/// # use time::format_description::well_known::{Rfc2822, Rfc3339};
+/// # use time::macros::{datetime, format_description};
+/// # use time::parsing::TryInOrder;
+/// # use time::OffsetDateTime;
+/// let format = TryInOrder((
+///     Rfc3339,
+///     Rfc2822,
+///     format_description!("[unix_timestamp]"),
+/// ));
+///
+/// assert_eq!(
+///     OffsetDateTime::parse("2021-01-02T03:04:05Z", &format)?,
+///     datetime!(2021-01-02 03:04:05 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 +0000", &format)?,
+///     datetime!(2021-01-02 03:04:05 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("1609556645", &format)?,
+///     datetime!(2021-01-02 03:04:05 UTC)
+/// );
+/// assert!(OffsetDateTime::parse("2021-01-02", &format).is_err());
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TryInOrder<T>(pub T);
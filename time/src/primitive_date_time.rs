@@ -11,11 +11,13 @@ use std::io;
 use powerfmt::ext::FormatterExt as _;
 use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
 
+use crate::convert::*;
 #[cfg(feature = "formatting")]
 use crate::formatting::Formattable;
-use crate::internal_macros::{const_try, const_try_opt};
+use crate::internal_macros::{const_try, const_try_opt, expect_opt};
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{FromParsed, Parsable, ParserOptions};
+use crate::time_zone::{self, LocalResult, TimeZone};
 use crate::{
     error, util, Date, Duration, Month, OffsetDateTime, Time, UtcDateTime, UtcOffset, Weekday,
 };
@@ -106,6 +108,99 @@ impl PrimitiveDateTime {
         Self { date, time }
     }
 
+    /// Create a `PrimitiveDateTime` from the Julian day and the provided [`Time`].
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::{datetime, time};
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_julian_day(2_451_545, time!(12:00)),
+    ///     Ok(datetime!(2000-01-01 12:00)),
+    /// );
+    /// assert!(PrimitiveDateTime::from_julian_day(i32::MAX, time!(0:00)).is_err());
+    /// ```
+    #[doc(alias = "from_julian_day_with_time")]
+    pub const fn from_julian_day(
+        julian_day: i32,
+        time: Time,
+    ) -> Result<Self, error::ComponentRange> {
+        Ok(Self::new(
+            const_try!(Date::from_julian_day(julian_day)),
+            time,
+        ))
+    }
+
+    /// Create a `PrimitiveDateTime` from the fractional Julian date.
+    ///
+    /// Following the astronomical convention, each Julian date begins at noon. The Julian date
+    /// 2451545.0 is therefore noon on 2000-01-01, while 2451544.5 is midnight at the start of that
+    /// day. The result is rounded to the nearest nanosecond, though the precision of an `f64`
+    /// limits the accuracy to tens of microseconds for contemporary dates.
+    ///
+    /// An error is returned if the value is not finite or is outside the supported range.
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_julian_date(2_451_545.0),
+    ///     Ok(datetime!(2000-01-01 12:00)),
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_julian_date(2_451_544.75),
+    ///     Ok(datetime!(2000-01-01 6:00)),
+    /// );
+    /// assert!(PrimitiveDateTime::from_julian_date(f64::NAN).is_err());
+    /// ```
+    pub fn from_julian_date(julian_date: f64) -> Result<Self, error::ComponentRange> {
+        const MIN_JULIAN_DAY: i32 = Date::MIN.to_julian_day();
+        const MAX_JULIAN_DAY: i32 = Date::MAX.to_julian_day();
+
+        // Shift the value such that each day begins at midnight.
+        let days = julian_date + 0.5;
+        let out_of_range = || error::ComponentRange {
+            name: "julian_date",
+            minimum: MIN_JULIAN_DAY as _,
+            maximum: MAX_JULIAN_DAY as _,
+            value: days as _,
+            conditional_message: None,
+            conditional_date: None,
+        };
+
+        // This comparison is written such that `NaN` is rejected.
+        if !(days >= MIN_JULIAN_DAY as f64 && days < MAX_JULIAN_DAY as f64 + 1.) {
+            return Err(out_of_range());
+        }
+
+        // `f64::floor` and `f64::round` are not available without `std`. As the value is known to
+        // be in range, truncating and adjusting negative values is equivalent.
+        let mut julian_day = days as i32;
+        if julian_day as f64 > days {
+            julian_day -= 1;
+        }
+        let mut nanos = ((days - julian_day as f64) * Nanosecond::per(Day) as f64 + 0.5) as u64;
+        // Rounding may carry into the next day.
+        if nanos >= Nanosecond::per(Day) {
+            if julian_day == MAX_JULIAN_DAY {
+                return Err(out_of_range());
+            }
+            julian_day += 1;
+            nanos -= Nanosecond::per(Day);
+        }
+
+        // Safety: All values are in range.
+        let time = unsafe {
+            Time::__from_hms_nanos_unchecked(
+                (nanos / Nanosecond::per(Hour)) as _,
+                (nanos % Nanosecond::per(Hour) / Nanosecond::per(Minute)) as _,
+                (nanos % Nanosecond::per(Minute) / Nanosecond::per(Second) as u64) as _,
+                (nanos % Nanosecond::per(Second) as u64) as _,
+            )
+        };
+
+        Self::from_julian_day(julian_day, time)
+    }
+
     // region: component getters
     /// Get the [`Date`] component of the `PrimitiveDateTime`.
     ///
@@ -153,6 +248,19 @@ impl PrimitiveDateTime {
         self.date().month()
     }
 
+    /// Get the zero-based month of the date.
+    ///
+    /// The returned value will always be in the range `0..=11`.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2019-01-01 0:00).month0(), 0);
+    /// assert_eq!(datetime!(2019-12-31 0:00).month0(), 11);
+    /// ```
+    pub const fn month0(self) -> u8 {
+        self.date().month0()
+    }
+
     /// Get the day of the date.
     ///
     /// The returned value will always be in the range `1..=31`.
@@ -179,6 +287,30 @@ impl PrimitiveDateTime {
         self.date().ordinal()
     }
 
+    /// Get the zero-based day of the year.
+    ///
+    /// The returned value will always be in the range `0..=365` (`0..=364` for common years).
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2019-01-01 0:00).ordinal0(), 0);
+    /// assert_eq!(datetime!(2019-12-31 0:00).ordinal0(), 364);
+    /// ```
+    pub const fn ordinal0(self) -> u16 {
+        self.date().ordinal0()
+    }
+
+    /// Whether the year of the date is a leap year in the proleptic Gregorian calendar.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert!(datetime!(2020-06-15 0:00).is_leap_year());
+    /// assert!(!datetime!(2019-06-15 0:00).is_leap_year());
+    /// ```
+    pub const fn is_leap_year(self) -> bool {
+        self.date().is_leap_year()
+    }
+
     /// Get the ISO week number.
     ///
     /// The returned value will always be in the range `1..=53`.
@@ -316,6 +448,23 @@ impl PrimitiveDateTime {
     pub const fn to_julian_day(self) -> i32 {
         self.date().to_julian_day()
     }
+
+    /// Get the fractional Julian date, taking the time into account.
+    ///
+    /// Following the astronomical convention, each Julian date begins at noon. Due to the
+    /// precision of an `f64`, the result is accurate to tens of microseconds for contemporary
+    /// dates.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2000-01-01 12:00).to_julian_date(), 2_451_545.0);
+    /// assert_eq!(datetime!(2000-01-01 0:00).to_julian_date(), 2_451_544.5);
+    /// assert_eq!(datetime!(2000-01-01 18:00).to_julian_date(), 2_451_545.25);
+    /// ```
+    pub fn to_julian_date(self) -> f64 {
+        let since_midnight = (self.time() - Time::MIDNIGHT).as_seconds_f64();
+        self.to_julian_day() as f64 - 0.5 + since_midnight / Second::per(Day) as f64
+    }
     // endregion date getters
 
     // region: time getters
@@ -330,6 +479,17 @@ impl PrimitiveDateTime {
         self.time().as_hms()
     }
 
+    /// Get the clock hour and minute.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2020-01-01 0:00:00).as_hm(), (0, 0));
+    /// assert_eq!(datetime!(2020-01-01 23:59:59).as_hm(), (23, 59));
+    /// ```
+    pub const fn as_hm(self) -> (u8, u8) {
+        self.time().as_hm()
+    }
+
     /// Get the clock hour, minute, second, and millisecond.
     ///
     /// ```rust
@@ -510,6 +670,59 @@ impl PrimitiveDateTime {
     pub const fn as_utc(self) -> UtcDateTime {
         UtcDateTime::from_primitive(self)
     }
+
+    /// Assuming that the existing `PrimitiveDateTime` represents a local date-time in the provided
+    /// [`TimeZone`], return the instants it refers to.
+    ///
+    /// The local date-time may not occur at all, such as when clocks are turned forward, or it may
+    /// occur twice, such as when clocks are turned back. The [`LocalResult`] indicates which is the
+    /// case.
+    ///
+    /// ```rust
+    /// # use time::time_zone::LocalResult;
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 0:00).assume_timezone(&offset!(-1)),
+    ///     LocalResult::Unique(datetime!(2019-01-01 0:00 -1)),
+    /// );
+    /// ```
+    pub fn assume_timezone(self, time_zone: &(impl TimeZone + ?Sized)) -> LocalResult {
+        time_zone::resolve_local(self, time_zone)
+    }
+
+    /// Assuming that the existing `PrimitiveDateTime` represents a local date-time in the provided
+    /// [`TimeZone`], return the instant it refers to.
+    ///
+    /// Unlike [`assume_timezone`](Self::assume_timezone), this returns an error if the local
+    /// date-time does not refer to exactly one instant.
+    ///
+    /// ```rust
+    /// # use time::error::InvalidLocalTime;
+    /// # use time::time_zone::SimpleTz;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2021-07-01 12:00).assume_timezone_checked(&SimpleTz::US_EASTERN),
+    ///     Ok(datetime!(2021-07-01 12:00 -4)),
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2021-03-14 2:30).assume_timezone_checked(&SimpleTz::US_EASTERN),
+    ///     Err(InvalidLocalTime::Nonexistent),
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2021-11-07 1:30).assume_timezone_checked(&SimpleTz::US_EASTERN),
+    ///     Err(InvalidLocalTime::Ambiguous),
+    /// );
+    /// ```
+    pub fn assume_timezone_checked(
+        self,
+        time_zone: &(impl TimeZone + ?Sized),
+    ) -> Result<OffsetDateTime, error::InvalidLocalTime> {
+        match time_zone::resolve_local(self, time_zone) {
+            LocalResult::Unique(datetime) => Ok(datetime),
+            LocalResult::Ambiguous(..) => Err(error::InvalidLocalTime::Ambiguous),
+            LocalResult::Gap => Err(error::InvalidLocalTime::Nonexistent),
+        }
+    }
     // endregion attach offset
 
     // region: checked arithmetic
@@ -572,6 +785,60 @@ impl PrimitiveDateTime {
             time,
         })
     }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time_macros::datetime;
+    /// let datetime = Date::MAX.midnight();
+    /// assert_eq!(datetime.checked_add_std(1.std_days()), None);
+    ///
+    /// assert_eq!(
+    ///     datetime!(2019-11-25 15:30).checked_add_std(27.std_hours()),
+    ///     Some(datetime!(2019-11-26 18:30))
+    /// );
+    /// ```
+    pub const fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        let (is_next_day, time) = self.time.adjusting_add_std(duration);
+        let date = const_try_opt!(self.date.checked_add_std(duration));
+
+        Some(Self {
+            date: if is_next_day {
+                const_try_opt!(date.next_day())
+            } else {
+                date
+            },
+            time,
+        })
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time_macros::datetime;
+    /// let datetime = Date::MIN.midnight();
+    /// assert_eq!(datetime.checked_sub_std(1.std_days()), None);
+    ///
+    /// assert_eq!(
+    ///     datetime!(2019-11-25 15:30).checked_sub_std(27.std_hours()),
+    ///     Some(datetime!(2019-11-24 12:30))
+    /// );
+    /// ```
+    pub const fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        let (is_previous_day, time) = self.time.adjusting_sub_std(duration);
+        let date = const_try_opt!(self.date.checked_sub_std(duration));
+
+        Some(Self {
+            date: if is_previous_day {
+                const_try_opt!(date.previous_day())
+            } else {
+                date
+            },
+            time,
+        })
+    }
     // endregion: checked arithmetic
 
     // region: saturating arithmetic
@@ -635,6 +902,63 @@ impl PrimitiveDateTime {
         }
     }
     // endregion: saturating arithmetic
+
+    // region: rounding
+    /// Truncate the time to the previous multiple of the [`Duration`] since midnight, preserving
+    /// the date.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34:56.789).truncate_to(1.seconds()),
+    ///     datetime!(2019-01-01 12:34:56)
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34:56).truncate_to(15.minutes()),
+    ///     datetime!(2019-01-01 12:30)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `duration` is not positive.
+    #[must_use = "This method does not mutate the original `PrimitiveDateTime`."]
+    pub const fn truncate_to(self, duration: Duration) -> Self {
+        Self::new(self.date, self.time.truncate_to(duration))
+    }
+
+    /// Round the time to the nearest multiple of the [`Duration`] since midnight. Values exactly
+    /// halfway between two multiples are rounded up. Midnight of the following day is treated as
+    /// the multiple after the last one in the day.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:37:30).round_to(15.minutes()),
+    ///     datetime!(2019-01-01 12:45)
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2019-12-31 23:59:30).round_to(1.minutes()),
+    ///     datetime!(2020-01-01 0:00)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `duration` is not positive or if the resulting value is out of range.
+    #[must_use = "This method does not mutate the original `PrimitiveDateTime`."]
+    pub const fn round_to(self, duration: Duration) -> Self {
+        let (is_next_day, time) = self.time.adjusting_round(duration, true);
+        let date = if is_next_day {
+            expect_opt!(self.date.next_day(), "resulting value is out of range")
+        } else {
+            self.date
+        };
+        Self::new(date, time)
+    }
+    // endregion rounding
 }
 
 // region: replacement
@@ -873,7 +1197,10 @@ impl PrimitiveDateTime {
         output: &mut impl io::Write,
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(output, Some(self.date), Some(self.time), None)
+        let result = format.format_into_buffered(output, Some(self.date), Some(self.time), None);
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_format(false, result.as_ref().ok().copied());
+        result
     }
 
     /// Format the `PrimitiveDateTime` using the provided [format
@@ -890,7 +1217,36 @@ impl PrimitiveDateTime {
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(Some(self.date), Some(self.time), None)
+        let result = format.format(Some(self.date), Some(self.time), None);
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_format(true, result.as_ref().ok().map(String::len));
+        result
+    }
+
+    /// Determine the number of bytes the `PrimitiveDateTime` would be formatted as using the
+    /// provided [format description](crate::format_description).
+    ///
+    /// The length is exact, so it can be used to allocate a buffer of the correct size before
+    /// calling [`format_into`](Self::format_into).
+    ///
+    /// The length is determined by formatting the value and discarding the output, so calling this
+    /// costs as much as formatting.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::datetime;
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?;
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05).formatted_len(&format)?,
+    ///     "2020-01-02 03:04:05".len()
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn formatted_len(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.formatted_len(Some(self.date), Some(self.time), None)
     }
 }
 
@@ -913,8 +1269,41 @@ impl PrimitiveDateTime {
         input: &str,
         description: &(impl Parsable + ?Sized),
     ) -> Result<Self, error::Parse> {
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_parse(input.len());
         description.parse_primitive_date_time(input.as_bytes())
     }
+
+    /// Parse a `PrimitiveDateTime` from the input using the provided [format
+    /// description](crate::format_description), subject to the provided [`ParserOptions`]. With
+    /// limits set, this should be preferred over [`PrimitiveDateTime::parse`] when the input is
+    /// untrusted.
+    ///
+    /// ```rust
+    /// # use time::parsing::ParserOptions;
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::{datetime, format_description};
+    /// let format = format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    /// let options = ParserOptions::DEFAULT.with_max_input_len(19);
+    /// assert_eq!(
+    ///     PrimitiveDateTime::parse_with_options("2020-01-02 03:04:05", &format, options)?,
+    ///     datetime!(2020-01-02 03:04:05)
+    /// );
+    /// assert!(
+    ///     PrimitiveDateTime::parse_with_options("2020-01-02 03:04:05 ", &format, options).is_err()
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_options(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+        options: ParserOptions,
+    ) -> Result<Self, error::Parse> {
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_parse(input.len());
+        let parsed = description.parse_with_options(input.as_bytes(), options)?;
+        Ok(Self::from_parsed(parsed, options)?)
+    }
 }
 
 impl SmartDisplay for PrimitiveDateTime {
@@ -1065,4 +1454,95 @@ impl Sub for PrimitiveDateTime {
         (self.date - rhs.date) + (self.time - rhs.time)
     }
 }
+
+#[cfg(feature = "alloc")]
+impl TryFrom<(i32, u8, u8, u8, u8, u8, u32)> for PrimitiveDateTime {
+    type Error = error::ComponentRanges;
+
+    /// Create a `PrimitiveDateTime` from the year, month, day, hour, minute, second, and
+    /// nanosecond. Every component is validated, so the error describes all components that are
+    /// out of range rather than only the first.
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     PrimitiveDateTime::try_from((2021, 1, 2, 3, 4, 5, 6)),
+    ///     Ok(datetime!(2021-01-02 03:04:05.000_000_006))
+    /// );
+    /// let err = PrimitiveDateTime::try_from((2021, 2, 29, 3, 60, 5, 6)).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "day must be in the range 1..=28 for February 2021; minute must be in the range 0..=59"
+    /// );
+    /// ```
+    fn try_from(
+        (year, month, day, hour, minute, second, nanosecond): (i32, u8, u8, u8, u8, u8, u32),
+    ) -> Result<Self, Self::Error> {
+        let mut errors = alloc::vec::Vec::new();
+        let datetime = from_components(
+            (year, month, day, hour, minute, second, nanosecond),
+            &mut errors,
+        );
+        match (datetime, error::ComponentRanges::from_errors(errors)) {
+            (Some(datetime), None) => Ok(datetime),
+            (_, Some(err)) => Err(err),
+            (None, None) => unreachable!("an error is recorded for every invalid component"),
+        }
+    }
+}
+
+/// Create a `PrimitiveDateTime` from its components, recording an error for every component that
+/// is out of range rather than stopping at the first. `None` is returned if any error was
+/// recorded.
+#[cfg(feature = "alloc")]
+pub(crate) fn from_components(
+    (year, month, day, hour, minute, second, nanosecond): (i32, u8, u8, u8, u8, u8, u32),
+    errors: &mut alloc::vec::Vec<error::ComponentRange>,
+) -> Option<PrimitiveDateTime> {
+    let year_is_valid = Date::from_ordinal_date(year, 1)
+        .map_err(|err| errors.push(err))
+        .is_ok();
+    let month = Month::try_from(month).map_err(|err| errors.push(err)).ok();
+
+    let date = match month {
+        Some(month) if year_is_valid => Date::from_calendar_date(year, month, day)
+            .map_err(|err| errors.push(err))
+            .ok(),
+        _ => {
+            // The length of the month cannot be determined, so the day is checked against the
+            // longest the month could be.
+            let maximum = match month {
+                Some(Month::February) => 29,
+                Some(month) => month.length(year),
+                None => 31,
+            };
+            if day == 0 || day > maximum {
+                errors.push(error::ComponentRange {
+                    name: "day",
+                    minimum: 1,
+                    maximum: maximum.into(),
+                    value: day.into(),
+                    conditional_message: month.map(|_| "for the given month"),
+                    conditional_date: None,
+                });
+            }
+            None
+        }
+    };
+
+    let time = Time::from_hms_nano(hour, minute, second, nanosecond)
+        .map_err(|_| {
+            let results = [
+                Time::from_hms_nano(hour, 0, 0, 0),
+                Time::from_hms_nano(0, minute, 0, 0),
+                Time::from_hms_nano(0, 0, second, 0),
+                Time::from_hms_nano(0, 0, 0, nanosecond),
+            ];
+            errors.extend(results.into_iter().filter_map(Result::err));
+        })
+        .ok();
+
+    Some(PrimitiveDateTime::new(date?, time?))
+}
 // endregion trait impls
@@ -30,18 +30,29 @@
 //! }
 //! ```
 //!
-//! An implementation for `Instant` is intentionally omitted since its values are only meaningful in
-//! relation to a [`Duration`], and obtaining an `Instant` from a [`Duration`] is very simple
-//! anyway.
+//! Shrinking moves values toward a simple value of the same type, so that failing tests are reported
+//! with inputs that are easy to reason about. Dates and date-times shrink toward
+//! [`UNIX_EPOCH`](OffsetDateTime::UNIX_EPOCH), times toward [midnight](Time::MIDNIGHT), offsets
+//! toward [UTC](UtcOffset::UTC), and durations toward [zero](Duration::ZERO). As instants are
+//! opaque, they are generated relative to and shrink toward a fixed instant that is obtained the
+//! first time one is needed.
 
 use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use std::time::Instant as StdInstant;
 
 use quickcheck::{empty_shrinker, single_shrinker, Arbitrary, Gen};
 
+#[cfg(feature = "std")]
+#[allow(deprecated)]
+use crate::Instant;
 use crate::{
     Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset, Weekday,
 };
 
+/// The year of the Unix epoch, which dates shrink toward.
+const UNIX_EPOCH_YEAR: i32 = 1970;
+
 /// Obtain an arbitrary value between the minimum and maximum inclusive.
 macro_rules! arbitrary_between {
     ($type:ty; $gen:expr, $min:expr, $max:expr) => {{
@@ -63,10 +74,15 @@ impl Arbitrary for Date {
     }
 
     fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // Shrink the distance from the first day of the Unix epoch's year, rather than the values
+        // themselves, so that the date moves toward 1970-01-01.
+        let (year, ordinal) = self.to_ordinal_date();
         Box::new(
-            self.to_ordinal_date()
+            (year - UNIX_EPOCH_YEAR, ordinal - 1)
                 .shrink()
-                .flat_map(|(year, ordinal)| Self::from_ordinal_date(year, ordinal)),
+                .flat_map(|(year, ordinal)| {
+                    Self::from_ordinal_date(year + UNIX_EPOCH_YEAR, ordinal + 1)
+                }),
         )
     }
 }
@@ -171,6 +187,43 @@ impl Arbitrary for UtcDateTime {
     }
 }
 
+#[cfg(feature = "std")]
+#[allow(deprecated)]
+impl Arbitrary for Instant {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let anchor = Self(instant_anchor());
+        let offset = Duration::new(i32::arbitrary(g).into(), i32::arbitrary(g));
+        // The platform may not be able to represent the instant, such as one before the system was
+        // started. When this happens, the offset is applied in the other direction instead.
+        anchor
+            .checked_add(offset)
+            .or_else(|| anchor.checked_sub(offset))
+            .unwrap_or(anchor)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let anchor = Self(instant_anchor());
+        Box::new(
+            (*self - anchor)
+                .shrink()
+                .filter_map(move |offset| anchor.checked_add(offset)),
+        )
+    }
+}
+
+/// Obtain the instant that arbitrary [`Instant`]s are generated relative to and shrink toward. This
+/// is the same for the lifetime of the process.
+#[cfg(feature = "std")]
+fn instant_anchor() -> StdInstant {
+    use std::sync::{Mutex, PoisonError};
+
+    static ANCHOR: Mutex<Option<StdInstant>> = Mutex::new(None);
+    *ANCHOR
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_or_insert_with(StdInstant::now)
+}
+
 impl Arbitrary for Weekday {
     fn arbitrary(g: &mut Gen) -> Self {
         use Weekday::*;
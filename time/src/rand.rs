@@ -1,5 +1,18 @@
-//! Implementation of [`Distribution`] for various structs.
+//! Implementation of [`Distribution`] and [`SampleUniform`] for various structs.
+//!
+//! The types in this module are the [`SampleUniform::Sampler`] types of their corresponding types
+//! in the crate root. They are not intended to be used directly; instead, sample from a range of
+//! values using [`Rng::gen_range`] or [`Uniform`](rand::distributions::Uniform).
+//!
+//! ```rust
+//! # use rand::Rng;
+//! # use time::macros::date;
+//! # let mut rng = rand::rngs::mock::StepRng::new(0, 656_175_560);
+//! let date = rng.gen_range(date!(2020-01-01)..date!(2021-01-01));
+//! assert_eq!(date.year(), 2020);
+//! ```
 
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformInt, UniformSampler};
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
@@ -85,3 +98,219 @@ impl Distribution<Month> for Standard {
         }
     }
 }
+
+/// The [`UniformSampler`] for [`Date`].
+#[derive(Debug, Clone, Copy)]
+pub struct UniformDate {
+    /// The sampler for the Julian day.
+    julian_day: UniformInt<i32>,
+}
+
+impl SampleUniform for Date {
+    type Sampler = UniformDate;
+}
+
+impl UniformSampler for UniformDate {
+    type X = Date;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self {
+            julian_day: UniformInt::new(
+                low.borrow().to_julian_day(),
+                high.borrow().to_julian_day(),
+            ),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self {
+            julian_day: UniformInt::new_inclusive(
+                low.borrow().to_julian_day(),
+                high.borrow().to_julian_day(),
+            ),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Date::from_julian_day_unchecked(self.julian_day.sample(rng))
+    }
+}
+
+/// The [`UniformSampler`] for [`Duration`].
+#[derive(Debug, Clone, Copy)]
+pub struct UniformDuration {
+    /// The sampler for the total number of nanoseconds.
+    nanoseconds: UniformInt<i128>,
+}
+
+impl SampleUniform for Duration {
+    type Sampler = UniformDuration;
+}
+
+impl UniformSampler for UniformDuration {
+    type X = Duration;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self {
+            nanoseconds: UniformInt::new(
+                low.borrow().whole_nanoseconds(),
+                high.borrow().whole_nanoseconds(),
+            ),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self {
+            nanoseconds: UniformInt::new_inclusive(
+                low.borrow().whole_nanoseconds(),
+                high.borrow().whole_nanoseconds(),
+            ),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Duration::nanoseconds_i128(self.nanoseconds.sample(rng))
+    }
+}
+
+/// Generate a [`UniformSampler`] for a type whose values are sampled as a number of nanoseconds
+/// after the lower bound.
+macro_rules! sampler_after_low {
+    ($($type:ty => $sampler:ident;)*) => {$(
+        #[doc = concat!("The [`UniformSampler`] for [`", stringify!($type), "`].")]
+        #[derive(Debug, Clone, Copy)]
+        pub struct $sampler {
+            /// The lower bound of the range.
+            low: $type,
+            /// The sampler for the number of nanoseconds after the lower bound.
+            nanoseconds: UniformInt<i128>,
+        }
+
+        impl SampleUniform for $type {
+            type Sampler = $sampler;
+        }
+
+        impl UniformSampler for $sampler {
+            type X = $type;
+
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let low = *low.borrow();
+                Self {
+                    low,
+                    nanoseconds: UniformInt::new(0, (*high.borrow() - low).whole_nanoseconds()),
+                }
+            }
+
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let low = *low.borrow();
+                Self {
+                    low,
+                    nanoseconds: UniformInt::new_inclusive(
+                        0,
+                        (*high.borrow() - low).whole_nanoseconds(),
+                    ),
+                }
+            }
+
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                self.low + Duration::nanoseconds_i128(self.nanoseconds.sample(rng))
+            }
+        }
+    )*};
+}
+
+sampler_after_low! {
+    Time => UniformTime;
+    PrimitiveDateTime => UniformPrimitiveDateTime;
+}
+
+/// The [`UniformSampler`] for [`OffsetDateTime`].
+///
+/// Sampled values have the offset of the lower bound. If the value cannot be represented with
+/// that offset, the offset of the upper bound is used instead.
+#[derive(Debug, Clone, Copy)]
+pub struct UniformOffsetDateTime {
+    /// The lower bound of the range.
+    low: OffsetDateTime,
+    /// The upper bound of the range.
+    high: OffsetDateTime,
+    /// The number of nanoseconds between the lower and upper bounds.
+    span: i128,
+    /// The sampler for the number of nanoseconds after the lower bound.
+    nanoseconds: UniformInt<i128>,
+}
+
+impl SampleUniform for OffsetDateTime {
+    type Sampler = UniformOffsetDateTime;
+}
+
+impl UniformSampler for UniformOffsetDateTime {
+    type X = OffsetDateTime;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low.borrow(), *high.borrow());
+        let span = (high - low).whole_nanoseconds();
+        Self {
+            low,
+            high,
+            span,
+            nanoseconds: UniformInt::new(0, span),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low.borrow(), *high.borrow());
+        let span = (high - low).whole_nanoseconds();
+        Self {
+            low,
+            high,
+            span,
+            nanoseconds: UniformInt::new_inclusive(0, span),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        let nanoseconds = self.nanoseconds.sample(rng);
+        // A value that is too large to be represented in the offset of the lower bound is at most
+        // the upper bound, so it can always be represented in the offset of the upper bound.
+        match self
+            .low
+            .checked_add(Duration::nanoseconds_i128(nanoseconds))
+        {
+            Some(value) => value,
+            None => self.high - Duration::nanoseconds_i128(self.span - nanoseconds),
+        }
+    }
+}
@@ -0,0 +1,405 @@
+//! Archived representations for use with [rkyv](https://docs.rs/rkyv).
+//!
+//! The types in this module are the [`Archive::Archived`] types of their corresponding types in
+//! the crate root. Their layout is fixed and uses little-endian integers, so archives can be shared
+//! between platforms. The layout does not depend on whether the `large-dates` feature is enabled,
+//! but the range of dates accepted during validation does.
+//!
+//! Archived values are validated when accessed with
+//! [`rkyv::access`](https://docs.rs/rkyv/latest/rkyv/fn.access.html) or
+//! [`rkyv::from_bytes`](https://docs.rs/rkyv/latest/rkyv/fn.from_bytes.html), such that only values
+//! that could have been produced by archiving a valid value are accepted.
+//!
+//! ```rust
+//! # use time::macros::datetime;
+//! # use time::OffsetDateTime;
+//! let value = datetime!(2024-01-02 03:04:05.678 +9:30);
+//! let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&value)?;
+//! let archived = rkyv::access::<time::rkyv::ArchivedOffsetDateTime, rkyv::rancor::Error>(&bytes)?;
+//! let deserialized: OffsetDateTime = rkyv::deserialize::<_, rkyv::rancor::Error>(archived)?;
+//! assert_eq!(deserialized, value);
+//! # Ok::<_, rkyv::rancor::Error>(())
+//! ```
+
+use core::fmt;
+
+use num_conv::prelude::*;
+use rkyv::bytecheck::{CheckBytes, Verify};
+use rkyv::munge::munge;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::rend::{i32_le, i64_le, u32_le};
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+use crate::{
+    error, Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset,
+};
+
+/// An archived value that does not correspond to any valid value.
+#[derive(Debug)]
+struct InvalidArchive(error::ComponentRange);
+
+impl fmt::Display for InvalidArchive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid archived value: {}", self.0)
+    }
+}
+
+impl core::error::Error for InvalidArchive {}
+
+/// Convert an error from a component being out of range into the error type of a validator or
+/// deserializer.
+fn invalid<E: Source>(err: error::ComponentRange) -> E {
+    E::new(InvalidArchive(err))
+}
+
+/// An archived [`Date`], stored as its Julian day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedDate {
+    julian_day: i32_le,
+}
+
+impl ArchivedDate {
+    /// Obtain the [`Date`] represented by the archived value.
+    const fn get(&self) -> Result<Date, error::ComponentRange> {
+        Date::from_julian_day(self.julian_day.to_native())
+    }
+}
+
+// Safety: `verify` only returns `Ok` if the archived value represents a valid `Date`.
+unsafe impl<C: Fallible + ?Sized> Verify<C> for ArchivedDate
+where
+    C::Error: Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        self.get().map(|_| ()).map_err(invalid)
+    }
+}
+
+impl Archive for Date {
+    type Archived = ArchivedDate;
+    type Resolver = ();
+
+    fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedDate { julian_day } = out);
+        julian_day.write(i32_le::from_native(self.to_julian_day()));
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Date {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Date, D> for ArchivedDate
+where
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<Date, D::Error> {
+        self.get().map_err(invalid)
+    }
+}
+
+/// An archived [`Time`], stored as its individual components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedTime {
+    nanosecond: u32_le,
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl ArchivedTime {
+    /// Obtain the [`Time`] represented by the archived value.
+    const fn get(&self) -> Result<Time, error::ComponentRange> {
+        Time::from_hms_nano(
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond.to_native(),
+        )
+    }
+}
+
+// Safety: `verify` only returns `Ok` if the archived value represents a valid `Time`.
+unsafe impl<C: Fallible + ?Sized> Verify<C> for ArchivedTime
+where
+    C::Error: Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        self.get().map(|_| ()).map_err(invalid)
+    }
+}
+
+impl Archive for Time {
+    type Archived = ArchivedTime;
+    type Resolver = ();
+
+    fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedTime { nanosecond, hour, minute, second } = out);
+        nanosecond.write(u32_le::from_native(self.nanosecond()));
+        hour.write(self.hour());
+        minute.write(self.minute());
+        second.write(self.second());
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Time {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Time, D> for ArchivedTime
+where
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<Time, D::Error> {
+        self.get().map_err(invalid)
+    }
+}
+
+/// An archived [`UtcOffset`], stored as its individual components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedUtcOffset {
+    hours: i8,
+    minutes: i8,
+    seconds: i8,
+}
+
+impl ArchivedUtcOffset {
+    /// Obtain the [`UtcOffset`] represented by the archived value.
+    const fn get(&self) -> Result<UtcOffset, error::ComponentRange> {
+        UtcOffset::from_hms(self.hours, self.minutes, self.seconds)
+    }
+}
+
+// Safety: `verify` only returns `Ok` if the archived value represents a valid `UtcOffset`.
+unsafe impl<C: Fallible + ?Sized> Verify<C> for ArchivedUtcOffset
+where
+    C::Error: Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        self.get().map(|_| ()).map_err(invalid)
+    }
+}
+
+impl Archive for UtcOffset {
+    type Archived = ArchivedUtcOffset;
+    type Resolver = ();
+
+    fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+        let (hours_value, minutes_value, seconds_value) = self.as_hms();
+        munge!(let ArchivedUtcOffset { hours, minutes, seconds } = out);
+        hours.write(hours_value);
+        minutes.write(minutes_value);
+        seconds.write(seconds_value);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for UtcOffset {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<UtcOffset, D> for ArchivedUtcOffset
+where
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<UtcOffset, D::Error> {
+        self.get().map_err(invalid)
+    }
+}
+
+/// An archived [`PrimitiveDateTime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(C)]
+pub struct ArchivedPrimitiveDateTime {
+    date: ArchivedDate,
+    time: ArchivedTime,
+}
+
+impl ArchivedPrimitiveDateTime {
+    /// Obtain the [`PrimitiveDateTime`] represented by the archived value.
+    fn get(&self) -> Result<PrimitiveDateTime, error::ComponentRange> {
+        Ok(PrimitiveDateTime::new(self.date.get()?, self.time.get()?))
+    }
+}
+
+impl Archive for PrimitiveDateTime {
+    type Archived = ArchivedPrimitiveDateTime;
+    type Resolver = ();
+
+    fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedPrimitiveDateTime { date, time } = out);
+        self.date().resolve((), date);
+        self.time().resolve((), time);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for PrimitiveDateTime {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<PrimitiveDateTime, D> for ArchivedPrimitiveDateTime
+where
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<PrimitiveDateTime, D::Error> {
+        self.get().map_err(invalid)
+    }
+}
+
+/// An archived [`OffsetDateTime`], stored as the local date and time alongside the offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(C)]
+pub struct ArchivedOffsetDateTime {
+    local_date_time: ArchivedPrimitiveDateTime,
+    offset: ArchivedUtcOffset,
+}
+
+impl Archive for OffsetDateTime {
+    type Archived = ArchivedOffsetDateTime;
+    type Resolver = ();
+
+    fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedOffsetDateTime { local_date_time, offset } = out);
+        PrimitiveDateTime::new(self.date(), self.time()).resolve((), local_date_time);
+        self.offset().resolve((), offset);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for OffsetDateTime {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<OffsetDateTime, D> for ArchivedOffsetDateTime
+where
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<OffsetDateTime, D::Error> {
+        let local_date_time = self.local_date_time.get().map_err(invalid)?;
+        let offset = self.offset.get().map_err(invalid)?;
+        Ok(local_date_time.assume_offset(offset))
+    }
+}
+
+/// An archived [`UtcDateTime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(C)]
+pub struct ArchivedUtcDateTime {
+    date_time: ArchivedPrimitiveDateTime,
+}
+
+impl Archive for UtcDateTime {
+    type Archived = ArchivedUtcDateTime;
+    type Resolver = ();
+
+    fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedUtcDateTime { date_time } = out);
+        PrimitiveDateTime::new(self.date(), self.time()).resolve((), date_time);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for UtcDateTime {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<UtcDateTime, D> for ArchivedUtcDateTime
+where
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<UtcDateTime, D::Error> {
+        Ok(self.date_time.get().map_err(invalid)?.as_utc())
+    }
+}
+
+/// An archived [`Duration`], stored as whole seconds and the remaining nanoseconds. Both
+/// components have the same sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedDuration {
+    seconds: i64_le,
+    nanoseconds: i32_le,
+}
+
+impl ArchivedDuration {
+    /// Obtain the [`Duration`] represented by the archived value.
+    fn get(&self) -> Result<Duration, error::ComponentRange> {
+        let seconds = self.seconds.to_native();
+        let nanoseconds = self.nanoseconds.to_native();
+
+        let max = 999_999_999;
+        let (minimum, maximum, conditional_message) = match seconds.signum() {
+            1 => (0, max, Some("when `seconds` is positive")),
+            -1 => (-max, 0, Some("when `seconds` is negative")),
+            _ => (-max, max, None),
+        };
+
+        if nanoseconds < minimum || nanoseconds > maximum {
+            return Err(error::ComponentRange {
+                name: "nanoseconds",
+                minimum: minimum.extend(),
+                maximum: maximum.extend(),
+                value: nanoseconds.extend(),
+                conditional_message,
+                conditional_date: None,
+            });
+        }
+
+        Ok(Duration::new(seconds, nanoseconds))
+    }
+}
+
+// Safety: `verify` only returns `Ok` if the archived value represents a valid `Duration`.
+unsafe impl<C: Fallible + ?Sized> Verify<C> for ArchivedDuration
+where
+    C::Error: Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        self.get().map(|_| ()).map_err(invalid)
+    }
+}
+
+impl Archive for Duration {
+    type Archived = ArchivedDuration;
+    type Resolver = ();
+
+    fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedDuration { seconds, nanoseconds } = out);
+        seconds.write(i64_le::from_native(self.whole_seconds()));
+        nanoseconds.write(i32_le::from_native(self.subsec_nanoseconds()));
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Duration {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Duration, D> for ArchivedDuration
+where
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<Duration, D::Error> {
+        self.get().map_err(invalid)
+    }
+}
@@ -0,0 +1,68 @@
+//! The [`Rounding`] enum and its associated `impl`s.
+
+/// How to round a value that lies between two representable values, such as when multiplying a
+/// [`Duration`](crate::Duration) by a floating point number.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceiling,
+    /// Round towards zero, discarding any fractional part.
+    TowardZero,
+    /// Round away from zero.
+    AwayFromZero,
+    /// Round to the nearest value. Values exactly halfway between two values are rounded away
+    /// from zero.
+    HalfAwayFromZero,
+    /// Round to the nearest value. Values exactly halfway between two values are rounded to the
+    /// even one. This is also known as banker's rounding.
+    HalfEven,
+}
+
+impl Rounding {
+    /// Round the value to an integer, saturating at the bounds of `i128`. The value must not be
+    /// NaN.
+    pub(crate) fn round(self, value: f64) -> i128 {
+        let truncated = value as i128;
+        // Any value with a magnitude of at least 2^52 is an integer, so this is exact.
+        let fraction = value - truncated as f64;
+        let magnitude = if fraction < 0. { -fraction } else { fraction };
+
+        let away_from_zero = match self {
+            Self::Floor => fraction < 0.,
+            Self::Ceiling => fraction > 0.,
+            Self::TowardZero => false,
+            Self::AwayFromZero => magnitude > 0.,
+            Self::HalfAwayFromZero => magnitude >= 0.5,
+            Self::HalfEven => magnitude > 0.5 || (magnitude >= 0.5 && truncated % 2 != 0),
+        };
+
+        match (away_from_zero, fraction < 0.) {
+            (false, _) => truncated,
+            (true, false) => truncated.saturating_add(1),
+            (true, true) => truncated.saturating_sub(1),
+        }
+    }
+
+    /// Whether a nonnegative integer should be rounded up to the next multiple of `divisor`, given
+    /// its `remainder` when divided by `divisor`. `is_odd` is whether the value rounded down is an
+    /// odd multiple of `divisor`.
+    pub(crate) const fn rounds_up(self, remainder: u32, divisor: u32, is_odd: bool) -> bool {
+        if remainder == 0 {
+            return false;
+        }
+
+        // Written to avoid overflow when doubling the remainder.
+        let above_half = remainder > divisor - remainder;
+        let is_half = remainder == divisor - remainder;
+
+        match self {
+            Self::Floor | Self::TowardZero => false,
+            Self::Ceiling | Self::AwayFromZero => true,
+            Self::HalfAwayFromZero => above_half || is_half,
+            Self::HalfEven => above_half || (is_half && is_odd),
+        }
+    }
+}
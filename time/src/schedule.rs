@@ -0,0 +1,598 @@
+//! Recurring schedules, such as those described by cron expressions.
+//!
+//! A [`Schedule`] matches a set of minutes, hours, days of the month, months, and days of the
+//! week in a fixed [`UtcOffset`]. It can be parsed from a cron expression or built up one
+//! component at a time, and yields the matching [`OffsetDateTime`]s after a given instant.
+//!
+//! ```rust
+//! # use time::schedule::Schedule;
+//! # use time::Weekday;
+//! # use time_macros::{datetime, offset, time};
+//! // Every Monday at 09:00 in UTC+2.
+//! let schedule = Schedule::new()
+//!     .weekdays(&[Weekday::Monday])
+//!     .at(time!(9:00))
+//!     .offset(offset!(+2));
+//! assert_eq!(
+//!     schedule.after(datetime!(2024-05-01 0:00 UTC)).take(2).collect::<Vec<_>>(),
+//!     [datetime!(2024-05-06 9:00 +2), datetime!(2024-05-13 9:00 +2)]
+//! );
+//!
+//! // The same, written as a cron expression. Cron expressions are interpreted in UTC.
+//! let schedule = "0 9 * * MON".parse::<Schedule>()?.offset(offset!(+2));
+//! assert_eq!(
+//!     schedule.next_after(datetime!(2024-05-01 0:00 UTC)),
+//!     Some(datetime!(2024-05-06 9:00 +2))
+//! );
+//! # Ok::<_, time::Error>(())
+//! ```
+
+use core::iter::FusedIterator;
+use core::str::FromStr;
+
+use num_conv::prelude::*;
+
+use crate::error::{self, InvalidSchedule};
+use crate::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+/// The number of years after which the Gregorian calendar repeats. If a schedule has no
+/// occurrence within this many years, it never will.
+const CALENDAR_CYCLE_YEARS: i32 = 400;
+
+/// A recurring schedule with a resolution of one minute.
+///
+/// A schedule matches every minute by default. Each component can be restricted to a set of
+/// values, either by parsing a cron expression or by calling the builder methods. As in cron, if
+/// both the days of the month and the days of the week are restricted, a day matches if it
+/// satisfies either restriction.
+///
+/// Cron expressions consist of five whitespace-separated fields: minute (0-59), hour (0-23), day
+/// of the month (1-31), month (1-12 or `JAN`-`DEC`), and day of the week (0-7 or `SUN`-`SAT`,
+/// where both 0 and 7 are Sunday). Each field is `*` or a comma-separated list of values and
+/// ranges such as `1-5`, any of which may be followed by a step such as `*/15` or `0-30/10`. The
+/// nicknames `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight`, and `@hourly`
+/// are also accepted. A parsed schedule is in UTC; use [`Schedule::offset`] to change this.
+///
+/// ```rust
+/// # use time::schedule::Schedule;
+/// # use time_macros::datetime;
+/// let schedule = "*/15 9-17 * * MON-FRI".parse::<Schedule>()?;
+/// assert_eq!(
+///     schedule.after(datetime!(2024-05-03 17:40 UTC)).take(3).collect::<Vec<_>>(),
+///     [
+///         datetime!(2024-05-03 17:45 UTC),
+///         datetime!(2024-05-06 9:00 UTC),
+///         datetime!(2024-05-06 9:15 UTC),
+///     ]
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Schedule {
+    /// The matching minutes, with bit `n` set if minute `n` matches.
+    minutes: u64,
+    /// The matching hours, with bit `n` set if hour `n` matches.
+    hours: u32,
+    /// The matching days of the month, with bit `n` set if day `n` matches.
+    days: u32,
+    /// The matching months, with bit `n` set if month `n` matches.
+    months: u16,
+    /// The matching days of the week, with bit `n` set if the day `n` days from Sunday matches.
+    weekdays: u8,
+    /// Whether the days of the month have been restricted.
+    days_restricted: bool,
+    /// Whether the days of the week have been restricted.
+    weekdays_restricted: bool,
+    /// The offset in which the schedule is evaluated.
+    offset: UtcOffset,
+}
+
+impl Schedule {
+    /// Create a schedule that matches every minute in UTC.
+    ///
+    /// ```rust
+    /// # use time::schedule::Schedule;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     Schedule::new().next_after(datetime!(2024-05-01 10:30:15 UTC)),
+    ///     Some(datetime!(2024-05-01 10:31 UTC))
+    /// );
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            minutes: (1 << 60) - 1,
+            hours: (1 << 24) - 1,
+            days: !1,
+            months: ((1 << 13) - 1) & !1,
+            weekdays: (1 << 7) - 1,
+            days_restricted: false,
+            weekdays_restricted: false,
+            offset: UtcOffset::UTC,
+        }
+    }
+
+    /// Restrict the schedule to the provided minutes.
+    ///
+    /// ```rust
+    /// # use time::schedule::Schedule;
+    /// # use time_macros::datetime;
+    /// let schedule = Schedule::new().minutes(&[0, 30])?;
+    /// assert_eq!(
+    ///     schedule.next_after(datetime!(2024-05-01 10:15 UTC)),
+    ///     Some(datetime!(2024-05-01 10:30 UTC))
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn minutes(self, minutes: &[u8]) -> Result<Self, error::ComponentRange> {
+        Ok(Self {
+            minutes: mask(minutes, "minute", 0, 59)?,
+            ..self
+        })
+    }
+
+    /// Restrict the schedule to the provided hours.
+    ///
+    /// ```rust
+    /// # use time::schedule::Schedule;
+    /// # use time_macros::datetime;
+    /// let schedule = Schedule::new().minutes(&[0])?.hours(&[6, 18])?;
+    /// assert_eq!(
+    ///     schedule.next_after(datetime!(2024-05-01 10:15 UTC)),
+    ///     Some(datetime!(2024-05-01 18:00 UTC))
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn hours(self, hours: &[u8]) -> Result<Self, error::ComponentRange> {
+        Ok(Self {
+            hours: mask(hours, "hour", 0, 23)?.truncate(),
+            ..self
+        })
+    }
+
+    /// Restrict the schedule to the minute and hour of the provided [`Time`]. The second and
+    /// subsecond are ignored.
+    ///
+    /// ```rust
+    /// # use time::schedule::Schedule;
+    /// # use time_macros::{datetime, time};
+    /// let schedule = Schedule::new().at(time!(9:30));
+    /// assert_eq!(
+    ///     schedule.next_after(datetime!(2024-05-01 10:15 UTC)),
+    ///     Some(datetime!(2024-05-02 9:30 UTC))
+    /// );
+    /// ```
+    pub const fn at(self, time: Time) -> Self {
+        Self {
+            minutes: 1 << time.minute(),
+            hours: 1 << time.hour(),
+            ..self
+        }
+    }
+
+    /// Restrict the schedule to the provided days of the month. Days that do not exist in a given
+    /// month, such as the 31st of April, are skipped.
+    ///
+    /// ```rust
+    /// # use time::schedule::Schedule;
+    /// # use time_macros::{datetime, time};
+    /// let schedule = Schedule::new().at(time!(0:00)).days(&[31])?;
+    /// assert_eq!(
+    ///     schedule.next_after(datetime!(2024-04-01 0:00 UTC)),
+    ///     Some(datetime!(2024-05-31 0:00 UTC))
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn days(self, days: &[u8]) -> Result<Self, error::ComponentRange> {
+        Ok(Self {
+            days: mask(days, "day", 1, 31)?.truncate(),
+            days_restricted: true,
+            ..self
+        })
+    }
+
+    /// Restrict the schedule to the provided months.
+    ///
+    /// ```rust
+    /// # use time::schedule::Schedule;
+    /// # use time::Month;
+    /// # use time_macros::{datetime, time};
+    /// let schedule = Schedule::new().at(time!(0:00)).days(&[1])?.months(&[Month::January]);
+    /// assert_eq!(
+    ///     schedule.next_after(datetime!(2024-04-01 0:00 UTC)),
+    ///     Some(datetime!(2025-01-01 0:00 UTC))
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn months(self, months: &[Month]) -> Self {
+        Self {
+            months: months
+                .iter()
+                .fold(0, |mask, &month| mask | 1 << u8::from(month)),
+            ..self
+        }
+    }
+
+    /// Restrict the schedule to the provided days of the week.
+    ///
+    /// ```rust
+    /// # use time::schedule::Schedule;
+    /// # use time::Weekday;
+    /// # use time_macros::{datetime, time};
+    /// let schedule = Schedule::new()
+    ///     .at(time!(12:00))
+    ///     .weekdays(&[Weekday::Saturday, Weekday::Sunday]);
+    /// assert_eq!(
+    ///     schedule.next_after(datetime!(2024-05-01 0:00 UTC)),
+    ///     Some(datetime!(2024-05-04 12:00 UTC))
+    /// );
+    /// ```
+    pub fn weekdays(self, weekdays: &[Weekday]) -> Self {
+        Self {
+            weekdays: weekdays.iter().fold(0, |mask, weekday| {
+                mask | 1 << weekday.number_days_from_sunday()
+            }),
+            weekdays_restricted: true,
+            ..self
+        }
+    }
+
+    /// Set the offset in which the schedule is evaluated. Occurrences are returned in this offset.
+    ///
+    /// ```rust
+    /// # use time::schedule::Schedule;
+    /// # use time_macros::{datetime, offset, time};
+    /// let schedule = Schedule::new().at(time!(9:00)).offset(offset!(-5));
+    /// assert_eq!(
+    ///     schedule.next_after(datetime!(2024-05-01 12:00 UTC)),
+    ///     Some(datetime!(2024-05-01 9:00 -5))
+    /// );
+    /// ```
+    pub const fn offset(self, offset: UtcOffset) -> Self {
+        Self { offset, ..self }
+    }
+
+    /// Whether the provided date matches the days of the month, months, and days of the week.
+    const fn matches_date(&self, date: Date) -> bool {
+        if self.months & (1 << date.month() as u8) == 0 {
+            return false;
+        }
+        let day_matches = self.days & (1 << date.day()) != 0;
+        let weekday_matches = self.weekdays & (1 << date.weekday().number_days_from_sunday()) != 0;
+        if self.days_restricted && self.weekdays_restricted {
+            day_matches || weekday_matches
+        } else {
+            day_matches && weekday_matches
+        }
+    }
+
+    /// Obtain the first occurrence of the schedule strictly after the provided instant, or `None`
+    /// if there is no such occurrence within the supported range of dates.
+    ///
+    /// ```rust
+    /// # use time::schedule::Schedule;
+    /// # use time_macros::{datetime, time};
+    /// let schedule = Schedule::new().at(time!(9:00));
+    /// assert_eq!(
+    ///     schedule.next_after(datetime!(2024-05-01 8:59:59 UTC)),
+    ///     Some(datetime!(2024-05-01 9:00 UTC))
+    /// );
+    /// assert_eq!(
+    ///     schedule.next_after(datetime!(2024-05-01 9:00 UTC)),
+    ///     Some(datetime!(2024-05-02 9:00 UTC))
+    /// );
+    /// ```
+    pub fn next_after(&self, datetime: OffsetDateTime) -> Option<OffsetDateTime> {
+        if self.minutes == 0 || self.hours == 0 || self.months == 0 {
+            return None;
+        }
+
+        let datetime = datetime
+            .checked_to_offset(self.offset)?
+            .truncate_to(Duration::MINUTE)
+            .checked_add(Duration::MINUTE)?;
+        let mut date = datetime.date();
+        let (mut hour, mut minute) = (datetime.hour(), datetime.minute());
+        let limit = date.year().saturating_add(CALENDAR_CYCLE_YEARS);
+
+        while date.year() <= limit {
+            let month = u8::from(date.month());
+            match next_bit(u64::from(self.months), month) {
+                Some(next) if next == month => {}
+                Some(next) => {
+                    let month = Month::try_from(next).ok()?;
+                    date = Date::from_calendar_date(date.year(), month, 1).ok()?;
+                    (hour, minute) = (0, 0);
+                    continue;
+                }
+                None => {
+                    date = Date::from_calendar_date(date.year() + 1, Month::January, 1).ok()?;
+                    (hour, minute) = (0, 0);
+                    continue;
+                }
+            }
+
+            if !self.matches_date(date) {
+                date = date.next_day()?;
+                (hour, minute) = (0, 0);
+                continue;
+            }
+
+            match next_bit(u64::from(self.hours), hour) {
+                Some(next) if next == hour => {}
+                Some(next) => (hour, minute) = (next, 0),
+                None => {
+                    date = date.next_day()?;
+                    (hour, minute) = (0, 0);
+                    continue;
+                }
+            }
+
+            match next_bit(self.minutes, minute) {
+                Some(minute) => {
+                    let time = Time::from_hms(hour, minute, 0).ok()?;
+                    return Some(PrimitiveDateTime::new(date, time).assume_offset(self.offset));
+                }
+                None if hour == 23 => {
+                    date = date.next_day()?;
+                    (hour, minute) = (0, 0);
+                }
+                None => (hour, minute) = (hour + 1, 0),
+            }
+        }
+
+        None
+    }
+
+    /// Obtain an iterator over the occurrences of the schedule strictly after the provided
+    /// instant.
+    ///
+    /// ```rust
+    /// # use time::schedule::Schedule;
+    /// # use time_macros::datetime;
+    /// let schedule = "30 */6 * * *".parse::<Schedule>()?;
+    /// assert_eq!(
+    ///     schedule.after(datetime!(2024-05-01 10:00 UTC)).take(3).collect::<Vec<_>>(),
+    ///     [
+    ///         datetime!(2024-05-01 12:30 UTC),
+    ///         datetime!(2024-05-01 18:30 UTC),
+    ///         datetime!(2024-05-02 0:30 UTC),
+    ///     ]
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn after(self, datetime: OffsetDateTime) -> Occurrences {
+        Occurrences {
+            schedule: self,
+            previous: Some(datetime),
+        }
+    }
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Obtain the lowest set bit of the mask at or above the provided index.
+fn next_bit(mask: u64, from: u8) -> Option<u8> {
+    let remaining = mask.checked_shr(u32::from(from))?;
+    if remaining == 0 {
+        None
+    } else {
+        u8::try_from(remaining.trailing_zeros())
+            .ok()
+            .map(|n| from + n)
+    }
+}
+
+/// Build a mask with bit `n` set for each of the provided values, ensuring that each is in range.
+fn mask(
+    values: &[u8],
+    name: &'static str,
+    minimum: u8,
+    maximum: u8,
+) -> Result<u64, error::ComponentRange> {
+    values.iter().try_fold(0, |mask, &value| {
+        if (minimum..=maximum).contains(&value) {
+            Ok(mask | 1 << value)
+        } else {
+            Err(error::ComponentRange {
+                name,
+                minimum: minimum.into(),
+                maximum: maximum.into(),
+                value: value.into(),
+                conditional_message: None,
+                conditional_date: None,
+            })
+        }
+    })
+}
+
+// region: parsing
+/// A field of a cron expression.
+struct Field {
+    /// The name of the field, used in errors.
+    name: &'static str,
+    /// The smallest permitted value.
+    minimum: u8,
+    /// The largest permitted value.
+    maximum: u8,
+    /// The names that may be used in place of numbers, starting with the minimum value.
+    names: &'static [&'static str],
+}
+
+/// The fields of a cron expression, in order.
+const FIELDS: [Field; 5] = [
+    Field {
+        name: "minute",
+        minimum: 0,
+        maximum: 59,
+        names: &[],
+    },
+    Field {
+        name: "hour",
+        minimum: 0,
+        maximum: 23,
+        names: &[],
+    },
+    Field {
+        name: "day of the month",
+        minimum: 1,
+        maximum: 31,
+        names: &[],
+    },
+    Field {
+        name: "month",
+        minimum: 1,
+        maximum: 12,
+        names: &[
+            "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+        ],
+    },
+    Field {
+        name: "day of the week",
+        minimum: 0,
+        maximum: 7,
+        names: &["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"],
+    },
+];
+
+impl Field {
+    /// Parse a single value, which may be a number or a name.
+    fn parse_value(&self, s: &str) -> Option<u8> {
+        let value = match self
+            .names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(s))
+        {
+            Some(index) => self.minimum + u8::try_from(index).ok()?,
+            None if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) => s.parse().ok()?,
+            None => return None,
+        };
+        (self.minimum..=self.maximum)
+            .contains(&value)
+            .then_some(value)
+    }
+
+    /// Parse the field into a mask with bit `n` set for each matching value `n`. The field is
+    /// restricted unless it begins with `*`.
+    fn parse(&self, s: &str) -> Result<(u64, bool), InvalidSchedule> {
+        let error = || InvalidSchedule::InvalidField { name: self.name };
+
+        let mut mask = 0;
+        for item in s.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step))
+                    if !step.is_empty() && step.bytes().all(|b| b.is_ascii_digit()) =>
+                {
+                    match step.parse::<u8>() {
+                        Ok(step @ 1..) => (range, Some(step)),
+                        _ => return Err(error()),
+                    }
+                }
+                Some(_) => return Err(error()),
+                None => (item, None),
+            };
+            let (start, end) = match range.split_once('-') {
+                _ if range == "*" => (self.minimum, self.maximum),
+                Some((start, end)) => (
+                    self.parse_value(start).ok_or_else(error)?,
+                    self.parse_value(end).ok_or_else(error)?,
+                ),
+                // As in most implementations, a single value with a step is the start of a range.
+                None if step.is_some() => {
+                    (self.parse_value(range).ok_or_else(error)?, self.maximum)
+                }
+                None => {
+                    let value = self.parse_value(range).ok_or_else(error)?;
+                    (value, value)
+                }
+            };
+            if start > end {
+                return Err(error());
+            }
+            for value in (start..=end).step_by(step.map_or(1, usize::from)) {
+                mask |= 1 << value;
+            }
+        }
+
+        Ok((mask, !s.starts_with('*')))
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = InvalidSchedule;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let expression = if let Some(nickname) = s.strip_prefix('@') {
+            [
+                ("yearly", "0 0 1 1 *"),
+                ("annually", "0 0 1 1 *"),
+                ("monthly", "0 0 1 * *"),
+                ("weekly", "0 0 * * 0"),
+                ("daily", "0 0 * * *"),
+                ("midnight", "0 0 * * *"),
+                ("hourly", "0 * * * *"),
+            ]
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(nickname))
+            .map(|&(_, expression)| expression)
+            .ok_or(InvalidSchedule::UnknownNickname)?
+        } else {
+            s
+        };
+
+        let mut fields = expression.split_ascii_whitespace();
+        let mut masks = [(0, false); 5];
+        for (mask, field) in masks.iter_mut().zip(&FIELDS) {
+            let s = fields.next().ok_or(InvalidSchedule::InvalidFieldCount)?;
+            *mask = field.parse(s)?;
+        }
+        if fields.next().is_some() {
+            return Err(InvalidSchedule::InvalidFieldCount);
+        }
+
+        let [minutes, hours, days, months, weekdays] = masks;
+        let (days, days_restricted) = days;
+        let (weekdays, weekdays_restricted) = weekdays;
+        // Both 0 and 7 are Sunday.
+        let weekdays = (weekdays | weekdays >> 7) & 0x7F;
+
+        // Each mask has only the bits within the range of its field set, so truncation is lossless.
+        Ok(Self {
+            minutes: minutes.0,
+            hours: hours.0.truncate(),
+            days: days.truncate(),
+            months: months.0.truncate(),
+            weekdays: weekdays.truncate(),
+            days_restricted,
+            weekdays_restricted,
+            offset: UtcOffset::UTC,
+        })
+    }
+}
+// endregion parsing
+
+/// An iterator over the occurrences of a [`Schedule`] after a given instant. This is obtained
+/// via [`Schedule::after`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Occurrences {
+    /// The schedule being iterated over.
+    schedule: Schedule,
+    /// The most recently yielded value, or the starting instant. `None` once the iterator is
+    /// exhausted.
+    previous: Option<OffsetDateTime>,
+}
+
+impl Iterator for Occurrences {
+    type Item = OffsetDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.previous = self.schedule.next_after(self.previous?);
+        self.previous
+    }
+}
+
+impl FusedIterator for Occurrences {}
@@ -0,0 +1,255 @@
+//! Use the [CBOR] tags for date/time values when serializing and deserializing an
+//! [`OffsetDateTime`].
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! Values are serialized with tag 1 (epoch-based date/time). The tagged value is an integer number
+//! of seconds if there is no fractional part and a floating point number otherwise. When
+//! deserializing, tag 0 (standard date/time string) is also accepted if the `parsing` feature is
+//! enabled, as are untagged values.
+//!
+//! Serde has no native notion of a tag. This module uses the convention of [`ciborium`], where a
+//! tagged value is an enum named `@@TAG@@` with a tuple variant `@@TAGGED@@` holding the tag and
+//! the value. Other non-human-readable formats see this enum as-is. Human-readable formats receive
+//! the untagged value.
+//!
+//! When deserializing, the offset is assumed to be UTC unless a tag 0 string contains one.
+//!
+//! [CBOR]: https://www.rfc-editor.org/rfc/rfc8949#section-3.4.1
+//! [`ciborium`]: https://docs.rs/ciborium
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use core::fmt;
+
+use serde::de::{self, Unexpected};
+use serde::ser::SerializeTupleVariant;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::ComponentRange;
+#[cfg(feature = "parsing")]
+use crate::format_description::well_known::Rfc3339;
+use crate::{Duration, OffsetDateTime};
+
+/// The name of the enum used to represent a tagged value.
+const TAG: &str = "@@TAG@@";
+/// The name of the variant holding a tag and its value.
+const TAGGED: &str = "@@TAGGED@@";
+/// The name of the variant holding a value without a tag.
+const UNTAGGED: &str = "@@UNTAGGED@@";
+/// The tag for a standard date/time string.
+const STRING_TAG: u64 = 0;
+/// The tag for an epoch-based date/time.
+const EPOCH_TAG: u64 = 1;
+
+/// Serialize an [`OffsetDateTime`] as an epoch-based date/time with CBOR tag 1.
+pub fn serialize<S: Serializer>(
+    datetime: &OffsetDateTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        return Epoch(*datetime).serialize(serializer);
+    }
+
+    let mut state = serializer.serialize_tuple_variant(TAG, 0, TAGGED, 2)?;
+    state.serialize_field(&EPOCH_TAG)?;
+    state.serialize_field(&Epoch(*datetime))?;
+    state.end()
+}
+
+/// Deserialize an [`OffsetDateTime`] from a date/time with CBOR tag 0 or 1, or an untagged value.
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(ValueVisitor)
+    } else {
+        deserializer.deserialize_enum(TAG, &[TAGGED, UNTAGGED], TagVisitor)
+    }
+}
+
+/// An [`OffsetDateTime`] that serializes as the number of seconds since the Unix epoch.
+struct Epoch(OffsetDateTime);
+
+impl Serialize for Epoch {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let seconds = self.0.unix_timestamp();
+        match self.0.nanosecond() {
+            0 => serializer.serialize_i64(seconds),
+            nanoseconds => serializer.serialize_f64(seconds as f64 + f64::from(nanoseconds) / 1e9),
+        }
+    }
+}
+
+/// An [`OffsetDateTime`] deserialized from the value of a tag.
+struct Value(OffsetDateTime);
+
+impl<'a> Deserialize<'a> for Value {
+    fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor).map(Self)
+    }
+}
+
+/// A serde visitor for the value of a tag, which may be a number or a string.
+struct ValueVisitor;
+
+impl de::Visitor<'_> for ValueVisitor {
+    type Value = OffsetDateTime;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a CBOR date/time")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<OffsetDateTime, E> {
+        OffsetDateTime::from_unix_timestamp(value).map_err(ComponentRange::into_de_error)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<OffsetDateTime, E> {
+        let value = i64::try_from(value)
+            .map_err(|_| E::invalid_value(Unexpected::Unsigned(value), &"a Unix timestamp"))?;
+        self.visit_i64(value)
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<OffsetDateTime, E> {
+        Duration::checked_seconds_f64(value)
+            .and_then(|duration| OffsetDateTime::UNIX_EPOCH.checked_add(duration))
+            .ok_or_else(|| E::invalid_value(Unexpected::Float(value), &"a Unix timestamp"))
+    }
+
+    #[cfg(feature = "parsing")]
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<OffsetDateTime, E> {
+        OffsetDateTime::parse(value, &Rfc3339).map_err(E::custom)
+    }
+}
+
+/// The variants of the enum used to represent a tagged value.
+enum Variant {
+    Tagged,
+    Untagged,
+}
+
+impl<'a> Deserialize<'a> for Variant {
+    fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        /// A serde visitor for the name of a variant.
+        struct VariantVisitor;
+
+        impl de::Visitor<'_> for VariantVisitor {
+            type Value = Variant;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a variant identifier")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Variant, E> {
+                match value {
+                    0 => Ok(Variant::Tagged),
+                    1 => Ok(Variant::Untagged),
+                    _ => Err(E::invalid_value(
+                        Unexpected::Unsigned(value),
+                        &"variant index 0 <= i < 2",
+                    )),
+                }
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Variant, E> {
+                match value {
+                    TAGGED => Ok(Variant::Tagged),
+                    UNTAGGED => Ok(Variant::Untagged),
+                    _ => Err(E::unknown_variant(value, &[TAGGED, UNTAGGED])),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(VariantVisitor)
+    }
+}
+
+/// A serde visitor for a value that may be tagged. Formats without tags may provide the value
+/// directly.
+struct TagVisitor;
+
+impl<'a> de::Visitor<'a> for TagVisitor {
+    type Value = OffsetDateTime;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a CBOR date/time")
+    }
+
+    fn visit_enum<A: de::EnumAccess<'a>>(self, data: A) -> Result<OffsetDateTime, A::Error> {
+        match data.variant()? {
+            (Variant::Tagged, variant) => de::VariantAccess::tuple_variant(variant, 2, self),
+            (Variant::Untagged, variant) => {
+                de::VariantAccess::newtype_variant::<Value>(variant).map(|value| value.0)
+            }
+        }
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<OffsetDateTime, E> {
+        ValueVisitor.visit_i64(value)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<OffsetDateTime, E> {
+        ValueVisitor.visit_u64(value)
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<OffsetDateTime, E> {
+        ValueVisitor.visit_f64(value)
+    }
+
+    #[cfg(feature = "parsing")]
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<OffsetDateTime, E> {
+        ValueVisitor.visit_str(value)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'a>>(self, mut seq: A) -> Result<OffsetDateTime, A::Error> {
+        let tag: u64 = item!(seq, "tag")?;
+        if tag != STRING_TAG && tag != EPOCH_TAG {
+            return Err(de::Error::invalid_value(
+                Unexpected::Unsigned(tag),
+                &"CBOR tag 0 or 1",
+            ));
+        }
+        let value: Value = item!(seq, "tagged value")?;
+        Ok(value.0)
+    }
+}
+
+/// Use the [CBOR] tags for date/time values when serializing and deserializing an
+/// [`Option<OffsetDateTime>`].
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [CBOR]: https://www.rfc-editor.org/rfc/rfc8949#section-3.4.1
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// A wrapper to serialize and deserialize an [`OffsetDateTime`] using CBOR tags.
+    struct Wrapper(OffsetDateTime);
+
+    impl Serialize for Wrapper {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'a> Deserialize<'a> for Wrapper {
+        fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+            super::deserialize(deserializer).map(Self)
+        }
+    }
+
+    /// Serialize an [`Option<OffsetDateTime>`] as an epoch-based date/time with CBOR tag 1.
+    pub fn serialize<S: Serializer>(
+        option: &Option<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        option.map(Wrapper).serialize(serializer)
+    }
+
+    /// Deserialize an [`Option<OffsetDateTime>`] from a date/time with CBOR tag 0 or 1, or an
+    /// untagged value.
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<OffsetDateTime>, D::Error> {
+        Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|wrapper| wrapper.0))
+    }
+}
@@ -0,0 +1,508 @@
+//! Serialize and deserialize date-times as structs with named fields.
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! The default representation of date-times in non-human-readable formats is a tuple. This is
+//! compact, but cannot be used where serde expects a map, such as when the value is part of a
+//! struct marked `#[serde(flatten)]`. This module instead represents the value as a struct with the
+//! fields `year`, `ordinal`, `hour`, `minute`, `second`, and `nanosecond`, regardless of the
+//! format. An [`OffsetDateTime`] additionally has the fields `offset_hours`, `offset_minutes`, and
+//! `offset_seconds`.
+//!
+//! The functions in this module are for [`OffsetDateTime`]. The [`primitive_date_time`] and
+//! [`utc_date_time`] modules provide the same representation for [`PrimitiveDateTime`] and
+//! [`UtcDateTime`] respectively.
+//!
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, IgnoredAny};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::ComponentRange;
+use crate::{Date, OffsetDateTime, PrimitiveDateTime, UtcDateTime, UtcOffset};
+
+/// The names of all fields, in the order they are serialized.
+const FIELDS_WITH_OFFSET: &[&str] = &[
+    "year",
+    "ordinal",
+    "hour",
+    "minute",
+    "second",
+    "nanosecond",
+    "offset_hours",
+    "offset_minutes",
+    "offset_seconds",
+];
+/// The names of the fields of types without an offset, in the order they are serialized.
+const FIELDS_WITHOUT_OFFSET: &[&str] =
+    &["year", "ordinal", "hour", "minute", "second", "nanosecond"];
+
+/// A date-time type that can be represented as a struct with named fields.
+trait Fields: Copy {
+    /// The name of the struct as seen by serde.
+    const NAME: &'static str;
+    /// Whether the type has an offset, and therefore the offset fields.
+    const HAS_OFFSET: bool;
+    /// The names of the fields, in the order they are serialized.
+    const FIELDS: &'static [&'static str] = if Self::HAS_OFFSET {
+        FIELDS_WITH_OFFSET
+    } else {
+        FIELDS_WITHOUT_OFFSET
+    };
+
+    /// Split the value into its date-time and offset.
+    fn into_parts(self) -> (PrimitiveDateTime, UtcOffset);
+    /// Construct the value from its date-time and offset. The offset is UTC if the type does not
+    /// have one.
+    fn from_parts(datetime: PrimitiveDateTime, offset: UtcOffset) -> Self;
+}
+
+impl Fields for OffsetDateTime {
+    const NAME: &'static str = "OffsetDateTime";
+    const HAS_OFFSET: bool = true;
+
+    fn into_parts(self) -> (PrimitiveDateTime, UtcOffset) {
+        (
+            PrimitiveDateTime::new(self.date(), self.time()),
+            self.offset(),
+        )
+    }
+
+    fn from_parts(datetime: PrimitiveDateTime, offset: UtcOffset) -> Self {
+        datetime.assume_offset(offset)
+    }
+}
+
+impl Fields for PrimitiveDateTime {
+    const NAME: &'static str = "PrimitiveDateTime";
+    const HAS_OFFSET: bool = false;
+
+    fn into_parts(self) -> (PrimitiveDateTime, UtcOffset) {
+        (self, UtcOffset::UTC)
+    }
+
+    fn from_parts(datetime: PrimitiveDateTime, _: UtcOffset) -> Self {
+        datetime
+    }
+}
+
+impl Fields for UtcDateTime {
+    const NAME: &'static str = "UtcDateTime";
+    const HAS_OFFSET: bool = false;
+
+    fn into_parts(self) -> (PrimitiveDateTime, UtcOffset) {
+        (
+            PrimitiveDateTime::new(self.date(), self.time()),
+            UtcOffset::UTC,
+        )
+    }
+
+    fn from_parts(datetime: PrimitiveDateTime, _: UtcOffset) -> Self {
+        datetime.as_utc()
+    }
+}
+
+/// Serialize an [`OffsetDateTime`] as a struct with named fields.
+pub fn serialize<S: Serializer>(
+    datetime: &OffsetDateTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_fields(*datetime, serializer)
+}
+
+/// Deserialize an [`OffsetDateTime`] from a struct with named fields.
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
+    deserialize_fields(deserializer)
+}
+
+/// Serialize a date-time as a struct with named fields.
+fn serialize_fields<T: Fields, S: Serializer>(value: T, serializer: S) -> Result<S::Ok, S::Error> {
+    let (datetime, offset) = value.into_parts();
+    let mut state = serializer.serialize_struct(T::NAME, T::FIELDS.len())?;
+    state.serialize_field("year", &datetime.year())?;
+    state.serialize_field("ordinal", &datetime.ordinal())?;
+    state.serialize_field("hour", &datetime.hour())?;
+    state.serialize_field("minute", &datetime.minute())?;
+    state.serialize_field("second", &datetime.second())?;
+    state.serialize_field("nanosecond", &datetime.nanosecond())?;
+    if T::HAS_OFFSET {
+        state.serialize_field("offset_hours", &offset.whole_hours())?;
+        state.serialize_field("offset_minutes", &offset.minutes_past_hour())?;
+        state.serialize_field("offset_seconds", &offset.seconds_past_minute())?;
+    }
+    state.end()
+}
+
+/// Deserialize a date-time from a struct with named fields.
+fn deserialize_fields<'a, T: Fields, D: Deserializer<'a>>(deserializer: D) -> Result<T, D::Error> {
+    deserializer.deserialize_struct(T::NAME, T::FIELDS, Visitor(PhantomData))
+}
+
+/// A field of the struct representation. Unknown fields are ignored, as are the offset fields for
+/// types without an offset.
+enum Field {
+    Year,
+    Ordinal,
+    Hour,
+    Minute,
+    Second,
+    Nanosecond,
+    OffsetHours,
+    OffsetMinutes,
+    OffsetSeconds,
+    Unknown,
+}
+
+impl Field {
+    /// Obtain the field with the given name.
+    fn from_name(name: &[u8]) -> Self {
+        match name {
+            b"year" => Self::Year,
+            b"ordinal" => Self::Ordinal,
+            b"hour" => Self::Hour,
+            b"minute" => Self::Minute,
+            b"second" => Self::Second,
+            b"nanosecond" => Self::Nanosecond,
+            b"offset_hours" => Self::OffsetHours,
+            b"offset_minutes" => Self::OffsetMinutes,
+            b"offset_seconds" => Self::OffsetSeconds,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl<'a> Deserialize<'a> for Field {
+    fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        /// A serde visitor for the name of a field.
+        struct FieldVisitor;
+
+        impl de::Visitor<'_> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a field identifier")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Field, E> {
+                Ok(usize::try_from(value)
+                    .ok()
+                    .and_then(|index| FIELDS_WITH_OFFSET.get(index))
+                    .map_or(Field::Unknown, |name| Field::from_name(name.as_bytes())))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                Ok(Field::from_name(value.as_bytes()))
+            }
+
+            fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Field, E> {
+                Ok(Field::from_name(value))
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+/// A serde visitor for the struct representation of a date-time.
+struct Visitor<T>(PhantomData<T>);
+
+impl<'a, T: Fields> de::Visitor<'a> for Visitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "struct `{}`", T::NAME)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'a>>(self, mut seq: A) -> Result<T, A::Error> {
+        let year = item!(seq, "year")?;
+        let ordinal = item!(seq, "ordinal")?;
+        let hour = item!(seq, "hour")?;
+        let minute = item!(seq, "minute")?;
+        let second = item!(seq, "second")?;
+        let nanosecond = item!(seq, "nanosecond")?;
+        let offset = if T::HAS_OFFSET {
+            (
+                item!(seq, "offset_hours")?,
+                item!(seq, "offset_minutes")?,
+                item!(seq, "offset_seconds")?,
+            )
+        } else {
+            (0, 0, 0)
+        };
+
+        build(year, ordinal, (hour, minute, second, nanosecond), offset)
+            .map_err(ComponentRange::into_de_error)
+    }
+
+    fn visit_map<A: de::MapAccess<'a>>(self, mut map: A) -> Result<T, A::Error> {
+        /// Store the value of a field, erroring if it was already present.
+        fn set<'a, A: de::MapAccess<'a>, T: Deserialize<'a>>(
+            map: &mut A,
+            slot: &mut Option<T>,
+            name: &'static str,
+        ) -> Result<(), A::Error> {
+            if slot.is_some() {
+                return Err(de::Error::duplicate_field(name));
+            }
+            *slot = Some(map.next_value()?);
+            Ok(())
+        }
+
+        /// Obtain the value of a field, erroring if it was not present.
+        fn get<T, E: de::Error>(slot: Option<T>, name: &'static str) -> Result<T, E> {
+            slot.ok_or_else(|| E::missing_field(name))
+        }
+
+        let mut year = None;
+        let mut ordinal = None;
+        let mut hour = None;
+        let mut minute = None;
+        let mut second = None;
+        let mut nanosecond = None;
+        let mut offset_hours = None;
+        let mut offset_minutes = None;
+        let mut offset_seconds = None;
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Year => set(&mut map, &mut year, "year")?,
+                Field::Ordinal => set(&mut map, &mut ordinal, "ordinal")?,
+                Field::Hour => set(&mut map, &mut hour, "hour")?,
+                Field::Minute => set(&mut map, &mut minute, "minute")?,
+                Field::Second => set(&mut map, &mut second, "second")?,
+                Field::Nanosecond => set(&mut map, &mut nanosecond, "nanosecond")?,
+                Field::OffsetHours if T::HAS_OFFSET => {
+                    set(&mut map, &mut offset_hours, "offset_hours")?;
+                }
+                Field::OffsetMinutes if T::HAS_OFFSET => {
+                    set(&mut map, &mut offset_minutes, "offset_minutes")?;
+                }
+                Field::OffsetSeconds if T::HAS_OFFSET => {
+                    set(&mut map, &mut offset_seconds, "offset_seconds")?;
+                }
+                Field::OffsetHours
+                | Field::OffsetMinutes
+                | Field::OffsetSeconds
+                | Field::Unknown => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        let offset = if T::HAS_OFFSET {
+            (
+                get(offset_hours, "offset_hours")?,
+                get(offset_minutes, "offset_minutes")?,
+                get(offset_seconds, "offset_seconds")?,
+            )
+        } else {
+            (0, 0, 0)
+        };
+
+        build(
+            get(year, "year")?,
+            get(ordinal, "ordinal")?,
+            (
+                get(hour, "hour")?,
+                get(minute, "minute")?,
+                get(second, "second")?,
+                get(nanosecond, "nanosecond")?,
+            ),
+            offset,
+        )
+        .map_err(ComponentRange::into_de_error)
+    }
+}
+
+/// Construct a date-time from the values of its fields.
+fn build<T: Fields>(
+    year: i32,
+    ordinal: u16,
+    (hour, minute, second, nanosecond): (u8, u8, u8, u32),
+    (offset_hours, offset_minutes, offset_seconds): (i8, i8, i8),
+) -> Result<T, ComponentRange> {
+    let datetime =
+        Date::from_ordinal_date(year, ordinal)?.with_hms_nano(hour, minute, second, nanosecond)?;
+    let offset = UtcOffset::from_hms(offset_hours, offset_minutes, offset_seconds)?;
+    Ok(T::from_parts(datetime, offset))
+}
+
+/// A wrapper to serialize a date-time using the struct representation.
+struct Wrapper<T>(T);
+
+impl<T: Fields> Serialize for Wrapper<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_fields(self.0, serializer)
+    }
+}
+
+/// A serde visitor for an optional date-time in the struct representation.
+struct OptionVisitor<T>(PhantomData<T>);
+
+impl<'a, T: Fields> de::Visitor<'a> for OptionVisitor<T> {
+    type Value = Option<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "an optional struct `{}`", T::NAME)
+    }
+
+    fn visit_some<D: Deserializer<'a>>(self, deserializer: D) -> Result<Option<T>, D::Error> {
+        deserialize_fields(deserializer).map(Some)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Option<T>, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Option<T>, E> {
+        Ok(None)
+    }
+}
+
+/// Serialize an optional date-time as a struct with named fields.
+fn serialize_option_fields<T: Fields, S: Serializer>(
+    option: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    option.map(Wrapper).serialize(serializer)
+}
+
+/// Deserialize an optional date-time from a struct with named fields.
+fn deserialize_option_fields<'a, T: Fields, D: Deserializer<'a>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    deserializer.deserialize_option(OptionVisitor(PhantomData))
+}
+
+/// Serialize and deserialize an [`Option<OffsetDateTime>`] as a struct with named fields.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Serialize an [`Option<OffsetDateTime>`] as a struct with named fields.
+    pub fn serialize<S: Serializer>(
+        option: &Option<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_option_fields(option, serializer)
+    }
+
+    /// Deserialize an [`Option<OffsetDateTime>`] from a struct with named fields.
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<OffsetDateTime>, D::Error> {
+        deserialize_option_fields(deserializer)
+    }
+}
+
+/// Serialize and deserialize a [`PrimitiveDateTime`] as a struct with named fields.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// The fields are the same as those of an [`OffsetDateTime`], except that there are no offset
+/// fields.
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod primitive_date_time {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Serialize a [`PrimitiveDateTime`] as a struct with named fields.
+    pub fn serialize<S: Serializer>(
+        datetime: &PrimitiveDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_fields(*datetime, serializer)
+    }
+
+    /// Deserialize a [`PrimitiveDateTime`] from a struct with named fields.
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<PrimitiveDateTime, D::Error> {
+        deserialize_fields(deserializer)
+    }
+
+    /// Serialize and deserialize an [`Option<PrimitiveDateTime>`] as a struct with named fields.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    ///
+    /// [with]: https://serde.rs/field-attrs.html#with
+    pub mod option {
+        #[allow(clippy::wildcard_imports)]
+        use super::super::*;
+
+        /// Serialize an [`Option<PrimitiveDateTime>`] as a struct with named fields.
+        pub fn serialize<S: Serializer>(
+            option: &Option<PrimitiveDateTime>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serialize_option_fields(option, serializer)
+        }
+
+        /// Deserialize an [`Option<PrimitiveDateTime>`] from a struct with named fields.
+        pub fn deserialize<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Option<PrimitiveDateTime>, D::Error> {
+            deserialize_option_fields(deserializer)
+        }
+    }
+}
+
+/// Serialize and deserialize a [`UtcDateTime`] as a struct with named fields.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// The fields are the same as those of an [`OffsetDateTime`], except that there are no offset
+/// fields.
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod utc_date_time {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Serialize a [`UtcDateTime`] as a struct with named fields.
+    pub fn serialize<S: Serializer>(
+        datetime: &UtcDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_fields(*datetime, serializer)
+    }
+
+    /// Deserialize a [`UtcDateTime`] from a struct with named fields.
+    pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<UtcDateTime, D::Error> {
+        deserialize_fields(deserializer)
+    }
+
+    /// Serialize and deserialize an [`Option<UtcDateTime>`] as a struct with named fields.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    ///
+    /// [with]: https://serde.rs/field-attrs.html#with
+    pub mod option {
+        #[allow(clippy::wildcard_imports)]
+        use super::super::*;
+
+        /// Serialize an [`Option<UtcDateTime>`] as a struct with named fields.
+        pub fn serialize<S: Serializer>(
+            option: &Option<UtcDateTime>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serialize_option_fields(option, serializer)
+        }
+
+        /// Deserialize an [`Option<UtcDateTime>`] from a struct with named fields.
+        pub fn deserialize<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Option<UtcDateTime>, D::Error> {
+            deserialize_option_fields(deserializer)
+        }
+    }
+}
@@ -13,16 +13,19 @@ macro_rules! item {
     };
 }
 
+pub mod cbor;
+pub mod fields;
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 pub mod iso8601;
-#[cfg(any(feature = "formatting", feature = "parsing"))]
+pub mod msgpack;
+#[cfg(all(not(time_no_names), any(feature = "formatting", feature = "parsing")))]
 pub mod rfc2822;
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 pub mod rfc3339;
 pub mod timestamp;
 mod visitor;
 
-#[cfg(feature = "serde-human-readable")]
+#[cfg(all(feature = "serde-human-readable", not(time_no_names)))]
 use alloc::string::ToString;
 use core::marker::PhantomData;
 
@@ -503,7 +506,7 @@ impl<'a> Deserialize<'a> for UtcOffset {
 // region: Weekday
 impl Serialize for Weekday {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        #[cfg(feature = "serde-human-readable")]
+        #[cfg(all(feature = "serde-human-readable", not(time_no_names)))]
         if serializer.is_human_readable() {
             #[cfg(not(feature = "std"))]
             use alloc::string::ToString;
@@ -528,7 +531,7 @@ impl<'a> Deserialize<'a> for Weekday {
 // region: Month
 impl Serialize for Month {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        #[cfg(feature = "serde-human-readable")]
+        #[cfg(all(feature = "serde-human-readable", not(time_no_names)))]
         if serializer.is_human_readable() {
             #[cfg(not(feature = "std"))]
             use alloc::string::String;
@@ -0,0 +1,222 @@
+//! Use the [MessagePack timestamp extension type] when serializing and deserializing an
+//! [`OffsetDateTime`].
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! The smallest of the 32-, 64-, and 96-bit timestamp formats that can represent the value exactly
+//! is used. All three are accepted when deserializing.
+//!
+//! Serde has no native notion of an extension type. This module uses the convention of
+//! [`rmp-serde`], where an extension is a newtype struct named `_ExtStruct` containing a tuple of
+//! the type (here, `-1`) and the data as bytes. Other non-human-readable formats see this struct
+//! as-is. Human-readable formats receive a tuple of the Unix timestamp and the nanoseconds within
+//! the second.
+//!
+//! When deserializing, the offset is assumed to be UTC.
+//!
+//! [MessagePack timestamp extension type]: https://github.com/msgpack/msgpack/blob/master/spec.md#timestamp-extension-type
+//! [`rmp-serde`]: https://docs.rs/rmp-serde
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use core::fmt;
+
+use num_conv::prelude::*;
+use serde::de::{self, Unexpected};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::ComponentRange;
+use crate::OffsetDateTime;
+
+/// The name of the newtype struct used to represent an extension.
+const EXT_STRUCT: &str = "_ExtStruct";
+/// The extension type of a timestamp.
+const TIMESTAMP_TYPE: i8 = -1;
+
+/// Serialize an [`OffsetDateTime`] as a MessagePack timestamp.
+pub fn serialize<S: Serializer>(
+    datetime: &OffsetDateTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let seconds = datetime.unix_timestamp();
+    let nanoseconds = datetime.nanosecond();
+    if serializer.is_human_readable() {
+        return (seconds, nanoseconds).serialize(serializer);
+    }
+
+    let mut buf = [0; 12];
+    let data = encode(seconds, nanoseconds, &mut buf);
+    serializer.serialize_newtype_struct(EXT_STRUCT, &(TIMESTAMP_TYPE, Bytes(data)))
+}
+
+/// Deserialize an [`OffsetDateTime`] from a MessagePack timestamp.
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
+    if deserializer.is_human_readable() {
+        let (seconds, nanoseconds) = <(i64, u32)>::deserialize(deserializer)?;
+        to_datetime(seconds, nanoseconds).map_err(ComponentRange::into_de_error)
+    } else {
+        deserializer.deserialize_newtype_struct(EXT_STRUCT, ExtVisitor)
+    }
+}
+
+/// Encode the timestamp into the provided buffer, returning the portion that was used.
+fn encode(seconds: i64, nanoseconds: u32, buf: &mut [u8; 12]) -> &[u8] {
+    // The 32- and 64-bit formats can only represent non-negative values less than 2^34 seconds.
+    if let Ok(seconds @ 0..=0x3_FFFF_FFFF) = u64::try_from(seconds) {
+        let value = (nanoseconds.extend::<u64>() << 34) | seconds;
+        if let Ok(value) = u32::try_from(value) {
+            buf[..4].copy_from_slice(&value.to_be_bytes());
+            &buf[..4]
+        } else {
+            buf[..8].copy_from_slice(&value.to_be_bytes());
+            &buf[..8]
+        }
+    } else {
+        buf[..4].copy_from_slice(&nanoseconds.to_be_bytes());
+        buf[4..].copy_from_slice(&seconds.to_be_bytes());
+        buf
+    }
+}
+
+/// Decode a timestamp in any of the three formats, returning the seconds and nanoseconds.
+fn decode(data: &[u8]) -> Option<(i64, u32)> {
+    match *data {
+        [a, b, c, d] => Some((i64::from(u32::from_be_bytes([a, b, c, d])), 0)),
+        [a, b, c, d, e, f, g, h] => {
+            let value = u64::from_be_bytes([a, b, c, d, e, f, g, h]);
+            Some((
+                i64::try_from(value & 0x3_FFFF_FFFF).ok()?,
+                (value >> 34).truncate(),
+            ))
+        }
+        [a, b, c, d, ref seconds @ ..] => Some((
+            i64::from_be_bytes(seconds.try_into().ok()?),
+            u32::from_be_bytes([a, b, c, d]),
+        )),
+        _ => None,
+    }
+}
+
+/// Construct an [`OffsetDateTime`] from a Unix timestamp and the nanoseconds within the second.
+fn to_datetime(seconds: i64, nanoseconds: u32) -> Result<OffsetDateTime, ComponentRange> {
+    OffsetDateTime::from_unix_timestamp(seconds)?.replace_nanosecond(nanoseconds)
+}
+
+/// A slice that serializes as bytes.
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// The data of a timestamp extension, deserialized from bytes.
+struct Data(i64, u32);
+
+impl<'a> Deserialize<'a> for Data {
+    fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(DataVisitor)
+    }
+}
+
+/// A serde visitor for the data of a timestamp extension.
+struct DataVisitor;
+
+impl<'a> de::Visitor<'a> for DataVisitor {
+    type Value = Data;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("4, 8, or 12 bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Data, E> {
+        decode(value)
+            .map(|(seconds, nanoseconds)| Data(seconds, nanoseconds))
+            .ok_or_else(|| E::invalid_length(value.len(), &self))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'a>>(self, mut seq: A) -> Result<Data, A::Error> {
+        let mut buf = [0; 12];
+        let mut len = 0;
+        while let Some(byte) = seq.next_element()? {
+            if len == buf.len() {
+                return Err(de::Error::invalid_length(len + 1, &self));
+            }
+            buf[len] = byte;
+            len += 1;
+        }
+        self.visit_bytes(&buf[..len])
+    }
+}
+
+/// A serde visitor for an extension, which must be a timestamp.
+struct ExtVisitor;
+
+impl<'a> de::Visitor<'a> for ExtVisitor {
+    type Value = OffsetDateTime;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a MessagePack timestamp")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'a>>(
+        self,
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        deserializer.deserialize_tuple(2, self)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'a>>(self, mut seq: A) -> Result<OffsetDateTime, A::Error> {
+        let ext_type: i8 = item!(seq, "extension type")?;
+        if ext_type != TIMESTAMP_TYPE {
+            return Err(de::Error::invalid_value(
+                Unexpected::Signed(ext_type.extend()),
+                &"the timestamp extension type -1",
+            ));
+        }
+        let Data(seconds, nanoseconds) = item!(seq, "extension data")?;
+        to_datetime(seconds, nanoseconds).map_err(ComponentRange::into_de_error)
+    }
+}
+
+/// Use the [MessagePack timestamp extension type] when serializing and deserializing an
+/// [`Option<OffsetDateTime>`].
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [MessagePack timestamp extension type]: https://github.com/msgpack/msgpack/blob/master/spec.md#timestamp-extension-type
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// A wrapper to serialize and deserialize an [`OffsetDateTime`] as a MessagePack timestamp.
+    struct Wrapper(OffsetDateTime);
+
+    impl Serialize for Wrapper {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'a> Deserialize<'a> for Wrapper {
+        fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+            super::deserialize(deserializer).map(Self)
+        }
+    }
+
+    /// Serialize an [`Option<OffsetDateTime>`] as a MessagePack timestamp.
+    pub fn serialize<S: Serializer>(
+        option: &Option<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        option.map(Wrapper).serialize(serializer)
+    }
+
+    /// Deserialize an [`Option<OffsetDateTime>`] from a MessagePack timestamp.
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<OffsetDateTime>, D::Error> {
+        Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|wrapper| wrapper.0))
+    }
+}
@@ -8,8 +8,11 @@
 //! [with]: https://serde.rs/field-attrs.html#with
 
 pub mod microseconds;
+pub mod microseconds_i64;
 pub mod milliseconds;
+pub mod milliseconds_i64;
 pub mod nanoseconds;
+pub mod nanoseconds_i64;
 
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
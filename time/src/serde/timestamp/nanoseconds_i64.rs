@@ -0,0 +1,76 @@
+//! Treat an [`OffsetDateTime`] as a [Unix timestamp] with nanoseconds for
+//! the purposes of serde. The timestamp is an `i64`.
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! Unlike [`nanoseconds`](super::nanoseconds), which uses an `i128`, this is supported by
+//! formats without 128-bit integers.
+//!
+//! Serialization fails for dates before 1677-09-21 or after 2262-04-11, as the timestamp does not
+//! fit in an `i64`.
+//!
+//! When deserializing, the offset is assumed to be UTC.
+//!
+//! [Unix timestamp]: https://en.wikipedia.org/wiki/Unix_time
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use serde::ser::Error as _;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::OffsetDateTime;
+
+/// The error message when the timestamp does not fit in an `i64`.
+const OUT_OF_RANGE: &str = "timestamp with nanoseconds does not fit in an `i64`";
+
+/// Serialize an `OffsetDateTime` as its Unix timestamp with nanoseconds
+pub fn serialize<S: Serializer>(
+    datetime: &OffsetDateTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let timestamp = i64::try_from(datetime.unix_timestamp_nanos())
+        .map_err(|_| S::Error::custom(OUT_OF_RANGE))?;
+    timestamp.serialize(serializer)
+}
+
+/// Deserialize an `OffsetDateTime` from its Unix timestamp with nanoseconds
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
+    let value: i64 = <_>::deserialize(deserializer)?;
+    OffsetDateTime::from_unix_timestamp_nanos(i128::from(value))
+        .map_err(|err| de::Error::invalid_value(de::Unexpected::Signed(err.value), &err))
+}
+
+/// Treat an `Option<OffsetDateTime>` as a [Unix timestamp] with nanoseconds
+/// for the purposes of serde. The timestamp is an `i64`.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// When deserializing, the offset is assumed to be UTC.
+///
+/// [Unix timestamp]: https://en.wikipedia.org/wiki/Unix_time
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    /// Serialize an `Option<OffsetDateTime>` as its Unix timestamp with nanoseconds
+    pub fn serialize<S: Serializer>(
+        option: &Option<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        option
+            .map(|datetime| i64::try_from(datetime.unix_timestamp_nanos()))
+            .transpose()
+            .map_err(|_| S::Error::custom(OUT_OF_RANGE))?
+            .serialize(serializer)
+    }
+
+    /// Deserialize an `Option<OffsetDateTime>` from its Unix timestamp with nanoseconds
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<OffsetDateTime>, D::Error> {
+        Option::deserialize(deserializer)?
+            .map(|value: i64| OffsetDateTime::from_unix_timestamp_nanos(i128::from(value)))
+            .transpose()
+            .map_err(|err| de::Error::invalid_value(de::Unexpected::Signed(err.value), &err))
+    }
+}
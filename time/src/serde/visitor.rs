@@ -48,23 +48,49 @@ impl<'a> de::Visitor<'a> for Visitor<Duration> {
         formatter.write_str("a `Duration`")
     }
 
+    /// Parse the human-readable form of a `Duration`, which is exactly what is emitted when
+    /// serializing:
+    ///
+    /// ```text
+    /// duration    = [ "-" ] seconds "." nanoseconds
+    /// seconds     = 1*DIGIT ; the magnitude, which must fit in an `i64` once the sign is applied
+    /// nanoseconds = 9DIGIT  ; the magnitude of the subsecond part, always nine digits
+    /// ```
+    ///
+    /// The sign applies to both parts, so `-0.500000000` is negative half a second.
     fn visit_str<E: de::Error>(self, value: &str) -> Result<Duration, E> {
         let (seconds, nanoseconds) = value.split_once('.').ok_or_else(|| {
             de::Error::invalid_value(de::Unexpected::Str(value), &"a decimal point")
         })?;
 
-        let seconds = seconds
-            .parse()
-            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(seconds), &"seconds"))?;
-        let mut nanoseconds = nanoseconds.parse().map_err(|_| {
+        let (is_negative, seconds) = match seconds.strip_prefix('-') {
+            Some(seconds) => (true, seconds),
+            None => (false, seconds),
+        };
+        let invalid_seconds =
+            || de::Error::invalid_value(de::Unexpected::Str(seconds), &"seconds");
+        if seconds.is_empty() || !seconds.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(invalid_seconds());
+        }
+        let seconds = seconds.parse::<u64>().map_err(|_| invalid_seconds())?;
+        let seconds = if is_negative {
+            0_i64.checked_sub_unsigned(seconds)
+        } else {
+            i64::try_from(seconds).ok()
+        }
+        .ok_or_else(invalid_seconds)?;
+
+        if nanoseconds.len() != 9 || !nanoseconds.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Str(nanoseconds),
+                &"nine digits of nanoseconds",
+            ));
+        }
+        let mut nanoseconds = nanoseconds.parse::<i32>().map_err(|_| {
             de::Error::invalid_value(de::Unexpected::Str(nanoseconds), &"nanoseconds")
         })?;
-
-        if seconds < 0
-            // make sure sign does not disappear when seconds == 0
-            || (seconds == 0 && value.starts_with("-"))
-        {
-            nanoseconds *= -1;
+        if is_negative {
+            nanoseconds = -nanoseconds;
         }
 
         Ok(Duration::new(seconds, nanoseconds))
@@ -346,6 +372,7 @@ macro_rules! well_known {
     };
 }
 
+#[cfg(not(time_no_names))]
 well_known!("an", "RFC2822", Rfc2822);
 well_known!("an", "RFC3339", Rfc3339);
 well_known!(
@@ -29,19 +29,55 @@ struct SystemTime {
     wMilliseconds: u16,
 }
 
+// ffi: WINAPI DYNAMIC_TIME_ZONE_INFORMATION struct
+#[repr(C)]
+#[allow(non_snake_case, clippy::missing_docs_in_private_items)]
+struct DynamicTimeZoneInformation {
+    Bias: i32,
+    StandardName: [u16; 32],
+    StandardDate: SystemTime,
+    StandardBias: i32,
+    DaylightName: [u16; 32],
+    DaylightDate: SystemTime,
+    DaylightBias: i32,
+    TimeZoneKeyName: [u16; 128],
+    DynamicDaylightTimeDisabled: u8,
+}
+
+/// The value returned by `GetDynamicTimeZoneInformation` on failure.
+const TIME_ZONE_ID_INVALID: u32 = u32::MAX;
+
 #[link(name = "kernel32")]
 extern "system" {
     // https://docs.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-systemtimetofiletime
     fn SystemTimeToFileTime(lpSystemTime: *const SystemTime, lpFileTime: *mut FileTime) -> i32;
 
-    // https://docs.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-systemtimetotzspecificlocaltime
-    fn SystemTimeToTzSpecificLocalTime(
-        lpTimeZoneInformation: *const core::ffi::c_void, // We only pass `nullptr` here
+    // https://docs.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-getdynamictimezoneinformation
+    fn GetDynamicTimeZoneInformation(pTimeZoneInformation: *mut DynamicTimeZoneInformation) -> u32;
+
+    // https://docs.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-systemtimetotzspecificlocaltimeex
+    fn SystemTimeToTzSpecificLocalTimeEx(
+        lpTimeZoneInformation: *const DynamicTimeZoneInformation,
         lpUniversalTime: *const SystemTime,
         lpLocalTime: *mut SystemTime,
     ) -> i32;
 }
 
+/// Obtain the system's current time zone, including its historical rules. Returns `None` if any
+/// error occurred.
+fn dynamic_time_zone_information() -> Option<DynamicTimeZoneInformation> {
+    let mut tzi = MaybeUninit::uninit();
+
+    // Safety: `GetDynamicTimeZoneInformation` is thread-safe.
+    if TIME_ZONE_ID_INVALID == unsafe { GetDynamicTimeZoneInformation(tzi.as_mut_ptr()) } {
+        // failed
+        None
+    } else {
+        // Safety: The call succeeded.
+        Some(unsafe { tzi.assume_init() })
+    }
+}
+
 /// Convert a `SYSTEMTIME` to a `FILETIME`. Returns `None` if any error occurred.
 fn systemtime_to_filetime(systime: &SystemTime) -> Option<FileTime> {
     let mut ft = MaybeUninit::uninit();
@@ -81,19 +117,18 @@ fn offset_to_systemtime(datetime: OffsetDateTime) -> SystemTime {
 
 /// Obtain the system's UTC offset.
 pub(super) fn local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
-    // This function falls back to UTC if any system call fails.
     let systime_utc = offset_to_systemtime(datetime.to_offset(UtcOffset::UTC));
 
+    // Passing the dynamic time zone information (rather than a null pointer) causes Windows to
+    // apply the rules in effect for the year in question, not only the ones in effect now.
+    let tzi = dynamic_time_zone_information()?;
+
     // Safety: `local_time` is only read if it is properly initialized, and
-    // `SystemTimeToTzSpecificLocalTime` is thread-safe.
+    // `SystemTimeToTzSpecificLocalTimeEx` is thread-safe.
     let systime_local = unsafe {
         let mut local_time = MaybeUninit::uninit();
 
-        if 0 == SystemTimeToTzSpecificLocalTime(
-            core::ptr::null(), // use system's current timezone
-            &systime_utc,
-            local_time.as_mut_ptr(),
-        ) {
+        if 0 == SystemTimeToTzSpecificLocalTimeEx(&tzi, &systime_utc, local_time.as_mut_ptr()) {
             // call failed
             return None;
         } else {
@@ -9,3 +9,10 @@ mod refresh_tz;
 pub(crate) use self::local_offset_at::local_offset_at;
 #[cfg(feature = "local-offset")]
 pub(crate) use self::refresh_tz::{refresh_tz, refresh_tz_unchecked};
+
+crate::internal_macros::cfg_wasm_bindgen! {
+    #[cfg(feature = "std")]
+    mod performance_now;
+    #[cfg(feature = "std")]
+    pub(crate) use self::performance_now::Instant;
+}
@@ -0,0 +1,74 @@
+//! A monotonic clock backed by `performance.now()`, for use on `wasm32-unknown-unknown` where
+//! [`std::time::Instant`] is not implemented.
+
+use core::ops::{Add, Sub};
+use core::time::Duration as StdDuration;
+
+use js_sys::{Function, Reflect};
+
+/// A point in time, stored as the duration since the time origin of the current context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Instant(StdDuration);
+
+impl Instant {
+    /// Obtain the current instant from `performance.now()`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `performance.now()` is not available in the current context.
+    pub(crate) fn now() -> Self {
+        let global = js_sys::global();
+        let milliseconds = Reflect::get(&global, &"performance".into())
+            .and_then(|performance| {
+                Function::from(Reflect::get(&performance, &"now".into())?).call0(&performance)
+            })
+            .ok()
+            .and_then(|value| value.as_f64())
+            .expect("`performance.now()` is not available");
+
+        // The value is never negative, but guard against a misbehaving environment to avoid a
+        // panic in `Duration::from_secs_f64`.
+        if milliseconds >= 0. {
+            Self(StdDuration::from_secs_f64(milliseconds / 1_000.))
+        } else {
+            Self(StdDuration::ZERO)
+        }
+    }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    pub(crate) fn checked_add(self, duration: StdDuration) -> Option<Self> {
+        self.0.checked_add(duration).map(Self)
+    }
+
+    /// Computes `self - duration`, returning `None` if the result would precede the time origin.
+    pub(crate) fn checked_sub(self, duration: StdDuration) -> Option<Self> {
+        self.0.checked_sub(duration).map(Self)
+    }
+}
+
+impl Sub for Instant {
+    type Output = StdDuration;
+
+    /// Saturates at zero if `rhs` is later than `self`, matching [`std::time::Instant`].
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.0.saturating_sub(rhs.0)
+    }
+}
+
+impl Add<StdDuration> for Instant {
+    type Output = Self;
+
+    fn add(self, duration: StdDuration) -> Self::Output {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl Sub<StdDuration> for Instant {
+    type Output = Self;
+
+    fn sub(self, duration: StdDuration) -> Self::Output {
+        self.checked_sub(duration)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
@@ -0,0 +1,129 @@
+//! Utilities for testing code that depends on the current time.
+//!
+//! [`MockClock`] freezes the time observed by [`OffsetDateTime::now_utc`],
+//! [`UtcDateTime::now`](crate::UtcDateTime::now), and [`Instant::now`](crate::Instant::now), after
+//! which it only changes when explicitly set or advanced. The mock is tracked per thread, so tests
+//! running in parallel do not interfere with one another. It takes precedence over any clock
+//! installed with [`set_global_clock`](crate::clock::set_global_clock).
+//!
+//! This module is intended for tests and should only be enabled as a development dependency.
+//!
+//! ```rust
+//! # use time::test_util::MockClock;
+//! # use time::OffsetDateTime;
+//! # use time::ext::NumericalDuration;
+//! # use time_macros::datetime;
+//! MockClock::set(datetime!(2024-01-01 0:00 UTC));
+//! assert_eq!(OffsetDateTime::now_utc(), datetime!(2024-01-01 0:00 UTC));
+//!
+//! MockClock::advance(90.minutes());
+//! assert_eq!(OffsetDateTime::now_utc(), datetime!(2024-01-01 1:30 UTC));
+//!
+//! MockClock::reset();
+//! assert!(OffsetDateTime::now_utc() > datetime!(2024-01-01 1:30 UTC));
+//! ```
+
+#![allow(deprecated)]
+
+use core::cell::Cell;
+
+use crate::{Duration, Instant, OffsetDateTime, UtcDateTime};
+
+/// The frozen state of the clock.
+#[derive(Clone, Copy)]
+struct Frozen {
+    /// The value returned by [`UtcDateTime::now`] and [`OffsetDateTime::now_utc`].
+    now: UtcDateTime,
+    /// The value returned by [`Instant::now`].
+    instant: Instant,
+}
+
+std::thread_local! {
+    /// The frozen state of the clock for the current thread, if any.
+    static FROZEN: Cell<Option<Frozen>> = const { Cell::new(None) };
+}
+
+/// A clock that can be frozen, set, and advanced for the current thread.
+///
+/// This type cannot be constructed. All of its methods are associated functions.
+#[derive(Debug, Clone, Copy)]
+pub enum MockClock {}
+
+impl MockClock {
+    /// Freeze the clock for the current thread at the current time. If the clock is already
+    /// frozen, this has no effect.
+    pub fn freeze() {
+        if !Self::is_frozen() {
+            FROZEN.with(|frozen| {
+                frozen.set(Some(Frozen {
+                    now: crate::clock::now_utc(),
+                    instant: Instant::now(),
+                }));
+            });
+        }
+    }
+
+    /// Freeze the clock for the current thread at the provided date and time. If the clock is
+    /// already frozen, the value of [`Instant::now`] is unaffected.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the date and time in UTC is outside the supported range.
+    pub fn set(datetime: OffsetDateTime) {
+        let instant = Self::frozen().map_or_else(Instant::now, |frozen| frozen.instant);
+        FROZEN.with(|frozen| {
+            frozen.set(Some(Frozen {
+                now: datetime.to_utc(),
+                instant,
+            }));
+        });
+    }
+
+    /// Advance the clock for the current thread by the provided duration, which may be negative.
+    /// If the clock is not frozen, it is first frozen at the current time.
+    ///
+    /// # Panics
+    ///
+    /// This may panic if the resulting date and time or instant cannot be represented.
+    pub fn advance(duration: Duration) {
+        Self::freeze();
+        let Some(Frozen { now, instant }) = Self::frozen() else {
+            unreachable!("the clock was frozen above");
+        };
+        FROZEN.with(|frozen| {
+            frozen.set(Some(Frozen {
+                now: now
+                    .checked_add(duration)
+                    .expect("resulting value out of range"),
+                instant: instant
+                    .checked_add(duration)
+                    .expect("overflow when adding duration to instant"),
+            }));
+        });
+    }
+
+    /// Unfreeze the clock for the current thread, restoring the actual time.
+    pub fn reset() {
+        FROZEN.with(|frozen| frozen.set(None));
+    }
+
+    /// Whether the clock is frozen for the current thread.
+    pub fn is_frozen() -> bool {
+        Self::frozen().is_some()
+    }
+
+    /// Obtain the frozen state of the clock for the current thread, if any.
+    fn frozen() -> Option<Frozen> {
+        FROZEN.with(Cell::get)
+    }
+}
+
+/// Obtain the frozen date and time for the current thread, if any.
+pub(crate) fn now_utc() -> Option<UtcDateTime> {
+    MockClock::frozen().map(|frozen| frozen.now)
+}
+
+/// Obtain the frozen instant for the current thread, if any.
+pub(crate) fn instant() -> Option<Instant> {
+    MockClock::frozen().map(|frozen| frozen.instant)
+}
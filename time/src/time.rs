@@ -3,12 +3,13 @@
 #[cfg(feature = "formatting")]
 use alloc::string::String;
 use core::fmt;
+use core::iter::{self, FusedIterator};
 use core::ops::{Add, Sub};
 use core::time::Duration as StdDuration;
 #[cfg(feature = "formatting")]
 use std::io;
 
-use deranged::{RangedU32, RangedU8};
+use deranged::{RangedU32, RangedU64, RangedU8};
 use num_conv::prelude::*;
 use powerfmt::ext::FormatterExt;
 use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
@@ -18,7 +19,7 @@ use crate::convert::*;
 use crate::formatting::Formattable;
 use crate::internal_macros::{cascade, ensure_ranged, impl_add_assign, impl_sub_assign};
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{FromParsed, Parsable, ParserOptions};
 use crate::util::DateAdjustment;
 use crate::{error, Duration};
 
@@ -39,6 +40,8 @@ type Minutes = RangedU8<0, { Minute::per(Hour) - 1 }>;
 type Seconds = RangedU8<0, { Second::per(Minute) - 1 }>;
 /// The type of the `nanosecond` field of `Time`.
 type Nanoseconds = RangedU32<0, { Nanosecond::per(Second) - 1 }>;
+/// The number of nanoseconds since midnight.
+type NanosecondsOfDay = RangedU64<0, { Nanosecond::per(Day) - 1 }>;
 
 /// The clock time within a given date. Nanosecond precision.
 ///
@@ -303,6 +306,109 @@ impl Time {
             ensure_ranged!(Nanoseconds: nanosecond),
         ))
     }
+
+    /// Create a `Time` from the number of nanoseconds since midnight.
+    const fn from_nanosecond_of_day_ranged(nanosecond_of_day: NanosecondsOfDay) -> Self {
+        let nanos = nanosecond_of_day.get();
+        // Safety: The value is less than one day, so all components are in range.
+        unsafe {
+            Self::__from_hms_nanos_unchecked(
+                (nanos / Nanosecond::per(Hour)) as _,
+                (nanos % Nanosecond::per(Hour) / Nanosecond::per(Minute)) as _,
+                (nanos % Nanosecond::per(Minute) / Nanosecond::per(Second) as u64) as _,
+                (nanos % Nanosecond::per(Second) as u64) as _,
+            )
+        }
+    }
+
+    /// Attempt to create a `Time` from the number of milliseconds since midnight.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// # use time_macros::time;
+    /// assert_eq!(Time::from_millisecond_of_day(0), Ok(time!(0:00)));
+    /// assert_eq!(
+    ///     Time::from_millisecond_of_day(45_296_789),
+    ///     Ok(time!(12:34:56.789))
+    /// );
+    /// assert!(Time::from_millisecond_of_day(86_400_000).is_err()); // 86_400_000 isn't a valid millisecond of the day.
+    /// ```
+    pub const fn from_millisecond_of_day(
+        millisecond_of_day: u32,
+    ) -> Result<Self, error::ComponentRange> {
+        Ok(Self::from_nanosecond_of_day_ranged(ensure_ranged!(
+            NanosecondsOfDay: millisecond_of_day as u64 * Nanosecond::per(Millisecond) as u64
+        )))
+    }
+
+    /// Attempt to create a `Time` from the number of microseconds since midnight.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// # use time_macros::time;
+    /// assert_eq!(Time::from_microsecond_of_day(0), Ok(time!(0:00)));
+    /// assert_eq!(
+    ///     Time::from_microsecond_of_day(45_296_789_012),
+    ///     Ok(time!(12:34:56.789_012))
+    /// );
+    /// assert!(Time::from_microsecond_of_day(86_400_000_000).is_err()); // 86_400_000_000 isn't a valid microsecond of the day.
+    /// ```
+    pub const fn from_microsecond_of_day(
+        microsecond_of_day: u64,
+    ) -> Result<Self, error::ComponentRange> {
+        Ok(Self::from_nanosecond_of_day_ranged(ensure_ranged!(
+            NanosecondsOfDay: microsecond_of_day * Nanosecond::per(Microsecond) as u64
+        )))
+    }
+
+    /// Attempt to create a `Time` from the number of nanoseconds since midnight.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// # use time_macros::time;
+    /// assert_eq!(Time::from_nanosecond_of_day(0), Ok(time!(0:00)));
+    /// assert_eq!(
+    ///     Time::from_nanosecond_of_day(45_296_789_012_345),
+    ///     Ok(time!(12:34:56.789_012_345))
+    /// );
+    /// assert!(Time::from_nanosecond_of_day(86_400_000_000_000).is_err()); // 86_400_000_000_000 isn't a valid nanosecond of the day.
+    /// ```
+    pub const fn from_nanosecond_of_day(
+        nanosecond_of_day: u64,
+    ) -> Result<Self, error::ComponentRange> {
+        Ok(Self::from_nanosecond_of_day_ranged(ensure_ranged!(
+            NanosecondsOfDay: nanosecond_of_day
+        )))
+    }
+
+    /// Obtain an iterator over the times in a day, starting at midnight and advancing by `step`
+    /// until the end of the day is reached.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalDuration, Time};
+    /// # use time_macros::time;
+    /// let mut times = Time::every(6.hours());
+    /// assert_eq!(times.next(), Some(time!(0:00)));
+    /// assert_eq!(times.next(), Some(time!(6:00)));
+    /// assert_eq!(times.next(), Some(time!(12:00)));
+    /// assert_eq!(times.next(), Some(time!(18:00)));
+    /// assert_eq!(times.next(), None);
+    /// assert_eq!(Time::every(1.minutes()).count(), 1_440);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `step` is not positive.
+    pub fn every(step: Duration) -> impl FusedIterator<Item = Self> + Clone {
+        assert!(step.is_positive(), "step must be positive");
+        let step = step.whole_nanoseconds();
+
+        iter::successors(Some(0), move |&nanoseconds| {
+            Some(nanoseconds + step)
+                .filter(|&nanoseconds| nanoseconds < i128::from(Nanosecond::per(Day)))
+        })
+        .map(|nanoseconds| Self::MIDNIGHT + Duration::nanoseconds_i128(nanoseconds))
+    }
     // endregion constructors
 
     // region: getters
@@ -317,6 +423,17 @@ impl Time {
         (self.hour.get(), self.minute.get(), self.second.get())
     }
 
+    /// Get the clock hour and minute.
+    ///
+    /// ```rust
+    /// # use time_macros::time;
+    /// assert_eq!(time!(0:00:00).as_hm(), (0, 0));
+    /// assert_eq!(time!(23:59:59).as_hm(), (23, 59));
+    /// ```
+    pub const fn as_hm(self) -> (u8, u8) {
+        (self.hour.get(), self.minute.get())
+    }
+
     /// Get the clock hour, minute, second, and millisecond.
     ///
     /// ```rust
@@ -362,6 +479,8 @@ impl Time {
     ///     (23, 59, 59, 999_999_999)
     /// );
     /// ```
+    ///
+    /// This is the inverse of `Time::try_from((hour, minute, second, nanosecond))`.
     pub const fn as_hms_nano(self) -> (u8, u8, u8, u32) {
         (
             self.hour.get(),
@@ -454,6 +573,60 @@ impl Time {
     pub const fn nanosecond(self) -> u32 {
         self.nanosecond.get()
     }
+
+    /// Get the number of milliseconds since midnight.
+    ///
+    /// The returned value will always be in the range `0..86_400_000`.
+    ///
+    /// ```rust
+    /// # use time_macros::time;
+    /// assert_eq!(time!(0:00).millisecond_of_day(), 0);
+    /// assert_eq!(time!(12:34:56.789).millisecond_of_day(), 45_296_789);
+    /// assert_eq!(time!(23:59:59.999_999_999).millisecond_of_day(), 86_399_999);
+    /// ```
+    pub const fn millisecond_of_day(self) -> u32 {
+        (self.nanosecond_of_day() / Nanosecond::per(Millisecond) as u64) as u32
+    }
+
+    /// Get the number of microseconds since midnight.
+    ///
+    /// The returned value will always be in the range `0..86_400_000_000`.
+    ///
+    /// ```rust
+    /// # use time_macros::time;
+    /// assert_eq!(time!(0:00).microsecond_of_day(), 0);
+    /// assert_eq!(time!(12:34:56.789_012).microsecond_of_day(), 45_296_789_012);
+    /// assert_eq!(
+    ///     time!(23:59:59.999_999_999).microsecond_of_day(),
+    ///     86_399_999_999
+    /// );
+    /// ```
+    pub const fn microsecond_of_day(self) -> u64 {
+        self.nanosecond_of_day() / Nanosecond::per(Microsecond) as u64
+    }
+
+    /// Get the number of nanoseconds since midnight.
+    ///
+    /// The returned value will always be in the range `0..86_400_000_000_000`.
+    ///
+    /// ```rust
+    /// # use time_macros::time;
+    /// assert_eq!(time!(0:00).nanosecond_of_day(), 0);
+    /// assert_eq!(
+    ///     time!(12:34:56.789_012_345).nanosecond_of_day(),
+    ///     45_296_789_012_345
+    /// );
+    /// assert_eq!(
+    ///     time!(23:59:59.999_999_999).nanosecond_of_day(),
+    ///     86_399_999_999_999
+    /// );
+    /// ```
+    pub const fn nanosecond_of_day(self) -> u64 {
+        self.hour.get() as u64 * Nanosecond::per(Hour)
+            + self.minute.get() as u64 * Nanosecond::per(Minute)
+            + self.second.get() as u64 * Nanosecond::per(Second) as u64
+            + self.nanosecond.get() as u64
+    }
     // endregion getters
 
     // region: arithmetic helpers
@@ -593,6 +766,138 @@ impl Time {
     }
     // endregion arithmetic helpers
 
+    // region: wrapping arithmetic
+    /// Add the sub-day time of the [`Duration`] to the `Time`, wrapping on overflow. This is the
+    /// same as `self + duration`, but additionally returns the number of days carried: `1` if
+    /// the result is on the next day, `-1` if it is on the previous day, and `0` otherwise.
+    ///
+    /// Only the portion of the duration less than a day is considered, so the carry is always
+    /// in the range `-1..=1`. The number of whole days in the duration is available via
+    /// [`Duration::whole_days`], and the total number of days to advance is the sum of the two.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::time;
+    /// assert_eq!(time!(12:00).add_with_wrap(2.hours()), (time!(14:00), 0));
+    /// assert_eq!(time!(23:00).add_with_wrap(2.hours()), (time!(1:00), 1));
+    /// assert_eq!(time!(1:00).add_with_wrap((-2).hours()), (time!(23:00), -1));
+    /// assert_eq!(time!(23:00).add_with_wrap(26.hours()), (time!(1:00), 1));
+    /// ```
+    pub const fn add_with_wrap(self, duration: Duration) -> (Self, i8) {
+        let (date_adjustment, time) = self.adjusting_add(duration);
+        (time, date_adjustment.days())
+    }
+
+    /// Subtract the sub-day time of the [`Duration`] from the `Time`, wrapping on overflow. This is
+    /// the same as `self - duration`, but additionally returns the number of days carried: `1` if
+    /// the result is on the next day, `-1` if it is on the previous day, and `0` otherwise.
+    ///
+    /// Only the portion of the duration less than a day is considered, so the carry is always
+    /// in the range `-1..=1`. The number of whole days in the duration is available via
+    /// [`Duration::whole_days`], and the total number of days to go back is the difference of
+    /// the two.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::time;
+    /// assert_eq!(time!(14:00).sub_with_wrap(2.hours()), (time!(12:00), 0));
+    /// assert_eq!(time!(1:00).sub_with_wrap(2.hours()), (time!(23:00), -1));
+    /// assert_eq!(time!(23:00).sub_with_wrap((-2).hours()), (time!(1:00), 1));
+    /// ```
+    pub const fn sub_with_wrap(self, duration: Duration) -> (Self, i8) {
+        let (date_adjustment, time) = self.adjusting_sub(duration);
+        (time, date_adjustment.days())
+    }
+    // endregion wrapping arithmetic
+
+    // region: rounding
+    /// Round the `Time` to a multiple of the [`Duration`] since midnight, returning whether the
+    /// result is on the next day. Truncates if `round` is false.
+    pub(crate) const fn adjusting_round(self, duration: Duration, round: bool) -> (bool, Self) {
+        assert!(duration.is_positive(), "duration must be positive");
+        let step = duration.whole_nanoseconds();
+
+        let nanos = self.hour.get() as i128 * Nanosecond::per(Hour) as i128
+            + self.minute.get() as i128 * Nanosecond::per(Minute) as i128
+            + self.second.get() as i128 * Nanosecond::per(Second) as i128
+            + self.nanosecond.get() as i128;
+        let lower = nanos - nanos % step;
+        // Each day is aligned to midnight, so the following midnight is the next candidate after
+        // the last multiple within the day.
+        let upper = if step < Nanosecond::per(Day) as i128 - lower {
+            lower + step
+        } else {
+            Nanosecond::per(Day) as i128
+        };
+
+        // Halfway values are rounded up.
+        let nanos = if round && nanos - lower >= upper - nanos {
+            if upper == Nanosecond::per(Day) as i128 {
+                return (true, Self::MIDNIGHT);
+            }
+            upper
+        } else {
+            lower
+        };
+
+        let nanos = nanos as u64;
+        (
+            false,
+            // Safety: The value is less than one day, so all components are in range.
+            unsafe {
+                Self::__from_hms_nanos_unchecked(
+                    (nanos / Nanosecond::per(Hour)) as _,
+                    (nanos % Nanosecond::per(Hour) / Nanosecond::per(Minute)) as _,
+                    (nanos % Nanosecond::per(Minute) / Nanosecond::per(Second) as u64) as _,
+                    (nanos % Nanosecond::per(Second) as u64) as _,
+                )
+            },
+        )
+    }
+
+    /// Truncate the `Time` to the previous multiple of the [`Duration`] since midnight. If the
+    /// `Time` is already a multiple, it is returned unchanged.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::time;
+    /// assert_eq!(time!(12:34:56.789).truncate_to(1.seconds()), time!(12:34:56));
+    /// assert_eq!(time!(12:34:56).truncate_to(1.minutes()), time!(12:34));
+    /// assert_eq!(time!(12:34:56).truncate_to(15.minutes()), time!(12:30));
+    /// assert_eq!(time!(12:34:56).truncate_to(1.days()), time!(0:00));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `duration` is not positive.
+    #[must_use = "This method does not mutate the original `Time`."]
+    pub const fn truncate_to(self, duration: Duration) -> Self {
+        self.adjusting_round(duration, false).1
+    }
+
+    /// Round the `Time` to the nearest multiple of the [`Duration`] since midnight. Values exactly
+    /// halfway between two multiples are rounded up. The following midnight is treated as the
+    /// multiple after the last one in the day; rounding to it wraps to midnight.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::time;
+    /// assert_eq!(time!(12:34:56.789).round_to(1.seconds()), time!(12:34:57));
+    /// assert_eq!(time!(12:34:29).round_to(1.minutes()), time!(12:34));
+    /// assert_eq!(time!(12:34:30).round_to(1.minutes()), time!(12:35));
+    /// assert_eq!(time!(12:37:29).round_to(15.minutes()), time!(12:30));
+    /// assert_eq!(time!(23:59:30).round_to(1.minutes()), time!(0:00));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `duration` is not positive.
+    #[must_use = "This method does not mutate the original `Time`."]
+    pub const fn round_to(self, duration: Duration) -> Self {
+        self.adjusting_round(duration, true).1
+    }
+    // endregion rounding
+
     // region: replacement
     /// Replace the clock hour.
     ///
@@ -718,7 +1023,10 @@ impl Time {
         output: &mut impl io::Write,
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(output, None, Some(self), None)
+        let result = format.format_into_buffered(output, None, Some(self), None);
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_format(false, result.as_ref().ok().copied());
+        result
     }
 
     /// Format the `Time` using the provided [format description](crate::format_description).
@@ -731,7 +1039,33 @@ impl Time {
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(None, Some(self), None)
+        let result = format.format(None, Some(self), None);
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_format(true, result.as_ref().ok().map(String::len));
+        result
+    }
+
+    /// Determine the number of bytes the `Time` would be formatted as using the provided [format
+    /// description](crate::format_description).
+    ///
+    /// The length is exact, so it can be used to allocate a buffer of the correct size before
+    /// calling [`format_into`](Self::format_into).
+    ///
+    /// The length is determined by formatting the value and discarding the output, so calling this
+    /// costs as much as formatting.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::time;
+    /// let format = format_description::parse("[hour]:[minute]:[second]")?;
+    /// assert_eq!(time!(12:00).formatted_len(&format)?, "12:00:00".len());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn formatted_len(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.formatted_len(None, Some(self), None)
     }
 }
 
@@ -751,8 +1085,38 @@ impl Time {
         input: &str,
         description: &(impl Parsable + ?Sized),
     ) -> Result<Self, error::Parse> {
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_parse(input.len());
         description.parse_time(input.as_bytes())
     }
+
+    /// Parse a `Time` from the input using the provided [format
+    /// description](crate::format_description), subject to the provided [`ParserOptions`]. With
+    /// limits set, this should be preferred over [`Time::parse`] when the input is untrusted.
+    ///
+    /// ```rust
+    /// # use time::parsing::ParserOptions;
+    /// # use time::Time;
+    /// # use time_macros::{time, format_description};
+    /// let format = format_description!("[hour]:[minute]:[second].[subsecond]");
+    /// let options = ParserOptions::DEFAULT.with_max_input_len(10);
+    /// assert_eq!(
+    ///     Time::parse_with_options("12:00:00.5", &format, options)?,
+    ///     time!(12:00:00.5)
+    /// );
+    /// assert!(Time::parse_with_options("12:00:00.5 ", &format, options).is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_options(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+        options: ParserOptions,
+    ) -> Result<Self, error::Parse> {
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_parse(input.len());
+        let parsed = description.parse_with_options(input.as_bytes(), options)?;
+        Ok(Self::from_parsed(parsed, options)?)
+    }
 }
 
 mod private {
@@ -946,4 +1310,14 @@ impl Sub for Time {
         unsafe { Duration::new_unchecked(seconds, nanoseconds) }
     }
 }
+
+impl TryFrom<(u8, u8, u8, u32)> for Time {
+    type Error = error::ComponentRange;
+
+    fn try_from(
+        (hour, minute, second, nanosecond): (u8, u8, u8, u32),
+    ) -> Result<Self, Self::Error> {
+        Self::from_hms_nano(hour, minute, second, nanosecond)
+    }
+}
 // endregion trait impls
@@ -0,0 +1,415 @@
+//! Conversion between instants and local date-times in a time zone.
+//!
+//! This crate does not contain any time zone data. Instead, the [`TimeZone`] trait allows other
+//! crates to provide the offset in effect at any instant, which is then used by
+//! [`OffsetDateTime::to_timezone`] and [`PrimitiveDateTime::assume_timezone`]. For the common
+//! case of a time zone that alternates between standard and daylight saving time on fixed dates,
+//! [`SimpleTz`] can be used without any time zone data.
+
+use crate::{
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset, Weekday,
+};
+
+/// A time zone, which determines the [`UtcOffset`] in effect at any given instant.
+///
+/// Resolving a local date-time with [`PrimitiveDateTime::assume_timezone`] determines the candidate
+/// offsets by looking up the offset two days before and two days after it, treating the local
+/// date-time as UTC. Implementations are expected to change their offset at most once between those
+/// two instants, which is the case for all real-world time zones. Otherwise, a local date-time near
+/// the changes may be resolved incorrectly.
+///
+/// ```rust
+/// # use time::time_zone::{LocalResult, TimeZone};
+/// # use time_macros::{datetime, offset, utc_datetime};
+/// # use time::{UtcDateTime, UtcOffset};
+/// /// Central European Time, with daylight saving time only observed in 2021.
+/// struct Cet2021;
+///
+/// impl TimeZone for Cet2021 {
+///     fn offset_at(&self, datetime: UtcDateTime) -> UtcOffset {
+///         let summer_start = utc_datetime!(2021-03-28 1:00);
+///         let summer_end = utc_datetime!(2021-10-31 1:00);
+///         if datetime >= summer_start && datetime < summer_end {
+///             offset!(+2)
+///         } else {
+///             offset!(+1)
+///         }
+///     }
+/// }
+///
+/// assert_eq!(
+///     datetime!(2021-07-01 12:00 UTC).to_timezone(&Cet2021),
+///     datetime!(2021-07-01 14:00 +2),
+/// );
+/// assert_eq!(
+///     datetime!(2021-01-01 12:00).assume_timezone(&Cet2021),
+///     LocalResult::Unique(datetime!(2021-01-01 12:00 +1)),
+/// );
+/// assert_eq!(
+///     datetime!(2021-03-28 2:30).assume_timezone(&Cet2021),
+///     LocalResult::Gap,
+/// );
+/// assert_eq!(
+///     datetime!(2021-10-31 2:30).assume_timezone(&Cet2021),
+///     LocalResult::Ambiguous(
+///         datetime!(2021-10-31 2:30 +2),
+///         datetime!(2021-10-31 2:30 +1),
+///     ),
+/// );
+/// ```
+pub trait TimeZone {
+    /// The offset from UTC in effect at the provided instant.
+    fn offset_at(&self, datetime: UtcDateTime) -> UtcOffset;
+}
+
+impl<T: TimeZone + ?Sized> TimeZone for &T {
+    fn offset_at(&self, datetime: UtcDateTime) -> UtcOffset {
+        (**self).offset_at(datetime)
+    }
+}
+
+/// A fixed offset is a time zone whose offset never changes.
+impl TimeZone for UtcOffset {
+    fn offset_at(&self, _: UtcDateTime) -> UtcOffset {
+        *self
+    }
+}
+
+/// A time zone that observes daylight saving time according to fixed rules, such as "from the
+/// second Sunday in March until the first Sunday in November".
+///
+/// No time zone data is required, so this can be constructed in `const` contexts and is available
+/// without the standard library. Historical changes to a time zone's rules are not taken into
+/// account; the current rules are applied to all years.
+///
+/// ```rust
+/// # use time::time_zone::{DstRules, SimpleTz, TransitionRule};
+/// # use time_macros::{datetime, offset, time};
+/// # use time::{Month, Weekday};
+/// assert_eq!(
+///     datetime!(2021-07-01 12:00 UTC).to_timezone(&SimpleTz::US_EASTERN),
+///     datetime!(2021-07-01 8:00 -4),
+/// );
+///
+/// // Australian Eastern Time, which observes daylight saving time from the first Sunday in October
+/// // until the first Sunday in April.
+/// const AUSTRALIA_EASTERN: SimpleTz = SimpleTz::new(
+///     offset!(+10),
+///     offset!(+11),
+///     DstRules::new(
+///         TransitionRule::local(Month::October, 1, Weekday::Sunday, time!(2:00)),
+///         TransitionRule::local(Month::April, 1, Weekday::Sunday, time!(3:00)),
+///     ),
+/// );
+/// assert_eq!(
+///     datetime!(2021-01-01 0:00 UTC).to_timezone(&AUSTRALIA_EASTERN),
+///     datetime!(2021-01-01 11:00 +11),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SimpleTz {
+    /// The offset outside of daylight saving time.
+    standard: UtcOffset,
+    /// The offset during daylight saving time.
+    daylight: UtcOffset,
+    /// When daylight saving time starts and ends.
+    rules: DstRules,
+}
+
+impl SimpleTz {
+    /// Eastern Time in the United States and Canada.
+    pub const US_EASTERN: Self = Self::new(hours(-5), hours(-4), DstRules::US);
+    /// Central Time in the United States and Canada.
+    pub const US_CENTRAL: Self = Self::new(hours(-6), hours(-5), DstRules::US);
+    /// Mountain Time in the United States and Canada.
+    pub const US_MOUNTAIN: Self = Self::new(hours(-7), hours(-6), DstRules::US);
+    /// Pacific Time in the United States and Canada.
+    pub const US_PACIFIC: Self = Self::new(hours(-8), hours(-7), DstRules::US);
+    /// Western European Time, as observed in the United Kingdom, Ireland, and Portugal.
+    pub const EU_WESTERN: Self = Self::new(hours(0), hours(1), DstRules::EU);
+    /// Central European Time, as observed in most of the European Union.
+    pub const EU_CENTRAL: Self = Self::new(hours(1), hours(2), DstRules::EU);
+    /// Eastern European Time, as observed in Finland, Greece, and Romania among others.
+    pub const EU_EASTERN: Self = Self::new(hours(2), hours(3), DstRules::EU);
+
+    /// Create a time zone that uses the `daylight` offset between the start and end of daylight
+    /// saving time, and the `standard` offset otherwise.
+    pub const fn new(standard: UtcOffset, daylight: UtcOffset, rules: DstRules) -> Self {
+        Self {
+            standard,
+            daylight,
+            rules,
+        }
+    }
+
+    /// Get the offset outside of daylight saving time.
+    pub const fn standard(self) -> UtcOffset {
+        self.standard
+    }
+
+    /// Get the offset during daylight saving time.
+    pub const fn daylight(self) -> UtcOffset {
+        self.daylight
+    }
+
+    /// Get the rules for when daylight saving time starts and ends.
+    pub const fn rules(self) -> DstRules {
+        self.rules
+    }
+}
+
+impl TimeZone for SimpleTz {
+    fn offset_at(&self, datetime: UtcDateTime) -> UtcOffset {
+        let year = datetime.year();
+        let (Some(start), Some(end)) = (
+            self.rules.start.instant(year, self.standard),
+            self.rules.end.instant(year, self.daylight),
+        ) else {
+            return self.standard;
+        };
+
+        // In the southern hemisphere, daylight saving time spans the end of the year.
+        let is_daylight = if start <= end {
+            start <= datetime && datetime < end
+        } else {
+            datetime < end || start <= datetime
+        };
+
+        if is_daylight {
+            self.daylight
+        } else {
+            self.standard
+        }
+    }
+}
+
+/// When daylight saving time starts and ends each year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DstRules {
+    /// The transition from standard time to daylight saving time.
+    start: TransitionRule,
+    /// The transition from daylight saving time to standard time.
+    end: TransitionRule,
+}
+
+impl DstRules {
+    /// The rules used in the United States and Canada: from 2:00 local time on the second Sunday
+    /// in March until 2:00 local time on the first Sunday in November.
+    pub const US: Self = Self::new(
+        TransitionRule::local(Month::March, 2, Weekday::Sunday, hms(2)),
+        TransitionRule::local(Month::November, 1, Weekday::Sunday, hms(2)),
+    );
+    /// The rules used in the European Union and United Kingdom: from 1:00 UTC on the last Sunday
+    /// in March until 1:00 UTC on the last Sunday in October.
+    pub const EU: Self = Self::new(
+        TransitionRule::utc(Month::March, 5, Weekday::Sunday, hms(1)),
+        TransitionRule::utc(Month::October, 5, Weekday::Sunday, hms(1)),
+    );
+
+    /// Create rules where daylight saving time begins at `start` and ends at `end`.
+    pub const fn new(start: TransitionRule, end: TransitionRule) -> Self {
+        Self { start, end }
+    }
+
+    /// Get the transition from standard time to daylight saving time.
+    pub const fn start(self) -> TransitionRule {
+        self.start
+    }
+
+    /// Get the transition from daylight saving time to standard time.
+    pub const fn end(self) -> TransitionRule {
+        self.end
+    }
+}
+
+/// A yearly transition between standard and daylight saving time, occurring on the nth weekday of
+/// a month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TransitionRule {
+    /// The month the transition occurs in.
+    month: Month,
+    /// Which occurrence of the weekday in the month the transition occurs on. `5` indicates the
+    /// last occurrence.
+    week: u8,
+    /// The weekday the transition occurs on.
+    weekday: Weekday,
+    /// The time of day the transition occurs at.
+    time: Time,
+    /// Whether `time` is in UTC rather than in the offset in effect before the transition.
+    is_utc: bool,
+}
+
+impl TransitionRule {
+    /// Create a rule for a transition on the `week`th `weekday` of `month`, at `time` in the local
+    /// time in effect before the transition. A `week` of `5` indicates the last occurrence of the
+    /// weekday in the month.
+    ///
+    /// # Panics
+    ///
+    /// This may panic if `week` is not in the range `1..=5`.
+    pub const fn local(month: Month, week: u8, weekday: Weekday, time: Time) -> Self {
+        assert!(week >= 1 && week <= 5, "week must be in the range 1..=5");
+        Self {
+            month,
+            week,
+            weekday,
+            time,
+            is_utc: false,
+        }
+    }
+
+    /// Create a rule for a transition on the `week`th `weekday` of `month`, at `time` in UTC. A
+    /// `week` of `5` indicates the last occurrence of the weekday in the month.
+    ///
+    /// # Panics
+    ///
+    /// This may panic if `week` is not in the range `1..=5`.
+    pub const fn utc(month: Month, week: u8, weekday: Weekday, time: Time) -> Self {
+        assert!(week >= 1 && week <= 5, "week must be in the range 1..=5");
+        Self {
+            month,
+            week,
+            weekday,
+            time,
+            is_utc: true,
+        }
+    }
+
+    /// Get the month the transition occurs in.
+    pub const fn month(self) -> Month {
+        self.month
+    }
+
+    /// Get which occurrence of the weekday in the month the transition occurs on. `5` indicates
+    /// the last occurrence.
+    pub const fn week(self) -> u8 {
+        self.week
+    }
+
+    /// Get the weekday the transition occurs on.
+    pub const fn weekday(self) -> Weekday {
+        self.weekday
+    }
+
+    /// Get the time of day the transition occurs at.
+    pub const fn time(self) -> Time {
+        self.time
+    }
+
+    /// Whether the time of the transition is in UTC rather than local time.
+    pub const fn is_utc(self) -> bool {
+        self.is_utc
+    }
+
+    /// The instant the transition occurs in the given year, where `offset` is the offset in effect
+    /// before the transition.
+    fn instant(self, year: i32, offset: UtcOffset) -> Option<UtcDateTime> {
+        let first = Date::from_calendar_date(year, self.month, 1).ok()?;
+        let days_until_weekday = (self.weekday.number_days_from_monday() + 7
+            - first.weekday().number_days_from_monday())
+            % 7;
+        let mut day = 1 + days_until_weekday + 7 * (self.week - 1);
+        if day > self.month.length(year) {
+            day -= 7;
+        }
+
+        let datetime = Date::from_calendar_date(year, self.month, day)
+            .ok()?
+            .with_time(self.time);
+        if self.is_utc {
+            Some(datetime.as_utc())
+        } else {
+            datetime.assume_offset(offset).checked_to_utc()
+        }
+    }
+}
+
+/// Create an offset of a whole number of hours for use in constants.
+const fn hours(hours: i8) -> UtcOffset {
+    match UtcOffset::from_hms(hours, 0, 0) {
+        Ok(offset) => offset,
+        Err(_) => panic!("offset must be in range"),
+    }
+}
+
+/// Create a time at the start of the hour for use in constants.
+const fn hms(hour: u8) -> Time {
+    match Time::from_hms(hour, 0, 0) {
+        Ok(time) => time,
+        Err(_) => panic!("time must be in range"),
+    }
+}
+
+/// The result of interpreting a local date-time in a [`TimeZone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalResult {
+    /// The local date-time occurs exactly once.
+    Unique(OffsetDateTime),
+    /// The local date-time occurs twice, such as when clocks are turned back. The earlier instant
+    /// is first.
+    Ambiguous(OffsetDateTime, OffsetDateTime),
+    /// The local date-time does not occur, such as when clocks are turned forward.
+    Gap,
+}
+
+impl LocalResult {
+    /// Obtain the date-time if it is unique, returning `None` otherwise.
+    pub const fn unique(self) -> Option<OffsetDateTime> {
+        match self {
+            Self::Unique(datetime) => Some(datetime),
+            Self::Ambiguous(..) | Self::Gap => None,
+        }
+    }
+
+    /// Obtain the earliest instant the local date-time refers to, returning `None` if there is a
+    /// gap.
+    pub const fn earliest(self) -> Option<OffsetDateTime> {
+        match self {
+            Self::Unique(datetime) | Self::Ambiguous(datetime, _) => Some(datetime),
+            Self::Gap => None,
+        }
+    }
+
+    /// Obtain the latest instant the local date-time refers to, returning `None` if there is a gap.
+    pub const fn latest(self) -> Option<OffsetDateTime> {
+        match self {
+            Self::Unique(datetime) | Self::Ambiguous(_, datetime) => Some(datetime),
+            Self::Gap => None,
+        }
+    }
+}
+
+/// Determine the instants at which the local date-time occurs in the time zone.
+pub(crate) fn resolve_local(
+    datetime: PrimitiveDateTime,
+    time_zone: &(impl TimeZone + ?Sized),
+) -> LocalResult {
+    // As offsets are less than 26 hours, any transition affecting the local date-time is between
+    // these two instants.
+    let offset_before = time_zone.offset_at(datetime.saturating_sub(Duration::days(2)).as_utc());
+    let offset_after = time_zone.offset_at(datetime.saturating_add(Duration::days(2)).as_utc());
+
+    // The local date-time is valid in an offset if the time zone uses that offset at the resulting
+    // instant.
+    let in_offset = |offset: UtcOffset| {
+        let candidate = datetime.assume_offset(offset);
+        match candidate.checked_to_utc() {
+            Some(utc) if time_zone.offset_at(utc) == offset => Some(candidate),
+            _ => None,
+        }
+    };
+
+    let before = in_offset(offset_before);
+    let after = if offset_after == offset_before {
+        None
+    } else {
+        in_offset(offset_after)
+    };
+
+    match (before, after) {
+        (Some(before), Some(after)) if before <= after => LocalResult::Ambiguous(before, after),
+        (Some(before), Some(after)) => LocalResult::Ambiguous(after, before),
+        (Some(datetime), None) | (None, Some(datetime)) => LocalResult::Unique(datetime),
+        (None, None) => LocalResult::Gap,
+    }
+}
@@ -0,0 +1,87 @@
+//! Timestamps for [`tracing-subscriber`](https://docs.rs/tracing-subscriber).
+//!
+//! [`Timestamp`] formats the current time using any [`Formattable`] description, and can be
+//! passed directly to
+//! [`with_timer`](https://docs.rs/tracing-subscriber/latest/tracing_subscriber/fmt/struct.SubscriberBuilder.html#method.with_timer).
+//!
+//! ```rust
+//! # use time::format_description::well_known::Rfc3339;
+//! # use time::tracing::Timestamp;
+//! # use time_macros::{format_description, offset};
+//! // RFC 3339 in UTC.
+//! let subscriber = tracing_subscriber::fmt()
+//!     .with_timer(Timestamp::utc(Rfc3339))
+//!     .finish();
+//! # drop(subscriber);
+//!
+//! // A custom format in a fixed offset.
+//! let subscriber = tracing_subscriber::fmt()
+//!     .with_timer(Timestamp::new(
+//!         format_description!("[hour]:[minute]:[second].[subsecond digits:3]"),
+//!         offset!(+2),
+//!     ))
+//!     .finish();
+//! # drop(subscriber);
+//! ```
+
+use core::fmt;
+
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::time::FormatTime;
+
+use crate::formatting::Formattable;
+use crate::{OffsetDateTime, UtcOffset};
+
+/// A [`FormatTime`] implementation that writes the current time using the provided format
+/// description.
+///
+/// If the current time cannot be formatted, such as when the description requires components
+/// that are not available, an error is returned to the subscriber.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timestamp<F> {
+    /// The format description used to write the time.
+    format: F,
+    /// The offset to which the current time is converted before formatting.
+    offset: UtcOffset,
+}
+
+impl<F: Formattable> Timestamp<F> {
+    /// Write the current time in the provided offset.
+    pub const fn new(format: F, offset: UtcOffset) -> Self {
+        Self { format, offset }
+    }
+
+    /// Write the current time in UTC.
+    pub const fn utc(format: F) -> Self {
+        Self::new(format, UtcOffset::UTC)
+    }
+
+    /// Write the current time in the system's local offset. The offset is determined once, when
+    /// this method is called, and is not updated afterwards.
+    ///
+    /// This fails under the same conditions as [`UtcOffset::current_local_offset`].
+    #[cfg(feature = "local-offset")]
+    pub fn local(format: F) -> Result<Self, crate::error::IndeterminateOffset> {
+        Ok(Self::new(format, UtcOffset::current_local_offset()?))
+    }
+
+    /// Obtain the format description used to write the time.
+    pub const fn format(&self) -> &F {
+        &self.format
+    }
+
+    /// Obtain the offset in which the time is written.
+    pub const fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+}
+
+impl<F: Formattable> FormatTime for Timestamp<F> {
+    fn format_time(&self, w: &mut Writer<'_>) -> fmt::Result {
+        let formatted = OffsetDateTime::now_utc()
+            .to_offset(self.offset)
+            .format(&self.format)
+            .map_err(|_| fmt::Error)?;
+        w.write_str(&formatted)
+    }
+}
@@ -23,7 +23,7 @@ use crate::internal_macros::{
     cascade, const_try, const_try_opt, div_floor, ensure_ranged, expect_opt,
 };
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{FromParsed, Parsable, ParserOptions};
 use crate::{
     error, util, Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
 };
@@ -114,7 +114,8 @@ impl UtcDateTime {
     pub const MAX: Self = Self::new(Date::MAX, Time::MAX);
 
     // region: constructors
-    /// Create a new `UtcDateTime` with the current date and time.
+    /// Create a new `UtcDateTime` with the current date and time. This is obtained from the global
+    /// [`Clock`](crate::clock::Clock), which is the system clock by default.
     ///
     /// ```rust
     /// # use time::UtcDateTime;
@@ -122,21 +123,87 @@ impl UtcDateTime {
     /// ```
     #[cfg(feature = "std")]
     pub fn now() -> Self {
-        #[cfg(all(
-            target_family = "wasm",
-            not(any(target_os = "emscripten", target_os = "wasi")),
-            feature = "wasm-bindgen"
-        ))]
-        {
-            js_sys::Date::new_0().into()
+        crate::clock::now_utc()
+    }
+
+    /// Create a new `UtcDateTime` from the provided [`SystemTime`], returning an error if the
+    /// value is outside the range supported by this crate. Unlike the [`From`] implementation,
+    /// this never panics. The full precision of the `SystemTime` is retained, which is 100
+    /// nanoseconds on Windows and one nanosecond on most other platforms.
+    ///
+    /// ```rust
+    /// # use std::time::{Duration, SystemTime};
+    /// # use time::UtcDateTime;
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     UtcDateTime::try_from_system_time(SystemTime::UNIX_EPOCH + Duration::from_secs(86_400)),
+    ///     Ok(utc_datetime!(1970-01-02 0:00))
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_from_system_time(system_time: SystemTime) -> Result<Self, error::ConversionRange> {
+        match system_time.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => Self::UNIX_EPOCH.checked_add_std(duration),
+            Err(err) => Self::UNIX_EPOCH.checked_sub_std(err.duration()),
         }
+        .ok_or(error::ConversionRange)
+    }
 
-        #[cfg(not(all(
-            target_family = "wasm",
-            not(any(target_os = "emscripten", target_os = "wasi")),
-            feature = "wasm-bindgen"
-        )))]
-        SystemTime::now().into()
+    /// Create a new `UtcDateTime` from the provided [`SystemTime`], saturating to
+    /// [`UtcDateTime::MIN`] or [`UtcDateTime::MAX`] if the value is outside the range supported by
+    /// this crate.
+    ///
+    /// ```rust
+    /// # use std::time::{Duration, SystemTime};
+    /// # use time::UtcDateTime;
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     UtcDateTime::saturating_from_system_time(SystemTime::UNIX_EPOCH),
+    ///     utc_datetime!(1970-01-01 0:00)
+    /// );
+    /// let far_future = SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs(1 << 62));
+    /// # if let Some(far_future) = far_future {
+    /// assert_eq!(
+    ///     UtcDateTime::saturating_from_system_time(far_future),
+    ///     UtcDateTime::MAX
+    /// );
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn saturating_from_system_time(system_time: SystemTime) -> Self {
+        Self::try_from_system_time(system_time).unwrap_or_else(|_| {
+            if system_time < SystemTime::UNIX_EPOCH {
+                Self::MIN
+            } else {
+                Self::MAX
+            }
+        })
+    }
+
+    /// Convert the `UtcDateTime` into a [`SystemTime`], returning an error if the value cannot be
+    /// represented. Unlike the [`From`] implementation, this never panics.
+    ///
+    /// The range of `SystemTime` is platform-specific. Notably, Windows cannot represent values
+    /// before 1601, and some platforms cannot represent any value before the Unix epoch. On
+    /// Windows, the value is also truncated to a multiple of 100 nanoseconds.
+    ///
+    /// ```rust
+    /// # use std::time::{Duration, SystemTime};
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     utc_datetime!(1970-01-02 0:00).try_to_system_time(),
+    ///     Ok(SystemTime::UNIX_EPOCH + Duration::from_secs(86_400))
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_to_system_time(self) -> Result<SystemTime, error::ConversionRange> {
+        let duration = self - Self::UNIX_EPOCH;
+        if duration.is_negative() {
+            SystemTime::UNIX_EPOCH.checked_sub(duration.unsigned_abs())
+        } else {
+            SystemTime::UNIX_EPOCH.checked_add(duration.unsigned_abs())
+        }
+        .ok_or(error::ConversionRange)
     }
 
     /// Create a new `UtcDateTime` from the provided [`Date`] and [`Time`].
@@ -155,6 +222,43 @@ impl UtcDateTime {
         }
     }
 
+    /// Create a new `UtcDateTime` from the Julian day and the provided [`Time`].
+    ///
+    /// ```rust
+    /// # use time::UtcDateTime;
+    /// # use time_macros::{time, utc_datetime};
+    /// assert_eq!(
+    ///     UtcDateTime::from_julian_day(2_451_545, time!(12:00)),
+    ///     Ok(utc_datetime!(2000-01-01 12:00)),
+    /// );
+    /// ```
+    #[doc(alias = "from_julian_day_with_time")]
+    pub const fn from_julian_day(
+        julian_day: i32,
+        time: Time,
+    ) -> Result<Self, error::ComponentRange> {
+        Ok(Self::from_primitive(const_try!(
+            PrimitiveDateTime::from_julian_day(julian_day, time)
+        )))
+    }
+
+    /// Create a new `UtcDateTime` from the fractional Julian date.
+    ///
+    /// Following the astronomical convention, each Julian date begins at noon. See
+    /// [`PrimitiveDateTime::from_julian_date`] for details on precision.
+    ///
+    /// ```rust
+    /// # use time::UtcDateTime;
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     UtcDateTime::from_julian_date(2_451_545.0),
+    ///     Ok(utc_datetime!(2000-01-01 12:00)),
+    /// );
+    /// ```
+    pub fn from_julian_date(julian_date: f64) -> Result<Self, error::ComponentRange> {
+        PrimitiveDateTime::from_julian_date(julian_date).map(Self::from_primitive)
+    }
+
     /// Create a new `UtcDateTime` from the [`PrimitiveDateTime`], assuming that the latter is UTC.
     pub(crate) const fn from_primitive(date_time: PrimitiveDateTime) -> Self {
         Self { inner: date_time }
@@ -244,6 +348,49 @@ impl UtcDateTime {
             },
         ))
     }
+
+    /// Construct an `UtcDateTime` from the provided Unix timestamp (in milliseconds).
+    ///
+    /// This is commonly used when interoperating with JavaScript's `Date.now()` or Java's
+    /// `System.currentTimeMillis()`.
+    ///
+    /// ```rust
+    /// # use time::UtcDateTime;
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     UtcDateTime::from_unix_timestamp_millis(0),
+    ///     Ok(UtcDateTime::UNIX_EPOCH),
+    /// );
+    /// assert_eq!(
+    ///     UtcDateTime::from_unix_timestamp_millis(1_546_300_800_123),
+    ///     Ok(utc_datetime!(2019-01-01 0:00:00.123)),
+    /// );
+    /// assert_eq!(
+    ///     UtcDateTime::from_unix_timestamp_millis(-1),
+    ///     Ok(utc_datetime!(1969-12-31 23:59:59.999)),
+    /// );
+    /// ```
+    pub const fn from_unix_timestamp_millis(timestamp: i64) -> Result<Self, error::ComponentRange> {
+        Self::from_unix_timestamp_nanos(timestamp as i128 * Nanosecond::per(Millisecond) as i128)
+    }
+
+    /// Construct an `UtcDateTime` from the provided Unix timestamp (in microseconds).
+    ///
+    /// ```rust
+    /// # use time::UtcDateTime;
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     UtcDateTime::from_unix_timestamp_micros(0),
+    ///     Ok(UtcDateTime::UNIX_EPOCH),
+    /// );
+    /// assert_eq!(
+    ///     UtcDateTime::from_unix_timestamp_micros(1_546_300_800_123_456),
+    ///     Ok(utc_datetime!(2019-01-01 0:00:00.123_456)),
+    /// );
+    /// ```
+    pub const fn from_unix_timestamp_micros(timestamp: i64) -> Result<Self, error::ComponentRange> {
+        Self::from_unix_timestamp_nanos(timestamp as i128 * Nanosecond::per(Microsecond) as i128)
+    }
     // endregion constructors
 
     // region: to_offset
@@ -381,6 +528,46 @@ impl UtcDateTime {
         self.unix_timestamp() as i128 * Nanosecond::per(Second) as i128 + self.nanosecond() as i128
     }
 
+    /// Get the Unix timestamp in milliseconds. Any sub-millisecond component is truncated toward
+    /// negative infinity, consistent with [`unix_timestamp`](Self::unix_timestamp).
+    ///
+    /// ```rust
+    /// use time_macros::utc_datetime;
+    /// assert_eq!(utc_datetime!(1970-01-01 0:00).unix_timestamp_millis(), 0);
+    /// assert_eq!(
+    ///     utc_datetime!(2019-01-01 0:00:00.123_456).unix_timestamp_millis(),
+    ///     1_546_300_800_123,
+    /// );
+    /// assert_eq!(
+    ///     utc_datetime!(1969-12-31 23:59:59.999_9).unix_timestamp_millis(),
+    ///     -1,
+    /// );
+    /// ```
+    pub const fn unix_timestamp_millis(self) -> i64 {
+        self.unix_timestamp() * Millisecond::per(Second) as i64
+            + (self.nanosecond() / Nanosecond::per(Millisecond)) as i64
+    }
+
+    /// Get the Unix timestamp in microseconds. Any sub-microsecond component is truncated toward
+    /// negative infinity, consistent with [`unix_timestamp`](Self::unix_timestamp).
+    ///
+    /// Unlike [`unix_timestamp_millis`](Self::unix_timestamp_millis), the value is returned as an
+    /// `i128`, as it does not fit in an `i64` for all years when the `large-dates` feature is
+    /// enabled.
+    ///
+    /// ```rust
+    /// use time_macros::utc_datetime;
+    /// assert_eq!(utc_datetime!(1970-01-01 0:00).unix_timestamp_micros(), 0);
+    /// assert_eq!(
+    ///     utc_datetime!(2019-01-01 0:00:00.123_456_789).unix_timestamp_micros(),
+    ///     1_546_300_800_123_456,
+    /// );
+    /// ```
+    pub const fn unix_timestamp_micros(self) -> i128 {
+        self.unix_timestamp() as i128 * Microsecond::per(Second) as i128
+            + (self.nanosecond() / Nanosecond::per(Microsecond) as u32) as i128
+    }
+
     /// Get the [`Date`] component of the `UtcDateTime`.
     ///
     /// ```rust
@@ -426,6 +613,19 @@ impl UtcDateTime {
         self.date().month()
     }
 
+    /// Get the zero-based month of the date.
+    ///
+    /// The returned value will always be in the range `0..=11`.
+    ///
+    /// ```rust
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(utc_datetime!(2019-01-01 0:00).month0(), 0);
+    /// assert_eq!(utc_datetime!(2019-12-31 0:00).month0(), 11);
+    /// ```
+    pub const fn month0(self) -> u8 {
+        self.date().month0()
+    }
+
     /// Get the day of the date.
     ///
     /// The returned value will always be in the range `1..=31`.
@@ -452,6 +652,30 @@ impl UtcDateTime {
         self.date().ordinal()
     }
 
+    /// Get the zero-based day of the year.
+    ///
+    /// The returned value will always be in the range `0..=365` (`0..=364` for common years).
+    ///
+    /// ```rust
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(utc_datetime!(2019-01-01 0:00).ordinal0(), 0);
+    /// assert_eq!(utc_datetime!(2019-12-31 0:00).ordinal0(), 364);
+    /// ```
+    pub const fn ordinal0(self) -> u16 {
+        self.date().ordinal0()
+    }
+
+    /// Whether the year of the date is a leap year in the proleptic Gregorian calendar.
+    ///
+    /// ```rust
+    /// # use time_macros::utc_datetime;
+    /// assert!(utc_datetime!(2020-06-15 0:00).is_leap_year());
+    /// assert!(!utc_datetime!(2019-06-15 0:00).is_leap_year());
+    /// ```
+    pub const fn is_leap_year(self) -> bool {
+        self.date().is_leap_year()
+    }
+
     /// Get the ISO week number.
     ///
     /// The returned value will always be in the range `1..=53`.
@@ -589,6 +813,20 @@ impl UtcDateTime {
     pub const fn to_julian_day(self) -> i32 {
         self.date().to_julian_day()
     }
+
+    /// Get the fractional Julian date, taking the time into account.
+    ///
+    /// Following the astronomical convention, each Julian date begins at noon. See
+    /// [`PrimitiveDateTime::to_julian_date`] for details on precision.
+    ///
+    /// ```rust
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(utc_datetime!(2000-01-01 12:00).to_julian_date(), 2_451_545.0);
+    /// assert_eq!(utc_datetime!(2000-01-01 0:00).to_julian_date(), 2_451_544.5);
+    /// ```
+    pub fn to_julian_date(self) -> f64 {
+        self.inner.to_julian_date()
+    }
     // endregion date getters
 
     // region: time getters
@@ -603,6 +841,17 @@ impl UtcDateTime {
         self.time().as_hms()
     }
 
+    /// Get the clock hour and minute.
+    ///
+    /// ```rust
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(utc_datetime!(2020-01-01 0:00:00).as_hm(), (0, 0));
+    /// assert_eq!(utc_datetime!(2020-01-01 23:59:59).as_hm(), (23, 59));
+    /// ```
+    pub const fn as_hm(self) -> (u8, u8) {
+        self.time().as_hm()
+    }
+
     /// Get the clock hour, minute, second, and millisecond.
     ///
     /// ```rust
@@ -767,6 +1016,40 @@ impl UtcDateTime {
             .inner
             .checked_sub(duration))))
     }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{UtcDateTime, ext::NumericalStdDuration};
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(UtcDateTime::MAX.checked_add_std(1.std_days()), None);
+    /// assert_eq!(
+    ///     utc_datetime!(2019 - 11 - 25 15:30).checked_add_std(27.std_hours()),
+    ///     Some(utc_datetime!(2019 - 11 - 26 18:30))
+    /// );
+    /// ```
+    pub const fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        Some(Self::from_primitive(const_try_opt!(self
+            .inner
+            .checked_add_std(duration))))
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{UtcDateTime, ext::NumericalStdDuration};
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(UtcDateTime::MIN.checked_sub_std(1.std_days()), None);
+    /// assert_eq!(
+    ///     utc_datetime!(2019 - 11 - 25 15:30).checked_sub_std(27.std_hours()),
+    ///     Some(utc_datetime!(2019 - 11 - 24 12:30))
+    /// );
+    /// ```
+    pub const fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        Some(Self::from_primitive(const_try_opt!(self
+            .inner
+            .checked_sub_std(duration))))
+    }
     // endregion checked arithmetic
 
     // region: saturating arithmetic
@@ -814,6 +1097,57 @@ impl UtcDateTime {
         Self::from_primitive(self.inner.saturating_sub(duration))
     }
     // endregion saturating arithmetic
+
+    // region: rounding
+    /// Truncate the time to the previous multiple of the [`Duration`] since midnight, preserving
+    /// the date.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     utc_datetime!(2019-01-01 12:34:56.789).truncate_to(1.seconds()),
+    ///     utc_datetime!(2019-01-01 12:34:56)
+    /// );
+    /// assert_eq!(
+    ///     utc_datetime!(2019-01-01 12:34:56).truncate_to(15.minutes()),
+    ///     utc_datetime!(2019-01-01 12:30)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `duration` is not positive.
+    #[must_use = "This method does not mutate the original `UtcDateTime`."]
+    pub const fn truncate_to(self, duration: Duration) -> Self {
+        Self::from_primitive(self.inner.truncate_to(duration))
+    }
+
+    /// Round the time to the nearest multiple of the [`Duration`] since midnight. Values exactly
+    /// halfway between two multiples are rounded up. Midnight of the following day is treated as
+    /// the multiple after the last one in the day.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     utc_datetime!(2019-01-01 12:37:30).round_to(15.minutes()),
+    ///     utc_datetime!(2019-01-01 12:45)
+    /// );
+    /// assert_eq!(
+    ///     utc_datetime!(2019-12-31 23:59:30).round_to(1.minutes()),
+    ///     utc_datetime!(2020-01-01 0:00)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `duration` is not positive or if the resulting value is out of range.
+    #[must_use = "This method does not mutate the original `UtcDateTime`."]
+    pub const fn round_to(self, duration: Duration) -> Self {
+        Self::from_primitive(self.inner.round_to(duration))
+    }
+    // endregion rounding
 }
 
 // region: replacement
@@ -1042,12 +1376,15 @@ impl UtcDateTime {
         output: &mut impl io::Write,
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(
+        let result = format.format_into_buffered(
             output,
             Some(self.date()),
             Some(self.time()),
             Some(UtcOffset::UTC),
-        )
+        );
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_format(false, result.as_ref().ok().copied());
+        result
     }
 
     /// Format the `UtcDateTime` using the provided [format
@@ -1067,7 +1404,36 @@ impl UtcDateTime {
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(Some(self.date()), Some(self.time()), Some(UtcOffset::UTC))
+        let result = format.format(Some(self.date()), Some(self.time()), Some(UtcOffset::UTC));
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_format(true, result.as_ref().ok().map(String::len));
+        result
+    }
+
+    /// Determine the number of bytes the `UtcDateTime` would be formatted as using the provided
+    /// [format description](crate::format_description).
+    ///
+    /// The length is exact, so it can be used to allocate a buffer of the correct size before
+    /// calling [`format_into`](Self::format_into).
+    ///
+    /// The length is determined by formatting the value and discarding the output, so calling this
+    /// costs as much as formatting.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::utc_datetime;
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]:[second]")?;
+    /// assert_eq!(
+    ///     utc_datetime!(2020-01-02 03:04:05).formatted_len(&format)?,
+    ///     "2020-01-02 03:04:05".len()
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn formatted_len(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.formatted_len(Some(self.date()), Some(self.time()), Some(UtcOffset::UTC))
     }
 }
 
@@ -1087,13 +1453,68 @@ impl UtcDateTime {
     /// );
     /// # Ok::<_, time::Error>(())
     /// ```
+    ///
+    /// An error is returned if the value is outside the supported range once converted to UTC.
+    ///
+    #[cfg_attr(
+        all(not(feature = "large-dates"), not(time_no_names)),
+        doc = "```rust"
+    )]
+    #[cfg_attr(
+        any(feature = "large-dates", time_no_names),
+        doc = "```rust,ignore"
+    )]
+    /// # use time::error::{Parse, TryFromParsed};
+    /// # use time::format_description::well_known::{Rfc2822, Rfc3339Lenient};
+    /// # use time::UtcDateTime;
+    /// // Assuming `large-dates` feature is disabled.
+    /// assert!(matches!(
+    ///     UtcDateTime::parse("9999-12-31T23:59:59-01:00", &Rfc3339Lenient::DEFAULT),
+    ///     Err(Parse::TryFromParsed(TryFromParsed::ComponentRange(_)))
+    /// ));
+    /// assert!(matches!(
+    ///     UtcDateTime::parse("Fri, 31 Dec 9999 23:59:59 -0100", &Rfc2822),
+    ///     Err(Parse::TryFromParsed(TryFromParsed::ComponentRange(_)))
+    /// ));
+    /// ```
     pub fn parse(
         input: &str,
         description: &(impl Parsable + ?Sized),
     ) -> Result<Self, error::Parse> {
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_parse(input.len());
         description.parse_utc_date_time(input.as_bytes())
     }
 
+    /// Parse an `UtcDateTime` from the input using the provided [format
+    /// description](crate::format_description), subject to the provided [`ParserOptions`]. With
+    /// limits set, this should be preferred over [`UtcDateTime::parse`] when the input is
+    /// untrusted.
+    ///
+    /// ```rust
+    /// # use time::parsing::ParserOptions;
+    /// # use time::UtcDateTime;
+    /// # use time_macros::{utc_datetime, format_description};
+    /// let format = format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    /// let options = ParserOptions::DEFAULT.with_max_input_len(19);
+    /// assert_eq!(
+    ///     UtcDateTime::parse_with_options("2020-01-02 03:04:05", &format, options)?,
+    ///     utc_datetime!(2020-01-02 03:04:05)
+    /// );
+    /// assert!(UtcDateTime::parse_with_options("2020-01-02 03:04:05 ", &format, options).is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_options(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+        options: ParserOptions,
+    ) -> Result<Self, error::Parse> {
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_parse(input.len());
+        let parsed = description.parse_with_options(input.as_bytes(), options)?;
+        Ok(Self::from_parsed(parsed, options)?)
+    }
+
     /// A helper method to check if the `UtcDateTime` is a valid representation of a leap second.
     /// Leap seconds, when parsed, are represented as the preceding nanosecond. However, leap
     /// seconds can only occur as the last second of a month UTC.
@@ -1335,6 +1756,11 @@ impl PartialOrd<UtcDateTime> for OffsetDateTime {
 
 #[cfg(feature = "std")]
 impl From<SystemTime> for UtcDateTime {
+    /// # Panics
+    ///
+    /// This may panic if the value is outside the range supported by this crate. Use
+    /// [`UtcDateTime::try_from_system_time`] or [`UtcDateTime::saturating_from_system_time`] to
+    /// avoid this.
     fn from(system_time: SystemTime) -> Self {
         match system_time.duration_since(SystemTime::UNIX_EPOCH) {
             Ok(duration) => Self::UNIX_EPOCH + duration,
@@ -1345,6 +1771,10 @@ impl From<SystemTime> for UtcDateTime {
 
 #[cfg(feature = "std")]
 impl From<UtcDateTime> for SystemTime {
+    /// # Panics
+    ///
+    /// This may panic if the value cannot be represented by a `SystemTime`, the range of which is
+    /// platform-specific. Use [`UtcDateTime::try_to_system_time`] to avoid this.
     fn from(datetime: UtcDateTime) -> Self {
         let duration = datetime - UtcDateTime::UNIX_EPOCH;
 
@@ -3,11 +3,14 @@
 #[cfg(feature = "formatting")]
 use alloc::string::String;
 use core::fmt;
+use core::iter::FusedIterator;
 use core::ops::Neg;
+use core::str::FromStr;
 #[cfg(feature = "formatting")]
 use std::io;
 
-use deranged::{RangedI32, RangedI8};
+use deranged::{RangedI16, RangedI32, RangedI8};
+use num_conv::prelude::*;
 use powerfmt::ext::FormatterExt;
 use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
 
@@ -17,7 +20,7 @@ use crate::error;
 use crate::formatting::Formattable;
 use crate::internal_macros::ensure_ranged;
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{FromParsed, Parsable, ParserOptions};
 #[cfg(feature = "local-offset")]
 use crate::sys::local_offset_at;
 #[cfg(feature = "local-offset")]
@@ -42,6 +45,11 @@ type WholeSeconds = RangedI32<
             + Seconds::MAX.get() as i32
     },
 >;
+/// The type capable of storing the range of whole minutes that a `UtcOffset` can encompass.
+type WholeMinutes = RangedI16<
+    { Hours::MIN.get() as i16 * Minute::per(Hour) as i16 + Minutes::MIN.get() as i16 },
+    { Hours::MAX.get() as i16 * Minute::per(Hour) as i16 + Minutes::MAX.get() as i16 },
+>;
 
 /// An offset from UTC.
 ///
@@ -65,6 +73,24 @@ impl UtcOffset {
     /// ```
     pub const UTC: Self = Self::from_whole_seconds_ranged(WholeSeconds::new_static::<0>());
 
+    /// The smallest valid `UtcOffset`, which is 25 hours, 59 minutes, and 59 seconds behind UTC.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// # use time_macros::offset;
+    /// assert_eq!(UtcOffset::MIN, offset!(-25:59:59));
+    /// ```
+    pub const MIN: Self = Self::from_whole_seconds_ranged(WholeSeconds::MIN);
+
+    /// The largest valid `UtcOffset`, which is 25 hours, 59 minutes, and 59 seconds ahead of UTC.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// # use time_macros::offset;
+    /// assert_eq!(UtcOffset::MAX, offset!(+25:59:59));
+    /// ```
+    pub const MAX: Self = Self::from_whole_seconds_ranged(WholeSeconds::MAX);
+
     // region: constructors
     /// Create a `UtcOffset` representing an offset of the hours, minutes, and seconds provided, the
     /// validity of which must be guaranteed by the caller. All three parameters must have the same
@@ -178,12 +204,50 @@ impl UtcOffset {
     /// assert_eq!(UtcOffset::from_whole_seconds(3_723)?.as_hms(), (1, 2, 3));
     /// # Ok::<_, time::Error>(())
     /// ```
+    ///
+    /// As this method is `const`, it can be used to build lookup tables of offsets at compile
+    /// time. An out-of-range value is then a compile error.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// const fn offset(seconds: i32) -> UtcOffset {
+    ///     match UtcOffset::from_whole_seconds(seconds) {
+    ///         Ok(offset) => offset,
+    ///         Err(_) => panic!("offset out of range"),
+    ///     }
+    /// }
+    ///
+    /// const OFFSETS: [UtcOffset; 3] = [offset(-18_000), offset(0), offset(19_800)];
+    /// assert_eq!(OFFSETS[2].as_hms(), (5, 30, 0));
+    /// ```
     pub const fn from_whole_seconds(seconds: i32) -> Result<Self, error::ComponentRange> {
         Ok(Self::from_whole_seconds_ranged(
             ensure_ranged!(WholeSeconds: seconds),
         ))
     }
 
+    /// Create a `UtcOffset` representing an offset by the number of minutes provided. This is the
+    /// inverse of [`UtcOffset::whole_minutes`] for offsets without a seconds component.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::from_whole_minutes(330)?.as_hms(), (5, 30, 0));
+    /// assert_eq!(UtcOffset::from_whole_minutes(-62)?.as_hms(), (-1, -2, 0));
+    /// assert!(UtcOffset::from_whole_minutes(1_560).is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn from_whole_minutes(minutes: i16) -> Result<Self, error::ComponentRange> {
+        let minutes = ensure_ranged!(WholeMinutes: minutes).get();
+        // Safety: The minutes are in range and have the same sign as the hours.
+        Ok(unsafe {
+            Self::__from_hms_unchecked(
+                (minutes / Minute::per(Hour) as i16) as _,
+                (minutes % Minute::per(Hour) as i16) as _,
+                0,
+            )
+        })
+    }
+
     /// Create a `UtcOffset` representing an offset by the number of seconds provided.
     // ignore because the function is crate-private
     /// ```rust,ignore
@@ -205,6 +269,34 @@ impl UtcOffset {
             )
         }
     }
+
+    /// Obtain an iterator over every `UtcOffset` that is a whole number of minutes, from the most
+    /// negative to the most positive.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// # use time_macros::offset;
+    /// let mut offsets = UtcOffset::all_whole_minutes();
+    /// assert_eq!(offsets.len(), 3_119);
+    /// assert_eq!(offsets.next(), Some(offset!(-25:59)));
+    /// assert_eq!(offsets.next(), Some(offset!(-25:58)));
+    /// assert_eq!(offsets.next_back(), Some(offset!(+25:59)));
+    /// ```
+    pub fn all_whole_minutes(
+    ) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator + FusedIterator + Clone {
+        let max_minutes = Hours::MAX.get().extend::<i16>() * Minute::per(Hour) as i16
+            + Minutes::MAX.get().extend::<i16>();
+        (-max_minutes..=max_minutes).map(|minutes| {
+            // Safety: The minutes are in range and have the same sign as the hours.
+            unsafe {
+                Self::__from_hms_unchecked(
+                    (minutes / Minute::per(Hour) as i16).truncate(),
+                    (minutes % Minute::per(Hour) as i16).truncate(),
+                    0,
+                )
+            }
+        })
+    }
     // endregion constructors
 
     // region: getters
@@ -374,7 +466,10 @@ impl UtcOffset {
         output: &mut impl io::Write,
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(output, None, None, Some(self))
+        let result = format.format_into_buffered(output, None, None, Some(self));
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_format(false, result.as_ref().ok().copied());
+        result
     }
 
     /// Format the `UtcOffset` using the provided [format description](crate::format_description).
@@ -387,7 +482,33 @@ impl UtcOffset {
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(None, None, Some(self))
+        let result = format.format(None, None, Some(self));
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_format(true, result.as_ref().ok().map(String::len));
+        result
+    }
+
+    /// Determine the number of bytes the `UtcOffset` would be formatted as using the provided
+    /// [format description](crate::format_description).
+    ///
+    /// The length is exact, so it can be used to allocate a buffer of the correct size before
+    /// calling [`format_into`](Self::format_into).
+    ///
+    /// The length is determined by formatting the value and discarding the output, so calling this
+    /// costs as much as formatting.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// # use time_macros::offset;
+    /// let format = format_description::parse("[offset_hour sign:mandatory]:[offset_minute]")?;
+    /// assert_eq!(offset!(+1).formatted_len(&format)?, "+01:00".len());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn formatted_len(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.formatted_len(None, None, Some(self))
     }
 }
 
@@ -407,8 +528,38 @@ impl UtcOffset {
         input: &str,
         description: &(impl Parsable + ?Sized),
     ) -> Result<Self, error::Parse> {
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_parse(input.len());
         description.parse_offset(input.as_bytes())
     }
+
+    /// Parse an `UtcOffset` from the input using the provided [format
+    /// description](crate::format_description), subject to the provided [`ParserOptions`]. With
+    /// limits set, this should be preferred over [`UtcOffset::parse`] when the input is untrusted.
+    ///
+    /// ```rust
+    /// # use time::parsing::ParserOptions;
+    /// # use time::UtcOffset;
+    /// # use time_macros::{offset, format_description};
+    /// let format = format_description!("[offset_hour]:[offset_minute]");
+    /// let options = ParserOptions::DEFAULT.with_max_input_len(6);
+    /// assert_eq!(
+    ///     UtcOffset::parse_with_options("-03:42", &format, options)?,
+    ///     offset!(-3:42)
+    /// );
+    /// assert!(UtcOffset::parse_with_options("-03:42 ", &format, options).is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_options(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+        options: ParserOptions,
+    ) -> Result<Self, error::Parse> {
+        #[cfg(feature = "bench-hooks")]
+        crate::bench_hooks::record_parse(input.len());
+        let parsed = description.parse_with_options(input.as_bytes(), options)?;
+        Ok(Self::from_parsed(parsed, options)?)
+    }
 }
 
 mod private {
@@ -453,11 +604,80 @@ impl SmartDisplay for UtcOffset {
 }
 
 impl fmt::Display for UtcOffset {
+    /// Format the offset as `±HH:MM:SS`, such as `+01:02:03` or `-00:30:00`. A zero offset is
+    /// formatted as `+00:00:00`.
+    ///
+    /// This format is stable and is accepted by the [`FromStr`] implementation, so the output can
+    /// always be parsed back to the original value.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         SmartDisplay::fmt(self, f)
     }
 }
 
+impl FromStr for UtcOffset {
+    type Err = error::InvalidOffset;
+
+    /// Parse an offset from the form `±HH:MM:SS` produced by the [`Display`](fmt::Display)
+    /// implementation. The seconds or both the minutes and seconds may be omitted, in which case
+    /// they are zero. `UTC` and `Z` are also accepted, ignoring ASCII case, and represent a zero
+    /// offset.
+    ///
+    /// Each component must be exactly two digits. The accepted forms are stable.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// # use time_macros::offset;
+    /// assert_eq!("+01:02:03".parse(), Ok(offset!(+1:02:03)));
+    /// assert_eq!("-05:30".parse(), Ok(offset!(-5:30)));
+    /// assert_eq!("+09".parse(), Ok(offset!(+9)));
+    /// assert_eq!("UTC".parse(), Ok(UtcOffset::UTC));
+    /// assert_eq!("Z".parse(), Ok(UtcOffset::UTC));
+    /// assert!("+1:00".parse::<UtcOffset>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use error::InvalidOffset::InvalidSyntax;
+
+        /// Parse a component consisting of exactly two digits.
+        const fn two_digits(digits: [u8; 2]) -> Option<i8> {
+            match digits {
+                [tens @ b'0'..=b'9', ones @ b'0'..=b'9'] => {
+                    Some(((tens - b'0') * 10 + (ones - b'0')) as i8)
+                }
+                _ => None,
+            }
+        }
+
+        if s.eq_ignore_ascii_case("UTC") || s.eq_ignore_ascii_case("Z") {
+            return Ok(Self::UTC);
+        }
+
+        let (is_negative, rest) = match s.as_bytes() {
+            [b'+', rest @ ..] => (false, rest),
+            [b'-', rest @ ..] => (true, rest),
+            _ => return Err(InvalidSyntax),
+        };
+        let (hours, minutes, seconds) = match *rest {
+            [h1, h2] => (two_digits([h1, h2]), Some(0), Some(0)),
+            [h1, h2, b':', m1, m2] => (two_digits([h1, h2]), two_digits([m1, m2]), Some(0)),
+            [h1, h2, b':', m1, m2, b':', s1, s2] => (
+                two_digits([h1, h2]),
+                two_digits([m1, m2]),
+                two_digits([s1, s2]),
+            ),
+            _ => return Err(InvalidSyntax),
+        };
+        let (Some(hours), Some(minutes), Some(seconds)) = (hours, minutes, seconds) else {
+            return Err(InvalidSyntax);
+        };
+
+        Ok(if is_negative {
+            Self::from_hms(-hours, -minutes, -seconds)?
+        } else {
+            Self::from_hms(hours, minutes, seconds)?
+        })
+    }
+}
+
 impl fmt::Debug for UtcOffset {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(self, f)
@@ -1,7 +1,10 @@
 //! Utility functions, including updating time zone information.
 
+use core::fmt;
+
 pub use time_core::util::{days_in_year, is_leap_year, weeks_in_year};
 
+use crate::internal_macros::const_try_opt;
 use crate::Month;
 
 /// Whether to adjust the date, and in which direction. Useful when implementing arithmetic.
@@ -14,6 +17,51 @@ pub(crate) enum DateAdjustment {
     None,
 }
 
+impl DateAdjustment {
+    /// The number of days by which the date is adjusted.
+    pub(crate) const fn days(self) -> i8 {
+        match self {
+            Self::Previous => -1,
+            Self::Next => 1,
+            Self::None => 0,
+        }
+    }
+}
+
+/// A writer that discards its input, counting the number of characters written. This is used to
+/// determine the width of values whose formatted width is not otherwise known in advance.
+#[derive(Default)]
+pub(crate) struct WidthCounter(pub(crate) usize);
+
+impl fmt::Write for WidthCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// A value that is displayed by calling the wrapped function. Any padding requested by the
+/// formatter is ignored.
+pub(crate) struct DisplayFn<F>(pub(crate) F);
+
+impl<F: Fn(&mut dyn fmt::Write) -> fmt::Result> fmt::Display for DisplayFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// Get the English ordinal suffix of a number, such as "st" for 1 and "nd" for 22.
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+pub(crate) const fn ordinal_suffix(value: u8) -> &'static str {
+    match (value % 100, value % 10) {
+        (11..=13, _) => "th",
+        (_, 1) => "st",
+        (_, 2) => "nd",
+        (_, 3) => "rd",
+        _ => "th",
+    }
+}
+
 /// Get the number of days in the month of a given year.
 ///
 /// ```rust
@@ -39,6 +87,93 @@ pub const fn days_in_year_month(year: i32, month: Month) -> u8 {
     month.length(year)
 }
 
+/// Get the first leap year strictly after the provided year, or `None` if it is greater than
+/// `i32::MAX`.
+///
+/// ```rust
+/// # use time::util;
+/// assert_eq!(util::next_leap_year(2020), Some(2024));
+/// assert_eq!(util::next_leap_year(2021), Some(2024));
+/// assert_eq!(util::next_leap_year(2096), Some(2104));
+/// assert_eq!(util::next_leap_year(i32::MAX), None);
+/// ```
+pub const fn next_leap_year(year: i32) -> Option<i32> {
+    // Leap years are never more than eight years apart, so this loop is short.
+    let mut year = year;
+    loop {
+        year = const_try_opt!(year.checked_add(1));
+        if is_leap_year(year) {
+            return Some(year);
+        }
+    }
+}
+
+/// Get the last leap year strictly before the provided year, or `None` if it is less than
+/// `i32::MIN`.
+///
+/// ```rust
+/// # use time::util;
+/// assert_eq!(util::previous_leap_year(2024), Some(2020));
+/// assert_eq!(util::previous_leap_year(2023), Some(2020));
+/// assert_eq!(util::previous_leap_year(2104), Some(2096));
+/// assert_eq!(util::previous_leap_year(i32::MIN), None);
+/// ```
+pub const fn previous_leap_year(year: i32) -> Option<i32> {
+    // Leap years are never more than eight years apart, so this loop is short.
+    let mut year = year;
+    loop {
+        year = const_try_opt!(year.checked_sub(1));
+        if is_leap_year(year) {
+            return Some(year);
+        }
+    }
+}
+
+/// Convert a year of the proleptic Gregorian calendar to the year of the Holocene calendar, which is
+/// 10,000 greater. Returns `None` if the result is greater than `i32::MAX`.
+///
+/// Holocene years of recent dates have five digits, so constructing a [`Date`](crate::Date) with
+/// one requires the `large-dates` feature. For readability, the digits can be grouped when
+/// formatting using the `group_separator` modifier of the year.
+///
+/// ```rust
+/// # use time::util;
+/// assert_eq!(util::holocene_year(2024), Some(12_024));
+/// assert_eq!(util::holocene_year(-9999), Some(1));
+/// assert_eq!(util::holocene_year(i32::MAX), None);
+/// ```
+pub const fn holocene_year(year: i32) -> Option<i32> {
+    year.checked_add(10_000)
+}
+
+/// Convert a year of the Holocene calendar to the year of the proleptic Gregorian calendar, which
+/// is 10,000 less. Returns `None` if the result is less than `i32::MIN`.
+///
+/// ```rust
+/// # use time::util;
+/// assert_eq!(util::year_from_holocene(12_024), Some(2024));
+/// assert_eq!(util::year_from_holocene(1), Some(-9999));
+/// assert_eq!(util::year_from_holocene(i32::MIN), None);
+/// ```
+pub const fn year_from_holocene(year: i32) -> Option<i32> {
+    year.checked_sub(10_000)
+}
+
+/// Whether the `large-dates` feature is enabled. When it is, years between -999,999 and 999,999
+/// are supported. Otherwise, only years between -9999 and 9999 are supported.
+///
+/// As features are unified across a dependency graph, this may be `true` even if the feature was
+/// not requested by the caller.
+///
+/// ```rust
+/// # use time::{util, Date};
+/// let max_year = if util::is_large_dates_enabled() { 999_999 } else { 9999 };
+/// assert_eq!(Date::MAX.year(), max_year);
+/// ```
+pub const fn is_large_dates_enabled() -> bool {
+    cfg!(feature = "large-dates")
+}
+
 /// Update time zone information from the system.
 ///
 /// For a version of this function that is guaranteed to be sound, see [`refresh_tz`].
@@ -0,0 +1,145 @@
+//! The [`WasmInstant`] struct and its associated `impl`s.
+
+use core::cmp::Ordering;
+use core::ops::{Add, Sub};
+use core::time::Duration as StdDuration;
+
+use crate::internal_macros::{impl_add_assign, impl_sub_assign};
+use crate::{sys, Duration};
+
+/// A measurement of a monotonically non-decreasing clock, for use in web browsers where
+/// [`std::time::Instant`] is not implemented. Opaque and useful only with [`Duration`].
+///
+/// The clock is backed by [`performance.now()`][performance-now], which measures the time elapsed
+/// since the time origin of the current context. Its precision depends on the browser and is
+/// frequently reduced to as coarse as one millisecond to mitigate timing attacks. The clock may
+/// also not advance while the page is suspended.
+///
+/// This type is only available on WebAssembly targets other than Emscripten and WASI, with the
+/// `wasm-bindgen` feature enabled. On all other targets, use [`std::time::Instant`] along with
+/// [`InstantExt`](crate::ext::InstantExt).
+///
+/// [performance-now]: https://developer.mozilla.org/en-US/docs/Web/API/Performance/now
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WasmInstant(sys::Instant);
+
+impl WasmInstant {
+    /// Returns a `WasmInstant` corresponding to "now".
+    ///
+    /// # Panics
+    ///
+    /// This panics if `performance.now()` is not available in the current context.
+    pub fn now() -> Self {
+        Self(sys::Instant::now())
+    }
+
+    /// Returns the amount of time elapsed since this instant was created. The duration will always
+    /// be nonnegative if the instant is not synthetically created.
+    pub fn elapsed(self) -> Duration {
+        Self::now() - self
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one, or `None` if the
+    /// duration cannot be represented. The duration is negative if `earlier` is later than `self`.
+    pub fn checked_duration_since(self, earlier: Self) -> Option<Duration> {
+        match self.0.cmp(&earlier.0) {
+            Ordering::Equal => Some(Duration::ZERO),
+            Ordering::Greater => (self.0 - earlier.0).try_into().ok(),
+            Ordering::Less => Duration::try_from(earlier.0 - self.0)
+                .ok()
+                .map(|duration| -duration),
+        }
+    }
+
+    /// Returns the amount of time elapsed from another instant to this one, saturating to
+    /// [`Duration::MIN`] or [`Duration::MAX`] if the duration cannot be represented. The duration
+    /// is negative if `earlier` is later than `self`.
+    pub fn saturating_duration_since(self, earlier: Self) -> Duration {
+        match self.checked_duration_since(earlier) {
+            Some(duration) => duration,
+            None if self.0 > earlier.0 => Duration::MAX,
+            None => Duration::MIN,
+        }
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self + duration` if `t` can be represented, `None`
+    /// otherwise. An instant preceding the time origin cannot be represented.
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        if duration.is_negative() {
+            self.0.checked_sub(duration.unsigned_abs()).map(Self)
+        } else {
+            self.0.checked_add(duration.unsigned_abs()).map(Self)
+        }
+    }
+
+    /// Returns `Some(t)` where `t` is the time `self - duration` if `t` can be represented, `None`
+    /// otherwise. An instant preceding the time origin cannot be represented.
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        if duration.is_negative() {
+            self.0.checked_add(duration.unsigned_abs()).map(Self)
+        } else {
+            self.0.checked_sub(duration.unsigned_abs()).map(Self)
+        }
+    }
+}
+
+impl Sub for WasmInstant {
+    type Output = Duration;
+
+    /// # Panics
+    ///
+    /// This may panic if an overflow occurs.
+    fn sub(self, other: Self) -> Self::Output {
+        self.checked_duration_since(other)
+            .expect("overflow converting `std::time::Duration` to `time::Duration`")
+    }
+}
+
+impl Add<Duration> for WasmInstant {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented.
+    fn add(self, duration: Duration) -> Self::Output {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl Add<StdDuration> for WasmInstant {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented.
+    fn add(self, duration: StdDuration) -> Self::Output {
+        Self(self.0 + duration)
+    }
+}
+
+impl Sub<Duration> for WasmInstant {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented.
+    fn sub(self, duration: Duration) -> Self::Output {
+        self.checked_sub(duration)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl Sub<StdDuration> for WasmInstant {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented.
+    fn sub(self, duration: StdDuration) -> Self::Output {
+        Self(self.0 - duration)
+    }
+}
+
+impl_add_assign!(WasmInstant: Duration, StdDuration);
+impl_sub_assign!(WasmInstant: Duration, StdDuration);
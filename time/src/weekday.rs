@@ -1,8 +1,11 @@
 //! Days of the week.
 
+#[cfg(not(time_no_names))]
 use core::fmt;
+#[cfg(not(time_no_names))]
 use core::str::FromStr;
 
+#[cfg(not(time_no_names))]
 use powerfmt::smart_display::{FormatterOptions, Metadata, SmartDisplay};
 
 use self::Weekday::*;
@@ -111,6 +114,30 @@ impl Weekday {
         }
     }
 
+    /// Obtain the weekday with the given one-indexed number, counting from `week_start`.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_number(1, Weekday::Monday), Ok(Weekday::Monday));
+    /// assert_eq!(Weekday::from_number(1, Weekday::Sunday), Ok(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_number(7, Weekday::Sunday), Ok(Weekday::Saturday));
+    /// assert!(Weekday::from_number(0, Weekday::Monday).is_err());
+    /// assert!(Weekday::from_number(8, Weekday::Monday).is_err());
+    /// ```
+    pub const fn from_number(n: u8, week_start: Self) -> Result<Self, error::ComponentRange> {
+        match n {
+            1..=7 => Ok(week_start.nth_next(n - 1)),
+            _ => Err(error::ComponentRange {
+                name: "weekday",
+                minimum: 1,
+                maximum: 7,
+                value: n as _,
+                conditional_message: None,
+                conditional_date: None,
+            }),
+        }
+    }
+
     /// Get the one-indexed number of days from Monday.
     ///
     /// ```rust
@@ -161,13 +188,16 @@ impl Weekday {
     }
 }
 
+#[cfg(not(time_no_names))]
 mod private {
     #[non_exhaustive]
     #[derive(Debug, Clone, Copy)]
     pub struct WeekdayMetadata;
 }
+#[cfg(not(time_no_names))]
 use private::WeekdayMetadata;
 
+#[cfg(not(time_no_names))]
 impl SmartDisplay for Weekday {
     type Metadata = WeekdayMetadata;
 
@@ -196,25 +226,43 @@ impl SmartDisplay for Weekday {
     }
 }
 
+#[cfg(not(time_no_names))]
 impl fmt::Display for Weekday {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         SmartDisplay::fmt(self, f)
     }
 }
 
+#[cfg(not(time_no_names))]
 impl FromStr for Weekday {
     type Err = error::InvalidVariant;
 
+    /// Parse a weekday from its English name. Both the full name (`"Monday"`) and the three-letter
+    /// abbreviation (`"Mon"`) are accepted, ignoring ASCII case. The accepted values are not
+    /// affected by the system locale.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!("Monday".parse(), Ok(Weekday::Monday));
+    /// assert_eq!("monday".parse(), Ok(Weekday::Monday));
+    /// assert_eq!("MON".parse(), Ok(Weekday::Monday));
+    /// assert!("Mo".parse::<Weekday>().is_err());
+    /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "Monday" => Ok(Monday),
-            "Tuesday" => Ok(Tuesday),
-            "Wednesday" => Ok(Wednesday),
-            "Thursday" => Ok(Thursday),
-            "Friday" => Ok(Friday),
-            "Saturday" => Ok(Saturday),
-            "Sunday" => Ok(Sunday),
-            _ => Err(error::InvalidVariant),
-        }
+        const NAMES: [(&str, Weekday); 7] = [
+            ("Monday", Monday),
+            ("Tuesday", Tuesday),
+            ("Wednesday", Wednesday),
+            ("Thursday", Thursday),
+            ("Friday", Friday),
+            ("Saturday", Saturday),
+            ("Sunday", Sunday),
+        ];
+
+        NAMES
+            .iter()
+            .find(|(name, _)| s.eq_ignore_ascii_case(name) || s.eq_ignore_ascii_case(&name[..3]))
+            .map(|&(_, weekday)| weekday)
+            .ok_or(error::InvalidVariant)
     }
 }
@@ -0,0 +1,172 @@
+//! A small command-line tool for formatting, parsing, and validating using the public API of
+//! `time`. It doubles as a reference for applications that accept format descriptions at runtime.
+//!
+//! ```text
+//! timeparse format [--strftime] <description> [<timestamp>]
+//! timeparse parse [--strftime] <description> <input>
+//! timeparse validate [--strftime] <description>
+//! ```
+//!
+//! The description is one of `rfc2822`, `rfc3339`, or `iso8601`, or otherwise a [format
+//! description] using version 2 of the syntax. With `--strftime`, a custom description uses the
+//! syntax of `strftime` instead.
+//!
+//! - `format` formats the provided RFC 3339 timestamp, or the current time in UTC if none is
+//!   provided.
+//! - `parse` parses the input and prints the most complete value that can be constructed from it,
+//!   whether that is an `OffsetDateTime`, `PrimitiveDateTime`, `Date`, `Time`, or `UtcOffset`.
+//! - `validate` checks that the description is valid.
+//!
+//! [format description]: https://time-rs.github.io/book/api/format-description.html
+
+#![allow(clippy::print_stdout)] // printing is the purpose of this tool
+
+use std::env;
+use std::process::ExitCode;
+
+use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use time::format_description::{self, OwnedFormatItem};
+use time::parsing::Parsed;
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+/// The message printed when the arguments are invalid or help is requested.
+const USAGE: &str = "\
+usage:
+    timeparse format [--strftime] <description> [<timestamp>]
+    timeparse parse [--strftime] <description> <input>
+    timeparse validate [--strftime] <description>
+
+<description> is one of `rfc2822`, `rfc3339`, or `iso8601`, or a version 2 format description such
+as `[year]-[month]-[day]`. With `--strftime`, it is instead a description such as `%Y-%m-%d`.
+<timestamp> is in the format of RFC 3339 and defaults to the current time.";
+
+/// A description provided on the command line.
+enum Description {
+    /// The well-known RFC 2822 format.
+    Rfc2822,
+    /// The well-known RFC 3339 format.
+    Rfc3339,
+    /// The well-known ISO 8601 format, using the default configuration.
+    Iso8601,
+    /// A format description parsed at runtime.
+    Custom(OwnedFormatItem),
+}
+
+impl Description {
+    /// Parse the description from a command-line argument.
+    fn new(
+        description: &str,
+        strftime: bool,
+    ) -> Result<Self, time::error::InvalidFormatDescription> {
+        Ok(match description {
+            "rfc2822" => Self::Rfc2822,
+            "rfc3339" => Self::Rfc3339,
+            "iso8601" => Self::Iso8601,
+            _ if strftime => Self::Custom(format_description::parse_strftime_owned(description)?),
+            _ => Self::Custom(format_description::parse_owned::<2>(description)?),
+        })
+    }
+
+    /// Format the value using the description.
+    fn format(&self, datetime: OffsetDateTime) -> Result<String, time::error::Format> {
+        match self {
+            Self::Rfc2822 => datetime.format(&Rfc2822),
+            Self::Rfc3339 => datetime.format(&Rfc3339),
+            Self::Iso8601 => datetime.format(&Iso8601::DEFAULT),
+            Self::Custom(items) => datetime.format(items),
+        }
+    }
+
+    /// Parse the entirety of the input using the description.
+    fn parse(&self, input: &str) -> Result<Parsed, String> {
+        let mut parsed = Parsed::new();
+        let remaining = match self {
+            Self::Rfc2822 => parsed.parse(input.as_bytes(), &Rfc2822),
+            Self::Rfc3339 => parsed.parse(input.as_bytes(), &Rfc3339),
+            Self::Iso8601 => parsed.parse(input.as_bytes(), &Iso8601::DEFAULT),
+            Self::Custom(items) => parsed.parse(input.as_bytes(), items),
+        }
+        .map_err(|err| err.to_string())?;
+
+        if remaining.is_empty() {
+            Ok(parsed)
+        } else {
+            Err(format!(
+                "unexpected trailing characters: {:?}",
+                String::from_utf8_lossy(remaining)
+            ))
+        }
+    }
+}
+
+/// Print the most complete value that can be constructed from the parsed components.
+fn print_parsed(parsed: Parsed) -> Result<(), String> {
+    if let Ok(value) = OffsetDateTime::try_from(parsed) {
+        println!("OffsetDateTime: {value}");
+    } else if let Ok(value) = PrimitiveDateTime::try_from(parsed) {
+        println!("PrimitiveDateTime: {value}");
+    } else if let Ok(value) = Date::try_from(parsed) {
+        println!("Date: {value}");
+    } else if let Ok(value) = Time::try_from(parsed) {
+        println!("Time: {value}");
+    } else if let Ok(value) = UtcOffset::try_from(parsed) {
+        println!("UtcOffset: {value}");
+    } else {
+        return Err("the input does not contain enough information to construct a value".into());
+    }
+    Ok(())
+}
+
+/// Run the command described by the arguments.
+fn run(mut args: Vec<String>) -> Result<(), String> {
+    let strftime = args.iter().any(|arg| arg == "--strftime");
+    args.retain(|arg| arg != "--strftime");
+
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    let (command, description, rest) = match args.as_slice() {
+        [command, description, rest @ ..] => (*command, *description, rest),
+        _ => return Err(USAGE.into()),
+    };
+    let description = Description::new(description, strftime).map_err(|err| err.to_string())?;
+
+    match (command, rest) {
+        ("format", []) => {
+            println!(
+                "{}",
+                description
+                    .format(OffsetDateTime::now_utc())
+                    .map_err(|err| err.to_string())?
+            );
+        }
+        ("format", [timestamp]) => {
+            let datetime =
+                OffsetDateTime::parse(timestamp, &Rfc3339).map_err(|err| err.to_string())?;
+            println!(
+                "{}",
+                description
+                    .format(datetime)
+                    .map_err(|err| err.to_string())?
+            );
+        }
+        ("parse", [input]) => print_parsed(description.parse(input)?)?,
+        ("validate", []) => println!("valid"),
+        _ => return Err(USAGE.into()),
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+
+    match run(args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}